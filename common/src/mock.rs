@@ -210,6 +210,26 @@ pub fn charlie() -> AccountId32 {
     AccountId32::from([3; 32])
 }
 
+/// Mock of pallet `airdrop::Config`.
+#[macro_export]
+macro_rules! mock_airdrop_config {
+    ($runtime:ty) => {
+        frame_support::parameter_types! {
+            pub AirdropTreasuryAccount: AccountId = AccountId::from([9; 32]);
+            pub const AirdropMaxProofLength: u32 = 32;
+            pub const AirdropClaimedRemovalLimit: u32 = 2;
+        }
+        impl airdrop::Config for $runtime {
+            type ClaimedRemovalLimit = AirdropClaimedRemovalLimit;
+            type ManagerOrigin = frame_system::EnsureRoot<AccountId>;
+            type MaxProofLength = AirdropMaxProofLength;
+            type RuntimeEvent = RuntimeEvent;
+            type TreasuryAccount = AirdropTreasuryAccount;
+            type WeightInfo = ();
+        }
+    };
+}
+
 /// Mock of pallet `apollo_platform::Config`.
 #[macro_export]
 macro_rules! mock_apollo_platform_config {
@@ -375,6 +395,7 @@ macro_rules! mock_bridge_multisig_config {
 macro_rules! mock_ceres_governance_platform_config {
     ($runtime:ty) => {
         impl ceres_governance_platform::Config for $runtime {
+            const CONVICTION_LOCK_PERIOD: Self::Moment = 10;
             type DescriptionLimit = DescriptionLimit;
             type OptionsLimit = OptionsLimit;
             type RuntimeEvent = RuntimeEvent;
@@ -419,6 +440,21 @@ macro_rules! mock_ceres_token_locker_config {
     };
 }
 
+/// Mock of pallet `concentrated_liquidity_pool::Config`.
+#[macro_export]
+macro_rules! mock_concentrated_liquidity_pool_config {
+    ($runtime:ty) => {
+        impl concentrated_liquidity_pool::Config for $runtime {
+            type DexInfoProvider = dex_manager::Pallet<$runtime>;
+            type EnsureDEXManager = dex_manager::Pallet<$runtime>;
+            type MaxInitializedTicks = frame_support::traits::ConstU32<1000>;
+            type RuntimeEvent = RuntimeEvent;
+            type TradingPairSourceManager = trading_pair::Pallet<$runtime>;
+            type WeightInfo = ();
+        }
+    };
+}
+
 /// Mock of pallet `common::Config`.
 #[macro_export]
 macro_rules! mock_common_config {
@@ -478,6 +514,7 @@ macro_rules! mock_dex_api_config {
         $order_book:ty
     ) => {
         impl dex_api::Config for $runtime {
+            type ConcentratedLiquidityPool = ();
             type DexInfoProvider = dex_manager::Pallet<$runtime>;
             type MockLiquiditySource = mock_liquidity_source::Pallet<$runtime, $liquidity_source1>;
             type MockLiquiditySource2 = mock_liquidity_source::Pallet<$runtime, $liquidity_source2>;
@@ -515,6 +552,7 @@ macro_rules! mock_dex_api_config {
     };
     ($runtime:ty, $mcbc_pool:ty, $xyk_pool:ty, $xst_pool:ty) => {
         impl dex_api::Config for $runtime {
+            type ConcentratedLiquidityPool = ();
             type DexInfoProvider = dex_manager::Pallet<$runtime>;
             type MockLiquiditySource = ();
             type MockLiquiditySource2 = ();
@@ -540,7 +578,11 @@ macro_rules! mock_dex_api_config {
 #[macro_export]
 macro_rules! mock_dex_manager_config {
     ($runtime:ty) => {
-        impl dex_manager::Config for $runtime {}
+        impl dex_manager::Config for $runtime {
+            type RuntimeEvent = RuntimeEvent;
+            type AssetInfoProvider = assets::Pallet<$runtime>;
+            type WeightInfo = ();
+        }
     };
 }
 
@@ -676,6 +718,7 @@ macro_rules! mock_kensetsu_config {
             type KenIncentiveRemintPercent = GetKenIncentiveRemintPercent;
             type LiquidityProxy = MockLiquidityProxy;
             type MaxCdpsPerOwner = frame_support::traits::ConstU32<10000>;
+            type MaxOracleRateAge = ();
             type MinimalStabilityFeeAccrue = MinimalStabilityFeeAccrue;
             type Oracle = MockOracle;
             type PriceTools = MockPriceTools;
@@ -817,14 +860,25 @@ macro_rules! mock_multicollateral_bonding_curve_pool_config {
 /// Mock of pallet `oracle_proxy::Config`.
 #[macro_export]
 macro_rules! mock_oracle_proxy_config {
-    ($runtime:ty, $band_chain_oracle:ty, $symbol:ty) => {
+    ($runtime:ty, $band_chain_oracle:ty, $symbol:ty, $price_tools:ty) => {
+        frame_support::parameter_types! {
+            pub const GetOracleProxyReferenceAssetId: common::AssetId32<common::PredefinedAssetId> =
+                common::DAI;
+        }
         impl oracle_proxy::Config for $runtime {
+            type AssetId = common::AssetId32<common::PredefinedAssetId>;
             type BandChainOracle = $band_chain_oracle;
+            type GetReferenceAssetId = GetOracleProxyReferenceAssetId;
+            type PriceTools = $price_tools;
             type RuntimeEvent = RuntimeEvent;
             type Symbol = $symbol;
+            type Time = Timestamp;
             type WeightInfo = ();
         }
     };
+    ($runtime:ty, $band_chain_oracle:ty, $symbol:ty) => {
+        mock_oracle_proxy_config!($runtime, $band_chain_oracle, $symbol, ());
+    };
     ($runtime:ty, $band_chain_oracle:ty) => {
         mock_oracle_proxy_config!(
             $runtime,
@@ -984,7 +1038,9 @@ macro_rules! mock_pallet_transaction_payment_config {
 macro_rules! mock_permissions_config {
     ($runtime:ty) => {
         impl permissions::Config for $runtime {
+            type MaxExpiringPermissionsPerBlock = frame_support::traits::ConstU32<3>;
             type RuntimeEvent = RuntimeEvent;
+            type WeightInfo = ();
         }
     };
 }
@@ -1325,6 +1381,7 @@ macro_rules! mock_xst_config {
             type GetSyntheticBaseAssetId = GetSyntheticBaseAssetId;
             type GetSyntheticBaseBuySellLimit = GetSyntheticBaseBuySellLimit;
             type GetXSTPoolPermissionedTechAccountId = GetXSTPoolPermissionedTechAccountId;
+            type MaxOracleRateAge = ();
             type Oracle = $oracle;
             type PriceToolsPallet = $price_tool;
             type RuntimeEvent = RuntimeEvent;
@@ -972,6 +972,7 @@ pub enum LiquiditySourceType {
     MockPool4,
     XSTPool,
     OrderBook,
+    ConcentratedLiquidityPool,
 }
 
 #[derive(
@@ -1008,6 +1009,69 @@ pub enum ManagementMode {
     Public,
 }
 
+/// Multiplier applied to governance votes in exchange for keeping them locked past the end of
+/// the poll.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    RuntimeDebug,
+    PartialEq,
+    Eq,
+    Copy,
+    Clone,
+    Default,
+    scale_info::TypeInfo,
+    MaxEncodedLen,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum VoteConviction {
+    /// Votes are counted once and unlocked when the poll ends.
+    #[default]
+    None,
+    /// Votes are doubled and locked for one lock period.
+    Locked2x,
+    /// Votes are tripled and locked for two lock periods.
+    Locked3x,
+    /// Votes are quadrupled and locked for four lock periods.
+    Locked4x,
+    /// Votes are quintupled and locked for eight lock periods.
+    Locked5x,
+    /// Votes are sextupled and locked for sixteen lock periods.
+    Locked6x,
+}
+
+impl VoteConviction {
+    /// Multiplier of the locked amount.
+    pub fn multiplier(self) -> Balance {
+        match self {
+            VoteConviction::None => 1,
+            VoteConviction::Locked2x => 2,
+            VoteConviction::Locked3x => 3,
+            VoteConviction::Locked4x => 4,
+            VoteConviction::Locked5x => 5,
+            VoteConviction::Locked6x => 6,
+        }
+    }
+
+    /// Number of lock periods the votes stay locked after the poll ends.
+    pub fn lock_periods(self) -> u32 {
+        match self {
+            VoteConviction::None => 0,
+            VoteConviction::Locked2x => 1,
+            VoteConviction::Locked3x => 2,
+            VoteConviction::Locked4x => 4,
+            VoteConviction::Locked5x => 8,
+            VoteConviction::Locked6x => 16,
+        }
+    }
+
+    /// Voting power of the `amount` locked with this conviction.
+    pub fn votes(self, amount: Balance) -> Balance {
+        amount.saturating_mul(self.multiplier())
+    }
+}
+
 impl Default for ManagementMode {
     fn default() -> Self {
         Self::Private
@@ -1343,6 +1407,7 @@ pub struct PswapRemintInfo {
     scale_info::TypeInfo,
     MaxEncodedLen,
 )]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum PriceVariant {
     Buy,
     Sell,
@@ -1375,6 +1440,10 @@ impl PriceVariant {
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum Oracle {
     BandChainFeed,
+    /// Time-weighted average price from `price-tools`
+    PriceToolsTwap,
+    /// Rate of the first available provider confirmed by the rest of providers
+    CrossCheck,
 }
 
 /// Information about received oracle symbol (price and last update time)
@@ -1393,6 +1462,10 @@ impl<N: Get<u32>> BoundedString<N> {
     pub fn truncate_from(data: &str) -> Self {
         Self(BoundedVec::truncate_from(data.as_bytes().to_vec()))
     }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
 }
 
 impl<N: Get<u32>> codec::Decode for BoundedString<N> {
@@ -1618,6 +1691,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn check_vote_conviction() {
+        assert_eq!(VoteConviction::default(), VoteConviction::None);
+        assert_eq!(VoteConviction::None.votes(10), 10);
+        assert_eq!(VoteConviction::Locked3x.votes(10), 30);
+        assert_eq!(VoteConviction::Locked6x.votes(Balance::MAX), Balance::MAX);
+        assert_eq!(VoteConviction::None.lock_periods(), 0);
+        assert_eq!(VoteConviction::Locked5x.lock_periods(), 8);
+    }
+
     #[test]
     fn check_itoa() {
         assert_eq!(itoa(10u8), b"10");
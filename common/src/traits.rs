@@ -317,6 +317,11 @@ pub trait DataFeed<Symbol, Rate, ResolveTime> {
     /// - `symbol`: which symbol to query
     fn quote(symbol: &Symbol) -> Result<Option<Rate>, DispatchError>;
 
+    /// Get rate for the specified symbol, failing if the rate is older than `max_age`
+    /// - `symbol`: which symbol to query
+    /// - `max_age`: maximum allowed age of the rate in seconds
+    fn quote_with_max_age(symbol: &Symbol, max_age: u64) -> Result<Option<Rate>, DispatchError>;
+
     /// Get all supported symbols and their last update time
     fn list_enabled_symbols() -> Result<Vec<(Symbol, ResolveTime)>, DispatchError>;
 
@@ -330,6 +335,10 @@ impl<Symbol, Rate, ResolveTime> DataFeed<Symbol, Rate, ResolveTime> for () {
         Ok(None)
     }
 
+    fn quote_with_max_age(_symbol: &Symbol, _max_age: u64) -> Result<Option<Rate>, DispatchError> {
+        Ok(None)
+    }
+
     fn list_enabled_symbols() -> Result<Vec<(Symbol, ResolveTime)>, DispatchError> {
         Ok(Vec::new())
     }
@@ -1601,6 +1610,9 @@ pub trait OrderBookManager<AccountId, AssetId, DEXId, Moment> {
         side: PriceVariant,
         lifespan: Option<Moment>,
     ) -> Result<(), DispatchError>;
+
+    /// Forbids trading and placement of new limit orders, existing orders can only be cancelled
+    fn freeze_orderbook(order_book_id: &OrderBookId<AssetId, DEXId>) -> Result<(), DispatchError>;
 }
 
 impl<AccountId, AssetId, DEXId, Moment> OrderBookManager<AccountId, AssetId, DEXId, Moment> for () {
@@ -1638,6 +1650,10 @@ impl<AccountId, AssetId, DEXId, Moment> OrderBookManager<AccountId, AssetId, DEX
     ) -> Result<(), DispatchError> {
         Ok(())
     }
+
+    fn freeze_orderbook(_order_book_id: &OrderBookId<AssetId, DEXId>) -> Result<(), DispatchError> {
+        Ok(())
+    }
 }
 
 pub trait ExtendedAssetsManager<AssetId, Moment, ContentSource> {
@@ -50,6 +50,7 @@ use common::{
     SBT_PRINVST, TBCD, USDT, VAL, VXOR, XOR, XST, XSTUSD,
 };
 use frame_support::sp_runtime::Percent;
+use frame_support::traits::Get;
 use framenode_runtime::eth_bridge::{AssetConfig, BridgeAssetData, NetworkConfig};
 use framenode_runtime::multicollateral_bonding_curve_pool::{
     DistributionAccount, DistributionAccountData, DistributionAccounts,
@@ -60,15 +61,15 @@ use framenode_runtime::WASM_BINARY;
 use framenode_runtime::{
     assets, eth_bridge, frame_system, AccountId, AssetId, AssetName, AssetSymbol, AssetsConfig,
     BabeConfig, BalancesConfig, BeefyConfig, BeefyId, BridgeMultisigConfig,
-    BridgeOutboundChannelConfig, CouncilConfig, DEXAPIConfig, DEXManagerConfig, DemocracyConfig,
-    EthBridgeConfig, ExtendedAssetsConfig, GetBaseAssetId, GetParliamentAccountId, GetPswapAssetId,
-    GetSyntheticBaseAssetId, GetValAssetId, GetXorAssetId, GrandpaConfig, ImOnlineId,
-    IrohaMigrationConfig, KensetsuConfig, LiquiditySourceType,
+    BridgeOutboundChannelConfig, CouncilConfig, DEXAPIConfig, DEXManagerConfig, DefaultCustomFees,
+    DemocracyConfig, EthBridgeConfig, ExtendedAssetsConfig, GetBaseAssetId, GetParliamentAccountId,
+    GetPswapAssetId, GetSyntheticBaseAssetId, GetValAssetId, GetXorAssetId, GrandpaConfig,
+    ImOnlineId, IrohaMigrationConfig, KensetsuConfig, LiquiditySourceType,
     MulticollateralBondingCurvePoolConfig, PermissionsConfig, PolkamarktConfig,
     PolkamarktFeeCollector, PswapDistributionConfig, RewardsConfig, Runtime, RuntimeGenesisConfig,
     SS58Prefix, SessionConfig, Signature, StakerStatus, StakingConfig, SystemConfig, TechAccountId,
     TechnicalCommitteeConfig, TechnicalConfig, TokensConfig, TradingPair, TradingPairConfig,
-    XSTPoolConfig,
+    XSTPoolConfig, XorFeeConfig,
 };
 #[cfg(not(feature = "runtime-wasm"))]
 const WASM_BINARY: Option<&[u8]> = None;
@@ -1810,6 +1811,7 @@ fn testnet_genesis(
                 LiquiditySourceType::MulticollateralBondingCurvePool,
                 LiquiditySourceType::XSTPool,
                 LiquiditySourceType::OrderBook,
+                LiquiditySourceType::ConcentratedLiquidityPool,
             ]
             .into(),
             _phantom: Default::default(),
@@ -1874,6 +1876,10 @@ fn testnet_genesis(
             burn_info: (fixed!(0.1), fixed!(0.000357), fixed!(0.65)),
         },
         iroha_migration: iroha_migration_config,
+        xor_fee: XorFeeConfig {
+            custom_fees: DefaultCustomFees::get(),
+            _phantom: Default::default(),
+        },
         kensetsu: KensetsuConfig {
             predefined_stablecoin_sora_peg: vec![
                 (KUSD, DAI, balance!(1)),
@@ -2890,6 +2896,10 @@ fn mainnet_genesis(
             iroha_accounts: Vec::new(),
             account_id: Some(iroha_migration_account_id),
         },
+        xor_fee: XorFeeConfig {
+            custom_fees: DefaultCustomFees::get(),
+            _phantom: Default::default(),
+        },
         kensetsu: KensetsuConfig {
             predefined_stablecoin_sora_peg: vec![
                 (KUSD, DAI, balance!(1)),
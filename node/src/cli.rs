@@ -41,6 +41,12 @@ pub struct Cli {
     /// Disable BEEFY gadget. Enabled by default
     #[clap(long)]
     pub disable_beefy: bool,
+
+    /// Author blocks with manual-seal instead of BABE/GRANDPA.
+    /// `instant` seals a block for every new transaction, `manual` waits for `engine_createBlock` RPC calls
+    #[cfg(feature = "private-net")]
+    #[clap(long, value_enum)]
+    pub dev_seal: Option<crate::dev_seal::DevSeal>,
 }
 
 #[derive(Debug, clap::Subcommand)]
@@ -73,6 +79,10 @@ pub enum Subcommand {
     #[cfg(feature = "private-net")]
     ForkOff(crate::fork_off::ForkOffCmd),
 
+    /// Compile qa-tools scenario into a batch call or a genesis chain spec
+    #[cfg(feature = "private-net")]
+    QaScenario(crate::qa_scenario::QaScenarioCmd),
+
     /// The custom benchmark subcommmand benchmarking runtime pallets.
     #[cfg(feature = "runtime-benchmarks")]
     #[clap(subcommand)]
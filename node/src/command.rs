@@ -115,6 +115,22 @@ mod tests {
         <Cli as SubstrateCli>::load_spec(&cli, "dev")
             .expect("dev chainspec should load with embedded wasm");
     }
+
+    #[test]
+    fn dev_seal_flag_is_parsed() {
+        use crate::dev_seal::DevSeal;
+
+        assert_eq!(Cli::parse_from(["framenode"]).dev_seal, None);
+        assert_eq!(
+            Cli::parse_from(["framenode", "--dev-seal=instant"]).dev_seal,
+            Some(DevSeal::Instant)
+        );
+        assert_eq!(
+            Cli::parse_from(["framenode", "--dev-seal", "manual"]).dev_seal,
+            Some(DevSeal::Manual)
+        );
+        assert!(Cli::try_parse_from(["framenode", "--dev-seal=babe"]).is_err());
+    }
 }
 
 #[cfg(all(test, not(feature = "private-net")))]
@@ -281,10 +297,33 @@ pub fn run() -> sc_cli::Result<()> {
 
             runner.async_run(|config| Ok((cmd.run(config), task_manager)))
         }
+        #[cfg(feature = "private-net")]
+        Some(Subcommand::QaScenario(cmd)) => {
+            let runner = cli.create_runner(cmd)?;
+            set_default_ss58_version();
+            runner.sync_run(|config| cmd.run(config))
+        }
         None => {
             let runner = cli.create_runner(&cli.run)?;
             set_default_ss58_version();
             runner.run_node_until_exit(|config| async move {
+                #[cfg(feature = "private-net")]
+                if let Some(seal) = cli.dev_seal {
+                    return match config.network.network_backend {
+                        sc_network::config::NetworkBackendType::Libp2p => {
+                            crate::dev_seal::new_dev::<sc_network::NetworkWorker<_, _>>(
+                                config, seal,
+                            )
+                            .map_err(sc_cli::Error::Service)
+                        }
+                        sc_network::config::NetworkBackendType::Litep2p => {
+                            crate::dev_seal::new_dev::<sc_network::Litep2pNetworkBackend>(
+                                config, seal,
+                            )
+                            .map_err(sc_cli::Error::Service)
+                        }
+                    };
+                }
                 #[cfg(feature = "wip")] // Bridges
                 return match config.network.network_backend {
                     sc_network::config::NetworkBackendType::Libp2p => {
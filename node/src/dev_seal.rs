@@ -0,0 +1,428 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Development service that authors blocks with manual-seal instead of BABE/GRANDPA.
+
+use crate::service::FullClient;
+use beefy_gadget::communication::notification::{
+    BeefyBestBlockStream, BeefyVersionedFinalityProofStream,
+};
+use framenode_runtime::opaque::Block;
+use framenode_runtime::BeefyId;
+use futures::channel::mpsc;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use sc_client_api::Backend;
+use sc_consensus_manual_seal::consensus::babe::BabeConsensusDataProvider;
+use sc_consensus_manual_seal::rpc::{ManualSeal, ManualSealApiServer};
+use sc_consensus_manual_seal::{EngineCommand, InstantSealParams, ManualSealParams};
+use sc_network::NetworkBackend;
+use sc_service::error::Error as ServiceError;
+use sc_service::{Configuration, TaskManager};
+use sc_transaction_pool_api::OffchainTransactionPoolFactory;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_consensus_babe::inherents::BabeCreateInherentDataProviders;
+use sp_consensus_babe::BabeApi;
+use sp_runtime::traits::Block as BlockT;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// Block authoring mode of the development service.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum DevSeal {
+    /// Seal and finalize a block as soon as a transaction enters the pool.
+    Instant,
+    /// Seal blocks only on `engine_createBlock` RPC requests.
+    Manual,
+}
+
+/// Timestamp source of sealed blocks.
+///
+/// Every block takes the next slot, so block timestamps don't depend on the wall clock and can be
+/// moved forward with `dev_warpTimestamp`.
+#[derive(Clone)]
+pub struct DevTimestamp {
+    next: Arc<AtomicU64>,
+    slot_duration: u64,
+}
+
+/// Round `millis` up to the closest multiple of `slot_duration`.
+fn align_to_slot(millis: u64, slot_duration: u64) -> u64 {
+    millis.div_ceil(slot_duration).saturating_mul(slot_duration)
+}
+
+impl DevTimestamp {
+    fn starting_at(millis: u64, slot_duration: u64) -> Self {
+        Self {
+            next: Arc::new(AtomicU64::new(align_to_slot(millis, slot_duration))),
+            slot_duration,
+        }
+    }
+
+    /// Start from the current time, but never before the slot following the best block.
+    fn new(client: &FullClient, slot_duration: u64) -> Result<Self, ServiceError> {
+        let now = sp_timestamp::Timestamp::current().as_millis();
+        let best_hash = client.info().best_hash;
+        let best_slot = client
+            .header(best_hash)?
+            .and_then(|header| sc_consensus_babe::find_pre_digest::<Block>(&header).ok())
+            .map(|pre_digest| u64::from(pre_digest.slot()))
+            .unwrap_or_default();
+        let after_best = best_slot.saturating_add(1).saturating_mul(slot_duration);
+        Ok(Self::starting_at(now.max(after_best), slot_duration))
+    }
+
+    /// Timestamp of the next sealed block.
+    pub fn next_timestamp(&self) -> u64 {
+        self.next.load(Ordering::SeqCst)
+    }
+
+    /// Move the next block timestamp forward by at least `millis`, returns the new timestamp.
+    pub fn warp(&self, millis: u64) -> u64 {
+        let delta = align_to_slot(millis, self.slot_duration);
+        self.next.fetch_add(delta, Ordering::SeqCst) + delta
+    }
+
+    fn take(&self) -> u64 {
+        self.next.fetch_add(self.slot_duration, Ordering::SeqCst)
+    }
+
+    fn inherent_data_providers(
+        &self,
+        take: bool,
+    ) -> (
+        sp_consensus_babe::inherents::InherentDataProvider,
+        sp_timestamp::InherentDataProvider,
+    ) {
+        let millis = if take {
+            self.take()
+        } else {
+            self.next_timestamp()
+        };
+        let timestamp = sp_timestamp::InherentDataProvider::new(millis.into());
+        let slot =
+            sp_consensus_babe::inherents::InherentDataProvider::from_timestamp_and_slot_duration(
+                *timestamp,
+                sp_consensus_babe::SlotDuration::from_millis(self.slot_duration),
+            );
+        (slot, timestamp)
+    }
+}
+
+#[rpc(server)]
+pub trait DevTimestampApi {
+    /// Move the timestamp of the next sealed block forward by `millis`, rounded up to whole slots.
+    #[method(name = "dev_warpTimestamp")]
+    fn warp_timestamp(&self, millis: u64) -> RpcResult<u64>;
+
+    /// Timestamp of the next sealed block.
+    #[method(name = "dev_nextTimestamp")]
+    fn next_timestamp(&self) -> RpcResult<u64>;
+}
+
+pub struct DevTimestampRpc {
+    timestamp: DevTimestamp,
+}
+
+impl DevTimestampRpc {
+    pub fn new(timestamp: DevTimestamp) -> Self {
+        Self { timestamp }
+    }
+}
+
+impl DevTimestampApiServer for DevTimestampRpc {
+    fn warp_timestamp(&self, millis: u64) -> RpcResult<u64> {
+        Ok(self.timestamp.warp(millis))
+    }
+
+    fn next_timestamp(&self) -> RpcResult<u64> {
+        Ok(self.timestamp.next_timestamp())
+    }
+}
+
+/// Create a development node that seals blocks with manual-seal.
+///
+/// There is no GRANDPA and BEEFY, blocks are finalized either on sealing (`instant`) or with
+/// `engine_createBlock`/`engine_finalizeBlock` RPC calls (`manual`).
+pub fn new_dev<FullNetwork>(
+    mut config: Configuration,
+    seal: DevSeal,
+) -> Result<TaskManager, ServiceError>
+where
+    FullNetwork: NetworkBackend<Block, <Block as BlockT>::Hash> + 'static,
+{
+    // Increase the default value by 2 to make wasm being able to use 128MB, each heap page is 64KiB
+    config.executor.default_heap_pages = Some(1024 * 2);
+
+    let executor = sc_service::new_wasm_executor(&config.executor);
+    let (client, backend, keystore_container, mut task_manager) =
+        sc_service::new_full_parts::<Block, framenode_runtime::RuntimeApi, _>(
+            &config, None, executor,
+        )?;
+    let client = Arc::new(client);
+    let select_chain = sc_consensus::LongestChain::new(backend.clone());
+    let transaction_pool = Arc::from(
+        sc_transaction_pool::Builder::new(
+            task_manager.spawn_essential_handle(),
+            client.clone(),
+            config.role.is_authority().into(),
+        )
+        .with_options(config.transaction_pool.clone())
+        .with_prometheus(config.prometheus_registry())
+        .build(),
+    );
+
+    let babe_config = sc_consensus_babe::configuration(&*client)?;
+    let slot_duration = babe_config.slot_duration().as_millis();
+    let timestamp = DevTimestamp::new(&client, slot_duration)?;
+    let (block_import, babe_link) = sc_consensus_babe::block_import(
+        babe_config,
+        client.clone(),
+        client.clone(),
+        {
+            let timestamp = timestamp.clone();
+            Arc::new(move |_, _| {
+                let providers = timestamp.inherent_data_providers(false);
+                async move { Ok(providers) }
+            }) as BabeCreateInherentDataProviders<Block>
+        },
+        select_chain.clone(),
+        OffchainTransactionPoolFactory::new(transaction_pool.clone()),
+    )?;
+
+    let authorities = client
+        .runtime_api()
+        .current_epoch(client.info().best_hash)
+        .map_err(|e| ServiceError::Other(format!("Failed to read BABE epoch: {:?}", e)))?
+        .authorities;
+    let consensus_data_provider = BabeConsensusDataProvider::new(
+        client.clone(),
+        keystore_container.keystore(),
+        babe_link.epoch_changes().clone(),
+        authorities,
+    )
+    .map_err(|e| ServiceError::Other(format!("Failed to create BABE data provider: {:?}", e)))?;
+
+    let import_queue = sc_consensus_manual_seal::import_queue(
+        Box::new(block_import.clone()),
+        &task_manager.spawn_essential_handle(),
+        config.prometheus_registry(),
+    );
+
+    let metrics = <FullNetwork as NetworkBackend<Block, <Block as BlockT>::Hash>>::register_notification_metrics(
+        config.prometheus_config.as_ref().map(|cfg| &cfg.registry),
+    );
+    let net_config = sc_network::config::FullNetworkConfiguration::<
+        Block,
+        <Block as BlockT>::Hash,
+        FullNetwork,
+    >::new(
+        &config.network,
+        config
+            .prometheus_config
+            .as_ref()
+            .map(|cfg| cfg.registry.clone()),
+    );
+    let (network, system_rpc_tx, tx_handler_controller, sync_service) =
+        sc_service::build_network(sc_service::BuildNetworkParams {
+            config: &config,
+            net_config,
+            client: client.clone(),
+            transaction_pool: transaction_pool.clone(),
+            spawn_handle: task_manager.spawn_handle(),
+            import_queue,
+            block_announce_validator_builder: None,
+            warp_sync_config: None,
+            block_relay: None,
+            metrics,
+        })?;
+
+    if config.offchain_worker.enabled {
+        let offchain_workers =
+            sc_offchain::OffchainWorkers::new(sc_offchain::OffchainWorkerOptions {
+                runtime_api_provider: client.clone(),
+                is_validator: config.role.is_authority(),
+                keystore: Some(keystore_container.keystore()),
+                offchain_db: backend.offchain_storage(),
+                transaction_pool: Some(OffchainTransactionPoolFactory::new(
+                    transaction_pool.clone(),
+                )),
+                network_provider: Arc::new(network.clone()),
+                enable_http_requests: true,
+                custom_extensions: |_| vec![],
+            })?;
+        task_manager.spawn_handle().spawn(
+            "offchain-workers-runner",
+            "offchain-worker",
+            offchain_workers.run(client.clone(), task_manager.spawn_handle()),
+        );
+    }
+
+    let (command_sink, commands_stream) = match seal {
+        DevSeal::Instant => (None, None),
+        DevSeal::Manual => {
+            let (sink, stream) = mpsc::channel::<EngineCommand<<Block as BlockT>::Hash>>(1024);
+            (Some(sink), Some(stream))
+        }
+    };
+
+    let rpc_extensions_builder = {
+        let client = client.clone();
+        let pool = transaction_pool.clone();
+        let backend = backend.clone();
+        let timestamp = timestamp.clone();
+
+        move |subscription_executor| -> Result<crate::rpc::RpcExtension, sc_service::Error> {
+            // BEEFY doesn't run on the development service, its subscriptions never yield.
+            let (_, beefy_finality_proof_stream) =
+                BeefyVersionedFinalityProofStream::<Block, BeefyId>::channel();
+            let (_, beefy_best_block_stream) = BeefyBestBlockStream::<Block>::channel();
+            let deps = crate::rpc::FullDeps {
+                client: client.clone(),
+                pool: pool.clone(),
+                backend: backend.clone(),
+                deny_unsafe: sc_rpc::DenyUnsafe::Yes,
+                beefy: crate::rpc::BeefyDeps {
+                    beefy_finality_proof_stream,
+                    beefy_best_block_stream,
+                    subscription_executor,
+                },
+            };
+
+            let mut rpc = crate::rpc::create_full(deps)?;
+            rpc.merge(DevTimestampRpc::new(timestamp.clone()).into_rpc())
+                .map_err(|e| ServiceError::Application(Box::new(e)))?;
+            if let Some(command_sink) = command_sink.clone() {
+                rpc.merge(ManualSeal::new(command_sink).into_rpc())
+                    .map_err(|e| ServiceError::Application(Box::new(e)))?;
+            }
+            Ok(rpc)
+        }
+    };
+
+    let prometheus_registry = config.prometheus_registry().cloned();
+
+    let _rpc_handlers = sc_service::spawn_tasks(sc_service::SpawnTasksParams {
+        network,
+        client: client.clone(),
+        keystore: keystore_container.keystore(),
+        task_manager: &mut task_manager,
+        transaction_pool: transaction_pool.clone(),
+        rpc_builder: Box::new(rpc_extensions_builder),
+        backend,
+        system_rpc_tx,
+        config,
+        tx_handler_controller,
+        sync_service,
+        telemetry: None,
+        tracing_execute_block: None,
+    })?;
+
+    let mut proposer = sc_basic_authorship::ProposerFactory::new(
+        task_manager.spawn_handle(),
+        client.clone(),
+        transaction_pool.clone(),
+        prometheus_registry.as_ref(),
+        None,
+    );
+    // Increase default block size to be able to run runtime upgrade with larger runtime wasm
+    proposer.set_default_block_size_limit(sc_basic_authorship::DEFAULT_BLOCK_SIZE_LIMIT * 4);
+
+    let create_inherent_data_providers = move |_, ()| {
+        let providers = timestamp.inherent_data_providers(true);
+        async move { Ok(providers) }
+    };
+
+    match commands_stream {
+        None => {
+            let authorship =
+                sc_consensus_manual_seal::run_instant_seal_and_finalize(InstantSealParams {
+                    block_import,
+                    env: proposer,
+                    client,
+                    pool: transaction_pool,
+                    select_chain,
+                    consensus_data_provider: Some(Box::new(consensus_data_provider)),
+                    create_inherent_data_providers,
+                });
+            task_manager.spawn_essential_handle().spawn_blocking(
+                "instant-seal",
+                Some("block-authoring"),
+                authorship,
+            );
+        }
+        Some(commands_stream) => {
+            let authorship = sc_consensus_manual_seal::run_manual_seal(ManualSealParams {
+                block_import,
+                env: proposer,
+                client,
+                pool: transaction_pool,
+                commands_stream,
+                select_chain,
+                consensus_data_provider: Some(Box::new(consensus_data_provider)),
+                create_inherent_data_providers,
+            });
+            task_manager.spawn_essential_handle().spawn_blocking(
+                "manual-seal",
+                Some("block-authoring"),
+                authorship,
+            );
+        }
+    }
+
+    Ok(task_manager)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{align_to_slot, DevTimestamp};
+
+    #[test]
+    fn timestamps_are_aligned_to_slots() {
+        assert_eq!(align_to_slot(0, 6000), 0);
+        assert_eq!(align_to_slot(1, 6000), 6000);
+        assert_eq!(align_to_slot(12000, 6000), 12000);
+
+        let timestamp = DevTimestamp::starting_at(6001, 6000);
+        assert_eq!(timestamp.next_timestamp(), 12000);
+        assert_eq!(timestamp.take(), 12000);
+        assert_eq!(timestamp.take(), 18000);
+        assert_eq!(timestamp.next_timestamp(), 24000);
+    }
+
+    #[test]
+    fn warp_moves_next_timestamp_by_whole_slots() {
+        let timestamp = DevTimestamp::starting_at(6000, 6000);
+        assert_eq!(timestamp.warp(1), 12000);
+        assert_eq!(timestamp.warp(60_000), 72000);
+        assert_eq!(timestamp.take(), 72000);
+        assert_eq!(timestamp.next_timestamp(), 78000);
+    }
+}
@@ -28,14 +28,19 @@
 // STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+use std::collections::{BTreeMap, BTreeSet};
 use std::io::Write;
+use std::path::PathBuf;
 
+use codec::{Decode, Encode};
 use frame_remote_externalities::{
     Builder, Mode, OfflineConfig, OnlineConfig, RemoteExternalities, SnapshotConfig, Transport,
 };
+use framenode_runtime::opaque::SessionKeys;
+use framenode_runtime::AccountId;
 use sc_cli::CliConfiguration;
 use sc_service::Configuration;
-use sp_core::bytes::to_hex;
+use sp_core::bytes::{from_hex, to_hex};
 use sp_state_machine::{Backend as _, IterArgs, StorageIterator as _};
 
 const SKIPPED_PALLETS: [&str; 7] = [
@@ -48,7 +53,7 @@ const SKIPPED_PALLETS: [&str; 7] = [
     "Sudo",
 ];
 
-const INCLUDED_PREFIXES: [(&str, &str); 1] = [("System", "Accounts")];
+const INCLUDED_PREFIXES: [(&str, &str); 1] = [("System", "Account")];
 
 #[derive(Debug, Clone, clap::Parser)]
 pub struct ForkOffCmd {
@@ -68,6 +73,209 @@ pub struct ForkOffCmd {
     /// Print chainspec in raw format
     #[clap(long)]
     raw: bool,
+
+    /// Copy only storage of the given pallets from the remote chain. Can be repeated.
+    /// All pallets except the consensus related ones are copied by default
+    #[clap(long = "include-pallet", value_name = "PALLET")]
+    include_pallets: Vec<String>,
+
+    /// Keep storage of the given pallets from the local chain spec. Can be repeated
+    #[clap(long = "exclude-pallet", value_name = "PALLET")]
+    exclude_pallets: Vec<String>,
+
+    /// JSON file with sudo, validators and raw storage overrides for the forked state
+    #[clap(long, value_name = "FILE")]
+    overrides: Option<PathBuf>,
+}
+
+/// Overrides applied to the forked state after remote storage is copied.
+///
+/// Accounts are SS58 or hex encoded, storage keys and values are hex encoded and `null` value
+/// removes the key.
+#[derive(Debug, Default, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ForkOffOverrides {
+    #[serde(default)]
+    sudo: Option<String>,
+    #[serde(default)]
+    validators: Option<Vec<ForkOffValidator>>,
+    #[serde(default)]
+    storage: BTreeMap<String, Option<String>>,
+}
+
+/// Validator of the forked chain with its session keys, encoded as `author_rotateKeys` returns them.
+#[derive(Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ForkOffValidator {
+    account: String,
+    keys: String,
+}
+
+fn parse_account(account: &str) -> Result<AccountId, sc_cli::Error> {
+    account
+        .parse()
+        .map_err(|e| sc_cli::Error::Input(format!("Invalid account {account}: {e}")))
+}
+
+fn parse_hex(value: &str) -> Result<Vec<u8>, sc_cli::Error> {
+    from_hex(value).map_err(|e| sc_cli::Error::Input(format!("Invalid hex {value}: {e:?}")))
+}
+
+fn parse_session_keys(keys: &str) -> Result<SessionKeys, sc_cli::Error> {
+    SessionKeys::decode(&mut &parse_hex(keys)?[..])
+        .map_err(|e| sc_cli::Error::Input(format!("Invalid session keys {keys}: {e}")))
+}
+
+/// Hashed storage map key with `Twox64Concat` hasher.
+fn get_map_key(prefix: &[u8], key: &impl Encode) -> Vec<u8> {
+    let key = key.encode();
+    [prefix, &sp_core::twox_64(&key), &key].concat()
+}
+
+impl ForkOffOverrides {
+    fn load(path: &PathBuf) -> Result<Self, sc_cli::Error> {
+        let file = std::fs::File::open(path).map_err(|e| {
+            sc_cli::Error::Input(format!("Failed to open overrides file {path:?}: {e}"))
+        })?;
+        serde_json::from_reader(std::io::BufReader::new(file))
+            .map_err(|e| sc_cli::Error::Input(format!("Invalid overrides file {path:?}: {e}")))
+    }
+
+    fn apply(&self, top: &mut BTreeMap<Vec<u8>, Vec<u8>>) -> Result<(), sc_cli::Error> {
+        if let Some(sudo) = &self.sudo {
+            top.insert(
+                get_storage_prefix("Sudo", "Key"),
+                parse_account(sudo)?.encode(),
+            );
+        }
+        if let Some(validators) = &self.validators {
+            let validators = validators
+                .iter()
+                .map(|validator| {
+                    Ok((
+                        parse_account(&validator.account)?,
+                        parse_session_keys(&validator.keys)?,
+                    ))
+                })
+                .collect::<Result<Vec<_>, sc_cli::Error>>()?;
+            Self::apply_validators(top, &validators);
+        }
+        for (key, value) in &self.storage {
+            let key = parse_hex(key)?;
+            match value {
+                Some(value) => {
+                    top.insert(key, parse_hex(value)?);
+                }
+                None => {
+                    top.remove(&key);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Replaces the session validators and their keys together with the authorities of the
+    /// consensus pallets, so the forked chain is produced and finalized by them from genesis.
+    fn apply_validators(
+        top: &mut BTreeMap<Vec<u8>, Vec<u8>>,
+        validators: &[(AccountId, SessionKeys)],
+    ) {
+        let next_keys = get_storage_prefix("Session", "NextKeys");
+        let key_owner = get_storage_prefix("Session", "KeyOwner");
+        // keys of the local chain spec validators
+        top.retain(|k, _| !k.starts_with(&next_keys) && !k.starts_with(&key_owner));
+        for (account, keys) in validators {
+            top.insert(get_map_key(&next_keys, account), keys.encode());
+            for (raw_key, key_type) in keys.clone().into_raw_public_keys() {
+                top.insert(
+                    get_map_key(&key_owner, &(key_type, raw_key)),
+                    account.encode(),
+                );
+            }
+        }
+        top.insert(
+            get_storage_prefix("Session", "Validators"),
+            validators
+                .iter()
+                .map(|(account, _)| account)
+                .collect::<Vec<_>>()
+                .encode(),
+        );
+        top.insert(
+            get_storage_prefix("Session", "QueuedKeys"),
+            validators.encode(),
+        );
+        let babe_authorities = validators
+            .iter()
+            .map(|(_, keys)| (keys.babe.clone(), 1u64))
+            .collect::<Vec<_>>()
+            .encode();
+        top.insert(
+            get_storage_prefix("Babe", "Authorities"),
+            babe_authorities.clone(),
+        );
+        top.insert(
+            get_storage_prefix("Babe", "NextAuthorities"),
+            babe_authorities,
+        );
+        top.insert(
+            get_storage_prefix("Grandpa", "Authorities"),
+            validators
+                .iter()
+                .map(|(_, keys)| (keys.grandpa.clone(), 1u64))
+                .collect::<Vec<_>>()
+                .encode(),
+        );
+    }
+}
+
+/// Decides which storage keys are copied from the remote chain.
+struct StorageFilter {
+    skipped_pallets: BTreeSet<Vec<u8>>,
+    included_pallets: Option<BTreeSet<Vec<u8>>>,
+    included_prefixes: BTreeSet<Vec<u8>>,
+}
+
+impl StorageFilter {
+    fn new(include_pallets: &[String], exclude_pallets: &[String]) -> Self {
+        Self {
+            skipped_pallets: SKIPPED_PALLETS
+                .iter()
+                .cloned()
+                .chain(exclude_pallets.iter().map(String::as_str))
+                .map(get_pallet_prefix)
+                .collect(),
+            included_pallets: (!include_pallets.is_empty()).then(|| {
+                include_pallets
+                    .iter()
+                    .map(|pallet| get_pallet_prefix(pallet))
+                    .collect()
+            }),
+            included_prefixes: INCLUDED_PREFIXES
+                .iter()
+                .map(|(p, s)| get_storage_prefix(p, s))
+                .collect(),
+        }
+    }
+
+    fn copies_pallet(&self, pallet_prefix: &[u8]) -> bool {
+        !self.skipped_pallets.contains(pallet_prefix)
+            && self
+                .included_pallets
+                .as_ref()
+                .map_or(true, |included| included.contains(pallet_prefix))
+    }
+
+    /// Whether the key of the local chain spec is kept in the forked state.
+    fn keeps_local(&self, key: &[u8]) -> bool {
+        key.len() < 32 || !self.copies_pallet(&key[..16])
+    }
+
+    /// Whether the key of the remote chain is copied to the forked state.
+    fn copies_remote(&self, key: &[u8]) -> bool {
+        key.len() >= 32
+            && (self.copies_pallet(&key[..16]) || self.included_prefixes.contains(&key[..32]))
+    }
 }
 
 fn get_storage_prefix(pallet: &str, storage: &str) -> Vec<u8> {
@@ -84,7 +292,25 @@ fn get_pallet_prefix(pallet: &str) -> Vec<u8> {
 
 impl ForkOffCmd {
     pub async fn run(&self, mut cfg: Configuration) -> Result<(), sc_cli::Error> {
+        let overrides = self
+            .overrides
+            .as_ref()
+            .map(ForkOffOverrides::load)
+            .transpose()?
+            .unwrap_or_default();
+        let filter = StorageFilter::new(&self.include_pallets, &self.exclude_pallets);
         let transport: Transport = self.url.clone().into();
+        // Download only the requested pallets, snapshot made this way contains only them too.
+        let online_config = OnlineConfig {
+            transport,
+            pallets: self.include_pallets.clone(),
+            hashed_prefixes: if self.include_pallets.is_empty() {
+                Default::default()
+            } else {
+                filter.included_prefixes.iter().cloned().collect()
+            },
+            ..Default::default()
+        };
         let maybe_state_snapshot: Option<SnapshotConfig> = self.snapshot.clone().map(|s| s.into());
         let mut ext: RemoteExternalities<framenode_runtime::Block> =
             Builder::<framenode_runtime::Block>::default()
@@ -94,57 +320,36 @@ impl ForkOffCmd {
                             state_snapshot: state_snapshot.clone(),
                         },
                         OnlineConfig {
-                            transport,
                             state_snapshot: Some(state_snapshot),
-                            ..Default::default()
+                            ..online_config
                         },
                     )
                 } else {
-                    Mode::Online(OnlineConfig {
-                        transport,
-                        ..Default::default()
-                    })
+                    Mode::Online(online_config)
                 })
                 .build()
                 .await
-                .unwrap();
-        let skipped_prefixes = SKIPPED_PALLETS
-            .iter()
-            .cloned()
-            .map(get_pallet_prefix)
-            .collect::<std::collections::BTreeSet<_>>();
-        let included_prefixes = INCLUDED_PREFIXES
-            .iter()
-            .map(|(p, s)| get_storage_prefix(p, s))
-            .collect::<std::collections::BTreeSet<_>>();
+                .map_err(|e| sc_cli::Error::Input(format!("Failed to load remote state: {e}")))?;
         let mut storage = cfg.chain_spec.as_storage_builder().build_storage()?;
-        storage.top = storage
-            .top
-            .into_iter()
-            .filter(|(k, _)| {
-                if k.len() < 32 || skipped_prefixes.contains(&k[..16]) {
-                    true
-                } else {
-                    false
-                }
-            })
-            .collect();
+        storage.top.retain(|k, _| filter.keeps_local(k));
         let backend = ext.as_backend();
         let mut kv = backend
             .raw_iter(IterArgs::default())
             .map_err(|e| sc_cli::Error::Input(format!("Failed to iterate remote storage: {e}")))?;
+        let (mut copied, mut skipped) = (0usize, 0usize);
         while let Some(next_pair) = kv.next_pair(&backend) {
             let (k, v) = next_pair.map_err(|e| {
                 sc_cli::Error::Input(format!("Failed to read remote storage pair: {e}"))
             })?;
-            if k.len() >= 32
-                && (!skipped_prefixes.contains(&k[..16]) || included_prefixes.contains(&k[..32]))
-            {
+            if filter.copies_remote(&k) {
                 storage.top.insert(k, v);
+                copied += 1;
             } else {
                 log::debug!("Skipped {}", to_hex(&k, false));
+                skipped += 1;
             }
         }
+        log::info!("Copied {copied} remote storage keys, skipped {skipped}");
         // Delete System.LastRuntimeUpgrade to ensure that the on_runtime_upgrade event is triggered
         storage
             .top
@@ -153,9 +358,10 @@ impl ForkOffCmd {
         storage
             .top
             .insert(get_storage_prefix("Staking", "ForceEra"), vec![2]);
+        overrides.apply(&mut storage.top)?;
         cfg.chain_spec.set_storage(storage);
         let json = sc_service::chain_ops::build_spec(&*cfg.chain_spec, self.raw)?;
-        if std::io::stdout().write_all(json.as_bytes()).is_err() {
+        if std::io::stdout().lock().write_all(json.as_bytes()).is_err() {
             let _ = std::io::stderr().write_all(b"Error writing to stdout\n");
         }
         Ok(())
@@ -167,3 +373,138 @@ impl CliConfiguration for ForkOffCmd {
         &self.shared_params
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        get_map_key, get_pallet_prefix, get_storage_prefix, ForkOffOverrides, StorageFilter,
+    };
+    use codec::Encode;
+    use framenode_runtime::opaque::SessionKeys;
+    use framenode_runtime::AccountId;
+    use sp_core::bytes::to_hex;
+    use sp_keyring::Sr25519Keyring;
+    use std::collections::BTreeMap;
+
+    fn key(pallet: &str, storage: &str) -> Vec<u8> {
+        let mut key = get_storage_prefix(pallet, storage);
+        key.extend([1u8; 16]);
+        key
+    }
+
+    #[test]
+    fn default_filter_copies_all_but_skipped_pallets() {
+        let filter = StorageFilter::new(&[], &[]);
+        assert!(filter.copies_remote(&key("PoolXYK", "Reserves")));
+        assert!(!filter.keeps_local(&key("PoolXYK", "Reserves")));
+        assert!(!filter.copies_remote(&key("Session", "Validators")));
+        assert!(filter.keeps_local(&key("Session", "Validators")));
+        assert!(filter.copies_remote(&key("System", "Account")));
+        assert!(!filter.copies_remote(&key("System", "Number")));
+        assert!(filter.keeps_local(&key("System", "Account")));
+        assert!(!filter.copies_remote(&get_pallet_prefix("PoolXYK")));
+        assert!(filter.keeps_local(b":code"));
+    }
+
+    #[test]
+    fn filter_respects_included_and_excluded_pallets() {
+        let filter = StorageFilter::new(
+            &["PoolXYK".into(), "OrderBook".into(), "Sudo".into()],
+            &["OrderBook".into()],
+        );
+        assert!(filter.copies_remote(&key("PoolXYK", "Reserves")));
+        assert!(!filter.copies_remote(&key("OrderBook", "OrderBooks")));
+        assert!(filter.keeps_local(&key("OrderBook", "OrderBooks")));
+        assert!(!filter.copies_remote(&key("Kensetsu", "Cdps")));
+        assert!(filter.keeps_local(&key("Kensetsu", "Cdps")));
+        assert!(!filter.copies_remote(&key("Sudo", "Key")));
+        assert!(filter.copies_remote(&key("System", "Account")));
+    }
+
+    fn session_keys(seed: &str) -> SessionKeys {
+        let (_, _, _, babe, grandpa, im_online, beefy) =
+            framenode_chain_spec::authority_keys_from_seed(seed);
+        SessionKeys {
+            babe,
+            grandpa,
+            im_online,
+            beefy,
+        }
+    }
+
+    #[test]
+    fn overrides_replace_sudo_validators_and_storage() {
+        let alice: AccountId = Sr25519Keyring::Alice.to_account_id();
+        let bob: AccountId = Sr25519Keyring::Bob.to_account_id();
+        let alice_keys = session_keys("Alice");
+        let bob_keys = session_keys("Bob");
+        let overrides: ForkOffOverrides = serde_json::from_str(&format!(
+            r#"{{
+                "sudo": "{alice}",
+                "validators": [
+                    {{ "account": "{alice}", "keys": "{}" }},
+                    {{ "account": "{bob}", "keys": "{}" }}
+                ],
+                "storage": {{ "0x0102": "0x0304", "0x0506": null }}
+            }}"#,
+            to_hex(&alice_keys.encode(), false),
+            to_hex(&bob_keys.encode(), false),
+        ))
+        .unwrap();
+        let local_next_keys = get_map_key(
+            &get_storage_prefix("Session", "NextKeys"),
+            &Sr25519Keyring::Charlie.to_account_id(),
+        );
+        let mut top = BTreeMap::from([
+            (vec![5, 6], vec![7]),
+            (vec![1, 2], vec![0]),
+            (local_next_keys.clone(), vec![0]),
+        ]);
+        overrides.apply(&mut top).unwrap();
+
+        assert_eq!(
+            top.get(&get_storage_prefix("Sudo", "Key")),
+            Some(&alice.encode())
+        );
+        assert_eq!(
+            top.get(&get_storage_prefix("Session", "Validators")),
+            Some(&vec![alice.clone(), bob.clone()].encode())
+        );
+        assert_eq!(
+            top.get(&get_storage_prefix("Session", "QueuedKeys")),
+            Some(&vec![(alice.clone(), alice_keys.clone()), (bob, bob_keys.clone())].encode())
+        );
+        assert_eq!(top.get(&local_next_keys), None);
+        assert_eq!(
+            top.get(&get_map_key(
+                &get_storage_prefix("Session", "NextKeys"),
+                &alice
+            )),
+            Some(&alice_keys.encode())
+        );
+        assert_eq!(
+            top.get(&get_map_key(
+                &get_storage_prefix("Session", "KeyOwner"),
+                &(sp_core::crypto::key_types::BABE, alice_keys.babe.encode())
+            )),
+            Some(&alice.encode())
+        );
+        let babe_authorities = vec![(alice_keys.babe, 1u64), (bob_keys.babe, 1u64)].encode();
+        assert_eq!(
+            top.get(&get_storage_prefix("Babe", "Authorities")),
+            Some(&babe_authorities)
+        );
+        assert_eq!(
+            top.get(&get_storage_prefix("Babe", "NextAuthorities")),
+            Some(&babe_authorities)
+        );
+        assert_eq!(
+            top.get(&get_storage_prefix("Grandpa", "Authorities")),
+            Some(&vec![(alice_keys.grandpa, 1u64), (bob_keys.grandpa, 1u64)].encode())
+        );
+        assert_eq!(top.get(&vec![1, 2]), Some(&vec![3, 4]));
+        assert_eq!(top.get(&vec![5, 6]), None);
+
+        assert!(serde_json::from_str::<ForkOffOverrides>(r#"{"unknown": 1}"#).is_err());
+    }
+}
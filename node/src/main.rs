@@ -40,9 +40,13 @@ mod benchmarking;
 mod cli;
 mod command;
 mod data_feed_metrics;
+#[cfg(feature = "private-net")]
+mod dev_seal;
 mod eth_bridge_metrics;
 #[cfg(feature = "private-net")]
 mod fork_off;
+#[cfg(feature = "private-net")]
+mod qa_scenario;
 mod rpc;
 
 fn main() -> sc_cli::Result<()> {
@@ -0,0 +1,719 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Declarative scenarios for seeding private-net environments with `qa-tools`.
+//!
+//! A scenario is a JSON or TOML file (chosen by the file extension) which lists minted
+//! balances, prices, liquidity sources and Kensetsu collaterals. It is compiled either into
+//! a single `Sudo::sudo(Utility::batch_all(..))` call to submit to a running network or into
+//! a chain spec with the calls applied on top of the genesis state.
+//!
+//! Assets are referenced by the well known symbol (`XOR`, `VAL`, `DAI`, ...) or by hex id,
+//! amounts and prices are decimal strings with 18 digits precision, e.g. `"1.5"`.
+//!
+//! ```json
+//! {
+//!     "account": "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY",
+//!     "mints": [{ "asset": "XOR", "amount": "1000000" }],
+//!     "prices": [{ "asset": "VAL", "buy": "2", "sell": "1.9" }],
+//!     "xyk_pools": [{ "base": "XOR", "target": "VAL", "price": "0.5" }],
+//!     "order_books": [{
+//!         "base": "VAL",
+//!         "quote": "XOR",
+//!         "bids": { "highest_price": "0.5", "lowest_price": "0.4", "price_step": "0.01", "orders_per_price": 3 }
+//!     }],
+//!     "kensetsu_collaterals": [{
+//!         "asset": "VAL",
+//!         "stablecoin": "KUSD",
+//!         "hard_cap": "1000000",
+//!         "liquidation_ratio_percent": 50,
+//!         "max_liquidation_lot": "1000",
+//!         "stability_fee_rate": "0",
+//!         "minimal_collateral_deposit": "1"
+//!     }]
+//! }
+//! ```
+
+use std::io::Write;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use codec::Encode;
+use common::prelude::QuoteAmount;
+use common::{
+    AssetId32, AssetName, AssetSymbol, Balance, Fixed, OrderBookId, PredefinedAssetId, SymbolName,
+};
+use framenode_runtime::qa_tools::pallet_tools::{
+    mcbc, order_book, pool_xyk::AssetPairInput, price_tools::AssetPrices, xst,
+};
+use framenode_runtime::qa_tools::InputAssetId;
+use framenode_runtime::{AccountId, AssetId, DEXId, Runtime, RuntimeCall, RuntimeOrigin};
+use sc_cli::CliConfiguration;
+use sc_service::Configuration;
+use sp_core::bytes::to_hex;
+use sp_runtime::traits::Dispatchable;
+use sp_runtime::{FixedU128, Perbill};
+use sp_state_machine::BasicExternalities;
+
+type QaToolsCall = framenode_runtime::qa_tools::Call<Runtime>;
+
+/// What the scenario is compiled into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ScenarioOutput {
+    /// Hex encoded `Sudo::sudo(Utility::batch_all(..))` call.
+    Call,
+    /// Chain spec with the scenario applied to the genesis state.
+    Genesis,
+}
+
+#[derive(Debug, Clone, clap::Parser)]
+pub struct QaScenarioCmd {
+    /// Shared parameters of substrate cli.
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub shared_params: sc_cli::SharedParams,
+
+    /// Scenario file, JSON or TOML
+    #[clap(value_name = "FILE")]
+    scenario: PathBuf,
+
+    /// Compile the scenario into the batch call or the genesis chain spec
+    #[clap(long, value_enum, default_value = "call")]
+    output: ScenarioOutput,
+
+    /// Print chainspec in raw format
+    #[clap(long)]
+    raw: bool,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct MintInput {
+    asset: String,
+    /// Scenario account by default
+    #[serde(default)]
+    to: Option<String>,
+    amount: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PriceInput {
+    asset: String,
+    /// Amount of the asset per 1 XOR
+    buy: String,
+    sell: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct XykPoolInput {
+    #[serde(default)]
+    dex_id: DEXId,
+    base: String,
+    target: String,
+    /// Price of the target asset in terms of the base asset
+    price: String,
+    #[serde(default)]
+    base_reserves: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct NewSyntheticInput {
+    symbol: String,
+    name: String,
+    reference_symbol: String,
+    fee_ratio: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SyntheticScenarioInput {
+    asset: String,
+    /// Amount of the synthetic received for 1 synthetic base asset
+    price: String,
+    #[serde(default)]
+    register: Option<NewSyntheticInput>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct XstScenarioInput {
+    /// Buy and sell prices of synthetic base asset in terms of reference asset
+    #[serde(default)]
+    base_prices: Option<(String, String)>,
+    #[serde(default)]
+    synthetics: Vec<SyntheticScenarioInput>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct McbcCollateralInput {
+    asset: String,
+    /// Buy and sell prices of collateral in terms of reference asset
+    #[serde(default)]
+    ref_prices: Option<(String, String)>,
+    #[serde(default)]
+    reserves: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct McbcScenarioInput {
+    /// Target total supply of XOR, minted to or burned from the scenario account
+    #[serde(default)]
+    base_supply: Option<String>,
+    #[serde(default)]
+    collaterals: Vec<McbcCollateralInput>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SideInput {
+    highest_price: String,
+    lowest_price: String,
+    price_step: String,
+    orders_per_price: u32,
+    #[serde(default)]
+    lifespan: Option<u64>,
+    /// Min and max amount of generated orders
+    #[serde(default)]
+    amount_range: Option<(String, String)>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct OrderBookScenarioInput {
+    #[serde(default)]
+    dex_id: DEXId,
+    base: String,
+    quote: String,
+    #[serde(default)]
+    tick_size: Option<String>,
+    #[serde(default)]
+    step_lot_size: Option<String>,
+    #[serde(default)]
+    min_lot_size: Option<String>,
+    #[serde(default)]
+    max_lot_size: Option<String>,
+    #[serde(default)]
+    asks: Option<SideInput>,
+    #[serde(default)]
+    bids: Option<SideInput>,
+    #[serde(default)]
+    random_seed: Option<u32>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct KensetsuCollateralInput {
+    asset: String,
+    stablecoin: String,
+    hard_cap: String,
+    liquidation_ratio_percent: u32,
+    max_liquidation_lot: String,
+    /// Interest rate per second
+    stability_fee_rate: String,
+    minimal_collateral_deposit: String,
+}
+
+/// Scenario file contents, see module docs for the format.
+#[derive(Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Scenario {
+    /// Account which owns created orders, pools and receives minted assets by default
+    account: String,
+    #[serde(default)]
+    mints: Vec<MintInput>,
+    #[serde(default)]
+    prices: Vec<PriceInput>,
+    #[serde(default)]
+    xyk_pools: Vec<XykPoolInput>,
+    #[serde(default)]
+    xst: Option<XstScenarioInput>,
+    #[serde(default)]
+    mcbc: Option<McbcScenarioInput>,
+    #[serde(default)]
+    order_books: Vec<OrderBookScenarioInput>,
+    #[serde(default)]
+    kensetsu_collaterals: Vec<KensetsuCollateralInput>,
+    #[serde(default)]
+    presto: bool,
+}
+
+fn input_error(message: String) -> sc_cli::Error {
+    sc_cli::Error::Input(message)
+}
+
+fn parse_account(account: &str) -> Result<AccountId, sc_cli::Error> {
+    account
+        .parse()
+        .map_err(|e| input_error(format!("Invalid account {account}: {e}")))
+}
+
+fn parse_asset(asset: &str) -> Result<AssetId, sc_cli::Error> {
+    let predefined = match asset {
+        "XOR" => PredefinedAssetId::XOR,
+        "VAL" => PredefinedAssetId::VAL,
+        "PSWAP" => PredefinedAssetId::PSWAP,
+        "DAI" => PredefinedAssetId::DAI,
+        "ETH" => PredefinedAssetId::ETH,
+        "XST" => PredefinedAssetId::XST,
+        "XSTUSD" => PredefinedAssetId::XSTUSD,
+        "TBCD" => PredefinedAssetId::TBCD,
+        "KEN" => PredefinedAssetId::KEN,
+        "KUSD" => PredefinedAssetId::KUSD,
+        "KXOR" => PredefinedAssetId::KXOR,
+        _ => {
+            return AssetId32::from_str(asset)
+                .map_err(|e| input_error(format!("Invalid asset {asset}: {e}")))
+        }
+    };
+    Ok(AssetId32::from_asset_id(predefined))
+}
+
+/// Parse decimal string into the fixed point value with 18 digits precision.
+fn parse_amount(amount: &str) -> Result<Balance, sc_cli::Error> {
+    const DECIMALS: usize = 18;
+    let error = || input_error(format!("Invalid amount {amount}"));
+    let (integer, fraction) = amount.trim().split_once('.').unwrap_or((amount.trim(), ""));
+    if integer.is_empty()
+        || fraction.len() > DECIMALS
+        || !integer
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| c.is_ascii_digit())
+    {
+        return Err(error());
+    }
+    let integer: Balance = integer.parse().map_err(|_| error())?;
+    let fraction: Balance = format!("{fraction:0<DECIMALS$}")
+        .parse()
+        .map_err(|_| error())?;
+    integer
+        .checked_mul(10u128.pow(DECIMALS as u32))
+        .and_then(|integer| integer.checked_add(fraction))
+        .ok_or_else(error)
+}
+
+fn parse_prices((buy, sell): &(String, String)) -> Result<AssetPrices, sc_cli::Error> {
+    Ok(AssetPrices {
+        buy: parse_amount(buy)?,
+        sell: parse_amount(sell)?,
+    })
+}
+
+fn parse_side(
+    side: &SideInput,
+) -> Result<order_book::SideFillInput<framenode_runtime::Moment>, sc_cli::Error> {
+    Ok(order_book::SideFillInput {
+        highest_price: parse_amount(&side.highest_price)?,
+        lowest_price: parse_amount(&side.lowest_price)?,
+        price_step: parse_amount(&side.price_step)?,
+        orders_per_price: side.orders_per_price,
+        lifespan: side.lifespan,
+        amount_range_inclusive: side
+            .amount_range
+            .as_ref()
+            .map(|(min, max)| {
+                Ok::<_, sc_cli::Error>(order_book::RandomAmount::new(
+                    parse_amount(min)?,
+                    parse_amount(max)?,
+                ))
+            })
+            .transpose()?,
+    })
+}
+
+impl Scenario {
+    fn load(path: &PathBuf) -> Result<Self, sc_cli::Error> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| input_error(format!("Failed to read scenario file {path:?}: {e}")))?;
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => toml::from_str(&content).map_err(|e| e.to_string()),
+            _ => serde_json::from_str(&content).map_err(|e| e.to_string()),
+        }
+        .map_err(|e| input_error(format!("Invalid scenario file {path:?}: {e}")))
+    }
+
+    /// Compile the scenario into the root calls in the order they should be executed.
+    fn calls(&self) -> Result<Vec<RuntimeCall>, sc_cli::Error> {
+        let account = parse_account(&self.account)?;
+        let mut calls = vec![];
+
+        for mint in &self.mints {
+            calls.push(RuntimeCall::Assets(
+                framenode_runtime::assets::Call::force_mint {
+                    asset_id: parse_asset(&mint.asset)?,
+                    to: mint
+                        .to
+                        .as_deref()
+                        .map(parse_account)
+                        .transpose()?
+                        .unwrap_or_else(|| account.clone()),
+                    amount: parse_amount(&mint.amount)?,
+                },
+            ));
+        }
+
+        for price in &self.prices {
+            calls.push(RuntimeCall::QaTools(
+                QaToolsCall::price_tools_set_asset_price {
+                    asset_per_xor: parse_prices(&(price.buy.clone(), price.sell.clone()))?,
+                    asset_id: InputAssetId::Other(parse_asset(&price.asset)?),
+                },
+            ));
+        }
+
+        if !self.xyk_pools.is_empty() {
+            let pairs = self
+                .xyk_pools
+                .iter()
+                .map(|pool| {
+                    Ok(AssetPairInput {
+                        dex_id: pool.dex_id,
+                        asset_a: parse_asset(&pool.base)?,
+                        asset_b: parse_asset(&pool.target)?,
+                        price: parse_amount(&pool.price)?,
+                        maybe_asset_a_reserves: pool
+                            .base_reserves
+                            .as_deref()
+                            .map(parse_amount)
+                            .transpose()?,
+                    })
+                })
+                .collect::<Result<Vec<_>, sc_cli::Error>>()?;
+            calls.push(RuntimeCall::QaTools(QaToolsCall::xyk_initialize {
+                account: account.clone(),
+                pairs,
+            }));
+        }
+
+        if let Some(input) = &self.xst {
+            let synthetics_prices = input
+                .synthetics
+                .iter()
+                .map(|synthetic| {
+                    let existence = match &synthetic.register {
+                        None => xst::SyntheticExistence::AlreadyExists,
+                        Some(new) => xst::SyntheticExistence::RegisterNewAsset {
+                            symbol: AssetSymbol::from_str(&new.symbol).map_err(|e| {
+                                input_error(format!("Invalid symbol {}: {e}", new.symbol))
+                            })?,
+                            name: AssetName::from_str(&new.name).map_err(|e| {
+                                input_error(format!("Invalid name {}: {e}", new.name))
+                            })?,
+                            reference_symbol: SymbolName::from_str(&new.reference_symbol).map_err(
+                                |e| {
+                                    input_error(format!(
+                                        "Invalid reference symbol {}: {e}",
+                                        new.reference_symbol
+                                    ))
+                                },
+                            )?,
+                            fee_ratio: Fixed::from_bits(parse_amount(&new.fee_ratio)? as i128),
+                        },
+                    };
+                    Ok(xst::SyntheticInput {
+                        asset_id: parse_asset(&synthetic.asset)?,
+                        expected_quote: xst::SyntheticQuote {
+                            direction: xst::SyntheticQuoteDirection::SyntheticBaseToSynthetic,
+                            amount: QuoteAmount::with_desired_input(common::balance!(1)),
+                            result: parse_amount(&synthetic.price)?,
+                        },
+                        existence,
+                    })
+                })
+                .collect::<Result<Vec<_>, sc_cli::Error>>()?;
+            calls.push(RuntimeCall::QaTools(QaToolsCall::xst_initialize {
+                base_prices: input
+                    .base_prices
+                    .as_ref()
+                    .map(|prices| {
+                        let prices = parse_prices(prices)?;
+                        Ok::<_, sc_cli::Error>(xst::BaseInput {
+                            reference_per_synthetic_base_buy: prices.buy,
+                            reference_per_synthetic_base_sell: prices.sell,
+                        })
+                    })
+                    .transpose()?,
+                synthetics_prices,
+                relayer: account.clone(),
+            }));
+        }
+
+        if let Some(input) = &self.mcbc {
+            let tbcd = AssetId32::from_asset_id(PredefinedAssetId::TBCD);
+            let mut other_collaterals = vec![];
+            let mut tbcd_collateral = None;
+            for collateral in &input.collaterals {
+                let asset = parse_asset(&collateral.asset)?;
+                let parameters = mcbc::CollateralCommonParameters {
+                    ref_prices: collateral
+                        .ref_prices
+                        .as_ref()
+                        .map(parse_prices)
+                        .transpose()?,
+                    reserves: collateral
+                        .reserves
+                        .as_deref()
+                        .map(parse_amount)
+                        .transpose()?,
+                };
+                if asset == tbcd {
+                    tbcd_collateral = Some(mcbc::TbcdCollateralInput {
+                        parameters,
+                        ref_xor_prices: None,
+                    });
+                } else {
+                    other_collaterals.push(mcbc::OtherCollateralInput { asset, parameters });
+                }
+            }
+            calls.push(RuntimeCall::QaTools(QaToolsCall::mcbc_initialize {
+                base_supply: input
+                    .base_supply
+                    .as_deref()
+                    .map(|target| {
+                        Ok::<_, sc_cli::Error>(mcbc::BaseSupply {
+                            asset_collector: account.clone(),
+                            target: parse_amount(target)?,
+                        })
+                    })
+                    .transpose()?,
+                other_collaterals,
+                tbcd_collateral,
+            }));
+        }
+
+        if !self.order_books.is_empty() {
+            let default_attributes = order_book::OrderBookAttributes::default();
+            let settings = self
+                .order_books
+                .iter()
+                .map(|book| {
+                    let attribute = |value: &Option<String>, default: Balance| {
+                        value.as_deref().map(parse_amount).unwrap_or(Ok(default))
+                    };
+                    Ok((
+                        OrderBookId {
+                            dex_id: book.dex_id,
+                            base: parse_asset(&book.base)?,
+                            quote: parse_asset(&book.quote)?,
+                        },
+                        order_book::OrderBookAttributes {
+                            tick_size: attribute(&book.tick_size, default_attributes.tick_size)?,
+                            step_lot_size: attribute(
+                                &book.step_lot_size,
+                                default_attributes.step_lot_size,
+                            )?,
+                            min_lot_size: attribute(
+                                &book.min_lot_size,
+                                default_attributes.min_lot_size,
+                            )?,
+                            max_lot_size: attribute(
+                                &book.max_lot_size,
+                                default_attributes.max_lot_size,
+                            )?,
+                        },
+                        order_book::FillInput {
+                            asks: book.asks.as_ref().map(parse_side).transpose()?,
+                            bids: book.bids.as_ref().map(parse_side).transpose()?,
+                            random_seed: book.random_seed,
+                        },
+                    ))
+                })
+                .collect::<Result<Vec<_>, sc_cli::Error>>()?;
+            calls.push(RuntimeCall::QaTools(
+                QaToolsCall::order_book_create_and_fill_batch {
+                    bids_owner: account.clone(),
+                    asks_owner: account.clone(),
+                    settings,
+                },
+            ));
+        }
+
+        for collateral in &self.kensetsu_collaterals {
+            calls.push(RuntimeCall::Kensetsu(
+                framenode_runtime::kensetsu::Call::update_collateral_risk_parameters {
+                    collateral_asset_id: parse_asset(&collateral.asset)?,
+                    stablecoin_asset_id: parse_asset(&collateral.stablecoin)?,
+                    new_risk_parameters: framenode_runtime::kensetsu::CollateralRiskParameters {
+                        hard_cap: parse_amount(&collateral.hard_cap)?,
+                        liquidation_ratio: Perbill::from_percent(
+                            collateral.liquidation_ratio_percent,
+                        ),
+                        max_liquidation_lot: parse_amount(&collateral.max_liquidation_lot)?,
+                        stability_fee_rate: FixedU128::from_inner(parse_amount(
+                            &collateral.stability_fee_rate,
+                        )?),
+                        minimal_collateral_deposit: parse_amount(
+                            &collateral.minimal_collateral_deposit,
+                        )?,
+                    },
+                },
+            ));
+        }
+
+        if self.presto {
+            calls.push(RuntimeCall::QaTools(QaToolsCall::presto_initialize {}));
+        }
+
+        Ok(calls)
+    }
+}
+
+/// Wrap the calls into a single call which can be submitted by the sudo key.
+fn batch_call(calls: Vec<RuntimeCall>) -> RuntimeCall {
+    RuntimeCall::Sudo(pallet_sudo::Call::sudo {
+        call: Box::new(RuntimeCall::Utility(pallet_utility::Call::batch_all {
+            calls,
+        })),
+    })
+}
+
+/// Execute the calls on top of the genesis storage with root origin.
+fn apply_to_genesis(
+    calls: Vec<RuntimeCall>,
+    storage: &mut sp_core::storage::Storage,
+) -> Result<(), sc_cli::Error> {
+    BasicExternalities::execute_with_storage(storage, || {
+        for (index, call) in calls.into_iter().enumerate() {
+            call.dispatch(RuntimeOrigin::root()).map_err(|e| {
+                input_error(format!("Scenario call #{index} failed: {:?}", e.error))
+            })?;
+        }
+        Ok(())
+    })
+}
+
+impl QaScenarioCmd {
+    pub fn run(&self, mut cfg: Configuration) -> Result<(), sc_cli::Error> {
+        let calls = Scenario::load(&self.scenario)?.calls()?;
+        let output = match self.output {
+            ScenarioOutput::Call => to_hex(&batch_call(calls).encode(), false),
+            ScenarioOutput::Genesis => {
+                let mut storage = cfg.chain_spec.as_storage_builder().build_storage()?;
+                apply_to_genesis(calls, &mut storage)?;
+                cfg.chain_spec.set_storage(storage);
+                sc_service::chain_ops::build_spec(&*cfg.chain_spec, self.raw)?
+            }
+        };
+        if std::io::stdout()
+            .lock()
+            .write_all(output.as_bytes())
+            .is_err()
+        {
+            let _ = std::io::stderr().write_all(b"Error writing to stdout\n");
+        }
+        Ok(())
+    }
+}
+
+impl CliConfiguration for QaScenarioCmd {
+    fn shared_params(&self) -> &sc_cli::SharedParams {
+        &self.shared_params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_amount, parse_asset, RuntimeCall, Scenario};
+    use common::{balance, XOR};
+
+    #[test]
+    fn amounts_are_parsed_with_18_decimals() {
+        assert_eq!(parse_amount("1").unwrap(), balance!(1));
+        assert_eq!(parse_amount("0.5").unwrap(), balance!(0.5));
+        assert_eq!(
+            parse_amount("12.000000000000000001").unwrap(),
+            balance!(12) + 1
+        );
+        assert!(parse_amount("").is_err());
+        assert!(parse_amount(".5").is_err());
+        assert!(parse_amount("-1").is_err());
+        assert!(parse_amount("1.0000000000000000001").is_err());
+        assert!(parse_amount("1e18").is_err());
+    }
+
+    #[test]
+    fn assets_are_parsed_from_symbols_and_hex() {
+        assert_eq!(parse_asset("XOR").unwrap(), XOR);
+        assert_eq!(
+            parse_asset("0x0200000000000000000000000000000000000000000000000000000000000000")
+                .unwrap(),
+            XOR
+        );
+        assert!(parse_asset("UNKNOWN").is_err());
+    }
+
+    #[test]
+    fn scenario_is_compiled_in_order() {
+        let alice = sp_keyring::Sr25519Keyring::Alice.to_account_id();
+        let scenario: Scenario = toml::from_str(&format!(
+            r#"
+            account = "{alice}"
+            presto = true
+
+            [[mints]]
+            asset = "XOR"
+            amount = "1000"
+
+            [[xyk_pools]]
+            base = "XOR"
+            target = "VAL"
+            price = "0.5"
+
+            [[order_books]]
+            base = "VAL"
+            quote = "XOR"
+            bids = {{ highest_price = "0.5", lowest_price = "0.4", price_step = "0.01", orders_per_price = 2 }}
+
+            [[kensetsu_collaterals]]
+            asset = "VAL"
+            stablecoin = "KUSD"
+            hard_cap = "1000"
+            liquidation_ratio_percent = 50
+            max_liquidation_lot = "10"
+            stability_fee_rate = "0"
+            minimal_collateral_deposit = "1"
+            "#
+        ))
+        .unwrap();
+        let calls = scenario.calls().unwrap();
+        assert_eq!(calls.len(), 5);
+        assert!(matches!(calls[0], RuntimeCall::Assets(_)));
+        assert!(matches!(calls[1], RuntimeCall::QaTools(_)));
+        assert!(matches!(calls[3], RuntimeCall::Kensetsu(_)));
+        assert!(matches!(calls[4], RuntimeCall::QaTools(_)));
+
+        assert!(serde_json::from_str::<Scenario>(r#"{"account": "", "unknown": 1}"#).is_err());
+    }
+}
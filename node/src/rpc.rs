@@ -99,9 +99,26 @@ where
 }
 
 #[cfg(feature = "stage")]
-pub fn add_stage_rpc(
-    rpc: RpcExtension,
-) -> Result<RpcExtension, Box<dyn std::error::Error + Send + Sync>> {
+pub fn add_stage_rpc<C>(
+    mut rpc: RpcExtension,
+    client: Arc<C>,
+) -> Result<RpcExtension, Box<dyn std::error::Error + Send + Sync>>
+where
+    C: ProvideRuntimeApi<Block>,
+    C: HeaderBackend<Block> + HeaderMetadata<Block, Error = BlockChainError>,
+    C: Send + Sync + 'static,
+    C::Api: presto_rpc::PrestoRuntimeAPI<
+        Block,
+        AccountId,
+        AssetId,
+        Balance,
+        u64,
+        u64,
+        framenode_runtime::Moment,
+    >,
+{
+    use presto_rpc::{PrestoAPIServer, PrestoClient};
+    rpc.merge(PrestoClient::new(client).into_rpc())?;
     Ok(rpc)
 }
 
@@ -151,6 +168,13 @@ where
         Description,
     >,
     C::Api: polkamarkt_rpc::PolkamarktRuntimeAPI<Block, AccountId, Balance>,
+    C::Api: apollo_platform_rpc::ApolloRuntimeAPI<Block, AccountId, AssetId, Balance>,
+    C::Api: price_tools_rpc::PriceToolsRuntimeAPI<Block, AssetId, Balance, Symbol>,
+    C::Api: multicollateral_bonding_curve_pool_rpc::MulticollateralBondingCurvePoolRuntimeAPI<
+        Block,
+        AssetId,
+        Balance,
+    >,
     C::Api: liquidity_proxy_rpc::LiquidityProxyRuntimeAPI<
         Block,
         DEXId,
@@ -191,6 +215,7 @@ where
     C::Api: bridge_proxy_rpc::BridgeProxyRuntimeAPI<Block, AssetId>,
     P: TransactionPool + Send + Sync + 'static,
 {
+    use apollo_platform_rpc::{ApolloAPIServer, ApolloClient};
     use assets_rpc::{AssetsAPIServer, AssetsClient};
     use beefy_gadget_rpc::{Beefy, BeefyApiServer};
     use bridge_proxy_rpc::{BridgeProxyAPIServer, BridgeProxyClient};
@@ -202,9 +227,13 @@ where
     use leaf_provider_rpc::{LeafProviderAPIServer, LeafProviderClient};
     use liquidity_proxy_rpc::{LiquidityProxyAPIServer, LiquidityProxyClient};
     use mmr_rpc::{Mmr, MmrApiServer};
+    use multicollateral_bonding_curve_pool_rpc::{
+        MulticollateralBondingCurvePoolAPIServer, MulticollateralBondingCurvePoolClient,
+    };
     use oracle_proxy_rpc::{OracleProxyApiServer, OracleProxyClient};
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
     use polkamarkt_rpc::{PolkamarktAPIServer, PolkamarktClient};
+    use price_tools_rpc::{PriceToolsAPIServer, PriceToolsClient};
     use pswap_distribution_rpc::{PswapDistributionAPIServer, PswapDistributionClient};
     use rewards_rpc::{RewardsAPIServer, RewardsClient};
     use substrate_frame_rpc_system::{System, SystemApiServer};
@@ -240,8 +269,11 @@ where
     io.merge(TradingPairClient::new(client.clone()).into_rpc())?;
     io.merge(AssetsClient::new(client.clone()).into_rpc())?;
     io.merge(PolkamarktClient::new(client.clone()).into_rpc())?;
+    io.merge(ApolloClient::new(client.clone()).into_rpc())?;
     io.merge(LiquidityProxyClient::new(client.clone()).into_rpc())?;
     io.merge(OracleProxyClient::new(client.clone()).into_rpc())?;
+    io.merge(PriceToolsClient::new(client.clone()).into_rpc())?;
+    io.merge(MulticollateralBondingCurvePoolClient::new(client.clone()).into_rpc())?;
     io.merge(EthBridgeRpc::new(client.clone()).into_rpc())?;
     io.merge(IrohaMigrationClient::new(client.clone()).into_rpc())?;
     io.merge(PswapDistributionClient::new(client.clone()).into_rpc())?;
@@ -617,7 +617,7 @@ pub fn new_partial(
             let rpc = crate::rpc::add_wip_rpc(rpc, client.clone())?;
 
             #[cfg(feature = "stage")]
-            let rpc = crate::rpc::add_stage_rpc(rpc)?;
+            let rpc = crate::rpc::add_stage_rpc(rpc, client.clone())?;
 
             Ok(rpc)
         }
@@ -0,0 +1,134 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use codec::{Decode, Encode};
+use common::{eth, AssetManager, XOR};
+use frame_benchmarking::benchmarks;
+use frame_support::traits::Get;
+use frame_support::BoundedVec;
+use frame_system::RawOrigin;
+use hex_literal::hex;
+use sp_std::prelude::*;
+
+const CAMPAIGN_AMOUNT: Balance = 1_000_000;
+const CLAIM_AMOUNT: Balance = 1_000;
+
+/// Client account id
+fn caller<T: Config>() -> T::AccountId {
+    let bytes = hex!("92c4ff71ae7492a1e6fef5d80546ea16307c560ac1063ffaa5e0e084df1e2b7e");
+    T::AccountId::decode(&mut &bytes[..]).expect("Failed to decode account ID")
+}
+
+fn fund_treasury<T: Config>() {
+    T::AssetManager::update_balance(
+        RawOrigin::Root.into(),
+        T::TreasuryAccount::get(),
+        XOR.into(),
+        CAMPAIGN_AMOUNT.try_into().unwrap(),
+    )
+    .expect("Shall mint");
+}
+
+fn create_campaign<T: Config>(merkle_root: H256) -> CampaignId {
+    fund_treasury::<T>();
+    let campaign_id = NextCampaignId::<T>::get();
+    Pallet::<T>::create_campaign(
+        RawOrigin::Root.into(),
+        merkle_root,
+        XOR.into(),
+        CAMPAIGN_AMOUNT,
+        frame_system::Pallet::<T>::block_number() + 100u32.into(),
+    )
+    .expect("Shall create campaign");
+    campaign_id
+}
+
+fn insert_claimed<T: Config>(campaign_id: CampaignId, n: u32) {
+    for i in 0..n {
+        Claimed::<T>::insert(
+            campaign_id,
+            Beneficiary::<T::AccountId>::Ethereum(EthAddress::from_low_u64_be(i as u64)),
+            true,
+        );
+    }
+}
+
+benchmarks! {
+    create_campaign {
+        fund_treasury::<T>();
+        let deadline = frame_system::Pallet::<T>::block_number() + 100u32.into();
+    }: _(RawOrigin::Root, H256::repeat_byte(1), XOR.into(), CAMPAIGN_AMOUNT, deadline)
+    verify {
+        assert!(Campaigns::<T>::contains_key(0));
+    }
+
+    claim {
+        let p in 0 .. T::MaxProofLength::get();
+        let caller = caller::<T>();
+        let secret_key = secp256k1::SecretKey::parse(&[1; 32]).unwrap();
+        let eth_address = eth::public_key_to_eth_address(&secp256k1::PublicKey::from_secret_key(&secret_key));
+        let (signature, recovery_id) = secp256k1::sign(&eth::prepare_message(&caller.encode()), &secret_key);
+        let mut signature = signature.serialize().to_vec();
+        signature.push(recovery_id.serialize());
+
+        let proof: Vec<H256> = (0..p).map(|i| H256::from_low_u64_be(i as u64)).collect();
+        let leaf = leaf_hash(&Beneficiary::<T::AccountId>::Ethereum(eth_address), CLAIM_AMOUNT);
+        let campaign_id = create_campaign::<T>(compute_root(leaf, &proof));
+        let proof = BoundedVec::truncate_from(proof);
+    }: _(RawOrigin::Signed(caller), campaign_id, CLAIM_AMOUNT, proof, Some(signature))
+    verify {
+        assert!(Claimed::<T>::get(campaign_id, Beneficiary::Ethereum(eth_address)));
+    }
+
+    close_campaign {
+        let n in 0 .. T::ClaimedRemovalLimit::get();
+        let campaign_id = create_campaign::<T>(H256::repeat_byte(1));
+        insert_claimed::<T>(campaign_id, n);
+        frame_system::Pallet::<T>::set_block_number(frame_system::Pallet::<T>::block_number() + 101u32.into());
+    }: _(RawOrigin::Signed(caller::<T>()), campaign_id)
+    verify {
+        assert!(!Campaigns::<T>::contains_key(campaign_id));
+        assert!(Claimed::<T>::iter_prefix(campaign_id).next().is_none());
+    }
+
+    clear_claimed {
+        let n in 1 .. T::ClaimedRemovalLimit::get();
+        let campaign_id = NextCampaignId::<T>::get();
+        insert_claimed::<T>(campaign_id, n);
+        ClaimedCleanupCursor::<T>::insert(campaign_id, ClaimedCursorOf::default());
+    }: _(RawOrigin::Signed(caller::<T>()), campaign_id)
+    verify {
+        assert!(Claimed::<T>::iter_prefix(campaign_id).next().is_none());
+        assert!(!ClaimedCleanupCursor::<T>::contains_key(campaign_id));
+    }
+}
@@ -0,0 +1,447 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+mod benchmarking;
+
+pub mod weights;
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use common::eth::EthAddress;
+use common::Balance;
+use scale_info::TypeInfo;
+use sp_core::H256;
+use sp_io::hashing::keccak_256;
+use sp_runtime::RuntimeDebug;
+
+pub use pallet::*;
+
+pub type CampaignId = u32;
+
+/// Recipient of an airdrop allocation, i.e. the value a Merkle tree leaf is committed to.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum Beneficiary<AccountId> {
+    /// Allocation claimable by the account itself
+    Account(AccountId),
+    /// Allocation claimable by any account holding a signature of the Ethereum key
+    Ethereum(EthAddress),
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CampaignInfo<AssetId, BlockNumber> {
+    /// Root of the Merkle tree of `(beneficiary, amount)` leaves
+    pub merkle_root: H256,
+    /// Asset being distributed
+    pub asset_id: AssetId,
+    /// Amount reserved for the campaign
+    pub total_amount: Balance,
+    /// Amount already claimed
+    pub claimed_amount: Balance,
+    /// Last block at which claims are accepted
+    pub deadline: BlockNumber,
+}
+
+/// Hash of the Merkle tree leaf for the given allocation.
+pub fn leaf_hash<AccountId: Encode>(beneficiary: &Beneficiary<AccountId>, amount: Balance) -> H256 {
+    H256(keccak_256(&(beneficiary, amount).encode()))
+}
+
+/// Folds `proof` into `leaf`, hashing each pair of nodes in sorted order, and returns the root.
+pub fn compute_root(leaf: H256, proof: &[H256]) -> H256 {
+    proof.iter().fold(leaf, |node, sibling| {
+        let (left, right) = if node <= *sibling {
+            (node, *sibling)
+        } else {
+            (*sibling, node)
+        };
+        H256(keccak_256(&[left.as_bytes(), right.as_bytes()].concat()))
+    })
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use crate::weights::WeightInfo;
+    use common::{eth, AccountIdOf, AssetIdOf, AssetManager};
+    use frame_support::pallet_prelude::*;
+    use frame_support::{transactional, PalletId};
+    use frame_system::pallet_prelude::*;
+    use secp256k1::util::SIGNATURE_SIZE;
+    use secp256k1::{RecoveryId, Signature};
+    use sp_runtime::traits::AccountIdConversion;
+    use sp_runtime::ArithmeticError;
+    use sp_std::prelude::*;
+
+    const PALLET_ID: PalletId = PalletId(*b"airdrops");
+
+    /// The current storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+    #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config + common::Config {
+        #[allow(deprecated)]
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Origin allowed to create campaigns
+        type ManagerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Account funding new campaigns and receiving unclaimed funds back
+        type TreasuryAccount: Get<Self::AccountId>;
+
+        /// Maximum number of nodes in a Merkle proof
+        #[pallet::constant]
+        type MaxProofLength: Get<u32>;
+
+        /// Maximum number of claimed allocations removed from storage per call
+        #[pallet::constant]
+        type ClaimedRemovalLimit: Get<u32>;
+
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    pub type CampaignInfoOf<T> = CampaignInfo<AssetIdOf<T>, BlockNumberFor<T>>;
+
+    /// Storage key of a [`Claimed`] entry always fits into this length
+    pub type ClaimedCursorOf = BoundedVec<u8, ConstU32<128>>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Campaign created [campaign_id, asset_id, total_amount, deadline]
+        CampaignCreated(CampaignId, AssetIdOf<T>, Balance, BlockNumberFor<T>),
+        /// Allocation claimed [who, campaign_id, amount]
+        Claimed(AccountIdOf<T>, CampaignId, Balance),
+        /// Campaign closed and unclaimed funds returned to treasury [campaign_id, remaining]
+        CampaignClosed(CampaignId, Balance),
+    }
+
+    #[pallet::storage]
+    #[pallet::getter(fn next_campaign_id)]
+    pub type NextCampaignId<T: Config> = StorageValue<_, CampaignId, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn campaigns)]
+    pub type Campaigns<T: Config> =
+        StorageMap<_, Identity, CampaignId, CampaignInfoOf<T>, OptionQuery>;
+
+    /// Allocations already claimed, per campaign
+    #[pallet::storage]
+    #[pallet::getter(fn claimed)]
+    pub type Claimed<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        CampaignId,
+        Blake2_128Concat,
+        Beneficiary<AccountIdOf<T>>,
+        bool,
+        ValueQuery,
+    >;
+
+    /// Closed campaigns whose [`Claimed`] entries are not removed yet, with the cursor to
+    /// continue from (empty if removal should start over)
+    #[pallet::storage]
+    #[pallet::getter(fn claimed_cleanup_cursor)]
+    pub type ClaimedCleanupCursor<T: Config> =
+        StorageMap<_, Identity, CampaignId, ClaimedCursorOf, OptionQuery>;
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Campaign amount must be greater than zero
+        InvalidAmount,
+        /// Campaign deadline must be in the future
+        InvalidDeadline,
+        /// Campaign does not exist
+        CampaignDoesNotExist,
+        /// Campaign deadline has passed
+        CampaignExpired,
+        /// Campaign deadline has not passed yet
+        CampaignNotExpired,
+        /// Allocation has already been claimed
+        AlreadyClaimed,
+        /// Merkle proof does not match the campaign root
+        InvalidProof,
+        /// Campaign does not hold enough funds for the claim
+        InsufficientCampaignFunds,
+        /// Signature is malformed
+        SignatureInvalid,
+        /// Signer could not be recovered from the signature
+        SignatureVerificationFailed,
+        /// Campaign has no claimed allocations left to remove
+        NothingToClear,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Create an airdrop campaign funded from the treasury.
+        #[transactional]
+        #[pallet::call_index(0)]
+        #[pallet::weight(<T as Config>::WeightInfo::create_campaign())]
+        pub fn create_campaign(
+            origin: OriginFor<T>,
+            merkle_root: H256,
+            asset_id: AssetIdOf<T>,
+            total_amount: Balance,
+            deadline: BlockNumberFor<T>,
+        ) -> DispatchResultWithPostInfo {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            ensure!(total_amount > 0, Error::<T>::InvalidAmount);
+            ensure!(
+                deadline > frame_system::Pallet::<T>::block_number(),
+                Error::<T>::InvalidDeadline
+            );
+
+            T::AssetManager::transfer_from(
+                &asset_id,
+                &T::TreasuryAccount::get(),
+                &Self::account_id(),
+                total_amount,
+            )?;
+
+            let campaign_id = NextCampaignId::<T>::try_mutate(|next| {
+                let id = *next;
+                *next = next.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+                Ok::<_, DispatchError>(id)
+            })?;
+            Campaigns::<T>::insert(
+                campaign_id,
+                CampaignInfo {
+                    merkle_root,
+                    asset_id,
+                    total_amount,
+                    claimed_amount: 0,
+                    deadline,
+                },
+            );
+
+            Self::deposit_event(Event::CampaignCreated(
+                campaign_id,
+                asset_id,
+                total_amount,
+                deadline,
+            ));
+            Ok(().into())
+        }
+
+        /// Claim an allocation with a Merkle proof.
+        /// If `signature` is set, the allocation of the Ethereum address that signed the caller's
+        /// account id is claimed, otherwise the allocation of the caller's account.
+        #[transactional]
+        #[pallet::call_index(1)]
+        #[pallet::weight(<T as Config>::WeightInfo::claim(proof.len() as u32))]
+        pub fn claim(
+            origin: OriginFor<T>,
+            campaign_id: CampaignId,
+            amount: Balance,
+            proof: BoundedVec<H256, T::MaxProofLength>,
+            signature: Option<Vec<u8>>,
+        ) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+            let beneficiary = match signature {
+                Some(signature) => {
+                    Beneficiary::Ethereum(Self::recover_eth_address(&account_id, &signature)?)
+                }
+                None => Beneficiary::Account(account_id.clone()),
+            };
+
+            let mut campaign =
+                Campaigns::<T>::get(campaign_id).ok_or(Error::<T>::CampaignDoesNotExist)?;
+            Self::check_claim(campaign_id, &campaign, &beneficiary, amount, &proof)?;
+
+            T::AssetManager::transfer_from(
+                &campaign.asset_id,
+                &Self::account_id(),
+                &account_id,
+                amount,
+            )?;
+
+            campaign.claimed_amount = campaign.claimed_amount.saturating_add(amount);
+            Campaigns::<T>::insert(campaign_id, campaign);
+            Claimed::<T>::insert(campaign_id, beneficiary, true);
+
+            Self::deposit_event(Event::Claimed(account_id, campaign_id, amount));
+            Ok(().into())
+        }
+
+        /// Close an expired campaign and return its unclaimed funds to the treasury.
+        /// Claimed allocations of the campaign are removed up to [`Config::ClaimedRemovalLimit`],
+        /// the rest is removed with [`Pallet::clear_claimed`].
+        #[transactional]
+        #[pallet::call_index(2)]
+        #[pallet::weight(<T as Config>::WeightInfo::close_campaign(T::ClaimedRemovalLimit::get()))]
+        pub fn close_campaign(
+            origin: OriginFor<T>,
+            campaign_id: CampaignId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+            let campaign =
+                Campaigns::<T>::get(campaign_id).ok_or(Error::<T>::CampaignDoesNotExist)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() > campaign.deadline,
+                Error::<T>::CampaignNotExpired
+            );
+
+            let remaining = campaign
+                .total_amount
+                .saturating_sub(campaign.claimed_amount);
+            if remaining > 0 {
+                T::AssetManager::transfer_from(
+                    &campaign.asset_id,
+                    &Self::account_id(),
+                    &T::TreasuryAccount::get(),
+                    remaining,
+                )?;
+            }
+            Campaigns::<T>::remove(campaign_id);
+            let removed = Self::remove_claimed(campaign_id, None);
+
+            Self::deposit_event(Event::CampaignClosed(campaign_id, remaining));
+            Ok(Some(<T as Config>::WeightInfo::close_campaign(removed)).into())
+        }
+
+        /// Continue removing claimed allocations of a closed campaign.
+        #[pallet::call_index(3)]
+        #[pallet::weight(<T as Config>::WeightInfo::clear_claimed(T::ClaimedRemovalLimit::get()))]
+        pub fn clear_claimed(
+            origin: OriginFor<T>,
+            campaign_id: CampaignId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+            let cursor =
+                ClaimedCleanupCursor::<T>::get(campaign_id).ok_or(Error::<T>::NothingToClear)?;
+            let removed = Self::remove_claimed(campaign_id, Some(cursor));
+            Ok(Some(<T as Config>::WeightInfo::clear_claimed(removed)).into())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// The account ID of pallet
+        pub fn account_id() -> T::AccountId {
+            PALLET_ID.into_account_truncating()
+        }
+
+        /// Returns whether `beneficiary` can currently claim `amount` from the campaign.
+        pub fn is_eligible(
+            campaign_id: CampaignId,
+            beneficiary: &Beneficiary<AccountIdOf<T>>,
+            amount: Balance,
+            proof: &[H256],
+        ) -> bool {
+            Campaigns::<T>::get(campaign_id)
+                .map(|campaign| {
+                    Self::check_claim(campaign_id, &campaign, beneficiary, amount, proof).is_ok()
+                })
+                .unwrap_or(false)
+        }
+
+        /// Removes up to [`Config::ClaimedRemovalLimit`] claimed allocations of the campaign
+        /// and stores the cursor if some are left. Returns the number of removed entries.
+        fn remove_claimed(campaign_id: CampaignId, cursor: Option<ClaimedCursorOf>) -> u32 {
+            let cursor = cursor.filter(|cursor| !cursor.is_empty());
+            let result = Claimed::<T>::clear_prefix(
+                campaign_id,
+                T::ClaimedRemovalLimit::get(),
+                cursor.as_ref().map(|cursor| cursor.as_slice()),
+            );
+            match result.maybe_cursor {
+                Some(cursor) => ClaimedCleanupCursor::<T>::insert(
+                    campaign_id,
+                    ClaimedCursorOf::try_from(cursor).unwrap_or_default(),
+                ),
+                None => ClaimedCleanupCursor::<T>::remove(campaign_id),
+            }
+            result.unique
+        }
+
+        fn check_claim(
+            campaign_id: CampaignId,
+            campaign: &CampaignInfoOf<T>,
+            beneficiary: &Beneficiary<AccountIdOf<T>>,
+            amount: Balance,
+            proof: &[H256],
+        ) -> DispatchResult {
+            ensure!(
+                frame_system::Pallet::<T>::block_number() <= campaign.deadline,
+                Error::<T>::CampaignExpired
+            );
+            ensure!(
+                !Claimed::<T>::get(campaign_id, beneficiary),
+                Error::<T>::AlreadyClaimed
+            );
+            ensure!(
+                compute_root(leaf_hash(beneficiary, amount), proof) == campaign.merkle_root,
+                Error::<T>::InvalidProof
+            );
+            ensure!(
+                campaign.claimed_amount.saturating_add(amount) <= campaign.total_amount,
+                Error::<T>::InsufficientCampaignFunds
+            );
+            Ok(())
+        }
+
+        /// Recovers the Ethereum address that signed `account_id`, the same way `rewards::claim` does.
+        fn recover_eth_address(
+            account_id: &AccountIdOf<T>,
+            signature: &[u8],
+        ) -> Result<EthAddress, DispatchError> {
+            ensure!(
+                signature.len() == SIGNATURE_SIZE + 1,
+                Error::<T>::SignatureInvalid
+            );
+            let recovery_id = if signature[SIGNATURE_SIZE] >= 27 {
+                signature[SIGNATURE_SIZE] - 27
+            } else {
+                signature[SIGNATURE_SIZE]
+            };
+            let recovery_id = RecoveryId::parse(recovery_id)
+                .map_err(|_| Error::<T>::SignatureVerificationFailed)?;
+            let signature = Signature::parse_standard_slice(&signature[..SIGNATURE_SIZE])
+                .map_err(|_| Error::<T>::SignatureInvalid)?;
+            let message = eth::prepare_message(&account_id.encode());
+            let public_key = secp256k1::recover(&message, &signature, &recovery_id)
+                .map_err(|_| Error::<T>::SignatureVerificationFailed)?;
+            Ok(eth::public_key_to_eth_address(&public_key))
+        }
+    }
+}
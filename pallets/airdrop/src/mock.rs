@@ -0,0 +1,116 @@
+#![allow(deprecated, dead_code, unused_imports)]
+
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate as airdrop;
+
+use common::mock::ExistentialDeposits;
+use common::Amount;
+use common::{
+    mock_airdrop_config, mock_assets_config, mock_common_config, mock_currencies_config,
+    mock_frame_system_config, mock_pallet_balances_config, mock_permissions_config,
+    mock_technical_config, mock_tokens_config, AssetId32, DEXId, PredefinedAssetId, XOR, XST,
+};
+use currencies::BasicCurrencyAdapter;
+use frame_support::parameter_types;
+use frame_system::offchain::{CreateBare, CreateTransactionBase};
+use sp_runtime::MultiSignature;
+use sp_runtime::{
+    traits::{IdentifyAccount, Verify},
+    BuildStorage,
+};
+
+type AccountId = <<Signature as Verify>::Signer as IdentifyAccount>::AccountId;
+type AssetId = AssetId32<PredefinedAssetId>;
+type Balance = u128;
+type Block = frame_system::mocking::MockBlock<TestRuntime>;
+type BlockNumber = u64;
+type Signature = MultiSignature;
+type TechAccountId = common::TechAccountId<AccountId, TechAssetId, DEXId>;
+type TechAssetId = common::TechAssetId<PredefinedAssetId>;
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<TestRuntime>;
+
+frame_support::construct_runtime!(
+    pub enum TestRuntime where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
+        Assets: assets::{Pallet, Call, Storage, Config<T>, Event<T>},
+        Balances: pallet_balances::{Pallet, Call, Storage, Event<T>},
+        Technical: technical::{Pallet, Call, Config<T>, Event<T>},
+        Tokens: tokens::{Pallet, Call, Config<T>, Storage, Event<T>},
+        Permissions: permissions::{Pallet, Call, Config<T>, Storage, Event<T>},
+        Airdrop: airdrop::{Pallet, Call, Storage, Event<T>},
+    }
+);
+
+impl<LocalCall> CreateTransactionBase<LocalCall> for TestRuntime
+where
+    RuntimeCall: From<LocalCall>,
+{
+    type Extrinsic = UncheckedExtrinsic;
+    type RuntimeCall = RuntimeCall;
+}
+
+impl<LocalCall> CreateBare<LocalCall> for TestRuntime
+where
+    RuntimeCall: From<LocalCall>,
+{
+    fn create_bare(call: RuntimeCall) -> Self::Extrinsic {
+        UncheckedExtrinsic::new_bare(call)
+    }
+}
+
+parameter_types! {
+    pub const GetBaseAssetId: AssetId = XOR;
+    pub const GetBuyBackAssetId: AssetId = XST;
+}
+
+mock_airdrop_config!(TestRuntime);
+mock_assets_config!(TestRuntime);
+mock_common_config!(TestRuntime);
+mock_currencies_config!(TestRuntime);
+mock_frame_system_config!(TestRuntime);
+mock_pallet_balances_config!(TestRuntime);
+mock_permissions_config!(TestRuntime);
+mock_technical_config!(TestRuntime);
+mock_tokens_config!(TestRuntime);
+
+// Builds testing externalities
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut ext: sp_io::TestExternalities = SystemConfig::default().build_storage().unwrap().into();
+    ext.execute_with(|| {
+        System::set_block_number(1); // No events in zero block
+    });
+    ext
+}
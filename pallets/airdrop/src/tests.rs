@@ -0,0 +1,505 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::*;
+
+use crate::mock::{
+    new_test_ext, AirdropTreasuryAccount, RuntimeEvent, RuntimeOrigin, System, TestRuntime,
+};
+use codec::Encode;
+use common::{eth, AssetInfoProvider, XOR};
+use frame_support::{assert_noop, assert_ok, error::BadOrigin, traits::Get, BoundedVec};
+use hex_literal::hex;
+use sp_runtime::AccountId32;
+
+type AirdropPallet = Pallet<TestRuntime>;
+type Assets = assets::Pallet<TestRuntime>;
+
+const DEADLINE: u64 = 100;
+const CAMPAIGN_AMOUNT: Balance = 1_000;
+
+/// Predefined AccountId `Alice`
+pub fn alice_account_id() -> AccountId32 {
+    AccountId32::from(hex!(
+        "d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d"
+    ))
+}
+
+/// Predefined AccountId `Bob`
+pub fn bob_account_id() -> AccountId32 {
+    AccountId32::from(hex!(
+        "8eaf04151687736326c9fea17e25fc5287613693c912909cb226aa4794f26a48"
+    ))
+}
+
+fn eth_secret_key() -> secp256k1::SecretKey {
+    secp256k1::SecretKey::parse(&[1; 32]).unwrap()
+}
+
+fn eth_address() -> EthAddress {
+    eth::public_key_to_eth_address(&secp256k1::PublicKey::from_secret_key(&eth_secret_key()))
+}
+
+/// Signs `account_id` with the test Ethereum key the way wallets sign personal messages.
+fn eth_signature(account_id: &AccountId32) -> Vec<u8> {
+    let (signature, recovery_id) = secp256k1::sign(
+        &eth::prepare_message(&account_id.encode()),
+        &eth_secret_key(),
+    );
+    let mut signature = signature.serialize().to_vec();
+    signature.push(recovery_id.serialize() + 27);
+    signature
+}
+
+/// Merkle tree over Alice (100), Bob (200), the test Ethereum address (300) and a filler leaf.
+struct Tree {
+    leaves: [H256; 4],
+}
+
+impl Tree {
+    fn new() -> Self {
+        Tree {
+            leaves: [
+                leaf_hash(&Beneficiary::Account(alice_account_id()), 100),
+                leaf_hash(&Beneficiary::Account(bob_account_id()), 200),
+                leaf_hash::<AccountId32>(&Beneficiary::Ethereum(eth_address()), 300),
+                H256::repeat_byte(7),
+            ],
+        }
+    }
+
+    fn hash_pair(a: H256, b: H256) -> H256 {
+        compute_root(a, &[b])
+    }
+
+    fn root(&self) -> H256 {
+        Self::hash_pair(
+            Self::hash_pair(self.leaves[0], self.leaves[1]),
+            Self::hash_pair(self.leaves[2], self.leaves[3]),
+        )
+    }
+
+    fn proof(&self, index: usize) -> BoundedVec<H256, <TestRuntime as Config>::MaxProofLength> {
+        let sibling = self.leaves[index ^ 1];
+        let other_pair = if index < 2 {
+            Self::hash_pair(self.leaves[2], self.leaves[3])
+        } else {
+            Self::hash_pair(self.leaves[0], self.leaves[1])
+        };
+        BoundedVec::truncate_from(vec![sibling, other_pair])
+    }
+}
+
+fn create_campaign() -> CampaignId {
+    assert_ok!(Assets::update_balance(
+        RuntimeOrigin::root(),
+        AirdropTreasuryAccount::get(),
+        XOR,
+        CAMPAIGN_AMOUNT as i128,
+    ));
+    let campaign_id = NextCampaignId::<TestRuntime>::get();
+    assert_ok!(AirdropPallet::create_campaign(
+        RuntimeOrigin::root(),
+        Tree::new().root(),
+        XOR,
+        CAMPAIGN_AMOUNT,
+        DEADLINE,
+    ));
+    campaign_id
+}
+
+#[test]
+fn create_campaign_works() {
+    new_test_ext().execute_with(|| {
+        let campaign_id = create_campaign();
+
+        assert_eq!(
+            AirdropPallet::campaigns(campaign_id),
+            Some(CampaignInfo {
+                merkle_root: Tree::new().root(),
+                asset_id: XOR,
+                total_amount: CAMPAIGN_AMOUNT,
+                claimed_amount: 0,
+                deadline: DEADLINE,
+            })
+        );
+        assert_eq!(AirdropPallet::next_campaign_id(), campaign_id + 1);
+        assert_eq!(
+            Assets::free_balance(&XOR, &AirdropTreasuryAccount::get()).unwrap(),
+            0
+        );
+        assert_eq!(
+            Assets::free_balance(&XOR, &AirdropPallet::account_id()).unwrap(),
+            CAMPAIGN_AMOUNT
+        );
+        System::assert_last_event(RuntimeEvent::Airdrop(Event::CampaignCreated(
+            campaign_id,
+            XOR,
+            CAMPAIGN_AMOUNT,
+            DEADLINE,
+        )));
+    });
+}
+
+#[test]
+fn create_campaign_checks_parameters() {
+    new_test_ext().execute_with(|| {
+        let root = Tree::new().root();
+        assert_noop!(
+            AirdropPallet::create_campaign(
+                RuntimeOrigin::signed(alice_account_id()),
+                root,
+                XOR,
+                CAMPAIGN_AMOUNT,
+                DEADLINE
+            ),
+            BadOrigin
+        );
+        assert_noop!(
+            AirdropPallet::create_campaign(RuntimeOrigin::root(), root, XOR, 0, DEADLINE),
+            Error::<TestRuntime>::InvalidAmount
+        );
+        assert_noop!(
+            AirdropPallet::create_campaign(RuntimeOrigin::root(), root, XOR, CAMPAIGN_AMOUNT, 1),
+            Error::<TestRuntime>::InvalidDeadline
+        );
+    });
+}
+
+#[test]
+fn claim_by_account_works() {
+    new_test_ext().execute_with(|| {
+        let campaign_id = create_campaign();
+        let tree = Tree::new();
+
+        assert_ok!(AirdropPallet::claim(
+            RuntimeOrigin::signed(alice_account_id()),
+            campaign_id,
+            100,
+            tree.proof(0),
+            None
+        ));
+        assert_eq!(
+            Assets::free_balance(&XOR, &alice_account_id()).unwrap(),
+            100
+        );
+        assert_eq!(
+            AirdropPallet::campaigns(campaign_id)
+                .unwrap()
+                .claimed_amount,
+            100
+        );
+        assert!(AirdropPallet::claimed(
+            campaign_id,
+            Beneficiary::Account(alice_account_id())
+        ));
+        System::assert_last_event(RuntimeEvent::Airdrop(Event::Claimed(
+            alice_account_id(),
+            campaign_id,
+            100,
+        )));
+
+        assert_noop!(
+            AirdropPallet::claim(
+                RuntimeOrigin::signed(alice_account_id()),
+                campaign_id,
+                100,
+                tree.proof(0),
+                None
+            ),
+            Error::<TestRuntime>::AlreadyClaimed
+        );
+    });
+}
+
+#[test]
+fn claim_with_invalid_proof_fails() {
+    new_test_ext().execute_with(|| {
+        let campaign_id = create_campaign();
+        let tree = Tree::new();
+
+        // Wrong amount
+        assert_noop!(
+            AirdropPallet::claim(
+                RuntimeOrigin::signed(alice_account_id()),
+                campaign_id,
+                200,
+                tree.proof(0),
+                None
+            ),
+            Error::<TestRuntime>::InvalidProof
+        );
+        // Someone else's leaf
+        assert_noop!(
+            AirdropPallet::claim(
+                RuntimeOrigin::signed(alice_account_id()),
+                campaign_id,
+                200,
+                tree.proof(1),
+                None
+            ),
+            Error::<TestRuntime>::InvalidProof
+        );
+        assert_noop!(
+            AirdropPallet::claim(
+                RuntimeOrigin::signed(alice_account_id()),
+                campaign_id + 1,
+                100,
+                tree.proof(0),
+                None
+            ),
+            Error::<TestRuntime>::CampaignDoesNotExist
+        );
+    });
+}
+
+#[test]
+fn claim_with_eth_signature_works() {
+    new_test_ext().execute_with(|| {
+        let campaign_id = create_campaign();
+        let tree = Tree::new();
+
+        assert_ok!(AirdropPallet::claim(
+            RuntimeOrigin::signed(bob_account_id()),
+            campaign_id,
+            300,
+            tree.proof(2),
+            Some(eth_signature(&bob_account_id()))
+        ));
+        assert_eq!(Assets::free_balance(&XOR, &bob_account_id()).unwrap(), 300);
+        assert!(AirdropPallet::claimed(
+            campaign_id,
+            Beneficiary::Ethereum(eth_address())
+        ));
+
+        // Bob can still claim his own account allocation
+        assert_ok!(AirdropPallet::claim(
+            RuntimeOrigin::signed(bob_account_id()),
+            campaign_id,
+            200,
+            tree.proof(1),
+            None
+        ));
+        assert_eq!(Assets::free_balance(&XOR, &bob_account_id()).unwrap(), 500);
+    });
+}
+
+#[test]
+fn claim_with_foreign_signature_fails() {
+    new_test_ext().execute_with(|| {
+        let campaign_id = create_campaign();
+        let tree = Tree::new();
+
+        // Signature was made for Bob, so Alice recovers a different address
+        assert_noop!(
+            AirdropPallet::claim(
+                RuntimeOrigin::signed(alice_account_id()),
+                campaign_id,
+                300,
+                tree.proof(2),
+                Some(eth_signature(&bob_account_id()))
+            ),
+            Error::<TestRuntime>::InvalidProof
+        );
+        assert_noop!(
+            AirdropPallet::claim(
+                RuntimeOrigin::signed(alice_account_id()),
+                campaign_id,
+                300,
+                tree.proof(2),
+                Some(vec![0; 10])
+            ),
+            Error::<TestRuntime>::SignatureInvalid
+        );
+    });
+}
+
+#[test]
+fn claim_after_deadline_fails() {
+    new_test_ext().execute_with(|| {
+        let campaign_id = create_campaign();
+        System::set_block_number(DEADLINE + 1);
+
+        assert_noop!(
+            AirdropPallet::claim(
+                RuntimeOrigin::signed(alice_account_id()),
+                campaign_id,
+                100,
+                Tree::new().proof(0),
+                None
+            ),
+            Error::<TestRuntime>::CampaignExpired
+        );
+    });
+}
+
+#[test]
+fn close_campaign_returns_unclaimed_funds() {
+    new_test_ext().execute_with(|| {
+        let campaign_id = create_campaign();
+        assert_ok!(AirdropPallet::claim(
+            RuntimeOrigin::signed(alice_account_id()),
+            campaign_id,
+            100,
+            Tree::new().proof(0),
+            None
+        ));
+
+        assert_noop!(
+            AirdropPallet::close_campaign(RuntimeOrigin::signed(bob_account_id()), campaign_id),
+            Error::<TestRuntime>::CampaignNotExpired
+        );
+
+        System::set_block_number(DEADLINE + 1);
+        assert_ok!(AirdropPallet::close_campaign(
+            RuntimeOrigin::signed(bob_account_id()),
+            campaign_id
+        ));
+        assert_eq!(AirdropPallet::campaigns(campaign_id), None);
+        assert_eq!(
+            Assets::free_balance(&XOR, &AirdropTreasuryAccount::get()).unwrap(),
+            CAMPAIGN_AMOUNT - 100
+        );
+        assert_eq!(
+            Assets::free_balance(&XOR, &AirdropPallet::account_id()).unwrap(),
+            0
+        );
+        System::assert_last_event(RuntimeEvent::Airdrop(Event::CampaignClosed(
+            campaign_id,
+            CAMPAIGN_AMOUNT - 100,
+        )));
+    });
+}
+
+#[test]
+fn close_campaign_removes_claimed_allocations() {
+    let mut ext = new_test_ext();
+    let campaign_id = ext.execute_with(|| {
+        let campaign_id = create_campaign();
+        let tree = Tree::new();
+        assert_ok!(AirdropPallet::claim(
+            RuntimeOrigin::signed(alice_account_id()),
+            campaign_id,
+            100,
+            tree.proof(0),
+            None
+        ));
+        assert_ok!(AirdropPallet::claim(
+            RuntimeOrigin::signed(bob_account_id()),
+            campaign_id,
+            200,
+            tree.proof(1),
+            None
+        ));
+        assert_ok!(AirdropPallet::claim(
+            RuntimeOrigin::signed(bob_account_id()),
+            campaign_id,
+            300,
+            tree.proof(2),
+            Some(eth_signature(&bob_account_id()))
+        ));
+        assert_noop!(
+            AirdropPallet::clear_claimed(RuntimeOrigin::signed(bob_account_id()), campaign_id),
+            Error::<TestRuntime>::NothingToClear
+        );
+        campaign_id
+    });
+    // Removal limit only applies to entries committed in previous blocks
+    ext.commit_all().unwrap();
+
+    ext.execute_with(|| {
+        System::set_block_number(DEADLINE + 1);
+        assert_ok!(AirdropPallet::close_campaign(
+            RuntimeOrigin::signed(bob_account_id()),
+            campaign_id
+        ));
+        assert_eq!(Claimed::<TestRuntime>::iter_prefix(campaign_id).count(), 1);
+        assert!(AirdropPallet::claimed_cleanup_cursor(campaign_id).is_some());
+
+        assert_ok!(AirdropPallet::clear_claimed(
+            RuntimeOrigin::signed(bob_account_id()),
+            campaign_id
+        ));
+        assert_eq!(Claimed::<TestRuntime>::iter_prefix(campaign_id).count(), 0);
+        assert_eq!(AirdropPallet::claimed_cleanup_cursor(campaign_id), None);
+        assert_noop!(
+            AirdropPallet::clear_claimed(RuntimeOrigin::signed(bob_account_id()), campaign_id),
+            Error::<TestRuntime>::NothingToClear
+        );
+    });
+}
+
+#[test]
+fn is_eligible_works() {
+    new_test_ext().execute_with(|| {
+        let campaign_id = create_campaign();
+        let tree = Tree::new();
+        let alice = Beneficiary::Account(alice_account_id());
+
+        assert!(AirdropPallet::is_eligible(
+            campaign_id,
+            &alice,
+            100,
+            &tree.proof(0)
+        ));
+        assert!(AirdropPallet::is_eligible(
+            campaign_id,
+            &Beneficiary::Ethereum(eth_address()),
+            300,
+            &tree.proof(2)
+        ));
+        assert!(!AirdropPallet::is_eligible(
+            campaign_id,
+            &alice,
+            200,
+            &tree.proof(0)
+        ));
+        assert!(!AirdropPallet::is_eligible(
+            campaign_id + 1,
+            &alice,
+            100,
+            &tree.proof(0)
+        ));
+
+        assert_ok!(AirdropPallet::claim(
+            RuntimeOrigin::signed(alice_account_id()),
+            campaign_id,
+            100,
+            tree.proof(0),
+            None
+        ));
+        assert!(!AirdropPallet::is_eligible(
+            campaign_id,
+            &alice,
+            100,
+            &tree.proof(0)
+        ));
+    });
+}
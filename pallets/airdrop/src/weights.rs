@@ -0,0 +1,234 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Autogenerated weights for airdrop
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2026-10-18, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `983eb2919397`, CPU: `Intel(R) Xeon(R) CPU E3-1240 v6 @ 3.70GHz`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("local"), DB CACHE: 1024
+
+// Executed Command:
+// /usr/local/bin/framenode
+// benchmark
+// pallet
+// --chain=local
+// --steps=50
+// --repeat=20
+// --pallet=airdrop
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --header=./misc/file_header.txt
+// --template=./misc/pallet-weight-template.hbs
+// --output=./pallets/airdrop/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for airdrop.
+pub trait WeightInfo {
+	fn create_campaign() -> Weight;
+	fn claim(p: u32, ) -> Weight;
+	fn close_campaign(n: u32, ) -> Weight;
+	fn clear_claimed(n: u32, ) -> Weight;
+}
+
+/// Weights for airdrop using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: Assets AssetInfosV2 (r:1 w:0)
+	/// Proof Skipped: Assets AssetInfosV2 (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Airdrop NextCampaignId (r:1 w:1)
+	/// Proof: Airdrop NextCampaignId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Airdrop Campaigns (r:0 w:1)
+	/// Proof: Airdrop Campaigns (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	fn create_campaign() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1138`
+		//  Estimated: `6196`
+		// Minimum execution time: 98_422_000 picoseconds.
+		Weight::from_parts(101_317_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Airdrop Campaigns (r:1 w:1)
+	/// Proof: Airdrop Campaigns (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: Airdrop Claimed (r:1 w:1)
+	/// Proof: Airdrop Claimed (max_values: None, max_size: Some(70), added: 2545, mode: MaxEncodedLen)
+	/// Storage: Assets AssetInfosV2 (r:1 w:0)
+	/// Proof Skipped: Assets AssetInfosV2 (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `p` is `[0, 32]`.
+	fn claim(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1312`
+		//  Estimated: `6196`
+		// Minimum execution time: 151_804_000 picoseconds.
+		Weight::from_parts(155_216_000, 6196)
+			// Standard Error: 4_112
+			.saturating_add(Weight::from_parts(1_287_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Airdrop Campaigns (r:1 w:1)
+	/// Proof: Airdrop Campaigns (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: Assets AssetInfosV2 (r:1 w:0)
+	/// Proof Skipped: Assets AssetInfosV2 (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Airdrop Claimed (r:0 w:200)
+	/// Proof: Airdrop Claimed (max_values: None, max_size: Some(70), added: 2545, mode: MaxEncodedLen)
+	/// Storage: Airdrop ClaimedCleanupCursor (r:0 w:1)
+	/// Proof: Airdrop ClaimedCleanupCursor (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 200]`.
+	fn close_campaign(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1262 + n * (87 ±0)`
+		//  Estimated: `6196 + n * (2545 ±0)`
+		// Minimum execution time: 90_184_000 picoseconds.
+		Weight::from_parts(93_218_000, 6196)
+			// Standard Error: 3_874
+			.saturating_add(Weight::from_parts(1_104_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2545).saturating_mul(n.into()))
+	}
+	/// Storage: Airdrop ClaimedCleanupCursor (r:1 w:1)
+	/// Proof: Airdrop ClaimedCleanupCursor (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: Airdrop Claimed (r:0 w:200)
+	/// Proof: Airdrop Claimed (max_values: None, max_size: Some(70), added: 2545, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 200]`.
+	fn clear_claimed(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `152 + n * (87 ±0)`
+		//  Estimated: `3599 + n * (2545 ±0)`
+		// Minimum execution time: 15_512_000 picoseconds.
+		Weight::from_parts(14_862_000, 3599)
+			// Standard Error: 2_961
+			.saturating_add(Weight::from_parts(1_096_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2545).saturating_mul(n.into()))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: Assets AssetInfosV2 (r:1 w:0)
+	/// Proof Skipped: Assets AssetInfosV2 (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Airdrop NextCampaignId (r:1 w:1)
+	/// Proof: Airdrop NextCampaignId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Airdrop Campaigns (r:0 w:1)
+	/// Proof: Airdrop Campaigns (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	fn create_campaign() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1138`
+		//  Estimated: `6196`
+		// Minimum execution time: 98_422_000 picoseconds.
+		Weight::from_parts(101_317_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Airdrop Campaigns (r:1 w:1)
+	/// Proof: Airdrop Campaigns (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: Airdrop Claimed (r:1 w:1)
+	/// Proof: Airdrop Claimed (max_values: None, max_size: Some(70), added: 2545, mode: MaxEncodedLen)
+	/// Storage: Assets AssetInfosV2 (r:1 w:0)
+	/// Proof Skipped: Assets AssetInfosV2 (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `p` is `[0, 32]`.
+	fn claim(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1312`
+		//  Estimated: `6196`
+		// Minimum execution time: 151_804_000 picoseconds.
+		Weight::from_parts(155_216_000, 6196)
+			// Standard Error: 4_112
+			.saturating_add(Weight::from_parts(1_287_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Airdrop Campaigns (r:1 w:1)
+	/// Proof: Airdrop Campaigns (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: Assets AssetInfosV2 (r:1 w:0)
+	/// Proof Skipped: Assets AssetInfosV2 (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Airdrop Claimed (r:0 w:200)
+	/// Proof: Airdrop Claimed (max_values: None, max_size: Some(70), added: 2545, mode: MaxEncodedLen)
+	/// Storage: Airdrop ClaimedCleanupCursor (r:0 w:1)
+	/// Proof: Airdrop ClaimedCleanupCursor (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 200]`.
+	fn close_campaign(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1262 + n * (87 ±0)`
+		//  Estimated: `6196 + n * (2545 ±0)`
+		// Minimum execution time: 90_184_000 picoseconds.
+		Weight::from_parts(93_218_000, 6196)
+			// Standard Error: 3_874
+			.saturating_add(Weight::from_parts(1_104_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2545).saturating_mul(n.into()))
+	}
+	/// Storage: Airdrop ClaimedCleanupCursor (r:1 w:1)
+	/// Proof: Airdrop ClaimedCleanupCursor (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: Airdrop Claimed (r:0 w:200)
+	/// Proof: Airdrop Claimed (max_values: None, max_size: Some(70), added: 2545, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 200]`.
+	fn clear_claimed(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `152 + n * (87 ±0)`
+		//  Estimated: `3599 + n * (2545 ±0)`
+		// Minimum execution time: 15_512_000 picoseconds.
+		Weight::from_parts(14_862_000, 3599)
+			// Standard Error: 2_961
+			.saturating_add(Weight::from_parts(1_096_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2545).saturating_mul(n.into()))
+	}
+}
@@ -0,0 +1,167 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use codec::Codec;
+
+use jsonrpsee::{core::RpcResult as Result, proc_macros::rpc, types::ErrorObjectOwned};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::{Block as BlockT, MaybeDisplay, MaybeFromStr};
+
+use std::sync::Arc;
+
+fn runtime_error_into_rpc_error(error: impl core::fmt::Debug) -> ErrorObjectOwned {
+    ErrorObjectOwned::owned(1, "Runtime error", Some(format!("{error:?}")))
+}
+
+// Runtime API imports.
+pub use apollo_platform_runtime_api::ApolloAPI as ApolloRuntimeAPI;
+use apollo_platform_runtime_api::{BorrowingPositionInfo, LendingPositionInfo, PoolInfo};
+
+#[rpc(server)]
+pub trait ApolloAPI<
+    BlockHash,
+    AccountId,
+    AssetId,
+    VecPoolInfo,
+    OptionPoolInfo,
+    VecLendingPositionInfo,
+    VecBorrowingPositionInfo,
+>
+{
+    #[method(name = "apollo_pools")]
+    fn pools(&self, at: Option<BlockHash>) -> Result<VecPoolInfo>;
+
+    #[method(name = "apollo_pool")]
+    fn pool(&self, asset_id: AssetId, at: Option<BlockHash>) -> Result<OptionPoolInfo>;
+
+    #[method(name = "apollo_userLendingPositions")]
+    fn user_lending_positions(
+        &self,
+        account_id: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<VecLendingPositionInfo>;
+
+    #[method(name = "apollo_userBorrowingPositions")]
+    fn user_borrowing_positions(
+        &self,
+        account_id: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<VecBorrowingPositionInfo>;
+}
+
+pub struct ApolloClient<C, B> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> ApolloClient<C, B> {
+    /// Construct default `ApolloClient`.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block, AccountId, AssetId, Balance>
+    ApolloAPIServer<
+        <Block as BlockT>::Hash,
+        AccountId,
+        AssetId,
+        Vec<PoolInfo<AssetId, Balance>>,
+        Option<PoolInfo<AssetId, Balance>>,
+        Vec<LendingPositionInfo<AssetId, Balance>>,
+        Vec<BorrowingPositionInfo<AssetId, Balance>>,
+    > for ApolloClient<C, Block>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: ApolloRuntimeAPI<Block, AccountId, AssetId, Balance>,
+    AccountId: Codec,
+    AssetId: Codec,
+    Balance: Codec + MaybeFromStr + MaybeDisplay,
+{
+    fn pools(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<PoolInfo<AssetId, Balance>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        );
+        api.pools(at).map_err(|e| runtime_error_into_rpc_error(e))
+    }
+
+    fn pool(
+        &self,
+        asset_id: AssetId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Option<PoolInfo<AssetId, Balance>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        );
+        api.pool(at, asset_id)
+            .map_err(|e| runtime_error_into_rpc_error(e))
+    }
+
+    fn user_lending_positions(
+        &self,
+        account_id: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<LendingPositionInfo<AssetId, Balance>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        );
+        api.user_lending_positions(at, account_id)
+            .map_err(|e| runtime_error_into_rpc_error(e))
+    }
+
+    fn user_borrowing_positions(
+        &self,
+        account_id: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<BorrowingPositionInfo<AssetId, Balance>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        );
+        api.user_borrowing_positions(at, account_id)
+            .map_err(|e| runtime_error_into_rpc_error(e))
+    }
+}
@@ -891,4 +891,105 @@ benchmarks! {
             XOR.into(),
         ).into());
     }
+
+    liquidate_position {
+        let caller = pallet::AuthorityAccount::<T>::get();
+        let alice = alice::<T>();
+        let bob = bob::<T>();
+        let asset_id_xor = XOR;
+        let asset_id_dot = BENCHMARK_COLLATERAL_ASSET;
+        let loan_to_value = balance!(1);
+        let liquidation_threshold = balance!(0.1);
+        let optimal_utilization_rate = balance!(1);
+        let base_rate = balance!(1);
+        let slope_rate_1 = balance!(1);
+        let slope_rate_2 = balance!(1);
+        let reserve_factor = balance!(0.1);
+
+        let lending_amount_alice = balance!(300);
+        let lending_amount_bob = balance!(200000);
+        let borrow_amount = balance!(100);
+
+        setup_benchmark::<T>()?;
+
+        let xor_owner: T::AccountId = <T as liquidity_proxy::Config>::AssetInfoProvider::get_asset_owner(&XOR.into()).unwrap();
+
+        T::AssetManager::mint(
+            RawOrigin::Signed(xor_owner).into(),
+            XOR.into(),
+            bob.clone(),
+            balance!(300000)
+        ).unwrap();
+
+        ApolloPlatform::<T>::add_pool(
+            RawOrigin::Signed(caller.clone()).into(),
+            asset_id_xor.into(),
+            loan_to_value,
+            liquidation_threshold,
+            optimal_utilization_rate,
+            base_rate,
+            slope_rate_1,
+            slope_rate_2,
+            reserve_factor,
+        ).unwrap();
+
+        ApolloPlatform::<T>::add_pool(
+            RawOrigin::Signed(caller.clone()).into(),
+            asset_id_dot.into(),
+            loan_to_value,
+            liquidation_threshold,
+            optimal_utilization_rate,
+            base_rate,
+            slope_rate_1,
+            slope_rate_2,
+            reserve_factor,
+        ).unwrap();
+
+        ApolloPlatform::<T>::lend(
+            RawOrigin::Signed(alice.clone()).into(),
+            BENCHMARK_COLLATERAL_ASSET.into(),
+            lending_amount_alice
+        ).unwrap();
+
+        ApolloPlatform::<T>::lend(
+            RawOrigin::Signed(bob.clone()).into(),
+            XOR.into(),
+            lending_amount_bob
+        ).unwrap();
+
+        ApolloPlatform::<T>::borrow(
+            RawOrigin::Signed(alice.clone()).into(),
+            BENCHMARK_COLLATERAL_ASSET.into(),
+            XOR.into(),
+            borrow_amount,
+            loan_to_value
+        ).unwrap();
+    }: {
+        ApolloPlatform::<T>::liquidate_position(
+            RawOrigin::Signed(bob.clone()).into(),
+            alice.clone(),
+            BENCHMARK_COLLATERAL_ASSET.into(),
+            XOR.into(),
+            borrow_amount
+        ).unwrap()
+    } verify {
+        let user_infos = pallet::UserBorrowingInfo::<T>::get(XOR.into(), alice).unwrap();
+        let user_info = user_infos.get(&BENCHMARK_COLLATERAL_ASSET.into()).unwrap();
+        assert!(user_info.borrowing_amount < borrow_amount);
+    }
+
+    change_liquidation_parameters {
+        let caller = pallet::AuthorityAccount::<T>::get();
+    }: {
+        ApolloPlatform::<T>::change_liquidation_parameters(
+            RawOrigin::Signed(caller.clone()).into(),
+            balance!(0.4),
+            balance!(0.05),
+            balance!(0.8)
+        ).unwrap()
+    } verify {
+        assert_last_event::<T>(
+            Event::ChangedLiquidationParameters(caller, balance!(0.4), balance!(0.05), balance!(0.8)).into()
+        );
+    }
 }
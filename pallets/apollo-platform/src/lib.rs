@@ -71,16 +71,30 @@ pub mod pallet {
     use frame_support::sp_runtime::traits::AccountIdConversion;
     use frame_support::traits::StorageVersion;
     use frame_support::transactional;
-    use frame_support::PalletId;
+    use frame_support::{BoundedBTreeSet, PalletId};
     use frame_system::offchain::{CreateBare, SubmitTransaction};
     use frame_system::pallet_prelude::*;
     use frame_system::RawOrigin;
     use hex_literal::hex;
-    use sp_runtime::traits::{Saturating, UniqueSaturatedInto, Zero};
+    use sp_runtime::offchain::storage::StorageValueRef;
+    use sp_runtime::traits::{One, Saturating, UniqueSaturatedInto, Zero};
     use sp_std::collections::btree_map::BTreeMap;
+    use sp_std::collections::btree_set::BTreeSet;
     use sp_std::vec::Vec;
 
     const PALLET_ID: PalletId = PalletId(*b"apollolb");
+    /// Number of blocks in a year with 6 seconds per block
+    const BLOCKS_PER_YEAR: Balance = balance!(5256000);
+    /// Offchain storage key of the state seen by the last offchain worker run
+    const LIQUIDATION_SNAPSHOT_KEY: &[u8] = b"apollo-platform::liquidation-snapshot";
+    /// Max number of borrowing positions recorded as updated in one block,
+    /// all positions are checked by the offchain worker once it is reached
+    const MAX_UPDATED_BORROWING_POSITIONS: u32 = 1024;
+    /// Relative price move after which the offchain worker checks all positions of the asset
+    const LIQUIDATION_PRICE_MOVE_THRESHOLD: Balance = balance!(0.01);
+    /// Positions with the health factor within this margin above the backstop health factor are
+    /// checked on every offchain worker run, as smaller price moves of both of their assets are not tracked
+    const WATCHED_HEALTH_FACTOR_MARGIN: Balance = balance!(0.03);
 
     #[pallet::config]
     pub trait Config:
@@ -115,7 +129,7 @@ pub mod pallet {
     pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 
     /// The current storage version.
-    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -154,6 +168,30 @@ pub mod pallet {
     pub type UserTotalCollateral<T: Config> =
         StorageDoubleMap<_, Identity, AccountIdOf<T>, Identity, AssetIdOf<T>, Balance, OptionQuery>;
 
+    /// Collateral asset -> (Borrowed asset, AccountId)
+    #[pallet::storage]
+    #[pallet::getter(fn borrowers_by_collateral)]
+    pub type BorrowersByCollateral<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        AssetIdOf<T>,
+        Identity,
+        (AssetIdOf<T>, AccountIdOf<T>),
+        (),
+        OptionQuery,
+    >;
+
+    /// BlockNumber -> (Borrowed asset, AccountId) of positions which health could decrease in the block
+    #[pallet::storage]
+    #[pallet::getter(fn updated_borrowing_positions)]
+    pub type UpdatedBorrowingPositions<T: Config> = StorageMap<
+        _,
+        Identity,
+        BlockNumberFor<T>,
+        BoundedBTreeSet<(AssetIdOf<T>, AccountIdOf<T>), ConstU32<MAX_UPDATED_BORROWING_POSITIONS>>,
+        ValueQuery,
+    >;
+
     #[pallet::storage]
     #[pallet::getter(fn pool_info)]
     pub type PoolData<T: Config> = StorageMap<_, Identity, AssetIdOf<T>, PoolInfo, OptionQuery>;
@@ -252,6 +290,43 @@ pub mod pallet {
     pub type BorrowingRewardsPerBlock<T: Config> =
         StorageValue<_, Balance, ValueQuery, FixedBorrowingRewardsPerBlock<T>>;
 
+    #[pallet::type_value]
+    pub fn DefaultCloseFactor<T: Config>() -> Balance {
+        balance!(0.5)
+    }
+
+    /// Maximum share of the borrowed amount which can be repaid by a single liquidation
+    #[pallet::storage]
+    #[pallet::getter(fn close_factor)]
+    pub type CloseFactor<T: Config> = StorageValue<_, Balance, ValueQuery, DefaultCloseFactor<T>>;
+
+    #[pallet::type_value]
+    pub fn DefaultMaxLiquidationBonus<T: Config>() -> Balance {
+        balance!(0.1)
+    }
+
+    /// Maximum discount on collateral received by liquidators
+    #[pallet::storage]
+    #[pallet::getter(fn max_liquidation_bonus)]
+    pub type MaxLiquidationBonus<T: Config> =
+        StorageValue<_, Balance, ValueQuery, DefaultMaxLiquidationBonus<T>>;
+
+    #[pallet::type_value]
+    pub fn DefaultBackstopHealthFactor<T: Config>() -> Balance {
+        balance!(0.9)
+    }
+
+    /// Health factor below which the offchain worker liquidates whole positions
+    #[pallet::storage]
+    #[pallet::getter(fn backstop_health_factor)]
+    pub type BackstopHealthFactor<T: Config> =
+        StorageValue<_, Balance, ValueQuery, DefaultBackstopHealthFactor<T>>;
+
+    /// Borrowed asset -> Debt written off by liquidations of borrowers without collateral left
+    #[pallet::storage]
+    #[pallet::getter(fn bad_debt)]
+    pub type BadDebt<T: Config> = StorageMap<_, Identity, AssetIdOf<T>, Balance, ValueQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub (super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -283,6 +358,17 @@ pub mod pallet {
         PoolInfoEdited(AccountIdOf<T>, AssetIdOf<T>),
         /// Collateral added [who, collateral_asset, collateral_amount, borrow_asset]
         CollateralAdded(AccountIdOf<T>, AssetIdOf<T>, Balance, AssetIdOf<T>),
+        /// Position liquidated [liquidator, who, borrow_asset, repaid_amount, collateral_asset, seized_collateral]
+        PositionLiquidated(
+            AccountIdOf<T>,
+            AccountIdOf<T>,
+            AssetIdOf<T>,
+            Balance,
+            AssetIdOf<T>,
+            Balance,
+        ),
+        /// Changed liquidation parameters [who, close_factor, max_liquidation_bonus, backstop_health_factor]
+        ChangedLiquidationParameters(AccountIdOf<T>, Balance, Balance, Balance),
     }
 
     #[pallet::error]
@@ -357,6 +443,10 @@ pub mod pallet {
         RewardRateExceedsRemaining,
         /// Arithmetic error
         ArithmeticError,
+        /// Invalid liquidation amount
+        InvalidLiquidationAmount,
+        /// Invalid liquidation parameters
+        InvalidLiquidationParameters,
     }
 
     #[pallet::call]
@@ -650,8 +740,14 @@ pub mod pallet {
                     borrowing_rewards: 0,
                 };
                 borrow_info.insert(collateral_asset, new_user_info);
+                <BorrowersByCollateral<T>>::insert(
+                    collateral_asset,
+                    (borrowing_asset, user.clone()),
+                    (),
+                );
             }
             <UserBorrowingInfo<T>>::insert(borrowing_asset, user.clone(), borrow_info);
+            Self::mark_borrowing_position_updated(borrowing_asset, &user);
 
             // Update user's lending info according to given collateral
             let block_number = <frame_system::Pallet<T>>::block_number();
@@ -996,6 +1092,10 @@ pub mod pallet {
                 .map_err(|_| Error::<T>::CanNotTransferBorrowingRewards)?;
 
                 borrow_user_info.remove(&collateral_asset);
+                <BorrowersByCollateral<T>>::remove(
+                    collateral_asset,
+                    (borrowing_asset, user.clone()),
+                );
                 if borrow_user_info.is_empty() {
                     <UserBorrowingInfo<T>>::remove(borrowing_asset, user.clone());
                 } else {
@@ -1206,6 +1306,7 @@ pub mod pallet {
                 )?;
 
                 <PoolData<T>>::insert(*collateral_asset, collateral_pool_info);
+                <BorrowersByCollateral<T>>::remove(collateral_asset, (asset_id, user.clone()));
                 // Add user's borrowed amount tied with this asset to total_borrowed in given asset
                 total_borrowed += user_info.borrowing_amount;
             }
@@ -1462,6 +1563,244 @@ pub mod pallet {
             Self::deposit_event(Event::ChangedCollateralFactorAmount(user, amount));
             Ok(().into())
         }
+
+        /// Liquidate part of an unhealthy borrowing position. The liquidator repays borrowed asset
+        /// and receives collateral with a bonus, which grows while the health factor drops.
+        /// Debt left on exhausted collateral is moved to the other collaterals of the borrowed asset
+        /// and becomes bad debt only when the user has no collateral left
+        #[transactional]
+        #[pallet::call_index(13)]
+        #[pallet::weight(<T as Config>::WeightInfo::liquidate_position())]
+        pub fn liquidate_position(
+            origin: OriginFor<T>,
+            user: AccountIdOf<T>,
+            collateral_asset: AssetIdOf<T>,
+            borrowing_asset: AssetIdOf<T>,
+            amount_to_repay: Balance,
+        ) -> DispatchResultWithPostInfo {
+            let liquidator = ensure_signed(origin)?;
+            ensure!(
+                !amount_to_repay.is_zero(),
+                Error::<T>::InvalidLiquidationAmount
+            );
+
+            let mut borrow_pool_info =
+                <PoolData<T>>::get(borrowing_asset).ok_or(Error::<T>::PoolDoesNotExist)?;
+            let mut collateral_pool_info =
+                <PoolData<T>>::get(collateral_asset).ok_or(Error::<T>::PoolDoesNotExist)?;
+            let mut borrow_user_info = <UserBorrowingInfo<T>>::get(borrowing_asset, user.clone())
+                .ok_or(Error::<T>::NothingBorrowed)?;
+
+            // Health of the user is checked with the interest accrued by all positions
+            let block_number = <frame_system::Pallet<T>>::block_number();
+            for position in borrow_user_info.values_mut() {
+                Self::accrue_borrowing_interest_and_reward(
+                    position,
+                    &borrow_pool_info,
+                    block_number,
+                )?;
+            }
+            let mut user_info = borrow_user_info
+                .get(&collateral_asset)
+                .cloned()
+                .ok_or(Error::<T>::NonexistentBorrowingPosition)?;
+
+            let health_factor = Self::health_factor(&borrow_user_info, borrowing_asset);
+            ensure!(health_factor < balance!(1), Error::<T>::InvalidLiquidation);
+
+            let liquidation_bonus = Self::liquidation_bonus(health_factor);
+            let max_amount_to_repay = Self::max_liquidation_amount(
+                &borrow_user_info,
+                borrowing_asset,
+                collateral_asset,
+                liquidation_bonus,
+            );
+
+            // Collateral is sold with a discount equal to the liquidation bonus
+            let borrow_asset_price = Self::get_price(borrowing_asset);
+            let collateral_asset_price = Self::get_price(collateral_asset);
+            let collateral_per_repaid = FixedWrapper::from(borrow_asset_price)
+                * (FixedWrapper::from(balance!(1)) + FixedWrapper::from(liquidation_bonus))
+                / FixedWrapper::from(collateral_asset_price);
+
+            let mut repaid_amount = amount_to_repay.min(max_amount_to_repay);
+            let mut seized_collateral = (FixedWrapper::from(repaid_amount)
+                * collateral_per_repaid.clone())
+            .try_into_balance()
+            .unwrap_or(0);
+            if seized_collateral > user_info.collateral_amount {
+                seized_collateral = user_info.collateral_amount;
+                repaid_amount = (FixedWrapper::from(seized_collateral) / collateral_per_repaid)
+                    .try_into_balance()
+                    .unwrap_or(0);
+            }
+            ensure!(
+                !repaid_amount.is_zero() && !seized_collateral.is_zero(),
+                Error::<T>::InvalidLiquidationAmount
+            );
+
+            // Interest is repaid first, as in repay
+            let repaid_interest = repaid_amount.min(user_info.borrowing_interest);
+            let repaid_borrowing_amount = repaid_amount.saturating_sub(repaid_interest);
+            user_info.borrowing_interest =
+                user_info.borrowing_interest.saturating_sub(repaid_interest);
+            user_info.borrowing_amount = user_info
+                .borrowing_amount
+                .saturating_sub(repaid_borrowing_amount);
+            user_info.collateral_amount = user_info
+                .collateral_amount
+                .saturating_sub(seized_collateral);
+
+            // Update pools
+            borrow_pool_info.total_borrowed = borrow_pool_info
+                .total_borrowed
+                .saturating_sub(repaid_borrowing_amount);
+            borrow_pool_info.total_liquidity = borrow_pool_info
+                .total_liquidity
+                .saturating_add(repaid_borrowing_amount);
+            collateral_pool_info.total_collateral = collateral_pool_info
+                .total_collateral
+                .saturating_sub(seized_collateral);
+
+            // Update the total collateral
+            Self::decrease_total_collateral(&user, &collateral_asset, seized_collateral)?;
+
+            // Transfer repaid amount from liquidator to pallet
+            T::AssetManager::transfer_from(
+                &borrowing_asset,
+                &liquidator,
+                &Self::account_id(),
+                repaid_amount,
+            )
+            .map_err(|_| Error::<T>::CanNotTransferAmountToRepay)?;
+
+            // Transfer seized collateral to liquidator
+            T::AssetManager::transfer_from(
+                &collateral_asset,
+                &Self::account_id(),
+                &liquidator,
+                seized_collateral,
+            )
+            .map_err(|_| Error::<T>::UnableToTransferCollateral)?;
+
+            if user_info.collateral_amount.is_zero() {
+                // Transfer borrowing rewards to user
+                T::AssetManager::transfer_from(
+                    &APOLLO_ASSET_ID.into(),
+                    &Self::account_id(),
+                    &user,
+                    user_info.borrowing_rewards,
+                )
+                .map_err(|_| Error::<T>::CanNotTransferBorrowingRewards)?;
+
+                borrow_user_info.remove(&collateral_asset);
+                <BorrowersByCollateral<T>>::remove(
+                    collateral_asset,
+                    (borrowing_asset, user.clone()),
+                );
+
+                // Collateral is exhausted, so the remaining debt is moved to the most valuable
+                // of the other collaterals, or written off if the user has no collateral left
+                let other_collateral = borrow_user_info
+                    .iter()
+                    .max_by_key(|(asset_id, position)| {
+                        (FixedWrapper::from(position.collateral_amount)
+                            * FixedWrapper::from(Self::get_price(**asset_id)))
+                        .try_into_balance()
+                        .unwrap_or(0)
+                    })
+                    .map(|(asset_id, _)| *asset_id);
+
+                if let Some(position) =
+                    other_collateral.and_then(|asset_id| borrow_user_info.get_mut(&asset_id))
+                {
+                    position.borrowing_amount = position
+                        .borrowing_amount
+                        .checked_add(user_info.borrowing_amount)
+                        .ok_or(Error::<T>::ArithmeticError)?;
+                    position.borrowing_interest = position
+                        .borrowing_interest
+                        .checked_add(user_info.borrowing_interest)
+                        .ok_or(Error::<T>::ArithmeticError)?;
+                } else {
+                    // Unpaid interest was never lent out, so only the borrowed amount is lost
+                    borrow_pool_info.total_borrowed = borrow_pool_info
+                        .total_borrowed
+                        .saturating_sub(user_info.borrowing_amount);
+                    <BadDebt<T>>::mutate(borrowing_asset, |bad_debt| {
+                        *bad_debt = bad_debt.saturating_add(user_info.borrowing_amount);
+                    });
+                }
+            } else {
+                borrow_user_info.insert(collateral_asset, user_info);
+            }
+
+            if borrow_user_info.is_empty() {
+                <UserBorrowingInfo<T>>::remove(borrowing_asset, user.clone());
+            } else {
+                <UserBorrowingInfo<T>>::insert(borrowing_asset, user.clone(), borrow_user_info);
+                Self::mark_borrowing_position_updated(borrowing_asset, &user);
+            }
+
+            Self::update_pool_rates(&mut borrow_pool_info);
+            Self::update_pool_rates(&mut collateral_pool_info);
+            <PoolData<T>>::insert(borrowing_asset, borrow_pool_info);
+            <PoolData<T>>::insert(collateral_asset, collateral_pool_info);
+
+            if !repaid_interest.is_zero() {
+                Self::distribute_protocol_interest(
+                    borrowing_asset,
+                    repaid_interest,
+                    borrowing_asset,
+                )?;
+            }
+
+            Self::deposit_event(Event::PositionLiquidated(
+                liquidator,
+                user,
+                borrowing_asset,
+                repaid_amount,
+                collateral_asset,
+                seized_collateral,
+            ));
+            Ok(().into())
+        }
+
+        /// Change liquidation parameters
+        #[pallet::call_index(14)]
+        #[pallet::weight(<T as Config>::WeightInfo::change_liquidation_parameters())]
+        pub fn change_liquidation_parameters(
+            origin: OriginFor<T>,
+            close_factor: Balance,
+            max_liquidation_bonus: Balance,
+            backstop_health_factor: Balance,
+        ) -> DispatchResultWithPostInfo {
+            let user = ensure_signed(origin)?;
+
+            if user != AuthorityAccount::<T>::get() {
+                return Err(Error::<T>::Unauthorized.into());
+            }
+
+            ensure!(
+                !close_factor.is_zero()
+                    && close_factor <= balance!(1)
+                    && max_liquidation_bonus < balance!(1)
+                    && backstop_health_factor <= balance!(1),
+                Error::<T>::InvalidLiquidationParameters
+            );
+
+            <CloseFactor<T>>::put(close_factor);
+            <MaxLiquidationBonus<T>>::put(max_liquidation_bonus);
+            <BackstopHealthFactor<T>>::put(backstop_health_factor);
+
+            Self::deposit_event(Event::ChangedLiquidationParameters(
+                user,
+                close_factor,
+                max_liquidation_bonus,
+                backstop_health_factor,
+            ));
+            Ok(().into())
+        }
     }
 
     /// Validate unsigned call to this pallet.
@@ -1475,7 +1814,10 @@ pub mod pallet {
                 Call::liquidate { user, asset_id } => {
                     let user_infos =
                         UserBorrowingInfo::<T>::get(asset_id, user.clone()).unwrap_or_default();
-                    if Self::check_liquidation(&user_infos, *asset_id) {
+                    if !user_infos.is_empty()
+                        && Self::health_factor(&user_infos, *asset_id)
+                            < BackstopHealthFactor::<T>::get()
+                    {
                         ValidTransaction::with_tag_prefix("Apollo::liquidate")
                             .priority(T::UnsignedPriority::get())
                             .longevity(T::UnsignedLongevity::get())
@@ -1527,21 +1869,57 @@ pub mod pallet {
                 }
             }
 
+            <UpdatedBorrowingPositions<T>>::remove(
+                now.saturating_sub(T::BLOCKS_PER_FIFTEEN_MINUTES),
+            );
+
             T::DbWeight::get()
                 .reads(6)
-                .saturating_add(T::DbWeight::get().writes(4))
+                .saturating_add(T::DbWeight::get().writes(5))
         }
 
-        /// Off-chain worker procedure - calls liquidations
+        /// Off-chain worker procedure - calls liquidations of positions which were not
+        /// liquidated by keepers in time
         fn offchain_worker(block_number: BlockNumberFor<T>) {
             debug!(
                 "Entering off-chain worker, block number is {:?}",
                 block_number
             );
 
-            for (asset_id, user, user_infos) in UserBorrowingInfo::<T>::iter() {
+            let backstop_health_factor = BackstopHealthFactor::<T>::get();
+            let storage = StorageValueRef::persistent(LIQUIDATION_SNAPSHOT_KEY);
+            let previous_snapshot = storage
+                .get::<LiquidationSnapshot<BlockNumberFor<T>, AssetIdOf<T>, AccountIdOf<T>>>()
+                .ok()
+                .flatten();
+            let mut snapshot = LiquidationSnapshot {
+                block_number,
+                backstop_health_factor,
+                pools: PoolData::<T>::iter()
+                    .map(|(asset_id, pool_info)| {
+                        (
+                            asset_id,
+                            (Self::get_price(asset_id), pool_info.liquidation_threshold),
+                        )
+                    })
+                    .collect(),
+                watched_positions: BTreeSet::new(),
+            };
+            let watched_health_factor =
+                backstop_health_factor.saturating_add(WATCHED_HEALTH_FACTOR_MARGIN);
+
+            for (asset_id, user) in Self::liquidation_candidates(previous_snapshot, &mut snapshot) {
+                let user_infos =
+                    UserBorrowingInfo::<T>::get(asset_id, user.clone()).unwrap_or_default();
+                if user_infos.is_empty() {
+                    continue;
+                }
+                let health_factor = Self::health_factor(&user_infos, asset_id);
+                if health_factor < watched_health_factor {
+                    snapshot.watched_positions.insert((asset_id, user.clone()));
+                }
                 // Check liquidation
-                if Self::check_liquidation(&user_infos, asset_id) {
+                if health_factor < backstop_health_factor {
                     // Liquidate
                     debug!("Liquidation of user {:?}", user);
                     let call = Call::<T>::liquidate {
@@ -1557,9 +1935,22 @@ pub mod pallet {
                     }
                 }
             }
+
+            storage.set(&snapshot);
         }
     }
 
+    /// State seen by the offchain worker, positions are checked again only when it changes
+    #[derive(Encode, Decode)]
+    struct LiquidationSnapshot<BlockNumber, AssetId, AccountId> {
+        block_number: BlockNumber,
+        backstop_health_factor: Balance,
+        /// Asset -> (price, liquidation threshold), the price is kept until it moves by the threshold
+        pools: BTreeMap<AssetId, (Balance, Balance)>,
+        /// Positions which were sent to liquidation or are close to it
+        watched_positions: BTreeSet<(AssetId, AccountId)>,
+    }
+
     pub struct DenominateXorAndTbcd<T: Config>(PhantomData<T>);
 
     impl<T: Config> OnDenominate<BalanceOf<T>> for DenominateXorAndTbcd<T> {
@@ -1688,6 +2079,28 @@ pub mod pallet {
             user_infos: &BTreeMap<AssetIdOf<T>, BorrowingPosition<BlockNumberFor<T>>>,
             borrowing_asset: AssetIdOf<T>,
         ) -> bool {
+            Self::health_factor(user_infos, borrowing_asset) < balance!(1)
+        }
+
+        /// Ratio of the collaterals value weighted by liquidation thresholds to the borrowed value,
+        /// the position can be liquidated once it drops below 1
+        pub fn health_factor(
+            user_infos: &BTreeMap<AssetIdOf<T>, BorrowingPosition<BlockNumberFor<T>>>,
+            borrowing_asset: AssetIdOf<T>,
+        ) -> Balance {
+            let (sum_of_thresholds, total_borrowed_in_dollars) =
+                Self::thresholds_and_borrowed_in_dollars(user_infos, borrowing_asset);
+
+            (FixedWrapper::from(sum_of_thresholds) / FixedWrapper::from(total_borrowed_in_dollars))
+                .try_into_balance()
+                .unwrap_or(0)
+        }
+
+        /// Collaterals value weighted by liquidation thresholds and borrowed value in dollars
+        fn thresholds_and_borrowed_in_dollars(
+            user_infos: &BTreeMap<AssetIdOf<T>, BorrowingPosition<BlockNumberFor<T>>>,
+            borrowing_asset: AssetIdOf<T>,
+        ) -> (Balance, Balance) {
             let mut sum_of_thresholds: Balance = 0;
             let mut total_borrowed: Balance = 0;
 
@@ -1704,8 +2117,10 @@ pub mod pallet {
                 .try_into_balance()
                 .unwrap_or(0);
 
-                // Add borrowing amount to total borrowed
-                total_borrowed += user_info.borrowing_amount;
+                // Add borrowing amount with the accrued interest to total borrowed
+                total_borrowed += user_info
+                    .borrowing_amount
+                    .saturating_add(user_info.borrowing_interest);
             }
 
             let borrowing_asset_price = Self::get_price(borrowing_asset);
@@ -1714,12 +2129,226 @@ pub mod pallet {
             .try_into_balance()
             .unwrap_or(0);
 
-            let health_factor = (FixedWrapper::from(sum_of_thresholds)
-                / FixedWrapper::from(total_borrowed_in_dollars))
+            (sum_of_thresholds, total_borrowed_in_dollars)
+        }
+
+        /// Discount on collateral for liquidators, it grows from 0 as the health factor drops
+        /// below 1, so liquidators compete for the smallest bonus
+        pub fn liquidation_bonus(health_factor: Balance) -> Balance {
+            balance!(1)
+                .saturating_sub(health_factor)
+                .min(MaxLiquidationBonus::<T>::get())
+        }
+
+        /// Maximum amount of borrowed asset which can be repaid from the given collateral by a
+        /// single liquidation. It is limited by the close factor and by the amount which restores
+        /// the health factor to 1.
+        pub fn max_liquidation_amount(
+            user_infos: &BTreeMap<AssetIdOf<T>, BorrowingPosition<BlockNumberFor<T>>>,
+            borrowing_asset: AssetIdOf<T>,
+            collateral_asset: AssetIdOf<T>,
+            liquidation_bonus: Balance,
+        ) -> Balance {
+            let Some(user_info) = user_infos.get(&collateral_asset) else {
+                return 0;
+            };
+            let close_factor_amount = (FixedWrapper::from(
+                user_info
+                    .borrowing_amount
+                    .saturating_add(user_info.borrowing_interest),
+            ) * FixedWrapper::from(CloseFactor::<T>::get()))
+            .try_into_balance()
+            .unwrap_or(0);
+
+            // Repaying `x` reduces the borrowed value by `x * price` and the weighted collateral
+            // value by `x * price * (1 + bonus) * threshold`
+            let liquidation_threshold = PoolData::<T>::get(collateral_asset)
+                .unwrap_or_default()
+                .liquidation_threshold;
+            let seized_threshold = (FixedWrapper::from(balance!(1))
+                + FixedWrapper::from(liquidation_bonus))
+                * FixedWrapper::from(liquidation_threshold);
+            let Some(seized_threshold) = seized_threshold.try_into_balance().ok() else {
+                return close_factor_amount;
+            };
+            if seized_threshold >= balance!(1) {
+                // Liquidation can not restore the health factor
+                return close_factor_amount;
+            }
+
+            let (sum_of_thresholds, total_borrowed_in_dollars) =
+                Self::thresholds_and_borrowed_in_dollars(user_infos, borrowing_asset);
+            let restoring_amount =
+                (FixedWrapper::from(total_borrowed_in_dollars.saturating_sub(sum_of_thresholds))
+                    / FixedWrapper::from(Self::get_price(borrowing_asset))
+                    / FixedWrapper::from(balance!(1) - seized_threshold))
+                .try_into_balance()
+                .unwrap_or(close_factor_amount);
+
+            close_factor_amount.min(restoring_amount)
+        }
+
+        /// Position will be checked by the offchain worker even if prices do not change
+        fn mark_borrowing_position_updated(borrowing_asset: AssetIdOf<T>, user: &AccountIdOf<T>) {
+            let block_number = <frame_system::Pallet<T>>::block_number();
+            <UpdatedBorrowingPositions<T>>::mutate(block_number, |positions| {
+                // Full set makes the offchain worker check all positions
+                let _ = positions.try_insert((borrowing_asset, user.clone()));
+            });
+        }
+
+        /// Borrowing positions which health could decrease since the previous offchain worker run.
+        /// Prices of the snapshot which moved less than the threshold are replaced by the previous ones.
+        fn liquidation_candidates(
+            previous_snapshot: Option<
+                LiquidationSnapshot<BlockNumberFor<T>, AssetIdOf<T>, AccountIdOf<T>>,
+            >,
+            snapshot: &mut LiquidationSnapshot<BlockNumberFor<T>, AssetIdOf<T>, AccountIdOf<T>>,
+        ) -> BTreeSet<(AssetIdOf<T>, AccountIdOf<T>)> {
+            let previous_snapshot = match previous_snapshot {
+                Some(previous_snapshot)
+                    if previous_snapshot.backstop_health_factor
+                        == snapshot.backstop_health_factor
+                        && previous_snapshot.block_number < snapshot.block_number
+                        && snapshot
+                            .block_number
+                            .saturating_sub(previous_snapshot.block_number)
+                            <= T::BLOCKS_PER_FIFTEEN_MINUTES =>
+                {
+                    previous_snapshot
+                }
+                // Updated positions are not stored for that long, so all positions are checked
+                _ => return UserBorrowingInfo::<T>::iter_keys().collect(),
+            };
+
+            let mut candidates = previous_snapshot.watched_positions;
+            let mut block_number = previous_snapshot.block_number.saturating_add(One::one());
+            while block_number <= snapshot.block_number {
+                let positions = UpdatedBorrowingPositions::<T>::get(block_number);
+                if positions.len() >= MAX_UPDATED_BORROWING_POSITIONS as usize {
+                    // Some of the updated positions may be missing
+                    return UserBorrowingInfo::<T>::iter_keys().collect();
+                }
+                candidates.extend(positions);
+                block_number = block_number.saturating_add(One::one());
+            }
+
+            for (asset_id, pool_state) in snapshot.pools.iter_mut() {
+                if let Some(previous_pool_state) = previous_snapshot.pools.get(asset_id) {
+                    if previous_pool_state.1 == pool_state.1
+                        && !Self::price_moved(previous_pool_state.0, pool_state.0)
+                    {
+                        *pool_state = *previous_pool_state;
+                        continue;
+                    }
+                }
+                candidates.extend(
+                    UserBorrowingInfo::<T>::iter_key_prefix(asset_id).map(|user| (*asset_id, user)),
+                );
+                candidates.extend(BorrowersByCollateral::<T>::iter_key_prefix(asset_id));
+            }
+
+            candidates
+        }
+
+        /// Whether the price moved from the previous one by the threshold
+        fn price_moved(previous_price: Balance, price: Balance) -> bool {
+            let threshold_move = (FixedWrapper::from(previous_price)
+                * FixedWrapper::from(LIQUIDATION_PRICE_MOVE_THRESHOLD))
             .try_into_balance()
             .unwrap_or(0);
+            let price_move = previous_price.abs_diff(price);
+            !price_move.is_zero() && price_move >= threshold_move
+        }
+
+        /// Share of the pool funds which is borrowed
+        pub fn utilization_rate(pool_info: &PoolInfo) -> Balance {
+            let total_pool_amount = pool_info
+                .total_borrowed
+                .saturating_add(pool_info.total_liquidity);
+            if total_pool_amount.is_zero() {
+                0
+            } else {
+                (FixedWrapper::from(pool_info.total_borrowed)
+                    / FixedWrapper::from(total_pool_amount))
+                .try_into_balance()
+                .unwrap_or(0)
+            }
+        }
+
+        /// Annual interest rate paid by borrowers of the pool asset
+        pub fn borrowing_apy(pool_info: &PoolInfo) -> Balance {
+            (FixedWrapper::from(pool_info.borrowing_rate) * FixedWrapper::from(BLOCKS_PER_YEAR))
+                .try_into_balance()
+                .unwrap_or(0)
+        }
+
+        /// Annual yield of lenders, which is paid in APOLLO, relative to the value of lent asset
+        pub fn lending_apy(asset_id: AssetIdOf<T>, pool_info: &PoolInfo) -> Balance {
+            let basic_lending_rate = if LendingRewards::<T>::get().is_zero() {
+                0
+            } else {
+                pool_info.basic_lending_rate
+            };
+            Self::rewards_apy(
+                asset_id,
+                basic_lending_rate.saturating_add(pool_info.profit_lending_rate),
+                pool_info.total_liquidity,
+            )
+        }
+
+        /// Annual yield of borrowers, which is paid in APOLLO, relative to the value of borrowed asset
+        pub fn borrowing_rewards_apy(asset_id: AssetIdOf<T>, pool_info: &PoolInfo) -> Balance {
+            let borrowing_rewards_rate = if BorrowingRewards::<T>::get().is_zero() {
+                0
+            } else {
+                pool_info.borrowing_rewards_rate
+            };
+            Self::rewards_apy(asset_id, borrowing_rewards_rate, pool_info.total_borrowed)
+        }
+
+        fn rewards_apy(
+            asset_id: AssetIdOf<T>,
+            rewards_per_block: Balance,
+            amount: Balance,
+        ) -> Balance {
+            let amount_in_dollars =
+                FixedWrapper::from(amount) * FixedWrapper::from(Self::get_price(asset_id));
+            let rewards_in_dollars = FixedWrapper::from(rewards_per_block)
+                * FixedWrapper::from(BLOCKS_PER_YEAR)
+                * FixedWrapper::from(Self::get_price(APOLLO_ASSET_ID.into()));
+
+            (rewards_in_dollars / amount_in_dollars)
+                .try_into_balance()
+                .unwrap_or(0)
+        }
 
-            health_factor < balance!(1)
+        /// Lending position of the user with the earnings accrued up to the current block
+        pub fn accrued_lending_position(
+            asset_id: AssetIdOf<T>,
+            user: &AccountIdOf<T>,
+        ) -> Option<LendingPosition<BlockNumberFor<T>>> {
+            let mut user_info = UserLendingInfo::<T>::get(asset_id, user)?;
+            let mut pool_info = PoolData::<T>::get(asset_id)?;
+            let block_number = frame_system::Pallet::<T>::block_number();
+            Self::accrue_lending_earnings(&mut user_info, &mut pool_info, block_number).ok()?;
+            Some(user_info)
+        }
+
+        /// Borrowing positions of the user by collateral assets with the interest and rewards
+        /// accrued up to the current block
+        pub fn accrued_borrowing_positions(
+            asset_id: AssetIdOf<T>,
+            user: &AccountIdOf<T>,
+        ) -> Option<BTreeMap<AssetIdOf<T>, BorrowingPosition<BlockNumberFor<T>>>> {
+            let mut user_infos = UserBorrowingInfo::<T>::get(asset_id, user)?;
+            let pool_info = PoolData::<T>::get(asset_id)?;
+            let block_number = frame_system::Pallet::<T>::block_number();
+            for user_info in user_infos.values_mut() {
+                Self::accrue_borrowing_interest_and_reward(user_info, &pool_info, block_number)
+                    .ok()?;
+            }
+            Some(user_infos)
         }
 
         pub fn calculate_lending_earnings(
@@ -1917,17 +2546,7 @@ pub mod pallet {
         }
 
         fn update_pool_rates(pool_info: &mut PoolInfo) {
-            let total_pool_amount = pool_info
-                .total_borrowed
-                .saturating_add(pool_info.total_liquidity);
-            let utilization_rate = if total_pool_amount.is_zero() {
-                0
-            } else {
-                (FixedWrapper::from(pool_info.total_borrowed)
-                    / FixedWrapper::from(total_pool_amount))
-                .try_into_balance()
-                .unwrap_or(0)
-            };
+            let utilization_rate = Self::utilization_rate(pool_info);
 
             pool_info.profit_lending_rate = Self::calculate_profit_lending_rate(
                 pool_info.rewards,
@@ -1943,7 +2562,7 @@ pub mod pallet {
             utilization_rate: Balance,
             over_optimal_utilization: bool,
         ) -> Balance {
-            let base_rate = FixedWrapper::from(rewards) / FixedWrapper::from(balance!(5256000));
+            let base_rate = FixedWrapper::from(rewards) / FixedWrapper::from(BLOCKS_PER_YEAR);
             let rate = if over_optimal_utilization {
                 base_rate * (FixedWrapper::from(balance!(1)) + FixedWrapper::from(utilization_rate))
             } else {
@@ -1983,7 +2602,7 @@ pub mod pallet {
                 }
             };
 
-            (annual_rate / FixedWrapper::from(balance!(5256000)))
+            (annual_rate / FixedWrapper::from(BLOCKS_PER_YEAR))
                 .try_into_balance()
                 .unwrap_or(0)
         }
@@ -1,4 +1,4 @@
-use crate::{Config, Pallet, UserBorrowingInfo, UserTotalCollateral};
+use crate::{BorrowersByCollateral, Config, Pallet, UserBorrowingInfo, UserTotalCollateral};
 use common::prelude::Balance;
 use frame_support::__private::log::{error, info};
 use frame_support::pallet_prelude::*;
@@ -88,3 +88,73 @@ where
         Ok(())
     }
 }
+
+pub struct MigrateToV2<T>(core::marker::PhantomData<T>);
+
+impl<T> OnRuntimeUpgrade for MigrateToV2<T>
+where
+    T: Config,
+{
+    fn on_runtime_upgrade() -> frame_support::weights::Weight {
+        if Pallet::<T>::on_chain_storage_version() != StorageVersion::new(1) {
+            error!(
+                "Runtime upgrade executed with wrong storage version, expected 1, got {:?}",
+                Pallet::<T>::on_chain_storage_version()
+            );
+            return <T as frame_system::Config>::DbWeight::get().reads(1);
+        }
+
+        info!("Applying migration to version 2: Index borrowing positions by collateral");
+
+        let mut read_entries = 0u64;
+        let mut indexed_entries = 0u64;
+        <UserBorrowingInfo<T>>::iter().for_each(|(borrowing_asset, user, borrowing_map)| {
+            read_entries += 1;
+            borrowing_map.keys().for_each(|collateral_asset| {
+                <BorrowersByCollateral<T>>::insert(
+                    collateral_asset,
+                    (borrowing_asset, user.clone()),
+                    (),
+                );
+                indexed_entries += 1;
+            });
+        });
+
+        info!("Indexed {} borrowing positions", indexed_entries);
+
+        StorageVersion::new(2).put::<Pallet<T>>();
+
+        <T as frame_system::Config>::DbWeight::get()
+            .reads_writes(read_entries + 1, indexed_entries + 1)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::DispatchError> {
+        ensure!(
+            Pallet::<T>::on_chain_storage_version() == StorageVersion::new(1),
+            "must upgrade linearly"
+        );
+        Ok(Vec::new())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+        for (borrowing_asset, user, borrowing_map) in <UserBorrowingInfo<T>>::iter() {
+            for collateral_asset in borrowing_map.keys() {
+                ensure!(
+                    <BorrowersByCollateral<T>>::contains_key(
+                        collateral_asset,
+                        (borrowing_asset, user.clone())
+                    ),
+                    "Borrowing position is not indexed"
+                );
+            }
+        }
+
+        ensure!(
+            Pallet::<T>::on_chain_storage_version() == StorageVersion::new(2),
+            "should be upgraded to version 2"
+        );
+        Ok(())
+    }
+}
@@ -28,6 +28,10 @@ use {
         RawOrigin,
     },
     permissions::{Scope, MANAGE_DEX},
+    sp_core::offchain::{
+        testing::{TestOffchainExt, TestTransactionPoolExt},
+        OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+    },
     sp_runtime::{traits::Zero, AccountId32, BuildStorage, Perbill},
 };
 
@@ -82,7 +86,7 @@ construct_runtime! {
         Technical: technical::{Pallet, Call, Storage, Event<T>},
         CeresLiquidityLocker: ceres_liquidity_locker::{Pallet, Call, Storage, Event<T>},
         Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
-        DexManager: dex_manager::{Pallet, Call, Config<T>, Storage},
+        DexManager: dex_manager::{Pallet, Call, Config<T>, Storage, Event<T>},
         PriceTools: price_tools::{Pallet, Storage, Event<T>},
     }
 }
@@ -434,7 +438,13 @@ impl ExtBuilder {
         .assimilate_storage(&mut t)
         .unwrap();
 
-        t.into()
+        let (offchain, _) = TestOffchainExt::new();
+        let (pool, _) = TestTransactionPoolExt::new();
+        let mut ext = sp_io::TestExternalities::from(t);
+        ext.register_extension(OffchainDbExt::new(offchain.clone()));
+        ext.register_extension(OffchainWorkerExt::new(offchain));
+        ext.register_extension(TransactionPoolExt::new(pool));
+        ext
     }
 }
 
@@ -1,5 +1,5 @@
 mod test {
-    use crate::migrations::MigrateToV1;
+    use crate::migrations::{MigrateToV1, MigrateToV2};
     use crate::UserBorrowingInfo;
     use crate::*;
     use crate::{mock::*, PoolInfo};
@@ -4504,6 +4504,115 @@ mod test {
         });
     }
 
+    #[test]
+    fn runtime_api_helpers_ok() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            static_set_dex();
+
+            assert_ok!(assets::Pallet::<Runtime>::mint_to(
+                &DOT,
+                &alice(),
+                &alice(),
+                balance!(200)
+            ));
+
+            assert_ok!(assets::Pallet::<Runtime>::mint_to(
+                &XOR,
+                &alice(),
+                &bob(),
+                balance!(300)
+            ));
+
+            let user = RuntimeOrigin::signed(ApolloPlatform::authority_account());
+
+            assert_ok!(ApolloPlatform::add_pool(
+                user.clone(),
+                XOR,
+                balance!(1),
+                balance!(1),
+                balance!(1),
+                balance!(1),
+                balance!(1),
+                balance!(1),
+                balance!(1),
+            ));
+
+            assert_ok!(ApolloPlatform::add_pool(
+                user,
+                DOT,
+                balance!(1),
+                balance!(1),
+                balance!(1),
+                balance!(1),
+                balance!(1),
+                balance!(1),
+                balance!(1),
+            ));
+
+            assert_ok!(ApolloPlatform::lend(
+                RuntimeOrigin::signed(alice()),
+                DOT,
+                balance!(100),
+            ));
+
+            assert_ok!(ApolloPlatform::lend(
+                RuntimeOrigin::signed(bob()),
+                XOR,
+                balance!(300),
+            ));
+
+            assert_ok!(ApolloPlatform::borrow(
+                RuntimeOrigin::signed(alice()),
+                DOT,
+                XOR,
+                balance!(100),
+                balance!(1)
+            ));
+
+            let borrow_pool_info = pallet::PoolData::<Runtime>::get(XOR).unwrap();
+            assert_eq!(
+                ApolloPlatform::utilization_rate(&borrow_pool_info),
+                balance!(0.5)
+            );
+            assert_eq!(
+                ApolloPlatform::borrowing_apy(&borrow_pool_info),
+                (FixedWrapper::from(borrow_pool_info.borrowing_rate)
+                    * FixedWrapper::from(balance!(5256000)))
+                .try_into_balance()
+                .unwrap()
+            );
+
+            let borrow_user_info = pallet::UserBorrowingInfo::<Runtime>::get(XOR, alice()).unwrap();
+            assert_eq!(
+                ApolloPlatform::check_liquidation(&borrow_user_info, XOR),
+                ApolloPlatform::health_factor(&borrow_user_info, XOR) < balance!(1)
+            );
+
+            // Accrued values are calculated without changing the storage
+            frame_system::Pallet::<Runtime>::set_block_number(100);
+            let correct_borrowing_interest = calculate_borrowing_interest(alice(), XOR, DOT, 100);
+            let accrued_positions =
+                ApolloPlatform::accrued_borrowing_positions(XOR, &alice()).unwrap();
+            assert_eq!(
+                accrued_positions.get(&DOT).unwrap().borrowing_interest,
+                correct_borrowing_interest.0
+            );
+            assert_eq!(
+                pallet::UserBorrowingInfo::<Runtime>::get(XOR, alice()).unwrap(),
+                borrow_user_info
+            );
+
+            let accrued_lending_position =
+                ApolloPlatform::accrued_lending_position(XOR, &bob()).unwrap();
+            assert_eq!(accrued_lending_position.lending_amount, balance!(300));
+            assert_eq!(
+                ApolloPlatform::accrued_lending_position(XOR, &alice()),
+                None
+            );
+        });
+    }
+
     #[test]
     fn distribute_protocol_interest_pool_does_not_exist() {
         let mut ext = ExtBuilder::default().build();
@@ -7435,4 +7544,419 @@ mod test {
             );
         });
     }
+
+    fn init_liquidation_position(liquidation_threshold: Balance, loan_to_value: Balance) {
+        static_set_dex();
+
+        assert_ok!(assets::Pallet::<Runtime>::mint_to(
+            &DOT,
+            &alice(),
+            &alice(),
+            balance!(200)
+        ));
+
+        assert_ok!(assets::Pallet::<Runtime>::mint_to(
+            &XOR,
+            &alice(),
+            &bob(),
+            balance!(300000)
+        ));
+
+        assert_ok!(assets::Pallet::<Runtime>::mint_to(
+            &XOR,
+            &alice(),
+            &charles(),
+            balance!(100)
+        ));
+
+        assert_ok!(ApolloPlatform::add_pool(
+            RuntimeOrigin::signed(ApolloPlatform::authority_account()),
+            XOR,
+            balance!(1),
+            balance!(1),
+            balance!(1),
+            balance!(1),
+            balance!(1),
+            balance!(1),
+            balance!(0.1),
+        ));
+
+        assert_ok!(ApolloPlatform::add_pool(
+            RuntimeOrigin::signed(ApolloPlatform::authority_account()),
+            DOT,
+            balance!(1),
+            liquidation_threshold,
+            balance!(1),
+            balance!(1),
+            balance!(1),
+            balance!(1),
+            balance!(0.1),
+        ));
+
+        assert_ok!(ApolloPlatform::lend(
+            RuntimeOrigin::signed(alice()),
+            DOT,
+            balance!(200),
+        ));
+
+        assert_ok!(ApolloPlatform::lend(
+            RuntimeOrigin::signed(bob()),
+            XOR,
+            balance!(300000),
+        ));
+
+        assert_ok!(ApolloPlatform::borrow(
+            RuntimeOrigin::signed(alice()),
+            DOT,
+            XOR,
+            balance!(100),
+            loan_to_value
+        ));
+    }
+
+    #[test]
+    fn liquidate_position_healthy_position() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            init_liquidation_position(balance!(1), balance!(1));
+
+            assert_err!(
+                ApolloPlatform::liquidate_position(
+                    RuntimeOrigin::signed(charles()),
+                    alice(),
+                    DOT,
+                    XOR,
+                    balance!(10)
+                ),
+                Error::<Runtime>::InvalidLiquidation
+            );
+        });
+    }
+
+    #[test]
+    fn liquidate_position_invalid_amount() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            init_liquidation_position(balance!(0.5), balance!(1));
+
+            assert_err!(
+                ApolloPlatform::liquidate_position(
+                    RuntimeOrigin::signed(charles()),
+                    alice(),
+                    DOT,
+                    XOR,
+                    balance!(0)
+                ),
+                Error::<Runtime>::InvalidLiquidationAmount
+            );
+        });
+    }
+
+    #[test]
+    fn liquidate_position_limited_by_close_factor_ok() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            System::set_block_number(1);
+            init_liquidation_position(balance!(0.5), balance!(1));
+
+            let user_infos = pallet::UserBorrowingInfo::<Runtime>::get(XOR, alice()).unwrap();
+            assert_eq!(
+                ApolloPlatform::health_factor(&user_infos, XOR),
+                balance!(0.5)
+            );
+
+            // Health factor can not be restored, so only half of the debt is repaid with the maximum bonus
+            assert_ok!(ApolloPlatform::liquidate_position(
+                RuntimeOrigin::signed(charles()),
+                alice(),
+                DOT,
+                XOR,
+                balance!(100)
+            ));
+
+            System::assert_last_event(RuntimeEvent::ApolloPlatform(
+                crate::Event::PositionLiquidated(
+                    charles(),
+                    alice(),
+                    XOR,
+                    balance!(50),
+                    DOT,
+                    balance!(55),
+                ),
+            ));
+
+            let user_infos = pallet::UserBorrowingInfo::<Runtime>::get(XOR, alice()).unwrap();
+            let user_info = user_infos.get(&DOT).unwrap();
+            assert_eq!(user_info.borrowing_amount, balance!(50));
+            assert_eq!(user_info.collateral_amount, balance!(45));
+            assert_eq!(
+                pallet::UserTotalCollateral::<Runtime>::get(alice(), DOT),
+                Some(balance!(45))
+            );
+
+            assert_eq!(
+                pallet::PoolData::<Runtime>::get(XOR)
+                    .unwrap()
+                    .total_borrowed,
+                balance!(50)
+            );
+            assert_eq!(
+                pallet::PoolData::<Runtime>::get(DOT)
+                    .unwrap()
+                    .total_collateral,
+                balance!(45)
+            );
+
+            assert_eq!(
+                assets::Pallet::<Runtime>::free_balance(&XOR, &charles()).unwrap(),
+                balance!(50)
+            );
+            assert_eq!(
+                assets::Pallet::<Runtime>::free_balance(&DOT, &charles()).unwrap(),
+                balance!(55)
+            );
+        });
+    }
+
+    #[test]
+    fn liquidate_position_restores_health_factor_ok() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            init_liquidation_position(balance!(0.45), balance!(0.5));
+
+            let user_infos = pallet::UserBorrowingInfo::<Runtime>::get(XOR, alice()).unwrap();
+            assert_eq!(
+                ApolloPlatform::health_factor(&user_infos, XOR),
+                balance!(0.9)
+            );
+
+            assert_ok!(ApolloPlatform::liquidate_position(
+                RuntimeOrigin::signed(charles()),
+                alice(),
+                DOT,
+                XOR,
+                balance!(100)
+            ));
+
+            let user_infos = pallet::UserBorrowingInfo::<Runtime>::get(XOR, alice()).unwrap();
+            let user_info = user_infos.get(&DOT).unwrap();
+            let repaid_amount =
+                balance!(100) - assets::Pallet::<Runtime>::free_balance(&XOR, &charles()).unwrap();
+            let seized_collateral =
+                assets::Pallet::<Runtime>::free_balance(&DOT, &charles()).unwrap();
+
+            // Less than the close factor allows is repaid, just enough to restore the health factor
+            assert!(repaid_amount < balance!(50));
+            assert_eq!(user_info.borrowing_amount, balance!(100) - repaid_amount);
+            assert_eq!(
+                user_info.collateral_amount,
+                balance!(200) - seized_collateral
+            );
+
+            let health_factor = ApolloPlatform::health_factor(&user_infos, XOR);
+            assert!(health_factor > balance!(0.9999) && health_factor < balance!(1.0001));
+        });
+    }
+
+    fn exhaust_liquidation_collateral(other_collateral: Option<AssetId>) {
+        System::set_block_number(1);
+        init_liquidation_position(balance!(0.5), balance!(1));
+
+        let mut user_infos = pallet::UserBorrowingInfo::<Runtime>::get(XOR, alice()).unwrap();
+        let user_info = user_infos.get_mut(&DOT).unwrap();
+        user_info.collateral_amount = balance!(20);
+        user_info.borrowing_interest = balance!(1);
+        if let Some(collateral_asset) = other_collateral {
+            user_infos.insert(
+                collateral_asset,
+                BorrowingPosition {
+                    collateral_amount: balance!(10),
+                    borrowing_amount: balance!(10),
+                    borrowing_interest: 0,
+                    last_borrowing_block: 1,
+                    borrowing_rewards: 0,
+                },
+            );
+        }
+        pallet::UserBorrowingInfo::<Runtime>::insert(XOR, alice(), user_infos);
+
+        // Seized collateral is limited by the whole collateral of the position
+        assert_ok!(ApolloPlatform::liquidate_position(
+            RuntimeOrigin::signed(charles()),
+            alice(),
+            DOT,
+            XOR,
+            balance!(100)
+        ));
+        assert_eq!(
+            assets::Pallet::<Runtime>::free_balance(&DOT, &charles()).unwrap(),
+            balance!(20)
+        );
+    }
+
+    #[test]
+    fn liquidate_position_moves_remaining_debt_to_other_collateral() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            exhaust_liquidation_collateral(Some(KSM));
+
+            let repaid_amount =
+                balance!(100) - assets::Pallet::<Runtime>::free_balance(&XOR, &charles()).unwrap();
+            let user_infos = pallet::UserBorrowingInfo::<Runtime>::get(XOR, alice()).unwrap();
+            assert!(user_infos.get(&DOT).is_none());
+
+            // Interest is repaid first, so only the borrowed amount is moved
+            let user_info = user_infos.get(&KSM).unwrap();
+            assert_eq!(user_info.borrowing_amount, balance!(111) - repaid_amount);
+            assert_eq!(user_info.borrowing_interest, 0);
+            assert_eq!(user_info.collateral_amount, balance!(10));
+
+            // Moved debt is still borrowed from the pool
+            assert_eq!(
+                pallet::PoolData::<Runtime>::get(XOR)
+                    .unwrap()
+                    .total_borrowed,
+                balance!(101) - repaid_amount
+            );
+            assert_eq!(ApolloPlatform::bad_debt(XOR), 0);
+        });
+    }
+
+    #[test]
+    fn liquidate_position_records_bad_debt_without_collateral_left() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            exhaust_liquidation_collateral(None);
+
+            let repaid_amount =
+                balance!(100) - assets::Pallet::<Runtime>::free_balance(&XOR, &charles()).unwrap();
+            assert!(pallet::UserBorrowingInfo::<Runtime>::get(XOR, alice()).is_none());
+
+            // Interest is repaid first and the remaining debt is written off
+            assert_eq!(
+                pallet::PoolData::<Runtime>::get(XOR)
+                    .unwrap()
+                    .total_borrowed,
+                0
+            );
+            assert_eq!(ApolloPlatform::bad_debt(XOR), balance!(101) - repaid_amount);
+        });
+    }
+
+    #[test]
+    fn change_liquidation_parameters_unauthorized() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            assert_err!(
+                ApolloPlatform::change_liquidation_parameters(
+                    RuntimeOrigin::signed(alice()),
+                    balance!(0.4),
+                    balance!(0.05),
+                    balance!(0.8)
+                ),
+                Error::<Runtime>::Unauthorized
+            );
+        });
+    }
+
+    #[test]
+    fn change_liquidation_parameters_invalid_parameters() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            assert_err!(
+                ApolloPlatform::change_liquidation_parameters(
+                    RuntimeOrigin::signed(ApolloPlatform::authority_account()),
+                    balance!(0),
+                    balance!(0.05),
+                    balance!(0.8)
+                ),
+                Error::<Runtime>::InvalidLiquidationParameters
+            );
+
+            assert_err!(
+                ApolloPlatform::change_liquidation_parameters(
+                    RuntimeOrigin::signed(ApolloPlatform::authority_account()),
+                    balance!(0.4),
+                    balance!(1),
+                    balance!(0.8)
+                ),
+                Error::<Runtime>::InvalidLiquidationParameters
+            );
+        });
+    }
+
+    #[test]
+    fn change_liquidation_parameters_ok() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            assert_ok!(ApolloPlatform::change_liquidation_parameters(
+                RuntimeOrigin::signed(ApolloPlatform::authority_account()),
+                balance!(0.4),
+                balance!(0.05),
+                balance!(0.8)
+            ));
+
+            assert_eq!(pallet::CloseFactor::<Runtime>::get(), balance!(0.4));
+            assert_eq!(
+                pallet::MaxLiquidationBonus::<Runtime>::get(),
+                balance!(0.05)
+            );
+            assert_eq!(
+                pallet::BackstopHealthFactor::<Runtime>::get(),
+                balance!(0.8)
+            );
+        });
+    }
+
+    #[test]
+    fn offchain_worker_submits_backstop_liquidations() {
+        use sp_core::offchain::{testing::TestTransactionPoolExt, TransactionPoolExt};
+
+        let (pool, pool_state) = TestTransactionPoolExt::new();
+        let mut ext = ExtBuilder::default().build();
+        ext.register_extension(TransactionPoolExt::new(pool));
+        ext.execute_with(|| {
+            init_liquidation_position(balance!(0.95), balance!(1));
+
+            // Position is left to keepers while it is above the backstop health factor
+            ApolloPlatform::offchain_worker(1);
+            assert!(pool_state.read().transactions.is_empty());
+
+            assert_ok!(ApolloPlatform::change_liquidation_parameters(
+                RuntimeOrigin::signed(ApolloPlatform::authority_account()),
+                balance!(0.5),
+                balance!(0.1),
+                balance!(1)
+            ));
+
+            ApolloPlatform::offchain_worker(2);
+            assert_eq!(pool_state.read().transactions.len(), 1);
+        });
+    }
+
+    #[test]
+    fn migration_to_v2_indexes_borrowing_positions() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            init_liquidation_position(balance!(1), balance!(1));
+            assert!(pallet::BorrowersByCollateral::<Runtime>::contains_key(
+                DOT,
+                (XOR, alice())
+            ));
+
+            pallet::BorrowersByCollateral::<Runtime>::remove(DOT, (XOR, alice()));
+            StorageVersion::new(1).put::<pallet::Pallet<Runtime>>();
+
+            MigrateToV2::<Runtime>::on_runtime_upgrade();
+
+            assert!(pallet::BorrowersByCollateral::<Runtime>::contains_key(
+                DOT,
+                (XOR, alice())
+            ));
+            assert_eq!(
+                pallet::Pallet::<Runtime>::on_chain_storage_version(),
+                StorageVersion::new(2)
+            );
+        });
+    }
 }
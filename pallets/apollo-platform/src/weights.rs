@@ -73,6 +73,8 @@ pub trait WeightInfo {
 	fn remove_pool() -> Weight;
 	fn edit_pool_info() -> Weight;
 	fn add_collateral() -> Weight;
+	fn liquidate_position() -> Weight;
+	fn change_liquidation_parameters() -> Weight;
 }
 
 /// Weights for apollo_platform using the Substrate node and recommended hardware.
@@ -381,6 +383,46 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+	/// Storage: ApolloPlatform PoolData (r:2 w:2)
+	/// Proof Skipped: ApolloPlatform PoolData (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ApolloPlatform UserBorrowingInfo (r:1 w:1)
+	/// Proof Skipped: ApolloPlatform UserBorrowingInfo (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ApolloPlatform UserTotalCollateral (r:1 w:1)
+	/// Proof Skipped: ApolloPlatform UserTotalCollateral (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ApolloPlatform UpdatedBorrowingPositions (r:1 w:1)
+	/// Proof Skipped: ApolloPlatform UpdatedBorrowingPositions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ApolloPlatform BadDebt (r:1 w:1)
+	/// Proof Skipped: ApolloPlatform BadDebt (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PriceTools FastPriceInfos (r:3 w:0)
+	/// Proof Skipped: PriceTools FastPriceInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn liquidate_position() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2735`
+		//  Estimated: `31065`
+		// Minimum execution time: 151_820_000 picoseconds.
+		Weight::from_parts(154_604_000, 31065)
+			.saturating_add(T::DbWeight::get().reads(17_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: ApolloPlatform AuthorityAccount (r:1 w:0)
+	/// Proof Skipped: ApolloPlatform AuthorityAccount (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ApolloPlatform CloseFactor (r:0 w:1)
+	/// Proof Skipped: ApolloPlatform CloseFactor (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ApolloPlatform MaxLiquidationBonus (r:0 w:1)
+	/// Proof Skipped: ApolloPlatform MaxLiquidationBonus (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ApolloPlatform BackstopHealthFactor (r:0 w:1)
+	/// Proof Skipped: ApolloPlatform BackstopHealthFactor (max_values: Some(1), max_size: None, mode: Measured)
+	fn change_liquidation_parameters() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `114`
+		//  Estimated: `723`
+		// Minimum execution time: 24_380_000 picoseconds.
+		Weight::from_parts(24_935_000, 723)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -688,4 +730,44 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
+	/// Storage: ApolloPlatform PoolData (r:2 w:2)
+	/// Proof Skipped: ApolloPlatform PoolData (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ApolloPlatform UserBorrowingInfo (r:1 w:1)
+	/// Proof Skipped: ApolloPlatform UserBorrowingInfo (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ApolloPlatform UserTotalCollateral (r:1 w:1)
+	/// Proof Skipped: ApolloPlatform UserTotalCollateral (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ApolloPlatform UpdatedBorrowingPositions (r:1 w:1)
+	/// Proof Skipped: ApolloPlatform UpdatedBorrowingPositions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ApolloPlatform BadDebt (r:1 w:1)
+	/// Proof Skipped: ApolloPlatform BadDebt (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PriceTools FastPriceInfos (r:3 w:0)
+	/// Proof Skipped: PriceTools FastPriceInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn liquidate_position() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2735`
+		//  Estimated: `31065`
+		// Minimum execution time: 151_820_000 picoseconds.
+		Weight::from_parts(154_604_000, 31065)
+			.saturating_add(RocksDbWeight::get().reads(17_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: ApolloPlatform AuthorityAccount (r:1 w:0)
+	/// Proof Skipped: ApolloPlatform AuthorityAccount (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ApolloPlatform CloseFactor (r:0 w:1)
+	/// Proof Skipped: ApolloPlatform CloseFactor (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ApolloPlatform MaxLiquidationBonus (r:0 w:1)
+	/// Proof Skipped: ApolloPlatform MaxLiquidationBonus (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ApolloPlatform BackstopHealthFactor (r:0 w:1)
+	/// Proof Skipped: ApolloPlatform BackstopHealthFactor (max_values: Some(1), max_size: None, mode: Measured)
+	fn change_liquidation_parameters() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `114`
+		//  Estimated: `723`
+		// Minimum execution time: 24_380_000 picoseconds.
+		Weight::from_parts(24_935_000, 723)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
 }
@@ -100,5 +100,14 @@ benchmarks! {
     }: _(RawOrigin::Root, parameters)
     verify {}
 
+    set_aggregation_parameters {
+        let relayer = relayer::<T>();
+        Band::<T>::add_relayers(RawOrigin::Root.into(), vec![relayer])?;
+        let parameters = AggregationParameters::new(1, fixed!(0.1));
+    }: _(RawOrigin::Root, Some(parameters.clone()))
+    verify {
+        assert_eq!(Band::<T>::aggregation_parameters(), Some(parameters));
+    }
+
     impl_benchmark_test_suite!(Band, crate::mock::new_test_ext(), crate::mock::Runtime);
 }
@@ -35,8 +35,10 @@ use common::prelude::FixedWrapper;
 use common::{fixed, fixed_wrapper, Balance, DataFeed, Fixed, OnNewSymbolsRelayed, Oracle, Rate};
 use fallible_iterator::FallibleIterator;
 use frame_support::pallet_prelude::*;
+use frame_support::sp_runtime::SaturatedConversion;
 use frame_support::traits::Time;
 use frame_system::pallet_prelude::*;
+use sp_std::collections::btree_map::BTreeMap;
 use sp_std::collections::btree_set::BTreeSet;
 use sp_std::prelude::*;
 pub use weights::WeightInfo;
@@ -69,6 +71,69 @@ pub struct FeeCalculationParameters {
 
 impl DecodeWithMemTracking for FeeCalculationParameters {}
 
+/// Parameters of the multi-relayer aggregation mode.
+///
+/// When set, every trusted relayer submits its own rate for a round and the stored rate
+/// becomes the median of the submissions once `quorum` of them agree.
+#[derive(RuntimeDebug, Encode, Decode, TypeInfo, Clone, PartialEq, Eq)]
+pub struct AggregationParameters {
+    /// Number of relayer submissions required to finalize a round.
+    pub quorum: u32,
+    /// Maximum relative deviation of a submission from the round median.
+    /// Submissions deviating further are rejected as outliers.
+    pub max_deviation: Fixed,
+}
+
+impl DecodeWithMemTracking for AggregationParameters {}
+
+impl AggregationParameters {
+    pub fn validate<T: Config<I>, I: 'static>(&self) -> Result<(), DispatchError> {
+        ensure!(
+            self.quorum > 0 && self.max_deviation > fixed!(0),
+            Error::<T, I>::InvalidAggregationParameters
+        );
+        let relayers_count = Pallet::<T, I>::trusted_relayers()
+            .map(|relayers| relayers.len())
+            .unwrap_or_default();
+        ensure!(
+            self.quorum as usize <= relayers_count,
+            Error::<T, I>::InvalidAggregationParameters
+        );
+        Ok(())
+    }
+
+    pub fn new(quorum: u32, max_deviation: Fixed) -> Self {
+        Self {
+            quorum,
+            max_deviation,
+        }
+    }
+}
+
+/// Pending aggregation round of a symbol.
+#[derive(RuntimeDebug, Encode, Decode, TypeInfo, Clone, PartialEq, Eq)]
+pub struct RelayRound<AccountId> {
+    /// Resolve time of the first submission in the round.
+    pub started_at: u64,
+    /// Latest resolve time among the submissions.
+    pub resolve_time: u64,
+    /// Request identifier of the latest submission.
+    pub request_id: u64,
+    /// Rates submitted by relayers in this round.
+    pub submissions: BTreeMap<AccountId, Balance>,
+}
+
+impl<AccountId> RelayRound<AccountId> {
+    pub fn new(resolve_time: u64, request_id: u64) -> Self {
+        Self {
+            started_at: resolve_time,
+            resolve_time,
+            request_id,
+            submissions: BTreeMap::new(),
+        }
+    }
+}
+
 pub use pallet::*;
 
 impl FeeCalculationParameters {
@@ -153,6 +218,22 @@ impl<T: Config<I>, I: 'static> DataFeed<T::Symbol, Rate, u64> for Pallet<T, I> {
         Ok(Some(rate.into()))
     }
 
+    fn quote_with_max_age(symbol: &T::Symbol, max_age: u64) -> Result<Option<Rate>, DispatchError> {
+        let rate = if let Some(rate) = Self::quote(symbol)? {
+            rate
+        } else {
+            return Ok(None);
+        };
+
+        let now = T::Time::now().saturated_into::<u64>() / MILLISECS_MULTIPLIER;
+        ensure!(
+            now.saturating_sub(rate.last_updated) <= max_age,
+            Error::<T, I>::RateExpired
+        );
+
+        Ok(Some(rate))
+    }
+
     fn list_enabled_symbols() -> Result<Vec<(T::Symbol, u64)>, DispatchError> {
         Ok(Vec::from_iter(SymbolRates::<T, I>::iter().filter_map(
             |item| match item {
@@ -253,6 +334,16 @@ pub mod pallet {
     pub type DynamicFeeParameters<T: Config<I>, I: 'static = ()> =
         StorageValue<_, FeeCalculationParameters, ValueQuery, DefaultDynamicFeeParameters<T, I>>;
 
+    #[pallet::storage]
+    #[pallet::getter(fn aggregation_parameters)]
+    pub type AggregationParams<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, AggregationParameters, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn relay_rounds)]
+    pub type RelayRounds<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::Symbol, RelayRound<T::AccountId>, OptionQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -262,6 +353,14 @@ pub mod pallet {
         RelayersAdded(Vec<T::AccountId>),
         /// Relayer accounts were removed from trusted list. [relayers]
         RelayersRemoved(Vec<T::AccountId>),
+        /// Relayer submitted a rate to the aggregation round. [symbol, relayer, rate]
+        RateSubmitted(T::Symbol, T::AccountId, Balance),
+        /// Submissions deviating too far from the round median were rejected. [symbol, outliers]
+        OutliersRejected(T::Symbol, Vec<(T::AccountId, Balance)>),
+        /// Aggregation round reached quorum and the median rate was stored. [symbol, rate, submissions]
+        RoundFinalized(T::Symbol, Balance, u32),
+        /// Aggregation parameters were changed. [parameters]
+        AggregationParametersChanged(Option<AggregationParameters>),
     }
 
     #[pallet::error]
@@ -282,6 +381,8 @@ pub mod pallet {
         DynamicFeeCalculationError,
         /// Dynamic fee parameters are invalid,
         InvalidDynamicFeeParameters,
+        /// Aggregation parameters are invalid.
+        InvalidAggregationParameters,
     }
 
     #[pallet::hooks]
@@ -312,6 +413,10 @@ pub mod pallet {
         ///
         /// If `rates` contains duplicated symbols, then the last rate will be stored.
         ///
+        /// If aggregation mode is enabled (see [`set_aggregation_parameters()`]), the rates are
+        /// recorded as the relayer's submissions to the current round of each symbol instead.
+        /// The stored rate is updated with the median of the round once the quorum is reached.
+        ///
         /// - `origin`: the relayer account on whose behalf the transaction is being executed,
         /// - `rates`: symbols with rates in USD represented as fixed point with precision = 9,
         /// - `resolve_time`: symbols which rates are provided,
@@ -324,7 +429,16 @@ pub mod pallet {
             resolve_time: u64,
             request_id: u64,
         ) -> DispatchResultWithPostInfo {
-            Self::ensure_relayer(origin)?;
+            let relayer = Self::ensure_relayer(origin)?;
+
+            if let Some(parameters) = Self::aggregation_parameters() {
+                let symbol_rates =
+                    Self::submit_rates(relayer, rates, resolve_time, request_id, parameters)?;
+                if !symbol_rates.is_empty() {
+                    Self::deposit_event(Event::SymbolsRelayed(symbol_rates));
+                }
+                return Ok(().into());
+            }
 
             let symbol_rates = Self::update_rates(
                 rates,
@@ -354,6 +468,8 @@ pub mod pallet {
         /// Should be used in emergency situations i.e. then previous value was
         /// relayed by a faulty/malicious actor.
         ///
+        /// Bypasses aggregation rounds and drops pending submissions for the relayed symbols.
+        ///
         /// - `origin`: the relayer account on whose behalf the transaction is being executed,
         /// - `rates`: symbols with rates in USD represented as fixed point with precision = 9,
         /// - `resolve_time`: symbols which rates are provided,
@@ -373,6 +489,7 @@ pub mod pallet {
                 resolve_time,
                 request_id,
                 |option_old_rate, new_rate, symbol| {
+                    RelayRounds::<T, I>::remove(symbol);
                     if let Some(rate) = option_old_rate {
                         SymbolCheckBlock::<T, I>::remove(
                             Self::calc_expiration_block(rate.last_updated_block),
@@ -489,16 +606,161 @@ pub mod pallet {
             DynamicFeeParameters::<T, I>::put(fee_parameters);
             Ok(().into())
         }
+
+        /// Enable, update or disable (with `None`) the multi-relayer aggregation mode.
+        ///
+        /// Pending rounds are dropped on every change.
+        ///
+        /// - `origin`: the sudo account on whose behalf the transaction is being executed,
+        /// - `parameters`: quorum and maximum deviation of the submissions from the median.
+        #[pallet::call_index(5)]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::set_aggregation_parameters())]
+        pub fn set_aggregation_parameters(
+            origin: OriginFor<T>,
+            parameters: Option<AggregationParameters>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            if let Some(parameters) = &parameters {
+                parameters.validate::<T, I>()?;
+            }
+            AggregationParams::<T, I>::set(parameters.clone());
+            let _ = RelayRounds::<T, I>::clear(u32::MAX, None);
+            Self::deposit_event(Event::AggregationParametersChanged(parameters));
+            Ok(().into())
+        }
     }
 }
 
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
-    fn ensure_relayer(origin: OriginFor<T>) -> DispatchResult {
+    fn ensure_relayer(origin: OriginFor<T>) -> Result<T::AccountId, DispatchError> {
         let account_id = ensure_signed(origin)?;
 
         Self::trusted_relayers()
             .and_then(|relayers| relayers.contains(&account_id).then_some(()))
             .ok_or_else(|| Error::<T, I>::UnauthorizedRelayer.into())
+            .map(|_| account_id)
+    }
+
+    /// Record `relayer` submissions to the aggregation rounds and finalize the rounds which
+    /// reached the quorum.
+    ///
+    /// Returns symbols with the finalized median rates.
+    fn submit_rates(
+        relayer: T::AccountId,
+        rates: BoundedVec<(T::Symbol, u64), T::MaxRelaySymbols>,
+        resolve_time: u64,
+        request_id: u64,
+        parameters: AggregationParameters,
+    ) -> Result<Vec<(T::Symbol, Balance)>, DispatchError> {
+        let now = frame_system::Pallet::<T>::block_number();
+        let mut finalized = Vec::new();
+        let mut new_symbols = BTreeSet::new();
+
+        for (symbol, raw_rate) in rates {
+            let value = Self::raw_rate_into_balance(raw_rate)?;
+
+            // Submissions older than the stored rate are ignored, same as in the regular mode
+            if Self::rates(&symbol).map_or(false, |rate| rate.last_updated > resolve_time) {
+                continue;
+            }
+
+            let mut round = Self::relay_rounds(&symbol)
+                .filter(|round| !Self::is_round_expired(round, resolve_time))
+                .unwrap_or_else(|| RelayRound::new(resolve_time, request_id));
+            round.submissions.insert(relayer.clone(), value);
+            round.resolve_time = round.resolve_time.max(resolve_time);
+            round.request_id = request_id;
+            Self::deposit_event(Event::RateSubmitted(symbol.clone(), relayer.clone(), value));
+
+            if (round.submissions.len() as u32) < parameters.quorum {
+                RelayRounds::<T, I>::insert(&symbol, round);
+                continue;
+            }
+
+            let outliers = Self::find_outliers(&round.submissions, parameters.max_deviation);
+            if !outliers.is_empty() {
+                for (account, _) in outliers.iter() {
+                    round.submissions.remove(account);
+                }
+                Self::deposit_event(Event::OutliersRejected(symbol.clone(), outliers));
+            }
+
+            let submissions_count = round.submissions.len() as u32;
+            if submissions_count < parameters.quorum {
+                RelayRounds::<T, I>::insert(&symbol, round);
+                continue;
+            }
+
+            let mut values: Vec<Balance> = round.submissions.values().copied().collect();
+            let median_rate = median(&mut values);
+            let new_rate = BandRate {
+                value: median_rate,
+                last_updated: round.resolve_time,
+                request_id: round.request_id,
+                dynamic_fee: fixed!(0),
+                last_updated_block: now,
+            };
+            SymbolRates::<T, I>::mutate(&symbol, |option_old_rate| match option_old_rate {
+                Some(rate) => Self::update_rate_if_outdated(rate, new_rate, &symbol),
+                None => {
+                    new_symbols.insert(symbol.clone());
+                    _ = option_old_rate.insert(new_rate);
+                    SymbolCheckBlock::<T, I>::insert(
+                        Self::calc_expiration_block(now),
+                        &symbol,
+                        true,
+                    );
+                    Ok(())
+                }
+            })?;
+            RelayRounds::<T, I>::remove(&symbol);
+
+            Self::deposit_event(Event::RoundFinalized(
+                symbol.clone(),
+                median_rate,
+                submissions_count,
+            ));
+            finalized.push((symbol, median_rate));
+        }
+
+        T::OnNewSymbolsRelayedHook::on_new_symbols_relayed(Oracle::BandChainFeed, new_symbols)?;
+
+        Ok(finalized)
+    }
+
+    /// Round is expired if it was started more than stale period ago relative to `resolve_time`.
+    fn is_round_expired(round: &RelayRound<T::AccountId>, resolve_time: u64) -> bool {
+        let stale_period = T::GetBandRateStalePeriod::get().saturated_into::<u64>();
+        resolve_time
+            .saturating_sub(round.started_at)
+            .saturating_mul(MILLISECS_MULTIPLIER)
+            >= stale_period
+    }
+
+    /// Find submissions which deviate from the median of all submissions more than `max_deviation`.
+    pub fn find_outliers(
+        submissions: &BTreeMap<T::AccountId, Balance>,
+        max_deviation: Fixed,
+    ) -> Vec<(T::AccountId, Balance)> {
+        let mut values: Vec<Balance> = submissions.values().copied().collect();
+        let median_rate = median(&mut values);
+        submissions
+            .iter()
+            .filter(|(_, value)| {
+                let difference = if **value > median_rate {
+                    **value - median_rate
+                } else {
+                    median_rate - **value
+                };
+                if median_rate == 0 {
+                    return difference > 0;
+                }
+                (FixedWrapper::from(difference) / FixedWrapper::from(median_rate))
+                    .get()
+                    .map_or(true, |deviation| deviation > max_deviation)
+            })
+            .map(|(account, value)| (account.clone(), *value))
+            .collect()
     }
 
     /// Update rates in the storage with the new ones.
@@ -620,3 +882,20 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         block_number + T::GetBandRateStaleBlockPeriod::get()
     }
 }
+
+/// Median of `values`. Average of the two middle values is taken for even number of values.
+///
+/// Returns 0 for empty `values`.
+pub fn median(values: &mut [Balance]) -> Balance {
+    if values.is_empty() {
+        return 0;
+    }
+    values.sort_unstable();
+    let middle = values.len() / 2;
+    if values.len() % 2 == 0 {
+        let (lower, upper) = (values[middle - 1], values[middle]);
+        lower + (upper - lower) / 2
+    } else {
+        values[middle]
+    }
+}
@@ -37,7 +37,9 @@ use frame_system::pallet_prelude::BlockNumberFor;
 use sp_core::TryCollect;
 use sp_std::collections::btree_set::BTreeSet;
 
-use crate::{mock::*, BandRate, Error, FeeCalculationParameters, SymbolCheckBlock};
+use crate::{
+    mock::*, AggregationParameters, BandRate, Error, FeeCalculationParameters, SymbolCheckBlock,
+};
 
 pub fn band_rate_into_balance(rate: u64) -> Balance {
     let fixed = Fixed::from_bits(rate as i128 * super::RATE_MULTIPLIER);
@@ -644,3 +646,235 @@ fn should_calculate_dynamic_fee() {
         assert_eq!(rate_d.dynamic_fee, fixed!(1));
     })
 }
+
+#[test]
+fn set_aggregation_parameters_should_validate_input() {
+    new_test_ext().execute_with(|| {
+        Band::add_relayers(RuntimeOrigin::root(), vec![1, 2, 3]).expect("Failed to add relayers");
+
+        assert_noop!(
+            Band::set_aggregation_parameters(
+                RuntimeOrigin::signed(1),
+                Some(AggregationParameters::new(2, fixed!(0.1)))
+            ),
+            BadOrigin
+        );
+        assert_noop!(
+            Band::set_aggregation_parameters(
+                RuntimeOrigin::root(),
+                Some(AggregationParameters::new(0, fixed!(0.1)))
+            ),
+            Error::<Runtime>::InvalidAggregationParameters
+        );
+        assert_noop!(
+            Band::set_aggregation_parameters(
+                RuntimeOrigin::root(),
+                Some(AggregationParameters::new(4, fixed!(0.1)))
+            ),
+            Error::<Runtime>::InvalidAggregationParameters
+        );
+        assert_noop!(
+            Band::set_aggregation_parameters(
+                RuntimeOrigin::root(),
+                Some(AggregationParameters::new(2, fixed!(0)))
+            ),
+            Error::<Runtime>::InvalidAggregationParameters
+        );
+
+        let parameters = AggregationParameters::new(3, fixed!(0.1));
+        Band::set_aggregation_parameters(RuntimeOrigin::root(), Some(parameters.clone()))
+            .expect("Failed to set aggregation parameters");
+        assert_eq!(Band::aggregation_parameters(), Some(parameters));
+
+        Band::set_aggregation_parameters(RuntimeOrigin::root(), None)
+            .expect("Failed to disable aggregation");
+        assert_eq!(Band::aggregation_parameters(), None);
+    });
+}
+
+#[test]
+fn relay_should_store_median_when_quorum_is_reached() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Band::add_relayers(RuntimeOrigin::root(), vec![1, 2, 3]).expect("Failed to add relayers");
+        Band::set_aggregation_parameters(
+            RuntimeOrigin::root(),
+            Some(AggregationParameters::new(3, fixed!(0.1))),
+        )
+        .expect("Failed to set aggregation parameters");
+
+        for (relayer, rate) in [(1, 100), (2, 102)] {
+            Band::relay(
+                RuntimeOrigin::signed(relayer),
+                vec![("USD".to_owned(), rate)].try_into().unwrap(),
+                0,
+                0,
+            )
+            .expect("Failed to relay rates");
+        }
+        assert_eq!(Band::rates("USD".to_owned()), None);
+        assert_eq!(
+            Band::relay_rounds("USD".to_owned())
+                .expect("Expected pending round")
+                .submissions
+                .len(),
+            2
+        );
+
+        // repeated submission replaces the previous one
+        Band::relay(
+            RuntimeOrigin::signed(2),
+            vec![("USD".to_owned(), 103)].try_into().unwrap(),
+            0,
+            0,
+        )
+        .expect("Failed to relay rates");
+        assert_eq!(Band::rates("USD".to_owned()), None);
+
+        Band::relay(
+            RuntimeOrigin::signed(3),
+            vec![("USD".to_owned(), 101)].try_into().unwrap(),
+            1,
+            2,
+        )
+        .expect("Failed to relay rates");
+
+        assert_eq!(
+            Band::rates("USD".to_owned()),
+            Some(BandRate {
+                value: band_rate_into_balance(101),
+                last_updated: 1,
+                request_id: 2,
+                dynamic_fee: fixed!(0),
+                last_updated_block: 1,
+            })
+        );
+        assert_eq!(Band::relay_rounds("USD".to_owned()), None);
+        System::assert_has_event(RuntimeEvent::Band(crate::Event::RoundFinalized(
+            "USD".to_owned(),
+            band_rate_into_balance(101),
+            3,
+        )));
+    });
+}
+
+#[test]
+fn relay_should_reject_outliers() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Band::add_relayers(RuntimeOrigin::root(), vec![1, 2, 3, 4])
+            .expect("Failed to add relayers");
+        Band::set_aggregation_parameters(
+            RuntimeOrigin::root(),
+            Some(AggregationParameters::new(3, fixed!(0.1))),
+        )
+        .expect("Failed to set aggregation parameters");
+
+        for (relayer, rate) in [(1, 100), (2, 101), (3, 200)] {
+            Band::relay(
+                RuntimeOrigin::signed(relayer),
+                vec![("USD".to_owned(), rate)].try_into().unwrap(),
+                0,
+                0,
+            )
+            .expect("Failed to relay rates");
+        }
+
+        System::assert_has_event(RuntimeEvent::Band(crate::Event::OutliersRejected(
+            "USD".to_owned(),
+            vec![(3, band_rate_into_balance(200))],
+        )));
+        assert_eq!(Band::rates("USD".to_owned()), None);
+        assert_eq!(
+            Band::relay_rounds("USD".to_owned())
+                .expect("Expected pending round")
+                .submissions
+                .len(),
+            2
+        );
+
+        Band::relay(
+            RuntimeOrigin::signed(4),
+            vec![("USD".to_owned(), 102)].try_into().unwrap(),
+            0,
+            0,
+        )
+        .expect("Failed to relay rates");
+
+        assert_eq!(
+            Band::rates("USD".to_owned()).map(|rate| rate.value),
+            Some(band_rate_into_balance(101))
+        );
+    });
+}
+
+#[test]
+fn relay_should_restart_expired_round() {
+    new_test_ext().execute_with(|| {
+        Band::add_relayers(RuntimeOrigin::root(), vec![1, 2]).expect("Failed to add relayers");
+        Band::set_aggregation_parameters(
+            RuntimeOrigin::root(),
+            Some(AggregationParameters::new(2, fixed!(0.1))),
+        )
+        .expect("Failed to set aggregation parameters");
+
+        Band::relay(
+            RuntimeOrigin::signed(1),
+            vec![("USD".to_owned(), 100)].try_into().unwrap(),
+            0,
+            0,
+        )
+        .expect("Failed to relay rates");
+
+        let resolve_time = GetBandRateStalePeriod::get() / 1000;
+        Band::relay(
+            RuntimeOrigin::signed(2),
+            vec![("USD".to_owned(), 100)].try_into().unwrap(),
+            resolve_time,
+            1,
+        )
+        .expect("Failed to relay rates");
+
+        assert_eq!(Band::rates("USD".to_owned()), None);
+        let round = Band::relay_rounds("USD".to_owned()).expect("Expected pending round");
+        assert_eq!(round.started_at, resolve_time);
+        assert_eq!(round.submissions.len(), 1);
+    });
+}
+
+#[test]
+fn quote_with_max_age_should_work() {
+    new_test_ext().execute_with(|| {
+        Band::add_relayers(RuntimeOrigin::root(), vec![1]).expect("Failed to add relayers");
+        Band::relay(
+            RuntimeOrigin::signed(1),
+            vec![("USD".to_owned(), 1)].try_into().unwrap(),
+            0,
+            0,
+        )
+        .expect("Failed to relay rates");
+
+        Timestamp::set_timestamp(120 * 1000);
+
+        assert_eq!(
+            <Band as DataFeed<String, Rate, u64>>::quote_with_max_age(&"USD".to_owned(), 60),
+            Err(Error::<Runtime>::RateExpired.into())
+        );
+        assert_eq!(
+            <Band as DataFeed<String, Rate, u64>>::quote_with_max_age(&"USD".to_owned(), 120)
+                .map(|rate| rate.map(|rate| rate.value)),
+            Ok(Some(band_rate_into_balance(1)))
+        );
+        assert_eq!(
+            <Band as DataFeed<String, Rate, u64>>::quote_with_max_age(&"EUR".to_owned(), 60),
+            Ok(None)
+        );
+    });
+}
+
+#[test]
+fn median_should_work() {
+    assert_eq!(crate::median(&mut []), 0);
+    assert_eq!(crate::median(&mut [5, 1, 3]), 3);
+    assert_eq!(crate::median(&mut [4, 1, 3, 2]), 2);
+}
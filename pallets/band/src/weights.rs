@@ -65,6 +65,7 @@ pub trait WeightInfo {
 	fn add_relayers() -> Weight;
 	fn remove_relayers() -> Weight;
 	fn set_dynamic_fee_parameters() -> Weight;
+	fn set_aggregation_parameters() -> Weight;
 }
 
 /// Weights for band using the Substrate node and recommended hardware.
@@ -72,8 +73,12 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: Band TrustedRelayers (r:1 w:0)
 	/// Proof Skipped: Band TrustedRelayers (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Band AggregationParams (r:1 w:0)
+	/// Proof Skipped: Band AggregationParams (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: Band SymbolRates (r:1 w:1)
 	/// Proof Skipped: Band SymbolRates (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Band RelayRounds (r:1 w:1)
+	/// Proof Skipped: Band RelayRounds (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Band SymbolCheckBlock (r:0 w:1)
 	/// Proof Skipped: Band SymbolCheckBlock (max_values: None, max_size: None, mode: Measured)
 	/// Storage: OracleProxy SymbolProviders (r:0 w:1)
@@ -83,9 +88,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		//  Measured:  `95`
 		//  Estimated: `3350`
 		// Minimum execution time: 38_031_000 picoseconds.
-		Weight::from_parts(38_412_000, 3350)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		Weight::from_parts(45_217_000, 3350)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: Band TrustedRelayers (r:1 w:0)
 	/// Proof Skipped: Band TrustedRelayers (max_values: Some(1), max_size: None, mode: Measured)
@@ -136,14 +141,33 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(8_578_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: Band TrustedRelayers (r:1 w:0)
+	/// Proof Skipped: Band TrustedRelayers (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Band AggregationParams (r:0 w:1)
+	/// Proof Skipped: Band AggregationParams (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Band RelayRounds (r:0 w:1)
+	/// Proof Skipped: Band RelayRounds (max_values: None, max_size: None, mode: Measured)
+	fn set_aggregation_parameters() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `95`
+		//  Estimated: `590`
+		// Minimum execution time: 14_102_000 picoseconds.
+		Weight::from_parts(14_398_000, 590)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	/// Storage: Band TrustedRelayers (r:1 w:0)
 	/// Proof Skipped: Band TrustedRelayers (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Band AggregationParams (r:1 w:0)
+	/// Proof Skipped: Band AggregationParams (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: Band SymbolRates (r:1 w:1)
 	/// Proof Skipped: Band SymbolRates (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Band RelayRounds (r:1 w:1)
+	/// Proof Skipped: Band RelayRounds (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Band SymbolCheckBlock (r:0 w:1)
 	/// Proof Skipped: Band SymbolCheckBlock (max_values: None, max_size: None, mode: Measured)
 	/// Storage: OracleProxy SymbolProviders (r:0 w:1)
@@ -153,9 +177,9 @@ impl WeightInfo for () {
 		//  Measured:  `95`
 		//  Estimated: `3350`
 		// Minimum execution time: 38_031_000 picoseconds.
-		Weight::from_parts(38_412_000, 3350)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		Weight::from_parts(45_217_000, 3350)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: Band TrustedRelayers (r:1 w:0)
 	/// Proof Skipped: Band TrustedRelayers (max_values: Some(1), max_size: None, mode: Measured)
@@ -206,4 +230,19 @@ impl WeightInfo for () {
 		Weight::from_parts(8_578_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: Band TrustedRelayers (r:1 w:0)
+	/// Proof Skipped: Band TrustedRelayers (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Band AggregationParams (r:0 w:1)
+	/// Proof Skipped: Band AggregationParams (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Band RelayRounds (r:0 w:1)
+	/// Proof Skipped: Band RelayRounds (max_values: None, max_size: None, mode: Measured)
+	fn set_aggregation_parameters() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `95`
+		//  Estimated: `590`
+		// Minimum execution time: 14_102_000 picoseconds.
+		Weight::from_parts(14_398_000, 590)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
@@ -4,8 +4,8 @@
 
 use super::*;
 
-use common::{balance, AssetInfoProvider, AssetManager, CERES_ASSET_ID};
-use frame_benchmarking::benchmarks;
+use common::{balance, AssetInfoProvider, AssetManager, VoteConviction, CERES_ASSET_ID};
+use frame_benchmarking::{account, benchmarks};
 use frame_system::{EventRecord, RawOrigin};
 use sp_core::H256;
 use sp_io::hashing::blake2_256;
@@ -47,12 +47,29 @@ benchmarks! {
         let owner: T::AccountId = T::AssetInfoProvider::get_asset_owner(&asset_id.into()).unwrap();
 
         T::AssetManager::mint(
-            RawOrigin::Signed(owner).into(),
+            RawOrigin::Signed(owner.clone()).into(),
             CERES_ASSET_ID.into(),
             caller.clone(),
             number_of_votes
         ).unwrap();
 
+        // Delegate voting power to caller, so that it is locked by the vote
+        let delegator: T::AccountId = account("delegator", 0, 0);
+        frame_system::Pallet::<T>::inc_providers(&delegator);
+        T::AssetManager::mint(
+            RawOrigin::Signed(owner).into(),
+            CERES_ASSET_ID.into(),
+            delegator.clone(),
+            number_of_votes
+        ).unwrap();
+        CeresGovernancePlatform::<T>::delegate(
+            RawOrigin::Signed(delegator).into(),
+            asset_id.into(),
+            caller.clone(),
+            number_of_votes,
+            VoteConviction::Locked6x
+        ).unwrap();
+
         // Create poll
         let poll_info = PollInfo {
             poll_asset: asset_id.into(),
@@ -171,6 +188,90 @@ benchmarks! {
         assert_last_event::<T>(Event::<T>::Withdrawn(caller, poll_id, asset_id.into(), number_of_votes).into());
     }
 
+    delegate {
+        let asset_id = CERES_ASSET_ID;
+        let amount = balance!(300);
+        let caller = pallet::AuthorityAccount::<T>::get();
+        let delegate: T::AccountId = account("delegate", 0, 0);
+
+        frame_system::Pallet::<T>::inc_providers(&caller);
+
+        let owner: T::AccountId = T::AssetInfoProvider::get_asset_owner(&asset_id.into()).unwrap();
+        T::AssetManager::mint(
+            RawOrigin::Signed(owner).into(),
+            CERES_ASSET_ID.into(),
+            caller.clone(),
+            amount
+        ).unwrap();
+    }: _(RawOrigin::Signed(caller.clone()), asset_id.into(), delegate.clone(), amount, VoteConviction::Locked6x)
+    verify {
+        assert_last_event::<T>(Event::<T>::Delegated(caller, delegate, asset_id.into(), amount).into());
+    }
+
+    undelegate {
+        let asset_id = CERES_ASSET_ID;
+        let amount = balance!(300);
+        let caller = pallet::AuthorityAccount::<T>::get();
+        let delegate: T::AccountId = account("delegate", 0, 0);
+        let current_timestamp = Timestamp::<T>::get();
+
+        frame_system::Pallet::<T>::inc_providers(&caller);
+
+        let owner: T::AccountId = T::AssetInfoProvider::get_asset_owner(&asset_id.into()).unwrap();
+        T::AssetManager::mint(
+            RawOrigin::Signed(owner).into(),
+            CERES_ASSET_ID.into(),
+            caller.clone(),
+            amount
+        ).unwrap();
+
+        CeresGovernancePlatform::<T>::delegate(
+            RawOrigin::Signed(caller.clone()).into(),
+            asset_id.into(),
+            delegate,
+            amount,
+            VoteConviction::Locked6x
+        ).unwrap();
+    }: _(RawOrigin::Signed(caller.clone()), asset_id.into())
+    verify {
+        let unlocking_timestamp = current_timestamp.max(T::CONVICTION_LOCK_PERIOD * 16u32.into());
+        assert_last_event::<T>(Event::<T>::Undelegated(caller, asset_id.into(), unlocking_timestamp).into());
+    }
+
+    withdraw_delegated_funds {
+        let asset_id = CERES_ASSET_ID;
+        let amount = balance!(300);
+        let caller = pallet::AuthorityAccount::<T>::get();
+        let delegate: T::AccountId = account("delegate", 0, 0);
+
+        frame_system::Pallet::<T>::inc_providers(&caller);
+
+        let owner: T::AccountId = T::AssetInfoProvider::get_asset_owner(&asset_id.into()).unwrap();
+        T::AssetManager::mint(
+            RawOrigin::Signed(owner).into(),
+            CERES_ASSET_ID.into(),
+            caller.clone(),
+            amount
+        ).unwrap();
+
+        CeresGovernancePlatform::<T>::delegate(
+            RawOrigin::Signed(caller.clone()).into(),
+            asset_id.into(),
+            delegate,
+            amount,
+            VoteConviction::None
+        ).unwrap();
+        CeresGovernancePlatform::<T>::undelegate(
+            RawOrigin::Signed(caller.clone()).into(),
+            asset_id.into()
+        ).unwrap();
+
+        pallet_timestamp::Now::<T>::put(Timestamp::<T>::get() + 1u32.into());
+    }: _(RawOrigin::Signed(caller.clone()), asset_id.into())
+    verify {
+        assert_last_event::<T>(Event::<T>::DelegatedFundsWithdrawn(caller, asset_id.into(), amount).into());
+    }
+
     impl_benchmark_test_suite!(
         Pallet,
         crate::mock::ExtBuilder::default().build(),
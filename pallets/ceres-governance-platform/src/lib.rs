@@ -13,7 +13,7 @@ mod mock;
 mod tests;
 
 use codec::{Decode, Encode};
-use common::{Balance, BoundedString};
+use common::{Balance, BoundedString, VoteConviction};
 use frame_support::BoundedVec;
 pub use weights::WeightInfo;
 
@@ -26,6 +26,32 @@ pub struct VotingInfo {
     number_of_votes: Balance,
     /// Asset withdrawn
     asset_withdrawn: bool,
+    /// Conviction of votes
+    conviction: VoteConviction,
+    /// Votes weighted by conviction, including delegated voting power
+    voting_power: Balance,
+}
+
+#[derive(Encode, Decode, PartialEq, Eq, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct DelegationInfo<AccountId, Moment> {
+    /// Account voting with delegated funds
+    delegate: AccountId,
+    /// Amount of delegated funds
+    amount: Balance,
+    /// Conviction of delegated funds
+    conviction: VoteConviction,
+    /// Timestamp after which funds can be withdrawn, set when delegation is revoked
+    unlocking_timestamp: Option<Moment>,
+}
+
+#[derive(Encode, Decode, Default, PartialEq, Eq, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct DelegatedVotingInfo<Moment> {
+    /// Voting power delegated to account
+    voting_power: Balance,
+    /// Latest end of poll in which delegated voting power was used
+    locked_until: Moment,
 }
 
 #[derive(Encode, Decode, Default, PartialEq, Eq, scale_info::TypeInfo)]
@@ -62,16 +88,21 @@ pub enum StorageVersion {
     V2,
     /// After migrating to open governance
     V3,
+    /// After adding conviction and delegated voting power to votes
+    V4,
 }
 
 pub use pallet::*;
 
 #[frame_support::pallet]
 pub mod pallet {
-    use crate::{migrations, PollInfo, StorageVersion, VotingInfo, WeightInfo};
+    use crate::{
+        migrations, DelegatedVotingInfo, DelegationInfo, PollInfo, StorageVersion, VotingInfo,
+        WeightInfo,
+    };
     use common::prelude::Balance;
-    use common::BoundedString;
     use common::{AssetIdOf, AssetManager};
+    use common::{BoundedString, VoteConviction};
     use frame_support::__private::log;
     use frame_support::pallet_prelude::OptionQuery;
     use frame_support::pallet_prelude::ValueQuery;
@@ -85,12 +116,17 @@ pub mod pallet {
     use pallet_timestamp as timestamp;
     use sp_core::H256;
     use sp_io::hashing::blake2_256;
+    use sp_runtime::traits::Saturating;
     use sp_std::collections::btree_set::BTreeSet;
+    use sp_std::prelude::*;
 
     const PALLET_ID: PalletId = PalletId(*b"ceresgov");
 
     #[pallet::config]
     pub trait Config: frame_system::Config + technical::Config + timestamp::Config {
+        /// Lock period of conviction votes represented in milliseconds
+        const CONVICTION_LOCK_PERIOD: Self::Moment;
+
         /// String limit
         type StringLimit: Get<u32>;
 
@@ -141,6 +177,32 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// Funds delegated by account for polls with particular asset
+    #[pallet::storage]
+    #[pallet::getter(fn delegations)]
+    pub type Delegations<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        AccountIdOf<T>,
+        Identity,
+        AssetIdOf<T>,
+        DelegationInfo<AccountIdOf<T>, T::Moment>,
+        OptionQuery,
+    >;
+
+    /// Voting power delegated to account for polls with particular asset
+    #[pallet::storage]
+    #[pallet::getter(fn delegated_voting_power)]
+    pub type DelegatedVotingPower<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        AccountIdOf<T>,
+        Identity,
+        AssetIdOf<T>,
+        DelegatedVotingInfo<T::Moment>,
+        ValueQuery,
+    >;
+
     #[pallet::type_value]
     pub fn DefaultForPalletStorageVersion<T: Config>() -> StorageVersion {
         StorageVersion::V1
@@ -179,6 +241,12 @@ pub mod pallet {
         ),
         /// Withdrawn [who, poll, asset, balance]
         Withdrawn(AccountIdOf<T>, H256, AssetIdOf<T>, Balance),
+        /// Delegated [who, delegate, asset, balance]
+        Delegated(AccountIdOf<T>, AccountIdOf<T>, AssetIdOf<T>, Balance),
+        /// Undelegated [who, asset, unlocking_timestamp]
+        Undelegated(AccountIdOf<T>, AssetIdOf<T>, T::Moment),
+        /// Delegated funds withdrawn [who, asset, balance]
+        DelegatedFundsWithdrawn(AccountIdOf<T>, AssetIdOf<T>, Balance),
     }
 
     #[pallet::error]
@@ -215,6 +283,20 @@ pub mod pallet {
         NotVoted,
         /// Unauthorized
         Unauthorized,
+        /// Votes are locked by conviction
+        VotesAreLocked,
+        /// Can't delegate to self
+        CantDelegateToSelf,
+        /// Already delegating
+        AlreadyDelegating,
+        /// Not delegating
+        NotDelegating,
+        /// Delegation already revoked
+        AlreadyUndelegated,
+        /// Delegation is not revoked
+        DelegationIsActive,
+        /// Delegated funds are locked
+        DelegatedFundsAreLocked,
     }
 
     #[pallet::call]
@@ -230,66 +312,13 @@ pub mod pallet {
             number_of_votes: Balance,
         ) -> DispatchResultWithPostInfo {
             let user = ensure_signed(origin)?;
-
-            ensure!(number_of_votes > 0, Error::<T>::InvalidNumberOfVotes);
-
-            let poll_info = <PollData<T>>::get(poll_id).ok_or(Error::<T>::PollDoesNotExist)?;
-            let current_timestamp = Timestamp::<T>::get();
-
-            ensure!(
-                current_timestamp >= poll_info.poll_start_timestamp,
-                Error::<T>::PollIsNotStarted
-            );
-
-            ensure!(
-                current_timestamp <= poll_info.poll_end_timestamp,
-                Error::<T>::PollIsFinished
-            );
-
-            // Check if voting option is valid, if not return error
-            let number_of_options = poll_info.options.len() as u32;
-            ensure!(
-                voting_option <= number_of_options && voting_option > 0u32,
-                Error::<T>::InvalidOption
-            );
-
-            // If already voted for one option, then can't vote for another option. But he can increase the number of votes on first option
-            if let Some(mut voting_info) = <Voting<T>>::get(poll_id, &user) {
-                ensure!(
-                    voting_info.voting_option == voting_option,
-                    Error::<T>::VoteDenied
-                );
-                voting_info.number_of_votes += number_of_votes;
-                <Voting<T>>::insert(poll_id, &user, voting_info);
-            } else {
-                let new_voting_info = VotingInfo {
-                    voting_option,
-                    number_of_votes,
-                    asset_withdrawn: false,
-                };
-                <Voting<T>>::insert(poll_id, &user, new_voting_info);
-            }
-
-            // Transfer asset to pallet
-            T::AssetManager::transfer_from(
-                &poll_info.poll_asset,
-                &user,
-                &Self::account_id(),
-                number_of_votes,
-            )
-            .map_err(|_assets_err| Error::<T>::NotEnoughFunds)?;
-
-            //Emit event
-            Self::deposit_event(Event::<T>::Voted(
+            Self::do_vote(
                 user,
                 poll_id,
                 voting_option,
-                poll_info.poll_asset,
                 number_of_votes,
-            ));
-
-            // Return a successful DispatchResult
-            Ok(().into())
+                VoteConviction::None,
+            )
         }
 
         /// Create poll
@@ -382,6 +411,13 @@ pub mod pallet {
                 !voting_info.asset_withdrawn,
                 Error::<T>::FundsAlreadyWithdrawn
             );
+            ensure!(
+                current_timestamp
+                    > poll_info
+                        .poll_end_timestamp
+                        .saturating_add(Self::conviction_lock_duration(voting_info.conviction)),
+                Error::<T>::VotesAreLocked
+            );
 
             // Withdraw asset
             T::AssetManager::transfer_from(
@@ -405,25 +441,186 @@ pub mod pallet {
             // Return a successful DispatchResult
             Ok(().into())
         }
+
+        /// Voting for option with votes locked past the end of poll by conviction
+        #[transactional]
+        #[pallet::call_index(3)]
+        #[pallet::weight(<T as Config>::WeightInfo::vote())]
+        pub fn vote_with_conviction(
+            origin: OriginFor<T>,
+            poll_id: H256,
+            voting_option: u32,
+            number_of_votes: Balance,
+            conviction: VoteConviction,
+        ) -> DispatchResultWithPostInfo {
+            let user = ensure_signed(origin)?;
+            Self::do_vote(user, poll_id, voting_option, number_of_votes, conviction)
+        }
+
+        /// Delegate voting power in polls with asset to another account
+        #[transactional]
+        #[pallet::call_index(4)]
+        #[pallet::weight(<T as Config>::WeightInfo::delegate())]
+        pub fn delegate(
+            origin: OriginFor<T>,
+            poll_asset: AssetIdOf<T>,
+            delegate: AccountIdOf<T>,
+            amount: Balance,
+            conviction: VoteConviction,
+        ) -> DispatchResultWithPostInfo {
+            let user = ensure_signed(origin)?;
+
+            ensure!(amount > 0, Error::<T>::InvalidNumberOfVotes);
+            ensure!(user != delegate, Error::<T>::CantDelegateToSelf);
+            ensure!(
+                !<Delegations<T>>::contains_key(&user, poll_asset),
+                Error::<T>::AlreadyDelegating
+            );
+
+            // Transfer asset to pallet
+            T::AssetManager::transfer_from(&poll_asset, &user, &Self::account_id(), amount)
+                .map_err(|_assets_err| Error::<T>::NotEnoughFunds)?;
+
+            <DelegatedVotingPower<T>>::mutate(&delegate, poll_asset, |delegated_voting_info| {
+                delegated_voting_info.voting_power = delegated_voting_info
+                    .voting_power
+                    .saturating_add(conviction.votes(amount));
+            });
+            <Delegations<T>>::insert(
+                &user,
+                poll_asset,
+                DelegationInfo {
+                    delegate: delegate.clone(),
+                    amount,
+                    conviction,
+                    unlocking_timestamp: None,
+                },
+            );
+
+            // Emit event
+            Self::deposit_event(Event::<T>::Delegated(user, delegate, poll_asset, amount));
+
+            // Return a successful DispatchResult
+            Ok(().into())
+        }
+
+        /// Revoke delegation, delegated funds are unlocked after polls in which they were used
+        #[pallet::call_index(5)]
+        #[pallet::weight(<T as Config>::WeightInfo::undelegate())]
+        pub fn undelegate(
+            origin: OriginFor<T>,
+            poll_asset: AssetIdOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let user = ensure_signed(origin)?;
+
+            let mut delegation_info =
+                <Delegations<T>>::get(&user, poll_asset).ok_or(Error::<T>::NotDelegating)?;
+            ensure!(
+                delegation_info.unlocking_timestamp.is_none(),
+                Error::<T>::AlreadyUndelegated
+            );
+
+            let mut delegated_voting_info =
+                <DelegatedVotingPower<T>>::get(&delegation_info.delegate, poll_asset);
+            delegated_voting_info.voting_power = delegated_voting_info
+                .voting_power
+                .saturating_sub(delegation_info.conviction.votes(delegation_info.amount));
+
+            let unlocking_timestamp = Timestamp::<T>::get().max(
+                delegated_voting_info
+                    .locked_until
+                    .saturating_add(Self::conviction_lock_duration(delegation_info.conviction)),
+            );
+            delegation_info.unlocking_timestamp = Some(unlocking_timestamp);
+
+            <DelegatedVotingPower<T>>::insert(
+                &delegation_info.delegate,
+                poll_asset,
+                delegated_voting_info,
+            );
+            <Delegations<T>>::insert(&user, poll_asset, delegation_info);
+
+            // Emit event
+            Self::deposit_event(Event::<T>::Undelegated(
+                user,
+                poll_asset,
+                unlocking_timestamp,
+            ));
+
+            // Return a successful DispatchResult
+            Ok(().into())
+        }
+
+        /// Withdraw funds of revoked delegation
+        #[pallet::call_index(6)]
+        #[pallet::weight(<T as Config>::WeightInfo::withdraw_delegated_funds())]
+        pub fn withdraw_delegated_funds(
+            origin: OriginFor<T>,
+            poll_asset: AssetIdOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let user = ensure_signed(origin)?;
+
+            let delegation_info =
+                <Delegations<T>>::get(&user, poll_asset).ok_or(Error::<T>::NotDelegating)?;
+            let unlocking_timestamp = delegation_info
+                .unlocking_timestamp
+                .ok_or(Error::<T>::DelegationIsActive)?;
+            ensure!(
+                Timestamp::<T>::get() > unlocking_timestamp,
+                Error::<T>::DelegatedFundsAreLocked
+            );
+
+            // Withdraw asset
+            T::AssetManager::transfer_from(
+                &poll_asset,
+                &Self::account_id(),
+                &user,
+                delegation_info.amount,
+            )?;
+
+            <Delegations<T>>::remove(&user, poll_asset);
+
+            // Emit event
+            Self::deposit_event(Event::<T>::DelegatedFundsWithdrawn(
+                user,
+                poll_asset,
+                delegation_info.amount,
+            ));
+
+            // Return a successful DispatchResult
+            Ok(().into())
+        }
     }
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
         fn on_runtime_upgrade() -> Weight {
-            if Self::pallet_storage_version() == StorageVersion::V2 {
-                sp_runtime::runtime_logger::RuntimeLogger::init();
-                log::info!(
-                    "Applying migration to version 2: Migrating to open governance - version 3"
-                );
-
-                if let Err(err) = common::with_transaction(migrations::migrate::<T>) {
-                    log::error!("Failed to migrate: {err:?}");
-                } else {
-                    PalletStorageVersion::<T>::put(StorageVersion::V3);
+            match Self::pallet_storage_version() {
+                StorageVersion::V2 => {
+                    sp_runtime::runtime_logger::RuntimeLogger::init();
+                    log::info!(
+                        "Applying migration to version 2: Migrating to open governance - version 3"
+                    );
+
+                    if let Err(err) = common::with_transaction(migrations::migrate::<T>) {
+                        log::error!("Failed to migrate: {err:?}");
+                    } else {
+                        // Votes are migrated straight to the conviction voting layout
+                        PalletStorageVersion::<T>::put(StorageVersion::V4);
+                    }
+                    <T as frame_system::Config>::BlockWeights::get().max_block
                 }
-                <T as frame_system::Config>::BlockWeights::get().max_block
-            } else {
-                Weight::zero()
+                StorageVersion::V3 => {
+                    sp_runtime::runtime_logger::RuntimeLogger::init();
+                    log::info!(
+                        "Applying migration to version 3: Adding conviction voting - version 4"
+                    );
+
+                    let weight = migrations::migrate_to_conviction_voting::<T>();
+                    PalletStorageVersion::<T>::put(StorageVersion::V4);
+                    weight
+                }
+                _ => Weight::zero(),
             }
         }
     }
@@ -433,5 +630,118 @@ pub mod pallet {
         fn account_id() -> T::AccountId {
             PALLET_ID.into_account_truncating()
         }
+
+        /// Duration for which votes with conviction stay locked after poll ends
+        fn conviction_lock_duration(conviction: VoteConviction) -> T::Moment {
+            T::CONVICTION_LOCK_PERIOD.saturating_mul(conviction.lock_periods().into())
+        }
+
+        fn do_vote(
+            user: AccountIdOf<T>,
+            poll_id: H256,
+            voting_option: u32,
+            number_of_votes: Balance,
+            conviction: VoteConviction,
+        ) -> DispatchResultWithPostInfo {
+            ensure!(number_of_votes > 0, Error::<T>::InvalidNumberOfVotes);
+
+            let poll_info = <PollData<T>>::get(poll_id).ok_or(Error::<T>::PollDoesNotExist)?;
+            let current_timestamp = Timestamp::<T>::get();
+
+            ensure!(
+                current_timestamp >= poll_info.poll_start_timestamp,
+                Error::<T>::PollIsNotStarted
+            );
+
+            ensure!(
+                current_timestamp <= poll_info.poll_end_timestamp,
+                Error::<T>::PollIsFinished
+            );
+
+            // Check if voting option is valid, if not return error
+            let number_of_options = poll_info.options.len() as u32;
+            ensure!(
+                voting_option <= number_of_options && voting_option > 0u32,
+                Error::<T>::InvalidOption
+            );
+
+            // If already voted for one option, then can't vote for another option or with another conviction. But he can increase the number of votes on first option
+            if let Some(mut voting_info) = <Voting<T>>::get(poll_id, &user) {
+                ensure!(
+                    voting_info.voting_option == voting_option
+                        && voting_info.conviction == conviction,
+                    Error::<T>::VoteDenied
+                );
+                voting_info.number_of_votes += number_of_votes;
+                voting_info.voting_power = voting_info
+                    .voting_power
+                    .saturating_add(conviction.votes(number_of_votes));
+                <Voting<T>>::insert(poll_id, &user, voting_info);
+            } else {
+                // Delegated voting power is counted on the first vote and stays locked until the poll ends
+                let mut delegated_voting_info =
+                    <DelegatedVotingPower<T>>::get(&user, poll_info.poll_asset);
+                if delegated_voting_info.voting_power > 0 {
+                    delegated_voting_info.locked_until = delegated_voting_info
+                        .locked_until
+                        .max(poll_info.poll_end_timestamp);
+                    <DelegatedVotingPower<T>>::insert(
+                        &user,
+                        poll_info.poll_asset,
+                        &delegated_voting_info,
+                    );
+                }
+
+                let new_voting_info = VotingInfo {
+                    voting_option,
+                    number_of_votes,
+                    asset_withdrawn: false,
+                    conviction,
+                    voting_power: conviction
+                        .votes(number_of_votes)
+                        .saturating_add(delegated_voting_info.voting_power),
+                };
+                <Voting<T>>::insert(poll_id, &user, new_voting_info);
+            }
+
+            // Transfer asset to pallet
+            T::AssetManager::transfer_from(
+                &poll_info.poll_asset,
+                &user,
+                &Self::account_id(),
+                number_of_votes,
+            )
+            .map_err(|_assets_err| Error::<T>::NotEnoughFunds)?;
+
+            //Emit event
+            Self::deposit_event(Event::<T>::Voted(
+                user,
+                poll_id,
+                voting_option,
+                poll_info.poll_asset,
+                number_of_votes,
+            ));
+
+            // Return a successful DispatchResult
+            Ok(().into())
+        }
+
+        /// Votes per option of poll, weighted by conviction and including delegated voting power
+        pub fn poll_tallies(poll_id: H256) -> Vec<(BoundedString<T::StringLimit>, Balance)> {
+            let Some(poll_info) = <PollData<T>>::get(poll_id) else {
+                return Vec::new();
+            };
+
+            let mut votes = vec![0; poll_info.options.len()];
+            for voting_info in <Voting<T>>::iter_prefix_values(poll_id) {
+                if let Some(option_votes) =
+                    votes.get_mut((voting_info.voting_option as usize).wrapping_sub(1))
+                {
+                    *option_votes = option_votes.saturating_add(voting_info.voting_power);
+                }
+            }
+
+            poll_info.options.into_iter().zip(votes).collect()
+        }
     }
 }
@@ -1,7 +1,7 @@
 use crate::*;
 use codec::{Decode, Encode};
 use common::generate_storage_instance;
-use common::{AssetIdOf, CERES_ASSET_ID};
+use common::{AssetIdOf, VoteConviction, CERES_ASSET_ID};
 use frame_support::__private::log;
 use frame_support::pallet_prelude::*;
 use frame_support::BoundedVec;
@@ -179,6 +179,8 @@ pub fn migrate<T: Config>() -> Result<(), sp_runtime::DispatchError> {
                     voting_option: voting_info.voting_option,
                     number_of_votes: voting_info.number_of_votes,
                     asset_withdrawn: voting_info.ceres_withdrawn,
+                    conviction: VoteConviction::None,
+                    voting_power: voting_info.number_of_votes,
                 },
             );
         }
@@ -186,3 +188,32 @@ pub fn migrate<T: Config>() -> Result<(), sp_runtime::DispatchError> {
 
     Ok(())
 }
+
+#[derive(Encode, Decode, Default, PartialEq, Eq, scale_info::TypeInfo)]
+pub struct VotingInfoV3 {
+    pub voting_option: u32,
+    pub number_of_votes: Balance,
+    pub asset_withdrawn: bool,
+}
+
+pub fn migrate_to_conviction_voting<T: Config>() -> Weight {
+    let mut weight: u64 = 0;
+
+    <Voting<T>>::translate_values::<VotingInfoV3, _>(|voting_info| {
+        weight += 1;
+        Some(crate::VotingInfo {
+            voting_option: voting_info.voting_option,
+            number_of_votes: voting_info.number_of_votes,
+            asset_withdrawn: voting_info.asset_withdrawn,
+            conviction: VoteConviction::None,
+            voting_power: voting_info.number_of_votes,
+        })
+    });
+
+    log::info!(
+        target: "runtime",
+        "VotingInfo migrated to conviction voting"
+    );
+
+    T::DbWeight::get().reads_writes(weight, weight)
+}
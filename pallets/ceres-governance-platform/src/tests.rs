@@ -1,9 +1,10 @@
-use crate::migrations::{OldPollInfo, OldVotingInfo};
+use crate::migrations::{OldPollInfo, OldVotingInfo, VotingInfoV3};
 use crate::mock::*;
 use crate::{pallet, Error};
 use codec::{Decode, Encode};
 use common::{
-    balance, generate_storage_instance, AssetInfoProvider, BoundedString, CERES_ASSET_ID,
+    balance, generate_storage_instance, AssetInfoProvider, BoundedString, VoteConviction,
+    CERES_ASSET_ID,
 };
 use frame_support::pallet_prelude::{StorageDoubleMap, StorageMap};
 use frame_support::storage::types::ValueQuery;
@@ -16,6 +17,31 @@ use sp_core::H256;
 use sp_io::hashing::blake2_256;
 use sp_runtime::traits::AccountIdConversion;
 
+fn create_test_poll(poll_end_timestamp: u64) -> H256 {
+    let poll_start_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+    let mut options = BoundedVec::default();
+
+    options.try_push("Option 1".try_into().unwrap()).unwrap();
+    options.try_push("Option 2".try_into().unwrap()).unwrap();
+    options.try_push("Option 3".try_into().unwrap()).unwrap();
+
+    let user = CeresGovernancePlatform::authority_account();
+    let nonce = frame_system::Pallet::<Runtime>::account_nonce(&user);
+    let encoded: [u8; 32] = (&user, nonce).using_encoded(blake2_256);
+
+    assert_ok!(CeresGovernancePlatform::create_poll(
+        RuntimeOrigin::signed(user),
+        CERES_ASSET_ID,
+        poll_start_timestamp,
+        poll_end_timestamp,
+        "Title".try_into().unwrap(),
+        "Description".try_into().unwrap(),
+        options
+    ));
+
+    H256::from(encoded)
+}
+
 #[test]
 fn create_poll_unauthorized_account() {
     let mut ext = ExtBuilder::default().build();
@@ -619,6 +645,320 @@ fn vote_multiple_times_ok() {
     });
 }
 
+#[test]
+fn vote_with_conviction_denied() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let poll_id = create_test_poll(100);
+
+        assert_ok!(CeresGovernancePlatform::vote(
+            RuntimeOrigin::signed(ALICE),
+            poll_id,
+            1u32,
+            balance!(100)
+        ));
+
+        assert_err!(
+            CeresGovernancePlatform::vote_with_conviction(
+                RuntimeOrigin::signed(ALICE),
+                poll_id,
+                1u32,
+                balance!(100),
+                VoteConviction::Locked2x
+            ),
+            Error::<Runtime>::VoteDenied
+        );
+    });
+}
+
+#[test]
+fn vote_with_conviction_ok() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let poll_id = create_test_poll(100);
+
+        assert_ok!(CeresGovernancePlatform::vote_with_conviction(
+            RuntimeOrigin::signed(ALICE),
+            poll_id,
+            2u32,
+            balance!(100),
+            VoteConviction::Locked3x
+        ));
+        assert_ok!(CeresGovernancePlatform::vote_with_conviction(
+            RuntimeOrigin::signed(ALICE),
+            poll_id,
+            2u32,
+            balance!(50),
+            VoteConviction::Locked3x
+        ));
+        assert_ok!(CeresGovernancePlatform::vote(
+            RuntimeOrigin::signed(BOB),
+            poll_id,
+            1u32,
+            balance!(200)
+        ));
+
+        let voting_info = pallet::Voting::<Runtime>::get(poll_id, &ALICE).unwrap();
+        assert_eq!(voting_info.number_of_votes, balance!(150));
+        assert_eq!(voting_info.voting_power, balance!(450));
+
+        let tallies = CeresGovernancePlatform::poll_tallies(poll_id);
+        assert_eq!(
+            tallies,
+            vec![
+                (BoundedString::truncate_from("Option 1"), balance!(200)),
+                (BoundedString::truncate_from("Option 2"), balance!(450)),
+                (BoundedString::truncate_from("Option 3"), 0),
+            ]
+        );
+    });
+}
+
+#[test]
+fn withdraw_votes_are_locked() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let poll_id = create_test_poll(100);
+
+        assert_ok!(CeresGovernancePlatform::vote_with_conviction(
+            RuntimeOrigin::signed(ALICE),
+            poll_id,
+            1u32,
+            balance!(100),
+            VoteConviction::Locked3x
+        ));
+
+        // Locked3x locks votes for two lock periods after the end of poll
+        pallet_timestamp::Pallet::<Runtime>::set_timestamp(120);
+
+        assert_err!(
+            CeresGovernancePlatform::withdraw(RuntimeOrigin::signed(ALICE), poll_id),
+            Error::<Runtime>::VotesAreLocked
+        );
+
+        pallet_timestamp::Pallet::<Runtime>::set_timestamp(121);
+
+        assert_ok!(CeresGovernancePlatform::withdraw(
+            RuntimeOrigin::signed(ALICE),
+            poll_id
+        ));
+    });
+}
+
+#[test]
+fn delegate_cant_delegate_to_self() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        assert_err!(
+            CeresGovernancePlatform::delegate(
+                RuntimeOrigin::signed(ALICE),
+                CERES_ASSET_ID,
+                ALICE,
+                balance!(100),
+                VoteConviction::None
+            ),
+            Error::<Runtime>::CantDelegateToSelf
+        );
+    });
+}
+
+#[test]
+fn delegate_already_delegating() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        assert_ok!(CeresGovernancePlatform::delegate(
+            RuntimeOrigin::signed(BOB),
+            CERES_ASSET_ID,
+            ALICE,
+            balance!(100),
+            VoteConviction::None
+        ));
+
+        assert_err!(
+            CeresGovernancePlatform::delegate(
+                RuntimeOrigin::signed(BOB),
+                CERES_ASSET_ID,
+                ALICE,
+                balance!(100),
+                VoteConviction::None
+            ),
+            Error::<Runtime>::AlreadyDelegating
+        );
+    });
+}
+
+#[test]
+fn delegate_ok() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let poll_id = create_test_poll(100);
+
+        assert_ok!(CeresGovernancePlatform::delegate(
+            RuntimeOrigin::signed(BOB),
+            CERES_ASSET_ID,
+            ALICE,
+            balance!(100),
+            VoteConviction::Locked2x
+        ));
+
+        let pallet_account = PalletId(*b"ceresgov").into_account_truncating();
+        assert_eq!(
+            Assets::free_balance(&CERES_ASSET_ID, &pallet_account)
+                .expect("Failed to query free balance."),
+            balance!(100)
+        );
+        assert_eq!(
+            CeresGovernancePlatform::delegated_voting_power(ALICE, CERES_ASSET_ID).voting_power,
+            balance!(200)
+        );
+
+        assert_ok!(CeresGovernancePlatform::vote(
+            RuntimeOrigin::signed(ALICE),
+            poll_id,
+            3u32,
+            balance!(100)
+        ));
+
+        let voting_info = pallet::Voting::<Runtime>::get(poll_id, &ALICE).unwrap();
+        assert_eq!(voting_info.voting_power, balance!(300));
+        assert_eq!(
+            CeresGovernancePlatform::delegated_voting_power(ALICE, CERES_ASSET_ID).locked_until,
+            100
+        );
+        assert_eq!(
+            CeresGovernancePlatform::poll_tallies(poll_id)[2],
+            (BoundedString::truncate_from("Option 3"), balance!(300))
+        );
+    });
+}
+
+#[test]
+fn undelegate_not_delegating() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        assert_err!(
+            CeresGovernancePlatform::undelegate(RuntimeOrigin::signed(BOB), CERES_ASSET_ID),
+            Error::<Runtime>::NotDelegating
+        );
+    });
+}
+
+#[test]
+fn undelegate_ok() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let poll_id = create_test_poll(100);
+
+        assert_ok!(CeresGovernancePlatform::delegate(
+            RuntimeOrigin::signed(BOB),
+            CERES_ASSET_ID,
+            ALICE,
+            balance!(100),
+            VoteConviction::Locked2x
+        ));
+        assert_ok!(CeresGovernancePlatform::vote(
+            RuntimeOrigin::signed(ALICE),
+            poll_id,
+            1u32,
+            balance!(100)
+        ));
+        assert_ok!(CeresGovernancePlatform::undelegate(
+            RuntimeOrigin::signed(BOB),
+            CERES_ASSET_ID
+        ));
+
+        assert_err!(
+            CeresGovernancePlatform::undelegate(RuntimeOrigin::signed(BOB), CERES_ASSET_ID),
+            Error::<Runtime>::AlreadyUndelegated
+        );
+
+        // Delegated funds used in poll stay locked until its end and one lock period after it
+        let delegation_info = CeresGovernancePlatform::delegations(BOB, CERES_ASSET_ID).unwrap();
+        assert_eq!(delegation_info.unlocking_timestamp, Some(110));
+        assert_eq!(
+            CeresGovernancePlatform::delegated_voting_power(ALICE, CERES_ASSET_ID).voting_power,
+            0
+        );
+
+        // Votes cast with delegated power are kept in tallies
+        assert_eq!(
+            CeresGovernancePlatform::poll_tallies(poll_id)[0],
+            (BoundedString::truncate_from("Option 1"), balance!(300))
+        );
+    });
+}
+
+#[test]
+fn withdraw_delegated_funds_delegation_is_active() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        assert_ok!(CeresGovernancePlatform::delegate(
+            RuntimeOrigin::signed(BOB),
+            CERES_ASSET_ID,
+            ALICE,
+            balance!(100),
+            VoteConviction::None
+        ));
+
+        assert_err!(
+            CeresGovernancePlatform::withdraw_delegated_funds(
+                RuntimeOrigin::signed(BOB),
+                CERES_ASSET_ID
+            ),
+            Error::<Runtime>::DelegationIsActive
+        );
+    });
+}
+
+#[test]
+fn withdraw_delegated_funds_ok() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let poll_id = create_test_poll(100);
+
+        assert_ok!(CeresGovernancePlatform::delegate(
+            RuntimeOrigin::signed(BOB),
+            CERES_ASSET_ID,
+            ALICE,
+            balance!(100),
+            VoteConviction::Locked2x
+        ));
+        assert_ok!(CeresGovernancePlatform::vote(
+            RuntimeOrigin::signed(ALICE),
+            poll_id,
+            1u32,
+            balance!(100)
+        ));
+        assert_ok!(CeresGovernancePlatform::undelegate(
+            RuntimeOrigin::signed(BOB),
+            CERES_ASSET_ID
+        ));
+
+        pallet_timestamp::Pallet::<Runtime>::set_timestamp(110);
+
+        assert_err!(
+            CeresGovernancePlatform::withdraw_delegated_funds(
+                RuntimeOrigin::signed(BOB),
+                CERES_ASSET_ID
+            ),
+            Error::<Runtime>::DelegatedFundsAreLocked
+        );
+
+        pallet_timestamp::Pallet::<Runtime>::set_timestamp(111);
+
+        assert_ok!(CeresGovernancePlatform::withdraw_delegated_funds(
+            RuntimeOrigin::signed(BOB),
+            CERES_ASSET_ID
+        ));
+
+        assert_eq!(
+            Assets::free_balance(&CERES_ASSET_ID, &BOB).expect("Failed to query free balance."),
+            balance!(500)
+        );
+        assert!(CeresGovernancePlatform::delegations(BOB, CERES_ASSET_ID).is_none());
+    });
+}
+
 #[test]
 fn withdraw_poll_does_not_exist() {
     let mut ext = ExtBuilder::default().build();
@@ -906,7 +1246,7 @@ fn ceres_governance_migration_works() {
 
         assert_eq!(
             pallet::Pallet::<Runtime>::pallet_storage_version(),
-            crate::StorageVersion::V3
+            crate::StorageVersion::V4
         );
 
         let nonce_a: <Runtime as frame_system::Config>::Nonce = 305u32.into();
@@ -950,5 +1290,54 @@ fn ceres_governance_migration_works() {
         assert!(!voting_a.asset_withdrawn);
         assert!(voting_b.asset_withdrawn);
         assert!(!voting_c.asset_withdrawn);
+        assert_eq!(voting_a.conviction, VoteConviction::None);
+        assert_eq!(voting_b.voting_power, balance!(69));
+        assert_eq!(voting_c.voting_power, balance!(100));
+    });
+}
+
+#[test]
+fn ceres_governance_conviction_voting_migration_works() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        generate_storage_instance!(CeresGovernancePlatform, Voting);
+
+        type VotingV3 = StorageDoubleMap<
+            VotingOldInstance,
+            Identity,
+            H256,
+            Identity,
+            AccountId,
+            VotingInfoV3,
+            ValueQuery,
+        >;
+
+        let poll_id = H256::from([1u8; 32]);
+        VotingV3::insert(
+            poll_id,
+            &ALICE,
+            VotingInfoV3 {
+                voting_option: 2,
+                number_of_votes: balance!(100),
+                asset_withdrawn: true,
+            },
+        );
+
+        pallet::PalletStorageVersion::<Runtime>::put(crate::StorageVersion::V3);
+
+        //Storage migration
+        pallet::Pallet::<Runtime>::on_runtime_upgrade();
+
+        assert_eq!(
+            pallet::Pallet::<Runtime>::pallet_storage_version(),
+            crate::StorageVersion::V4
+        );
+
+        let voting_info = pallet::Voting::<Runtime>::get(poll_id, &ALICE).unwrap();
+        assert_eq!(voting_info.voting_option, 2u32);
+        assert_eq!(voting_info.number_of_votes, balance!(100));
+        assert!(voting_info.asset_withdrawn);
+        assert_eq!(voting_info.conviction, VoteConviction::None);
+        assert_eq!(voting_info.voting_power, balance!(100));
     });
 }
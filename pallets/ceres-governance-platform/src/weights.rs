@@ -63,6 +63,9 @@ pub trait WeightInfo {
 	fn vote() -> Weight;
 	fn create_poll() -> Weight;
 	fn withdraw() -> Weight;
+	fn delegate() -> Weight;
+	fn undelegate() -> Weight;
+	fn withdraw_delegated_funds() -> Weight;
 }
 
 /// Weights for ceres_governance_platform using the Substrate node and recommended hardware.
@@ -74,6 +77,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	/// Storage: CeresGovernancePlatform Voting (r:1 w:1)
 	/// Proof Skipped: CeresGovernancePlatform Voting (max_values: None, max_size: None, mode: Measured)
+	/// Storage: CeresGovernancePlatform DelegatedVotingPower (r:1 w:1)
+	/// Proof Skipped: CeresGovernancePlatform DelegatedVotingPower (max_values: None, max_size: None, mode: Measured)
 	/// Storage: ExtendedAssets SoulboundAsset (r:1 w:0)
 	/// Proof: ExtendedAssets SoulboundAsset (max_values: None, max_size: Some(322091), added: 324566, mode: MaxEncodedLen)
 	/// Storage: Assets AssetInfosV2 (r:1 w:0)
@@ -84,12 +89,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	fn vote() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `6315`
-		//  Estimated: `361867`
-		// Minimum execution time: 143_519_000 picoseconds.
-		Weight::from_parts(144_361_000, 361867)
-			.saturating_add(T::DbWeight::get().reads(9_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+		//  Measured:  `6471`
+		//  Estimated: `363938`
+		// Minimum execution time: 147_391_000 picoseconds.
+		Weight::from_parts(148_960_000, 363938)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: Timestamp Now (r:1 w:0)
 	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
@@ -131,6 +136,63 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(8_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	/// Storage: CeresGovernancePlatform Delegations (r:1 w:1)
+	/// Proof Skipped: CeresGovernancePlatform Delegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ExtendedAssets SoulboundAsset (r:1 w:0)
+	/// Proof: ExtendedAssets SoulboundAsset (max_values: None, max_size: Some(322091), added: 324566, mode: MaxEncodedLen)
+	/// Storage: Assets AssetInfosV2 (r:1 w:0)
+	/// Proof Skipped: Assets AssetInfosV2 (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: CeresGovernancePlatform DelegatedVotingPower (r:1 w:1)
+	/// Proof Skipped: CeresGovernancePlatform DelegatedVotingPower (max_values: None, max_size: None, mode: Measured)
+	fn delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1613`
+		//  Estimated: `349863`
+		// Minimum execution time: 121_446_000 picoseconds.
+		Weight::from_parts(122_871_000, 349863)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: CeresGovernancePlatform Delegations (r:1 w:1)
+	/// Proof Skipped: CeresGovernancePlatform Delegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: CeresGovernancePlatform DelegatedVotingPower (r:1 w:1)
+	/// Proof Skipped: CeresGovernancePlatform DelegatedVotingPower (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn undelegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3977`
+		// Minimum execution time: 31_504_000 picoseconds.
+		Weight::from_parts(32_011_000, 3977)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: CeresGovernancePlatform Delegations (r:1 w:1)
+	/// Proof Skipped: CeresGovernancePlatform Delegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: ExtendedAssets SoulboundAsset (r:1 w:0)
+	/// Proof: ExtendedAssets SoulboundAsset (max_values: None, max_size: Some(322091), added: 324566, mode: MaxEncodedLen)
+	/// Storage: Assets AssetInfosV2 (r:1 w:0)
+	/// Proof Skipped: Assets AssetInfosV2 (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn withdraw_delegated_funds() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1902`
+		//  Estimated: `346016`
+		// Minimum execution time: 113_905_000 picoseconds.
+		Weight::from_parts(115_027_000, 346016)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -141,6 +203,8 @@ impl WeightInfo for () {
 	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	/// Storage: CeresGovernancePlatform Voting (r:1 w:1)
 	/// Proof Skipped: CeresGovernancePlatform Voting (max_values: None, max_size: None, mode: Measured)
+	/// Storage: CeresGovernancePlatform DelegatedVotingPower (r:1 w:1)
+	/// Proof Skipped: CeresGovernancePlatform DelegatedVotingPower (max_values: None, max_size: None, mode: Measured)
 	/// Storage: ExtendedAssets SoulboundAsset (r:1 w:0)
 	/// Proof: ExtendedAssets SoulboundAsset (max_values: None, max_size: Some(322091), added: 324566, mode: MaxEncodedLen)
 	/// Storage: Assets AssetInfosV2 (r:1 w:0)
@@ -151,12 +215,12 @@ impl WeightInfo for () {
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	fn vote() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `6315`
-		//  Estimated: `361867`
-		// Minimum execution time: 143_519_000 picoseconds.
-		Weight::from_parts(144_361_000, 361867)
-			.saturating_add(RocksDbWeight::get().reads(9_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+		//  Measured:  `6471`
+		//  Estimated: `363938`
+		// Minimum execution time: 147_391_000 picoseconds.
+		Weight::from_parts(148_960_000, 363938)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: Timestamp Now (r:1 w:0)
 	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
@@ -198,4 +262,61 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(8_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+	/// Storage: CeresGovernancePlatform Delegations (r:1 w:1)
+	/// Proof Skipped: CeresGovernancePlatform Delegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ExtendedAssets SoulboundAsset (r:1 w:0)
+	/// Proof: ExtendedAssets SoulboundAsset (max_values: None, max_size: Some(322091), added: 324566, mode: MaxEncodedLen)
+	/// Storage: Assets AssetInfosV2 (r:1 w:0)
+	/// Proof Skipped: Assets AssetInfosV2 (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: CeresGovernancePlatform DelegatedVotingPower (r:1 w:1)
+	/// Proof Skipped: CeresGovernancePlatform DelegatedVotingPower (max_values: None, max_size: None, mode: Measured)
+	fn delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1613`
+		//  Estimated: `349863`
+		// Minimum execution time: 121_446_000 picoseconds.
+		Weight::from_parts(122_871_000, 349863)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: CeresGovernancePlatform Delegations (r:1 w:1)
+	/// Proof Skipped: CeresGovernancePlatform Delegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: CeresGovernancePlatform DelegatedVotingPower (r:1 w:1)
+	/// Proof Skipped: CeresGovernancePlatform DelegatedVotingPower (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn undelegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3977`
+		// Minimum execution time: 31_504_000 picoseconds.
+		Weight::from_parts(32_011_000, 3977)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: CeresGovernancePlatform Delegations (r:1 w:1)
+	/// Proof Skipped: CeresGovernancePlatform Delegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: ExtendedAssets SoulboundAsset (r:1 w:0)
+	/// Proof: ExtendedAssets SoulboundAsset (max_values: None, max_size: Some(322091), added: 324566, mode: MaxEncodedLen)
+	/// Storage: Assets AssetInfosV2 (r:1 w:0)
+	/// Proof Skipped: Assets AssetInfosV2 (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn withdraw_delegated_funds() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1902`
+		//  Estimated: `346016`
+		// Minimum execution time: 113_905_000 picoseconds.
+		Weight::from_parts(115_027_000, 346016)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
 }
@@ -613,6 +613,58 @@ benchmarks! {
         assert_last_event::<T>(Event::<T>::RemovedWhitelistedIloOrganizer(ilo_organizer).into());
     }
 
+    set_sale_format {
+        let caller = alice::<T>();
+        frame_system::Pallet::<T>::inc_providers(&caller);
+        let current_timestamp = Timestamp::<T>::get();
+        let sale_format = SaleFormat::DutchAuction { reserve_price: balance!(0.1) };
+
+        let asset_id = AssetIdOf::<T>::from(CERES_ASSET_ID);
+        let asset_owner = <T as Config>::AssetInfoProvider::get_asset_owner(&asset_id).unwrap();
+
+        T::AssetManager::mint(
+            RawOrigin::Signed(asset_owner).into(),
+            CERES_ASSET_ID.into(),
+            caller.clone(),
+            balance!(20000)
+        ).unwrap();
+
+        CeresLaunchpad::<T>::add_whitelisted_ilo_organizer(
+            RawOrigin::Signed(pallet::AuthorityAccount::<T>::get()).into(),
+            caller.clone()
+        ).unwrap();
+
+        // Create ILO
+        CeresLaunchpad::<T>::create_ilo(
+            RawOrigin::Signed(caller.clone()).into(),
+            XOR.into(),
+            CERES_ASSET_ID.into(),
+            balance!(7693),
+            balance!(3000),
+            balance!(0.13),
+            balance!(600),
+            balance!(1000),
+            balance!(0.2),
+            balance!(0.25),
+            true,
+            balance!(0.75),
+            balance!(0.25),
+            31,
+            current_timestamp + 5u32.into(),
+            current_timestamp + 10u32.into(),
+            balance!(1000),
+            balance!(0.2),
+            current_timestamp + 3u32.into(),
+            balance!(0.2),
+            balance!(0.2),
+            current_timestamp + 3u32.into(),
+            balance!(0.2)
+        ).unwrap();
+    }: _(RawOrigin::Signed(caller.clone()), CERES_ASSET_ID.into(), sale_format)
+    verify {
+        assert_last_event::<T>(Event::<T>::SaleFormatChanged(caller, CERES_ASSET_ID.into()).into());
+    }
+
     impl_benchmark_test_suite!(
         Pallet,
         crate::mock::ExtBuilder::benchmarking().build(),
@@ -12,8 +12,9 @@ mod tests;
 
 mod benchmarking;
 
-use codec::{Decode, Encode};
+use codec::{Decode, DecodeWithMemTracking, Encode};
 use common::TradingPairSourceManager;
+use sp_runtime::RuntimeDebug;
 pub use weights::WeightInfo;
 
 #[derive(Encode, Decode, Default, PartialEq, Eq, scale_info::TypeInfo)]
@@ -72,13 +73,46 @@ pub struct ContributionInfo<Balance> {
     number_of_claims: u32,
 }
 
+/// Pricing rule applied to contributions and to the outcome of an ILO
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    scale_info::TypeInfo,
+)]
+pub enum SaleFormat<Balance> {
+    /// Tokens are sold at `ilo_price` until the hard cap is hit
+    #[default]
+    FixedPrice,
+    /// Price descends linearly from `ilo_price` to `reserve_price` over the sale and every
+    /// contributor pays the clearing price
+    DutchAuction { reserve_price: Balance },
+    /// Contributions above the hard cap are accepted and refunded pro-rata when ILO is finished
+    Overflow,
+}
+
+/// Outcome of a finished ILO which wasn't sold at a fixed price
+#[derive(Encode, Decode, Default, PartialEq, Eq, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct SaleSettlement<Balance> {
+    price: Balance,
+    accepted_funds: Balance,
+    total_funds: Balance,
+}
+
 pub use pallet::*;
 
 #[frame_support::pallet]
 #[allow(clippy::too_many_arguments)]
 pub mod pallet {
     use super::*;
-    use crate::{ContributionInfo, ContributorsVesting, ILOInfo};
+    use crate::{ContributionInfo, ContributorsVesting, ILOInfo, SaleFormat, SaleSettlement};
     use common::fixnum::ops::RoundMode;
     use common::prelude::{Balance, FixedWrapper, XOR};
     use common::{
@@ -233,6 +267,18 @@ pub mod pallet {
     #[pallet::getter(fn whitelisted_ilo_organizers)]
     pub type WhitelistedIloOrganizers<T: Config> = StorageValue<_, Vec<AccountIdOf<T>>, ValueQuery>;
 
+    /// Sale format of ILO, fixed price if not set
+    #[pallet::storage]
+    #[pallet::getter(fn sale_formats)]
+    pub type SaleFormats<T: Config> =
+        StorageMap<_, Identity, AssetIdOf<T>, SaleFormat<Balance>, ValueQuery>;
+
+    /// Clearing price and accepted funds of finished ILOs which weren't sold at a fixed price
+    #[pallet::storage]
+    #[pallet::getter(fn sale_settlements)]
+    pub type SaleSettlements<T: Config> =
+        StorageMap<_, Identity, AssetIdOf<T>, SaleSettlement<Balance>, OptionQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub (super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -260,6 +306,10 @@ pub mod pallet {
         RemovedWhitelistedContributor(AccountIdOf<T>),
         /// ILO organizer removed [who]
         RemovedWhitelistedIloOrganizer(AccountIdOf<T>),
+        /// Sale format changed [who, what]
+        SaleFormatChanged(AccountIdOf<T>, AssetIdOf<T>),
+        /// Oversubscribed funds refunded [who, what, balance]
+        Refunded(AccountIdOf<T>, AssetIdOf<T>, Balance),
     }
 
     #[pallet::error]
@@ -348,6 +398,10 @@ pub mod pallet {
         InvalidFeePercent,
         /// Asset in which funds are being raised is not supported
         BaseAssetNotSupported,
+        /// Reserve price must be greater than zero and lower than ILO price
+        InvalidSaleFormat,
+        /// Sale format can't be changed after ILO has started
+        CantChangeSaleFormat,
     }
 
     #[pallet::call]
@@ -566,14 +620,23 @@ pub mod pallet {
                     <= ilo_info.max_contribution,
                 Error::<T>::ContributionIsBiggerThenMax
             );
-            ensure!(
-                ilo_info.funds_raised + funds_to_contribute <= ilo_info.hard_cap,
-                Error::<T>::HardCapIsHit
-            );
+
+            let sale_format = <SaleFormats<T>>::get(asset_id);
+            if sale_format != SaleFormat::Overflow {
+                ensure!(
+                    ilo_info.funds_raised + funds_to_contribute
+                        <= Self::funds_cap(&ilo_info, &sale_format, current_timestamp),
+                    Error::<T>::HardCapIsHit
+                );
+            }
 
             // Calculate amount of bought tokens
             let tokens_bought = (FixedWrapper::from(funds_to_contribute)
-                / FixedWrapper::from(ilo_info.ilo_price))
+                / FixedWrapper::from(Self::current_price(
+                    &ilo_info,
+                    &sale_format,
+                    current_timestamp,
+                )))
             .try_into_balance()
             .unwrap_or(0);
 
@@ -691,9 +754,12 @@ pub mod pallet {
 
             // Get current timestamp
             let current_timestamp = Timestamp::<T>::get();
+            let sale_format = <SaleFormats<T>>::get(asset_id);
             ensure!(
                 current_timestamp > ilo_info.end_timestamp
-                    || ilo_info.funds_raised == ilo_info.hard_cap,
+                    || (sale_format != SaleFormat::Overflow
+                        && ilo_info.funds_raised
+                            >= Self::funds_cap(&ilo_info, &sale_format, current_timestamp)),
                 Error::<T>::ILOIsNotFinished
             );
             ensure!(!ilo_info.failed, Error::<T>::ILOIsFailed);
@@ -724,6 +790,26 @@ pub mod pallet {
                 return Ok(().into());
             }
 
+            // Settle price and accepted funds for auction and overflow sales
+            let mut listing_price = ilo_info.listing_price;
+            if let Some(settlement) = Self::settle_sale(&ilo_info, &sale_format) {
+                ilo_info.funds_raised = settlement.accepted_funds;
+                ilo_info.sold_tokens = (FixedWrapper::from(settlement.accepted_funds)
+                    / FixedWrapper::from(settlement.price))
+                .try_into_balance()
+                .unwrap_or(0)
+                .min(ilo_info.tokens_for_ilo);
+                // Tokens are listed with the same premium over the clearing price as over ILO price
+                if settlement.price != ilo_info.ilo_price {
+                    listing_price = (FixedWrapper::from(ilo_info.listing_price)
+                        * FixedWrapper::from(settlement.price)
+                        / FixedWrapper::from(ilo_info.ilo_price))
+                    .try_into_balance()
+                    .unwrap_or(ilo_info.listing_price);
+                }
+                <SaleSettlements<T>>::insert(asset_id, settlement);
+            }
+
             // Transfer fee to authority account
             let funds_raised_fee = (FixedWrapper::from(ilo_info.funds_raised)
                 * FixedWrapper::from(FeePercentOnRaisedFunds::<T>::get()))
@@ -772,7 +858,7 @@ pub mod pallet {
 
             // Deposit liquidity
             let tokens_for_liquidity = (FixedWrapper::from(funds_for_liquidity)
-                / FixedWrapper::from(ilo_info.listing_price))
+                / FixedWrapper::from(listing_price))
             .try_into_balance()
             .unwrap_or(0);
             ensure!(
@@ -980,6 +1066,33 @@ pub mod pallet {
                 )?;
                 contribution_info.claiming_finished = true;
             } else {
+                // Settle contribution against clearing price on the first claim
+                if contribution_info.tokens_claimed.is_zero() {
+                    if let Some(settlement) = <SaleSettlements<T>>::get(asset_id) {
+                        let refund = Self::settle_contribution(&settlement, &mut contribution_info);
+                        if !refund.is_zero() {
+                            T::AssetManager::transfer_from(
+                                &ilo_info.base_asset,
+                                &pallet_account,
+                                &user,
+                                refund,
+                            )?;
+                            Self::deposit_event(Event::<T>::Refunded(
+                                user.clone(),
+                                asset_id,
+                                refund,
+                            ));
+                        }
+
+                        if contribution_info.tokens_bought.is_zero() {
+                            contribution_info.claiming_finished = true;
+                            <Contributions<T>>::insert(asset_id, &user, contribution_info);
+                            Self::deposit_event(Event::Claimed(user, asset_id));
+                            return Ok(().into());
+                        }
+                    }
+                }
+
                 let first_release_percent = ilo_info.contributors_vesting.first_release_percent;
                 let claimable = if first_release_percent == balance!(1) {
                     contribution_info
@@ -1230,6 +1343,43 @@ pub mod pallet {
 
             Ok(().into())
         }
+
+        /// Change sale format of ILO before it starts
+        #[pallet::call_index(14)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_sale_format())]
+        pub fn set_sale_format(
+            origin: OriginFor<T>,
+            asset_id: AssetIdOf<T>,
+            sale_format: SaleFormat<Balance>,
+        ) -> DispatchResultWithPostInfo {
+            let user = ensure_signed(origin)?;
+
+            // Get ILO info
+            let ilo_info = <ILOs<T>>::get(asset_id).ok_or(Error::<T>::ILODoesNotExist)?;
+
+            if user != ilo_info.ilo_organizer {
+                return Err(Error::<T>::Unauthorized.into());
+            }
+
+            ensure!(
+                Timestamp::<T>::get() <= ilo_info.start_timestamp,
+                Error::<T>::CantChangeSaleFormat
+            );
+
+            if let SaleFormat::DutchAuction { reserve_price } = sale_format {
+                ensure!(
+                    !reserve_price.is_zero() && reserve_price < ilo_info.ilo_price,
+                    Error::<T>::InvalidSaleFormat
+                );
+            }
+
+            <SaleFormats<T>>::insert(asset_id, sale_format);
+
+            // Emit an event
+            Self::deposit_event(Event::SaleFormatChanged(user, asset_id));
+
+            Ok(().into())
+        }
     }
 
     #[pallet::hooks]
@@ -1289,6 +1439,134 @@ pub mod pallet {
             PALLET_ID.into_account_truncating()
         }
 
+        /// Price at which tokens are currently bought
+        fn current_price(
+            ilo_info: &ILOInfo<Balance, AccountIdOf<T>, T::Moment, AssetIdOf<T>>,
+            sale_format: &SaleFormat<Balance>,
+            current_timestamp: T::Moment,
+        ) -> Balance {
+            match sale_format {
+                SaleFormat::DutchAuction { reserve_price } => {
+                    let duration: u128 = ilo_info
+                        .end_timestamp
+                        .saturating_sub(ilo_info.start_timestamp)
+                        .unique_saturated_into();
+                    let elapsed: u128 = current_timestamp
+                        .saturating_sub(ilo_info.start_timestamp)
+                        .unique_saturated_into();
+                    let elapsed = elapsed.min(duration);
+                    let price_drop = ilo_info
+                        .ilo_price
+                        .saturating_sub(*reserve_price)
+                        .saturating_mul(elapsed)
+                        .checked_div(duration)
+                        .unwrap_or(0);
+                    ilo_info.ilo_price.saturating_sub(price_drop)
+                }
+                SaleFormat::FixedPrice | SaleFormat::Overflow => ilo_info.ilo_price,
+            }
+        }
+
+        /// Maximum funds ILO can currently raise
+        fn funds_cap(
+            ilo_info: &ILOInfo<Balance, AccountIdOf<T>, T::Moment, AssetIdOf<T>>,
+            sale_format: &SaleFormat<Balance>,
+            current_timestamp: T::Moment,
+        ) -> Balance {
+            match sale_format {
+                SaleFormat::DutchAuction { .. } => {
+                    (FixedWrapper::from(Self::current_price(
+                        ilo_info,
+                        sale_format,
+                        current_timestamp,
+                    )) * FixedWrapper::from(ilo_info.tokens_for_ilo))
+                    .try_into_balance()
+                    .unwrap_or(0)
+                    .min(ilo_info.hard_cap)
+                }
+                SaleFormat::FixedPrice | SaleFormat::Overflow => ilo_info.hard_cap,
+            }
+        }
+
+        /// Clearing price and accepted funds of successful ILO
+        fn settle_sale(
+            ilo_info: &ILOInfo<Balance, AccountIdOf<T>, T::Moment, AssetIdOf<T>>,
+            sale_format: &SaleFormat<Balance>,
+        ) -> Option<SaleSettlement<Balance>> {
+            let total_funds = ilo_info.funds_raised;
+            match sale_format {
+                SaleFormat::FixedPrice => None,
+                SaleFormat::DutchAuction { reserve_price } => {
+                    // Round clearing price up so sold tokens never exceed tokens for ILO
+                    let mut price = (FixedWrapper::from(total_funds)
+                        / FixedWrapper::from(ilo_info.tokens_for_ilo))
+                    .try_into_balance()
+                    .unwrap_or(0);
+                    let covered_funds = (FixedWrapper::from(price)
+                        * FixedWrapper::from(ilo_info.tokens_for_ilo))
+                    .try_into_balance()
+                    .unwrap_or(0);
+                    if covered_funds < total_funds {
+                        price += 1;
+                    }
+
+                    Some(SaleSettlement {
+                        price: price.max(*reserve_price),
+                        accepted_funds: total_funds,
+                        total_funds,
+                    })
+                }
+                SaleFormat::Overflow => Some(SaleSettlement {
+                    price: ilo_info.ilo_price,
+                    accepted_funds: total_funds.min(ilo_info.hard_cap),
+                    total_funds,
+                }),
+            }
+        }
+
+        /// Applies clearing price and pro-rata acceptance to contribution, returning the refund
+        fn settle_contribution(
+            settlement: &SaleSettlement<Balance>,
+            contribution_info: &mut ContributionInfo<Balance>,
+        ) -> Balance {
+            let accepted_funds = if settlement.accepted_funds == settlement.total_funds {
+                contribution_info.funds_contributed
+            } else {
+                (FixedWrapper::from(contribution_info.funds_contributed)
+                    * FixedWrapper::from(settlement.accepted_funds)
+                    / FixedWrapper::from(settlement.total_funds))
+                .try_into_balance()
+                .unwrap_or(0)
+            };
+            // Refund is rounded down on its own, so refunds never exceed the funds left over
+            let refund = if settlement.accepted_funds == settlement.total_funds {
+                0
+            } else {
+                (FixedWrapper::from(contribution_info.funds_contributed)
+                    * FixedWrapper::from(
+                        settlement
+                            .total_funds
+                            .saturating_sub(settlement.accepted_funds),
+                    )
+                    / FixedWrapper::from(settlement.total_funds))
+                .try_into_balance()
+                .unwrap_or(0)
+                .min(
+                    contribution_info
+                        .funds_contributed
+                        .saturating_sub(accepted_funds),
+                )
+            };
+
+            contribution_info.funds_contributed = accepted_funds;
+            contribution_info.tokens_bought = (FixedWrapper::from(accepted_funds)
+                / FixedWrapper::from(settlement.price))
+            .try_into_balance()
+            .unwrap_or(0);
+
+            refund
+        }
+
         fn vesting_unlocks_count(first_release_percent: Balance, vesting_percent: Balance) -> u32 {
             if first_release_percent == balance!(1) {
                 0
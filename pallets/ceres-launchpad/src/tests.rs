@@ -1,5 +1,5 @@
 use crate::mock::*;
-use crate::{pallet, Error, FeePercentOnRaisedFunds, Pallet as CeresLaunchpadPallet};
+use crate::{pallet, Error, FeePercentOnRaisedFunds, Pallet as CeresLaunchpadPallet, SaleFormat};
 use common::fixnum::ops::CheckedAdd;
 use common::prelude::FixedWrapper;
 use common::{
@@ -3889,3 +3889,485 @@ fn create_ilo_tokens_for_ilo_overflow() {
         );
     });
 }
+
+#[test]
+fn set_sale_format_unauthorized() {
+    preset_initial(|| {
+        let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+        assert_ok!(CeresLaunchpadPallet::<Runtime>::create_ilo(
+            RuntimeOrigin::signed(ALICE),
+            XOR,
+            CERES_ASSET_ID,
+            balance!(7693),
+            balance!(3000),
+            balance!(0.13),
+            balance!(600),
+            balance!(1000),
+            balance!(0.2),
+            balance!(1500),
+            false,
+            balance!(0.75),
+            balance!(0.25),
+            31,
+            current_timestamp + 5,
+            current_timestamp + 10,
+            balance!(1000),
+            balance!(0.2),
+            current_timestamp + 3,
+            balance!(0.2),
+            balance!(1),
+            current_timestamp + 3,
+            balance!(0)
+        ));
+
+        assert_err!(
+            CeresLaunchpadPallet::<Runtime>::set_sale_format(
+                RuntimeOrigin::signed(BOB),
+                CERES_ASSET_ID,
+                SaleFormat::Overflow
+            ),
+            Error::<Runtime>::Unauthorized
+        );
+    });
+}
+
+#[test]
+fn set_sale_format_invalid_sale_format() {
+    preset_initial(|| {
+        let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+        assert_ok!(CeresLaunchpadPallet::<Runtime>::create_ilo(
+            RuntimeOrigin::signed(ALICE),
+            XOR,
+            CERES_ASSET_ID,
+            balance!(7693),
+            balance!(3000),
+            balance!(0.13),
+            balance!(600),
+            balance!(1000),
+            balance!(0.2),
+            balance!(1500),
+            false,
+            balance!(0.75),
+            balance!(0.25),
+            31,
+            current_timestamp + 5,
+            current_timestamp + 10,
+            balance!(1000),
+            balance!(0.2),
+            current_timestamp + 3,
+            balance!(0.2),
+            balance!(1),
+            current_timestamp + 3,
+            balance!(0)
+        ));
+
+        assert_err!(
+            CeresLaunchpadPallet::<Runtime>::set_sale_format(
+                RuntimeOrigin::signed(ALICE),
+                CERES_ASSET_ID,
+                SaleFormat::DutchAuction {
+                    reserve_price: balance!(0)
+                }
+            ),
+            Error::<Runtime>::InvalidSaleFormat
+        );
+
+        assert_err!(
+            CeresLaunchpadPallet::<Runtime>::set_sale_format(
+                RuntimeOrigin::signed(ALICE),
+                CERES_ASSET_ID,
+                SaleFormat::DutchAuction {
+                    reserve_price: balance!(0.13)
+                }
+            ),
+            Error::<Runtime>::InvalidSaleFormat
+        );
+    });
+}
+
+#[test]
+fn set_sale_format_cant_change_sale_format() {
+    preset_initial(|| {
+        let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+        assert_ok!(CeresLaunchpadPallet::<Runtime>::create_ilo(
+            RuntimeOrigin::signed(ALICE),
+            XOR,
+            CERES_ASSET_ID,
+            balance!(7693),
+            balance!(3000),
+            balance!(0.13),
+            balance!(600),
+            balance!(1000),
+            balance!(0.2),
+            balance!(1500),
+            false,
+            balance!(0.75),
+            balance!(0.25),
+            31,
+            current_timestamp + 5,
+            current_timestamp + 10,
+            balance!(1000),
+            balance!(0.2),
+            current_timestamp + 3,
+            balance!(0.2),
+            balance!(1),
+            current_timestamp + 3,
+            balance!(0)
+        ));
+
+        pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 6);
+
+        assert_err!(
+            CeresLaunchpadPallet::<Runtime>::set_sale_format(
+                RuntimeOrigin::signed(ALICE),
+                CERES_ASSET_ID,
+                SaleFormat::Overflow
+            ),
+            Error::<Runtime>::CantChangeSaleFormat
+        );
+    });
+}
+
+#[test]
+fn set_sale_format_ok() {
+    preset_initial(|| {
+        let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+        assert_ok!(CeresLaunchpadPallet::<Runtime>::create_ilo(
+            RuntimeOrigin::signed(ALICE),
+            XOR,
+            CERES_ASSET_ID,
+            balance!(7693),
+            balance!(3000),
+            balance!(0.13),
+            balance!(600),
+            balance!(1000),
+            balance!(0.2),
+            balance!(1500),
+            false,
+            balance!(0.75),
+            balance!(0.25),
+            31,
+            current_timestamp + 5,
+            current_timestamp + 10,
+            balance!(1000),
+            balance!(0.2),
+            current_timestamp + 3,
+            balance!(0.2),
+            balance!(1),
+            current_timestamp + 3,
+            balance!(0)
+        ));
+
+        assert_eq!(
+            pallet::SaleFormats::<Runtime>::get(CERES_ASSET_ID),
+            SaleFormat::FixedPrice
+        );
+
+        let sale_format = SaleFormat::DutchAuction {
+            reserve_price: balance!(0.1),
+        };
+        assert_ok!(CeresLaunchpadPallet::<Runtime>::set_sale_format(
+            RuntimeOrigin::signed(ALICE),
+            CERES_ASSET_ID,
+            sale_format
+        ));
+
+        assert_eq!(
+            pallet::SaleFormats::<Runtime>::get(CERES_ASSET_ID),
+            sale_format
+        );
+    });
+}
+
+#[test]
+fn dutch_auction_sold_out_ok() {
+    preset_initial(|| {
+        let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+        assert_ok!(CeresLaunchpadPallet::<Runtime>::create_ilo(
+            RuntimeOrigin::signed(ALICE),
+            XOR,
+            CERES_ASSET_ID,
+            balance!(7693),
+            balance!(3000),
+            balance!(0.13),
+            balance!(600),
+            balance!(1000),
+            balance!(0.2),
+            balance!(1500),
+            false,
+            balance!(0.75),
+            balance!(0.25),
+            31,
+            current_timestamp + 5,
+            current_timestamp + 105,
+            balance!(1000),
+            balance!(0.2),
+            current_timestamp + 3,
+            balance!(0.2),
+            balance!(1),
+            current_timestamp + 3,
+            balance!(0)
+        ));
+
+        assert_ok!(CeresLaunchpadPallet::<Runtime>::set_sale_format(
+            RuntimeOrigin::signed(ALICE),
+            CERES_ASSET_ID,
+            SaleFormat::DutchAuction {
+                reserve_price: balance!(0.1)
+            }
+        ));
+
+        // Halfway through the sale price has dropped to 0.115
+        pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 55);
+
+        assert_err!(
+            CeresLaunchpadPallet::<Runtime>::contribute(
+                RuntimeOrigin::signed(CHARLES),
+                CERES_ASSET_ID,
+                balance!(885)
+            ),
+            Error::<Runtime>::HardCapIsHit
+        );
+
+        assert_ok!(CeresLaunchpadPallet::<Runtime>::contribute(
+            RuntimeOrigin::signed(CHARLES),
+            CERES_ASSET_ID,
+            balance!(884.695)
+        ));
+
+        let contribution_info = pallet::Contributions::<Runtime>::get(CERES_ASSET_ID, CHARLES);
+        assert_eq!(contribution_info.tokens_bought, balance!(7693));
+
+        assert_ok!(CeresLaunchpadPallet::<Runtime>::finish_ilo(
+            RuntimeOrigin::signed(ALICE),
+            CERES_ASSET_ID
+        ));
+
+        let settlement = pallet::SaleSettlements::<Runtime>::get(CERES_ASSET_ID).unwrap();
+        assert_eq!(settlement.price, balance!(0.115));
+
+        let ilo_info = pallet::ILOs::<Runtime>::get(CERES_ASSET_ID).unwrap();
+        assert!(ilo_info.succeeded);
+        assert_eq!(ilo_info.funds_raised, balance!(884.695));
+        assert_eq!(ilo_info.sold_tokens, balance!(7693));
+
+        assert_ok!(CeresLaunchpadPallet::<Runtime>::claim(
+            RuntimeOrigin::signed(CHARLES),
+            CERES_ASSET_ID,
+        ));
+
+        assert_eq!(
+            Assets::free_balance(&CERES_ASSET_ID, &CHARLES).expect("Failed to query free balance."),
+            balance!(5000) + balance!(7693)
+        );
+        assert_eq!(
+            Assets::free_balance(&XOR, &CHARLES).expect("Failed to query free balance."),
+            balance!(2000) - balance!(884.695)
+        );
+    });
+}
+
+#[test]
+fn dutch_auction_settles_at_reserve_price() {
+    preset_initial(|| {
+        let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+        assert_ok!(CeresLaunchpadPallet::<Runtime>::create_ilo(
+            RuntimeOrigin::signed(ALICE),
+            XOR,
+            CERES_ASSET_ID,
+            balance!(7693),
+            balance!(3000),
+            balance!(0.13),
+            balance!(600),
+            balance!(1000),
+            balance!(0.2),
+            balance!(1500),
+            false,
+            balance!(0.75),
+            balance!(0.25),
+            31,
+            current_timestamp + 5,
+            current_timestamp + 105,
+            balance!(1000),
+            balance!(0.2),
+            current_timestamp + 3,
+            balance!(0.2),
+            balance!(1),
+            current_timestamp + 3,
+            balance!(0)
+        ));
+
+        assert_ok!(CeresLaunchpadPallet::<Runtime>::set_sale_format(
+            RuntimeOrigin::signed(ALICE),
+            CERES_ASSET_ID,
+            SaleFormat::DutchAuction {
+                reserve_price: balance!(0.1)
+            }
+        ));
+
+        pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 55);
+
+        assert_ok!(CeresLaunchpadPallet::<Runtime>::contribute(
+            RuntimeOrigin::signed(CHARLES),
+            CERES_ASSET_ID,
+            balance!(700)
+        ));
+
+        assert_err!(
+            CeresLaunchpadPallet::<Runtime>::finish_ilo(
+                RuntimeOrigin::signed(ALICE),
+                CERES_ASSET_ID
+            ),
+            Error::<Runtime>::ILOIsNotFinished
+        );
+
+        pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 106);
+
+        assert_ok!(CeresLaunchpadPallet::<Runtime>::finish_ilo(
+            RuntimeOrigin::signed(ALICE),
+            CERES_ASSET_ID
+        ));
+
+        let settlement = pallet::SaleSettlements::<Runtime>::get(CERES_ASSET_ID).unwrap();
+        assert_eq!(settlement.price, balance!(0.1));
+
+        let ilo_info = pallet::ILOs::<Runtime>::get(CERES_ASSET_ID).unwrap();
+        assert_eq!(ilo_info.sold_tokens, balance!(7000));
+
+        // Pool is seeded with the listing premium over the clearing price
+        let (xor_liq, ceres_liq) = pool_xyk::Reserves::<Runtime>::get(XOR, CERES_ASSET_ID);
+        let pool_price = (FixedWrapper::from(xor_liq) / FixedWrapper::from(ceres_liq))
+            .try_into_balance()
+            .unwrap();
+        let listing_price = (FixedWrapper::from(balance!(0.2)) * FixedWrapper::from(balance!(0.1))
+            / FixedWrapper::from(balance!(0.13)))
+        .try_into_balance()
+        .unwrap();
+        assert!(pool_price.abs_diff(listing_price) < balance!(0.000001));
+
+        assert_ok!(CeresLaunchpadPallet::<Runtime>::claim(
+            RuntimeOrigin::signed(CHARLES),
+            CERES_ASSET_ID,
+        ));
+
+        let contribution_info = pallet::Contributions::<Runtime>::get(CERES_ASSET_ID, CHARLES);
+        assert_eq!(contribution_info.tokens_bought, balance!(7000));
+        assert!(contribution_info.claiming_finished);
+        assert_eq!(
+            Assets::free_balance(&CERES_ASSET_ID, &CHARLES).expect("Failed to query free balance."),
+            balance!(5000) + balance!(7000)
+        );
+    });
+}
+
+#[test]
+fn overflow_sale_refunds_pro_rata_ok() {
+    preset_initial(|| {
+        let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+        assert_ok!(CeresLaunchpadPallet::<Runtime>::create_ilo(
+            RuntimeOrigin::signed(ALICE),
+            XOR,
+            CERES_ASSET_ID,
+            balance!(7693),
+            balance!(3000),
+            balance!(0.13),
+            balance!(600),
+            balance!(1000),
+            balance!(0.2),
+            balance!(1500),
+            false,
+            balance!(0.75),
+            balance!(0.25),
+            31,
+            current_timestamp + 5,
+            current_timestamp + 10,
+            balance!(1000),
+            balance!(0.2),
+            current_timestamp + 3,
+            balance!(0.2),
+            balance!(1),
+            current_timestamp + 3,
+            balance!(0)
+        ));
+
+        assert_ok!(CeresLaunchpadPallet::<Runtime>::set_sale_format(
+            RuntimeOrigin::signed(ALICE),
+            CERES_ASSET_ID,
+            SaleFormat::Overflow
+        ));
+
+        assert_ok!(assets::Pallet::<Runtime>::mint_to(
+            &XOR,
+            &ALICE,
+            &DAN,
+            balance!(500)
+        ));
+
+        pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 6);
+
+        assert_ok!(CeresLaunchpadPallet::<Runtime>::contribute(
+            RuntimeOrigin::signed(CHARLES),
+            CERES_ASSET_ID,
+            balance!(1500)
+        ));
+        assert_ok!(CeresLaunchpadPallet::<Runtime>::contribute(
+            RuntimeOrigin::signed(DAN),
+            CERES_ASSET_ID,
+            balance!(500)
+        ));
+
+        // Oversubscribed sale can only be finished after it ends
+        assert_err!(
+            CeresLaunchpadPallet::<Runtime>::finish_ilo(
+                RuntimeOrigin::signed(ALICE),
+                CERES_ASSET_ID
+            ),
+            Error::<Runtime>::ILOIsNotFinished
+        );
+
+        pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 11);
+
+        assert_ok!(CeresLaunchpadPallet::<Runtime>::finish_ilo(
+            RuntimeOrigin::signed(ALICE),
+            CERES_ASSET_ID
+        ));
+
+        let ilo_info = pallet::ILOs::<Runtime>::get(CERES_ASSET_ID).unwrap();
+        assert_eq!(ilo_info.funds_raised, balance!(1000));
+        assert_eq!(
+            ilo_info.sold_tokens,
+            (FixedWrapper::from(balance!(1000)) / FixedWrapper::from(balance!(0.13)))
+                .try_into_balance()
+                .unwrap()
+        );
+
+        assert_ok!(CeresLaunchpadPallet::<Runtime>::claim(
+            RuntimeOrigin::signed(CHARLES),
+            CERES_ASSET_ID,
+        ));
+        assert_ok!(CeresLaunchpadPallet::<Runtime>::claim(
+            RuntimeOrigin::signed(DAN),
+            CERES_ASSET_ID,
+        ));
+
+        assert_eq!(
+            Assets::free_balance(&XOR, &CHARLES).expect("Failed to query free balance."),
+            balance!(2000) - balance!(1500) + balance!(750)
+        );
+        assert_eq!(
+            Assets::free_balance(&XOR, &DAN).expect("Failed to query free balance."),
+            balance!(250)
+        );
+        assert_eq!(
+            Assets::free_balance(&CERES_ASSET_ID, &CHARLES).expect("Failed to query free balance."),
+            balance!(5000)
+                + (FixedWrapper::from(balance!(750)) / FixedWrapper::from(balance!(0.13)))
+                    .try_into_balance()
+                    .unwrap()
+        );
+
+        let contribution_info = pallet::Contributions::<Runtime>::get(CERES_ASSET_ID, DAN);
+        assert_eq!(contribution_info.funds_contributed, balance!(250));
+        assert!(contribution_info.claiming_finished);
+    });
+}
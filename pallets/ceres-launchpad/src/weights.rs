@@ -73,6 +73,7 @@ pub trait WeightInfo {
 	fn remove_whitelisted_contributor() -> Weight;
 	fn add_whitelisted_ilo_organizer() -> Weight;
 	fn remove_whitelisted_ilo_organizer() -> Weight;
+	fn set_sale_format() -> Weight;
 }
 
 /// Weights for ceres_launchpad using the Substrate node and recommended hardware.
@@ -367,6 +368,21 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: CeresLaunchpad ILOs (r:1 w:0)
+	/// Proof Skipped: CeresLaunchpad ILOs (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: CeresLaunchpad SaleFormats (r:0 w:1)
+	/// Proof Skipped: CeresLaunchpad SaleFormats (max_values: None, max_size: None, mode: Measured)
+	fn set_sale_format() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `520`
+		//  Estimated: `3985`
+		// Minimum execution time: 27_310_000 picoseconds.
+		Weight::from_parts(28_042_000, 3985)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -660,4 +676,19 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: CeresLaunchpad ILOs (r:1 w:0)
+	/// Proof Skipped: CeresLaunchpad ILOs (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: CeresLaunchpad SaleFormats (r:0 w:1)
+	/// Proof Skipped: CeresLaunchpad SaleFormats (max_values: None, max_size: None, mode: Measured)
+	fn set_sale_format() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `520`
+		//  Estimated: `3985`
+		// Minimum execution time: 27_310_000 picoseconds.
+		Weight::from_parts(28_042_000, 3985)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -0,0 +1,261 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use common::prelude::{QuoteAmount, SwapAmount};
+use common::{balance, AssetManager, DEXId, TradingPairSourceManager, XOR};
+use frame_benchmarking::{benchmarks, whitelisted_caller};
+use frame_system::RawOrigin;
+
+const TICK_SPACING: u32 = 10;
+
+fn dex_id<T: Config>() -> T::DEXId {
+    DEXId::Polkaswap.into()
+}
+
+/// Registers a target asset paired with XOR and funds `caller` with both assets.
+fn setup_pair<T: Config>(caller: &T::AccountId) -> AssetIdOf<T> {
+    let target = T::AssetManager::register_from(
+        caller,
+        AssetSymbol(b"CLTEST".to_vec()),
+        AssetName(b"Concentrated liquidity test".to_vec()),
+        common::DEFAULT_BALANCE_PRECISION,
+        balance!(1000000),
+        true,
+        AssetType::Regular,
+        None,
+        None,
+    )
+    .expect("Shall register target asset");
+    T::AssetManager::update_balance(
+        RawOrigin::Root.into(),
+        caller.clone(),
+        XOR.into(),
+        balance!(1000000).try_into().unwrap(),
+    )
+    .expect("Shall mint XOR");
+    T::TradingPairSourceManager::register_pair(dex_id::<T>(), XOR.into(), target)
+        .expect("Shall register trading pair");
+    target
+}
+
+fn setup_pool<T: Config>(caller: &T::AccountId) -> AssetIdOf<T> {
+    let target = setup_pair::<T>(caller);
+    Pallet::<T>::create_pool(
+        RawOrigin::Signed(caller.clone()).into(),
+        dex_id::<T>(),
+        XOR.into(),
+        target,
+        Permill::from_parts(3000),
+        TICK_SPACING,
+        balance!(1),
+    )
+    .expect("Shall create pool");
+    target
+}
+
+fn setup_position<T: Config>(
+    caller: &T::AccountId,
+    target: AssetIdOf<T>,
+    tick_lower: i32,
+    tick_upper: i32,
+) -> AssetIdOf<T> {
+    Pallet::<T>::mint_position(
+        RawOrigin::Signed(caller.clone()).into(),
+        dex_id::<T>(),
+        XOR.into(),
+        target,
+        tick_lower,
+        tick_upper,
+        balance!(1000),
+        balance!(1000),
+        0,
+        0,
+    )
+    .expect("Shall mint position");
+    Positions::<T>::iter()
+        .find(|(_, position)| {
+            position.tick_lower == tick_lower && position.tick_upper == tick_upper
+        })
+        .map(|(position_id, _)| position_id)
+        .expect("Position exists")
+}
+
+/// Creates positions whose boundaries are crossed by the benchmarked swaps.
+fn setup_liquidity<T: Config>(caller: &T::AccountId) -> AssetIdOf<T> {
+    let target = setup_pool::<T>(caller);
+    for i in 1..=10 {
+        setup_position::<T>(caller, target, -100 * i, 100 * i);
+    }
+    target
+}
+
+benchmarks! {
+    create_pool {
+        let caller: T::AccountId = whitelisted_caller();
+        let target = setup_pair::<T>(&caller);
+    }: _(
+        RawOrigin::Signed(caller),
+        dex_id::<T>(),
+        XOR.into(),
+        target,
+        Permill::from_parts(3000),
+        TICK_SPACING,
+        balance!(1)
+    )
+    verify {
+        assert!(Pools::<T>::contains_key((dex_id::<T>(), TradingPair {
+            base_asset_id: XOR.into(),
+            target_asset_id: target,
+        })));
+    }
+
+    mint_position {
+        let caller: T::AccountId = whitelisted_caller();
+        let target = setup_pool::<T>(&caller);
+    }: _(
+        RawOrigin::Signed(caller),
+        dex_id::<T>(),
+        XOR.into(),
+        target,
+        -1000,
+        1000,
+        balance!(1000),
+        balance!(1000),
+        0,
+        0
+    )
+    verify {
+        assert_eq!(Positions::<T>::iter().count(), 1);
+    }
+
+    increase_liquidity {
+        let caller: T::AccountId = whitelisted_caller();
+        let target = setup_pool::<T>(&caller);
+        let position_id = setup_position::<T>(&caller, target, -1000, 1000);
+        let liquidity = Positions::<T>::get(position_id).unwrap().liquidity;
+    }: _(RawOrigin::Signed(caller), position_id, balance!(1000), balance!(1000), 0, 0)
+    verify {
+        assert!(Positions::<T>::get(position_id).unwrap().liquidity > liquidity);
+    }
+
+    decrease_liquidity {
+        let caller: T::AccountId = whitelisted_caller();
+        let target = setup_pool::<T>(&caller);
+        let position_id = setup_position::<T>(&caller, target, -1000, 1000);
+        let liquidity = Positions::<T>::get(position_id).unwrap().liquidity;
+    }: _(RawOrigin::Signed(caller), position_id, liquidity / 2, 0, 0)
+    verify {
+        assert!(Positions::<T>::get(position_id).unwrap().liquidity < liquidity);
+    }
+
+    collect_fees {
+        let caller: T::AccountId = whitelisted_caller();
+        let target = setup_pool::<T>(&caller);
+        let position_id = setup_position::<T>(&caller, target, -1000, 1000);
+        Pallet::<T>::exchange(
+            &caller,
+            &caller,
+            &dex_id::<T>(),
+            &XOR.into(),
+            &target,
+            SwapAmount::with_desired_input(balance!(10), 0),
+        )
+        .expect("Shall exchange");
+    }: _(RawOrigin::Signed(caller), position_id)
+    verify {
+        assert_eq!(Positions::<T>::get(position_id).unwrap().tokens_owed_base, 0);
+    }
+
+    burn_position {
+        let caller: T::AccountId = whitelisted_caller();
+        let target = setup_pool::<T>(&caller);
+        let position_id = setup_position::<T>(&caller, target, -1000, 1000);
+        let liquidity = Positions::<T>::get(position_id).unwrap().liquidity;
+        Pallet::<T>::decrease_liquidity(
+            RawOrigin::Signed(caller.clone()).into(),
+            position_id,
+            liquidity,
+            0,
+            0,
+        )
+        .expect("Shall withdraw liquidity");
+    }: _(RawOrigin::Signed(caller), position_id)
+    verify {
+        assert!(Positions::<T>::get(position_id).is_none());
+    }
+
+    quote {
+        let caller: T::AccountId = whitelisted_caller();
+        let target = setup_liquidity::<T>(&caller);
+    }: {
+        Pallet::<T>::quote(
+            &dex_id::<T>(),
+            &XOR.into(),
+            &target,
+            QuoteAmount::with_desired_input(balance!(5000)),
+            true,
+        )
+        .unwrap();
+    }
+
+    step_quote {
+        let a in 10..1000;
+        let caller: T::AccountId = whitelisted_caller();
+        let target = setup_liquidity::<T>(&caller);
+    }: {
+        Pallet::<T>::step_quote(
+            &dex_id::<T>(),
+            &XOR.into(),
+            &target,
+            QuoteAmount::with_desired_input(balance!(5000)),
+            a as usize,
+            true,
+        )
+        .unwrap();
+    }
+
+    exchange {
+        let caller: T::AccountId = whitelisted_caller();
+        let target = setup_liquidity::<T>(&caller);
+    }: {
+        Pallet::<T>::exchange(
+            &caller,
+            &caller,
+            &dex_id::<T>(),
+            &XOR.into(),
+            &target,
+            SwapAmount::with_desired_input(balance!(5000), 0),
+        )
+        .unwrap();
+    }
+}
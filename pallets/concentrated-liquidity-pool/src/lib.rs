@@ -0,0 +1,1425 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! # Concentrated liquidity pool
+//!
+//! Pools for XYK trading pairs in which liquidity providers choose the price range their
+//! liquidity is active in. Ranges are bounded by ticks, where tick `i` corresponds to the price
+//! `1.0001 ^ i` of the target asset in the base asset of the DEX. Swap fees are accrued per
+//! position through fee growth accumulators stored on ticks.
+//!
+//! Every position is an indivisible NFT asset; whoever holds it owns the position.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+mod benchmarking;
+
+pub mod math;
+pub mod weights;
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use common::alt::{DiscreteQuotation, SideAmount, SwapChunk};
+use common::prelude::{Balance, OutcomeFee, QuoteAmount, SwapAmount, SwapOutcome, SwapVariant};
+use common::{
+    AssetIdOf, AssetInfoProvider, AssetManager, AssetName, AssetSymbol, AssetType, DexInfoProvider,
+    FromGenericPair, LiquiditySource, LiquiditySourceType, RewardReason, TradingPair,
+};
+use frame_support::dispatch::DispatchResult;
+use frame_support::weights::Weight;
+use frame_support::{ensure, fail, RuntimeDebug};
+use math::ONE;
+use scale_info::TypeInfo;
+use sp_runtime::traits::Zero;
+use sp_runtime::{DispatchError, Permill};
+use sp_std::vec::Vec;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+pub const TECH_ACCOUNT_PREFIX: &[u8] = b"concentrated-liquidity";
+pub const POSITION_SYMBOL: &[u8] = b"CLP";
+pub const POSITION_NAME: &[u8] = b"Concentrated Liquidity Position";
+/// Highest fee a pool can be created with.
+pub const MAX_FEE: Permill = Permill::from_percent(10);
+/// Highest tick spacing a pool can be created with.
+pub const MAX_TICK_SPACING: u32 = 10_000;
+
+/// Pools are identified by DEX and trading pair, the base asset of the pair is the DEX base asset.
+pub type PoolKeyOf<T> = (<T as common::Config>::DEXId, TradingPair<AssetIdOf<T>>);
+pub type PositionOf<T> = Position<<T as common::Config>::DEXId, AssetIdOf<T>>;
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PoolInfo<AccountId> {
+    /// Account holding the reserves and the accrued fees of the pool.
+    pub account: AccountId,
+    /// Swap fee charged from the input asset.
+    pub fee: Permill,
+    /// Position boundaries must be multiples of this value.
+    pub tick_spacing: u32,
+    /// Square root of the current price with 18 decimals.
+    pub sqrt_price: Balance,
+    /// The greatest tick whose price does not exceed the current price.
+    pub tick: i32,
+    /// Liquidity active at the current price.
+    pub liquidity: Balance,
+    /// Base asset fees earned per unit of liquidity over the pool lifetime, wraps on overflow.
+    pub fee_growth_global_base: Balance,
+    /// Target asset fees earned per unit of liquidity over the pool lifetime, wraps on overflow.
+    pub fee_growth_global_target: Balance,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct TickInfo {
+    /// Total liquidity of positions using this tick as a boundary.
+    pub liquidity_gross: Balance,
+    /// Liquidity added to the pool when the price crosses this tick upwards.
+    pub liquidity_net: i128,
+    /// Base asset fee growth on the other side of this tick relative to the current tick.
+    pub fee_growth_outside_base: Balance,
+    /// Target asset fee growth on the other side of this tick relative to the current tick.
+    pub fee_growth_outside_target: Balance,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Position<DEXId, AssetId> {
+    pub dex_id: DEXId,
+    pub trading_pair: TradingPair<AssetId>,
+    pub tick_lower: i32,
+    pub tick_upper: i32,
+    pub liquidity: Balance,
+    pub fee_growth_inside_last_base: Balance,
+    pub fee_growth_inside_last_target: Balance,
+    /// Fees and withdrawn amounts which can be collected by the owner.
+    pub tokens_owed_base: Balance,
+    pub tokens_owed_target: Balance,
+}
+
+/// Result of a simulated swap which can be either quoted or applied to the pool.
+#[derive(Clone, RuntimeDebug)]
+pub struct SwapState {
+    /// Input amount including fee.
+    pub input: Balance,
+    pub output: Balance,
+    /// Fee in the input asset.
+    pub fee: Balance,
+    /// Part of the requested amount which can't be filled with available liquidity.
+    pub remaining: Balance,
+    pub sqrt_price: Balance,
+    pub tick: i32,
+    pub liquidity: Balance,
+    pub fee_growth_global_base: Balance,
+    pub fee_growth_global_target: Balance,
+    /// Crossed ticks with the global fee growth at the moment of crossing.
+    pub crossed_ticks: Vec<(i32, Balance, Balance)>,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use common::{EnsureDEXManager, ManagementMode, TradingPairSourceManager};
+    use frame_support::pallet_prelude::*;
+    use frame_support::traits::StorageVersion;
+    use frame_system::pallet_prelude::*;
+
+    /// The current storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+    #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config + common::Config + technical::Config {
+        #[allow(deprecated)]
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        type EnsureDEXManager: EnsureDEXManager<Self::DEXId, Self::AccountId, DispatchError>;
+        type TradingPairSourceManager: TradingPairSourceManager<Self::DEXId, AssetIdOf<Self>>;
+        type DexInfoProvider: DexInfoProvider<Self::DEXId, common::DEXInfo<AssetIdOf<Self>>>;
+        /// Maximum number of initialized ticks in a single pool.
+        #[pallet::constant]
+        type MaxInitializedTicks: Get<u32>;
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::storage]
+    #[pallet::getter(fn pool)]
+    pub type Pools<T: Config> =
+        StorageMap<_, Blake2_128Concat, PoolKeyOf<T>, PoolInfo<T::AccountId>, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn tick)]
+    pub type Ticks<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        PoolKeyOf<T>,
+        Twox64Concat,
+        i32,
+        TickInfo,
+        ValueQuery,
+    >;
+
+    /// Sorted list of ticks with non-zero gross liquidity.
+    #[pallet::storage]
+    #[pallet::getter(fn initialized_ticks)]
+    pub type InitializedTicks<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        PoolKeyOf<T>,
+        BoundedVec<i32, T::MaxInitializedTicks>,
+        ValueQuery,
+    >;
+
+    /// Positions by their NFT asset id.
+    #[pallet::storage]
+    #[pallet::getter(fn position)]
+    pub type Positions<T: Config> =
+        StorageMap<_, Identity, AssetIdOf<T>, PositionOf<T>, OptionQuery>;
+
+    /// Used to derive asset ids of position NFTs.
+    #[pallet::storage]
+    pub type NextPositionIndex<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        PoolCreated {
+            dex_id: T::DEXId,
+            trading_pair: TradingPair<AssetIdOf<T>>,
+            pool_account: T::AccountId,
+            fee: Permill,
+            tick_spacing: u32,
+            sqrt_price: Balance,
+        },
+        PositionMinted {
+            owner: T::AccountId,
+            position_id: AssetIdOf<T>,
+            dex_id: T::DEXId,
+            trading_pair: TradingPair<AssetIdOf<T>>,
+            tick_lower: i32,
+            tick_upper: i32,
+        },
+        LiquidityIncreased {
+            position_id: AssetIdOf<T>,
+            liquidity: Balance,
+            base_amount: Balance,
+            target_amount: Balance,
+        },
+        LiquidityDecreased {
+            position_id: AssetIdOf<T>,
+            liquidity: Balance,
+            base_amount: Balance,
+            target_amount: Balance,
+        },
+        FeesCollected {
+            position_id: AssetIdOf<T>,
+            owner: T::AccountId,
+            base_amount: Balance,
+            target_amount: Balance,
+        },
+        PositionBurned {
+            position_id: AssetIdOf<T>,
+            owner: T::AccountId,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Pool for the trading pair already exists
+        PoolAlreadyExists,
+        /// Pool for the trading pair doesn't exist
+        PoolDoesNotExist,
+        /// Trading pair must be registered before the pool is created
+        TradingPairDoesNotExist,
+        /// Pools can't be created with indivisible assets
+        UnableToCreatePoolWithIndivisibleAssets,
+        /// Pools can't be created on this DEX
+        DexIsForbidden,
+        /// Fee is above the allowed maximum
+        InvalidFee,
+        /// Tick spacing is zero or above the allowed maximum
+        InvalidTickSpacing,
+        /// Initial price is out of the supported tick range
+        InvalidInitialPrice,
+        /// Lower tick must be less than upper tick and both must be in the supported range
+        InvalidTickRange,
+        /// Tick is not a multiple of the pool tick spacing
+        TickNotAlignedWithSpacing,
+        /// Operation results in zero liquidity
+        ZeroLiquidity,
+        /// Position doesn't have that much liquidity
+        InsufficientPositionLiquidity,
+        /// Resulting amount is beyond the provided limits
+        SlippageLimitExceeded,
+        /// Pool liquidity is not enough to fill the swap
+        NotEnoughLiquidity,
+        /// Position doesn't exist
+        PositionDoesNotExist,
+        /// Caller doesn't hold the position NFT
+        NotPositionOwner,
+        /// Position still has liquidity or uncollected tokens
+        PositionNotEmpty,
+        /// Pool has reached the maximum number of initialized ticks
+        TooManyInitializedTicks,
+        /// Input and output assets must differ
+        AssetsMustNotBeSame,
+        /// One of the assets must be the DEX base asset
+        BaseAssetIsNotInPair,
+        /// Fixed-point calculation failed
+        CalculationError,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Create a concentrated liquidity pool for an existing trading pair.
+        ///
+        /// - `initial_price`: price of the target asset in the base asset with 18 decimals.
+        #[pallet::call_index(0)]
+        #[pallet::weight(<T as Config>::WeightInfo::create_pool())]
+        pub fn create_pool(
+            origin: OriginFor<T>,
+            dex_id: T::DEXId,
+            base_asset_id: AssetIdOf<T>,
+            target_asset_id: AssetIdOf<T>,
+            fee: Permill,
+            tick_spacing: u32,
+            initial_price: Balance,
+        ) -> DispatchResult {
+            ensure_signed(origin.clone())?;
+            T::EnsureDEXManager::ensure_can_manage(&dex_id, origin, ManagementMode::Public)?;
+            ensure!(
+                dex_id != common::DEXId::PolkaswapPresto.into(),
+                Error::<T>::DexIsForbidden
+            );
+            let dex_info = T::DexInfoProvider::get_dex_info(&dex_id)?;
+            ensure!(
+                base_asset_id == dex_info.base_asset_id,
+                Error::<T>::BaseAssetIsNotInPair
+            );
+            ensure!(
+                base_asset_id != target_asset_id,
+                Error::<T>::AssetsMustNotBeSame
+            );
+            ensure!(
+                T::TradingPairSourceManager::is_trading_pair_enabled(
+                    &dex_id,
+                    &base_asset_id,
+                    &target_asset_id
+                )?,
+                Error::<T>::TradingPairDoesNotExist
+            );
+            ensure!(
+                !T::AssetInfoProvider::is_non_divisible(&base_asset_id)
+                    && !T::AssetInfoProvider::is_non_divisible(&target_asset_id),
+                Error::<T>::UnableToCreatePoolWithIndivisibleAssets
+            );
+            ensure!(fee <= MAX_FEE, Error::<T>::InvalidFee);
+            ensure!(
+                tick_spacing > 0 && tick_spacing <= MAX_TICK_SPACING,
+                Error::<T>::InvalidTickSpacing
+            );
+
+            let trading_pair = TradingPair {
+                base_asset_id,
+                target_asset_id,
+            };
+            let pool_key = (dex_id, trading_pair);
+            ensure!(
+                !Pools::<T>::contains_key(&pool_key),
+                Error::<T>::PoolAlreadyExists
+            );
+
+            let sqrt_price = Self::sqrt_of_price(initial_price)?;
+            let tick =
+                math::tick_at_sqrt_price(sqrt_price).ok_or(Error::<T>::InvalidInitialPrice)?;
+
+            let tech_account_id = Self::pool_tech_account_id(&pool_key);
+            technical::Pallet::<T>::register_tech_account_id(tech_account_id.clone())?;
+            let pool_account =
+                technical::Pallet::<T>::tech_account_id_to_account_id(&tech_account_id)?;
+
+            T::TradingPairSourceManager::enable_source_for_trading_pair(
+                &dex_id,
+                &base_asset_id,
+                &target_asset_id,
+                LiquiditySourceType::ConcentratedLiquidityPool,
+            )?;
+
+            Pools::<T>::insert(
+                &pool_key,
+                PoolInfo {
+                    account: pool_account.clone(),
+                    fee,
+                    tick_spacing,
+                    sqrt_price,
+                    tick,
+                    liquidity: 0,
+                    fee_growth_global_base: 0,
+                    fee_growth_global_target: 0,
+                },
+            );
+
+            Self::deposit_event(Event::PoolCreated {
+                dex_id,
+                trading_pair,
+                pool_account,
+                fee,
+                tick_spacing,
+                sqrt_price,
+            });
+            Ok(())
+        }
+
+        /// Open a new position in `[tick_lower, tick_upper)` and mint its NFT to the caller.
+        #[pallet::call_index(1)]
+        #[pallet::weight(<T as Config>::WeightInfo::mint_position())]
+        pub fn mint_position(
+            origin: OriginFor<T>,
+            dex_id: T::DEXId,
+            base_asset_id: AssetIdOf<T>,
+            target_asset_id: AssetIdOf<T>,
+            tick_lower: i32,
+            tick_upper: i32,
+            base_amount_desired: Balance,
+            target_amount_desired: Balance,
+            base_amount_min: Balance,
+            target_amount_min: Balance,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let trading_pair = TradingPair {
+                base_asset_id,
+                target_asset_id,
+            };
+            let pool_key = (dex_id, trading_pair);
+            let pool = Pools::<T>::get(&pool_key).ok_or(Error::<T>::PoolDoesNotExist)?;
+            Self::ensure_valid_ticks(&pool, tick_lower, tick_upper)?;
+
+            let position_id = Self::mint_position_nft(&who)?;
+            let mut position = Position {
+                dex_id,
+                trading_pair,
+                tick_lower,
+                tick_upper,
+                liquidity: 0,
+                fee_growth_inside_last_base: 0,
+                fee_growth_inside_last_target: 0,
+                tokens_owed_base: 0,
+                tokens_owed_target: 0,
+            };
+            let (liquidity, base_amount, target_amount) = Self::add_liquidity(
+                &who,
+                &pool_key,
+                &mut position,
+                base_amount_desired,
+                target_amount_desired,
+                base_amount_min,
+                target_amount_min,
+            )?;
+            Positions::<T>::insert(position_id, position);
+
+            Self::deposit_event(Event::PositionMinted {
+                owner: who,
+                position_id,
+                dex_id,
+                trading_pair,
+                tick_lower,
+                tick_upper,
+            });
+            Self::deposit_event(Event::LiquidityIncreased {
+                position_id,
+                liquidity,
+                base_amount,
+                target_amount,
+            });
+            Ok(())
+        }
+
+        /// Add liquidity to an existing position within its range.
+        #[pallet::call_index(2)]
+        #[pallet::weight(<T as Config>::WeightInfo::increase_liquidity())]
+        pub fn increase_liquidity(
+            origin: OriginFor<T>,
+            position_id: AssetIdOf<T>,
+            base_amount_desired: Balance,
+            target_amount_desired: Balance,
+            base_amount_min: Balance,
+            target_amount_min: Balance,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let mut position = Self::ensure_position_owner(&who, &position_id)?;
+            let pool_key = (position.dex_id, position.trading_pair);
+            let (liquidity, base_amount, target_amount) = Self::add_liquidity(
+                &who,
+                &pool_key,
+                &mut position,
+                base_amount_desired,
+                target_amount_desired,
+                base_amount_min,
+                target_amount_min,
+            )?;
+            Positions::<T>::insert(position_id, position);
+
+            Self::deposit_event(Event::LiquidityIncreased {
+                position_id,
+                liquidity,
+                base_amount,
+                target_amount,
+            });
+            Ok(())
+        }
+
+        /// Remove `liquidity` from the position and transfer the withdrawn amounts to the caller.
+        /// Fees accrued so far stay in the position until they are collected.
+        #[pallet::call_index(3)]
+        #[pallet::weight(<T as Config>::WeightInfo::decrease_liquidity())]
+        pub fn decrease_liquidity(
+            origin: OriginFor<T>,
+            position_id: AssetIdOf<T>,
+            liquidity: Balance,
+            base_amount_min: Balance,
+            target_amount_min: Balance,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(!liquidity.is_zero(), Error::<T>::ZeroLiquidity);
+            let mut position = Self::ensure_position_owner(&who, &position_id)?;
+            ensure!(
+                position.liquidity >= liquidity,
+                Error::<T>::InsufficientPositionLiquidity
+            );
+            let pool_key = (position.dex_id, position.trading_pair);
+            let mut pool = Pools::<T>::get(&pool_key).ok_or(Error::<T>::PoolDoesNotExist)?;
+
+            let (base_amount, target_amount) = math::amounts_for_liquidity(
+                pool.sqrt_price,
+                Self::sqrt_price_at(position.tick_lower)?,
+                Self::sqrt_price_at(position.tick_upper)?,
+                liquidity,
+                false,
+            )
+            .ok_or(Error::<T>::CalculationError)?;
+            ensure!(
+                base_amount >= base_amount_min && target_amount >= target_amount_min,
+                Error::<T>::SlippageLimitExceeded
+            );
+
+            Self::modify_position(
+                &pool_key,
+                &mut pool,
+                &mut position,
+                -Self::signed(liquidity)?,
+            )?;
+            Pools::<T>::insert(&pool_key, &pool);
+            Positions::<T>::insert(position_id, position.clone());
+
+            Self::transfer_from_pool(
+                &pool,
+                &position.trading_pair,
+                &who,
+                base_amount,
+                target_amount,
+            )?;
+
+            Self::deposit_event(Event::LiquidityDecreased {
+                position_id,
+                liquidity,
+                base_amount,
+                target_amount,
+            });
+            Ok(())
+        }
+
+        /// Transfer the fees accrued by the position to the caller.
+        #[pallet::call_index(4)]
+        #[pallet::weight(<T as Config>::WeightInfo::collect_fees())]
+        pub fn collect_fees(origin: OriginFor<T>, position_id: AssetIdOf<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let mut position = Self::ensure_position_owner(&who, &position_id)?;
+            let pool_key = (position.dex_id, position.trading_pair);
+            let mut pool = Pools::<T>::get(&pool_key).ok_or(Error::<T>::PoolDoesNotExist)?;
+
+            if !position.liquidity.is_zero() {
+                // zero liquidity delta only settles the accrued fees
+                Self::modify_position(&pool_key, &mut pool, &mut position, 0)?;
+            }
+            let base_amount = core::mem::take(&mut position.tokens_owed_base);
+            let target_amount = core::mem::take(&mut position.tokens_owed_target);
+            Positions::<T>::insert(position_id, position.clone());
+
+            Self::transfer_from_pool(
+                &pool,
+                &position.trading_pair,
+                &who,
+                base_amount,
+                target_amount,
+            )?;
+
+            Self::deposit_event(Event::FeesCollected {
+                position_id,
+                owner: who,
+                base_amount,
+                target_amount,
+            });
+            Ok(())
+        }
+
+        /// Burn the NFT of an empty position.
+        #[pallet::call_index(5)]
+        #[pallet::weight(<T as Config>::WeightInfo::burn_position())]
+        pub fn burn_position(origin: OriginFor<T>, position_id: AssetIdOf<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let position = Self::ensure_position_owner(&who, &position_id)?;
+            ensure!(
+                position.liquidity.is_zero()
+                    && position.tokens_owed_base.is_zero()
+                    && position.tokens_owed_target.is_zero(),
+                Error::<T>::PositionNotEmpty
+            );
+            T::AssetManager::burn_from(&position_id, &who, &who, 1)?;
+            Positions::<T>::remove(position_id);
+
+            Self::deposit_event(Event::PositionBurned {
+                position_id,
+                owner: who,
+            });
+            Ok(())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn pool_tech_account_id(pool_key: &PoolKeyOf<T>) -> T::TechAccountId {
+        T::TechAccountId::from_generic_pair(TECH_ACCOUNT_PREFIX.to_vec(), pool_key.encode())
+    }
+
+    /// Current price of the target asset in the base asset.
+    pub fn spot_price(pool_key: &PoolKeyOf<T>) -> Option<Balance> {
+        let pool = Pools::<T>::get(pool_key)?;
+        math::mul_div(pool.sqrt_price, pool.sqrt_price, ONE)
+    }
+
+    /// Fees accrued by the position that would be paid out by `collect_fees` now,
+    /// as `(base, target)`.
+    pub fn uncollected_fees(position_id: &AssetIdOf<T>) -> Option<(Balance, Balance)> {
+        let position = Positions::<T>::get(position_id)?;
+        if position.liquidity.is_zero() {
+            return Some((position.tokens_owed_base, position.tokens_owed_target));
+        }
+        let pool_key = (position.dex_id, position.trading_pair);
+        let pool = Pools::<T>::get(&pool_key)?;
+        let (inside_base, inside_target) =
+            Self::fee_growth_inside(&pool_key, &pool, position.tick_lower, position.tick_upper);
+        let base = math::mul_div(
+            position.liquidity,
+            inside_base.wrapping_sub(position.fee_growth_inside_last_base),
+            ONE,
+        )?;
+        let target = math::mul_div(
+            position.liquidity,
+            inside_target.wrapping_sub(position.fee_growth_inside_last_target),
+            ONE,
+        )?;
+        Some((
+            position.tokens_owed_base.saturating_add(base),
+            position.tokens_owed_target.saturating_add(target),
+        ))
+    }
+
+    fn sqrt_of_price(price: Balance) -> Result<Balance, DispatchError> {
+        let sqrt_price = (sp_core::U256::from(price) * sp_core::U256::from(ONE)).integer_sqrt();
+        let sqrt_price: Balance = sqrt_price
+            .try_into()
+            .map_err(|_| Error::<T>::InvalidInitialPrice)?;
+        let min = Self::sqrt_price_at(math::MIN_TICK)?;
+        let max = Self::sqrt_price_at(math::MAX_TICK)?;
+        ensure!(
+            sqrt_price >= min && sqrt_price <= max,
+            Error::<T>::InvalidInitialPrice
+        );
+        Ok(sqrt_price)
+    }
+
+    fn sqrt_price_at(tick: i32) -> Result<Balance, DispatchError> {
+        Ok(math::sqrt_price_at_tick(tick).ok_or(Error::<T>::InvalidTickRange)?)
+    }
+
+    fn signed(liquidity: Balance) -> Result<i128, DispatchError> {
+        Ok(i128::try_from(liquidity).map_err(|_| Error::<T>::CalculationError)?)
+    }
+
+    fn ensure_valid_ticks(
+        pool: &PoolInfo<T::AccountId>,
+        tick_lower: i32,
+        tick_upper: i32,
+    ) -> DispatchResult {
+        ensure!(
+            tick_lower < tick_upper && tick_lower >= math::MIN_TICK && tick_upper <= math::MAX_TICK,
+            Error::<T>::InvalidTickRange
+        );
+        let spacing = pool.tick_spacing as i32;
+        ensure!(
+            tick_lower % spacing == 0 && tick_upper % spacing == 0,
+            Error::<T>::TickNotAlignedWithSpacing
+        );
+        Ok(())
+    }
+
+    fn ensure_position_owner(
+        who: &T::AccountId,
+        position_id: &AssetIdOf<T>,
+    ) -> Result<PositionOf<T>, DispatchError> {
+        let position = Positions::<T>::get(position_id).ok_or(Error::<T>::PositionDoesNotExist)?;
+        ensure!(
+            !T::AssetInfoProvider::free_balance(position_id, who)?.is_zero(),
+            Error::<T>::NotPositionOwner
+        );
+        Ok(position)
+    }
+
+    fn mint_position_nft(owner: &T::AccountId) -> Result<AssetIdOf<T>, DispatchError> {
+        let index = NextPositionIndex::<T>::mutate(|index| {
+            let current = *index;
+            *index = index.saturating_add(1);
+            current
+        });
+        let position_id = T::AssetManager::gen_asset_id_from_any(&(TECH_ACCOUNT_PREFIX, index));
+        T::AssetManager::register_asset_id(
+            owner.clone(),
+            position_id,
+            AssetSymbol(POSITION_SYMBOL.to_vec()),
+            AssetName(POSITION_NAME.to_vec()),
+            0,
+            1,
+            false,
+            AssetType::NFT,
+            None,
+            None,
+        )?;
+        Ok(position_id)
+    }
+
+    /// Adds as much liquidity as the desired amounts allow and pulls the required amounts
+    /// from `who`. Returns the added liquidity and the deposited `(base, target)` amounts.
+    fn add_liquidity(
+        who: &T::AccountId,
+        pool_key: &PoolKeyOf<T>,
+        position: &mut PositionOf<T>,
+        base_amount_desired: Balance,
+        target_amount_desired: Balance,
+        base_amount_min: Balance,
+        target_amount_min: Balance,
+    ) -> Result<(Balance, Balance, Balance), DispatchError> {
+        let mut pool = Pools::<T>::get(pool_key).ok_or(Error::<T>::PoolDoesNotExist)?;
+        let sqrt_price_lower = Self::sqrt_price_at(position.tick_lower)?;
+        let sqrt_price_upper = Self::sqrt_price_at(position.tick_upper)?;
+        let liquidity = math::liquidity_for_amounts(
+            pool.sqrt_price,
+            sqrt_price_lower,
+            sqrt_price_upper,
+            base_amount_desired,
+            target_amount_desired,
+        )
+        .ok_or(Error::<T>::CalculationError)?;
+        ensure!(!liquidity.is_zero(), Error::<T>::ZeroLiquidity);
+
+        let (base_amount, target_amount) = math::amounts_for_liquidity(
+            pool.sqrt_price,
+            sqrt_price_lower,
+            sqrt_price_upper,
+            liquidity,
+            true,
+        )
+        .ok_or(Error::<T>::CalculationError)?;
+        ensure!(
+            base_amount >= base_amount_min && target_amount >= target_amount_min,
+            Error::<T>::SlippageLimitExceeded
+        );
+
+        Self::modify_position(pool_key, &mut pool, position, Self::signed(liquidity)?)?;
+        Pools::<T>::insert(pool_key, &pool);
+
+        let TradingPair {
+            base_asset_id,
+            target_asset_id,
+        } = pool_key.1;
+        if !base_amount.is_zero() {
+            T::AssetManager::transfer_from(&base_asset_id, who, &pool.account, base_amount)?;
+        }
+        if !target_amount.is_zero() {
+            T::AssetManager::transfer_from(&target_asset_id, who, &pool.account, target_amount)?;
+        }
+        Ok((liquidity, base_amount, target_amount))
+    }
+
+    fn transfer_from_pool(
+        pool: &PoolInfo<T::AccountId>,
+        trading_pair: &TradingPair<AssetIdOf<T>>,
+        to: &T::AccountId,
+        base_amount: Balance,
+        target_amount: Balance,
+    ) -> DispatchResult {
+        if !base_amount.is_zero() {
+            T::AssetManager::transfer_from(
+                &trading_pair.base_asset_id,
+                &pool.account,
+                to,
+                base_amount,
+            )?;
+        }
+        if !target_amount.is_zero() {
+            T::AssetManager::transfer_from(
+                &trading_pair.target_asset_id,
+                &pool.account,
+                to,
+                target_amount,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Settles the fees of the position and applies `liquidity_delta` to it, its boundary ticks
+    /// and the pool. Withdrawn principal is not added to the owed tokens.
+    fn modify_position(
+        pool_key: &PoolKeyOf<T>,
+        pool: &mut PoolInfo<T::AccountId>,
+        position: &mut PositionOf<T>,
+        liquidity_delta: i128,
+    ) -> DispatchResult {
+        if !position.liquidity.is_zero() {
+            let (inside_base, inside_target) =
+                Self::fee_growth_inside(pool_key, pool, position.tick_lower, position.tick_upper);
+            let owed_base = math::mul_div(
+                position.liquidity,
+                inside_base.wrapping_sub(position.fee_growth_inside_last_base),
+                ONE,
+            )
+            .ok_or(Error::<T>::CalculationError)?;
+            let owed_target = math::mul_div(
+                position.liquidity,
+                inside_target.wrapping_sub(position.fee_growth_inside_last_target),
+                ONE,
+            )
+            .ok_or(Error::<T>::CalculationError)?;
+            position.tokens_owed_base = position.tokens_owed_base.saturating_add(owed_base);
+            position.tokens_owed_target = position.tokens_owed_target.saturating_add(owed_target);
+            position.fee_growth_inside_last_base = inside_base;
+            position.fee_growth_inside_last_target = inside_target;
+        }
+
+        if liquidity_delta == 0 {
+            return Ok(());
+        }
+
+        Self::update_tick(pool_key, pool, position.tick_lower, liquidity_delta, false)?;
+        Self::update_tick(pool_key, pool, position.tick_upper, liquidity_delta, true)?;
+
+        if position.tick_lower <= pool.tick && pool.tick < position.tick_upper {
+            pool.liquidity = Self::apply_delta(pool.liquidity, liquidity_delta)?;
+        }
+        let was_empty = position.liquidity.is_zero();
+        position.liquidity = Self::apply_delta(position.liquidity, liquidity_delta)?;
+
+        if was_empty {
+            // boundary ticks may have just been initialized, start accruing from now
+            let (inside_base, inside_target) =
+                Self::fee_growth_inside(pool_key, pool, position.tick_lower, position.tick_upper);
+            position.fee_growth_inside_last_base = inside_base;
+            position.fee_growth_inside_last_target = inside_target;
+        }
+        Ok(())
+    }
+
+    fn apply_delta(liquidity: Balance, delta: i128) -> Result<Balance, DispatchError> {
+        let result = if delta >= 0 {
+            liquidity.checked_add(delta.unsigned_abs())
+        } else {
+            liquidity.checked_sub(delta.unsigned_abs())
+        };
+        Ok(result.ok_or(Error::<T>::CalculationError)?)
+    }
+
+    fn update_tick(
+        pool_key: &PoolKeyOf<T>,
+        pool: &PoolInfo<T::AccountId>,
+        tick: i32,
+        liquidity_delta: i128,
+        upper: bool,
+    ) -> DispatchResult {
+        let mut info = Ticks::<T>::get(pool_key, tick);
+        let gross_before = info.liquidity_gross;
+        info.liquidity_gross = Self::apply_delta(gross_before, liquidity_delta)?;
+        info.liquidity_net = if upper {
+            info.liquidity_net.checked_sub(liquidity_delta)
+        } else {
+            info.liquidity_net.checked_add(liquidity_delta)
+        }
+        .ok_or(Error::<T>::CalculationError)?;
+
+        if gross_before.is_zero() {
+            // by convention all growth before initialization happened below the tick
+            if tick <= pool.tick {
+                info.fee_growth_outside_base = pool.fee_growth_global_base;
+                info.fee_growth_outside_target = pool.fee_growth_global_target;
+            }
+            InitializedTicks::<T>::try_mutate(pool_key, |ticks| -> DispatchResult {
+                if let Err(index) = ticks.binary_search(&tick) {
+                    ticks
+                        .try_insert(index, tick)
+                        .map_err(|_| Error::<T>::TooManyInitializedTicks)?;
+                }
+                Ok(())
+            })?;
+        }
+
+        if info.liquidity_gross.is_zero() {
+            Ticks::<T>::remove(pool_key, tick);
+            InitializedTicks::<T>::mutate(pool_key, |ticks| {
+                if let Ok(index) = ticks.binary_search(&tick) {
+                    ticks.remove(index);
+                }
+            });
+        } else {
+            Ticks::<T>::insert(pool_key, tick, info);
+        }
+        Ok(())
+    }
+
+    fn fee_growth_inside(
+        pool_key: &PoolKeyOf<T>,
+        pool: &PoolInfo<T::AccountId>,
+        tick_lower: i32,
+        tick_upper: i32,
+    ) -> (Balance, Balance) {
+        let lower = Ticks::<T>::get(pool_key, tick_lower);
+        let upper = Ticks::<T>::get(pool_key, tick_upper);
+        let global_base = pool.fee_growth_global_base;
+        let global_target = pool.fee_growth_global_target;
+
+        let (below_base, below_target) = if pool.tick >= tick_lower {
+            (
+                lower.fee_growth_outside_base,
+                lower.fee_growth_outside_target,
+            )
+        } else {
+            (
+                global_base.wrapping_sub(lower.fee_growth_outside_base),
+                global_target.wrapping_sub(lower.fee_growth_outside_target),
+            )
+        };
+        let (above_base, above_target) = if pool.tick < tick_upper {
+            (
+                upper.fee_growth_outside_base,
+                upper.fee_growth_outside_target,
+            )
+        } else {
+            (
+                global_base.wrapping_sub(upper.fee_growth_outside_base),
+                global_target.wrapping_sub(upper.fee_growth_outside_target),
+            )
+        };
+        (
+            global_base
+                .wrapping_sub(below_base)
+                .wrapping_sub(above_base),
+            global_target
+                .wrapping_sub(below_target)
+                .wrapping_sub(above_target),
+        )
+    }
+
+    /// Finds the pool for the swap direction, returns the pool key and whether the base asset
+    /// is the input.
+    fn pool_key_for_swap(
+        dex_id: &T::DEXId,
+        input_asset_id: &AssetIdOf<T>,
+        output_asset_id: &AssetIdOf<T>,
+    ) -> Result<(PoolKeyOf<T>, bool), DispatchError> {
+        ensure!(
+            input_asset_id != output_asset_id,
+            Error::<T>::AssetsMustNotBeSame
+        );
+        let dex_info = T::DexInfoProvider::get_dex_info(dex_id)?;
+        let (target_asset_id, base_is_input) = if *input_asset_id == dex_info.base_asset_id {
+            (*output_asset_id, true)
+        } else if *output_asset_id == dex_info.base_asset_id {
+            (*input_asset_id, false)
+        } else {
+            fail!(Error::<T>::BaseAssetIsNotInPair);
+        };
+        let trading_pair = TradingPair {
+            base_asset_id: dex_info.base_asset_id,
+            target_asset_id,
+        };
+        Ok(((*dex_id, trading_pair), base_is_input))
+    }
+
+    /// Walks initialized ticks in the swap direction until `amount` is filled or liquidity
+    /// runs out. Doesn't modify storage.
+    pub fn simulate_swap(
+        pool_key: &PoolKeyOf<T>,
+        pool: &PoolInfo<T::AccountId>,
+        base_is_input: bool,
+        amount: QuoteAmount<Balance>,
+        deduce_fee: bool,
+    ) -> Result<SwapState, DispatchError> {
+        let fee_parts = if deduce_fee {
+            pool.fee.deconstruct()
+        } else {
+            0
+        };
+        let exact_input = matches!(amount, QuoteAmount::WithDesiredInput { .. });
+        let ticks = InitializedTicks::<T>::get(pool_key);
+        let mut state = SwapState {
+            input: 0,
+            output: 0,
+            fee: 0,
+            remaining: amount.amount(),
+            sqrt_price: pool.sqrt_price,
+            tick: pool.tick,
+            liquidity: pool.liquidity,
+            fee_growth_global_base: pool.fee_growth_global_base,
+            fee_growth_global_target: pool.fee_growth_global_target,
+            crossed_ticks: Vec::new(),
+        };
+
+        while !state.remaining.is_zero() {
+            let next_tick = if base_is_input {
+                ticks.iter().find(|tick| **tick > state.tick)
+            } else {
+                ticks.iter().rev().find(|tick| **tick <= state.tick)
+            };
+            let Some(next_tick) = next_tick.copied() else {
+                break;
+            };
+            let sqrt_price_next = Self::sqrt_price_at(next_tick)?;
+
+            let (amount_in, amount_out, fee, sqrt_price_new) = Self::swap_step(
+                state.sqrt_price,
+                sqrt_price_next,
+                state.liquidity,
+                state.remaining,
+                exact_input,
+                fee_parts,
+                base_is_input,
+            )
+            .ok_or(Error::<T>::CalculationError)?;
+
+            let consumed = if exact_input {
+                amount_in.saturating_add(fee)
+            } else {
+                amount_out
+            };
+            state.remaining = state.remaining.saturating_sub(consumed);
+            state.input = state.input.saturating_add(amount_in).saturating_add(fee);
+            state.output = state.output.saturating_add(amount_out);
+            state.fee = state.fee.saturating_add(fee);
+
+            if !state.liquidity.is_zero() && !fee.is_zero() {
+                let growth =
+                    math::mul_div(fee, ONE, state.liquidity).ok_or(Error::<T>::CalculationError)?;
+                if base_is_input {
+                    state.fee_growth_global_base =
+                        state.fee_growth_global_base.wrapping_add(growth);
+                } else {
+                    state.fee_growth_global_target =
+                        state.fee_growth_global_target.wrapping_add(growth);
+                }
+            }
+
+            if sqrt_price_new == sqrt_price_next {
+                let info = Ticks::<T>::get(pool_key, next_tick);
+                state.crossed_ticks.push((
+                    next_tick,
+                    state.fee_growth_global_base,
+                    state.fee_growth_global_target,
+                ));
+                if base_is_input {
+                    state.liquidity = Self::apply_delta(state.liquidity, info.liquidity_net)?;
+                    state.tick = next_tick;
+                } else {
+                    state.liquidity = Self::apply_delta(state.liquidity, -info.liquidity_net)?;
+                    state.tick = next_tick - 1;
+                }
+                state.sqrt_price = sqrt_price_next;
+            } else {
+                state.sqrt_price = sqrt_price_new;
+                state.tick =
+                    math::tick_at_sqrt_price(sqrt_price_new).ok_or(Error::<T>::CalculationError)?;
+                break;
+            }
+        }
+        Ok(state)
+    }
+
+    /// Swaps within a single tick range, returns `(amount_in, amount_out, fee, new_sqrt_price)`.
+    fn swap_step(
+        sqrt_price: Balance,
+        sqrt_price_next: Balance,
+        liquidity: Balance,
+        remaining: Balance,
+        exact_input: bool,
+        fee_parts: u32,
+        base_is_input: bool,
+    ) -> Option<(Balance, Balance, Balance, Balance)> {
+        if liquidity.is_zero() {
+            return Some((0, 0, 0, sqrt_price_next));
+        }
+        let input_delta = |from: Balance, to: Balance| {
+            if base_is_input {
+                math::base_delta(from, to, liquidity, true)
+            } else {
+                math::target_delta(from, to, liquidity, true)
+            }
+        };
+        let output_delta = |from: Balance, to: Balance| {
+            if base_is_input {
+                math::target_delta(from, to, liquidity, false)
+            } else {
+                math::base_delta(from, to, liquidity, false)
+            }
+        };
+        let clamp = |price: Balance| {
+            if base_is_input {
+                price.min(sqrt_price_next)
+            } else {
+                price.max(sqrt_price_next)
+            }
+        };
+
+        if exact_input {
+            let remaining_net = math::net_of_fee(remaining, fee_parts)?;
+            let max_in = input_delta(sqrt_price, sqrt_price_next)?;
+            let (amount_in, fee, sqrt_price_new) = if remaining_net >= max_in {
+                let fee = math::fee_on_net(max_in, fee_parts)?.min(remaining - max_in);
+                (max_in, fee, sqrt_price_next)
+            } else {
+                let sqrt_price_new = clamp(math::next_sqrt_price_from_input(
+                    sqrt_price,
+                    liquidity,
+                    remaining_net,
+                    base_is_input,
+                )?);
+                (remaining_net, remaining - remaining_net, sqrt_price_new)
+            };
+            let amount_out = output_delta(sqrt_price, sqrt_price_new)?;
+            Some((amount_in, amount_out, fee, sqrt_price_new))
+        } else {
+            let max_out = output_delta(sqrt_price, sqrt_price_next)?;
+            let (amount_out, sqrt_price_new) = if remaining >= max_out {
+                (max_out, sqrt_price_next)
+            } else {
+                let sqrt_price_new = clamp(math::next_sqrt_price_from_output(
+                    sqrt_price,
+                    liquidity,
+                    remaining,
+                    base_is_input,
+                )?);
+                (remaining, sqrt_price_new)
+            };
+            let amount_in = input_delta(sqrt_price, sqrt_price_new)?;
+            let fee = math::fee_on_net(amount_in, fee_parts)?;
+            Some((amount_in, amount_out, fee, sqrt_price_new))
+        }
+    }
+
+    fn apply_swap(pool_key: &PoolKeyOf<T>, pool: &mut PoolInfo<T::AccountId>, state: &SwapState) {
+        for (tick, growth_base, growth_target) in state.crossed_ticks.iter() {
+            Ticks::<T>::mutate_exists(pool_key, tick, |info| {
+                if let Some(info) = info {
+                    info.fee_growth_outside_base =
+                        growth_base.wrapping_sub(info.fee_growth_outside_base);
+                    info.fee_growth_outside_target =
+                        growth_target.wrapping_sub(info.fee_growth_outside_target);
+                }
+            });
+        }
+        pool.sqrt_price = state.sqrt_price;
+        pool.tick = state.tick;
+        pool.liquidity = state.liquidity;
+        pool.fee_growth_global_base = state.fee_growth_global_base;
+        pool.fee_growth_global_target = state.fee_growth_global_target;
+    }
+
+    /// Simulates the swap against the current pool state.
+    fn quote_filled(
+        pool_key: &PoolKeyOf<T>,
+        base_is_input: bool,
+        amount: QuoteAmount<Balance>,
+        deduce_fee: bool,
+    ) -> Result<SwapState, DispatchError> {
+        let pool = Pools::<T>::get(pool_key).ok_or(Error::<T>::PoolDoesNotExist)?;
+        Self::simulate_swap(pool_key, &pool, base_is_input, amount, deduce_fee)
+    }
+}
+
+impl<T: Config> LiquiditySource<T::DEXId, T::AccountId, AssetIdOf<T>, Balance, DispatchError>
+    for Pallet<T>
+{
+    fn can_exchange(
+        dex_id: &T::DEXId,
+        input_asset_id: &AssetIdOf<T>,
+        output_asset_id: &AssetIdOf<T>,
+    ) -> bool {
+        match Self::pool_key_for_swap(dex_id, input_asset_id, output_asset_id) {
+            Ok((pool_key, _)) => {
+                Pools::<T>::contains_key(&pool_key)
+                    && !InitializedTicks::<T>::get(&pool_key).is_empty()
+            }
+            Err(_) => false,
+        }
+    }
+
+    fn quote(
+        dex_id: &T::DEXId,
+        input_asset_id: &AssetIdOf<T>,
+        output_asset_id: &AssetIdOf<T>,
+        amount: QuoteAmount<Balance>,
+        deduce_fee: bool,
+    ) -> Result<(SwapOutcome<Balance, AssetIdOf<T>>, Weight), DispatchError> {
+        let (pool_key, base_is_input) =
+            Self::pool_key_for_swap(dex_id, input_asset_id, output_asset_id)?;
+        let state = Self::quote_filled(&pool_key, base_is_input, amount, deduce_fee)?;
+        ensure!(state.remaining.is_zero(), Error::<T>::NotEnoughLiquidity);
+        let calculated = match amount {
+            QuoteAmount::WithDesiredInput { .. } => state.output,
+            QuoteAmount::WithDesiredOutput { .. } => state.input,
+        };
+        let fee = OutcomeFee::from_asset(*input_asset_id, state.fee);
+        Ok((SwapOutcome::new(calculated, fee), Self::quote_weight()))
+    }
+
+    fn step_quote(
+        dex_id: &T::DEXId,
+        input_asset_id: &AssetIdOf<T>,
+        output_asset_id: &AssetIdOf<T>,
+        amount: QuoteAmount<Balance>,
+        recommended_samples_count: usize,
+        deduce_fee: bool,
+    ) -> Result<(DiscreteQuotation<AssetIdOf<T>, Balance>, Weight), DispatchError> {
+        let mut quotation = DiscreteQuotation::new();
+
+        if amount.amount().is_zero() {
+            return Ok((quotation, Weight::zero()));
+        }
+
+        let samples_count = recommended_samples_count.max(1);
+        let (pool_key, base_is_input) =
+            Self::pool_key_for_swap(dex_id, input_asset_id, output_asset_id)?;
+        let variant = amount.variant();
+
+        // limit the amount by the liquidity available in the swap direction
+        let full = Self::quote_filled(&pool_key, base_is_input, amount, deduce_fee)?;
+        let amount = if full.remaining.is_zero() {
+            amount.amount()
+        } else {
+            let filled = amount.amount().saturating_sub(full.remaining);
+            quotation.limits.max_amount = Some(match variant {
+                SwapVariant::WithDesiredInput => SideAmount::Input(filled),
+                SwapVariant::WithDesiredOutput => SideAmount::Output(filled),
+            });
+            filled
+        };
+        if amount.is_zero() {
+            return Ok((quotation, Self::step_quote_weight(samples_count)));
+        }
+
+        let common_step = amount / samples_count as Balance;
+        let mut volumes = Vec::new();
+        let mut remaining = amount;
+        for i in 1..samples_count {
+            volumes.push((common_step * i as Balance, common_step));
+            remaining = remaining.saturating_sub(common_step);
+        }
+        volumes.push((amount, remaining));
+
+        let mut sub_sum = Balance::zero();
+        let mut sub_fee = Balance::zero();
+        for (volume, step) in volumes {
+            let state = Self::quote_filled(
+                &pool_key,
+                base_is_input,
+                QuoteAmount::with_variant(variant, volume),
+                deduce_fee,
+            )?;
+            let calculated = match variant {
+                SwapVariant::WithDesiredInput => state.output,
+                SwapVariant::WithDesiredOutput => state.input,
+            };
+            let fee_chunk =
+                OutcomeFee::from_asset(*input_asset_id, state.fee.saturating_sub(sub_fee));
+            let chunk = match variant {
+                SwapVariant::WithDesiredInput => {
+                    SwapChunk::new(step, calculated.saturating_sub(sub_sum), fee_chunk)
+                }
+                SwapVariant::WithDesiredOutput => {
+                    SwapChunk::new(calculated.saturating_sub(sub_sum), step, fee_chunk)
+                }
+            };
+            sub_sum = calculated;
+            sub_fee = state.fee;
+            quotation.chunks.push_back(chunk);
+        }
+
+        Ok((quotation, Self::step_quote_weight(samples_count)))
+    }
+
+    fn exchange(
+        sender: &T::AccountId,
+        receiver: &T::AccountId,
+        dex_id: &T::DEXId,
+        input_asset_id: &AssetIdOf<T>,
+        output_asset_id: &AssetIdOf<T>,
+        swap_amount: SwapAmount<Balance>,
+    ) -> Result<(SwapOutcome<Balance, AssetIdOf<T>>, Weight), DispatchError> {
+        common::with_transaction(|| {
+            let (pool_key, base_is_input) =
+                Self::pool_key_for_swap(dex_id, input_asset_id, output_asset_id)?;
+            let mut pool = Pools::<T>::get(&pool_key).ok_or(Error::<T>::PoolDoesNotExist)?;
+            let state = Self::simulate_swap(
+                &pool_key,
+                &pool,
+                base_is_input,
+                swap_amount.clone().into(),
+                true,
+            )?;
+            ensure!(state.remaining.is_zero(), Error::<T>::NotEnoughLiquidity);
+
+            let calculated = match swap_amount {
+                SwapAmount::WithDesiredInput { min_amount_out, .. } => {
+                    ensure!(
+                        state.output >= min_amount_out,
+                        Error::<T>::SlippageLimitExceeded
+                    );
+                    state.output
+                }
+                SwapAmount::WithDesiredOutput { max_amount_in, .. } => {
+                    ensure!(
+                        state.input <= max_amount_in,
+                        Error::<T>::SlippageLimitExceeded
+                    );
+                    state.input
+                }
+            };
+
+            Self::apply_swap(&pool_key, &mut pool, &state);
+            Pools::<T>::insert(&pool_key, &pool);
+
+            T::AssetManager::transfer_from(input_asset_id, sender, &pool.account, state.input)?;
+            T::AssetManager::transfer_from(output_asset_id, &pool.account, receiver, state.output)?;
+
+            let fee = OutcomeFee::from_asset(*input_asset_id, state.fee);
+            Ok((SwapOutcome::new(calculated, fee), Self::exchange_weight()))
+        })
+    }
+
+    fn check_rewards(
+        _target_id: &T::DEXId,
+        _input_asset_id: &AssetIdOf<T>,
+        _output_asset_id: &AssetIdOf<T>,
+        _input_amount: Balance,
+        _output_amount: Balance,
+    ) -> Result<(Vec<(Balance, AssetIdOf<T>, RewardReason)>, Weight), DispatchError> {
+        // Concentrated liquidity pools have no rewards currently
+        Ok((Vec::new(), Weight::zero()))
+    }
+
+    fn quote_without_impact(
+        dex_id: &T::DEXId,
+        input_asset_id: &AssetIdOf<T>,
+        output_asset_id: &AssetIdOf<T>,
+        amount: QuoteAmount<Balance>,
+        deduce_fee: bool,
+    ) -> Result<SwapOutcome<Balance, AssetIdOf<T>>, DispatchError> {
+        let (pool_key, base_is_input) =
+            Self::pool_key_for_swap(dex_id, input_asset_id, output_asset_id)?;
+        let pool = Pools::<T>::get(&pool_key).ok_or(Error::<T>::PoolDoesNotExist)?;
+        ensure!(!pool.liquidity.is_zero(), Error::<T>::NotEnoughLiquidity);
+        let fee_parts = if deduce_fee {
+            pool.fee.deconstruct()
+        } else {
+            0
+        };
+        let price = math::mul_div(pool.sqrt_price, pool.sqrt_price, ONE)
+            .ok_or(Error::<T>::CalculationError)?;
+        // converts input into output at the spot price
+        let convert = |value: Balance| {
+            if base_is_input {
+                math::mul_div(value, ONE, price)
+            } else {
+                math::mul_div(value, price, ONE)
+            }
+        };
+        let convert_back = |value: Balance| {
+            if base_is_input {
+                math::mul_div_up(value, price, ONE)
+            } else {
+                math::mul_div_up(value, ONE, price)
+            }
+        };
+        let (calculated, fee) = match amount {
+            QuoteAmount::WithDesiredInput { desired_amount_in } => {
+                let net = math::net_of_fee(desired_amount_in, fee_parts)
+                    .ok_or(Error::<T>::CalculationError)?;
+                let output = convert(net).ok_or(Error::<T>::CalculationError)?;
+                (output, desired_amount_in - net)
+            }
+            QuoteAmount::WithDesiredOutput { desired_amount_out } => {
+                let net = convert_back(desired_amount_out).ok_or(Error::<T>::CalculationError)?;
+                let fee = math::fee_on_net(net, fee_parts).ok_or(Error::<T>::CalculationError)?;
+                (net.saturating_add(fee), fee)
+            }
+        };
+        Ok(SwapOutcome::new(
+            calculated,
+            OutcomeFee::from_asset(*input_asset_id, fee),
+        ))
+    }
+
+    fn quote_weight() -> Weight {
+        <T as Config>::WeightInfo::quote()
+    }
+
+    fn step_quote_weight(samples_count: usize) -> Weight {
+        <T as Config>::WeightInfo::step_quote(samples_count as u32)
+    }
+
+    fn exchange_weight() -> Weight {
+        <T as Config>::WeightInfo::exchange()
+    }
+
+    fn check_rewards_weight() -> Weight {
+        Weight::zero()
+    }
+}
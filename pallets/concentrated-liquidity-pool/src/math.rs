@@ -0,0 +1,293 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Fixed-point helpers for concentrated liquidity.
+//!
+//! Square root prices, liquidity and amounts are all stored with 18 decimals, the same
+//! precision as [`Balance`]. The price is expressed in base asset per target asset, so moving
+//! the price up means the pool receives the base asset and gives away the target asset.
+
+use common::prelude::Balance;
+use sp_core::U256;
+
+/// Smallest tick a position boundary can be set to.
+pub const MIN_TICK: i32 = -200_000;
+/// Largest tick a position boundary can be set to.
+pub const MAX_TICK: i32 = 200_000;
+/// Fixed-point one.
+pub const ONE: Balance = 1_000_000_000_000_000_000;
+/// Denominator of `Permill` fee parts.
+const FEE_DENOMINATOR: Balance = 1_000_000;
+
+/// `sqrt(1.0001) ^ (2 ^ i)` with 18 decimals, used to build the sqrt price of a tick bit by bit.
+const SQRT_POWERS: [Balance; 18] = [
+    1_000_049_998_750_062_496,
+    1_000_100_000_000_000_000,
+    1_000_200_010_000_000_000,
+    1_000_400_060_004_000_100,
+    1_000_800_280_056_007_000,
+    1_001_601_200_560_182_043,
+    1_003_204_964_963_598_014,
+    1_006_420_201_727_613_920,
+    1_012_881_622_445_451_097,
+    1_025_929_181_087_729_343,
+    1_052_530_684_607_338_948,
+    1_107_820_842_039_993_613,
+    1_227_267_018_058_200_482,
+    1_506_184_333_613_467_388,
+    2_268_591_246_822_644_826,
+    5_146_506_245_160_322_222,
+    26_486_526_531_474_198_664,
+    701_536_087_702_486_644_953,
+];
+
+/// `a * b / c` rounded down.
+pub fn mul_div(a: Balance, b: Balance, c: Balance) -> Option<Balance> {
+    if c == 0 {
+        return None;
+    }
+    let result = U256::from(a).checked_mul(U256::from(b))? / U256::from(c);
+    result.try_into().ok()
+}
+
+/// `a * b / c` rounded up.
+pub fn mul_div_up(a: Balance, b: Balance, c: Balance) -> Option<Balance> {
+    if c == 0 {
+        return None;
+    }
+    let product = U256::from(a).checked_mul(U256::from(b))?;
+    let c = U256::from(c);
+    let mut result = product / c;
+    if !(product % c).is_zero() {
+        result = result.checked_add(U256::one())?;
+    }
+    result.try_into().ok()
+}
+
+/// Square root of `1.0001 ^ tick` with 18 decimals.
+pub fn sqrt_price_at_tick(tick: i32) -> Option<Balance> {
+    if !(MIN_TICK..=MAX_TICK).contains(&tick) {
+        return None;
+    }
+    let abs_tick = tick.unsigned_abs();
+    let mut result = ONE;
+    for (i, power) in SQRT_POWERS.iter().enumerate() {
+        if abs_tick & (1 << i) != 0 {
+            result = mul_div(result, *power, ONE)?;
+        }
+    }
+    if tick < 0 {
+        result = mul_div(ONE, ONE, result)?;
+    }
+    Some(result)
+}
+
+/// The greatest tick whose sqrt price does not exceed `sqrt_price`.
+pub fn tick_at_sqrt_price(sqrt_price: Balance) -> Option<i32> {
+    let (mut low, mut high) = (MIN_TICK, MAX_TICK);
+    if sqrt_price < sqrt_price_at_tick(low)? {
+        return None;
+    }
+    if sqrt_price >= sqrt_price_at_tick(high)? {
+        return Some(high);
+    }
+    // invariant: price(low) <= sqrt_price < price(high)
+    while high - low > 1 {
+        let mid = low + (high - low) / 2;
+        if sqrt_price_at_tick(mid)? <= sqrt_price {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    Some(low)
+}
+
+/// Amount of the target asset held by `liquidity` between two sqrt prices.
+pub fn target_delta(
+    sqrt_price_a: Balance,
+    sqrt_price_b: Balance,
+    liquidity: Balance,
+    round_up: bool,
+) -> Option<Balance> {
+    let (lower, upper) = ordered(sqrt_price_a, sqrt_price_b);
+    if lower == 0 {
+        return None;
+    }
+    let diff = upper - lower;
+    if round_up {
+        mul_div_up(mul_div_up(liquidity, diff, upper)?, ONE, lower)
+    } else {
+        mul_div(mul_div(liquidity, diff, upper)?, ONE, lower)
+    }
+}
+
+/// Amount of the base asset held by `liquidity` between two sqrt prices.
+pub fn base_delta(
+    sqrt_price_a: Balance,
+    sqrt_price_b: Balance,
+    liquidity: Balance,
+    round_up: bool,
+) -> Option<Balance> {
+    let (lower, upper) = ordered(sqrt_price_a, sqrt_price_b);
+    if round_up {
+        mul_div_up(liquidity, upper - lower, ONE)
+    } else {
+        mul_div(liquidity, upper - lower, ONE)
+    }
+}
+
+/// Liquidity that `target_amount` provides between two sqrt prices.
+pub fn liquidity_for_target(
+    sqrt_price_a: Balance,
+    sqrt_price_b: Balance,
+    target_amount: Balance,
+) -> Option<Balance> {
+    let (lower, upper) = ordered(sqrt_price_a, sqrt_price_b);
+    let intermediate = mul_div(lower, upper, ONE)?;
+    mul_div(target_amount, intermediate, upper - lower)
+}
+
+/// Liquidity that `base_amount` provides between two sqrt prices.
+pub fn liquidity_for_base(
+    sqrt_price_a: Balance,
+    sqrt_price_b: Balance,
+    base_amount: Balance,
+) -> Option<Balance> {
+    let (lower, upper) = ordered(sqrt_price_a, sqrt_price_b);
+    mul_div(base_amount, ONE, upper - lower)
+}
+
+/// Maximum liquidity that can be minted in `[sqrt_price_lower, sqrt_price_upper]` at the
+/// current price from the desired amounts.
+pub fn liquidity_for_amounts(
+    sqrt_price: Balance,
+    sqrt_price_lower: Balance,
+    sqrt_price_upper: Balance,
+    base_amount: Balance,
+    target_amount: Balance,
+) -> Option<Balance> {
+    if sqrt_price <= sqrt_price_lower {
+        liquidity_for_target(sqrt_price_lower, sqrt_price_upper, target_amount)
+    } else if sqrt_price < sqrt_price_upper {
+        let from_target = liquidity_for_target(sqrt_price, sqrt_price_upper, target_amount)?;
+        let from_base = liquidity_for_base(sqrt_price_lower, sqrt_price, base_amount)?;
+        Some(from_target.min(from_base))
+    } else {
+        liquidity_for_base(sqrt_price_lower, sqrt_price_upper, base_amount)
+    }
+}
+
+/// Amounts of `(base, target)` represented by `liquidity` in `[sqrt_price_lower, sqrt_price_upper]`
+/// at the current price.
+pub fn amounts_for_liquidity(
+    sqrt_price: Balance,
+    sqrt_price_lower: Balance,
+    sqrt_price_upper: Balance,
+    liquidity: Balance,
+    round_up: bool,
+) -> Option<(Balance, Balance)> {
+    if sqrt_price <= sqrt_price_lower {
+        let target = target_delta(sqrt_price_lower, sqrt_price_upper, liquidity, round_up)?;
+        Some((0, target))
+    } else if sqrt_price < sqrt_price_upper {
+        let base = base_delta(sqrt_price_lower, sqrt_price, liquidity, round_up)?;
+        let target = target_delta(sqrt_price, sqrt_price_upper, liquidity, round_up)?;
+        Some((base, target))
+    } else {
+        let base = base_delta(sqrt_price_lower, sqrt_price_upper, liquidity, round_up)?;
+        Some((base, 0))
+    }
+}
+
+/// Sqrt price after `amount` of the input asset is added to the pool.
+pub fn next_sqrt_price_from_input(
+    sqrt_price: Balance,
+    liquidity: Balance,
+    amount: Balance,
+    base_is_input: bool,
+) -> Option<Balance> {
+    if base_is_input {
+        // price moves up: s' = s + a / L
+        sqrt_price.checked_add(mul_div(amount, ONE, liquidity)?)
+    } else {
+        // price moves down: s' = L * s / (L + a * s)
+        let denominator = liquidity.checked_add(mul_div(amount, sqrt_price, ONE)?)?;
+        mul_div_up(liquidity, sqrt_price, denominator)
+    }
+}
+
+/// Sqrt price after `amount` of the output asset is removed from the pool.
+pub fn next_sqrt_price_from_output(
+    sqrt_price: Balance,
+    liquidity: Balance,
+    amount: Balance,
+    base_is_input: bool,
+) -> Option<Balance> {
+    if base_is_input {
+        // target leaves the pool, price moves up: s' = L * s / (L - a * s)
+        let denominator = liquidity.checked_sub(mul_div_up(amount, sqrt_price, ONE)?)?;
+        if denominator == 0 {
+            return None;
+        }
+        mul_div_up(liquidity, sqrt_price, denominator)
+    } else {
+        // base leaves the pool, price moves down: s' = s - a / L
+        sqrt_price.checked_sub(mul_div_up(amount, ONE, liquidity)?)
+    }
+}
+
+/// Fee charged on top of `amount_in` so that it makes up `1 - fee` of the gross input.
+pub fn fee_on_net(amount_in: Balance, fee_parts: u32) -> Option<Balance> {
+    let fee_parts = Balance::from(fee_parts);
+    mul_div_up(
+        amount_in,
+        fee_parts,
+        FEE_DENOMINATOR.checked_sub(fee_parts)?,
+    )
+}
+
+/// Part of the gross input that remains after the fee is taken.
+pub fn net_of_fee(amount_in: Balance, fee_parts: u32) -> Option<Balance> {
+    let fee_parts = Balance::from(fee_parts);
+    mul_div(
+        amount_in,
+        FEE_DENOMINATOR.checked_sub(fee_parts)?,
+        FEE_DENOMINATOR,
+    )
+}
+
+fn ordered(a: Balance, b: Balance) -> (Balance, Balance) {
+    if a <= b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
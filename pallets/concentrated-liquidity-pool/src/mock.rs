@@ -0,0 +1,176 @@
+#![allow(deprecated, dead_code, unused_imports)]
+
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate as concentrated_liquidity_pool;
+
+use common::mock::ExistentialDeposits;
+use common::{
+    balance, mock_assets_config, mock_common_config, mock_concentrated_liquidity_pool_config,
+    mock_currencies_config, mock_dex_manager_config, mock_frame_system_config,
+    mock_pallet_balances_config, mock_permissions_config, mock_technical_config,
+    mock_tokens_config, mock_trading_pair_config, Amount, AssetId32, AssetName, AssetSymbol, DEXId,
+    DEXInfo, PredefinedAssetId, TradingPair, DEFAULT_BALANCE_PRECISION, PSWAP, VAL, XOR, XST,
+};
+use currencies::BasicCurrencyAdapter;
+use frame_support::parameter_types;
+use sp_core::crypto::AccountId32;
+use sp_runtime::BuildStorage;
+
+pub type AccountId = AccountId32;
+type AssetId = AssetId32<PredefinedAssetId>;
+type Balance = u128;
+type Block = frame_system::mocking::MockBlock<TestRuntime>;
+type BlockNumber = u64;
+type TechAccountId = common::TechAccountId<AccountId, TechAssetId, DEXId>;
+type TechAssetId = common::TechAssetId<PredefinedAssetId>;
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<TestRuntime>;
+
+pub const DEX_ID: DEXId = DEXId::Polkaswap;
+
+frame_support::construct_runtime!(
+    pub enum TestRuntime where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
+        Permissions: permissions::{Pallet, Call, Config<T>, Storage, Event<T>},
+        DexManager: dex_manager::{Pallet, Call, Config<T>, Storage, Event<T>},
+        TradingPair: trading_pair::{Pallet, Call, Config<T>, Storage, Event<T>},
+        Balances: pallet_balances::{Pallet, Call, Storage, Event<T>},
+        Tokens: tokens::{Pallet, Call, Config<T>, Storage, Event<T>},
+        Currencies: currencies::{Pallet, Call, Storage},
+        Assets: assets::{Pallet, Call, Config<T>, Storage, Event<T>},
+        Technical: technical::{Pallet, Call, Config<T>, Event<T>},
+        ConcentratedLiquidityPool: concentrated_liquidity_pool::{Pallet, Call, Storage, Event<T>},
+    }
+);
+
+parameter_types! {
+    pub const GetBaseAssetId: AssetId = XOR;
+    pub const GetBuyBackAssetId: AssetId = XST;
+}
+
+mock_assets_config!(TestRuntime);
+mock_common_config!(TestRuntime);
+mock_concentrated_liquidity_pool_config!(TestRuntime);
+mock_currencies_config!(TestRuntime);
+mock_dex_manager_config!(TestRuntime);
+mock_frame_system_config!(TestRuntime);
+mock_pallet_balances_config!(TestRuntime);
+mock_permissions_config!(TestRuntime);
+mock_technical_config!(TestRuntime);
+mock_tokens_config!(TestRuntime);
+mock_trading_pair_config!(TestRuntime);
+
+#[allow(non_snake_case)]
+pub fn ALICE() -> AccountId {
+    AccountId32::from([1; 32])
+}
+
+#[allow(non_snake_case)]
+pub fn BOB() -> AccountId {
+    AccountId32::from([2; 32])
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = SystemConfig::default().build_storage().unwrap();
+
+    dex_manager::GenesisConfig::<TestRuntime> {
+        dex_list: vec![(
+            DEX_ID,
+            DEXInfo {
+                base_asset_id: PSWAP,
+                synthetic_base_asset_id: XST,
+                is_public: true,
+            },
+        )],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    assets::GenesisConfig::<TestRuntime> {
+        endowed_assets: [
+            (XOR, "XOR", "SORA"),
+            (VAL, "VAL", "SORA Token"),
+            (PSWAP, "PSWAP", "Polkaswap"),
+        ]
+        .into_iter()
+        .map(|(asset_id, symbol, name)| {
+            (
+                asset_id,
+                ALICE(),
+                AssetSymbol(symbol.as_bytes().to_vec()),
+                AssetName(name.as_bytes().to_vec()),
+                DEFAULT_BALANCE_PRECISION,
+                balance!(0),
+                true,
+                None,
+                None,
+            )
+        })
+        .collect(),
+        regulated_assets: Default::default(),
+        sbt_assets: Default::default(),
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    tokens::GenesisConfig::<TestRuntime> {
+        balances: vec![
+            (ALICE(), PSWAP, balance!(1000000)),
+            (ALICE(), VAL, balance!(1000000)),
+            (BOB(), PSWAP, balance!(1000000)),
+            (BOB(), VAL, balance!(1000000)),
+        ],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    trading_pair::GenesisConfig::<TestRuntime> {
+        trading_pairs: vec![(
+            DEX_ID,
+            TradingPair {
+                base_asset_id: PSWAP,
+                target_asset_id: VAL,
+            },
+        )],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext: sp_io::TestExternalities = t.into();
+    ext.execute_with(|| {
+        System::set_block_number(1); // No events in zero block
+    });
+    ext
+}
@@ -0,0 +1,580 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate::mock::*;
+use crate::{math, Error, Event, Pallet, Pools, Positions};
+use common::prelude::{QuoteAmount, SwapAmount};
+use common::{
+    balance, AssetInfoProvider, AssetType, LiquiditySource, LiquiditySourceType,
+    TradingPairSourceManager, PSWAP, VAL,
+};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::Permill;
+
+type ClPool = Pallet<TestRuntime>;
+type AssetId = common::AssetId32<common::PredefinedAssetId>;
+
+const FEE: Permill = Permill::from_parts(3000);
+
+fn create_pool() {
+    assert_ok!(ClPool::create_pool(
+        RuntimeOrigin::signed(ALICE()),
+        DEX_ID,
+        PSWAP,
+        VAL,
+        FEE,
+        10,
+        balance!(1),
+    ));
+}
+
+fn mint(who: AccountId, tick_lower: i32, tick_upper: i32, amount: u128) -> AssetId {
+    assert_ok!(ClPool::mint_position(
+        RuntimeOrigin::signed(who),
+        DEX_ID,
+        PSWAP,
+        VAL,
+        tick_lower,
+        tick_upper,
+        amount,
+        amount,
+        0,
+        0,
+    ));
+    let position_id = System::events()
+        .into_iter()
+        .find_map(|record| match record.event {
+            RuntimeEvent::ConcentratedLiquidityPool(Event::PositionMinted {
+                position_id, ..
+            }) => Some(position_id),
+            _ => None,
+        })
+        .expect("position is minted");
+    System::reset_events();
+    position_id
+}
+
+fn free_balance(asset_id: &AssetId, who: &AccountId) -> u128 {
+    assets::Pallet::<TestRuntime>::free_balance(asset_id, who).unwrap()
+}
+
+fn assert_approx(left: u128, right: u128, tolerance: u128) {
+    assert!(
+        left.abs_diff(right) <= tolerance,
+        "{left} differs from {right} by more than {tolerance}"
+    );
+}
+
+#[test]
+fn tick_math_round_trips() {
+    assert_eq!(math::sqrt_price_at_tick(0), Some(math::ONE));
+    assert_eq!(math::sqrt_price_at_tick(math::MAX_TICK + 1), None);
+    for tick in [
+        math::MIN_TICK,
+        -123_457,
+        -1,
+        0,
+        1,
+        999,
+        54_321,
+        math::MAX_TICK,
+    ] {
+        let sqrt_price = math::sqrt_price_at_tick(tick).unwrap();
+        assert_eq!(math::tick_at_sqrt_price(sqrt_price), Some(tick));
+        if tick > math::MIN_TICK {
+            assert_eq!(math::tick_at_sqrt_price(sqrt_price - 1), Some(tick - 1));
+        }
+    }
+}
+
+#[test]
+fn create_pool_works() {
+    new_test_ext().execute_with(|| {
+        create_pool();
+        let pool = Pools::<TestRuntime>::get((
+            DEX_ID,
+            common::TradingPair {
+                base_asset_id: PSWAP,
+                target_asset_id: VAL,
+            },
+        ))
+        .unwrap();
+        assert_eq!(pool.sqrt_price, balance!(1));
+        assert_eq!(pool.tick, 0);
+        assert_eq!(pool.liquidity, 0);
+        assert!(
+            trading_pair::Pallet::<TestRuntime>::is_source_enabled_for_trading_pair(
+                &DEX_ID,
+                &PSWAP,
+                &VAL,
+                LiquiditySourceType::ConcentratedLiquidityPool,
+            )
+            .unwrap()
+        );
+
+        assert_noop!(
+            ClPool::create_pool(
+                RuntimeOrigin::signed(ALICE()),
+                DEX_ID,
+                PSWAP,
+                VAL,
+                FEE,
+                10,
+                balance!(1),
+            ),
+            Error::<TestRuntime>::PoolAlreadyExists
+        );
+    });
+}
+
+#[test]
+fn create_pool_fails_with_invalid_parameters() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ClPool::create_pool(
+                RuntimeOrigin::signed(ALICE()),
+                DEX_ID,
+                PSWAP,
+                common::XOR,
+                FEE,
+                10,
+                balance!(1),
+            ),
+            Error::<TestRuntime>::TradingPairDoesNotExist
+        );
+        assert_noop!(
+            ClPool::create_pool(
+                RuntimeOrigin::signed(ALICE()),
+                DEX_ID,
+                VAL,
+                PSWAP,
+                FEE,
+                10,
+                balance!(1),
+            ),
+            Error::<TestRuntime>::BaseAssetIsNotInPair
+        );
+        assert_noop!(
+            ClPool::create_pool(
+                RuntimeOrigin::signed(ALICE()),
+                DEX_ID,
+                PSWAP,
+                VAL,
+                Permill::from_percent(11),
+                10,
+                balance!(1),
+            ),
+            Error::<TestRuntime>::InvalidFee
+        );
+        assert_noop!(
+            ClPool::create_pool(
+                RuntimeOrigin::signed(ALICE()),
+                DEX_ID,
+                PSWAP,
+                VAL,
+                FEE,
+                0,
+                balance!(1),
+            ),
+            Error::<TestRuntime>::InvalidTickSpacing
+        );
+        assert_noop!(
+            ClPool::create_pool(
+                RuntimeOrigin::signed(ALICE()),
+                DEX_ID,
+                PSWAP,
+                VAL,
+                FEE,
+                10,
+                0,
+            ),
+            Error::<TestRuntime>::InvalidInitialPrice
+        );
+    });
+}
+
+#[test]
+fn mint_position_issues_nft() {
+    new_test_ext().execute_with(|| {
+        create_pool();
+        let base_before = free_balance(&PSWAP, &ALICE());
+        let target_before = free_balance(&VAL, &ALICE());
+
+        let position_id = mint(ALICE(), -1000, 1000, balance!(1000));
+
+        assert_eq!(free_balance(&position_id, &ALICE()), 1);
+        assert_eq!(
+            assets::Pallet::<TestRuntime>::get_asset_type(&position_id),
+            AssetType::NFT
+        );
+        let position = Positions::<TestRuntime>::get(position_id).unwrap();
+        assert_eq!((position.tick_lower, position.tick_upper), (-1000, 1000));
+        assert!(position.liquidity > 0);
+
+        // price is in the middle of the range, so both assets are deposited
+        assert_approx(
+            base_before - free_balance(&PSWAP, &ALICE()),
+            balance!(1000),
+            balance!(0.001),
+        );
+        assert_approx(
+            target_before - free_balance(&VAL, &ALICE()),
+            balance!(1000),
+            balance!(0.001),
+        );
+        assert_eq!(
+            ClPool::initialized_ticks((DEX_ID, position.trading_pair)).into_inner(),
+            vec![-1000, 1000]
+        );
+    });
+}
+
+#[test]
+fn mint_position_out_of_range_takes_single_asset() {
+    new_test_ext().execute_with(|| {
+        create_pool();
+        let base_before = free_balance(&PSWAP, &ALICE());
+        let target_before = free_balance(&VAL, &ALICE());
+
+        // range above the current price holds only the target asset
+        mint(ALICE(), 100, 1000, balance!(1000));
+        assert_eq!(free_balance(&PSWAP, &ALICE()), base_before);
+        assert_approx(
+            target_before - free_balance(&VAL, &ALICE()),
+            balance!(1000),
+            balance!(0.001),
+        );
+
+        // range below the current price holds only the base asset
+        let target_before = free_balance(&VAL, &ALICE());
+        mint(ALICE(), -1000, -100, balance!(1000));
+        assert_eq!(free_balance(&VAL, &ALICE()), target_before);
+    });
+}
+
+#[test]
+fn mint_position_validates_ticks() {
+    new_test_ext().execute_with(|| {
+        create_pool();
+        assert_noop!(
+            ClPool::mint_position(
+                RuntimeOrigin::signed(ALICE()),
+                DEX_ID,
+                PSWAP,
+                VAL,
+                1000,
+                -1000,
+                balance!(1),
+                balance!(1),
+                0,
+                0,
+            ),
+            Error::<TestRuntime>::InvalidTickRange
+        );
+        assert_noop!(
+            ClPool::mint_position(
+                RuntimeOrigin::signed(ALICE()),
+                DEX_ID,
+                PSWAP,
+                VAL,
+                -1005,
+                1000,
+                balance!(1),
+                balance!(1),
+                0,
+                0,
+            ),
+            Error::<TestRuntime>::TickNotAlignedWithSpacing
+        );
+        assert_noop!(
+            ClPool::mint_position(
+                RuntimeOrigin::signed(ALICE()),
+                DEX_ID,
+                PSWAP,
+                VAL,
+                -1000,
+                1000,
+                balance!(1),
+                balance!(1),
+                balance!(2),
+                0,
+            ),
+            Error::<TestRuntime>::SlippageLimitExceeded
+        );
+    });
+}
+
+#[test]
+fn exchange_matches_quote_and_accrues_fees() {
+    new_test_ext().execute_with(|| {
+        create_pool();
+        let position_id = mint(ALICE(), -1000, 1000, balance!(1000));
+
+        let (outcome, _) = ClPool::quote(
+            &DEX_ID,
+            &PSWAP,
+            &VAL,
+            QuoteAmount::with_desired_input(balance!(100)),
+            true,
+        )
+        .unwrap();
+        assert!(outcome.amount < balance!(100));
+        assert_eq!(outcome.fee.get_by_asset(&PSWAP), balance!(0.3));
+
+        let target_before = free_balance(&VAL, &BOB());
+        let (exchanged, _) = ClPool::exchange(
+            &BOB(),
+            &BOB(),
+            &DEX_ID,
+            &PSWAP,
+            &VAL,
+            SwapAmount::with_desired_input(balance!(100), outcome.amount),
+        )
+        .unwrap();
+        assert_eq!(exchanged.amount, outcome.amount);
+        assert_eq!(free_balance(&VAL, &BOB()) - target_before, outcome.amount);
+
+        // the only position gets the whole fee
+        let base_before = free_balance(&PSWAP, &ALICE());
+        assert_ok!(ClPool::collect_fees(
+            RuntimeOrigin::signed(ALICE()),
+            position_id
+        ));
+        assert_approx(
+            free_balance(&PSWAP, &ALICE()) - base_before,
+            balance!(0.3),
+            1_000,
+        );
+        let position = Positions::<TestRuntime>::get(position_id).unwrap();
+        assert_eq!(position.tokens_owed_base, 0);
+        assert_eq!(position.tokens_owed_target, 0);
+    });
+}
+
+#[test]
+fn exchange_respects_slippage_limits() {
+    new_test_ext().execute_with(|| {
+        create_pool();
+        mint(ALICE(), -1000, 1000, balance!(1000));
+
+        assert_noop!(
+            ClPool::exchange(
+                &BOB(),
+                &BOB(),
+                &DEX_ID,
+                &VAL,
+                &PSWAP,
+                SwapAmount::with_desired_input(balance!(10), balance!(10)),
+            ),
+            Error::<TestRuntime>::SlippageLimitExceeded
+        );
+        assert_noop!(
+            ClPool::exchange(
+                &BOB(),
+                &BOB(),
+                &DEX_ID,
+                &VAL,
+                &PSWAP,
+                SwapAmount::with_desired_output(balance!(10), balance!(10)),
+            ),
+            Error::<TestRuntime>::SlippageLimitExceeded
+        );
+        // more than the range holds
+        assert_noop!(
+            ClPool::quote(
+                &DEX_ID,
+                &VAL,
+                &PSWAP,
+                QuoteAmount::with_desired_output(balance!(2000)),
+                true,
+            ),
+            Error::<TestRuntime>::NotEnoughLiquidity
+        );
+    });
+}
+
+#[test]
+fn exchange_crosses_ticks() {
+    new_test_ext().execute_with(|| {
+        create_pool();
+        let narrow = mint(ALICE(), -100, 100, balance!(100));
+        let wide = mint(BOB(), -2000, 2000, balance!(100));
+        let pool_key = (
+            DEX_ID,
+            common::TradingPair {
+                base_asset_id: PSWAP,
+                target_asset_id: VAL,
+            },
+        );
+        let narrow_liquidity = Positions::<TestRuntime>::get(narrow).unwrap().liquidity;
+        let wide_liquidity = Positions::<TestRuntime>::get(wide).unwrap().liquidity;
+        assert_eq!(
+            Pools::<TestRuntime>::get(&pool_key).unwrap().liquidity,
+            narrow_liquidity + wide_liquidity
+        );
+
+        assert_ok!(ClPool::exchange(
+            &BOB(),
+            &BOB(),
+            &DEX_ID,
+            &PSWAP,
+            &VAL,
+            SwapAmount::with_desired_input(balance!(150), 0),
+        ));
+        let pool = Pools::<TestRuntime>::get(&pool_key).unwrap();
+        assert!(pool.tick >= 100);
+        assert_eq!(pool.liquidity, wide_liquidity);
+
+        // narrow position is out of range and holds only the base asset now
+        let base_before = free_balance(&PSWAP, &ALICE());
+        let target_before = free_balance(&VAL, &ALICE());
+        assert_ok!(ClPool::decrease_liquidity(
+            RuntimeOrigin::signed(ALICE()),
+            narrow,
+            narrow_liquidity,
+            0,
+            0,
+        ));
+        assert!(free_balance(&PSWAP, &ALICE()) > base_before);
+        assert_eq!(free_balance(&VAL, &ALICE()), target_before);
+
+        // swapping back returns the price into the narrow range without its liquidity
+        assert_ok!(ClPool::exchange(
+            &BOB(),
+            &BOB(),
+            &DEX_ID,
+            &VAL,
+            &PSWAP,
+            SwapAmount::with_desired_input(balance!(80), 0),
+        ));
+        let pool = Pools::<TestRuntime>::get(&pool_key).unwrap();
+        assert!(pool.tick < 0);
+        assert_eq!(pool.liquidity, wide_liquidity);
+        assert_eq!(
+            ClPool::initialized_ticks(&pool_key).into_inner(),
+            vec![-2000, 2000]
+        );
+    });
+}
+
+#[test]
+fn step_quote_sums_up_to_quote() {
+    new_test_ext().execute_with(|| {
+        create_pool();
+        mint(ALICE(), -1000, 1000, balance!(1000));
+        mint(ALICE(), -200, 200, balance!(1000));
+
+        let amount = QuoteAmount::with_desired_input(balance!(500));
+        let (quotation, _) = ClPool::step_quote(&DEX_ID, &PSWAP, &VAL, amount, 10, true).unwrap();
+        let (outcome, _) = ClPool::quote(&DEX_ID, &PSWAP, &VAL, amount, true).unwrap();
+        assert_eq!(quotation.chunks.len(), 10);
+        assert_eq!(
+            quotation
+                .chunks
+                .iter()
+                .map(|chunk| chunk.input)
+                .sum::<u128>(),
+            balance!(500)
+        );
+        assert_eq!(
+            quotation
+                .chunks
+                .iter()
+                .map(|chunk| chunk.output)
+                .sum::<u128>(),
+            outcome.amount
+        );
+        assert!(quotation.limits.max_amount.is_none());
+
+        // requesting more than available is limited by the liquidity in range
+        let (quotation, _) = ClPool::step_quote(
+            &DEX_ID,
+            &VAL,
+            &PSWAP,
+            QuoteAmount::with_desired_output(balance!(5000)),
+            10,
+            true,
+        )
+        .unwrap();
+        assert!(quotation.limits.max_amount.is_some());
+        assert!(
+            quotation
+                .chunks
+                .iter()
+                .map(|chunk| chunk.output)
+                .sum::<u128>()
+                < balance!(5000)
+        );
+    });
+}
+
+#[test]
+fn position_belongs_to_nft_holder() {
+    new_test_ext().execute_with(|| {
+        create_pool();
+        let position_id = mint(ALICE(), -1000, 1000, balance!(10));
+        let liquidity = Positions::<TestRuntime>::get(position_id)
+            .unwrap()
+            .liquidity;
+
+        assert_noop!(
+            ClPool::decrease_liquidity(RuntimeOrigin::signed(BOB()), position_id, liquidity, 0, 0),
+            Error::<TestRuntime>::NotPositionOwner
+        );
+        assert_ok!(assets::Pallet::<TestRuntime>::transfer(
+            RuntimeOrigin::signed(ALICE()),
+            position_id,
+            BOB(),
+            1,
+        ));
+        assert_noop!(
+            ClPool::collect_fees(RuntimeOrigin::signed(ALICE()), position_id),
+            Error::<TestRuntime>::NotPositionOwner
+        );
+
+        assert_noop!(
+            ClPool::burn_position(RuntimeOrigin::signed(BOB()), position_id),
+            Error::<TestRuntime>::PositionNotEmpty
+        );
+        assert_ok!(ClPool::decrease_liquidity(
+            RuntimeOrigin::signed(BOB()),
+            position_id,
+            liquidity,
+            0,
+            0,
+        ));
+        assert_ok!(ClPool::burn_position(
+            RuntimeOrigin::signed(BOB()),
+            position_id
+        ));
+        assert_eq!(free_balance(&position_id, &BOB()), 0);
+        assert!(Positions::<TestRuntime>::get(position_id).is_none());
+    });
+}
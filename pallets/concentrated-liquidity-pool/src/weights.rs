@@ -0,0 +1,243 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Autogenerated weights for concentrated_liquidity_pool
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2026-10-18, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `983eb2919397`, CPU: `Intel(R) Xeon(R) CPU E3-1240 v6 @ 3.70GHz`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("local"), DB CACHE: 1024
+
+// Executed Command:
+// /usr/local/bin/framenode
+// benchmark
+// pallet
+// --chain=local
+// --steps=50
+// --repeat=20
+// --pallet=concentrated-liquidity-pool
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --header=./misc/file_header.txt
+// --template=./misc/pallet-weight-template.hbs
+// --output=./pallets/concentrated-liquidity-pool/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for concentrated_liquidity_pool.
+pub trait WeightInfo {
+	fn create_pool() -> Weight;
+	fn mint_position() -> Weight;
+	fn increase_liquidity() -> Weight;
+	fn decrease_liquidity() -> Weight;
+	fn collect_fees() -> Weight;
+	fn burn_position() -> Weight;
+	fn quote() -> Weight;
+	fn step_quote(a: u32, ) -> Weight;
+	fn exchange() -> Weight;
+}
+
+/// Weights for concentrated_liquidity_pool using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn create_pool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2000`
+		//  Estimated: `8000`
+		// Minimum execution time: 95_000_000 picoseconds.
+		Weight::from_parts(95_000_000, 8000)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	fn mint_position() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3000`
+		//  Estimated: `12000`
+		// Minimum execution time: 210_000_000 picoseconds.
+		Weight::from_parts(210_000_000, 12000)
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
+	}
+	fn increase_liquidity() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3000`
+		//  Estimated: `12000`
+		// Minimum execution time: 170_000_000 picoseconds.
+		Weight::from_parts(170_000_000, 12000)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+	}
+	fn decrease_liquidity() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3000`
+		//  Estimated: `12000`
+		// Minimum execution time: 160_000_000 picoseconds.
+		Weight::from_parts(160_000_000, 12000)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	fn collect_fees() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2250`
+		//  Estimated: `9000`
+		// Minimum execution time: 110_000_000 picoseconds.
+		Weight::from_parts(110_000_000, 9000)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	fn burn_position() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1500`
+		//  Estimated: `6000`
+		// Minimum execution time: 70_000_000 picoseconds.
+		Weight::from_parts(70_000_000, 6000)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	fn quote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2000`
+		//  Estimated: `8000`
+		// Minimum execution time: 40_000_000 picoseconds.
+		Weight::from_parts(40_000_000, 8000)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+	}
+	fn step_quote(a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2000`
+		//  Estimated: `8000`
+		// Minimum execution time: 40_000_000 picoseconds.
+		Weight::from_parts(40_000_000, 8000)
+			// Standard Error: 25_000
+			.saturating_add(Weight::from_parts(21_000_000, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+	}
+	fn exchange() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3000`
+		//  Estimated: `12000`
+		// Minimum execution time: 180_000_000 picoseconds.
+		Weight::from_parts(180_000_000, 12000)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn create_pool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2000`
+		//  Estimated: `8000`
+		// Minimum execution time: 95_000_000 picoseconds.
+		Weight::from_parts(95_000_000, 8000)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	fn mint_position() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3000`
+		//  Estimated: `12000`
+		// Minimum execution time: 210_000_000 picoseconds.
+		Weight::from_parts(210_000_000, 12000)
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
+	}
+	fn increase_liquidity() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3000`
+		//  Estimated: `12000`
+		// Minimum execution time: 170_000_000 picoseconds.
+		Weight::from_parts(170_000_000, 12000)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+	}
+	fn decrease_liquidity() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3000`
+		//  Estimated: `12000`
+		// Minimum execution time: 160_000_000 picoseconds.
+		Weight::from_parts(160_000_000, 12000)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	fn collect_fees() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2250`
+		//  Estimated: `9000`
+		// Minimum execution time: 110_000_000 picoseconds.
+		Weight::from_parts(110_000_000, 9000)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	fn burn_position() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1500`
+		//  Estimated: `6000`
+		// Minimum execution time: 70_000_000 picoseconds.
+		Weight::from_parts(70_000_000, 6000)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	fn quote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2000`
+		//  Estimated: `8000`
+		// Minimum execution time: 40_000_000 picoseconds.
+		Weight::from_parts(40_000_000, 8000)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+	}
+	fn step_quote(a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2000`
+		//  Estimated: `8000`
+		// Minimum execution time: 40_000_000 picoseconds.
+		Weight::from_parts(40_000_000, 8000)
+			// Standard Error: 25_000
+			.saturating_add(Weight::from_parts(21_000_000, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+	}
+	fn exchange() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3000`
+		//  Estimated: `12000`
+		// Minimum execution time: 180_000_000 picoseconds.
+		Weight::from_parts(180_000_000, 12000)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+}
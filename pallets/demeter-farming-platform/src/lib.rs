@@ -28,11 +28,13 @@ pub enum StorageVersion {
     V2,
     /// After adding lazy reward accounting fields
     V3,
+    /// After switching to per-block reward accrual
+    V4,
 }
 
 #[derive(Encode, Decode, Default, PartialEq, Eq, scale_info::TypeInfo)]
 #[cfg_attr(feature = "std", derive(Debug))]
-pub struct PoolData<AssetId> {
+pub struct PoolData<AssetId, BlockNumber> {
     pub multiplier: u32,
     pub deposit_fee: Balance,
     pub is_core: bool,
@@ -43,6 +45,7 @@ pub struct PoolData<AssetId> {
     pub reward_per_token: Balance,
     pub is_removed: bool,
     pub base_asset: AssetId,
+    pub last_reward_block: BlockNumber,
 }
 
 #[derive(Encode, Decode, Default, PartialEq, Eq, scale_info::TypeInfo)]
@@ -87,7 +90,7 @@ pub mod pallet {
     use frame_system::pallet_prelude::*;
     use frame_system::RawOrigin;
     use hex_literal::hex;
-    use sp_runtime::traits::{AccountIdConversion, Zero};
+    use sp_runtime::traits::{AccountIdConversion, SaturatedConversion, Zero};
     use sp_std::prelude::*;
 
     const PALLET_ID: PalletId = PalletId(*b"deofarms");
@@ -106,7 +109,7 @@ pub mod pallet {
         /// Demeter asset id
         type DemeterAssetId: Get<AssetIdOf<Self>>;
 
-        /// One hour represented in block number
+        /// Interval of the legacy reward tranches, used to checkpoint pools on upgrade
         const BLOCKS_PER_HOUR_AND_A_HALF: BlockNumberFor<Self>;
 
         /// Weight information for extrinsics in this pallet.
@@ -148,7 +151,7 @@ pub mod pallet {
         AssetIdOf<T>,
         Identity,
         AssetIdOf<T>,
-        Vec<PoolData<AssetIdOf<T>>>,
+        Vec<PoolData<AssetIdOf<T>, BlockNumberFor<T>>>,
         ValueQuery,
     >;
 
@@ -419,6 +422,7 @@ pub mod pallet {
                 reward_per_token: 0,
                 is_removed: false,
                 base_asset,
+                last_reward_block: frame_system::Pallet::<T>::block_number(),
             };
 
             if is_farm {
@@ -464,6 +468,7 @@ pub mod pallet {
         ) -> DispatchResultWithPostInfo {
             let user = ensure_signed(origin)?;
             ensure!(pooled_tokens != 0, Error::<T>::ZeroDeposit);
+            let now = frame_system::Pallet::<T>::block_number();
 
             // Get pool info and check if pool exists
             let mut pool_infos = <Pools<T>>::get(&pool_asset, &reward_asset);
@@ -559,6 +564,7 @@ pub mod pallet {
                                     && p_info.is_farm == is_farm
                                     && p_info.base_asset == base_asset
                                 {
+                                    Self::accrue_pool_rewards(p_info, now)?;
                                     Self::accrue_user_rewards(u_info, p_info)?;
                                     p_info.total_tokens_in_pool = p_info
                                         .total_tokens_in_pool
@@ -574,6 +580,7 @@ pub mod pallet {
             }
 
             // Update user info
+            Self::accrue_pool_rewards(&mut pool_infos[pool_index], now)?;
             if let Some(index) = user_index {
                 let u_info = &mut user_infos[index];
                 Self::accrue_user_rewards(u_info, &pool_infos[pool_index])?;
@@ -649,6 +656,10 @@ pub mod pallet {
                 })
                 .ok_or(Error::<T>::ZeroRewards)?;
 
+            Self::accrue_pool_rewards(
+                &mut pool_infos[pool_index],
+                frame_system::Pallet::<T>::block_number(),
+            )?;
             Self::accrue_user_rewards(&mut user_infos[user_index], &pool_infos[pool_index])?;
             let rewards = user_infos[user_index].rewards;
             ensure!(rewards != 0, Error::<T>::ZeroRewards);
@@ -727,6 +738,10 @@ pub mod pallet {
                 })
                 .ok_or(Error::<T>::PoolDoesNotExist)?;
 
+            Self::accrue_pool_rewards(
+                &mut pool_infos[pool_index],
+                frame_system::Pallet::<T>::block_number(),
+            )?;
             Self::accrue_user_rewards(&mut user_infos[user_index], &pool_infos[pool_index])?;
 
             if is_farm == false {
@@ -793,6 +808,10 @@ pub mod pallet {
                         && pool_info.base_asset == base_asset
                 })
                 .ok_or(Error::<T>::PoolDoesNotExist)?;
+            Self::settle_pool_rewards(
+                &mut pool_infos[pool_index],
+                frame_system::Pallet::<T>::block_number(),
+            )?;
             let old_multiplier = pool_infos[pool_index].multiplier;
             pool_infos[pool_index].is_removed = true;
             pool_infos[pool_index].rewards_to_be_distributed = balance!(0);
@@ -914,6 +933,7 @@ pub mod pallet {
             }
 
             // Get pool info
+            let now = frame_system::Pallet::<T>::block_number();
             let mut pool_infos = <Pools<T>>::get(&pool_asset, &reward_asset);
             let mut exist = false;
 
@@ -923,6 +943,7 @@ pub mod pallet {
                     && p_info.base_asset == base_asset
                 {
                     exist = true;
+                    Self::accrue_pool_rewards(p_info, now)?;
                     p_info.total_tokens_in_pool = total_tokens;
                 }
             }
@@ -983,6 +1004,10 @@ pub mod pallet {
                 })
                 .ok_or(Error::<T>::UserInfoDoesNotExist)?;
 
+            Self::accrue_pool_rewards(
+                &mut pool_infos[pool_index],
+                frame_system::Pallet::<T>::block_number(),
+            )?;
             Self::accrue_user_rewards(&mut user_infos[user_index], &pool_infos[pool_index])?;
             let old_pool_tokens = user_infos[user_index].pooled_tokens;
             user_infos[user_index].pooled_tokens = pool_tokens;
@@ -1147,6 +1172,7 @@ pub mod pallet {
             let multiplier = pool_infos[pool_index].multiplier;
             pool_infos[pool_index].is_removed = false;
             pool_infos[pool_index].rewards_to_be_distributed = balance!(0);
+            pool_infos[pool_index].last_reward_block = frame_system::Pallet::<T>::block_number();
 
             let mut token_info = <TokenInfos<T>>::get(&reward_asset)
                 .ok_or(Error::<T>::RewardTokenIsNotRegistered)?;
@@ -1184,11 +1210,8 @@ pub mod pallet {
         fn on_initialize(now: BlockNumberFor<T>) -> Weight {
             let mut weight = Weight::zero();
 
-            if (now % T::BLOCKS_PER_HOUR_AND_A_HALF).is_zero() {
-                weight = weight.saturating_add(Self::distribute_rewards_to_users());
-            }
             if (now % T::BLOCKS_PER_ONE_DAY).is_zero() {
-                weight = weight.saturating_add(Self::distribute_rewards_to_pools());
+                weight = weight.saturating_add(Self::distribute_rewards_to_pools(now));
             }
 
             weight
@@ -1198,15 +1221,20 @@ pub mod pallet {
             match Self::pallet_storage_version() {
                 StorageVersion::V1 => {
                     let weight = migrations::migrate::<T>();
-                    PalletStorageVersion::<T>::put(StorageVersion::V3);
+                    PalletStorageVersion::<T>::put(StorageVersion::V4);
                     weight
                 }
                 StorageVersion::V2 => {
                     let weight = migrations::migrate_v2_to_v3::<T>();
-                    PalletStorageVersion::<T>::put(StorageVersion::V3);
+                    PalletStorageVersion::<T>::put(StorageVersion::V4);
+                    weight
+                }
+                StorageVersion::V3 => {
+                    let weight = migrations::migrate_v3_to_v4::<T>();
+                    PalletStorageVersion::<T>::put(StorageVersion::V4);
                     weight
                 }
-                StorageVersion::V3 => Weight::zero(),
+                StorageVersion::V4 => Weight::zero(),
             }
         }
     }
@@ -1249,9 +1277,69 @@ pub mod pallet {
                 .map_err(|_| Error::<T>::ArithmeticError.into())
         }
 
+        /// Accrue pool rewards for the blocks elapsed since the last checkpoint
+        pub(crate) fn accrue_pool_rewards(
+            pool_info: &mut PoolData<AssetIdOf<T>, BlockNumberFor<T>>,
+            now: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            if now <= pool_info.last_reward_block {
+                return Ok(());
+            }
+
+            if !pool_info.is_removed
+                && pool_info.rewards_to_be_distributed != 0
+                && pool_info.total_tokens_in_pool != 0
+            {
+                let elapsed_blocks: u32 = (now - pool_info.last_reward_block).saturated_into();
+                let blocks_per_day: u32 = T::BLOCKS_PER_ONE_DAY.saturated_into();
+                let pending_rewards = (FixedWrapper256::from(pool_info.rewards_to_be_distributed)
+                    * FixedWrapper256::from(balance!(elapsed_blocks))
+                    / FixedWrapper256::from(balance!(blocks_per_day)))
+                .try_into_balance()
+                .map_err(|_| Error::<T>::ArithmeticError)?;
+                let reward_per_token = (FixedWrapper256::from(pending_rewards)
+                    / FixedWrapper256::from(pool_info.total_tokens_in_pool))
+                .try_into_balance()
+                .map_err(|_| Error::<T>::ArithmeticError)?;
+
+                // rewards keep accruing since the last reward block until they are worth something per token
+                if reward_per_token == 0 {
+                    return Ok(());
+                }
+
+                let rewards = pool_info
+                    .rewards
+                    .checked_add(pending_rewards)
+                    .ok_or(Error::<T>::ArithmeticError)?;
+                let reward_per_token = pool_info
+                    .reward_per_token
+                    .checked_add(reward_per_token)
+                    .ok_or(Error::<T>::ArithmeticError)?;
+
+                pool_info.rewards = rewards;
+                pool_info.reward_per_token = reward_per_token;
+            }
+            pool_info.last_reward_block = now;
+
+            Ok(())
+        }
+
+        /// Accrue pool rewards before the rate changes, the checkpoint moves even if nothing accrues
+        pub(crate) fn settle_pool_rewards(
+            pool_info: &mut PoolData<AssetIdOf<T>, BlockNumberFor<T>>,
+            now: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            Self::accrue_pool_rewards(pool_info, now)?;
+            if now > pool_info.last_reward_block {
+                pool_info.last_reward_block = now;
+            }
+
+            Ok(())
+        }
+
         pub(crate) fn accrue_user_rewards(
             user_info: &mut UserInfo<AssetIdOf<T>>,
-            pool_info: &PoolData<AssetIdOf<T>>,
+            pool_info: &PoolData<AssetIdOf<T>, BlockNumberFor<T>>,
         ) -> DispatchResult {
             if pool_info.reward_per_token > user_info.reward_per_token_paid {
                 let reward_per_token_diff =
@@ -1288,6 +1376,29 @@ pub mod pallet {
             Ok((reserved, reads))
         }
 
+        fn accrue_all_pools_rewards(now: BlockNumberFor<T>) -> (u64, u64) {
+            let mut reads = 0u64;
+            let mut writes = 0u64;
+
+            for (pool_asset, reward_asset, mut pool_infos) in Pools::<T>::iter() {
+                reads = reads.saturating_add(1);
+                for pool_info in pool_infos.iter_mut() {
+                    if let Err(err) = Self::settle_pool_rewards(pool_info, now) {
+                        frame_support::__private::log::warn!(
+                            "Failed to accrue pool rewards for {:?}/{:?}: {:?}",
+                            pool_asset,
+                            reward_asset,
+                            err
+                        );
+                    }
+                }
+                <Pools<T>>::insert(pool_asset, reward_asset, pool_infos);
+                writes = writes.saturating_add(1);
+            }
+
+            (reads, writes)
+        }
+
         fn clear_rewards_to_be_distributed(token_asset_id: &AssetIdOf<T>) -> (u64, u64) {
             let mut reads = 0u64;
             let mut writes = 0u64;
@@ -1338,11 +1449,12 @@ pub mod pallet {
         }
 
         /// Distribute rewards to pools
-        fn distribute_rewards_to_pools() -> Weight {
-            let mut reads: u64 = 0;
-            let mut writes: u64 = 0;
+        fn distribute_rewards_to_pools(now: BlockNumberFor<T>) -> Weight {
             let blocks = 14400_u32;
 
+            // Settle the previous day rate before it gets replaced
+            let (mut reads, mut writes) = Self::accrue_all_pools_rewards(now);
+
             if let Err(err) = Self::mint_deo() {
                 frame_support::__private::log::warn!("Failed to mint DEO rewards: {:?}", err);
             }
@@ -1547,103 +1659,6 @@ pub mod pallet {
                 .saturating_add(T::DbWeight::get().writes(writes))
         }
 
-        fn distribute_rewards_to_users() -> Weight {
-            let mut reads: u64 = 0;
-            let mut writes: u64 = 0;
-            let per_hour_and_half = balance!(0.0625);
-            let zero = balance!(0);
-
-            for (pool_asset, reward_asset, mut pool_infos) in Pools::<T>::iter() {
-                reads = reads.saturating_add(1);
-                let mut changed = false;
-                for pool_info in pool_infos.iter_mut() {
-                    if pool_info.rewards_to_be_distributed != zero
-                        && !pool_info.is_removed
-                        && pool_info.total_tokens_in_pool != zero
-                    {
-                        let amount_per_hour =
-                            match (FixedWrapper256::from(pool_info.rewards_to_be_distributed)
-                                * FixedWrapper256::from(per_hour_and_half))
-                            .try_into_balance()
-                            {
-                                Ok(amount) => amount,
-                                Err(err) => {
-                                    frame_support::__private::log::warn!(
-                                    "Failed to calculate user reward tranche for {:?}/{:?}: {:?}",
-                                    pool_asset,
-                                    reward_asset,
-                                    err
-                                );
-                                    continue;
-                                }
-                            };
-                        if amount_per_hour == zero {
-                            continue;
-                        }
-                        let reward_per_token = match (FixedWrapper256::from(amount_per_hour)
-                            / FixedWrapper256::from(pool_info.total_tokens_in_pool))
-                        .try_into_balance()
-                        {
-                            Ok(reward) => reward,
-                            Err(err) => {
-                                frame_support::__private::log::warn!(
-                                    "Failed to calculate reward per token for {:?}/{:?}: {:?}",
-                                    pool_asset,
-                                    reward_asset,
-                                    err
-                                );
-                                continue;
-                            }
-                        };
-                        if reward_per_token == zero {
-                            frame_support::__private::log::warn!(
-                                "Skipping reward distribution with zero reward per token for {:?}/{:?}",
-                                pool_asset,
-                                reward_asset
-                            );
-                            continue;
-                        }
-
-                        let rewards = match pool_info.rewards.checked_add(amount_per_hour) {
-                            Some(rewards) => rewards,
-                            None => {
-                                frame_support::__private::log::warn!(
-                                    "Failed to add user reward tranche for {:?}/{:?}",
-                                    pool_asset,
-                                    reward_asset
-                                );
-                                continue;
-                            }
-                        };
-                        let new_reward_per_token =
-                            match pool_info.reward_per_token.checked_add(reward_per_token) {
-                                Some(reward_per_token) => reward_per_token,
-                                None => {
-                                    frame_support::__private::log::warn!(
-                                        "Failed to add reward per token for {:?}/{:?}",
-                                        pool_asset,
-                                        reward_asset
-                                    );
-                                    continue;
-                                }
-                            };
-
-                        pool_info.rewards = rewards;
-                        pool_info.reward_per_token = new_reward_per_token;
-                        changed = true;
-                    }
-                }
-                if changed {
-                    <Pools<T>>::insert(pool_asset, reward_asset, pool_infos);
-                    writes = writes.saturating_add(1);
-                }
-            }
-
-            T::DbWeight::get()
-                .reads(reads)
-                .saturating_add(T::DbWeight::get().writes(writes))
-        }
-
         /// Check if user has enough free liquidity for withdrawing
         pub fn check_if_has_enough_liquidity_out_of_farming(
             user: &AccountIdOf<T>,
@@ -1695,6 +1710,7 @@ impl<T: Config> DemeterFarming<T::AccountId, AssetIdOf<T>> for Pallet<T> {
         pool_asset: AssetIdOf<T>,
     ) -> Result<(), DispatchError> {
         common::with_transaction(|| {
+            let now = frame_system::Pallet::<T>::block_number();
             let mut user_infos = <UserInfos<T>>::get(&user);
             for u_info in user_infos.iter_mut() {
                 if u_info.pool_asset == pool_asset
@@ -1709,6 +1725,7 @@ impl<T: Config> DemeterFarming<T::AccountId, AssetIdOf<T>> for Pallet<T> {
                                 && p_info.is_farm
                                 && p_info.base_asset == base_asset
                             {
+                                Pallet::<T>::accrue_pool_rewards(p_info, now)?;
                                 Pallet::<T>::accrue_user_rewards(u_info, p_info)?;
                                 p_info.total_tokens_in_pool = p_info
                                     .total_tokens_in_pool
@@ -1752,12 +1769,17 @@ impl<T: Config> OnDenominate<BalanceOf<T>> for DenominateXorAndTbcd<T> {
             update_token_info(&xor)?;
             update_token_info(&tbcd)?;
 
+            let now = frame_system::Pallet::<T>::block_number();
             for (pool_asset, reward_asset, mut pool_infos) in <Pools<T>>::iter() {
                 let mut i = 0;
                 let mut updated = false;
 
                 while i < pool_infos.len() {
                     let pool_info = &mut pool_infos[i];
+                    if now > pool_info.last_reward_block {
+                        Pallet::<T>::settle_pool_rewards(pool_info, now)?;
+                        updated = true;
+                    }
 
                     if pool_asset == xor
                         || pool_asset == tbcd
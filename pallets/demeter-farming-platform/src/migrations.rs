@@ -4,6 +4,8 @@ use common::{Balance, XOR};
 use frame_support::__private::log;
 use frame_support::traits::Get;
 use frame_support::weights::Weight;
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::traits::{One, Saturating, Zero};
 use sp_std::vec::Vec;
 
 #[derive(Encode, Decode, Default, PartialEq, Eq, scale_info::TypeInfo)]
@@ -40,6 +42,20 @@ struct V2PoolData<AssetId> {
     pub base_asset: AssetId,
 }
 
+#[derive(Encode, Decode, Default, PartialEq, Eq, scale_info::TypeInfo)]
+struct V3PoolData<AssetId> {
+    pub multiplier: u32,
+    pub deposit_fee: Balance,
+    pub is_core: bool,
+    pub is_farm: bool,
+    pub total_tokens_in_pool: Balance,
+    pub rewards: Balance,
+    pub rewards_to_be_distributed: Balance,
+    pub reward_per_token: Balance,
+    pub is_removed: bool,
+    pub base_asset: AssetId,
+}
+
 #[derive(Encode, Decode, Default, PartialEq, Eq, scale_info::TypeInfo)]
 struct V2UserInfo<AssetId> {
     pub base_asset: AssetId,
@@ -64,8 +80,26 @@ pub fn migrate_v2_to_v3<T: Config>() -> Weight {
         .saturating_add(recompute_token_multipliers::<T>())
 }
 
+pub fn migrate_v3_to_v4<T: Config>() -> Weight {
+    sp_runtime::runtime_logger::RuntimeLogger::init();
+    migrate_reward_accrual_checkpoints::<T>()
+}
+
+/// Last block covered by the legacy reward tranches
+fn legacy_reward_checkpoint<T: Config>() -> BlockNumberFor<T> {
+    let now = frame_system::Pallet::<T>::block_number();
+    let interval = T::BLOCKS_PER_HOUR_AND_A_HALF;
+    if now.is_zero() || interval.is_zero() {
+        return now;
+    }
+
+    let last_block = now.saturating_sub(One::one());
+    last_block.saturating_sub(last_block % interval)
+}
+
 pub fn migrate_pool_and_user_data<T: Config>() -> Weight {
     let mut weight: u64 = 0;
+    let last_reward_block = legacy_reward_checkpoint::<T>();
 
     Pools::<T>::translate::<Vec<OldPoolData>, _>(|k1, _, v| {
         Some(
@@ -89,9 +123,10 @@ pub fn migrate_pool_and_user_data<T: Config>() -> Weight {
                         reward_per_token: 0,
                         is_removed: old_pool_data.is_removed,
                         base_asset,
+                        last_reward_block,
                     }
                 })
-                .collect::<Vec<PoolData<AssetIdOf<T>>>>(),
+                .collect::<Vec<PoolData<AssetIdOf<T>, BlockNumberFor<T>>>>(),
         )
     });
 
@@ -130,6 +165,7 @@ pub fn migrate_pool_and_user_data<T: Config>() -> Weight {
 
 pub fn migrate_reward_checkpoints<T: Config>() -> Weight {
     let mut weight: u64 = 0;
+    let last_reward_block = legacy_reward_checkpoint::<T>();
 
     Pools::<T>::translate::<Vec<V2PoolData<AssetIdOf<T>>>, _>(|_, _, v| {
         Some(
@@ -148,9 +184,10 @@ pub fn migrate_reward_checkpoints<T: Config>() -> Weight {
                         reward_per_token: 0,
                         is_removed: pool_data.is_removed,
                         base_asset: pool_data.base_asset,
+                        last_reward_block,
                     }
                 })
-                .collect::<Vec<PoolData<AssetIdOf<T>>>>(),
+                .collect::<Vec<PoolData<AssetIdOf<T>, BlockNumberFor<T>>>>(),
         )
     });
 
@@ -182,6 +219,42 @@ pub fn migrate_reward_checkpoints<T: Config>() -> Weight {
     T::DbWeight::get().reads_writes(weight, weight)
 }
 
+pub fn migrate_reward_accrual_checkpoints<T: Config>() -> Weight {
+    let mut weight: u64 = 0;
+    let last_reward_block = legacy_reward_checkpoint::<T>();
+
+    Pools::<T>::translate::<Vec<V3PoolData<AssetIdOf<T>>>, _>(|_, _, v| {
+        Some(
+            v.into_iter()
+                .map(|pool_data| {
+                    weight += 1;
+
+                    PoolData {
+                        multiplier: pool_data.multiplier,
+                        deposit_fee: pool_data.deposit_fee,
+                        is_core: pool_data.is_core,
+                        is_farm: pool_data.is_farm,
+                        total_tokens_in_pool: pool_data.total_tokens_in_pool,
+                        rewards: pool_data.rewards,
+                        rewards_to_be_distributed: pool_data.rewards_to_be_distributed,
+                        reward_per_token: pool_data.reward_per_token,
+                        is_removed: pool_data.is_removed,
+                        base_asset: pool_data.base_asset,
+                        last_reward_block,
+                    }
+                })
+                .collect::<Vec<PoolData<AssetIdOf<T>, BlockNumberFor<T>>>>(),
+        )
+    });
+
+    log::info!(
+        target: "runtime",
+        "PoolData migrated to per-block reward accrual checkpoints"
+    );
+
+    T::DbWeight::get().reads_writes(weight, weight)
+}
+
 pub fn dedupe_active_pools<T: Config>() -> Weight {
     let mut reads: u64 = 0;
     let mut writes: u64 = 0;
@@ -575,6 +575,7 @@ mod tests {
                 reward_per_token: 0,
                 is_removed: false,
                 base_asset: XOR,
+                last_reward_block: 0,
             };
 
             demeter_farming_platform::Pools::<Runtime>::append(
@@ -1491,6 +1492,7 @@ mod tests {
                 reward_per_token: 0,
                 is_removed: false,
                 base_asset: XOR,
+                last_reward_block: 0,
             };
 
             demeter_farming_platform::Pools::<Runtime>::append(
@@ -1545,6 +1547,7 @@ mod tests {
                 reward_per_token: 0,
                 is_removed: false,
                 base_asset: XOR,
+                last_reward_block: 0,
             };
 
             demeter_farming_platform::Pools::<Runtime>::append(
@@ -1596,6 +1599,7 @@ mod tests {
                 reward_per_token: 0,
                 is_removed: false,
                 base_asset: XOR,
+                last_reward_block: 0,
             };
 
             demeter_farming_platform::Pools::<Runtime>::append(
@@ -1669,6 +1673,7 @@ mod tests {
                 reward_per_token: 0,
                 is_removed: false,
                 base_asset: XSTUSD,
+                last_reward_block: 0,
             };
 
             let token_info = TokenInfo {
@@ -1791,6 +1796,7 @@ mod tests {
                 reward_per_token: 0,
                 is_removed: false,
                 base_asset,
+                last_reward_block: 0,
             };
 
             demeter_farming_platform::Pools::<Runtime>::append(
@@ -2021,6 +2027,7 @@ mod tests {
                 reward_per_token: 0,
                 is_removed: false,
                 base_asset: XOR,
+                last_reward_block: 0,
             };
 
             let token_info = TokenInfo {
@@ -2082,6 +2089,7 @@ mod tests {
                 reward_per_token: 0,
                 is_removed: false,
                 base_asset: XSTUSD,
+                last_reward_block: 0,
             };
 
             let token_info = TokenInfo {
@@ -2228,6 +2236,7 @@ mod tests {
                 reward_per_token: 0,
                 is_removed: false,
                 base_asset: XOR,
+                last_reward_block: 0,
             };
 
             let mut token_info = TokenInfo {
@@ -2308,6 +2317,7 @@ mod tests {
                 reward_per_token: 0,
                 is_removed: false,
                 base_asset: XOR,
+                last_reward_block: 0,
             };
 
             demeter_farming_platform::Pools::<Runtime>::append(
@@ -2377,6 +2387,7 @@ mod tests {
                 reward_per_token: 0,
                 is_removed: false,
                 base_asset: XSTUSD,
+                last_reward_block: 0,
             };
 
             demeter_farming_platform::Pools::<Runtime>::append(
@@ -2482,6 +2493,7 @@ mod tests {
                 reward_per_token: 0,
                 is_removed: false,
                 base_asset: XOR,
+                last_reward_block: 0,
             };
 
             demeter_farming_platform::Pools::<Runtime>::append(
@@ -2528,6 +2540,7 @@ mod tests {
                 reward_per_token: 0,
                 is_removed: false,
                 base_asset: XOR,
+                last_reward_block: 0,
             };
 
             demeter_farming_platform::Pools::<Runtime>::append(
@@ -2583,6 +2596,7 @@ mod tests {
                 reward_per_token: 0,
                 is_removed: false,
                 base_asset: XSTUSD,
+                last_reward_block: 0,
             };
 
             demeter_farming_platform::Pools::<Runtime>::append(
@@ -2821,6 +2835,7 @@ mod tests {
                 reward_per_token: 0,
                 is_removed: false,
                 base_asset: XOR,
+                last_reward_block: 0,
             };
 
             demeter_farming_platform::Pools::<Runtime>::append(
@@ -2875,6 +2890,7 @@ mod tests {
                 reward_per_token: 0,
                 is_removed: false,
                 base_asset: XSTUSD,
+                last_reward_block: 0,
             };
 
             demeter_farming_platform::Pools::<Runtime>::append(
@@ -2931,6 +2947,7 @@ mod tests {
                 reward_per_token: 0,
                 is_removed: false,
                 base_asset,
+                last_reward_block: 0,
             };
             demeter_farming_platform::Pools::<Runtime>::append(
                 &pool_asset,
@@ -2995,6 +3012,7 @@ mod tests {
                 reward_per_token: 0,
                 is_removed: false,
                 base_asset: XSTUSD,
+                last_reward_block: 0,
             };
             demeter_farming_platform::Pools::<Runtime>::append(
                 &pool_asset,
@@ -3211,11 +3229,19 @@ mod tests {
                 balance!(100)
             ));
 
-            run_to_block(16201);
+            run_to_block(16245);
 
             // Check XOR/CERES pool and CERES pool - reward DEO
             let pool_infos = demeter_farming_platform::Pools::<Runtime>::get(&ceres, &deo);
-            for pool_info in pool_infos {
+            for mut pool_info in pool_infos {
+                assert_eq!(pool_info.rewards, 0);
+                assert_eq!(pool_info.last_reward_block, 14440);
+                assert_ok!(
+                    demeter_farming_platform::Pallet::<Runtime>::accrue_pool_rewards(
+                        &mut pool_info,
+                        16245
+                    )
+                );
                 if pool_info.is_farm && pool_info.base_asset == xor {
                     assert_eq!(pool_info.total_tokens_in_pool, balance!(3.84));
                     assert_eq!(pool_info.rewards_to_be_distributed, balance!(8640));
@@ -3266,7 +3292,13 @@ mod tests {
 
             // Check XOR/CERES pool and CERES pool - reward UTIL
             let pool_infos = demeter_farming_platform::Pools::<Runtime>::get(&ceres, &util);
-            for pool_info in pool_infos {
+            for mut pool_info in pool_infos {
+                assert_ok!(
+                    demeter_farming_platform::Pallet::<Runtime>::accrue_pool_rewards(
+                        &mut pool_info,
+                        16245
+                    )
+                );
                 if pool_info.is_farm && pool_info.base_asset == xor {
                     assert_eq!(pool_info.total_tokens_in_pool, balance!(0.96));
                     assert_eq!(pool_info.rewards_to_be_distributed, balance!(72));
@@ -3382,6 +3414,7 @@ mod tests {
                 reward_per_token: 0,
                 is_removed: false,
                 base_asset: pool_asset,
+                last_reward_block: 0,
             };
             demeter_farming_platform::Pools::<Runtime>::append(
                 &pool_asset,
@@ -3404,14 +3437,7 @@ mod tests {
                 );
             }
 
-            demeter_farming_platform::Pallet::<Runtime>::on_initialize(900);
-            let pool_infos =
-                demeter_farming_platform::Pools::<Runtime>::get(&pool_asset, &reward_asset);
-            assert_eq!(pool_infos[0].rewards, balance!(0.0625));
-            assert_eq!(
-                pool_infos[0].reward_per_token,
-                balance!(0.020833333333333333)
-            );
+            System::set_block_number(1805);
 
             for user in [ALICE, BOB, CHARLES] {
                 assert_ok!(demeter_farming_platform::Pallet::<Runtime>::get_rewards(
@@ -3425,7 +3451,12 @@ mod tests {
 
             let pool_infos =
                 demeter_farming_platform::Pools::<Runtime>::get(&pool_asset, &reward_asset);
-            assert_eq!(pool_infos[0].rewards, 1);
+            assert_eq!(
+                pool_infos[0].reward_per_token,
+                balance!(0.041666666666666666)
+            );
+            assert_eq!(pool_infos[0].last_reward_block, 1805);
+            assert_eq!(pool_infos[0].rewards, 2);
         });
     }
 
@@ -4478,7 +4509,7 @@ mod tests {
 
             assert!(
                 demeter_farming_platform::PalletStorageVersion::<Runtime>::get()
-                    == demeter_farming_platform::StorageVersion::V3
+                    == demeter_farming_platform::StorageVersion::V4
             );
             let pool_infos =
                 demeter_farming_platform::Pools::<Runtime>::get(asset_ceres, asset_ceres);
@@ -4512,6 +4543,88 @@ mod tests {
         });
     }
 
+    #[test]
+    fn demeter_farming_platform_v3_to_v4_migration_works() {
+        preset_initial(|| {
+            generate_storage_instance!(DemeterFarmingPlatform, Pools);
+            type V3Pools = StorageDoubleMap<
+                PoolsOldInstance,
+                Identity,
+                AssetIdOf<Runtime>,
+                Identity,
+                AssetIdOf<Runtime>,
+                Vec<(
+                    u32,
+                    Balance,
+                    bool,
+                    bool,
+                    Balance,
+                    Balance,
+                    Balance,
+                    Balance,
+                    bool,
+                    AssetIdOf<Runtime>,
+                )>,
+                ValueQuery,
+            >;
+
+            let asset_xor: AssetId = XOR.into();
+            let asset_ceres: AssetId = CERES_ASSET_ID.into();
+
+            V3Pools::insert(
+                asset_ceres,
+                asset_ceres,
+                vec![(
+                    2u32,
+                    balance!(0.02),
+                    false,
+                    true,
+                    balance!(100),
+                    balance!(20),
+                    balance!(12),
+                    balance!(2),
+                    false,
+                    asset_xor,
+                )],
+            );
+            demeter_farming_platform::UserInfos::<Runtime>::insert(
+                ALICE,
+                vec![UserInfo {
+                    base_asset: asset_xor,
+                    pool_asset: asset_ceres,
+                    reward_asset: asset_ceres,
+                    is_farm: true,
+                    pooled_tokens: balance!(10),
+                    rewards: balance!(1),
+                    reward_per_token_paid: balance!(0.5),
+                }],
+            );
+
+            demeter_farming_platform::PalletStorageVersion::<Runtime>::put(
+                demeter_farming_platform::StorageVersion::V3,
+            );
+            System::set_block_number(1000);
+
+            demeter_farming_platform::Pallet::<Runtime>::on_runtime_upgrade();
+
+            assert!(
+                demeter_farming_platform::PalletStorageVersion::<Runtime>::get()
+                    == demeter_farming_platform::StorageVersion::V4
+            );
+            let pool_infos =
+                demeter_farming_platform::Pools::<Runtime>::get(asset_ceres, asset_ceres);
+            assert_eq!(pool_infos.len(), 1);
+            assert_eq!(pool_infos[0].rewards, balance!(20));
+            assert_eq!(pool_infos[0].rewards_to_be_distributed, balance!(12));
+            assert_eq!(pool_infos[0].reward_per_token, balance!(2));
+            assert_eq!(pool_infos[0].last_reward_block, 900);
+
+            let users = demeter_farming_platform::UserInfos::<Runtime>::get(ALICE);
+            assert_eq!(users[0].rewards, balance!(16));
+            assert_eq!(users[0].reward_per_token_paid, balance!(2));
+        });
+    }
+
     #[test]
     fn activate_removed_pool_ok() {
         let mut ext = ExtBuilder::default().build();
@@ -4531,6 +4644,7 @@ mod tests {
                 reward_per_token: 0,
                 is_removed: false,
                 base_asset: XOR,
+                last_reward_block: 0,
             };
 
             let token_info = TokenInfo {
@@ -4640,6 +4754,7 @@ mod tests {
                 reward_per_token: balance!(1),
                 is_removed: false,
                 base_asset: XOR,
+                last_reward_block: 0,
             };
 
             demeter_farming_platform::Pools::<Runtime>::insert(
@@ -4689,6 +4804,7 @@ mod tests {
                 reward_per_token: balance!(1),
                 is_removed: false,
                 base_asset: XOR,
+                last_reward_block: 0,
             };
 
             demeter_farming_platform::Pools::<Runtime>::insert(
@@ -4718,10 +4834,10 @@ mod tests {
     }
 
     #[test]
-    fn distribute_rewards_to_users_updates_pool_accounting_atomically() {
+    fn accrue_pool_rewards_updates_pool_accounting_atomically() {
         let mut ext = ExtBuilder::default().build();
         ext.execute_with(|| {
-            let pool_info = PoolData {
+            let mut pool_info = PoolData {
                 multiplier: 1,
                 deposit_fee: 0,
                 is_core: true,
@@ -4732,48 +4848,94 @@ mod tests {
                 reward_per_token: 0,
                 is_removed: false,
                 base_asset: XOR,
+                last_reward_block: 0,
             };
-            demeter_farming_platform::Pools::<Runtime>::insert(
-                XOR,
-                CERES_ASSET_ID,
-                vec![pool_info],
+            assert_err!(
+                demeter_farming_platform::Pallet::<Runtime>::accrue_pool_rewards(
+                    &mut pool_info,
+                    900
+                ),
+                demeter_farming_platform::Error::<Runtime>::ArithmeticError
             );
 
-            demeter_farming_platform::Pallet::<Runtime>::on_initialize(900);
+            assert_eq!(pool_info.rewards, Balance::MAX);
+            assert_eq!(pool_info.reward_per_token, 0);
+            assert_eq!(pool_info.last_reward_block, 0);
+        });
+    }
 
-            let pool_infos = demeter_farming_platform::Pools::<Runtime>::get(XOR, CERES_ASSET_ID);
-            assert_eq!(pool_infos[0].rewards, Balance::MAX);
-            assert_eq!(pool_infos[0].reward_per_token, 0);
+    #[test]
+    fn accrue_pool_rewards_defers_zero_reward_per_token() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            let mut pool_info = PoolData {
+                multiplier: 1,
+                deposit_fee: 0,
+                is_core: true,
+                is_farm: true,
+                total_tokens_in_pool: balance!(10000000000000000),
+                rewards: 0,
+                rewards_to_be_distributed: balance!(14.44),
+                reward_per_token: 0,
+                is_removed: false,
+                base_asset: XOR,
+                last_reward_block: 0,
+            };
+            assert_ok!(
+                demeter_farming_platform::Pallet::<Runtime>::accrue_pool_rewards(&mut pool_info, 1)
+            );
+
+            assert_eq!(pool_info.rewards, 0);
+            assert_eq!(pool_info.reward_per_token, 0);
+            assert_eq!(pool_info.last_reward_block, 0);
+
+            assert_ok!(
+                demeter_farming_platform::Pallet::<Runtime>::accrue_pool_rewards(
+                    &mut pool_info,
+                    100
+                )
+            );
+
+            assert_eq!(pool_info.rewards, balance!(0.1));
+            assert_eq!(pool_info.reward_per_token, 10);
+            assert_eq!(pool_info.last_reward_block, 100);
         });
     }
 
     #[test]
-    fn distribute_rewards_to_users_skips_zero_reward_per_token() {
+    fn settle_pool_rewards_moves_checkpoint_before_rate_change() {
         let mut ext = ExtBuilder::default().build();
         ext.execute_with(|| {
-            let pool_info = PoolData {
+            let mut pool_info = PoolData {
                 multiplier: 1,
                 deposit_fee: 0,
                 is_core: true,
                 is_farm: true,
-                total_tokens_in_pool: Balance::MAX,
+                total_tokens_in_pool: balance!(10000000000000000),
                 rewards: 0,
-                rewards_to_be_distributed: balance!(16),
+                rewards_to_be_distributed: balance!(14.44),
                 reward_per_token: 0,
                 is_removed: false,
                 base_asset: XOR,
+                last_reward_block: 0,
             };
-            demeter_farming_platform::Pools::<Runtime>::insert(
-                XOR,
-                CERES_ASSET_ID,
-                vec![pool_info],
+            assert_ok!(
+                demeter_farming_platform::Pallet::<Runtime>::settle_pool_rewards(&mut pool_info, 1)
             );
 
-            demeter_farming_platform::Pallet::<Runtime>::on_initialize(900);
+            assert_eq!(pool_info.rewards, 0);
+            assert_eq!(pool_info.reward_per_token, 0);
+            assert_eq!(pool_info.last_reward_block, 1);
 
-            let pool_infos = demeter_farming_platform::Pools::<Runtime>::get(XOR, CERES_ASSET_ID);
-            assert_eq!(pool_infos[0].rewards, 0);
-            assert_eq!(pool_infos[0].reward_per_token, 0);
+            // the new rate is applied only to the blocks after the checkpoint
+            pool_info.rewards_to_be_distributed = balance!(1444);
+            assert_ok!(
+                demeter_farming_platform::Pallet::<Runtime>::accrue_pool_rewards(&mut pool_info, 2)
+            );
+
+            assert_eq!(pool_info.rewards, balance!(0.1));
+            assert_eq!(pool_info.reward_per_token, 10);
+            assert_eq!(pool_info.last_reward_block, 2);
         });
     }
 
@@ -4810,6 +4972,7 @@ mod tests {
                 reward_per_token: balance!(1),
                 is_removed: false,
                 base_asset: XOR,
+                last_reward_block: 0,
             };
             let second_pool_info = PoolData {
                 multiplier: 1,
@@ -4822,6 +4985,7 @@ mod tests {
                 reward_per_token: 0,
                 is_removed: false,
                 base_asset: XOR,
+                last_reward_block: 0,
             };
 
             demeter_farming_platform::UserInfos::<Runtime>::insert(
@@ -4885,6 +5049,7 @@ mod tests {
                 reward_per_token: 0,
                 is_removed: false,
                 base_asset: XOR,
+                last_reward_block: 0,
             };
             let second_pool_info = PoolData {
                 multiplier: 1,
@@ -4897,6 +5062,7 @@ mod tests {
                 reward_per_token: 0,
                 is_removed: false,
                 base_asset: XOR,
+                last_reward_block: 0,
             };
 
             demeter_farming_platform::TokenInfos::<Runtime>::insert(CERES_ASSET_ID, token_info);
@@ -4921,17 +5087,6 @@ mod tests {
             assert_eq!(second_pool_infos[0].rewards_to_be_distributed, 0);
             assert_eq!(first_pool_infos[0].reward_per_token, 0);
             assert_eq!(second_pool_infos[0].reward_per_token, 0);
-
-            demeter_farming_platform::Pallet::<Runtime>::on_initialize(15300);
-
-            let first_pool_infos =
-                demeter_farming_platform::Pools::<Runtime>::get(XSTUSD, CERES_ASSET_ID);
-            let second_pool_infos =
-                demeter_farming_platform::Pools::<Runtime>::get(XOR, CERES_ASSET_ID);
-            assert_eq!(first_pool_infos[0].rewards_to_be_distributed, 0);
-            assert_eq!(second_pool_infos[0].rewards_to_be_distributed, 0);
-            assert_eq!(first_pool_infos[0].reward_per_token, 0);
-            assert_eq!(second_pool_infos[0].reward_per_token, 0);
         });
     }
 
@@ -4988,6 +5143,7 @@ mod tests {
                 reward_per_token: balance!(2),
                 is_removed: false,
                 base_asset: XOR,
+                last_reward_block: 0,
             };
             let user_info = || UserInfo {
                 base_asset: XOR,
@@ -5046,6 +5202,7 @@ mod tests {
                 reward_per_token: 0,
                 is_removed: false,
                 base_asset: XOR,
+                last_reward_block: 14440,
             };
 
             demeter_farming_platform::TokenInfos::<Runtime>::insert(CERES_ASSET_ID, token_info);
@@ -5083,6 +5240,7 @@ mod tests {
                 reward_per_token: 0,
                 is_removed: false,
                 base_asset: XOR,
+                last_reward_block: 0,
             };
             let removed_pool_info = PoolData {
                 multiplier: 1,
@@ -5095,6 +5253,7 @@ mod tests {
                 reward_per_token: 0,
                 is_removed: true,
                 base_asset: XOR,
+                last_reward_block: 0,
             };
             let token_info = TokenInfo {
                 farms_total_multiplier: 1,
@@ -5224,6 +5383,7 @@ mod tests {
                 reward_per_token: balance!(2),
                 is_removed: false,
                 base_asset: xor,
+                last_reward_block: 0,
             };
             demeter_farming_platform::Pools::<Runtime>::append(&ceres, &ceres, &pool_info);
             demeter_farming_platform::UserInfos::<Runtime>::append(
@@ -82,6 +82,7 @@ impl<T: Config>
             MulticollateralBondingCurvePool => can_exchange!(MulticollateralBondingCurvePool),
             XSTPool => can_exchange!(XSTPool),
             OrderBook => can_exchange!(OrderBook),
+            ConcentratedLiquidityPool => can_exchange!(ConcentratedLiquidityPool),
             MockPool => can_exchange!(MockLiquiditySource),
             MockPool2 => can_exchange!(MockLiquiditySource2),
             MockPool3 => can_exchange!(MockLiquiditySource3),
@@ -116,6 +117,7 @@ impl<T: Config>
             MulticollateralBondingCurvePool => quote!(MulticollateralBondingCurvePool),
             XSTPool => quote!(XSTPool),
             OrderBook => quote!(OrderBook),
+            ConcentratedLiquidityPool => quote!(ConcentratedLiquidityPool),
             MockPool => quote!(MockLiquiditySource),
             MockPool2 => quote!(MockLiquiditySource2),
             MockPool3 => quote!(MockLiquiditySource3),
@@ -152,6 +154,7 @@ impl<T: Config>
             MulticollateralBondingCurvePool => step_quote!(MulticollateralBondingCurvePool),
             XSTPool => step_quote!(XSTPool),
             OrderBook => step_quote!(OrderBook),
+            ConcentratedLiquidityPool => step_quote!(ConcentratedLiquidityPool),
             MockPool => step_quote!(MockLiquiditySource),
             MockPool2 => step_quote!(MockLiquiditySource2),
             MockPool3 => step_quote!(MockLiquiditySource3),
@@ -188,6 +191,7 @@ impl<T: Config>
             MulticollateralBondingCurvePool => exchange!(MulticollateralBondingCurvePool),
             XSTPool => exchange!(XSTPool),
             OrderBook => exchange!(OrderBook),
+            ConcentratedLiquidityPool => exchange!(ConcentratedLiquidityPool),
             MockPool => exchange!(MockLiquiditySource),
             MockPool2 => exchange!(MockLiquiditySource2),
             MockPool3 => exchange!(MockLiquiditySource3),
@@ -222,6 +226,7 @@ impl<T: Config>
             MulticollateralBondingCurvePool => check_rewards!(MulticollateralBondingCurvePool),
             XSTPool => check_rewards!(XSTPool),
             OrderBook => check_rewards!(OrderBook),
+            ConcentratedLiquidityPool => check_rewards!(ConcentratedLiquidityPool),
             MockPool => check_rewards!(MockLiquiditySource),
             MockPool2 => check_rewards!(MockLiquiditySource2),
             MockPool3 => check_rewards!(MockLiquiditySource3),
@@ -258,6 +263,7 @@ impl<T: Config>
             }
             XSTPool => quote_without_impact!(XSTPool),
             OrderBook => quote_without_impact!(OrderBook),
+            ConcentratedLiquidityPool => quote_without_impact!(ConcentratedLiquidityPool),
             MockPool => quote_without_impact!(MockLiquiditySource),
             MockPool2 => quote_without_impact!(MockLiquiditySource2),
             MockPool3 => quote_without_impact!(MockLiquiditySource3),
@@ -273,6 +279,7 @@ impl<T: Config>
             .max(T::XYKPool::quote_weight())
             .max(T::MulticollateralBondingCurvePool::quote_weight())
             .max(T::OrderBook::quote_weight())
+            .max(T::ConcentratedLiquidityPool::quote_weight())
     }
 
     fn step_quote_weight(samples_count: usize) -> Weight {
@@ -282,6 +289,7 @@ impl<T: Config>
                 samples_count,
             ))
             .max(T::OrderBook::step_quote_weight(samples_count))
+            .max(T::ConcentratedLiquidityPool::step_quote_weight(samples_count))
     }
 
     fn exchange_weight() -> Weight {
@@ -291,6 +299,7 @@ impl<T: Config>
                 LiquiditySourceType::MulticollateralBondingCurvePool,
                 LiquiditySourceType::XSTPool,
                 LiquiditySourceType::OrderBook,
+                LiquiditySourceType::ConcentratedLiquidityPool,
             ]
             .into_iter(),
         )
@@ -301,6 +310,7 @@ impl<T: Config>
             .max(T::XYKPool::check_rewards_weight())
             .max(T::MulticollateralBondingCurvePool::check_rewards_weight())
             .max(T::OrderBook::check_rewards_weight())
+            .max(T::ConcentratedLiquidityPool::check_rewards_weight())
     }
 }
 
@@ -361,6 +371,9 @@ impl<T: Config>
                 }
                 LiquiditySourceType::XSTPool => T::XSTPool::exchange_weight(),
                 LiquiditySourceType::OrderBook => T::OrderBook::exchange_weight(),
+                LiquiditySourceType::ConcentratedLiquidityPool => {
+                    T::ConcentratedLiquidityPool::exchange_weight()
+                }
                 LiquiditySourceType::BondingCurvePool
                 | LiquiditySourceType::MockPool
                 | LiquiditySourceType::MockPool2
@@ -442,6 +455,13 @@ pub mod pallet {
             Balance,
             DispatchError,
         >;
+        type ConcentratedLiquidityPool: LiquiditySource<
+            Self::DEXId,
+            Self::AccountId,
+            AssetIdOf<Self>,
+            Balance,
+            DispatchError,
+        >;
 
         type WeightInfo: WeightInfo;
     }
@@ -0,0 +1,129 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! DEX Manager module benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use common::{AssetManager, AssetName, AssetSymbol, Balance, DEXId, DEFAULT_BALANCE_PRECISION};
+use frame_benchmarking::{account, benchmarks};
+use frame_support::pallet_prelude::Zero;
+use frame_system::{EventRecord, RawOrigin};
+use sp_std::prelude::*;
+
+pub const DEX: DEXId = DEXId::PolkaswapPresto;
+
+// Support Functions
+fn register_asset<T: Config>(name: &'static str) -> AssetIdOf<T> {
+    let owner: T::AccountId = account(name, 0, 0);
+    frame_system::Pallet::<T>::inc_providers(&owner);
+    T::AssetManager::register_from(
+        &owner,
+        AssetSymbol(b"BENCH".to_vec()),
+        AssetName(b"Benchmark Token".to_vec()),
+        DEFAULT_BALANCE_PRECISION,
+        Balance::zero(),
+        true,
+        common::AssetType::Regular,
+        None,
+        None,
+    )
+    .expect("Failed to register asset")
+}
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+    let events = frame_system::Pallet::<T>::events();
+    let system_event: <T as frame_system::Config>::RuntimeEvent = generic_event.into();
+    // compare to the last event record
+    let EventRecord { event, .. } = &events[events.len() - 1];
+    assert_eq!(event, &system_event);
+}
+
+benchmarks! {
+    register_dex {
+        DEXInfos::<T>::remove(T::DEXId::from(DEX));
+        let base_asset_id = register_asset::<T>("base");
+        let synthetic_base_asset_id = register_asset::<T>("synthetic");
+        let manager: T::AccountId = account("manager", 0, 0);
+        frame_system::Pallet::<T>::inc_providers(&manager);
+    }: _(
+        RawOrigin::Root,
+        DEX.into(),
+        base_asset_id,
+        synthetic_base_asset_id,
+        false,
+        Some(manager.clone())
+    )
+    verify {
+        assert_last_event::<T>(
+            Event::<T>::DEXRegistered(
+                DEX.into(),
+                DEXInfo::<T> {
+                    base_asset_id,
+                    synthetic_base_asset_id,
+                    is_public: false,
+                },
+                Some(manager),
+            ).into()
+        )
+    }
+
+    set_dex_management_mode {
+        DEXInfos::<T>::insert(
+            T::DEXId::from(DEX),
+            DEXInfo::<T> {
+                base_asset_id: register_asset::<T>("base"),
+                synthetic_base_asset_id: register_asset::<T>("synthetic"),
+                is_public: false,
+            },
+        );
+    }: _(RawOrigin::Root, DEX.into(), true)
+    verify {
+        assert_last_event::<T>(Event::<T>::DEXManagementModeChanged(DEX.into(), true).into());
+        assert!(DEXInfos::<T>::get(T::DEXId::from(DEX)).unwrap().is_public);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{ExtBuilder, Runtime};
+    use frame_support::assert_ok;
+
+    #[test]
+    fn test_benchmarks() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(Pallet::<Runtime>::test_benchmark_register_dex());
+            assert_ok!(Pallet::<Runtime>::test_benchmark_set_dex_management_mode());
+        });
+    }
+}
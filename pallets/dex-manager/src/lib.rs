@@ -33,8 +33,10 @@
 #![allow(clippy::all)]
 
 use common::prelude::EnsureDEXManager;
-use common::AssetIdOf;
-use common::{hash, DexInfoProvider, ManagementMode};
+use common::{
+    hash, AssetIdOf, AssetInfoProvider, AssetName, AssetSymbol, BalancePrecision, ContentSource,
+    Description, DexInfoProvider, ManagementMode,
+};
 use frame_support::dispatch::DispatchResult;
 use frame_support::ensure;
 use frame_support::sp_runtime::DispatchError;
@@ -42,7 +44,10 @@ use frame_system::RawOrigin;
 use permissions::{Scope, MANAGE_DEX};
 use sp_std::vec::Vec;
 
+mod benchmarking;
 pub mod migrations;
+pub mod weights;
+pub use weights::WeightInfo;
 
 #[cfg(test)]
 mod mock;
@@ -111,9 +116,24 @@ pub mod pallet {
     use super::*;
     use frame_support::pallet_prelude::*;
     use frame_support::traits::StorageVersion;
+    use frame_system::pallet_prelude::*;
 
     #[pallet::config]
-    pub trait Config: frame_system::Config + common::Config + permissions::Config {}
+    pub trait Config: frame_system::Config + common::Config + permissions::Config {
+        #[allow(deprecated)]
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        type AssetInfoProvider: AssetInfoProvider<
+            AssetIdOf<Self>,
+            Self::AccountId,
+            AssetSymbol,
+            AssetName,
+            BalancePrecision,
+            ContentSource,
+            Description,
+        >;
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+    }
 
     /// The current storage version.
     const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
@@ -124,7 +144,97 @@ pub mod pallet {
     pub struct Pallet<T>(PhantomData<T>);
 
     #[pallet::call]
-    impl<T: Config> Pallet<T> {}
+    impl<T: Config> Pallet<T> {
+        /// Register a new DEX, its trading pairs can be registered right after that.
+        ///
+        /// - `origin`: the sudo account on whose behalf the transaction is being executed,
+        /// - `dex_id`: identifier of the new DEX,
+        /// - `base_asset_id`: base asset of the DEX, it must not be a base asset of another DEX,
+        /// - `synthetic_base_asset_id`: synthetic base asset of the DEX,
+        /// - `is_public`: if `true`, anyone can manage the DEX (e.g. register trading pairs),
+        /// - `manager`: account which is granted with the DEX management permission, required for
+        ///   private DEXes.
+        #[pallet::call_index(0)]
+        #[pallet::weight(<T as Config>::WeightInfo::register_dex())]
+        pub fn register_dex(
+            origin: OriginFor<T>,
+            dex_id: T::DEXId,
+            base_asset_id: AssetIdOf<T>,
+            synthetic_base_asset_id: AssetIdOf<T>,
+            is_public: bool,
+            manager: Option<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            ensure!(
+                !DEXInfos::<T>::contains_key(&dex_id),
+                Error::<T>::DEXIdAlreadyExists
+            );
+            ensure!(
+                base_asset_id != synthetic_base_asset_id,
+                Error::<T>::InvalidBaseAssets
+            );
+            ensure!(
+                DEXInfos::<T>::iter_values().all(|info| info.base_asset_id != base_asset_id),
+                Error::<T>::BaseAssetAlreadyUsed
+            );
+            ensure!(
+                is_public || manager.is_some(),
+                Error::<T>::ManagerIsRequired
+            );
+            T::AssetInfoProvider::ensure_asset_exists(&base_asset_id)?;
+            T::AssetInfoProvider::ensure_asset_exists(&synthetic_base_asset_id)?;
+
+            if let Some(manager) = &manager {
+                permissions::Pallet::<T>::assign_permission(
+                    manager.clone(),
+                    manager,
+                    MANAGE_DEX,
+                    Scope::Limited(hash(&dex_id)),
+                )?;
+            }
+
+            let dex_info = DEXInfo::<T> {
+                base_asset_id,
+                synthetic_base_asset_id,
+                is_public,
+            };
+            DEXInfos::<T>::insert(&dex_id, &dex_info);
+            Self::deposit_event(Event::DEXRegistered(dex_id, dex_info, manager));
+            Ok(().into())
+        }
+
+        /// Change management mode of a DEX.
+        ///
+        /// - `origin`: the sudo account on whose behalf the transaction is being executed,
+        /// - `dex_id`: identifier of the DEX,
+        /// - `is_public`: if `true`, anyone can manage the DEX, otherwise only accounts with the
+        ///   DEX management permission.
+        #[pallet::call_index(1)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_dex_management_mode())]
+        pub fn set_dex_management_mode(
+            origin: OriginFor<T>,
+            dex_id: T::DEXId,
+            is_public: bool,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            DEXInfos::<T>::try_mutate(&dex_id, |dex_info| -> DispatchResult {
+                let dex_info = dex_info.as_mut().ok_or(Error::<T>::DEXDoesNotExist)?;
+                dex_info.is_public = is_public;
+                Ok(())
+            })?;
+            Self::deposit_event(Event::DEXManagementModeChanged(dex_id, is_public));
+            Ok(().into())
+        }
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// New DEX was registered. [DEX Id, DEX Info, Manager]
+        DEXRegistered(T::DEXId, DEXInfo<T>, Option<T::AccountId>),
+        /// DEX management mode was changed. [DEX Id, Is Public]
+        DEXManagementModeChanged(T::DEXId, bool),
+    }
 
     #[pallet::error]
     pub enum Error<T> {
@@ -136,6 +246,12 @@ pub mod pallet {
         InvalidFeeValue,
         /// Account with given Id is not registered.
         InvalidAccountId,
+        /// Base asset and synthetic base asset of DEX must differ.
+        InvalidBaseAssets,
+        /// Base asset is already used by another DEX.
+        BaseAssetAlreadyUsed,
+        /// Private DEX must have a manager.
+        ManagerIsRequired,
     }
 
     #[pallet::storage]
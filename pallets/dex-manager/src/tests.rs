@@ -31,7 +31,10 @@
 use crate::mock::*;
 use crate::{Error, Pallet};
 use common::prelude::DEXInfo;
-use common::{hash, DexInfoProvider, EnsureDEXManager, ManagementMode, VAL, XOR, XST};
+use common::{
+    hash, AssetName, AssetSymbol, AssetType, DexInfoProvider, EnsureDEXManager, ManagementMode,
+    DEFAULT_BALANCE_PRECISION, DOT, VAL, XOR, XST,
+};
 use frame_support::assert_noop;
 use frame_support::assert_ok;
 use permissions::{Scope, MANAGE_DEX};
@@ -396,3 +399,169 @@ fn test_queries_for_nonexistant_dex_should_fail() {
         );
     })
 }
+
+fn register_test_assets() {
+    frame_system::Pallet::<Runtime>::inc_providers(&ALICE);
+    frame_system::Pallet::<Runtime>::inc_providers(&BOB);
+    for (asset_id, symbol) in [(XOR, "XOR"), (XST, "XST"), (VAL, "VAL")] {
+        assert_ok!(assets::Pallet::<Runtime>::register_asset_id(
+            ALICE,
+            asset_id,
+            AssetSymbol(symbol.as_bytes().to_vec()),
+            AssetName(symbol.as_bytes().to_vec()),
+            DEFAULT_BALANCE_PRECISION,
+            0,
+            true,
+            AssetType::Regular,
+            None,
+            None,
+        ));
+    }
+}
+
+#[test]
+fn test_register_dex_should_pass() {
+    ExtBuilder::default().build().execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        register_test_assets();
+        assert_ok!(DEXPallet::register_dex(
+            RuntimeOrigin::root(),
+            DEX_A_ID,
+            XOR,
+            XST,
+            true,
+            None
+        ));
+        let dex_info = DEXInfo {
+            base_asset_id: XOR,
+            synthetic_base_asset_id: XST,
+            is_public: true,
+        };
+        assert_eq!(DEXPallet::dex_id(DEX_A_ID), Some(dex_info.clone()));
+        frame_system::Pallet::<Runtime>::assert_last_event(
+            crate::Event::<Runtime>::DEXRegistered(DEX_A_ID, dex_info, None).into(),
+        );
+        assert_ok!(DEXPallet::ensure_can_manage(
+            &DEX_A_ID,
+            RuntimeOrigin::signed(BOB),
+            ManagementMode::Public,
+        ));
+    })
+}
+
+#[test]
+fn test_register_private_dex_should_grant_manager_permission() {
+    ExtBuilder::default().build().execute_with(|| {
+        register_test_assets();
+        assert_ok!(DEXPallet::register_dex(
+            RuntimeOrigin::root(),
+            DEX_B_ID,
+            VAL,
+            XST,
+            false,
+            Some(BOB)
+        ));
+        assert_ok!(DEXPallet::ensure_can_manage(
+            &DEX_B_ID,
+            RuntimeOrigin::signed(BOB),
+            ManagementMode::Private,
+        ));
+        assert_noop!(
+            DEXPallet::ensure_can_manage(
+                &DEX_B_ID,
+                RuntimeOrigin::signed(ALICE),
+                ManagementMode::Public,
+            ),
+            permissions::Error::<Runtime>::Forbidden
+        );
+    })
+}
+
+#[test]
+fn test_register_dex_should_fail() {
+    ExtBuilder::default().build().execute_with(|| {
+        register_test_assets();
+        assert_noop!(
+            DEXPallet::register_dex(RuntimeOrigin::signed(ALICE), DEX_A_ID, XOR, XST, true, None),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            DEXPallet::register_dex(RuntimeOrigin::root(), DEX_A_ID, XOR, XOR, true, None),
+            Error::<Runtime>::InvalidBaseAssets
+        );
+        assert_noop!(
+            DEXPallet::register_dex(RuntimeOrigin::root(), DEX_A_ID, XOR, XST, false, None),
+            Error::<Runtime>::ManagerIsRequired
+        );
+        assert_noop!(
+            DEXPallet::register_dex(RuntimeOrigin::root(), DEX_A_ID, DOT, XST, true, None),
+            assets::Error::<Runtime>::AssetIdNotExists
+        );
+        assert_ok!(DEXPallet::register_dex(
+            RuntimeOrigin::root(),
+            DEX_A_ID,
+            XOR,
+            XST,
+            true,
+            None
+        ));
+        assert_noop!(
+            DEXPallet::register_dex(RuntimeOrigin::root(), DEX_A_ID, VAL, XST, true, None),
+            Error::<Runtime>::DEXIdAlreadyExists
+        );
+        assert_noop!(
+            DEXPallet::register_dex(RuntimeOrigin::root(), DEX_B_ID, XOR, XST, true, None),
+            Error::<Runtime>::BaseAssetAlreadyUsed
+        );
+    })
+}
+
+#[test]
+fn test_set_dex_management_mode_should_pass() {
+    let mut ext = ExtBuilder {
+        initial_dex_list: vec![(
+            DEX_A_ID,
+            DEXInfo {
+                base_asset_id: XOR,
+                synthetic_base_asset_id: XST,
+                is_public: false,
+            },
+        )],
+        initial_permission_owners: vec![(MANAGE_DEX, Scope::Limited(hash(&DEX_A_ID)), vec![ALICE])],
+        initial_permissions: vec![(ALICE, Scope::Limited(hash(&DEX_A_ID)), vec![MANAGE_DEX])],
+        ..Default::default()
+    }
+    .build();
+    ext.execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        assert_noop!(
+            DEXPallet::ensure_can_manage(
+                &DEX_A_ID,
+                RuntimeOrigin::signed(BOB),
+                ManagementMode::Public,
+            ),
+            permissions::Error::<Runtime>::Forbidden
+        );
+        assert_noop!(
+            DEXPallet::set_dex_management_mode(RuntimeOrigin::signed(ALICE), DEX_A_ID, true),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            DEXPallet::set_dex_management_mode(RuntimeOrigin::root(), DEX_B_ID, true),
+            Error::<Runtime>::DEXDoesNotExist
+        );
+        assert_ok!(DEXPallet::set_dex_management_mode(
+            RuntimeOrigin::root(),
+            DEX_A_ID,
+            true
+        ));
+        frame_system::Pallet::<Runtime>::assert_last_event(
+            crate::Event::<Runtime>::DEXManagementModeChanged(DEX_A_ID, true).into(),
+        );
+        assert_ok!(DEXPallet::ensure_can_manage(
+            &DEX_A_ID,
+            RuntimeOrigin::signed(BOB),
+            ManagementMode::Public,
+        ));
+    })
+}
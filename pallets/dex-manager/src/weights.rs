@@ -0,0 +1,135 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Autogenerated weights for dex_manager
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2026-10-18, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `983eb2919397`, CPU: `Intel(R) Xeon(R) CPU E3-1240 v6 @ 3.70GHz`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("local"), DB CACHE: 1024
+
+// Executed Command:
+// /usr/local/bin/framenode
+// benchmark
+// pallet
+// --chain=local
+// --steps=50
+// --repeat=20
+// --pallet=dex_manager
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --header=./misc/file_header.txt
+// --template=./misc/pallet-weight-template.hbs
+// --output=./pallets/dex-manager/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for dex_manager.
+pub trait WeightInfo {
+	fn register_dex() -> Weight;
+	fn set_dex_management_mode() -> Weight;
+}
+
+/// Weights for dex_manager using the Substrate node and recommended hardware.
+#[allow(dead_code)]
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: DEXManager DEXInfos (r:6 w:1)
+	/// Proof Skipped: DEXManager DEXInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets AssetOwners (r:2 w:0)
+	/// Proof Skipped: Assets AssetOwners (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Permissions Owners (r:1 w:1)
+	/// Proof Skipped: Permissions Owners (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Permissions Permissions (r:1 w:1)
+	/// Proof Skipped: Permissions Permissions (max_values: None, max_size: None, mode: Measured)
+	fn register_dex() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2014`
+		//  Estimated: `25631`
+		// Minimum execution time: 98_512_000 picoseconds.
+		Weight::from_parts(99_874_000, 25631)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: DEXManager DEXInfos (r:1 w:1)
+	/// Proof Skipped: DEXManager DEXInfos (max_values: None, max_size: None, mode: Measured)
+	fn set_dex_management_mode() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `618`
+		//  Estimated: `4083`
+		// Minimum execution time: 24_157_000 picoseconds.
+		Weight::from_parts(24_633_000, 4083)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: DEXManager DEXInfos (r:6 w:1)
+	/// Proof Skipped: DEXManager DEXInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets AssetOwners (r:2 w:0)
+	/// Proof Skipped: Assets AssetOwners (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Permissions Owners (r:1 w:1)
+	/// Proof Skipped: Permissions Owners (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Permissions Permissions (r:1 w:1)
+	/// Proof Skipped: Permissions Permissions (max_values: None, max_size: None, mode: Measured)
+	fn register_dex() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2014`
+		//  Estimated: `25631`
+		// Minimum execution time: 98_512_000 picoseconds.
+		Weight::from_parts(99_874_000, 25631)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: DEXManager DEXInfos (r:1 w:1)
+	/// Proof Skipped: DEXManager DEXInfos (max_values: None, max_size: None, mode: Measured)
+	fn set_dex_management_mode() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `618`
+		//  Estimated: `4083`
+		// Minimum execution time: 24_157_000 picoseconds.
+		Weight::from_parts(24_633_000, 4083)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
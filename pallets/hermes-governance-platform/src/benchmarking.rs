@@ -5,8 +5,8 @@
 use super::*;
 
 use codec::{Decode, Encode};
-use common::{balance, AssetInfoProvider, AssetManager, HERMES_ASSET_ID};
-use frame_benchmarking::benchmarks;
+use common::{balance, AssetInfoProvider, AssetManager, VoteConviction, HERMES_ASSET_ID};
+use frame_benchmarking::{account, benchmarks};
 use frame_support::assert_ok;
 use frame_support::BoundedVec;
 use frame_support::PalletId;
@@ -52,12 +52,28 @@ benchmarks! {
         options.try_push("No".try_into().unwrap()).unwrap();
 
         T::AssetManager::mint(
-            RawOrigin::Signed(owner).into(),
+            RawOrigin::Signed(owner.clone()).into(),
             HERMES_ASSET_ID.into(),
             caller.clone(),
             hermes_amount
         ).unwrap();
 
+        // Delegate voting power to caller, so that it is locked by the vote
+        let delegator: T::AccountId = account("delegator", 0, 0);
+        frame_system::Pallet::<T>::inc_providers(&delegator);
+        T::AssetManager::mint(
+            RawOrigin::Signed(owner).into(),
+            HERMES_ASSET_ID.into(),
+            delegator.clone(),
+            hermes_amount
+        ).unwrap();
+        assert_ok!(HermesGovernancePlatform::<T>::delegate(
+            RawOrigin::Signed(delegator).into(),
+            caller.clone(),
+            hermes_amount,
+            VoteConviction::Locked6x
+        ));
+
         let hermes_poll_info = HermesPollInfo {
             creator: caller.clone(),
             hermes_locked,
@@ -253,6 +269,78 @@ benchmarks! {
         assert_last_event::<T>(Event::MinimumHermesForCreatingPollChanged(hermes_amount).into())
     }
 
+    delegate {
+        let caller = alice::<T>();
+        let delegate: T::AccountId = account("delegate", 0, 0);
+        let hermes_amount = balance!(1000);
+        let owner: T::AccountId = <T as Config>::AssetInfoProvider::get_asset_owner(&HERMES_ASSET_ID.clone().into()).unwrap();
+
+        T::AssetManager::mint(
+            RawOrigin::Signed(owner).into(),
+            HERMES_ASSET_ID.into(),
+            caller.clone(),
+            hermes_amount
+        ).unwrap();
+    }: _(RawOrigin::Signed(caller.clone()), delegate.clone(), hermes_amount, VoteConviction::Locked6x)
+    verify {
+        assert_last_event::<T>(Event::Delegated(caller, delegate, hermes_amount).into())
+    }
+
+    undelegate {
+        let caller = alice::<T>();
+        let delegate: T::AccountId = account("delegate", 0, 0);
+        let hermes_amount = balance!(1000);
+        let current_timestamp = Timestamp::<T>::get();
+        let owner: T::AccountId = <T as Config>::AssetInfoProvider::get_asset_owner(&HERMES_ASSET_ID.clone().into()).unwrap();
+
+        T::AssetManager::mint(
+            RawOrigin::Signed(owner).into(),
+            HERMES_ASSET_ID.into(),
+            caller.clone(),
+            hermes_amount
+        ).unwrap();
+
+        assert_ok!(HermesGovernancePlatform::<T>::delegate(
+            RawOrigin::Signed(caller.clone()).into(),
+            delegate,
+            hermes_amount,
+            VoteConviction::Locked6x
+        ));
+    }: _(RawOrigin::Signed(caller.clone()))
+    verify {
+        let unlocking_timestamp = current_timestamp.max(T::CONVICTION_LOCK_PERIOD * 16u32.into());
+        assert_last_event::<T>(Event::Undelegated(caller, unlocking_timestamp).into())
+    }
+
+    withdraw_delegated_funds {
+        let caller = alice::<T>();
+        let delegate: T::AccountId = account("delegate", 0, 0);
+        let hermes_amount = balance!(1000);
+        let owner: T::AccountId = <T as Config>::AssetInfoProvider::get_asset_owner(&HERMES_ASSET_ID.clone().into()).unwrap();
+
+        T::AssetManager::mint(
+            RawOrigin::Signed(owner).into(),
+            HERMES_ASSET_ID.into(),
+            caller.clone(),
+            hermes_amount
+        ).unwrap();
+
+        assert_ok!(HermesGovernancePlatform::<T>::delegate(
+            RawOrigin::Signed(caller.clone()).into(),
+            delegate,
+            hermes_amount,
+            VoteConviction::None
+        ));
+        assert_ok!(HermesGovernancePlatform::<T>::undelegate(
+            RawOrigin::Signed(caller.clone()).into()
+        ));
+
+        pallet_timestamp::Now::<T>::put(Timestamp::<T>::get() + 1u32.into());
+    }: _(RawOrigin::Signed(caller.clone()))
+    verify {
+        assert_last_event::<T>(Event::DelegatedFundsWithdrawn(caller, hermes_amount).into())
+    }
+
     impl_benchmark_test_suite!(
         Pallet,
         crate::mock::ExtBuilder::default().build(),
@@ -15,7 +15,7 @@ mod tests;
 extern crate alloc;
 
 use codec::{Decode, Encode};
-use common::{Balance, BoundedString};
+use common::{Balance, BoundedString, VoteConviction};
 use frame_support::BoundedVec;
 pub use weights::WeightInfo;
 
@@ -29,6 +29,32 @@ pub struct HermesVotingInfo<StringLimit: sp_core::Get<u32>> {
     number_of_hermes: Balance,
     /// Hermes withdrawn
     hermes_withdrawn: bool,
+    /// Conviction of vote
+    conviction: VoteConviction,
+    /// Hermes weighted by conviction, including delegated voting power
+    voting_power: Balance,
+}
+
+#[derive(Encode, Decode, PartialEq, Eq, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct HermesDelegationInfo<AccountId, Moment> {
+    /// Account voting with delegated Hermes
+    delegate: AccountId,
+    /// Amount of delegated Hermes
+    amount: Balance,
+    /// Conviction of delegated Hermes
+    conviction: VoteConviction,
+    /// Timestamp after which Hermes can be withdrawn, set when delegation is revoked
+    unlocking_timestamp: Option<Moment>,
+}
+
+#[derive(Encode, Decode, Default, PartialEq, Eq, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct HermesDelegatedVotingInfo<Moment> {
+    /// Voting power delegated to account
+    voting_power: Balance,
+    /// Latest end of poll in which delegated voting power was used
+    locked_until: Moment,
 }
 
 #[derive(Encode, Decode, Default, PartialEq, Eq, scale_info::TypeInfo)]
@@ -67,17 +93,22 @@ pub enum StorageVersion {
     V1,
     /// After migrating 'voting_option' to String, and new 'options' field in HermesPollInfo
     V2,
+    /// After adding conviction and delegated voting power to HermesVotingInfo
+    V3,
 }
 
 pub use pallet::*;
 
 #[frame_support::pallet]
 pub mod pallet {
-    use crate::{migrations, HermesPollInfo, HermesVotingInfo, StorageVersion, WeightInfo};
+    use crate::{
+        migrations, HermesDelegatedVotingInfo, HermesDelegationInfo, HermesPollInfo,
+        HermesVotingInfo, StorageVersion, WeightInfo,
+    };
     use common::prelude::Balance;
     use common::{
         balance, AssetIdOf, AssetInfoProvider, AssetManager, AssetName, AssetSymbol,
-        BalancePrecision, BoundedString, ContentSource, Description,
+        BalancePrecision, BoundedString, ContentSource, Description, VoteConviction,
     };
     use frame_support::pallet_prelude::*;
     use frame_support::sp_runtime::traits::AccountIdConversion;
@@ -89,7 +120,9 @@ pub mod pallet {
     use pallet_timestamp as timestamp;
     use sp_core::H256;
     use sp_io::hashing::blake2_256;
+    use sp_runtime::traits::Saturating;
     use sp_std::collections::btree_set::BTreeSet;
+    use sp_std::prelude::*;
 
     const PALLET_ID: PalletId = PalletId(*b"hermsgov");
 
@@ -103,6 +136,9 @@ pub mod pallet {
         /// Maximum duration of poll represented in milliseconds
         const MAX_DURATION_OF_POLL: Self::Moment;
 
+        /// Lock period of conviction votes represented in milliseconds
+        const CONVICTION_LOCK_PERIOD: Self::Moment;
+
         /// String limit
         type StringLimit: Get<u32>;
 
@@ -174,6 +210,23 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// Hermes delegated by account
+    #[pallet::storage]
+    #[pallet::getter(fn hermes_delegations)]
+    pub type HermesDelegations<T: Config> = StorageMap<
+        _,
+        Identity,
+        AccountIdOf<T>,
+        HermesDelegationInfo<AccountIdOf<T>, T::Moment>,
+        OptionQuery,
+    >;
+
+    /// Voting power delegated to account
+    #[pallet::storage]
+    #[pallet::getter(fn hermes_delegated_voting_power)]
+    pub type HermesDelegatedVotingPower<T: Config> =
+        StorageMap<_, Identity, AccountIdOf<T>, HermesDelegatedVotingInfo<T::Moment>, ValueQuery>;
+
     #[pallet::type_value]
     pub fn DefaultMinimumHermesVotingAmount<T: Config>() -> Balance {
         balance!(1000)
@@ -237,6 +290,12 @@ pub mod pallet {
         MinimumHermesForVotingChanged(Balance),
         /// Change minimum Hermes for creating poll [balance]
         MinimumHermesForCreatingPollChanged(Balance),
+        /// Hermes delegated [who, delegate, balance]
+        Delegated(AccountIdOf<T>, AccountIdOf<T>, Balance),
+        /// Hermes undelegated [who, unlocking_timestamp]
+        Undelegated(AccountIdOf<T>, T::Moment),
+        /// Delegated Hermes withdrawn [who, balance]
+        DelegatedFundsWithdrawn(AccountIdOf<T>, Balance),
     }
 
     #[pallet::error]
@@ -279,6 +338,20 @@ pub mod pallet {
         InvalidOption,
         /// Duplicate options
         DuplicateOptions,
+        /// Votes are locked by conviction
+        VotesAreLocked,
+        /// Can't delegate to self
+        CantDelegateToSelf,
+        /// Already delegating
+        AlreadyDelegating,
+        /// Not delegating
+        NotDelegating,
+        /// Delegation already revoked
+        AlreadyUndelegated,
+        /// Delegation is not revoked
+        DelegationIsActive,
+        /// Delegated funds are locked
+        DelegatedFundsAreLocked,
     }
 
     #[pallet::call]
@@ -293,64 +366,7 @@ pub mod pallet {
             voting_option: BoundedString<T::StringLimit>,
         ) -> DispatchResultWithPostInfo {
             let user = ensure_signed(origin)?;
-
-            let current_timestamp = Timestamp::<T>::get();
-            let hermes_poll_info =
-                <HermesPollData<T>>::get(&poll_id).ok_or(Error::<T>::PollDoesNotExist)?;
-
-            ensure!(
-                current_timestamp >= hermes_poll_info.poll_start_timestamp,
-                Error::<T>::PollIsNotStarted
-            );
-
-            ensure!(
-                current_timestamp <= hermes_poll_info.poll_end_timestamp,
-                Error::<T>::PollIsFinished
-            );
-
-            ensure!(
-                hermes_poll_info.options.contains(&voting_option),
-                Error::<T>::InvalidOption
-            );
-
-            ensure!(
-                MinimumHermesVotingAmount::<T>::get()
-                    <= <T as Config>::AssetInfoProvider::free_balance(
-                        &T::HermesAssetId::get().into(),
-                        &user
-                    )
-                    .unwrap_or(0),
-                Error::<T>::NotEnoughHermesForVoting
-            );
-
-            ensure!(
-                !<HermesVotings<T>>::contains_key(&poll_id, &user),
-                Error::<T>::AlreadyVoted
-            );
-
-            let hermes_voting_info = HermesVotingInfo {
-                voting_option: voting_option.clone(),
-                number_of_hermes: MinimumHermesVotingAmount::<T>::get(),
-                hermes_withdrawn: false,
-            };
-
-            // Transfer Hermes to pallet
-            T::AssetManager::transfer_from(
-                &T::HermesAssetId::get().into(),
-                &user,
-                &Self::account_id(),
-                hermes_voting_info.number_of_hermes,
-            )
-            .map_err(|_assets_err| Error::<T>::NotEnoughHermesForVoting)?;
-
-            // Update storage
-            <HermesVotings<T>>::insert(&poll_id, &user, hermes_voting_info);
-
-            // Emit event
-            Self::deposit_event(Event::<T>::Voted(user, poll_id, voting_option));
-
-            // Return a successful DispatchResult
-            Ok(().into())
+            Self::do_vote(user, poll_id, voting_option, VoteConviction::None)
         }
 
         /// Create poll
@@ -473,6 +489,14 @@ pub mod pallet {
                 Error::<T>::FundsAlreadyWithdrawn
             );
 
+            ensure!(
+                current_timestamp
+                    > hermes_poll_info.poll_end_timestamp.saturating_add(
+                        Self::conviction_lock_duration(hermes_voting_info.conviction)
+                    ),
+                Error::<T>::VotesAreLocked
+            );
+
             // Withdraw Hermes
             T::AssetManager::transfer_from(
                 &T::HermesAssetId::get().into(),
@@ -587,17 +611,165 @@ pub mod pallet {
             // Return a successful DispatchResult
             Ok(().into())
         }
+
+        /// Vote for some option with Hermes locked past the end of poll by conviction
+        #[transactional]
+        #[pallet::call_index(6)]
+        #[pallet::weight(<T as Config>::WeightInfo::vote())]
+        pub fn vote_with_conviction(
+            origin: OriginFor<T>,
+            poll_id: H256,
+            voting_option: BoundedString<T::StringLimit>,
+            conviction: VoteConviction,
+        ) -> DispatchResultWithPostInfo {
+            let user = ensure_signed(origin)?;
+            Self::do_vote(user, poll_id, voting_option, conviction)
+        }
+
+        /// Delegate Hermes voting power to another account
+        #[transactional]
+        #[pallet::call_index(7)]
+        #[pallet::weight(<T as Config>::WeightInfo::delegate())]
+        pub fn delegate(
+            origin: OriginFor<T>,
+            delegate: AccountIdOf<T>,
+            hermes_amount: Balance,
+            conviction: VoteConviction,
+        ) -> DispatchResultWithPostInfo {
+            let user = ensure_signed(origin)?;
+
+            ensure!(user != delegate, Error::<T>::CantDelegateToSelf);
+            ensure!(
+                hermes_amount >= MinimumHermesVotingAmount::<T>::get(),
+                Error::<T>::NotEnoughHermesForVoting
+            );
+            ensure!(
+                !<HermesDelegations<T>>::contains_key(&user),
+                Error::<T>::AlreadyDelegating
+            );
+
+            // Transfer Hermes to pallet
+            T::AssetManager::transfer_from(
+                &T::HermesAssetId::get().into(),
+                &user,
+                &Self::account_id(),
+                hermes_amount,
+            )
+            .map_err(|_assets_err| Error::<T>::NotEnoughHermesForVoting)?;
+
+            <HermesDelegatedVotingPower<T>>::mutate(&delegate, |delegated_voting_info| {
+                delegated_voting_info.voting_power = delegated_voting_info
+                    .voting_power
+                    .saturating_add(conviction.votes(hermes_amount));
+            });
+            <HermesDelegations<T>>::insert(
+                &user,
+                HermesDelegationInfo {
+                    delegate: delegate.clone(),
+                    amount: hermes_amount,
+                    conviction,
+                    unlocking_timestamp: None,
+                },
+            );
+
+            // Emit event
+            Self::deposit_event(Event::<T>::Delegated(user, delegate, hermes_amount));
+
+            // Return a successful DispatchResult
+            Ok(().into())
+        }
+
+        /// Revoke delegation, delegated Hermes are unlocked after polls in which they were used
+        #[pallet::call_index(8)]
+        #[pallet::weight(<T as Config>::WeightInfo::undelegate())]
+        pub fn undelegate(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            let user = ensure_signed(origin)?;
+
+            let mut delegation_info =
+                <HermesDelegations<T>>::get(&user).ok_or(Error::<T>::NotDelegating)?;
+            ensure!(
+                delegation_info.unlocking_timestamp.is_none(),
+                Error::<T>::AlreadyUndelegated
+            );
+
+            let mut delegated_voting_info =
+                <HermesDelegatedVotingPower<T>>::get(&delegation_info.delegate);
+            delegated_voting_info.voting_power = delegated_voting_info
+                .voting_power
+                .saturating_sub(delegation_info.conviction.votes(delegation_info.amount));
+
+            let unlocking_timestamp = Timestamp::<T>::get().max(
+                delegated_voting_info
+                    .locked_until
+                    .saturating_add(Self::conviction_lock_duration(delegation_info.conviction)),
+            );
+            delegation_info.unlocking_timestamp = Some(unlocking_timestamp);
+
+            <HermesDelegatedVotingPower<T>>::insert(
+                &delegation_info.delegate,
+                delegated_voting_info,
+            );
+            <HermesDelegations<T>>::insert(&user, delegation_info);
+
+            // Emit event
+            Self::deposit_event(Event::<T>::Undelegated(user, unlocking_timestamp));
+
+            // Return a successful DispatchResult
+            Ok(().into())
+        }
+
+        /// Withdraw Hermes of revoked delegation
+        #[pallet::call_index(9)]
+        #[pallet::weight(<T as Config>::WeightInfo::withdraw_delegated_funds())]
+        pub fn withdraw_delegated_funds(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            let user = ensure_signed(origin)?;
+
+            let delegation_info =
+                <HermesDelegations<T>>::get(&user).ok_or(Error::<T>::NotDelegating)?;
+            let unlocking_timestamp = delegation_info
+                .unlocking_timestamp
+                .ok_or(Error::<T>::DelegationIsActive)?;
+            ensure!(
+                Timestamp::<T>::get() > unlocking_timestamp,
+                Error::<T>::DelegatedFundsAreLocked
+            );
+
+            // Withdraw Hermes
+            T::AssetManager::transfer_from(
+                &T::HermesAssetId::get().into(),
+                &Self::account_id(),
+                &user,
+                delegation_info.amount,
+            )?;
+
+            <HermesDelegations<T>>::remove(&user);
+
+            // Emit event
+            Self::deposit_event(Event::<T>::DelegatedFundsWithdrawn(
+                user,
+                delegation_info.amount,
+            ));
+
+            // Return a successful DispatchResult
+            Ok(().into())
+        }
     }
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
         fn on_runtime_upgrade() -> Weight {
-            if Self::pallet_storage_version() == StorageVersion::V1 {
-                let weight = migrations::migrate::<T>();
-                PalletStorageVersion::<T>::put(StorageVersion::V2);
-                weight
-            } else {
-                Weight::zero()
+            match Self::pallet_storage_version() {
+                StorageVersion::V1 => {
+                    let weight = migrations::migrate::<T>();
+                    PalletStorageVersion::<T>::put(StorageVersion::V3);
+                    weight
+                }
+                StorageVersion::V2 => {
+                    let weight = migrations::migrate_to_conviction_voting::<T>();
+                    PalletStorageVersion::<T>::put(StorageVersion::V3);
+                    weight
+                }
+                StorageVersion::V3 => Weight::zero(),
             }
         }
     }
@@ -607,5 +779,112 @@ pub mod pallet {
         fn account_id() -> T::AccountId {
             PALLET_ID.into_account_truncating()
         }
+
+        /// Duration for which votes with conviction stay locked after poll ends
+        fn conviction_lock_duration(conviction: VoteConviction) -> T::Moment {
+            T::CONVICTION_LOCK_PERIOD.saturating_mul(conviction.lock_periods().into())
+        }
+
+        fn do_vote(
+            user: AccountIdOf<T>,
+            poll_id: H256,
+            voting_option: BoundedString<T::StringLimit>,
+            conviction: VoteConviction,
+        ) -> DispatchResultWithPostInfo {
+            let current_timestamp = Timestamp::<T>::get();
+            let hermes_poll_info =
+                <HermesPollData<T>>::get(&poll_id).ok_or(Error::<T>::PollDoesNotExist)?;
+
+            ensure!(
+                current_timestamp >= hermes_poll_info.poll_start_timestamp,
+                Error::<T>::PollIsNotStarted
+            );
+
+            ensure!(
+                current_timestamp <= hermes_poll_info.poll_end_timestamp,
+                Error::<T>::PollIsFinished
+            );
+
+            ensure!(
+                hermes_poll_info.options.contains(&voting_option),
+                Error::<T>::InvalidOption
+            );
+
+            ensure!(
+                MinimumHermesVotingAmount::<T>::get()
+                    <= <T as Config>::AssetInfoProvider::free_balance(
+                        &T::HermesAssetId::get().into(),
+                        &user
+                    )
+                    .unwrap_or(0),
+                Error::<T>::NotEnoughHermesForVoting
+            );
+
+            ensure!(
+                !<HermesVotings<T>>::contains_key(&poll_id, &user),
+                Error::<T>::AlreadyVoted
+            );
+
+            // Delegated voting power stays locked until the poll ends
+            let mut delegated_voting_info = <HermesDelegatedVotingPower<T>>::get(&user);
+            if delegated_voting_info.voting_power > 0 {
+                delegated_voting_info.locked_until = delegated_voting_info
+                    .locked_until
+                    .max(hermes_poll_info.poll_end_timestamp);
+                <HermesDelegatedVotingPower<T>>::insert(&user, &delegated_voting_info);
+            }
+
+            let number_of_hermes = MinimumHermesVotingAmount::<T>::get();
+            let hermes_voting_info = HermesVotingInfo {
+                voting_option: voting_option.clone(),
+                number_of_hermes,
+                hermes_withdrawn: false,
+                conviction,
+                voting_power: conviction
+                    .votes(number_of_hermes)
+                    .saturating_add(delegated_voting_info.voting_power),
+            };
+
+            // Transfer Hermes to pallet
+            T::AssetManager::transfer_from(
+                &T::HermesAssetId::get().into(),
+                &user,
+                &Self::account_id(),
+                hermes_voting_info.number_of_hermes,
+            )
+            .map_err(|_assets_err| Error::<T>::NotEnoughHermesForVoting)?;
+
+            // Update storage
+            <HermesVotings<T>>::insert(&poll_id, &user, hermes_voting_info);
+
+            // Emit event
+            Self::deposit_event(Event::<T>::Voted(user, poll_id, voting_option));
+
+            // Return a successful DispatchResult
+            Ok(().into())
+        }
+
+        /// Votes per option of poll, weighted by conviction and including delegated voting power
+        pub fn poll_tallies(poll_id: H256) -> Vec<(BoundedString<T::StringLimit>, Balance)> {
+            let Some(hermes_poll_info) = <HermesPollData<T>>::get(&poll_id) else {
+                return Vec::new();
+            };
+
+            let mut tallies: Vec<(BoundedString<T::StringLimit>, Balance)> = hermes_poll_info
+                .options
+                .into_iter()
+                .map(|option| (option, 0))
+                .collect();
+            for hermes_voting_info in <HermesVotings<T>>::iter_prefix_values(&poll_id) {
+                if let Some((_, votes)) = tallies
+                    .iter_mut()
+                    .find(|(option, _)| *option == hermes_voting_info.voting_option)
+                {
+                    *votes = votes.saturating_add(hermes_voting_info.voting_power);
+                }
+            }
+
+            tallies
+        }
     }
 }
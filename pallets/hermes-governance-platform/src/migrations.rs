@@ -3,7 +3,7 @@ use crate::{
 };
 use alloc::string::String;
 use codec::{Decode, Encode};
-use common::BoundedString;
+use common::{BoundedString, VoteConviction};
 use frame_support::__private::log;
 use frame_support::traits::Get;
 use frame_support::weights::Weight;
@@ -58,6 +58,8 @@ pub fn migrate_voting_and_poll_data<T: Config>() -> Weight {
                 voting_option: BoundedString::truncate_from(new_voting_option),
                 number_of_hermes,
                 hermes_withdrawn,
+                conviction: VoteConviction::None,
+                voting_power: number_of_hermes,
             })
         },
     );
@@ -88,3 +90,29 @@ pub fn migrate_voting_and_poll_data<T: Config>() -> Weight {
 
     T::DbWeight::get().reads_writes(weight, weight)
 }
+
+pub fn migrate_to_conviction_voting<T: Config>() -> Weight {
+    sp_runtime::runtime_logger::RuntimeLogger::init();
+    let mut weight: u64 = 0;
+
+    HermesVotings::<T>::translate_values::<(BoundedString<T::StringLimit>, Balance, bool), _>(
+        |(voting_option, number_of_hermes, hermes_withdrawn)| {
+            weight += 1;
+
+            Some(HermesVotingInfo {
+                voting_option,
+                number_of_hermes,
+                hermes_withdrawn,
+                conviction: VoteConviction::None,
+                voting_power: number_of_hermes,
+            })
+        },
+    );
+
+    log::info!(
+        target: "runtime",
+        "HermesVotingInfo migrated to new version with 'conviction' and 'voting_power' fields"
+    );
+
+    T::DbWeight::get().reads_writes(weight, weight)
+}
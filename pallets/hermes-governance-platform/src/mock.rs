@@ -43,7 +43,7 @@ construct_runtime! {
         Tokens: tokens::{Pallet, Call, Config<T>, Storage, Event<T>},
         Currencies: currencies::{Pallet, Call, Storage},
         Balances: pallet_balances::{Pallet, Call, Storage, Event<T>},
-        DexManager: dex_manager::{Pallet, Call, Config<T>, Storage},
+        DexManager: dex_manager::{Pallet, Call, Config<T>, Storage, Event<T>},
         Permissions: permissions::{Pallet, Call, Config<T>, Storage, Event<T>},
         Technical: technical::{Pallet, Call, Config<T>, Storage, Event<T>},
         PoolXYK: pool_xyk::{Pallet, Call, Storage, Event<T>},
@@ -112,6 +112,7 @@ parameter_types! {
 impl Config for Runtime {
     const MIN_DURATION_OF_POLL: Self::Moment = 14_400_000;
     const MAX_DURATION_OF_POLL: Self::Moment = 604_800_000;
+    const CONVICTION_LOCK_PERIOD: Self::Moment = 86_400_000;
     type StringLimit = StringLimit;
     type OptionsLimit = OptionsLimit;
     type TitleLimit = TitleLimit;
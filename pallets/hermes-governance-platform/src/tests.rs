@@ -3,7 +3,10 @@ use crate::mock::*;
 use crate::AccountIdOf;
 use crate::{pallet, Error, HermesPollInfo};
 use codec::Encode;
-use common::{balance, generate_storage_instance, AssetInfoProvider, HERMES_ASSET_ID};
+use common::{
+    balance, generate_storage_instance, AssetInfoProvider, Balance, BoundedString, VoteConviction,
+    HERMES_ASSET_ID,
+};
 use frame_support::pallet_prelude::{StorageDoubleMap, StorageMap};
 use frame_support::storage::types::OptionQuery;
 use frame_support::traits::Hooks;
@@ -14,6 +17,33 @@ use sp_core::H256;
 use sp_io::hashing::blake2_256;
 use sp_runtime::traits::AccountIdConversion;
 
+fn insert_test_poll(creator: AccountId) -> H256 {
+    let poll_start_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+    let poll_end_timestamp = poll_start_timestamp + 604800000;
+    let nonce = frame_system::Pallet::<Runtime>::account_nonce(&creator);
+    let encoded: [u8; 32] = (&creator, nonce).using_encoded(blake2_256);
+    let poll_id = H256::from(encoded);
+    let mut options = BoundedVec::default();
+    options.try_push("Option 1".try_into().unwrap()).unwrap();
+    options.try_push("Option 2".try_into().unwrap()).unwrap();
+    options.try_push("Option 3".try_into().unwrap()).unwrap();
+
+    let hermes_poll_info = HermesPollInfo {
+        creator,
+        hermes_locked: pallet::MinimumHermesAmountForCreatingPoll::<Runtime>::get(),
+        poll_start_timestamp,
+        poll_end_timestamp,
+        title: "Title".try_into().unwrap(),
+        description: "Description".try_into().unwrap(),
+        creator_hermes_withdrawn: false,
+        options,
+    };
+
+    pallet::HermesPollData::<Runtime>::insert(&poll_id, &hermes_poll_info);
+
+    poll_id
+}
+
 #[test]
 fn create_poll_invalid_start_timestamp() {
     let mut ext = ExtBuilder::default().build();
@@ -744,6 +774,221 @@ fn withdraw_funds_voter_ok() {
     });
 }
 
+#[test]
+fn vote_with_conviction_ok() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let poll_id = insert_test_poll(ALICE);
+
+        assert_ok!(HermesGovernancePlatform::delegate(
+            RuntimeOrigin::signed(CHARLES),
+            ALICE,
+            balance!(2000),
+            VoteConviction::Locked2x
+        ));
+
+        assert_ok!(HermesGovernancePlatform::vote_with_conviction(
+            RuntimeOrigin::signed(ALICE),
+            poll_id,
+            "Option 1".try_into().unwrap(),
+            VoteConviction::Locked3x
+        ));
+        assert_ok!(HermesGovernancePlatform::vote(
+            RuntimeOrigin::signed(CHARLES),
+            poll_id,
+            "Option 2".try_into().unwrap(),
+        ));
+
+        let hermes_voting_info = pallet::HermesVotings::<Runtime>::get(&poll_id, &ALICE).unwrap();
+        assert_eq!(hermes_voting_info.number_of_hermes, balance!(1000));
+        assert_eq!(hermes_voting_info.conviction, VoteConviction::Locked3x);
+        assert_eq!(hermes_voting_info.voting_power, balance!(7000));
+
+        assert_eq!(
+            HermesGovernancePlatform::poll_tallies(poll_id),
+            vec![
+                (BoundedString::truncate_from("Option 1"), balance!(7000)),
+                (BoundedString::truncate_from("Option 2"), balance!(1000)),
+                (BoundedString::truncate_from("Option 3"), 0),
+            ]
+        );
+    });
+}
+
+#[test]
+fn withdraw_funds_voter_votes_are_locked() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+        let poll_id = insert_test_poll(ALICE);
+
+        assert_ok!(HermesGovernancePlatform::vote_with_conviction(
+            RuntimeOrigin::signed(ALICE),
+            poll_id,
+            "Option 1".try_into().unwrap(),
+            VoteConviction::Locked2x
+        ));
+
+        pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 604900000);
+
+        assert_err!(
+            HermesGovernancePlatform::withdraw_funds_voter(RuntimeOrigin::signed(ALICE), poll_id),
+            Error::<Runtime>::VotesAreLocked
+        );
+
+        pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 691200001);
+
+        assert_ok!(HermesGovernancePlatform::withdraw_funds_voter(
+            RuntimeOrigin::signed(ALICE),
+            poll_id
+        ));
+    });
+}
+
+#[test]
+fn delegate_cant_delegate_to_self() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        assert_err!(
+            HermesGovernancePlatform::delegate(
+                RuntimeOrigin::signed(ALICE),
+                ALICE,
+                balance!(1000),
+                VoteConviction::None
+            ),
+            Error::<Runtime>::CantDelegateToSelf
+        );
+    });
+}
+
+#[test]
+fn delegate_not_enough_hermes_for_voting() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        assert_err!(
+            HermesGovernancePlatform::delegate(
+                RuntimeOrigin::signed(CHARLES),
+                ALICE,
+                balance!(999),
+                VoteConviction::None
+            ),
+            Error::<Runtime>::NotEnoughHermesForVoting
+        );
+
+        assert_err!(
+            HermesGovernancePlatform::delegate(
+                RuntimeOrigin::signed(BOB),
+                ALICE,
+                balance!(1000),
+                VoteConviction::None
+            ),
+            Error::<Runtime>::NotEnoughHermesForVoting
+        );
+    });
+}
+
+#[test]
+fn delegate_already_delegating() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        assert_ok!(HermesGovernancePlatform::delegate(
+            RuntimeOrigin::signed(CHARLES),
+            ALICE,
+            balance!(1000),
+            VoteConviction::None
+        ));
+
+        assert_err!(
+            HermesGovernancePlatform::delegate(
+                RuntimeOrigin::signed(CHARLES),
+                BOB,
+                balance!(1000),
+                VoteConviction::None
+            ),
+            Error::<Runtime>::AlreadyDelegating
+        );
+    });
+}
+
+#[test]
+fn undelegate_not_delegating() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        assert_err!(
+            HermesGovernancePlatform::undelegate(RuntimeOrigin::signed(CHARLES)),
+            Error::<Runtime>::NotDelegating
+        );
+    });
+}
+
+#[test]
+fn undelegate_and_withdraw_delegated_funds_ok() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+        let poll_id = insert_test_poll(ALICE);
+
+        assert_ok!(HermesGovernancePlatform::delegate(
+            RuntimeOrigin::signed(CHARLES),
+            ALICE,
+            balance!(2000),
+            VoteConviction::Locked2x
+        ));
+
+        assert_err!(
+            HermesGovernancePlatform::withdraw_delegated_funds(RuntimeOrigin::signed(CHARLES)),
+            Error::<Runtime>::DelegationIsActive
+        );
+
+        assert_ok!(HermesGovernancePlatform::vote(
+            RuntimeOrigin::signed(ALICE),
+            poll_id,
+            "Option 3".try_into().unwrap(),
+        ));
+        assert_ok!(HermesGovernancePlatform::undelegate(RuntimeOrigin::signed(
+            CHARLES
+        )));
+
+        assert_err!(
+            HermesGovernancePlatform::undelegate(RuntimeOrigin::signed(CHARLES)),
+            Error::<Runtime>::AlreadyUndelegated
+        );
+
+        // Delegated Hermes used in poll stay locked until its end and one lock period after it
+        let unlocking_timestamp = current_timestamp + 604800000 + 86400000;
+        assert_eq!(
+            HermesGovernancePlatform::hermes_delegations(CHARLES)
+                .unwrap()
+                .unlocking_timestamp,
+            Some(unlocking_timestamp)
+        );
+        assert_eq!(
+            HermesGovernancePlatform::hermes_delegated_voting_power(ALICE).voting_power,
+            0
+        );
+
+        pallet_timestamp::Pallet::<Runtime>::set_timestamp(unlocking_timestamp);
+
+        assert_err!(
+            HermesGovernancePlatform::withdraw_delegated_funds(RuntimeOrigin::signed(CHARLES)),
+            Error::<Runtime>::DelegatedFundsAreLocked
+        );
+
+        pallet_timestamp::Pallet::<Runtime>::set_timestamp(unlocking_timestamp + 1);
+
+        assert_ok!(HermesGovernancePlatform::withdraw_delegated_funds(
+            RuntimeOrigin::signed(CHARLES)
+        ));
+
+        assert_eq!(
+            Assets::free_balance(&HERMES_ASSET_ID, &CHARLES)
+                .expect("Failed to query free balance."),
+            balance!(300000)
+        );
+        assert!(HermesGovernancePlatform::hermes_delegations(CHARLES).is_none());
+    });
+}
+
 #[test]
 fn withdraw_funds_creator_poll_does_not_exist() {
     let mut ext = ExtBuilder::default().build();
@@ -1101,8 +1346,14 @@ fn hermes_governance_storage_migration_works() {
         let voting_b = pallet::HermesVotings::<Runtime>::get(&poll_id_b, &user1).unwrap();
         assert_eq!(poll_b.options, options);
         assert_eq!(voting_b.voting_option, "No".try_into().unwrap());
+        assert_eq!(voting_b.conviction, VoteConviction::None);
+        assert_eq!(voting_b.voting_power, number_of_hermes);
+        assert_eq!(
+            pallet::Pallet::<Runtime>::pallet_storage_version(),
+            crate::StorageVersion::V3
+        );
 
-        // Storage version should be V2 so no changes made
+        // Storage version should be V3 so no changes made
         pallet_timestamp::Pallet::<Runtime>::set_timestamp(11000000);
         run_to_block(10);
 
@@ -1121,3 +1372,45 @@ fn hermes_governance_storage_migration_works() {
         assert_eq!(voting_b.voting_option, "No".try_into().unwrap());
     });
 }
+
+#[test]
+fn hermes_governance_conviction_voting_migration_works() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        generate_storage_instance!(HermesGovernancePlatform, HermesVotings);
+
+        type HermesVotingsV2 = StorageDoubleMap<
+            HermesVotingsOldInstance,
+            Identity,
+            H256,
+            Identity,
+            AccountIdOf<Runtime>,
+            (BoundedString<StringLimit>, Balance, bool),
+            OptionQuery,
+        >;
+
+        let poll_id = H256::from([1u8; 32]);
+        HermesVotingsV2::insert(
+            &poll_id,
+            &ALICE,
+            (BoundedString::truncate_from("Yes"), balance!(1000), true),
+        );
+
+        pallet::PalletStorageVersion::<Runtime>::put(crate::StorageVersion::V2);
+
+        //Storage migration
+        pallet::Pallet::<Runtime>::on_runtime_upgrade();
+
+        assert_eq!(
+            pallet::Pallet::<Runtime>::pallet_storage_version(),
+            crate::StorageVersion::V3
+        );
+
+        let voting = pallet::HermesVotings::<Runtime>::get(&poll_id, &ALICE).unwrap();
+        assert_eq!(voting.voting_option, "Yes".try_into().unwrap());
+        assert_eq!(voting.number_of_hermes, balance!(1000));
+        assert!(voting.hermes_withdrawn);
+        assert_eq!(voting.conviction, VoteConviction::None);
+        assert_eq!(voting.voting_power, balance!(1000));
+    });
+}
@@ -66,6 +66,9 @@ pub trait WeightInfo {
 	fn withdraw_funds_creator() -> Weight;
 	fn change_min_hermes_for_voting() -> Weight;
 	fn change_min_hermes_for_creating_poll() -> Weight;
+	fn delegate() -> Weight;
+	fn undelegate() -> Weight;
+	fn withdraw_delegated_funds() -> Weight;
 }
 
 /// Weights for hermes_governance_platform using the Substrate node and recommended hardware.
@@ -81,6 +84,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
 	/// Storage: HermesGovernancePlatform HermesVotings (r:1 w:1)
 	/// Proof Skipped: HermesGovernancePlatform HermesVotings (max_values: None, max_size: None, mode: Measured)
+	/// Storage: HermesGovernancePlatform HermesDelegatedVotingPower (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesDelegatedVotingPower (max_values: None, max_size: None, mode: Measured)
 	/// Storage: ExtendedAssets SoulboundAsset (r:1 w:0)
 	/// Proof: ExtendedAssets SoulboundAsset (max_values: None, max_size: Some(322091), added: 324566, mode: MaxEncodedLen)
 	/// Storage: Assets AssetInfosV2 (r:1 w:0)
@@ -89,12 +94,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	fn vote() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1935`
-		//  Estimated: `351157`
-		// Minimum execution time: 146_609_000 picoseconds.
-		Weight::from_parts(150_872_000, 351157)
-			.saturating_add(T::DbWeight::get().reads(10_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+		//  Measured:  `2079`
+		//  Estimated: `352903`
+		// Minimum execution time: 149_318_000 picoseconds.
+		Weight::from_parts(153_940_000, 352903)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: Timestamp Now (r:1 w:0)
 	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
@@ -189,6 +194,65 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: HermesGovernancePlatform MinimumHermesVotingAmount (r:1 w:0)
+	/// Proof Skipped: HermesGovernancePlatform MinimumHermesVotingAmount (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: HermesGovernancePlatform HermesDelegations (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ExtendedAssets SoulboundAsset (r:1 w:0)
+	/// Proof: ExtendedAssets SoulboundAsset (max_values: None, max_size: Some(322091), added: 324566, mode: MaxEncodedLen)
+	/// Storage: Assets AssetInfosV2 (r:1 w:0)
+	/// Proof Skipped: Assets AssetInfosV2 (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: HermesGovernancePlatform HermesDelegatedVotingPower (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesDelegatedVotingPower (max_values: None, max_size: None, mode: Measured)
+	fn delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1622`
+		//  Estimated: `350398`
+		// Minimum execution time: 121_845_000 picoseconds.
+		Weight::from_parts(123_217_000, 350398)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: HermesGovernancePlatform HermesDelegations (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: HermesGovernancePlatform HermesDelegatedVotingPower (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesDelegatedVotingPower (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn undelegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `476`
+		//  Estimated: `3941`
+		// Minimum execution time: 30_118_000 picoseconds.
+		Weight::from_parts(30_702_000, 3941)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: HermesGovernancePlatform HermesDelegations (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: ExtendedAssets SoulboundAsset (r:1 w:0)
+	/// Proof: ExtendedAssets SoulboundAsset (max_values: None, max_size: Some(322091), added: 324566, mode: MaxEncodedLen)
+	/// Storage: Assets AssetInfosV2 (r:1 w:0)
+	/// Proof Skipped: Assets AssetInfosV2 (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn withdraw_delegated_funds() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1847`
+		//  Estimated: `345102`
+		// Minimum execution time: 114_590_000 picoseconds.
+		Weight::from_parts(115_483_000, 345102)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -203,6 +267,8 @@ impl WeightInfo for () {
 	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
 	/// Storage: HermesGovernancePlatform HermesVotings (r:1 w:1)
 	/// Proof Skipped: HermesGovernancePlatform HermesVotings (max_values: None, max_size: None, mode: Measured)
+	/// Storage: HermesGovernancePlatform HermesDelegatedVotingPower (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesDelegatedVotingPower (max_values: None, max_size: None, mode: Measured)
 	/// Storage: ExtendedAssets SoulboundAsset (r:1 w:0)
 	/// Proof: ExtendedAssets SoulboundAsset (max_values: None, max_size: Some(322091), added: 324566, mode: MaxEncodedLen)
 	/// Storage: Assets AssetInfosV2 (r:1 w:0)
@@ -211,12 +277,12 @@ impl WeightInfo for () {
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	fn vote() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1935`
-		//  Estimated: `351157`
-		// Minimum execution time: 146_609_000 picoseconds.
-		Weight::from_parts(150_872_000, 351157)
-			.saturating_add(RocksDbWeight::get().reads(10_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+		//  Measured:  `2079`
+		//  Estimated: `352903`
+		// Minimum execution time: 149_318_000 picoseconds.
+		Weight::from_parts(153_940_000, 352903)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: Timestamp Now (r:1 w:0)
 	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
@@ -311,4 +377,63 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: HermesGovernancePlatform MinimumHermesVotingAmount (r:1 w:0)
+	/// Proof Skipped: HermesGovernancePlatform MinimumHermesVotingAmount (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: HermesGovernancePlatform HermesDelegations (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ExtendedAssets SoulboundAsset (r:1 w:0)
+	/// Proof: ExtendedAssets SoulboundAsset (max_values: None, max_size: Some(322091), added: 324566, mode: MaxEncodedLen)
+	/// Storage: Assets AssetInfosV2 (r:1 w:0)
+	/// Proof Skipped: Assets AssetInfosV2 (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: HermesGovernancePlatform HermesDelegatedVotingPower (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesDelegatedVotingPower (max_values: None, max_size: None, mode: Measured)
+	fn delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1622`
+		//  Estimated: `350398`
+		// Minimum execution time: 121_845_000 picoseconds.
+		Weight::from_parts(123_217_000, 350398)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: HermesGovernancePlatform HermesDelegations (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: HermesGovernancePlatform HermesDelegatedVotingPower (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesDelegatedVotingPower (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn undelegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `476`
+		//  Estimated: `3941`
+		// Minimum execution time: 30_118_000 picoseconds.
+		Weight::from_parts(30_702_000, 3941)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: HermesGovernancePlatform HermesDelegations (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: ExtendedAssets SoulboundAsset (r:1 w:0)
+	/// Proof: ExtendedAssets SoulboundAsset (max_values: None, max_size: Some(322091), added: 324566, mode: MaxEncodedLen)
+	/// Storage: Assets AssetInfosV2 (r:1 w:0)
+	/// Proof Skipped: Assets AssetInfosV2 (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn withdraw_delegated_funds() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1847`
+		//  Estimated: `345102`
+		// Minimum execution time: 114_590_000 picoseconds.
+		Weight::from_parts(115_483_000, 345102)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
 }
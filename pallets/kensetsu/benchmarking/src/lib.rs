@@ -34,15 +34,16 @@
 use codec::Decode;
 use common::{
     balance, AssetId32, AssetIdOf, AssetManager, Balance, DEXId, PredefinedAssetId,
-    PriceToolsProvider, PriceVariant, SymbolName, DAI, KEN, KUSD, XOR,
+    PriceToolsProvider, PriceVariant, SymbolName, DAI, ETH, KEN, KUSD, PSWAP, TBCD, VAL, XOR, XST,
 };
 use frame_benchmarking::benchmarks;
 use frame_system::RawOrigin;
 use hex_literal::hex;
 use kensetsu::{
-    BorrowTax, BorrowTaxes, CdpId, CdpType, CollateralInfos, CollateralRiskParameters, Event,
-    KarmaBorrowTax, PegAsset, StablecoinCollateralIdentifier, StablecoinInfos,
-    StablecoinParameters, TbcdBorrowTax,
+    BasketCollaterals, BasketRiskParameters, BorrowTax, BorrowTaxes, CdpId, CdpType,
+    CollateralInfos, CollateralRiskParameters, Event, KarmaBorrowTax, PegAsset,
+    StablecoinCollateralIdentifier, StablecoinInfos, StablecoinParameters, TbcdBorrowTax,
+    MAX_BASKET_COLLATERALS,
 };
 use price_tools::AVG_BLOCK_SPAN;
 use sp_arithmetic::{Perbill, Percent};
@@ -50,6 +51,7 @@ use sp_core::Get;
 use sp_runtime::traits::{One, Zero};
 use sp_runtime::FixedU128;
 use sp_std::vec;
+use sp_std::vec::Vec;
 
 pub struct Pallet<T: Config>(kensetsu::Pallet<T>);
 pub trait Config:
@@ -229,14 +231,124 @@ fn incoming_spot_price<T: price_tools::Config>(asset_id: AssetIdOf<T>, price: Ba
     price_tools::FastPriceInfos::<T>::mutate(asset_id, |opt_val| {
         let val = opt_val.as_mut().unwrap();
         val.price_mut_of(PriceVariant::Buy)
-            .incoming_spot_price(price, PriceVariant::Buy, &price_tools::FAST_PARAMETERS)
+            .incoming_spot_price(
+                price,
+                PriceVariant::Buy,
+                &price_tools::FAST_PARAMETERS,
+                AVG_BLOCK_SPAN,
+            )
             .unwrap();
         val.price_mut_of(PriceVariant::Sell)
-            .incoming_spot_price(price, PriceVariant::Sell, &price_tools::FAST_PARAMETERS)
+            .incoming_spot_price(
+                price,
+                PriceVariant::Sell,
+                &price_tools::FAST_PARAMETERS,
+                AVG_BLOCK_SPAN,
+            )
             .unwrap();
     })
 }
 
+/// Assets used as basket collaterals, the first `n` of them are taken into the basket
+const BASKET_COLLATERALS: [AssetId32<PredefinedAssetId>; MAX_BASKET_COLLATERALS as usize] =
+    [XOR, VAL, PSWAP, ETH, DAI, KEN, TBCD, XST];
+
+/// Sets first `n` basket collaterals as collateral types of KUSD basket CDPs with liquidity and
+/// prices, returns their asset ids.
+fn set_basket_collaterals<T: Config>(n: u32) -> Vec<AssetIdOf<T>> {
+    initialize_liquidity_sources::<T>();
+    let mut collaterals = Vec::new();
+    for asset_id in BASKET_COLLATERALS.iter().take(n as usize) {
+        let asset_id: AssetIdOf<T> = (*asset_id).into();
+        // XOR/DAI and XOR/KEN pools are initialized with liquidity sources
+        if asset_id != XOR.into() && asset_id != DAI.into() && asset_id != KEN.into() {
+            let _ = trading_pair::Pallet::<T>::register(
+                RawOrigin::Signed(caller::<T>()).into(),
+                DEXId::Polkaswap.into(),
+                XOR.into(),
+                asset_id,
+            );
+            initialize_xyk_pool::<T>(asset_id);
+        }
+        if asset_id != XOR.into() && asset_id != DAI.into() {
+            let _ = price_tools::Pallet::<T>::register_asset(&asset_id);
+            for _ in 1..=AVG_BLOCK_SPAN {
+                incoming_spot_price::<T>(asset_id, balance!(1));
+            }
+        }
+        CollateralInfos::<T>::set(
+            StablecoinCollateralIdentifier {
+                collateral_asset_id: asset_id,
+                stablecoin_asset_id: KUSD.into(),
+            },
+            Some(kensetsu::CollateralInfo {
+                risk_parameters: CollateralRiskParameters {
+                    hard_cap: Balance::MAX,
+                    liquidation_ratio: Perbill::from_percent(50),
+                    max_liquidation_lot: balance!(100),
+                    stability_fee_rate: FixedU128::from_perbill(Perbill::from_percent(10)),
+                    minimal_collateral_deposit: balance!(0),
+                },
+                total_collateral: balance!(0),
+                stablecoin_supply: balance!(0),
+                last_fee_update_time: Default::default(),
+                interest_coefficient: FixedU128::one(),
+            }),
+        );
+        collaterals.push(asset_id);
+    }
+    kensetsu::Pallet::<T>::update_basket_risk_parameters(
+        RawOrigin::Root.into(),
+        KUSD.into(),
+        BasketRiskParameters {
+            hard_cap: Balance::MAX,
+            stability_fee_rate: FixedU128::from_perbill(Perbill::from_percent(10)),
+            minimal_collateral_value: balance!(0),
+        },
+    )
+    .expect("Shall set basket risk parameters");
+    collaterals
+}
+
+/// Mints `amount` of every asset to caller and returns them as basket collaterals
+fn mint_basket_collaterals<T: Config>(
+    collateral_asset_ids: &[AssetIdOf<T>],
+    amount: Balance,
+) -> BasketCollaterals<AssetIdOf<T>> {
+    let mut collaterals = BasketCollaterals::<AssetIdOf<T>>::default();
+    for asset_id in collateral_asset_ids {
+        T::AssetManager::update_balance(
+            RawOrigin::Root.into(),
+            caller::<T>(),
+            *asset_id,
+            amount.try_into().unwrap(),
+        )
+        .expect("Shall mint collateral");
+        collaterals
+            .try_push((*asset_id, amount))
+            .expect("Shall fit into basket");
+    }
+    collaterals
+}
+
+/// Sets liquidation ratio of basket collaterals too low, making basket CDPs unsafe
+fn make_basket_cdps_unsafe<T: Config>(collateral_asset_ids: &[AssetIdOf<T>]) {
+    for asset_id in collateral_asset_ids {
+        CollateralInfos::<T>::mutate(
+            StablecoinCollateralIdentifier {
+                collateral_asset_id: *asset_id,
+                stablecoin_asset_id: KUSD.into(),
+            },
+            |info| {
+                if let Some(info) = info.as_mut() {
+                    info.risk_parameters.liquidation_ratio = Perbill::from_percent(1);
+                    info.risk_parameters.stability_fee_rate = FixedU128::zero();
+                }
+            },
+        );
+    }
+}
+
 benchmarks! {
     where_clause {
         where
@@ -340,6 +452,33 @@ benchmarks! {
         kensetsu::Pallet::<T>::liquidate(RawOrigin::Signed(caller::<T>()).into(), cdp_id).unwrap();
     }
 
+    liquidate_basket {
+        let n in 1 .. MAX_BASKET_COLLATERALS;
+        let collateral_asset_ids = set_basket_collaterals::<T>(n);
+        let collaterals = mint_basket_collaterals::<T>(&collateral_asset_ids, balance!(100));
+        let debt = balance!(50);
+        kensetsu::Pallet::<T>::create_basket_cdp(
+            RawOrigin::Signed(caller::<T>()).into(),
+            collaterals,
+            KUSD.into(),
+            debt,
+            debt,
+        )
+        .expect("Shall create basket CDP");
+        let cdp_id = kensetsu::NextCDPId::<T>::get();
+        // the last added collateral is liquidated, so all pools and prices are involved
+        let priority = collateral_asset_ids.iter().rev().cloned().collect::<Vec<_>>();
+        kensetsu::Pallet::<T>::update_basket_liquidation_priority(
+            RawOrigin::Root.into(),
+            KUSD.into(),
+            priority.try_into().unwrap(),
+        )
+        .expect("Shall set liquidation priority");
+        make_basket_cdps_unsafe::<T>(&collateral_asset_ids);
+    }: {
+        kensetsu::Pallet::<T>::liquidate(RawOrigin::Signed(caller::<T>()).into(), cdp_id).unwrap();
+    }
+
     accrue {
         initialize_liquidity_sources::<T>();
         set_xor_as_collateral_type::<T>();
@@ -375,6 +514,73 @@ benchmarks! {
         ).unwrap();
     }
 
+    create_basket_cdp {
+        let n in 1 .. MAX_BASKET_COLLATERALS;
+        let collateral_asset_ids = set_basket_collaterals::<T>(n);
+        let collaterals = mint_basket_collaterals::<T>(&collateral_asset_ids, balance!(10));
+        let debt = balance!(1);
+    }: {
+        kensetsu::Pallet::<T>::create_basket_cdp(
+            RawOrigin::Signed(caller::<T>()).into(),
+            collaterals,
+            KUSD.into(),
+            debt,
+            debt,
+        ).unwrap();
+    }
+
+    update_basket_risk_parameters {
+        set_basket_collaterals::<T>(1);
+        let new_risk_parameters = BasketRiskParameters {
+            hard_cap: balance!(1000),
+            stability_fee_rate: FixedU128::from_perbill(Perbill::from_percent(42)),
+            minimal_collateral_value: balance!(10),
+        };
+    }: {
+        kensetsu::Pallet::<T>::update_basket_risk_parameters(
+            RawOrigin::Root.into(),
+            KUSD.into(),
+            new_risk_parameters,
+        ).unwrap();
+    }
+    verify {
+        let new_info = kensetsu::BasketInfos::<T>::get::<AssetIdOf<T>>(KUSD.into())
+            .expect("Must succeed");
+        assert_eq!(new_info.risk_parameters, new_risk_parameters);
+    }
+
+    update_basket_liquidation_priority {
+        let n in 1 .. MAX_BASKET_COLLATERALS;
+        set_kensetsu_dollar_stablecoin::<T>();
+        kensetsu::Pallet::<T>::update_basket_risk_parameters(
+            RawOrigin::Root.into(),
+            KUSD.into(),
+            BasketRiskParameters {
+                hard_cap: Balance::MAX,
+                stability_fee_rate: FixedU128::zero(),
+                minimal_collateral_value: balance!(0),
+            },
+        )
+        .expect("Shall set basket risk parameters");
+        let priority = BASKET_COLLATERALS
+            .iter()
+            .take(n as usize)
+            .map(|asset_id| (*asset_id).into())
+            .collect::<Vec<AssetIdOf<T>>>();
+    }: {
+        kensetsu::Pallet::<T>::update_basket_liquidation_priority(
+            RawOrigin::Root.into(),
+            KUSD.into(),
+            priority.clone().try_into().unwrap(),
+        ).unwrap();
+    }
+    verify {
+        assert_eq!(
+            kensetsu::BasketLiquidationPriority::<T>::get::<AssetIdOf<T>>(KUSD.into()).into_inner(),
+            priority
+        );
+    }
+
     update_borrow_tax {
         let new_borrow_taxes = BorrowTaxes{
             ken_borrow_tax: Percent::from_percent(1),
@@ -47,6 +47,8 @@ use common::{balance, AssetIdOf, AssetManager, Balance, DataFeed, Rate, SymbolNa
 use frame_support::__private::log::{debug, warn};
 use scale_info::TypeInfo;
 use sp_arithmetic::{FixedU128, Perbill, Percent};
+use sp_core::bounded::BoundedVec;
+use sp_core::ConstU32;
 
 #[cfg(test)]
 mod mock;
@@ -196,6 +198,89 @@ pub struct CollateralizedDebtPosition<AccountId, AssetId> {
     pub interest_coefficient: FixedU128,
 }
 
+/// Maximum number of collateral assets in a basket CDP.
+pub const MAX_BASKET_COLLATERALS: u32 = 8;
+
+/// Collateral assets and amounts locked in a basket CDP.
+pub type BasketCollaterals<AssetId> =
+    BoundedVec<(AssetId, Balance), ConstU32<MAX_BASKET_COLLATERALS>>;
+
+/// Risk management parameters for basket CDPs borrowing the specific stablecoin.
+/// Liquidation ratio and liquidation lot of every collateral in the basket are taken from
+/// `CollateralRiskParameters` of the collateral.
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    TypeInfo,
+    PartialEq,
+    Eq,
+    Copy,
+)]
+pub struct BasketRiskParameters {
+    /// Hard cap of total stablecoins issued for all basket CDPs.
+    pub hard_cap: Balance,
+
+    /// Protocol Interest rate per second
+    pub stability_fee_rate: FixedU128,
+
+    /// Minimal value of the whole basket in stablecoins.
+    /// Replaces per collateral minimal deposit in order to protect from empty CDPs.
+    pub minimal_collateral_value: Balance,
+}
+
+/// Basket parameters, includes risk info and additional data for interest rate calculation
+#[derive(Debug, Encode, Decode, DecodeWithMemTracking, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
+pub struct BasketInfo<Moment> {
+    /// Basket risk parameters set by risk management
+    pub risk_parameters: BasketRiskParameters,
+
+    /// Amount of stablecoins issued for all basket CDPs
+    pub stablecoin_supply: Balance,
+
+    /// the last timestamp when stability fee was accrued
+    pub last_fee_update_time: Moment,
+
+    /// Interest accrued for basket CDPs for all time
+    pub interest_coefficient: FixedU128,
+}
+
+/// Basket CDP - Collateralized Debt Position secured by several collateral assets.
+/// Health is computed from the sum of collateral values multiplied by their liquidation ratios.
+#[derive(
+    Debug, Clone, Encode, Decode, DecodeWithMemTracking, MaxEncodedLen, TypeInfo, PartialEq,
+)]
+pub struct BasketDebtPosition<AccountId, AssetId> {
+    /// CDP owner
+    pub owner: AccountId,
+
+    /// Collaterals in the order of deposit
+    pub collaterals: BasketCollaterals<AssetId>,
+
+    // Debt asset id
+    pub stablecoin_asset_id: AssetId,
+
+    /// Normalized outstanding debt in stablecoins.
+    pub debt: Balance,
+
+    /// Interest accrued for CDP, see `CollateralizedDebtPosition::interest_coefficient`.
+    pub interest_coefficient: FixedU128,
+}
+
+impl<AccountId, AssetId: PartialEq> BasketDebtPosition<AccountId, AssetId> {
+    /// Returns amount of the collateral asset in the basket.
+    pub fn collateral_amount(&self, collateral_asset_id: &AssetId) -> Balance {
+        self.collaterals
+            .iter()
+            .find(|(asset_id, _)| asset_id == collateral_asset_id)
+            .map_or(0, |(_, amount)| *amount)
+    }
+}
+
 #[derive(Clone, Debug, Default, Encode, Decode, DecodeWithMemTracking, TypeInfo, PartialEq)]
 pub struct BorrowTaxes {
     pub ken_borrow_tax: Percent,
@@ -253,7 +338,13 @@ pub mod pallet {
                 block_number
             );
             let mut unsafe_cdp_ids = VecDeque::<CdpId>::new();
-            for (cdp_id, cdp) in <CDPDepository<T>>::iter() {
+            let cdps = <CDPDepository<T>>::iter()
+                .map(|(cdp_id, cdp)| (cdp_id, Self::check_cdp_is_safe(&cdp)))
+                .chain(
+                    <BasketDepository<T>>::iter()
+                        .map(|(cdp_id, cdp)| (cdp_id, Self::check_basket_cdp_is_safe(&cdp))),
+                );
+            for (cdp_id, is_safe) in cdps {
                 if let Ok(true) = Self::is_accruable(&cdp_id) {
                     debug!("Accrue for CDP {:?}", cdp_id);
                     let call = Call::<T>::accrue { cdp_id };
@@ -267,7 +358,7 @@ pub mod pallet {
                 }
 
                 // Liquidation
-                match Self::check_cdp_is_safe(&cdp) {
+                match is_safe {
                     Ok(true) => {}
                     Ok(false) => {
                         debug!("CDP {:?} unsafe", cdp_id);
@@ -359,6 +450,10 @@ pub mod pallet {
         #[pallet::constant]
         type MinimalStabilityFeeAccrue: Get<Balance>;
 
+        /// Maximum age of oracle rates in seconds, `None` disables the check.
+        #[pallet::constant]
+        type MaxOracleRateAge: Get<Option<u64>>;
+
         /// A configuration for base priority of unsigned transactions.
         #[pallet::constant]
         type UnsignedPriority: Get<TransactionPriority>;
@@ -440,6 +535,30 @@ pub mod pallet {
     pub type CdpOwnerIndex<T: Config> =
         StorageMap<_, Identity, AccountIdOf<T>, BoundedVec<CdpId, T::MaxCdpsPerOwner>>;
 
+    /// Parameters of basket CDPs per stablecoin, include risk parameters and interest
+    /// recalculation coefficients.
+    #[pallet::storage]
+    #[pallet::getter(fn basket_infos)]
+    pub type BasketInfos<T: Config> = StorageMap<_, Identity, AssetIdOf<T>, BasketInfo<T::Moment>>;
+
+    /// Order in which collaterals of basket CDPs are liquidated per stablecoin. Collaterals
+    /// missing in the list are liquidated after listed ones in the order of deposit.
+    #[pallet::storage]
+    #[pallet::getter(fn basket_liquidation_priority)]
+    pub type BasketLiquidationPriority<T: Config> = StorageMap<
+        _,
+        Identity,
+        AssetIdOf<T>,
+        BoundedVec<AssetIdOf<T>, ConstU32<MAX_BASKET_COLLATERALS>>,
+        ValueQuery,
+    >;
+
+    /// Storage of all basket CDPs, ids are shared with `CDPDepository`
+    #[pallet::storage]
+    #[pallet::getter(fn basket_cdp)]
+    pub type BasketDepository<T: Config> =
+        StorageMap<_, Identity, CdpId, BasketDebtPosition<AccountIdOf<T>, AssetIdOf<T>>>;
+
     /// Genesis configuration parameters for predefined assets. Populates storage StablecoinInfos
     /// with predefined assets on initialization. Contains list of:
     /// - predefined asset id;
@@ -602,6 +721,25 @@ pub mod pallet {
             old_minimal_stability_fee_accrue: Balance,
             new_minimal_stability_fee_accrue: Balance,
         },
+        BasketCDPCreated {
+            cdp_id: CdpId,
+            owner: AccountIdOf<T>,
+            debt_asset_id: AssetIdOf<T>,
+        },
+        BasketCDPClosed {
+            cdp_id: CdpId,
+            owner: AccountIdOf<T>,
+            /// Collaterals returned to the CDP owner.
+            collaterals: BasketCollaterals<AssetIdOf<T>>,
+        },
+        BasketRiskParametersUpdated {
+            stablecoin_asset_id: AssetIdOf<T>,
+            risk_parameters: BasketRiskParameters,
+        },
+        BasketLiquidationPriorityUpdated {
+            stablecoin_asset_id: AssetIdOf<T>,
+            priority: BoundedVec<AssetIdOf<T>, ConstU32<MAX_BASKET_COLLATERALS>>,
+        },
     }
 
     #[pallet::error]
@@ -631,6 +769,15 @@ pub mod pallet {
 
         /// Collateral must be registered in PriceTools.
         CollateralNotRegisteredInPriceTools,
+
+        /// Basket CDPs are not configured for the stablecoin.
+        BasketInfoNotFound,
+        /// Basket must contain at least one collateral, each collateral at most once.
+        WrongBasketCollaterals,
+        /// Value of basket collaterals is below minimal.
+        BasketValueBelowMinimal,
+        /// Too many collaterals in the basket.
+        BasketCollateralsLimit,
     }
 
     #[pallet::call]
@@ -718,6 +865,14 @@ pub mod pallet {
         pub fn close_cdp(origin: OriginFor<T>, cdp_id: CdpId) -> DispatchResult {
             let who = ensure_signed(origin)?;
 
+            if BasketDepository::<T>::contains_key(cdp_id) {
+                let cdp = Self::get_basket_cdp_updated(cdp_id)?;
+                ensure!(who == cdp.owner, Error::<T>::OperationNotPermitted);
+
+                Self::repay_basket_debt_internal(cdp_id, cdp.debt)?;
+                return Self::delete_basket_cdp(cdp_id);
+            }
+
             let cdp = Self::get_cdp_updated(cdp_id)?;
             ensure!(who == cdp.owner, Error::<T>::OperationNotPermitted);
 
@@ -765,6 +920,14 @@ pub mod pallet {
                 borrow_amount_min <= borrow_amount_max,
                 Error::<T>::WrongBorrowAmounts
             );
+            if BasketDepository::<T>::contains_key(cdp_id) {
+                return Self::borrow_basket_internal(
+                    &who,
+                    cdp_id,
+                    borrow_amount_min,
+                    borrow_amount_max,
+                );
+            }
             Self::borrow_internal(&who, cdp_id, borrow_amount_min, borrow_amount_max)
         }
 
@@ -779,6 +942,11 @@ pub mod pallet {
         #[pallet::weight(<T as Config>::WeightInfo::repay_debt())]
         pub fn repay_debt(origin: OriginFor<T>, cdp_id: CdpId, amount: Balance) -> DispatchResult {
             let who = ensure_signed(origin)?;
+            if BasketDepository::<T>::contains_key(cdp_id) {
+                let cdp = Self::get_basket_cdp_updated(cdp_id)?;
+                ensure!(who == cdp.owner, Error::<T>::OperationNotPermitted);
+                return Self::repay_basket_debt_internal(cdp_id, amount);
+            }
             let cdp = Self::get_cdp_updated(cdp_id)?;
             ensure!(who == cdp.owner, Error::<T>::OperationNotPermitted);
             Self::repay_debt_internal(cdp_id, amount)
@@ -791,7 +959,8 @@ pub mod pallet {
         /// - `_origin`: The origin of the transaction (unused).
         /// - `cdp_id`: The ID of the CDP to be liquidated.
         #[pallet::call_index(5)]
-        #[pallet::weight(<T as Config>::WeightInfo::liquidate())]
+        #[pallet::weight(<T as Config>::WeightInfo::liquidate()
+            .max(<T as Config>::WeightInfo::liquidate_basket(MAX_BASKET_COLLATERALS)))]
         pub fn liquidate(_origin: OriginFor<T>, cdp_id: CdpId) -> DispatchResult {
             // only one liquidation per block
             ensure!(
@@ -799,6 +968,24 @@ pub mod pallet {
                 Error::<T>::LiquidationLimit
             );
 
+            if BasketDepository::<T>::contains_key(cdp_id) {
+                let cdp = Self::get_basket_cdp_updated(cdp_id)?;
+                ensure!(!Self::check_basket_cdp_is_safe(&cdp)?, Error::<T>::CDPSafe);
+                let (collateral_asset_id, collateral_liquidated, proceeds, penalty) =
+                    Self::liquidate_basket_internal(cdp_id, &cdp)?;
+
+                Self::deposit_event(Event::Liquidated {
+                    cdp_id,
+                    collateral_asset_id,
+                    collateral_amount: collateral_liquidated,
+                    debt_asset_id: cdp.stablecoin_asset_id,
+                    proceeds,
+                    penalty,
+                });
+
+                return Ok(());
+            }
+
             let cdp = Self::get_cdp_updated(cdp_id)?;
             ensure!(!Self::check_cdp_is_safe(&cdp)?, Error::<T>::CDPSafe);
             let (collateral_liquidated, proceeds, penalty) =
@@ -829,7 +1016,11 @@ pub mod pallet {
                 Self::is_accruable(&cdp_id)?,
                 Error::<T>::UncollectedStabilityFeeTooSmall
             );
-            Self::get_cdp_updated(cdp_id)?;
+            if BasketDepository::<T>::contains_key(cdp_id) {
+                Self::get_basket_cdp_updated(cdp_id)?;
+            } else {
+                Self::get_cdp_updated(cdp_id)?;
+            }
             Ok(())
         }
 
@@ -1237,6 +1428,168 @@ pub mod pallet {
                 Ok(())
             })
         }
+
+        /// Creates a Collateralized Debt Position (CDP) backed by a basket of collaterals.
+        /// Every collateral of the basket must be registered for the stablecoin.
+        ///
+        /// ## Parameters
+        ///
+        /// - `origin`: The origin of the transaction.
+        /// - `collaterals`: Collateral asset ids with amounts to be deposited.
+        /// - `stablecoin_asset_id`: The stablecoin to borrow.
+        /// - `borrow_amount_min`: The minimum amount the user wants to borrow.
+        /// - `borrow_amount_max`: The maximum amount the user wants to borrow.
+        #[pallet::call_index(19)]
+        #[pallet::weight(<T as Config>::WeightInfo::create_basket_cdp(collaterals.len() as u32))]
+        pub fn create_basket_cdp(
+            origin: OriginFor<T>,
+            collaterals: BasketCollaterals<AssetIdOf<T>>,
+            stablecoin_asset_id: AssetIdOf<T>,
+            borrow_amount_min: Balance,
+            borrow_amount_max: Balance,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(
+                borrow_amount_min <= borrow_amount_max,
+                Error::<T>::WrongBorrowAmounts
+            );
+            let basket_info =
+                Self::basket_infos(stablecoin_asset_id).ok_or(Error::<T>::BasketInfoNotFound)?;
+            ensure!(!collaterals.is_empty(), Error::<T>::WrongBasketCollaterals);
+            for (index, (collateral_asset_id, _)) in collaterals.iter().enumerate() {
+                ensure!(
+                    !collaterals
+                        .iter()
+                        .skip(index + 1)
+                        .any(|(asset_id, _)| asset_id == collateral_asset_id),
+                    Error::<T>::WrongBasketCollaterals
+                );
+                ensure!(
+                    CollateralInfos::<T>::contains_key(StablecoinCollateralIdentifier {
+                        collateral_asset_id: *collateral_asset_id,
+                        stablecoin_asset_id,
+                    }),
+                    Error::<T>::CollateralInfoNotFound
+                );
+            }
+
+            // checks minimal basket value requirement
+            let basket_value =
+                Self::get_basket_collateral_value(&collaterals, stablecoin_asset_id)?;
+            ensure!(
+                basket_value >= basket_info.risk_parameters.minimal_collateral_value,
+                Error::<T>::BasketValueBelowMinimal
+            );
+
+            let cdp_id = Self::insert_basket_cdp(BasketDebtPosition {
+                owner: who.clone(),
+                collaterals: BoundedVec::default(),
+                stablecoin_asset_id,
+                debt: balance!(0),
+                interest_coefficient: basket_info.interest_coefficient,
+            })?;
+            Self::deposit_event(Event::BasketCDPCreated {
+                cdp_id,
+                owner: who.clone(),
+                debt_asset_id: stablecoin_asset_id,
+            });
+
+            for (collateral_asset_id, collateral_amount) in collaterals {
+                if collateral_amount > 0 {
+                    Self::deposit_basket_internal(
+                        &who,
+                        cdp_id,
+                        collateral_asset_id,
+                        collateral_amount,
+                    )?;
+                }
+            }
+
+            if borrow_amount_max > 0 {
+                Self::borrow_basket_internal(&who, cdp_id, borrow_amount_min, borrow_amount_max)?;
+            }
+
+            Ok(())
+        }
+
+        /// Deposits collateral into a basket CDP.
+        ///
+        /// ## Parameters
+        ///
+        /// - `origin`: The origin of the transaction.
+        /// - `cdp_id`: The ID of the basket CDP to deposit collateral into.
+        /// - `collateral_asset_id`: The collateral asset to deposit.
+        /// - `collateral_amount`: The amount of collateral to deposit.
+        #[pallet::call_index(20)]
+        #[pallet::weight(<T as Config>::WeightInfo::deposit_collateral())]
+        pub fn deposit_basket_collateral(
+            origin: OriginFor<T>,
+            cdp_id: CdpId,
+            collateral_asset_id: AssetIdOf<T>,
+            collateral_amount: Balance,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::deposit_basket_internal(&who, cdp_id, collateral_asset_id, collateral_amount)
+        }
+
+        /// Sets risk parameters of basket CDPs for the stablecoin.
+        ///
+        /// ## Parameters
+        ///
+        /// - `origin`: The origin of the transaction, only root is allowed.
+        /// - `stablecoin_asset_id`: The stablecoin borrowed against baskets.
+        /// - `new_risk_parameters`: The new basket risk parameters.
+        #[pallet::call_index(21)]
+        #[pallet::weight(<T as Config>::WeightInfo::update_basket_risk_parameters())]
+        pub fn update_basket_risk_parameters(
+            origin: OriginFor<T>,
+            stablecoin_asset_id: AssetIdOf<T>,
+            new_risk_parameters: BasketRiskParameters,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            Self::upsert_basket_info(&stablecoin_asset_id, new_risk_parameters)?;
+            Self::deposit_event(Event::BasketRiskParametersUpdated {
+                stablecoin_asset_id,
+                risk_parameters: new_risk_parameters,
+            });
+
+            Ok(())
+        }
+
+        /// Sets the order in which collaterals of basket CDPs are liquidated.
+        ///
+        /// ## Parameters
+        ///
+        /// - `origin`: The origin of the transaction, only root is allowed.
+        /// - `stablecoin_asset_id`: The stablecoin borrowed against baskets.
+        /// - `priority`: Collateral asset ids, the first one is liquidated first.
+        #[pallet::call_index(22)]
+        #[pallet::weight(<T as Config>::WeightInfo::update_basket_liquidation_priority(priority.len() as u32))]
+        pub fn update_basket_liquidation_priority(
+            origin: OriginFor<T>,
+            stablecoin_asset_id: AssetIdOf<T>,
+            priority: BoundedVec<AssetIdOf<T>, ConstU32<MAX_BASKET_COLLATERALS>>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                BasketInfos::<T>::contains_key(stablecoin_asset_id),
+                Error::<T>::BasketInfoNotFound
+            );
+            for (index, asset_id) in priority.iter().enumerate() {
+                ensure!(
+                    !priority.iter().skip(index + 1).any(|x| x == asset_id),
+                    Error::<T>::WrongBasketCollaterals
+                );
+            }
+            BasketLiquidationPriority::<T>::insert(stablecoin_asset_id, priority.clone());
+            Self::deposit_event(Event::BasketLiquidationPriorityUpdated {
+                stablecoin_asset_id,
+                priority,
+            });
+
+            Ok(())
+        }
     }
 
     /// Validate unsigned call to this pallet.
@@ -1265,9 +1618,7 @@ pub mod pallet {
                     }
                 }
                 Call::liquidate { cdp_id } => {
-                    let cdp = Self::get_cdp_updated(*cdp_id)
-                        .map_err(|_| InvalidTransaction::Custom(VALIDATION_ERROR_CHECK_SAFE))?;
-                    if !Self::check_cdp_is_safe(&cdp)
+                    if !Self::check_updated_cdp_is_safe(*cdp_id)
                         .map_err(|_| InvalidTransaction::Custom(VALIDATION_ERROR_CHECK_SAFE))?
                     {
                         ValidTransaction::with_tag_prefix("Kensetsu::liquidate")
@@ -1417,29 +1768,18 @@ pub mod pallet {
             !LiquidatedThisBlock::<T>::get()
         }
 
-        /// Checks whether a Collateralized Debt Position (CDP) is currently considered safe based on its debt and collateral.
-        /// The function evaluates the safety of a CDP based on predefined liquidation ratios and collateral values,
-        /// providing an indication of its current safety status.
+        /// Returns collateral value in stablecoin pegged asset.
         ///
         /// ## Parameters
         ///
-        /// - `debt`: The current debt amount in the CDP.
-        /// - `collateral`: The current collateral amount in the CDP.
-        /// - `collateral_asset_id`: The asset ID associated with the collateral in the CDP.
-        /// - `stablecoin_asset_id`: The asset ID associated with the debt in the CDP.
-        fn get_max_safe_debt(
+        /// - `collateral_asset_id`: The asset ID associated with the collateral.
+        /// - `collateral`: The collateral amount.
+        /// - `stablecoin_asset_id`: The asset ID associated with the debt.
+        fn get_collateral_value(
             collateral_asset_id: AssetIdOf<T>,
             collateral: Balance,
             stablecoin_asset_id: AssetIdOf<T>,
-        ) -> Result<Balance, DispatchError> {
-            let liquidation_ratio = Self::collateral_infos(StablecoinCollateralIdentifier {
-                collateral_asset_id,
-                stablecoin_asset_id,
-            })
-            .ok_or(Error::<T>::CollateralInfoNotFound)?
-            .risk_parameters
-            .liquidation_ratio;
-
+        ) -> Result<FixedU128, DispatchError> {
             // collateral price in pegged asset
             let peg_asset = Self::stablecoin_infos(stablecoin_asset_id)
                 .ok_or(Error::<T>::StablecoinInfoNotFound)?
@@ -1454,10 +1794,12 @@ pub mod pallet {
                             &DAI.into(),
                             PriceVariant::Sell,
                         )?);
+                    let rate = match T::MaxOracleRateAge::get() {
+                        Some(max_age) => <T>::Oracle::quote_with_max_age(&symbol, max_age)?,
+                        None => <T>::Oracle::quote(&symbol)?,
+                    };
                     let stablecoin_price = FixedU128::from_inner(
-                        <T>::Oracle::quote(&symbol)?
-                            .ok_or(Error::<T>::SymbolNotEnabledByOracle)?
-                            .value,
+                        rate.ok_or(Error::<T>::SymbolNotEnabledByOracle)?.value,
                     );
                     collateral_price_dai
                         .checked_div(&stablecoin_price)
@@ -1474,12 +1816,72 @@ pub mod pallet {
             let collateral_volume = collateral_reference_price
                 .checked_mul(&FixedU128::from_inner(collateral))
                 .ok_or(Error::<T>::ArithmeticError)?;
+            Ok(collateral_volume)
+        }
+
+        /// Returns the max debt in stablecoins that is safe for the collateral amount.
+        ///
+        /// ## Parameters
+        ///
+        /// - `collateral_asset_id`: The asset ID associated with the collateral in the CDP.
+        /// - `collateral`: The current collateral amount in the CDP.
+        /// - `stablecoin_asset_id`: The asset ID associated with the debt in the CDP.
+        fn get_max_safe_debt(
+            collateral_asset_id: AssetIdOf<T>,
+            collateral: Balance,
+            stablecoin_asset_id: AssetIdOf<T>,
+        ) -> Result<Balance, DispatchError> {
+            let liquidation_ratio = Self::collateral_infos(StablecoinCollateralIdentifier {
+                collateral_asset_id,
+                stablecoin_asset_id,
+            })
+            .ok_or(Error::<T>::CollateralInfoNotFound)?
+            .risk_parameters
+            .liquidation_ratio;
+            let collateral_volume =
+                Self::get_collateral_value(collateral_asset_id, collateral, stablecoin_asset_id)?;
             let max_safe_debt = FixedU128::from_perbill(liquidation_ratio)
                 .checked_mul(&collateral_volume)
                 .ok_or(Error::<T>::ArithmeticError)?;
             Ok(max_safe_debt.into_inner())
         }
 
+        /// Returns the max debt that is safe for the basket, it is a sum of collateral values
+        /// multiplied by their liquidation ratios.
+        fn get_basket_max_safe_debt(
+            collaterals: &BasketCollaterals<AssetIdOf<T>>,
+            stablecoin_asset_id: AssetIdOf<T>,
+        ) -> Result<Balance, DispatchError> {
+            collaterals
+                .iter()
+                .try_fold(Balance::zero(), |max_safe_debt, (asset_id, amount)| {
+                    max_safe_debt
+                        .checked_add(Self::get_max_safe_debt(
+                            *asset_id,
+                            *amount,
+                            stablecoin_asset_id,
+                        )?)
+                        .ok_or(Error::<T>::ArithmeticError.into())
+                })
+        }
+
+        /// Returns the total value of basket collaterals in stablecoin pegged asset.
+        fn get_basket_collateral_value(
+            collaterals: &BasketCollaterals<AssetIdOf<T>>,
+            stablecoin_asset_id: AssetIdOf<T>,
+        ) -> Result<Balance, DispatchError> {
+            collaterals
+                .iter()
+                .try_fold(Balance::zero(), |value, (asset_id, amount)| {
+                    value
+                        .checked_add(
+                            Self::get_collateral_value(*asset_id, *amount, stablecoin_asset_id)?
+                                .into_inner(),
+                        )
+                        .ok_or(Error::<T>::ArithmeticError.into())
+                })
+        }
+
         /// Checks whether a Collateralized Debt Position (CDP) is currently considered safe based on its debt and collateral.
         /// The function evaluates the safety of a CDP based on predefined liquidation ratios and collateral values,
         /// providing an indication of its current safety status.
@@ -1502,6 +1904,29 @@ pub mod pallet {
             }
         }
 
+        /// Checks whether a basket CDP is currently considered safe based on its debt and the
+        /// basket value corrected by liquidation ratios of collaterals.
+        fn check_basket_cdp_is_safe(
+            cdp: &BasketDebtPosition<AccountIdOf<T>, AssetIdOf<T>>,
+        ) -> Result<bool, DispatchError> {
+            if cdp.debt == Balance::zero() {
+                Ok(true)
+            } else {
+                let max_safe_debt =
+                    Self::get_basket_max_safe_debt(&cdp.collaterals, cdp.stablecoin_asset_id)?;
+                Ok(cdp.debt <= max_safe_debt)
+            }
+        }
+
+        /// Updates CDP of any type to the current time and checks whether it is safe.
+        fn check_updated_cdp_is_safe(cdp_id: CdpId) -> Result<bool, DispatchError> {
+            if BasketDepository::<T>::contains_key(cdp_id) {
+                Self::check_basket_cdp_is_safe(&Self::get_basket_cdp_updated(cdp_id)?)
+            } else {
+                Self::check_cdp_is_safe(&Self::get_cdp_updated(cdp_id)?)
+            }
+        }
+
         /// Ensures that new emission will not exceed collateral hard cap
         fn ensure_collateral_cap(
             collateral_asset_id: AssetIdOf<T>,
@@ -1525,6 +1950,24 @@ pub mod pallet {
             Ok(())
         }
 
+        /// Ensures that new emission will not exceed basket hard cap
+        fn ensure_basket_cap(
+            stablecoin_asset_id: AssetIdOf<T>,
+            new_emission: Balance,
+        ) -> DispatchResult {
+            let basket_info =
+                Self::basket_infos(stablecoin_asset_id).ok_or(Error::<T>::BasketInfoNotFound)?;
+            ensure!(
+                basket_info
+                    .stablecoin_supply
+                    .checked_add(new_emission)
+                    .ok_or(Error::<T>::ArithmeticError)?
+                    <= basket_info.risk_parameters.hard_cap,
+                Error::<T>::HardCapSupply
+            );
+            Ok(())
+        }
+
         /// Deposits collateral to CDP.
         /// Handles internal deposit of collateral into a Collateralized Debt Position (CDP).
         ///
@@ -1561,14 +2004,67 @@ pub mod pallet {
             Ok(())
         }
 
-        /// Charges borrow taxes.
-        /// Applies borrow tax of 1% on borrow to buy back and burn KEN.
+        /// Handles internal deposit of collateral into a basket CDP.
         ///
         /// ## Parameters
-        /// - `stablecoin_asset_id`
-        /// - `borrow_amount_min` - borrow amount with slippage tolerance
-        /// - `borrow_amount_min` - borrow amount with slippage tolerance
-        /// - `borrow_amount_safe_with_tax` - borrow amount limit
+        ///
+        /// - `who`: The account making the collateral deposit, only the owner can add a new
+        ///   collateral asset to the basket.
+        /// - `cdp_id`: The ID of the basket CDP where the collateral is being deposited.
+        /// - `collateral_asset_id`: The collateral asset, must be listed for the CDP stablecoin.
+        /// - `collateral_amount`: The amount of collateral being deposited.
+        fn deposit_basket_internal(
+            who: &AccountIdOf<T>,
+            cdp_id: CdpId,
+            collateral_asset_id: AssetIdOf<T>,
+            collateral_amount: Balance,
+        ) -> DispatchResult {
+            let cdp = Self::basket_cdp(cdp_id).ok_or(Error::<T>::CDPNotFound)?;
+            if !cdp
+                .collaterals
+                .iter()
+                .any(|(asset_id, _)| *asset_id == collateral_asset_id)
+            {
+                ensure!(*who == cdp.owner, Error::<T>::OperationNotPermitted);
+            }
+            ensure!(
+                CollateralInfos::<T>::contains_key(StablecoinCollateralIdentifier {
+                    collateral_asset_id,
+                    stablecoin_asset_id: cdp.stablecoin_asset_id,
+                }),
+                Error::<T>::CollateralInfoNotFound
+            );
+            technical::Pallet::<T>::transfer_in(
+                &collateral_asset_id,
+                who,
+                &T::DepositoryTechAccount::get(),
+                collateral_amount,
+            )?;
+            Self::update_basket_collateral(
+                cdp_id,
+                collateral_asset_id,
+                cdp.collateral_amount(&collateral_asset_id)
+                    .checked_add(collateral_amount)
+                    .ok_or(Error::<T>::ArithmeticError)?,
+            )?;
+            Self::deposit_event(Event::CollateralDeposit {
+                cdp_id,
+                owner: who.clone(),
+                collateral_asset_id,
+                amount: collateral_amount,
+            });
+
+            Ok(())
+        }
+
+        /// Charges borrow taxes.
+        /// Applies borrow tax of 1% on borrow to buy back and burn KEN.
+        ///
+        /// ## Parameters
+        /// - `stablecoin_asset_id`
+        /// - `borrow_amount_min` - borrow amount with slippage tolerance
+        /// - `borrow_amount_min` - borrow amount with slippage tolerance
+        /// - `borrow_amount_safe_with_tax` - borrow amount limit
         fn charge_borrow_tax(
             stablecoin_asset_id: &AssetIdOf<T>,
             borrow_amount_min: Balance,
@@ -1723,6 +2219,59 @@ pub mod pallet {
             Ok(())
         }
 
+        /// Handles the internal borrowing operation within a basket CDP.
+        /// Borrow amount will be as max as possible in the range
+        /// `[borrow_amount_min, borrow_amount_max]` in order to confrom the slippage tolerance.
+        fn borrow_basket_internal(
+            who: &AccountIdOf<T>,
+            cdp_id: CdpId,
+            borrow_amount_min: Balance,
+            borrow_amount_max: Balance,
+        ) -> DispatchResult {
+            let cdp = Self::get_basket_cdp_updated(cdp_id)?;
+            ensure!(*who == cdp.owner, Error::<T>::OperationNotPermitted);
+            let max_safe_debt =
+                Self::get_basket_max_safe_debt(&cdp.collaterals, cdp.stablecoin_asset_id)?;
+            let borrow_amount_safe_with_tax = max_safe_debt
+                .checked_sub(cdp.debt)
+                .ok_or(Error::<T>::ArithmeticError)?;
+            let (borrow_amount_with_tax, borrow_amount) = Self::charge_borrow_tax(
+                &cdp.stablecoin_asset_id,
+                borrow_amount_min,
+                borrow_amount_max,
+                borrow_amount_safe_with_tax,
+            )?;
+            Self::ensure_basket_cap(cdp.stablecoin_asset_id, borrow_amount_with_tax)?;
+            Self::mint_to(who, &cdp.stablecoin_asset_id, borrow_amount)?;
+            Self::increase_basket_debt(cdp_id, borrow_amount_with_tax)?;
+            Self::deposit_event(Event::DebtIncreased {
+                cdp_id,
+                owner: who.clone(),
+                debt_asset_id: cdp.stablecoin_asset_id,
+                amount: borrow_amount_with_tax,
+            });
+
+            Ok(())
+        }
+
+        /// Repays basket CDP debt.
+        /// Burns stablecoin amount from CDP owner, updates CDP balances.
+        fn repay_basket_debt_internal(cdp_id: CdpId, amount: Balance) -> DispatchResult {
+            let cdp = Self::get_basket_cdp_updated(cdp_id)?;
+            // if repaying amount exceeds debt, leftover is not burned
+            let to_cover_debt = amount.min(cdp.debt);
+            Self::burn_from(&cdp.owner, &cdp.stablecoin_asset_id, to_cover_debt)?;
+            Self::decrease_basket_debt(cdp_id, to_cover_debt)?;
+            Self::deposit_event(Event::DebtPayment {
+                cdp_id,
+                owner: cdp.owner,
+                debt_asset_id: cdp.stablecoin_asset_id,
+                amount: to_cover_debt,
+            });
+
+            Ok(())
+        }
+
         /// Covers bad debt using a specified amount of stablecoin.
         /// The function facilitates the covering of bad debt using stablecoin from a specific account,
         /// handling the transfer and burning of stablecoin as needed to cover the bad debt.
@@ -1752,6 +2301,19 @@ pub mod pallet {
 
         /// Returns true if CDP has debt and uncollected stability fee is more than threshold.
         fn is_accruable(cdp_id: &CdpId) -> Result<bool, DispatchError> {
+            if let Some(cdp) = Self::basket_cdp(cdp_id) {
+                if cdp.debt > 0 {
+                    let (uncollected_stability_fee, _) =
+                        Self::calculate_basket_stability_fee(&cdp)?;
+                    let minimal_accruable_fee = Self::stablecoin_infos(cdp.stablecoin_asset_id)
+                        .ok_or(Error::<T>::StablecoinInfoNotFound)?
+                        .stablecoin_parameters
+                        .minimal_stability_fee_accrue;
+                    return Ok(uncollected_stability_fee >= minimal_accruable_fee);
+                } else {
+                    return Ok(false);
+                }
+            }
             let cdp = Self::cdp(cdp_id).ok_or(Error::<T>::CDPNotFound)?;
             if cdp.debt > 0 {
                 let (uncollected_stability_fee, _) = Self::calculate_stability_fee(*cdp_id)?;
@@ -1782,31 +2344,81 @@ pub mod pallet {
                 stablecoin_asset_id: *stablecoin_asset_id,
             })
             .ok_or(Error::<T>::CollateralInfoNotFound)?;
+            let (interest_coefficient, last_fee_update_time) = Self::compound_interest_coefficient(
+                collateral_info.interest_coefficient,
+                collateral_info.risk_parameters.stability_fee_rate,
+                collateral_info.last_fee_update_time,
+            )?;
+            collateral_info.interest_coefficient = interest_coefficient;
+            collateral_info.last_fee_update_time = last_fee_update_time;
+            Ok(collateral_info)
+        }
+
+        /// Recalculates basket interest coefficient of the stablecoin with the current timestamp.
+        fn calculate_basket_interest_coefficient(
+            stablecoin_asset_id: &AssetIdOf<T>,
+        ) -> Result<BasketInfo<T::Moment>, DispatchError> {
+            let mut basket_info =
+                Self::basket_infos(stablecoin_asset_id).ok_or(Error::<T>::BasketInfoNotFound)?;
+            let (interest_coefficient, last_fee_update_time) = Self::compound_interest_coefficient(
+                basket_info.interest_coefficient,
+                basket_info.risk_parameters.stability_fee_rate,
+                basket_info.last_fee_update_time,
+            )?;
+            basket_info.interest_coefficient = interest_coefficient;
+            basket_info.last_fee_update_time = last_fee_update_time;
+            Ok(basket_info)
+        }
+
+        /// Compounds interest coefficient from the last update time to the current timestamp.
+        ///
+        /// Returns:
+        /// - `interest_coefficient`: FixedU128
+        /// - `last_fee_update_time`: Moment
+        fn compound_interest_coefficient(
+            interest_coefficient: FixedU128,
+            stability_fee_rate: FixedU128,
+            last_fee_update_time: T::Moment,
+        ) -> Result<(FixedU128, T::Moment), DispatchError> {
             let now = Timestamp::<T>::get()
                 .checked_div(&T::Moment::from(1000u32))
                 .ok_or(Error::<T>::ArithmeticError)?;
-            ensure!(
-                now >= collateral_info.last_fee_update_time,
-                Error::<T>::AccrueWrongTime
-            );
+            ensure!(now >= last_fee_update_time, Error::<T>::AccrueWrongTime);
 
             // do not update if time is the same
-            if now > collateral_info.last_fee_update_time {
+            if now > last_fee_update_time {
                 let time_passed = now
-                    .checked_sub(&collateral_info.last_fee_update_time)
+                    .checked_sub(&last_fee_update_time)
                     .ok_or(Error::<T>::ArithmeticError)?;
                 let new_coefficient = compound(
-                    collateral_info.interest_coefficient.into_inner(),
-                    collateral_info.risk_parameters.stability_fee_rate,
+                    interest_coefficient.into_inner(),
+                    stability_fee_rate,
                     time_passed
                         .checked_into::<u64>()
                         .ok_or(Error::<T>::ArithmeticError)?,
                 )
                 .map_err(|_| Error::<T>::ArithmeticError)?;
-                collateral_info.last_fee_update_time = now;
-                collateral_info.interest_coefficient = FixedU128::from_inner(new_coefficient);
+                Ok((FixedU128::from_inner(new_coefficient), now))
+            } else {
+                Ok((interest_coefficient, last_fee_update_time))
             }
-            Ok(collateral_info)
+        }
+
+        /// Calculates stability fee on the debt accrued since CDP interest coefficient.
+        fn accrued_stability_fee(
+            debt: Balance,
+            cdp_interest_coefficient: FixedU128,
+            interest_coefficient: FixedU128,
+        ) -> Result<Balance, DispatchError> {
+            let interest_percent = interest_coefficient
+                .saturating_sub(cdp_interest_coefficient)
+                .checked_div(&cdp_interest_coefficient)
+                .ok_or(Error::<T>::ArithmeticError)?;
+            let stability_fee = FixedU128::from_inner(debt)
+                .checked_mul(&interest_percent)
+                .ok_or(Error::<T>::ArithmeticError)?
+                .into_inner();
+            Ok(stability_fee)
         }
 
         /// Calculates stability fee for the CDP for the current time.
@@ -1826,14 +2438,30 @@ pub mod pallet {
                 &cdp.stablecoin_asset_id,
             )?;
             let interest_coefficient = collateral_info.interest_coefficient;
-            let interest_percent = interest_coefficient
-                .saturating_sub(cdp.interest_coefficient)
-                .checked_div(&cdp.interest_coefficient)
-                .ok_or(Error::<T>::ArithmeticError)?;
-            let stability_fee = FixedU128::from_inner(cdp.debt)
-                .checked_mul(&interest_percent)
-                .ok_or(Error::<T>::ArithmeticError)?
-                .into_inner();
+            let stability_fee = Self::accrued_stability_fee(
+                cdp.debt,
+                cdp.interest_coefficient,
+                interest_coefficient,
+            )?;
+            Ok((stability_fee, interest_coefficient))
+        }
+
+        /// Calculates stability fee for the basket CDP for the current time.
+        ///
+        /// Returns:
+        /// - `stability_fee`: Balance
+        /// - `updated_interest_coefficient`: FixedU128
+        fn calculate_basket_stability_fee(
+            cdp: &BasketDebtPosition<AccountIdOf<T>, AssetIdOf<T>>,
+        ) -> Result<(Balance, FixedU128), DispatchError> {
+            let interest_coefficient =
+                Self::calculate_basket_interest_coefficient(&cdp.stablecoin_asset_id)?
+                    .interest_coefficient;
+            let stability_fee = Self::accrued_stability_fee(
+                cdp.debt,
+                cdp.interest_coefficient,
+                interest_coefficient,
+            )?;
             Ok((stability_fee, interest_coefficient))
         }
 
@@ -1867,7 +2495,43 @@ pub mod pallet {
                 &cdp.stablecoin_asset_id,
                 stability_fee,
             )?;
-            let mut new_bad_debt = StablecoinInfos::<T>::get(cdp.stablecoin_asset_id)
+            Self::collect_stability_fee(&cdp.stablecoin_asset_id, stability_fee)?;
+
+            Ok(cdp)
+        }
+
+        /// Updates basket CDP fields to the current time and saves storage:
+        /// - debt,
+        /// - interest coefficient
+        ///
+        /// ## Returns
+        /// - updated cdp
+        fn get_basket_cdp_updated(
+            cdp_id: CdpId,
+        ) -> Result<BasketDebtPosition<AccountIdOf<T>, AssetIdOf<T>>, DispatchError> {
+            let cdp = Self::basket_cdp(cdp_id).ok_or(Error::<T>::CDPNotFound)?;
+            let (stability_fee, new_coefficient) = Self::calculate_basket_stability_fee(&cdp)?;
+            let cdp = BasketDepository::<T>::try_mutate(cdp_id, |cdp| {
+                let cdp = cdp.as_mut().ok_or(Error::<T>::CDPNotFound)?;
+                cdp.debt = cdp
+                    .debt
+                    .checked_add(stability_fee)
+                    .ok_or(Error::<T>::ArithmeticError)?;
+                cdp.interest_coefficient = new_coefficient;
+                Ok::<BasketDebtPosition<T::AccountId, AssetIdOf<T>>, DispatchError>(cdp.clone())
+            })?;
+            Self::increase_basket_stablecoin_supply(&cdp.stablecoin_asset_id, stability_fee)?;
+            Self::collect_stability_fee(&cdp.stablecoin_asset_id, stability_fee)?;
+
+            Ok(cdp)
+        }
+
+        /// Covers bad debt with stability fee, the rest is minted to treasury as a protocol profit.
+        fn collect_stability_fee(
+            stablecoin_asset_id: &AssetIdOf<T>,
+            mut stability_fee: Balance,
+        ) -> DispatchResult {
+            let mut new_bad_debt = StablecoinInfos::<T>::get(*stablecoin_asset_id)
                 .ok_or(Error::<T>::StablecoinInfoNotFound)?
                 .bad_debt;
             if new_bad_debt > 0 {
@@ -1882,7 +2546,7 @@ pub mod pallet {
                         .ok_or(Error::<T>::ArithmeticError)?;
                     new_bad_debt = balance!(0);
                 };
-                StablecoinInfos::<T>::try_mutate(cdp.stablecoin_asset_id, |stablecoin_info| {
+                StablecoinInfos::<T>::try_mutate(*stablecoin_asset_id, |stablecoin_info| {
                     let stablecoin_info = stablecoin_info
                         .as_mut()
                         .ok_or(Error::<T>::CollateralInfoNotFound)?;
@@ -1890,9 +2554,7 @@ pub mod pallet {
                     DispatchResult::Ok(())
                 })?;
             }
-            Self::mint_treasury(&cdp.stablecoin_asset_id, stability_fee)?;
-
-            Ok(cdp)
+            Self::mint_treasury(stablecoin_asset_id, stability_fee)
         }
 
         /// Mint token to protocol technical account
@@ -1972,21 +2634,89 @@ pub mod pallet {
                 .min(risk_parameters.max_liquidation_lot);
             ensure!(collateral_to_liquidate > 0, Error::<T>::ZeroLiquidationLot);
 
+            let (collateral_liquidated, stablecoin_swapped) = Self::sell_collateral(
+                &cdp.collateral_asset_id,
+                &cdp.stablecoin_asset_id,
+                collateral_to_liquidate,
+                cdp.debt,
+            )?;
+            let treasury_account_id = technical::Pallet::<T>::tech_account_id_to_account_id(
+                &T::TreasuryTechAccount::get(),
+            )?;
+
+            // penalty is a protocol profit which stays on treasury tech account
+            let penalty = Self::liquidation_penalty() * stablecoin_swapped.min(cdp.debt);
+            Self::cover_bad_debt(&cdp.stablecoin_asset_id, penalty)?;
+            let proceeds = stablecoin_swapped - penalty;
+            Self::update_cdp_collateral(
+                cdp_id,
+                cdp.collateral_amount
+                    .checked_sub(collateral_liquidated)
+                    .ok_or(Error::<T>::ArithmeticError)?,
+            )?;
+            if cdp.debt > proceeds {
+                Self::burn_treasury(&cdp.stablecoin_asset_id, proceeds)?;
+                if cdp.collateral_amount <= collateral_liquidated {
+                    // no collateral, total default
+                    // CDP debt is not covered with liquidation, now it is a protocol bad debt
+                    let shortage = cdp
+                        .debt
+                        .checked_sub(proceeds)
+                        .ok_or(Error::<T>::ArithmeticError)?;
+                    Self::cover_with_protocol(&cdp.stablecoin_asset_id, shortage)?;
+                    // close empty CDP, debt == 0, collateral == 0
+                    Self::decrease_cdp_debt(cdp_id, cdp.debt)?;
+                    Self::delete_cdp(cdp_id)?;
+                } else {
+                    // partly covered
+                    Self::decrease_cdp_debt(cdp_id, proceeds)?;
+                }
+            } else {
+                Self::burn_treasury(&cdp.stablecoin_asset_id, cdp.debt)?;
+                // CDP debt is covered
+                Self::decrease_cdp_debt(cdp_id, cdp.debt)?;
+                // There is more stablecoins than to cover debt and penalty, leftover goes to cdp.owner
+                let leftover = proceeds
+                    .checked_sub(cdp.debt)
+                    .ok_or(Error::<T>::ArithmeticError)?;
+                T::AssetManager::transfer_from(
+                    &cdp.stablecoin_asset_id,
+                    &treasury_account_id,
+                    &cdp.owner,
+                    leftover,
+                )?;
+            };
+            LiquidatedThisBlock::<T>::put(true);
+
+            Ok((collateral_liquidated, proceeds, penalty))
+        }
+
+        /// Sells up to `collateral_to_liquidate` of collateral for stablecoin. The amount of
+        /// stablecoin requested is limited by `debt` plus liquidation penalty.
+        ///
+        /// ## Returns
+        /// - collateral_liquidated - collateral sold
+        /// - stablecoin_swapped - stablecoin received on treasury account
+        fn sell_collateral(
+            collateral_asset_id: &AssetIdOf<T>,
+            stablecoin_asset_id: &AssetIdOf<T>,
+            collateral_to_liquidate: Balance,
+            debt: Balance,
+        ) -> Result<(Balance, Balance), DispatchError> {
             // With quote before exchange we are sure that it will not result in infinite amount in for exchange and
             // there is enough liquidity for swap.
             let SwapOutcome { amount, .. } = T::LiquidityProxy::quote(
                 DEXId::Polkaswap.into(),
-                &cdp.collateral_asset_id,
-                &cdp.stablecoin_asset_id,
+                collateral_asset_id,
+                stablecoin_asset_id,
                 QuoteAmount::WithDesiredInput {
                     desired_amount_in: collateral_to_liquidate,
                 },
                 LiquiditySourceFilter::empty(DEXId::Polkaswap.into()),
                 true,
             )?;
-            let desired_amount = cdp
-                .debt
-                .checked_add(Self::liquidation_penalty() * cdp.debt)
+            let desired_amount = debt
+                .checked_add(Self::liquidation_penalty() * debt)
                 .ok_or(Error::<T>::ArithmeticError)?;
             let swap_amount = if amount > desired_amount {
                 SwapAmount::with_desired_output(desired_amount, collateral_to_liquidate)
@@ -2003,11 +2733,11 @@ pub mod pallet {
                 &T::DepositoryTechAccount::get(),
             )?;
             let stablecoin_balance_before = <T as Config>::AssetInfoProvider::free_balance(
-                &cdp.stablecoin_asset_id,
+                stablecoin_asset_id,
                 &treasury_account_id,
             )?;
             let collateral_balance_before = <T as Config>::AssetInfoProvider::free_balance(
-                &cdp.collateral_asset_id,
+                collateral_asset_id,
                 &depository_account_id,
             )?;
 
@@ -2015,18 +2745,18 @@ pub mod pallet {
                 DEXId::Polkaswap.into(),
                 &depository_account_id,
                 &treasury_account_id,
-                &cdp.collateral_asset_id,
-                &cdp.stablecoin_asset_id,
+                collateral_asset_id,
+                stablecoin_asset_id,
                 swap_amount,
                 LiquiditySourceFilter::empty(DEXId::Polkaswap.into()),
             )?;
 
             let stablecoin_balance_after = <T as Config>::AssetInfoProvider::free_balance(
-                &cdp.stablecoin_asset_id,
+                stablecoin_asset_id,
                 &treasury_account_id,
             )?;
             let collateral_balance_after = <T as Config>::AssetInfoProvider::free_balance(
-                &cdp.collateral_asset_id,
+                collateral_asset_id,
                 &depository_account_id,
             )?;
             // This value may differ from `desired_amount`, so this is calculation of actual
@@ -2038,19 +2768,75 @@ pub mod pallet {
                 .checked_sub(collateral_balance_after)
                 .ok_or(Error::<T>::ArithmeticError)?;
 
+            Ok((collateral_liquidated, stablecoin_swapped))
+        }
+
+        /// Liquidates one collateral of a basket CDP. Collaterals are taken in the liquidation
+        /// priority order set by governance, collaterals not listed in priority go after in the
+        /// basket order.
+        ///
+        /// ## Returns
+        /// - collateral_asset_id - collateral sold
+        /// - sold - collateral sold (in swap amount)
+        /// - proceeds - stablecoin got from swap (out amount) minus liquidation penalty
+        /// - penalty - liquidation penalty
+        fn liquidate_basket_internal(
+            cdp_id: CdpId,
+            cdp: &BasketDebtPosition<AccountIdOf<T>, AssetIdOf<T>>,
+        ) -> Result<(AssetIdOf<T>, Balance, Balance, Balance), DispatchError> {
+            let priority = BasketLiquidationPriority::<T>::get(cdp.stablecoin_asset_id);
+            let (collateral_asset_id, collateral_amount) = priority
+                .iter()
+                .filter_map(|asset_id| {
+                    let amount = cdp.collateral_amount(asset_id);
+                    (amount > 0).then_some((*asset_id, amount))
+                })
+                .chain(
+                    cdp.collaterals
+                        .iter()
+                        .filter(|(_, amount)| *amount > 0)
+                        .cloned(),
+                )
+                .next()
+                .ok_or(Error::<T>::ZeroLiquidationLot)?;
+            let risk_parameters = Self::collateral_infos(StablecoinCollateralIdentifier {
+                collateral_asset_id,
+                stablecoin_asset_id: cdp.stablecoin_asset_id,
+            })
+            .ok_or(Error::<T>::CollateralInfoNotFound)?
+            .risk_parameters;
+            let collateral_to_liquidate =
+                collateral_amount.min(risk_parameters.max_liquidation_lot);
+            ensure!(collateral_to_liquidate > 0, Error::<T>::ZeroLiquidationLot);
+
+            let (collateral_liquidated, stablecoin_swapped) = Self::sell_collateral(
+                &collateral_asset_id,
+                &cdp.stablecoin_asset_id,
+                collateral_to_liquidate,
+                cdp.debt,
+            )?;
+            let treasury_account_id = technical::Pallet::<T>::tech_account_id_to_account_id(
+                &T::TreasuryTechAccount::get(),
+            )?;
+
             // penalty is a protocol profit which stays on treasury tech account
             let penalty = Self::liquidation_penalty() * stablecoin_swapped.min(cdp.debt);
             Self::cover_bad_debt(&cdp.stablecoin_asset_id, penalty)?;
             let proceeds = stablecoin_swapped - penalty;
-            Self::update_cdp_collateral(
+            Self::update_basket_collateral(
                 cdp_id,
-                cdp.collateral_amount
+                collateral_asset_id,
+                collateral_amount
                     .checked_sub(collateral_liquidated)
                     .ok_or(Error::<T>::ArithmeticError)?,
             )?;
             if cdp.debt > proceeds {
                 Self::burn_treasury(&cdp.stablecoin_asset_id, proceeds)?;
-                if cdp.collateral_amount <= collateral_liquidated {
+                let basket_is_empty = Self::basket_cdp(cdp_id)
+                    .ok_or(Error::<T>::CDPNotFound)?
+                    .collaterals
+                    .is_empty();
+                if basket_is_empty {
                     // no collateral, total default
                     // CDP debt is not covered with liquidation, now it is a protocol bad debt
                     let shortage = cdp
@@ -2059,16 +2845,16 @@ pub mod pallet {
                         .ok_or(Error::<T>::ArithmeticError)?;
                     Self::cover_with_protocol(&cdp.stablecoin_asset_id, shortage)?;
                     // close empty CDP, debt == 0, collateral == 0
-                    Self::decrease_cdp_debt(cdp_id, cdp.debt)?;
-                    Self::delete_cdp(cdp_id)?;
+                    Self::decrease_basket_debt(cdp_id, cdp.debt)?;
+                    Self::delete_basket_cdp(cdp_id)?;
                 } else {
                     // partly covered
-                    Self::decrease_cdp_debt(cdp_id, proceeds)?;
+                    Self::decrease_basket_debt(cdp_id, proceeds)?;
                 }
             } else {
                 Self::burn_treasury(&cdp.stablecoin_asset_id, cdp.debt)?;
                 // CDP debt is covered
-                Self::decrease_cdp_debt(cdp_id, cdp.debt)?;
+                Self::decrease_basket_debt(cdp_id, cdp.debt)?;
                 // There is more stablecoins than to cover debt and penalty, leftover goes to cdp.owner
                 let leftover = proceeds
                     .checked_sub(cdp.debt)
@@ -2082,7 +2868,12 @@ pub mod pallet {
             };
             LiquidatedThisBlock::<T>::put(true);
 
-            Ok((collateral_liquidated, proceeds, penalty))
+            Ok((
+                collateral_asset_id,
+                collateral_liquidated,
+                proceeds,
+                penalty,
+            ))
         }
 
         /// Buys back token with stablecoin and burns. Then `remint_percent` of burned is reminted
@@ -2283,12 +3074,7 @@ pub mod pallet {
                     Ok::<(), Error<T>>(())
                 },
             )?;
-            if let Some(mut cdp_ids) = CdpOwnerIndex::<T>::take(&cdp.owner) {
-                cdp_ids.retain(|&x| x != cdp_id);
-                if !cdp_ids.is_empty() {
-                    CdpOwnerIndex::<T>::insert(&cdp.owner, cdp_ids);
-                }
-            }
+            Self::remove_from_owner_index(&cdp.owner, cdp_id);
             Self::deposit_event(Event::CDPClosed {
                 cdp_id,
                 owner: cdp.owner,
@@ -2298,6 +3084,181 @@ pub mod pallet {
             Ok(())
         }
 
+        /// Inserts a new basket CDP
+        /// Updates basket CDP storage and updates index owner -> CDP
+        fn insert_basket_cdp(
+            cdp: BasketDebtPosition<AccountIdOf<T>, AssetIdOf<T>>,
+        ) -> Result<CdpId, DispatchError> {
+            let cdp_id = Self::increment_cdp_id()?;
+            CdpOwnerIndex::<T>::try_append(&cdp.owner, cdp_id)
+                .map_err(|_| Error::<T>::CDPLimitPerUser)?;
+            BasketDepository::<T>::insert(cdp_id, cdp);
+            Ok(cdp_id)
+        }
+
+        /// Updates basket CDP balance of the collateral, empty entries are removed from basket.
+        fn update_basket_collateral(
+            cdp_id: CdpId,
+            collateral_asset_id: AssetIdOf<T>,
+            collateral_amount: Balance,
+        ) -> DispatchResult {
+            BasketDepository::<T>::try_mutate(cdp_id, |cdp| {
+                let cdp = cdp.as_mut().ok_or(Error::<T>::CDPNotFound)?;
+                let old_collateral = cdp.collateral_amount(&collateral_asset_id);
+                CollateralInfos::<T>::try_mutate(
+                    StablecoinCollateralIdentifier {
+                        collateral_asset_id,
+                        stablecoin_asset_id: cdp.stablecoin_asset_id,
+                    },
+                    |collateral_info| {
+                        let collateral_info = collateral_info
+                            .as_mut()
+                            .ok_or(Error::<T>::CollateralInfoNotFound)?;
+                        collateral_info.total_collateral = collateral_info
+                            .total_collateral
+                            .checked_sub(old_collateral)
+                            .ok_or(Error::<T>::ArithmeticError)?
+                            .checked_add(collateral_amount)
+                            .ok_or(Error::<T>::ArithmeticError)?;
+                        Ok::<(), Error<T>>(())
+                    },
+                )?;
+                match cdp
+                    .collaterals
+                    .iter_mut()
+                    .find(|(asset_id, _)| *asset_id == collateral_asset_id)
+                {
+                    Some((_, amount)) => *amount = collateral_amount,
+                    None => cdp
+                        .collaterals
+                        .try_push((collateral_asset_id, collateral_amount))
+                        .map_err(|_| Error::<T>::BasketCollateralsLimit)?,
+                }
+                cdp.collaterals.retain(|(_, amount)| *amount > 0);
+                Ok(())
+            })
+        }
+
+        /// Updates basket CDP debt by increasing the value.
+        fn increase_basket_debt(cdp_id: CdpId, debt_change: Balance) -> DispatchResult {
+            BasketDepository::<T>::try_mutate(cdp_id, |cdp| {
+                let cdp = cdp.as_mut().ok_or(Error::<T>::CDPNotFound)?;
+                cdp.debt = cdp
+                    .debt
+                    .checked_add(debt_change)
+                    .ok_or(Error::<T>::ArithmeticError)?;
+                Self::increase_basket_stablecoin_supply(&cdp.stablecoin_asset_id, debt_change)
+            })
+        }
+
+        /// Updates basket CDP debt by decreasing the value.
+        fn decrease_basket_debt(cdp_id: CdpId, debt_change: Balance) -> DispatchResult {
+            BasketDepository::<T>::try_mutate(cdp_id, |cdp| {
+                let cdp = cdp.as_mut().ok_or(Error::<T>::CDPNotFound)?;
+                cdp.debt = cdp
+                    .debt
+                    .checked_sub(debt_change)
+                    .ok_or(Error::<T>::ArithmeticError)?;
+                BasketInfos::<T>::try_mutate(cdp.stablecoin_asset_id, |basket_info| {
+                    let basket_info = basket_info.as_mut().ok_or(Error::<T>::BasketInfoNotFound)?;
+                    basket_info.stablecoin_supply = basket_info
+                        .stablecoin_supply
+                        .checked_sub(debt_change)
+                        .ok_or(Error::<T>::ArithmeticError)?;
+                    Ok(())
+                })
+            })
+        }
+
+        /// Increases supply of stablecoin issued against baskets.
+        fn increase_basket_stablecoin_supply(
+            stablecoin_asset_id: &AssetIdOf<T>,
+            amount: Balance,
+        ) -> DispatchResult {
+            BasketInfos::<T>::try_mutate(stablecoin_asset_id, |basket_info| {
+                let basket_info = basket_info.as_mut().ok_or(Error::<T>::BasketInfoNotFound)?;
+                basket_info.stablecoin_supply = basket_info
+                    .stablecoin_supply
+                    .checked_add(amount)
+                    .ok_or(Error::<T>::ArithmeticError)?;
+                Ok(())
+            })
+        }
+
+        /// Removes basket CDP entry from the storage and sends collaterals to the owner.
+        fn delete_basket_cdp(cdp_id: CdpId) -> DispatchResult {
+            let cdp = BasketDepository::<T>::take(cdp_id).ok_or(Error::<T>::CDPNotFound)?;
+            for (collateral_asset_id, transfer_out) in cdp.collaterals.iter() {
+                technical::Pallet::<T>::transfer_out(
+                    collateral_asset_id,
+                    &T::DepositoryTechAccount::get(),
+                    &cdp.owner,
+                    *transfer_out,
+                )?;
+                CollateralInfos::<T>::try_mutate(
+                    StablecoinCollateralIdentifier {
+                        collateral_asset_id: *collateral_asset_id,
+                        stablecoin_asset_id: cdp.stablecoin_asset_id,
+                    },
+                    |collateral_info| {
+                        let collateral_info = collateral_info
+                            .as_mut()
+                            .ok_or(Error::<T>::CollateralInfoNotFound)?;
+                        collateral_info.total_collateral = collateral_info
+                            .total_collateral
+                            .checked_sub(*transfer_out)
+                            .ok_or(Error::<T>::ArithmeticError)?;
+                        Ok::<(), Error<T>>(())
+                    },
+                )?;
+            }
+            Self::remove_from_owner_index(&cdp.owner, cdp_id);
+            Self::deposit_event(Event::BasketCDPClosed {
+                cdp_id,
+                owner: cdp.owner,
+                collaterals: cdp.collaterals,
+            });
+            Ok(())
+        }
+
+        /// Removes CDP id from the owner index.
+        fn remove_from_owner_index(owner: &AccountIdOf<T>, cdp_id: CdpId) {
+            if let Some(mut cdp_ids) = CdpOwnerIndex::<T>::take(owner) {
+                cdp_ids.retain(|&x| x != cdp_id);
+                if !cdp_ids.is_empty() {
+                    CdpOwnerIndex::<T>::insert(owner, cdp_ids);
+                }
+            }
+        }
+
+        /// Inserts or updates basket risk parameters for stablecoin.
+        fn upsert_basket_info(
+            stablecoin_asset_id: &AssetIdOf<T>,
+            new_risk_parameters: BasketRiskParameters,
+        ) -> DispatchResult {
+            ensure!(
+                StablecoinInfos::<T>::contains_key(stablecoin_asset_id),
+                Error::<T>::StablecoinInfoNotFound
+            );
+            let basket_info = if BasketInfos::<T>::contains_key(stablecoin_asset_id) {
+                let mut basket_info =
+                    Self::calculate_basket_interest_coefficient(stablecoin_asset_id)?;
+                basket_info.risk_parameters = new_risk_parameters;
+                basket_info
+            } else {
+                BasketInfo {
+                    risk_parameters: new_risk_parameters,
+                    stablecoin_supply: balance!(0),
+                    last_fee_update_time: Timestamp::<T>::get()
+                        .checked_div(&T::Moment::from(1000u32))
+                        .ok_or(Error::<T>::ArithmeticError)?,
+                    interest_coefficient: FixedU128::one(),
+                }
+            };
+            BasketInfos::<T>::insert(stablecoin_asset_id, basket_info);
+            Ok(())
+        }
+
         /// Inserts or updates `CollateralRiskParameters` for collateral asset id.
         /// If `CollateralRiskParameters` exists for asset id, then updates them.
         /// Else if `CollateralRiskParameters` does not exist, inserts a new value.
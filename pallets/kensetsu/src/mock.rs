@@ -205,6 +205,13 @@ impl DataFeed<SymbolName, Rate, u64> for MockOracle {
         }
     }
 
+    fn quote_with_max_age(
+        symbol: &SymbolName,
+        _max_age: u64,
+    ) -> Result<Option<Rate>, DispatchError> {
+        Self::quote(symbol)
+    }
+
     fn list_enabled_symbols() -> Result<Vec<(SymbolName, u64)>, DispatchError> {
         Ok(vec![(SymbolName::xau(), 0)])
     }
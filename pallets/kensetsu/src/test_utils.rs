@@ -31,7 +31,7 @@
 use super::*;
 use crate::mock::{RuntimeOrigin, TestRuntime};
 
-use common::{AccountIdOf, AssetInfoProvider, Balance, DAI, KUSD, KXOR, XOR};
+use common::{AccountIdOf, AssetInfoProvider, Balance, DAI, KUSD, KXOR, TBCD, XOR};
 use frame_support::assert_ok;
 use frame_system::pallet_prelude::OriginFor;
 use hex_literal::hex;
@@ -232,6 +232,57 @@ pub fn create_cdp_for_xor(
     NextCDPId::<TestRuntime>::get()
 }
 
+/// Configures XOR and TBCD as collaterals of Kensetsu dollar basket CDPs with the same
+/// liquidation ratio.
+pub fn configure_kensetsu_dollar_basket(liquidation_ratio: Perbill) {
+    configure_kensetsu_dollar_for_xor(
+        Balance::MAX,
+        liquidation_ratio,
+        FixedU128::zero(),
+        balance!(0),
+    );
+    assert_ok!(KensetsuPallet::update_collateral_risk_parameters(
+        RuntimeOrigin::root(),
+        TBCD,
+        KUSD,
+        CollateralRiskParameters {
+            hard_cap: Balance::MAX,
+            max_liquidation_lot: balance!(1000),
+            liquidation_ratio,
+            stability_fee_rate: FixedU128::zero(),
+            minimal_collateral_deposit: balance!(0),
+        }
+    ));
+    assert_ok!(KensetsuPallet::update_basket_risk_parameters(
+        RuntimeOrigin::root(),
+        KUSD,
+        BasketRiskParameters {
+            hard_cap: Balance::MAX,
+            stability_fee_rate: FixedU128::zero(),
+            minimal_collateral_value: balance!(0),
+        }
+    ));
+}
+
+/// Creates basket CDP with XOR and TBCD as collaterals
+pub fn create_basket_cdp_for_xor_and_tbcd(
+    owner: OriginFor<TestRuntime>,
+    xor_collateral: Balance,
+    tbcd_collateral: Balance,
+    debt: Balance,
+) -> CdpId {
+    add_balance(alice_account_id(), xor_collateral, XOR);
+    add_balance(alice_account_id(), tbcd_collateral, TBCD);
+    assert_ok!(KensetsuPallet::create_basket_cdp(
+        owner,
+        BoundedVec::truncate_from(vec![(XOR, xor_collateral), (TBCD, tbcd_collateral)]),
+        KUSD,
+        debt,
+        debt,
+    ));
+    NextCDPId::<TestRuntime>::get()
+}
+
 /// Deposits to CDP
 pub fn deposit_xor_to_cdp(
     owner: OriginFor<TestRuntime>,
@@ -33,10 +33,11 @@ use super::*;
 use crate::mock::{new_test_ext, MockLiquidityProxy, RuntimeOrigin, TestRuntime};
 use crate::test_utils::{
     add_balance, alice, alice_account_id, assert_bad_debt, assert_balance, bob, bob_account_id,
-    configure_kensetsu_dollar_for_xor, configure_kxor_for_xor, create_cdp_for_xor,
-    deposit_xor_to_cdp, depository_tech_account_id, get_account_cdp_ids, get_total_supply,
-    make_cdps_unsafe, set_bad_debt, set_borrow_tax, set_kensetsu_dollar_stablecoin,
-    set_kensetsu_gold_stablecoin, treasury_tech_account_id,
+    configure_kensetsu_dollar_basket, configure_kensetsu_dollar_for_xor, configure_kxor_for_xor,
+    create_basket_cdp_for_xor_and_tbcd, create_cdp_for_xor, deposit_xor_to_cdp,
+    depository_tech_account_id, get_account_cdp_ids, get_total_supply, make_cdps_unsafe,
+    set_bad_debt, set_borrow_tax, set_kensetsu_dollar_stablecoin, set_kensetsu_gold_stablecoin,
+    treasury_tech_account_id,
 };
 
 use common::{
@@ -3320,6 +3321,191 @@ fn test_update_minimal_stability_fee_accrue_sunny_day() {
     });
 }
 
+/// Basket CDP can not be created for stablecoin without basket parameters.
+#[test]
+fn test_create_basket_cdp_basket_info_not_found() {
+    new_test_ext().execute_with(|| {
+        configure_kensetsu_dollar_for_xor(
+            Balance::MAX,
+            Perbill::from_percent(50),
+            FixedU128::zero(),
+            balance!(0),
+        );
+
+        assert_noop!(
+            KensetsuPallet::create_basket_cdp(
+                alice(),
+                BoundedVec::truncate_from(vec![(XOR, balance!(100))]),
+                KUSD,
+                balance!(0),
+                balance!(0),
+            ),
+            KensetsuError::BasketInfoNotFound
+        );
+    });
+}
+
+/// Basket with the same collateral listed twice is rejected.
+#[test]
+fn test_create_basket_cdp_duplicated_collateral() {
+    new_test_ext().execute_with(|| {
+        configure_kensetsu_dollar_basket(Perbill::from_percent(50));
+        add_balance(alice_account_id(), balance!(200), XOR);
+
+        assert_noop!(
+            KensetsuPallet::create_basket_cdp(
+                alice(),
+                BoundedVec::truncate_from(vec![(XOR, balance!(100)), (XOR, balance!(100))]),
+                KUSD,
+                balance!(0),
+                balance!(0),
+            ),
+            KensetsuError::WrongBasketCollaterals
+        );
+    });
+}
+
+/// Max safe debt of basket CDP is a sum of collateral values multiplied by liquidation ratios.
+#[test]
+fn test_create_basket_cdp_borrow_against_basket() {
+    new_test_ext().execute_with(|| {
+        configure_kensetsu_dollar_basket(Perbill::from_percent(50));
+        let cdp_id = create_basket_cdp_for_xor_and_tbcd(
+            alice(),
+            balance!(100),
+            balance!(100),
+            balance!(100),
+        );
+
+        let cdp = KensetsuPallet::basket_cdp(cdp_id).expect("Must exist");
+        assert_eq!(cdp.debt, balance!(100));
+        assert_eq!(cdp.collateral_amount(&XOR), balance!(100));
+        assert_eq!(cdp.collateral_amount(&TBCD), balance!(100));
+        assert_eq!(
+            KensetsuPallet::basket_infos(KUSD)
+                .expect("Must exist")
+                .stablecoin_supply,
+            balance!(100)
+        );
+        assert_balance(&alice_account_id(), &KUSD, balance!(100));
+        assert_balance(&depository_tech_account_id(), &XOR, balance!(100));
+        assert_balance(&depository_tech_account_id(), &TBCD, balance!(100));
+        assert_noop!(
+            KensetsuPallet::borrow(alice(), cdp_id, balance!(1), balance!(1)),
+            KensetsuError::CDPUnsafe
+        );
+    });
+}
+
+/// Closing basket CDP returns every collateral to the owner.
+#[test]
+fn test_close_basket_cdp_sunny_day() {
+    new_test_ext().execute_with(|| {
+        configure_kensetsu_dollar_basket(Perbill::from_percent(50));
+        let cdp_id =
+            create_basket_cdp_for_xor_and_tbcd(alice(), balance!(100), balance!(50), balance!(0));
+
+        assert_ok!(KensetsuPallet::close_cdp(alice(), cdp_id));
+
+        System::assert_has_event(
+            Event::BasketCDPClosed {
+                cdp_id,
+                owner: alice_account_id(),
+                collaterals: BoundedVec::truncate_from(vec![
+                    (XOR, balance!(100)),
+                    (TBCD, balance!(50)),
+                ]),
+            }
+            .into(),
+        );
+        assert!(KensetsuPallet::basket_cdp(cdp_id).is_none());
+        assert_balance(&alice_account_id(), &XOR, balance!(100));
+        assert_balance(&alice_account_id(), &TBCD, balance!(50));
+        assert_balance(&depository_tech_account_id(), &XOR, balance!(0));
+        assert_balance(&depository_tech_account_id(), &TBCD, balance!(0));
+    });
+}
+
+/// Only the owner can add a new collateral asset to a basket, anyone can top up existing ones.
+#[test]
+fn test_deposit_basket_collateral_new_asset_only_by_owner() {
+    new_test_ext().execute_with(|| {
+        configure_kensetsu_dollar_basket(Perbill::from_percent(50));
+        add_balance(alice_account_id(), balance!(100), XOR);
+        assert_ok!(KensetsuPallet::create_basket_cdp(
+            alice(),
+            BoundedVec::truncate_from(vec![(XOR, balance!(100))]),
+            KUSD,
+            balance!(0),
+            balance!(0),
+        ));
+        let cdp_id = NextCDPId::<TestRuntime>::get();
+        add_balance(bob_account_id(), balance!(10), XOR);
+        add_balance(bob_account_id(), balance!(10), TBCD);
+
+        assert_noop!(
+            KensetsuPallet::deposit_basket_collateral(bob(), cdp_id, TBCD, balance!(10)),
+            KensetsuError::OperationNotPermitted
+        );
+        assert_ok!(KensetsuPallet::deposit_basket_collateral(
+            bob(),
+            cdp_id,
+            XOR,
+            balance!(10)
+        ));
+        add_balance(alice_account_id(), balance!(50), TBCD);
+        assert_ok!(KensetsuPallet::deposit_basket_collateral(
+            alice(),
+            cdp_id,
+            TBCD,
+            balance!(50)
+        ));
+
+        let cdp = KensetsuPallet::basket_cdp(cdp_id).expect("Must exist");
+        assert_eq!(cdp.collateral_amount(&XOR), balance!(110));
+        assert_eq!(cdp.collateral_amount(&TBCD), balance!(50));
+    });
+}
+
+/// Unsafe basket CDP is liquidated in governance defined priority order.
+#[test]
+fn test_liquidate_basket_cdp_priority_order() {
+    new_test_ext().execute_with(|| {
+        configure_kensetsu_dollar_basket(Perbill::from_percent(50));
+        assert_ok!(KensetsuPallet::update_basket_liquidation_priority(
+            RuntimeOrigin::root(),
+            KUSD,
+            BoundedVec::truncate_from(vec![TBCD, XOR]),
+        ));
+        let cdp_id = create_basket_cdp_for_xor_and_tbcd(
+            alice(),
+            balance!(100),
+            balance!(100),
+            balance!(100),
+        );
+        make_cdps_unsafe();
+        MockLiquidityProxy::set_amounts_for_the_next_exchange(KUSD, balance!(50));
+
+        assert_ok!(KensetsuPallet::liquidate(alice(), cdp_id));
+
+        System::assert_has_event(
+            Event::Liquidated {
+                cdp_id,
+                collateral_asset_id: TBCD,
+                collateral_amount: balance!(50),
+                debt_asset_id: KUSD,
+                proceeds: balance!(50),
+                penalty: balance!(0),
+            }
+            .into(),
+        );
+        let cdp = KensetsuPallet::basket_cdp(cdp_id).expect("Must exist");
+        assert_eq!(cdp.debt, balance!(50));
+        assert_eq!(cdp.collateral_amount(&XOR), balance!(100));
+        assert_eq!(cdp.collateral_amount(&TBCD), balance!(50));
+    });
+}
+
 #[test]
 fn denominate_zero_factor_leaves_collateral_infos_unchanged() {
     new_test_ext().execute_with(|| {
@@ -79,6 +79,10 @@ pub trait WeightInfo {
 	fn update_stability_fee_rate() -> Weight;
 	fn update_minimal_collateral_deposit() -> Weight;
 	fn update_minimal_stability_fee_accrue() -> Weight;
+	fn create_basket_cdp(n: u32, ) -> Weight;
+	fn liquidate_basket(n: u32, ) -> Weight;
+	fn update_basket_risk_parameters() -> Weight;
+	fn update_basket_liquidation_priority(n: u32, ) -> Weight;
 }
 
 /// Weights for kensetsu using the Substrate node and recommended hardware.
@@ -499,6 +503,150 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: Kensetsu BasketInfos (r:1 w:1)
+	/// Proof: Kensetsu BasketInfos (max_values: None, max_size: Some(120), added: 2595, mode: MaxEncodedLen)
+	/// Storage: Kensetsu CollateralInfos (r:8 w:8)
+	/// Proof: Kensetsu CollateralInfos (max_values: None, max_size: Some(188), added: 2663, mode: MaxEncodedLen)
+	/// Storage: Kensetsu StablecoinInfos (r:1 w:0)
+	/// Proof Skipped: Kensetsu StablecoinInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PriceTools FastPriceInfos (r:9 w:0)
+	/// Proof Skipped: PriceTools FastPriceInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Kensetsu NextCDPId (r:1 w:1)
+	/// Proof: Kensetsu NextCDPId (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Kensetsu CdpOwnerIndex (r:1 w:1)
+	/// Proof: Kensetsu CdpOwnerIndex (max_values: None, max_size: Some(160034), added: 162509, mode: MaxEncodedLen)
+	/// Storage: Kensetsu BasketDepository (r:1 w:1)
+	/// Proof: Kensetsu BasketDepository (max_values: None, max_size: Some(497), added: 2972, mode: MaxEncodedLen)
+	/// Storage: Technical TechAccounts (r:2 w:0)
+	/// Proof Skipped: Technical TechAccounts (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ExtendedAssets SoulboundAsset (r:9 w:0)
+	/// Proof: ExtendedAssets SoulboundAsset (max_values: None, max_size: Some(322091), added: 324566, mode: MaxEncodedLen)
+	/// Storage: Assets AssetInfosV2 (r:9 w:0)
+	/// Proof Skipped: Assets AssetInfosV2 (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:15 w:15)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Permissions Permissions (r:2 w:0)
+	/// Proof Skipped: Permissions Permissions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Kensetsu BorrowTax (r:1 w:0)
+	/// Proof: Kensetsu BorrowTax (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Tokens TotalIssuance (r:1 w:1)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 8]`.
+	fn create_basket_cdp(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6384 + n * (214 ±0)`
+		//  Estimated: `879217 + n * (5274 ±0)`
+		// Minimum execution time: 447_912_000 picoseconds.
+		Weight::from_parts(352_604_221, 879217)
+			// Standard Error: 97_528
+			.saturating_add(Weight::from_parts(103_117_482, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(16_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5274).saturating_mul(n.into()))
+	}
+	/// Storage: Kensetsu LiquidatedThisBlock (r:1 w:1)
+	/// Proof: Kensetsu LiquidatedThisBlock (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Kensetsu BasketDepository (r:1 w:1)
+	/// Proof: Kensetsu BasketDepository (max_values: None, max_size: Some(497), added: 2972, mode: MaxEncodedLen)
+	/// Storage: Kensetsu BasketInfos (r:1 w:1)
+	/// Proof: Kensetsu BasketInfos (max_values: None, max_size: Some(120), added: 2595, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Kensetsu StablecoinInfos (r:1 w:1)
+	/// Proof Skipped: Kensetsu StablecoinInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Kensetsu CollateralInfos (r:8 w:1)
+	/// Proof: Kensetsu CollateralInfos (max_values: None, max_size: Some(188), added: 2663, mode: MaxEncodedLen)
+	/// Storage: PriceTools FastPriceInfos (r:9 w:1)
+	/// Proof Skipped: PriceTools FastPriceInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Kensetsu BasketLiquidationPriority (r:1 w:0)
+	/// Proof: Kensetsu BasketLiquidationPriority (max_values: None, max_size: Some(289), added: 2764, mode: MaxEncodedLen)
+	/// Storage: Technical TechAccounts (r:3 w:0)
+	/// Proof Skipped: Technical TechAccounts (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets AssetInfosV2 (r:2 w:0)
+	/// Proof Skipped: Assets AssetInfosV2 (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Permissions Permissions (r:2 w:0)
+	/// Proof Skipped: Permissions Permissions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ExtendedAssets SoulboundAsset (r:2 w:0)
+	/// Proof: ExtendedAssets SoulboundAsset (max_values: None, max_size: Some(322091), added: 324566, mode: MaxEncodedLen)
+	/// Storage: DEXManager DEXInfos (r:1 w:0)
+	/// Proof Skipped: DEXManager DEXInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool EnabledSynthetics (r:2 w:0)
+	/// Proof Skipped: XSTPool EnabledSynthetics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DEXAPI EnabledSourceTypes (r:1 w:0)
+	/// Proof Skipped: DEXAPI EnabledSourceTypes (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: PoolXYK Properties (r:2 w:0)
+	/// Proof Skipped: PoolXYK Properties (max_values: None, max_size: None, mode: Measured)
+	/// Storage: MulticollateralBondingCurvePool EnabledTargets (r:1 w:0)
+	/// Proof Skipped: MulticollateralBondingCurvePool EnabledTargets (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: OrderBook OrderBooks (r:1 w:0)
+	/// Proof: OrderBook OrderBooks (max_values: None, max_size: Some(238), added: 2713, mode: MaxEncodedLen)
+	/// Storage: TradingPair LockedLiquiditySources (r:1 w:0)
+	/// Proof Skipped: TradingPair LockedLiquiditySources (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: System Account (r:4 w:4)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:3 w:3)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: Kensetsu LiquidationPenalty (r:1 w:0)
+	/// Proof: Kensetsu LiquidationPenalty (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Assets AssetOwners (r:1 w:0)
+	/// Proof Skipped: Assets AssetOwners (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PriceTools PriceInfos (r:1 w:1)
+	/// Proof Skipped: PriceTools PriceInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens TotalIssuance (r:1 w:1)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: PoolXYK Reserves (r:0 w:2)
+	/// Proof Skipped: PoolXYK Reserves (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[1, 8]`.
+	fn liquidate_basket(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `11247 + n * (183 ±0)`
+		//  Estimated: `867813 + n * (5165 ±0)`
+		// Minimum execution time: 918_406_000 picoseconds.
+		Weight::from_parts(893_571_048, 867813)
+			// Standard Error: 61_904
+			.saturating_add(Weight::from_parts(38_412_760, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(33_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(17_u64))
+			.saturating_add(Weight::from_parts(0, 5165).saturating_mul(n.into()))
+	}
+	/// Storage: Kensetsu StablecoinInfos (r:1 w:0)
+	/// Proof Skipped: Kensetsu StablecoinInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Kensetsu BasketInfos (r:1 w:1)
+	/// Proof: Kensetsu BasketInfos (max_values: None, max_size: Some(120), added: 2595, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn update_basket_risk_parameters() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `812`
+		//  Estimated: `4277`
+		// Minimum execution time: 41_738_000 picoseconds.
+		Weight::from_parts(42_915_000, 4277)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Kensetsu BasketInfos (r:1 w:0)
+	/// Proof: Kensetsu BasketInfos (max_values: None, max_size: Some(120), added: 2595, mode: MaxEncodedLen)
+	/// Storage: Kensetsu BasketLiquidationPriority (r:0 w:1)
+	/// Proof: Kensetsu BasketLiquidationPriority (max_values: None, max_size: Some(289), added: 2764, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 8]`.
+	fn update_basket_liquidation_priority(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `384`
+		//  Estimated: `3585`
+		// Minimum execution time: 27_461_000 picoseconds.
+		Weight::from_parts(27_936_218, 3585)
+			// Standard Error: 3_217
+			.saturating_add(Weight::from_parts(318_504, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -918,4 +1066,148 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: Kensetsu BasketInfos (r:1 w:1)
+	/// Proof: Kensetsu BasketInfos (max_values: None, max_size: Some(120), added: 2595, mode: MaxEncodedLen)
+	/// Storage: Kensetsu CollateralInfos (r:8 w:8)
+	/// Proof: Kensetsu CollateralInfos (max_values: None, max_size: Some(188), added: 2663, mode: MaxEncodedLen)
+	/// Storage: Kensetsu StablecoinInfos (r:1 w:0)
+	/// Proof Skipped: Kensetsu StablecoinInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PriceTools FastPriceInfos (r:9 w:0)
+	/// Proof Skipped: PriceTools FastPriceInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Kensetsu NextCDPId (r:1 w:1)
+	/// Proof: Kensetsu NextCDPId (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Kensetsu CdpOwnerIndex (r:1 w:1)
+	/// Proof: Kensetsu CdpOwnerIndex (max_values: None, max_size: Some(160034), added: 162509, mode: MaxEncodedLen)
+	/// Storage: Kensetsu BasketDepository (r:1 w:1)
+	/// Proof: Kensetsu BasketDepository (max_values: None, max_size: Some(497), added: 2972, mode: MaxEncodedLen)
+	/// Storage: Technical TechAccounts (r:2 w:0)
+	/// Proof Skipped: Technical TechAccounts (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ExtendedAssets SoulboundAsset (r:9 w:0)
+	/// Proof: ExtendedAssets SoulboundAsset (max_values: None, max_size: Some(322091), added: 324566, mode: MaxEncodedLen)
+	/// Storage: Assets AssetInfosV2 (r:9 w:0)
+	/// Proof Skipped: Assets AssetInfosV2 (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:15 w:15)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Permissions Permissions (r:2 w:0)
+	/// Proof Skipped: Permissions Permissions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Kensetsu BorrowTax (r:1 w:0)
+	/// Proof: Kensetsu BorrowTax (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Tokens TotalIssuance (r:1 w:1)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 8]`.
+	fn create_basket_cdp(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6384 + n * (214 ±0)`
+		//  Estimated: `879217 + n * (5274 ±0)`
+		// Minimum execution time: 447_912_000 picoseconds.
+		Weight::from_parts(352_604_221, 879217)
+			// Standard Error: 97_528
+			.saturating_add(Weight::from_parts(103_117_482, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(16_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5274).saturating_mul(n.into()))
+	}
+	/// Storage: Kensetsu LiquidatedThisBlock (r:1 w:1)
+	/// Proof: Kensetsu LiquidatedThisBlock (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Kensetsu BasketDepository (r:1 w:1)
+	/// Proof: Kensetsu BasketDepository (max_values: None, max_size: Some(497), added: 2972, mode: MaxEncodedLen)
+	/// Storage: Kensetsu BasketInfos (r:1 w:1)
+	/// Proof: Kensetsu BasketInfos (max_values: None, max_size: Some(120), added: 2595, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Kensetsu StablecoinInfos (r:1 w:1)
+	/// Proof Skipped: Kensetsu StablecoinInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Kensetsu CollateralInfos (r:8 w:1)
+	/// Proof: Kensetsu CollateralInfos (max_values: None, max_size: Some(188), added: 2663, mode: MaxEncodedLen)
+	/// Storage: PriceTools FastPriceInfos (r:9 w:1)
+	/// Proof Skipped: PriceTools FastPriceInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Kensetsu BasketLiquidationPriority (r:1 w:0)
+	/// Proof: Kensetsu BasketLiquidationPriority (max_values: None, max_size: Some(289), added: 2764, mode: MaxEncodedLen)
+	/// Storage: Technical TechAccounts (r:3 w:0)
+	/// Proof Skipped: Technical TechAccounts (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets AssetInfosV2 (r:2 w:0)
+	/// Proof Skipped: Assets AssetInfosV2 (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Permissions Permissions (r:2 w:0)
+	/// Proof Skipped: Permissions Permissions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ExtendedAssets SoulboundAsset (r:2 w:0)
+	/// Proof: ExtendedAssets SoulboundAsset (max_values: None, max_size: Some(322091), added: 324566, mode: MaxEncodedLen)
+	/// Storage: DEXManager DEXInfos (r:1 w:0)
+	/// Proof Skipped: DEXManager DEXInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool EnabledSynthetics (r:2 w:0)
+	/// Proof Skipped: XSTPool EnabledSynthetics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DEXAPI EnabledSourceTypes (r:1 w:0)
+	/// Proof Skipped: DEXAPI EnabledSourceTypes (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: PoolXYK Properties (r:2 w:0)
+	/// Proof Skipped: PoolXYK Properties (max_values: None, max_size: None, mode: Measured)
+	/// Storage: MulticollateralBondingCurvePool EnabledTargets (r:1 w:0)
+	/// Proof Skipped: MulticollateralBondingCurvePool EnabledTargets (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: OrderBook OrderBooks (r:1 w:0)
+	/// Proof: OrderBook OrderBooks (max_values: None, max_size: Some(238), added: 2713, mode: MaxEncodedLen)
+	/// Storage: TradingPair LockedLiquiditySources (r:1 w:0)
+	/// Proof Skipped: TradingPair LockedLiquiditySources (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: System Account (r:4 w:4)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:3 w:3)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: Kensetsu LiquidationPenalty (r:1 w:0)
+	/// Proof: Kensetsu LiquidationPenalty (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Assets AssetOwners (r:1 w:0)
+	/// Proof Skipped: Assets AssetOwners (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PriceTools PriceInfos (r:1 w:1)
+	/// Proof Skipped: PriceTools PriceInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens TotalIssuance (r:1 w:1)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: PoolXYK Reserves (r:0 w:2)
+	/// Proof Skipped: PoolXYK Reserves (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[1, 8]`.
+	fn liquidate_basket(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `11247 + n * (183 ±0)`
+		//  Estimated: `867813 + n * (5165 ±0)`
+		// Minimum execution time: 918_406_000 picoseconds.
+		Weight::from_parts(893_571_048, 867813)
+			// Standard Error: 61_904
+			.saturating_add(Weight::from_parts(38_412_760, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(33_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(17_u64))
+			.saturating_add(Weight::from_parts(0, 5165).saturating_mul(n.into()))
+	}
+	/// Storage: Kensetsu StablecoinInfos (r:1 w:0)
+	/// Proof Skipped: Kensetsu StablecoinInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Kensetsu BasketInfos (r:1 w:1)
+	/// Proof: Kensetsu BasketInfos (max_values: None, max_size: Some(120), added: 2595, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn update_basket_risk_parameters() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `812`
+		//  Estimated: `4277`
+		// Minimum execution time: 41_738_000 picoseconds.
+		Weight::from_parts(42_915_000, 4277)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Kensetsu BasketInfos (r:1 w:0)
+	/// Proof: Kensetsu BasketInfos (max_values: None, max_size: Some(120), added: 2595, mode: MaxEncodedLen)
+	/// Storage: Kensetsu BasketLiquidationPriority (r:0 w:1)
+	/// Proof: Kensetsu BasketLiquidationPriority (max_values: None, max_size: Some(289), added: 2764, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 8]`.
+	fn update_basket_liquidation_priority(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `384`
+		//  Estimated: `3585`
+		// Minimum execution time: 27_461_000 picoseconds.
+		Weight::from_parts(27_936_218, 3585)
+			// Standard Error: 3_217
+			.saturating_add(Weight::from_parts(318_504, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -0,0 +1,146 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+use codec::Codec;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::ErrorObjectOwned};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::{Block as BlockT, MaybeDisplay, MaybeFromStr};
+use std::sync::Arc;
+
+fn runtime_error_into_rpc_error(error: impl core::fmt::Debug) -> ErrorObjectOwned {
+    ErrorObjectOwned::owned(1, "Runtime error", Some(format!("{error:?}")))
+}
+
+// Runtime API imports.
+pub use multicollateral_bonding_curve_pool_runtime_api::MulticollateralBondingCurvePoolAPI as MulticollateralBondingCurvePoolRuntimeAPI;
+use multicollateral_bonding_curve_pool_runtime_api::{
+    CollateralReserveInfo, CollateralizationInfo,
+};
+
+#[rpc(server)]
+pub trait MulticollateralBondingCurvePoolAPI<
+    BlockHash,
+    AssetId,
+    OptionCollateralReserveInfo,
+    VecCollateralReserveInfos,
+    OptionCollateralizationInfo,
+>
+{
+    #[method(name = "multicollateralBondingCurvePool_getCollateralReserveInfo")]
+    fn get_collateral_reserve_info(
+        &self,
+        collateral_asset_id: AssetId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<OptionCollateralReserveInfo>;
+
+    #[method(name = "multicollateralBondingCurvePool_listCollateralReserveInfos")]
+    fn list_collateral_reserve_infos(
+        &self,
+        at: Option<BlockHash>,
+    ) -> RpcResult<VecCollateralReserveInfos>;
+
+    #[method(name = "multicollateralBondingCurvePool_getCollateralizationInfo")]
+    fn get_collateralization_info(
+        &self,
+        at: Option<BlockHash>,
+    ) -> RpcResult<OptionCollateralizationInfo>;
+}
+
+pub struct MulticollateralBondingCurvePoolClient<C, B> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> MulticollateralBondingCurvePoolClient<C, B> {
+    /// Construct default MulticollateralBondingCurvePool as intermediary impl for rpc.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block, AssetId, Balance>
+    MulticollateralBondingCurvePoolAPIServer<
+        <Block as BlockT>::Hash,
+        AssetId,
+        Option<CollateralReserveInfo<AssetId, Balance>>,
+        Vec<CollateralReserveInfo<AssetId, Balance>>,
+        Option<CollateralizationInfo<Balance>>,
+    > for MulticollateralBondingCurvePoolClient<C, Block>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: MulticollateralBondingCurvePoolRuntimeAPI<Block, AssetId, Balance>,
+    AssetId: Codec,
+    Balance: Codec + MaybeFromStr + MaybeDisplay,
+{
+    fn get_collateral_reserve_info(
+        &self,
+        collateral_asset_id: AssetId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<CollateralReserveInfo<AssetId, Balance>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        );
+        api.get_collateral_reserve_info(at, collateral_asset_id)
+            .map_err(runtime_error_into_rpc_error)
+    }
+
+    fn list_collateral_reserve_infos(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<CollateralReserveInfo<AssetId, Balance>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        );
+        api.list_collateral_reserve_infos(at)
+            .map_err(runtime_error_into_rpc_error)
+    }
+
+    fn get_collateralization_info(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<CollateralizationInfo<Balance>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        );
+        api.get_collateralization_info(at)
+            .map_err(runtime_error_into_rpc_error)
+    }
+}
@@ -0,0 +1,167 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::unnecessary_mut_passed)]
+
+use codec::{Codec, Decode, Encode};
+#[cfg(feature = "std")]
+use common::utils::{string_serialization, string_serialization_opt};
+use common::FixedInner;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::traits::{MaybeDisplay, MaybeFromStr};
+use sp_std::prelude::*;
+
+/// Reserves of a single collateral asset of the bonding curve.
+///
+/// Values and prices are given in terms of the bonding curve reference asset, prices are for
+/// a single XOR.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Default, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct CollateralReserveInfo<AssetId, Balance> {
+    pub asset_id: AssetId,
+    /// Amount of collateral stored in reserves account.
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization"
+        )
+    )]
+    pub reserve: Balance,
+    /// Value of stored collateral, `None` if the collateral price is not available.
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization_opt"
+        ),
+        serde(default)
+    )]
+    pub reserve_value: Option<Balance>,
+    /// Current buy price, `None` if it can't be calculated.
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization_opt"
+        ),
+        serde(default)
+    )]
+    pub buy_price: Option<Balance>,
+    /// Current sell price, `None` if it can't be calculated.
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization_opt"
+        ),
+        serde(default)
+    )]
+    pub sell_price: Option<Balance>,
+}
+
+/// Aggregated collateralization of circulating XOR.
+///
+/// Values are given in terms of the bonding curve reference asset.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Default, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct CollateralizationInfo<Balance> {
+    /// Total value of all collateral reserves with available prices.
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization"
+        )
+    )]
+    pub reserves_value: Balance,
+    /// XOR total supply.
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization"
+        )
+    )]
+    pub total_supply: Balance,
+    /// Value of XOR total supply, `None` if the XOR price is not available.
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization_opt"
+        ),
+        serde(default)
+    )]
+    pub supply_value: Option<Balance>,
+    /// Ratio of reserves value to supply value, fixed point number with 18 decimals.
+    #[cfg_attr(feature = "std", serde(with = "string_serialization_opt", default))]
+    pub collateral_ratio: Option<FixedInner>,
+}
+
+sp_api::decl_runtime_apis! {
+    pub trait MulticollateralBondingCurvePoolAPI<AssetId, Balance> where
+        AssetId: Codec,
+        Balance: Codec + MaybeFromStr + MaybeDisplay,
+    {
+        fn get_collateral_reserve_info(
+            collateral_asset_id: AssetId,
+        ) -> Option<CollateralReserveInfo<AssetId, Balance>>;
+
+        fn list_collateral_reserve_infos() -> Vec<CollateralReserveInfo<AssetId, Balance>>;
+
+        fn get_collateralization_info() -> Option<CollateralizationInfo<Balance>>;
+    }
+}
@@ -162,10 +162,20 @@ fn incoming_spot_price<T: price_tools::Config>(asset_id: AssetIdOf<T>, price: Ba
     price_tools::PriceInfos::<T>::mutate(&asset_id, |opt_val| {
         let val = opt_val.as_mut().unwrap();
         val.price_mut_of(PriceVariant::Buy)
-            .incoming_spot_price(price, PriceVariant::Buy, &price_tools::DEFAULT_PARAMETERS)
+            .incoming_spot_price(
+                price,
+                PriceVariant::Buy,
+                &price_tools::DEFAULT_PARAMETERS,
+                AVG_BLOCK_SPAN,
+            )
             .unwrap();
         val.price_mut_of(PriceVariant::Sell)
-            .incoming_spot_price(price, PriceVariant::Sell, &price_tools::DEFAULT_PARAMETERS)
+            .incoming_spot_price(
+                price,
+                PriceVariant::Sell,
+                &price_tools::DEFAULT_PARAMETERS,
+                AVG_BLOCK_SPAN,
+            )
             .unwrap();
     })
 }
@@ -1575,6 +1575,83 @@ impl<T: Config> Pallet<T> {
         Ok(price)
     }
 
+    /// Amount of collateral asset stored in reserves account and its value in terms of the reference asset.
+    /// Value is `None` if the collateral asset price is not available.
+    pub fn collateral_reserves(
+        collateral_asset_id: &AssetIdOf<T>,
+    ) -> Result<(Balance, Option<Balance>), DispatchError> {
+        let reserves_account_id =
+            Technical::<T>::tech_account_id_to_account_id(&Self::reserves_account_id())?;
+        let reserve = <T as Config>::AssetInfoProvider::free_balance(
+            collateral_asset_id,
+            &reserves_account_id,
+        )?;
+        let value = Self::reference_price(collateral_asset_id, PriceVariant::Sell)
+            .ok()
+            .and_then(|price| {
+                (FixedWrapper256::from(reserve) * price)
+                    .try_into_balance()
+                    .ok()
+            });
+        Ok((reserve, value))
+    }
+
+    /// Current buy and sell prices of a single main asset unit in terms of the reference asset,
+    /// returned as `(buy_price, sell_price)`.
+    pub fn current_prices(
+        collateral_asset_id: &AssetIdOf<T>,
+    ) -> Result<(Balance, Balance), DispatchError> {
+        let base_asset_id = GetBaseAssetIdOf::<T>::get();
+        let buy_price = Self::buy_function(
+            &base_asset_id,
+            collateral_asset_id,
+            PriceVariant::Buy,
+            FixedU256::zero(),
+        )?;
+        let sell_price =
+            Self::sell_function(&base_asset_id, collateral_asset_id, FixedU256::zero())?;
+        let into_balance = |price: FixedU256| {
+            FixedWrapper256::from(price)
+                .try_into_balance()
+                .map_err(|_| Error::<T>::PriceCalculationFailed)
+        };
+        Ok((into_balance(buy_price)?, into_balance(sell_price)?))
+    }
+
+    /// Aggregated collateralization of main asset supply.
+    ///
+    /// Returns `(reserves_value, total_supply, supply_value, ratio)`, where `reserves_value` is the
+    /// reference asset value of all enabled collaterals with available prices and `supply_value` is
+    /// the main asset total supply valued at its reference price. `ratio` is `reserves_value / supply_value`.
+    pub fn collateralization(
+    ) -> Result<(Balance, Balance, Option<Balance>, Option<Fixed>), DispatchError> {
+        let mut reserves_value = Balance::zero();
+        for collateral_asset_id in EnabledTargets::<T>::get().iter() {
+            if let (_, Some(value)) = Self::collateral_reserves(collateral_asset_id)? {
+                reserves_value = reserves_value
+                    .checked_add(value)
+                    .ok_or(Error::<T>::ArithmeticError)?;
+            }
+        }
+        let base_asset_id = GetBaseAssetIdOf::<T>::get();
+        let total_supply = <T as Config>::AssetInfoProvider::total_issuance(&base_asset_id)?;
+        let supply_value = Self::reference_price(&base_asset_id, PriceVariant::Sell)
+            .ok()
+            .and_then(|price| {
+                (FixedWrapper::from(total_supply) * price)
+                    .try_into_balance()
+                    .ok()
+            });
+        let ratio = supply_value
+            .filter(|value| !value.is_zero())
+            .and_then(|value| {
+                (FixedWrapper::from(reserves_value) / FixedWrapper::from(value))
+                    .get()
+                    .ok()
+            });
+        Ok((reserves_value, total_supply, supply_value, ratio))
+    }
+
     /// Calculate amount of PSWAP rewarded for collateralizing XOR in TBC.
     ///
     /// ideal_reserves_before = sell_function(0 to xor_total_supply_before_trade)
@@ -2267,6 +2267,136 @@ mod tests {
         });
     }
 
+    #[test]
+    fn should_report_reserves_and_collateralization() {
+        ExtBuilder::new(vec![
+            (
+                alice(),
+                XOR,
+                balance!(0),
+                AssetSymbol(b"XOR".to_vec()),
+                AssetName(b"SORA".to_vec()),
+                DEFAULT_BALANCE_PRECISION,
+            ),
+            (
+                alice(),
+                VAL,
+                balance!(2000),
+                AssetSymbol(b"VAL".to_vec()),
+                AssetName(b"SORA Validator Token".to_vec()),
+                DEFAULT_BALANCE_PRECISION,
+            ),
+            (
+                alice(),
+                DAI,
+                balance!(200000),
+                AssetSymbol(b"DAI".to_vec()),
+                AssetName(b"DAI".to_vec()),
+                DEFAULT_BALANCE_PRECISION,
+            ),
+            (
+                alice(),
+                USDT,
+                balance!(0),
+                AssetSymbol(b"USDT".to_vec()),
+                AssetName(b"Tether USD".to_vec()),
+                DEFAULT_BALANCE_PRECISION,
+            ),
+            (
+                alice(),
+                PSWAP,
+                balance!(0),
+                AssetSymbol(b"PSWAP".to_vec()),
+                AssetName(b"Polkaswap".to_vec()),
+                DEFAULT_BALANCE_PRECISION,
+            ),
+            (
+                alice(),
+                XSTUSD,
+                0,
+                AssetSymbol(b"XSTUSD".to_vec()),
+                AssetName(b"SORA Synthetic USD".to_vec()),
+                DEFAULT_BALANCE_PRECISION,
+            ),
+        ])
+        .build()
+        .execute_with(|| {
+            MockDEXApi::init().unwrap();
+            let _ = bonding_curve_pool_init(vec![]).unwrap();
+            TradingPair::register(
+                RuntimeOrigin::signed(alice()),
+                DEXId::Polkaswap.into(),
+                XOR,
+                VAL,
+            )
+            .expect("Failed to register trading pair.");
+            TradingPair::register(
+                RuntimeOrigin::signed(alice()),
+                DEXId::Polkaswap.into(),
+                XOR,
+                DAI,
+            )
+            .expect("Failed to register trading pair.");
+            MBCPool::initialize_pool_unchecked(VAL, false).expect("Failed to initialize pool.");
+            MBCPool::initialize_pool_unchecked(DAI, false).expect("Failed to initialize pool.");
+            MBCPool::set_reference_asset(RuntimeOrigin::signed(alice()), DAI).unwrap();
+
+            assert_eq!(MBCPool::collateral_reserves(&DAI).unwrap(), (0, Some(0)));
+
+            let dai_amount: Balance = balance!(200000);
+            MBCPool::exchange(
+                &alice(),
+                &alice(),
+                &DEXId::Polkaswap.into(),
+                &DAI,
+                &XOR,
+                SwapAmount::with_desired_input(dai_amount, Balance::zero()),
+            )
+            .unwrap();
+
+            // 20% of input is distributed and not stored in reserves
+            let dai_reserve = (FixedWrapper::from(dai_amount) * fixed_wrapper!(0.8)).into_balance();
+            assert_eq!(
+                MBCPool::collateral_reserves(&DAI).unwrap(),
+                (dai_reserve, Some(dai_reserve))
+            );
+            let (val_reserve, val_value) = MBCPool::collateral_reserves(&VAL).unwrap();
+            assert_eq!(val_reserve, 0);
+            assert_eq!(val_value, Some(0));
+
+            let (buy_price, sell_price) = MBCPool::current_prices(&DAI).unwrap();
+            assert_eq!(
+                buy_price,
+                FixedWrapper256::from(
+                    MBCPool::buy_function(&XOR, &DAI, PriceVariant::Buy, FixedU256::zero())
+                        .unwrap()
+                )
+                .into_balance()
+            );
+            assert_eq!(
+                sell_price,
+                FixedWrapper256::from(
+                    MBCPool::sell_function(&XOR, &DAI, FixedU256::zero()).unwrap()
+                )
+                .into_balance()
+            );
+            assert!(sell_price < buy_price);
+
+            let (reserves_value, total_supply, supply_value, ratio) =
+                MBCPool::collateralization().unwrap();
+            assert_eq!(reserves_value, dai_reserve);
+            assert_eq!(total_supply, Assets::total_issuance(&XOR).unwrap());
+            let supply_value = supply_value.unwrap();
+            assert!(supply_value > 0);
+            assert_eq!(
+                ratio,
+                (FixedWrapper::from(reserves_value) / FixedWrapper::from(supply_value))
+                    .get()
+                    .ok()
+            );
+        });
+    }
+
     #[test]
     fn fees_for_equivalent_trades_should_match() {
         ExtBuilder::new(vec![
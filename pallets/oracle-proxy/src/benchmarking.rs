@@ -37,6 +37,14 @@ use codec::alloc::collections::BTreeSet;
 use frame_benchmarking::benchmarks;
 use frame_system::RawOrigin;
 use sp_std::prelude::*;
+use sp_std::str::FromStr;
+
+fn symbol<T: Config>(sym: &str) -> T::Symbol {
+    let bytes = common::SymbolName::from_str(sym)
+        .expect("Failed to create symbol")
+        .encode();
+    T::Symbol::decode(&mut &bytes[..]).expect("Failed to decode symbol")
+}
 
 benchmarks! {
     enable_oracle {
@@ -54,5 +62,26 @@ benchmarks! {
         assert_eq!(OracleProxy::<T>::enabled_oracles(), BTreeSet::new());
     }
 
+    set_symbol_providers {
+        let symbol = symbol::<T>("EURO");
+        let providers: SymbolProvidersOf =
+            vec![Oracle::CrossCheck, Oracle::BandChainFeed, Oracle::PriceToolsTwap]
+                .try_into()
+                .unwrap();
+        TwapSymbolAssets::<T>::insert(&symbol, T::GetReferenceAssetId::get());
+    }: _(RawOrigin::Root, symbol.clone(), providers.clone(), Some(fixed!(0.05)))
+    verify {
+        assert_eq!(OracleProxy::<T>::enabled_symbols(symbol), providers);
+    }
+
+    set_twap_symbol_asset {
+        let symbol = symbol::<T>("EURO");
+        let asset_id = T::GetReferenceAssetId::get();
+        T::PriceTools::register_asset(&asset_id).ok();
+    }: _(RawOrigin::Root, symbol.clone(), Some(asset_id.clone()))
+    verify {
+        assert_eq!(OracleProxy::<T>::twap_symbol_assets(symbol), Some(asset_id));
+    }
+
     impl_benchmark_test_suite!(OracleProxy, crate::mock::new_test_ext(), crate::mock::Runtime);
 }
@@ -32,11 +32,19 @@
 // TODO #167: fix clippy warnings
 #![allow(clippy::all)]
 
-use common::{DataFeed, OnNewSymbolsRelayed, Oracle, Rate};
+use common::prelude::FixedWrapper;
+use common::{
+    fixed, DataFeed, Fixed, OnNewSymbolsRelayed, Oracle, PriceToolsProvider, PriceVariant, Rate,
+};
 use frame_support;
 use frame_support::pallet_prelude::*;
+use frame_support::traits::Time;
 use frame_system::pallet_prelude::*;
-use sp_std::{collections::btree_set::BTreeSet, vec::Vec};
+use sp_runtime::SaturatedConversion;
+use sp_std::{
+    collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+    vec::Vec,
+};
 
 pub use pallet::*;
 
@@ -48,34 +56,48 @@ mod tests;
 
 mod benchmarking;
 
+pub mod migrations;
 pub mod weights;
 pub use weights::WeightInfo;
 
+/// Maximum number of providers in a fallback chain of a symbol.
+pub const MAX_SYMBOL_PROVIDERS: u32 = 8;
+
+/// Multiplier to convert Moment to the resolve time in seconds
+const MILLISECS_MULTIPLIER: u64 = 1_000;
+
+pub type SymbolProvidersOf = BoundedVec<Oracle, ConstU32<MAX_SYMBOL_PROVIDERS>>;
+
 impl<T: Config> DataFeed<T::Symbol, Rate, u64> for Pallet<T> {
     fn quote(symbol: &T::Symbol) -> Result<Option<Rate>, DispatchError> {
-        let enabled_oracles = Self::enabled_oracles();
+        Self::quote_chain(symbol, None)
+    }
 
-        Self::enabled_symbols(symbol)
-            .into_iter()
-            .filter(|oracle| enabled_oracles.contains(&oracle))
-            .map(|oracle| match oracle {
-                Oracle::BandChainFeed => T::BandChainOracle::quote(symbol),
-            })
-            .next()
-            .unwrap_or(Ok(None))
+    fn quote_with_max_age(symbol: &T::Symbol, max_age: u64) -> Result<Option<Rate>, DispatchError> {
+        Self::quote_chain(symbol, Some(max_age))
     }
 
     fn list_enabled_symbols() -> Result<Vec<(T::Symbol, u64)>, DispatchError> {
-        let symbols_rates = Self::enabled_oracles()
-            .into_iter()
-            .flat_map(|oracle| match oracle {
+        let mut symbols_rates = BTreeMap::new();
+        for oracle in Self::enabled_oracles() {
+            let list = match oracle {
                 Oracle::BandChainFeed => match T::BandChainOracle::list_enabled_symbols() {
                     Ok(list) => list,
                     _ => Vec::new(),
                 },
-            })
-            .collect();
-        Ok(symbols_rates)
+                Oracle::PriceToolsTwap => {
+                    let now = Self::now();
+                    TwapSymbolAssets::<T>::iter_keys()
+                        .map(|symbol| (symbol, now))
+                        .collect()
+                }
+                Oracle::CrossCheck => Vec::new(),
+            };
+            for (symbol, resolve_time) in list {
+                symbols_rates.entry(symbol).or_insert(resolve_time);
+            }
+        }
+        Ok(symbols_rates.into_iter().collect())
     }
 
     fn quote_unchecked(symbol: &T::Symbol) -> Option<Rate> {
@@ -84,11 +106,11 @@ impl<T: Config> DataFeed<T::Symbol, Rate, u64> for Pallet<T> {
         Self::enabled_symbols(symbol)
             .into_iter()
             .filter(|oracle| enabled_oracles.contains(&oracle))
-            .map(|oracle| match oracle {
+            .find_map(|oracle| match oracle {
                 Oracle::BandChainFeed => T::BandChainOracle::quote_unchecked(symbol),
+                Oracle::PriceToolsTwap => Self::twap_quote(symbol).ok().flatten(),
+                Oracle::CrossCheck => None,
             })
-            .next()
-            .unwrap_or(None)
     }
 }
 
@@ -98,12 +120,138 @@ impl<T: Config> OnNewSymbolsRelayed<T::Symbol> for Pallet<T> {
         symbols: BTreeSet<T::Symbol>,
     ) -> Result<(), DispatchError> {
         symbols.into_iter().for_each(|symbol| {
-            SymbolProviders::<T>::set(symbol, Some(oracle_variant));
+            SymbolProviders::<T>::mutate(symbol, |providers| {
+                if !providers.contains(&oracle_variant) {
+                    // chain is full only if governance set it explicitly, keep it as is then
+                    let _ = providers.try_push(oracle_variant);
+                }
+            });
         });
         Ok(())
     }
 }
 
+impl<T: Config> Pallet<T> {
+    /// Quote `symbol` walking through its providers in order.
+    ///
+    /// Disabled providers are skipped. If a provider has no rate or its rate is stale, the next
+    /// one is used. [`Oracle::CrossCheck`] refuses to answer if the rest of providers diverge.
+    fn quote_chain(
+        symbol: &T::Symbol,
+        max_age: Option<u64>,
+    ) -> Result<Option<Rate>, DispatchError> {
+        let enabled_oracles = Self::enabled_oracles();
+        let providers = Self::enabled_symbols(symbol);
+        let mut last_error = None;
+
+        for oracle in providers
+            .iter()
+            .filter(|oracle| enabled_oracles.contains(*oracle))
+        {
+            let result = match oracle {
+                Oracle::CrossCheck => {
+                    Self::cross_check_quote(symbol, &providers, &enabled_oracles, max_age)?
+                }
+                oracle => match Self::provider_quote(*oracle, symbol, max_age) {
+                    Ok(rate) => rate,
+                    Err(error) => {
+                        last_error = Some(error);
+                        None
+                    }
+                },
+            };
+            if result.is_some() {
+                return Ok(result);
+            }
+        }
+
+        last_error.map_or(Ok(None), Err)
+    }
+
+    /// Quote `symbol` from a single data source, [`Oracle::CrossCheck`] has no own data.
+    fn provider_quote(
+        oracle: Oracle,
+        symbol: &T::Symbol,
+        max_age: Option<u64>,
+    ) -> Result<Option<Rate>, DispatchError> {
+        match (oracle, max_age) {
+            (Oracle::BandChainFeed, Some(max_age)) => {
+                T::BandChainOracle::quote_with_max_age(symbol, max_age)
+            }
+            (Oracle::BandChainFeed, None) => T::BandChainOracle::quote(symbol),
+            (Oracle::PriceToolsTwap, _) => Self::twap_quote(symbol),
+            (Oracle::CrossCheck, _) => Ok(None),
+        }
+    }
+
+    /// Quote `symbol` from the first available data source of the chain, making sure that
+    /// all other available data sources agree with it within [`CrossCheckDeviation`].
+    ///
+    /// Returns `None` if less than two data sources are available.
+    fn cross_check_quote(
+        symbol: &T::Symbol,
+        providers: &[Oracle],
+        enabled_oracles: &BTreeSet<Oracle>,
+        max_age: Option<u64>,
+    ) -> Result<Option<Rate>, DispatchError> {
+        let max_deviation =
+            Self::cross_check_deviation(symbol).ok_or(Error::<T>::CrossCheckNotConfigured)?;
+        let rates: Vec<Rate> = providers
+            .iter()
+            .filter(|oracle| **oracle != Oracle::CrossCheck && enabled_oracles.contains(*oracle))
+            .filter_map(|oracle| {
+                Self::provider_quote(*oracle, symbol, max_age)
+                    .ok()
+                    .flatten()
+            })
+            .collect();
+
+        let (primary, others) = match rates.split_first() {
+            Some((primary, others)) if !others.is_empty() => (primary, others),
+            _ => return Ok(None),
+        };
+        ensure!(primary.value > 0, Error::<T>::SourcesDiverged);
+        for rate in others {
+            let difference = if rate.value > primary.value {
+                rate.value - primary.value
+            } else {
+                primary.value - rate.value
+            };
+            let deviation = (FixedWrapper::from(difference) / FixedWrapper::from(primary.value))
+                .get()
+                .map_err(|_| Error::<T>::SourcesDiverged)?;
+            ensure!(deviation <= max_deviation, Error::<T>::SourcesDiverged);
+        }
+        Ok(Some(*primary))
+    }
+
+    /// Rate of `symbol` derived from the average price of its asset (see [`TwapSymbolAssets`])
+    /// in terms of the reference asset, which is expected to be a USD stablecoin.
+    fn twap_quote(symbol: &T::Symbol) -> Result<Option<Rate>, DispatchError> {
+        let asset_id = if let Some(asset_id) = Self::twap_symbol_assets(symbol) {
+            asset_id
+        } else {
+            return Ok(None);
+        };
+        let reference_asset_id = T::GetReferenceAssetId::get();
+        let buy_price =
+            T::PriceTools::get_average_price(&asset_id, &reference_asset_id, PriceVariant::Buy)?;
+        let sell_price =
+            T::PriceTools::get_average_price(&asset_id, &reference_asset_id, PriceVariant::Sell)?;
+        let value = buy_price / 2 + sell_price / 2 + (buy_price % 2 + sell_price % 2) / 2;
+        Ok(Some(Rate {
+            value,
+            last_updated: Self::now(),
+            dynamic_fee: fixed!(0),
+        }))
+    }
+
+    /// Current time in seconds.
+    fn now() -> u64 {
+        T::Time::now().saturated_into::<u64>() / MILLISECS_MULTIPLIER
+    }
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -118,9 +266,17 @@ pub mod pallet {
         type WeightInfo: WeightInfo;
         type Symbol: Parameter + Ord;
         type BandChainOracle: DataFeed<Self::Symbol, Rate, u64>;
+        /// Asset type used by [`Oracle::PriceToolsTwap`] provider
+        type AssetId: Parameter + Ord;
+        /// Source of average prices for [`Oracle::PriceToolsTwap`] provider
+        type PriceTools: PriceToolsProvider<Self::AssetId>;
+        /// Asset in which [`Oracle::PriceToolsTwap`] rates are quoted, expected to be a USD stablecoin
+        type GetReferenceAssetId: Get<Self::AssetId>;
+        /// Time used as the last update time of [`Oracle::PriceToolsTwap`] rates
+        type Time: Time;
     }
 
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -131,9 +287,23 @@ pub mod pallet {
     #[pallet::getter(fn enabled_oracles)]
     pub type EnabledOracles<T: Config> = StorageValue<_, BTreeSet<Oracle>, ValueQuery>;
 
+    /// Ordered list of providers of a symbol, the first one which has a fresh rate is used
     #[pallet::storage]
     #[pallet::getter(fn enabled_symbols)]
-    pub type SymbolProviders<T: Config> = StorageMap<_, Blake2_128Concat, T::Symbol, Oracle>;
+    pub type SymbolProviders<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::Symbol, SymbolProvidersOf, ValueQuery>;
+
+    /// Asset which average price is used as a rate of a symbol by [`Oracle::PriceToolsTwap`]
+    #[pallet::storage]
+    #[pallet::getter(fn twap_symbol_assets)]
+    pub type TwapSymbolAssets<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::Symbol, T::AssetId, OptionQuery>;
+
+    /// Maximum relative deviation between data sources allowed by [`Oracle::CrossCheck`]
+    #[pallet::storage]
+    #[pallet::getter(fn cross_check_deviation)]
+    pub type CrossCheckDeviation<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::Symbol, Fixed, OptionQuery>;
 
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -142,12 +312,30 @@ pub mod pallet {
         OracleEnabled(Oracle),
         /// Oracle was successfully disabled. [oracle]
         OracleDisabled(Oracle),
+        /// Providers of the symbol were changed. [symbol, providers, cross check deviation]
+        SymbolProvidersChanged(T::Symbol, SymbolProvidersOf, Option<Fixed>),
+        /// Asset backing the symbol for TWAP provider was changed. [symbol, asset]
+        TwapSymbolAssetChanged(T::Symbol, Option<T::AssetId>),
     }
 
     #[pallet::error]
     pub enum Error<T> {
         OracleAlreadyEnabled,
         OracleAlreadyDisabled,
+        /// Providers list is empty or contains duplicates
+        InvalidSymbolProviders,
+        /// Cross check requires a deviation threshold and at least two other providers
+        CrossCheckNotConfigured,
+        /// Cross check deviation must be positive
+        InvalidCrossCheckDeviation,
+        /// Cross check must be the first provider of the chain to guard the other ones
+        CrossCheckNotFirst,
+        /// TWAP provider requires an asset for the symbol
+        TwapAssetNotSet,
+        /// Asset is not tracked by price tools
+        TwapAssetNotRegistered,
+        /// Data sources of the symbol diverge beyond the cross check deviation
+        SourcesDiverged,
     }
 
     #[pallet::call]
@@ -195,6 +383,96 @@ pub mod pallet {
 
             Ok(().into())
         }
+
+        /// Sets an ordered fallback chain of providers for a symbol
+        ///
+        /// Checks if the caller is root
+        ///
+        /// - `origin`: the sudo account
+        /// - `symbol`: symbol which providers should be changed
+        /// - `providers`: providers in order of priority, without duplicates
+        /// - `cross_check_deviation`: maximum deviation between providers, required if
+        ///   [`Oracle::CrossCheck`] is in the chain, which must then be its first element
+        #[pallet::call_index(2)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_symbol_providers())]
+        pub fn set_symbol_providers(
+            origin: OriginFor<T>,
+            symbol: T::Symbol,
+            providers: SymbolProvidersOf,
+            cross_check_deviation: Option<Fixed>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            ensure!(!providers.is_empty(), Error::<T>::InvalidSymbolProviders);
+            let unique: BTreeSet<Oracle> = providers.iter().copied().collect();
+            ensure!(
+                unique.len() == providers.len(),
+                Error::<T>::InvalidSymbolProviders
+            );
+            if let Some(deviation) = cross_check_deviation {
+                let zero: Fixed = fixed!(0);
+                ensure!(deviation > zero, Error::<T>::InvalidCrossCheckDeviation);
+            }
+            if providers.contains(&Oracle::CrossCheck) {
+                ensure!(
+                    cross_check_deviation.is_some() && providers.len() > 2,
+                    Error::<T>::CrossCheckNotConfigured
+                );
+                ensure!(
+                    providers.first() == Some(&Oracle::CrossCheck),
+                    Error::<T>::CrossCheckNotFirst
+                );
+            }
+            if providers.contains(&Oracle::PriceToolsTwap) {
+                ensure!(
+                    TwapSymbolAssets::<T>::contains_key(&symbol),
+                    Error::<T>::TwapAssetNotSet
+                );
+            }
+
+            SymbolProviders::<T>::insert(&symbol, providers.clone());
+            CrossCheckDeviation::<T>::set(&symbol, cross_check_deviation);
+
+            Self::deposit_event(Event::SymbolProvidersChanged(
+                symbol,
+                providers,
+                cross_check_deviation,
+            ));
+
+            Ok(().into())
+        }
+
+        /// Sets an asset which average price is used by TWAP provider as a rate of a symbol
+        ///
+        /// Checks if the caller is root
+        ///
+        /// - `origin`: the sudo account
+        /// - `symbol`: symbol which asset should be changed
+        /// - `asset_id`: asset tracked by price tools, `None` removes the symbol from TWAP provider
+        #[pallet::call_index(3)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_twap_symbol_asset())]
+        pub fn set_twap_symbol_asset(
+            origin: OriginFor<T>,
+            symbol: T::Symbol,
+            asset_id: Option<T::AssetId>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            match &asset_id {
+                Some(asset_id) => ensure!(
+                    T::PriceTools::is_asset_registered(asset_id),
+                    Error::<T>::TwapAssetNotRegistered
+                ),
+                None => ensure!(
+                    !Self::enabled_symbols(&symbol).contains(&Oracle::PriceToolsTwap),
+                    Error::<T>::TwapAssetNotSet
+                ),
+            }
+
+            TwapSymbolAssets::<T>::set(&symbol, asset_id.clone());
+
+            Self::deposit_event(Event::TwapSymbolAssetChanged(symbol, asset_id));
+
+            Ok(().into())
+        }
     }
 
     #[pallet::genesis_config]
@@ -0,0 +1,91 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+pub mod v2 {
+    use crate::{Config, Pallet, SymbolProviders, SymbolProvidersOf};
+    use common::Oracle;
+    use core::marker::PhantomData;
+    use frame_support::pallet_prelude::*;
+    use frame_support::traits::OnRuntimeUpgrade;
+    use frame_support::{__private::log::info, traits::StorageVersion};
+    #[cfg(feature = "try-runtime")]
+    use sp_std::vec::Vec;
+
+    /// Migrates `SymbolProviders` from a single oracle to a fallback chain of oracles.
+    pub struct Migrate<T>(PhantomData<T>);
+
+    impl<T> OnRuntimeUpgrade for Migrate<T>
+    where
+        T: Config,
+    {
+        fn on_runtime_upgrade() -> Weight {
+            if StorageVersion::get::<Pallet<T>>() != StorageVersion::new(1) {
+                return T::DbWeight::get().reads(1);
+            }
+
+            let mut count = 0u64;
+            SymbolProviders::<T>::translate::<Oracle, _>(|_symbol, oracle| {
+                count += 1;
+                Some(SymbolProvidersOf::truncate_from(sp_std::vec![oracle]))
+            });
+            StorageVersion::new(2).put::<Pallet<T>>();
+            info!(
+                "oracle-proxy v2 applied successfully, {} symbols migrated.",
+                count
+            );
+
+            T::DbWeight::get().reads_writes(count + 1, count + 1)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::DispatchError> {
+            frame_support::ensure!(
+                StorageVersion::get::<Pallet<T>>() == StorageVersion::new(1),
+                "Wrong storage version before oracle-proxy v2 upgrade"
+            );
+            Ok((SymbolProviders::<T>::iter_keys().count() as u64).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+            frame_support::ensure!(
+                StorageVersion::get::<Pallet<T>>() == StorageVersion::new(2),
+                "Wrong storage version after oracle-proxy v2 upgrade"
+            );
+            let count = u64::decode(&mut &state[..])
+                .map_err(|_| "Failed to decode symbols count before oracle-proxy v2 upgrade")?;
+            frame_support::ensure!(
+                SymbolProviders::<T>::iter_values().count() as u64 == count,
+                "Some symbol providers were lost during oracle-proxy v2 upgrade"
+            );
+            Ok(())
+        }
+    }
+}
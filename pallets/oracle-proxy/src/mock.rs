@@ -34,11 +34,14 @@ use crate as oracle_proxy;
 use band;
 use common::{
     self, mock_band_config, mock_frame_system_config, mock_oracle_proxy_config,
-    mock_pallet_timestamp_config, SymbolName,
+    mock_pallet_timestamp_config, AssetId32, Balance, PredefinedAssetId, PriceToolsProvider,
+    PriceVariant, SymbolName,
 };
 use frame_support::traits::{ConstU16, ConstU32};
 use frame_system;
-use sp_runtime::BuildStorage;
+use sp_runtime::{BuildStorage, DispatchError, DispatchResult};
+use std::cell::RefCell;
+use std::collections::BTreeMap;
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
 type Block = frame_system::mocking::MockBlock<Runtime>;
@@ -60,10 +63,53 @@ frame_support::construct_runtime!(
 
 mock_band_config!(Runtime);
 mock_frame_system_config!(Runtime, ConstU16<42>, ConstU32<16>, ());
-mock_oracle_proxy_config!(Runtime, band::Pallet<Runtime>, SymbolName);
+mock_oracle_proxy_config!(Runtime, band::Pallet<Runtime>, SymbolName, MockPriceTools);
 mock_pallet_timestamp_config!(Runtime);
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
     SystemConfig::default().build_storage().unwrap().into()
 }
+
+type AssetId = AssetId32<PredefinedAssetId>;
+
+thread_local! {
+    static AVERAGE_PRICES: RefCell<BTreeMap<AssetId, (Balance, Balance)>> =
+        RefCell::new(BTreeMap::new());
+}
+
+/// Price tools mock with average prices set in tests, prices are quoted in any asset
+pub struct MockPriceTools;
+
+impl MockPriceTools {
+    pub fn set_average_price(asset_id: AssetId, buy: Balance, sell: Balance) {
+        AVERAGE_PRICES.with(|prices| prices.borrow_mut().insert(asset_id, (buy, sell)));
+    }
+}
+
+impl PriceToolsProvider<AssetId> for MockPriceTools {
+    fn is_asset_registered(asset_id: &AssetId) -> bool {
+        AVERAGE_PRICES.with(|prices| prices.borrow().contains_key(asset_id))
+    }
+
+    fn get_average_price(
+        input_asset_id: &AssetId,
+        _output_asset_id: &AssetId,
+        price_variant: PriceVariant,
+    ) -> Result<Balance, DispatchError> {
+        AVERAGE_PRICES
+            .with(|prices| prices.borrow().get(input_asset_id).copied())
+            .map(|(buy, sell)| match price_variant {
+                PriceVariant::Buy => buy,
+                PriceVariant::Sell => sell,
+            })
+            .ok_or_else(|| DispatchError::Other("Asset is not registered"))
+    }
+
+    fn register_asset(asset_id: &AssetId) -> DispatchResult {
+        AVERAGE_PRICES.with(|prices| {
+            prices.borrow_mut().entry(*asset_id).or_default();
+        });
+        Ok(())
+    }
+}
@@ -29,12 +29,12 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use codec::alloc::collections::HashSet;
-use common::{fixed, DataFeed, SymbolName};
-use frame_support::{assert_err, error::BadOrigin};
+use common::{balance, fixed, DataFeed, PriceToolsProvider, SymbolName, DAI, VAL};
+use frame_support::{assert_err, assert_ok, error::BadOrigin};
 use sp_core::TryCollect;
 use sp_std::str::FromStr;
 
-use crate::{mock::*, Oracle, Rate};
+use crate::{mock::*, Error, Oracle, Rate, SymbolProvidersOf};
 
 fn relay_symbols() {
     let symbols = vec![
@@ -161,3 +161,262 @@ fn quote_and_list_enabled_symbols_should_not_work_with_disabled_oracle() {
         )
     });
 }
+
+fn usd() -> SymbolName {
+    SymbolName::from_str("USD").expect("`USD` is a valid symbol name")
+}
+
+fn providers(providers: Vec<Oracle>) -> SymbolProvidersOf {
+    providers.try_into().expect("Too many providers")
+}
+
+fn enable_all_oracles() {
+    for oracle in [
+        Oracle::BandChainFeed,
+        Oracle::PriceToolsTwap,
+        Oracle::CrossCheck,
+    ] {
+        assert_ok!(OracleProxy::enable_oracle(RuntimeOrigin::root(), oracle));
+    }
+}
+
+fn relay_usd(raw_rate: u64, resolve_time: u64) {
+    let relayer = 1;
+    if Band::trusted_relayers().is_none() {
+        assert_ok!(Band::add_relayers(RuntimeOrigin::root(), vec![relayer]));
+    }
+    assert_ok!(Band::relay(
+        RuntimeOrigin::signed(relayer),
+        vec![(usd(), raw_rate)].try_into().unwrap(),
+        resolve_time,
+        0,
+    ));
+}
+
+#[test]
+fn relayed_symbols_should_get_band_provider() {
+    new_test_ext().execute_with(|| {
+        relay_symbols();
+
+        assert_eq!(
+            OracleProxy::enabled_symbols(usd()),
+            providers(vec![Oracle::BandChainFeed])
+        );
+    });
+}
+
+#[test]
+fn set_symbol_providers_should_validate_input() {
+    new_test_ext().execute_with(|| {
+        assert_err!(
+            OracleProxy::set_symbol_providers(
+                RuntimeOrigin::signed(1),
+                usd(),
+                providers(vec![Oracle::BandChainFeed]),
+                None
+            ),
+            BadOrigin
+        );
+        assert_err!(
+            OracleProxy::set_symbol_providers(
+                RuntimeOrigin::root(),
+                usd(),
+                providers(vec![]),
+                None
+            ),
+            Error::<Runtime>::InvalidSymbolProviders
+        );
+        assert_err!(
+            OracleProxy::set_symbol_providers(
+                RuntimeOrigin::root(),
+                usd(),
+                providers(vec![Oracle::BandChainFeed, Oracle::BandChainFeed]),
+                None
+            ),
+            Error::<Runtime>::InvalidSymbolProviders
+        );
+        assert_err!(
+            OracleProxy::set_symbol_providers(
+                RuntimeOrigin::root(),
+                usd(),
+                providers(vec![Oracle::BandChainFeed]),
+                Some(fixed!(0))
+            ),
+            Error::<Runtime>::InvalidCrossCheckDeviation
+        );
+        assert_err!(
+            OracleProxy::set_symbol_providers(
+                RuntimeOrigin::root(),
+                usd(),
+                providers(vec![Oracle::PriceToolsTwap]),
+                None
+            ),
+            Error::<Runtime>::TwapAssetNotSet
+        );
+
+        MockPriceTools::register_asset(&VAL).unwrap();
+        assert_ok!(OracleProxy::set_twap_symbol_asset(
+            RuntimeOrigin::root(),
+            usd(),
+            Some(VAL)
+        ));
+        assert_err!(
+            OracleProxy::set_symbol_providers(
+                RuntimeOrigin::root(),
+                usd(),
+                providers(vec![
+                    Oracle::CrossCheck,
+                    Oracle::BandChainFeed,
+                    Oracle::PriceToolsTwap
+                ]),
+                None
+            ),
+            Error::<Runtime>::CrossCheckNotConfigured
+        );
+        assert_err!(
+            OracleProxy::set_symbol_providers(
+                RuntimeOrigin::root(),
+                usd(),
+                providers(vec![Oracle::CrossCheck, Oracle::BandChainFeed]),
+                Some(fixed!(0.05))
+            ),
+            Error::<Runtime>::CrossCheckNotConfigured
+        );
+        assert_err!(
+            OracleProxy::set_symbol_providers(
+                RuntimeOrigin::root(),
+                usd(),
+                providers(vec![
+                    Oracle::BandChainFeed,
+                    Oracle::CrossCheck,
+                    Oracle::PriceToolsTwap
+                ]),
+                Some(fixed!(0.05))
+            ),
+            Error::<Runtime>::CrossCheckNotFirst
+        );
+
+        let chain = providers(vec![
+            Oracle::CrossCheck,
+            Oracle::BandChainFeed,
+            Oracle::PriceToolsTwap,
+        ]);
+        assert_ok!(OracleProxy::set_symbol_providers(
+            RuntimeOrigin::root(),
+            usd(),
+            chain.clone(),
+            Some(fixed!(0.05))
+        ));
+        assert_eq!(OracleProxy::enabled_symbols(usd()), chain);
+        assert_eq!(
+            OracleProxy::cross_check_deviation(usd()),
+            Some(fixed!(0.05))
+        );
+
+        // asset can't be removed while the symbol uses TWAP provider
+        assert_err!(
+            OracleProxy::set_twap_symbol_asset(RuntimeOrigin::root(), usd(), None),
+            Error::<Runtime>::TwapAssetNotSet
+        );
+        assert_err!(
+            OracleProxy::set_twap_symbol_asset(RuntimeOrigin::root(), usd(), Some(DAI)),
+            Error::<Runtime>::TwapAssetNotRegistered
+        );
+    });
+}
+
+#[test]
+fn quote_should_fall_back_to_next_provider() {
+    new_test_ext().execute_with(|| {
+        enable_all_oracles();
+        relay_usd(1_000_000_000, 0);
+        MockPriceTools::set_average_price(VAL, balance!(1.1), balance!(0.9));
+        assert_ok!(OracleProxy::set_twap_symbol_asset(
+            RuntimeOrigin::root(),
+            usd(),
+            Some(VAL)
+        ));
+        assert_ok!(OracleProxy::set_symbol_providers(
+            RuntimeOrigin::root(),
+            usd(),
+            providers(vec![Oracle::BandChainFeed, Oracle::PriceToolsTwap]),
+            None
+        ));
+
+        assert_eq!(
+            OracleProxy::quote(&usd()).map(|rate| rate.map(|rate| rate.value)),
+            Ok(Some(balance!(1)))
+        );
+
+        // Band rate is stale, TWAP is used
+        Timestamp::set_timestamp(GetBandRateStalePeriod::get() + 1000);
+        assert_eq!(
+            OracleProxy::quote(&usd()),
+            Ok(Some(Rate {
+                value: balance!(1),
+                last_updated: GetBandRateStalePeriod::get() / 1000 + 1,
+                dynamic_fee: fixed!(0),
+            }))
+        );
+
+        // Band rate is fresh again, but the oracle is disabled
+        relay_usd(2_000_000_000, GetBandRateStalePeriod::get() / 1000 + 1);
+        assert_eq!(
+            OracleProxy::quote(&usd()).map(|rate| rate.map(|rate| rate.value)),
+            Ok(Some(balance!(2)))
+        );
+        assert_ok!(OracleProxy::disable_oracle(
+            RuntimeOrigin::root(),
+            Oracle::BandChainFeed
+        ));
+        assert_eq!(
+            OracleProxy::quote(&usd()).map(|rate| rate.map(|rate| rate.value)),
+            Ok(Some(balance!(1)))
+        );
+    });
+}
+
+#[test]
+fn cross_check_should_refuse_diverged_sources() {
+    new_test_ext().execute_with(|| {
+        enable_all_oracles();
+        relay_usd(1_000_000_000, 0);
+        MockPriceTools::set_average_price(VAL, balance!(1.02), balance!(1));
+        assert_ok!(OracleProxy::set_twap_symbol_asset(
+            RuntimeOrigin::root(),
+            usd(),
+            Some(VAL)
+        ));
+        assert_ok!(OracleProxy::set_symbol_providers(
+            RuntimeOrigin::root(),
+            usd(),
+            providers(vec![
+                Oracle::CrossCheck,
+                Oracle::BandChainFeed,
+                Oracle::PriceToolsTwap,
+            ]),
+            Some(fixed!(0.05))
+        ));
+
+        assert_eq!(
+            OracleProxy::quote(&usd()).map(|rate| rate.map(|rate| rate.value)),
+            Ok(Some(balance!(1)))
+        );
+
+        MockPriceTools::set_average_price(VAL, balance!(1.5), balance!(1.4));
+        assert_err!(
+            OracleProxy::quote(&usd()),
+            Error::<Runtime>::SourcesDiverged
+        );
+
+        // nothing to cross check with, next providers are used
+        assert_ok!(OracleProxy::disable_oracle(
+            RuntimeOrigin::root(),
+            Oracle::PriceToolsTwap
+        ));
+        assert_eq!(
+            OracleProxy::quote(&usd()).map(|rate| rate.map(|rate| rate.value)),
+            Ok(Some(balance!(1)))
+        );
+    });
+}
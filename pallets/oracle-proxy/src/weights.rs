@@ -62,6 +62,8 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn enable_oracle() -> Weight;
 	fn disable_oracle() -> Weight;
+	fn set_symbol_providers() -> Weight;
+	fn set_twap_symbol_asset() -> Weight;
 }
 
 /// Weights for oracle_proxy using the Substrate node and recommended hardware.
@@ -89,6 +91,36 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: OracleProxy TwapSymbolAssets (r:1 w:0)
+	/// Proof Skipped: OracleProxy TwapSymbolAssets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: OracleProxy SymbolProviders (r:0 w:1)
+	/// Proof Skipped: OracleProxy SymbolProviders (max_values: None, max_size: None, mode: Measured)
+	/// Storage: OracleProxy CrossCheckDeviation (r:0 w:1)
+	/// Proof Skipped: OracleProxy CrossCheckDeviation (max_values: None, max_size: None, mode: Measured)
+	fn set_symbol_providers() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `114`
+		//  Estimated: `3579`
+		// Minimum execution time: 27_106_000 picoseconds.
+		Weight::from_parts(27_642_000, 3579)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: PriceTools PriceInfos (r:1 w:0)
+	/// Proof Skipped: PriceTools PriceInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: OracleProxy SymbolProviders (r:1 w:0)
+	/// Proof Skipped: OracleProxy SymbolProviders (max_values: None, max_size: None, mode: Measured)
+	/// Storage: OracleProxy TwapSymbolAssets (r:0 w:1)
+	/// Proof Skipped: OracleProxy TwapSymbolAssets (max_values: None, max_size: None, mode: Measured)
+	fn set_twap_symbol_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1227`
+		//  Estimated: `4692`
+		// Minimum execution time: 29_481_000 picoseconds.
+		Weight::from_parts(30_015_000, 4692)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -115,4 +147,34 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: OracleProxy TwapSymbolAssets (r:1 w:0)
+	/// Proof Skipped: OracleProxy TwapSymbolAssets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: OracleProxy SymbolProviders (r:0 w:1)
+	/// Proof Skipped: OracleProxy SymbolProviders (max_values: None, max_size: None, mode: Measured)
+	/// Storage: OracleProxy CrossCheckDeviation (r:0 w:1)
+	/// Proof Skipped: OracleProxy CrossCheckDeviation (max_values: None, max_size: None, mode: Measured)
+	fn set_symbol_providers() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `114`
+		//  Estimated: `3579`
+		// Minimum execution time: 27_106_000 picoseconds.
+		Weight::from_parts(27_642_000, 3579)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: PriceTools PriceInfos (r:1 w:0)
+	/// Proof Skipped: PriceTools PriceInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: OracleProxy SymbolProviders (r:1 w:0)
+	/// Proof Skipped: OracleProxy SymbolProviders (max_values: None, max_size: None, mode: Measured)
+	/// Storage: OracleProxy TwapSymbolAssets (r:0 w:1)
+	/// Proof Skipped: OracleProxy TwapSymbolAssets (max_values: None, max_size: None, mode: Measured)
+	fn set_twap_symbol_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1227`
+		//  Estimated: `4692`
+		// Minimum execution time: 29_481_000 picoseconds.
+		Weight::from_parts(30_015_000, 4692)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
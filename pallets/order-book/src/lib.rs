@@ -1469,6 +1469,22 @@ impl<T: Config> OrderBookManager<T::AccountId, AssetIdOf<T>, T::DEXId, MomentOf<
         let _ = Self::inner_place_limit_order(owner, order_book_id, price, amount, side, lifespan)?;
         Ok(())
     }
+
+    fn freeze_orderbook(
+        order_book_id: &OrderBookId<AssetIdOf<T>, T::DEXId>,
+    ) -> Result<(), DispatchError> {
+        let new_status = OrderBookStatus::OnlyCancel;
+        <OrderBooks<T>>::try_mutate(order_book_id, |order_book| {
+            let order_book = order_book.as_mut().ok_or(Error::<T>::UnknownOrderBook)?;
+            order_book.status = new_status;
+            Ok::<_, Error<T>>(())
+        })?;
+        Self::deposit_event(Event::<T>::OrderBookStatusChanged {
+            order_book_id: *order_book_id,
+            new_status,
+        });
+        Ok(())
+    }
 }
 
 impl<T: Config> LiquiditySource<T::DEXId, T::AccountId, AssetIdOf<T>, Balance, DispatchError>
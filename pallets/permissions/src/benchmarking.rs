@@ -0,0 +1,149 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Permissions module benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks};
+use frame_support::traits::{Get, Hooks};
+use frame_system::{EventRecord, RawOrigin};
+use sp_std::prelude::*;
+
+// Support Functions
+fn benchmark_scope() -> Scope {
+    Scope::Limited(hash(&b"benchmark"))
+}
+
+fn create_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+    let account_id: T::AccountId = account(name, index, 0);
+    frame_system::Pallet::<T>::inc_providers(&account_id);
+    account_id
+}
+
+fn create_owner<T: Config>() -> T::AccountId {
+    let owner = create_account::<T>("owner", 0);
+    Pallet::<T>::create_permission(owner.clone(), owner.clone(), MINT, benchmark_scope())
+        .expect("Failed to create permission");
+    owner
+}
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+    let events = frame_system::Pallet::<T>::events();
+    let system_event: <T as frame_system::Config>::RuntimeEvent = generic_event.into();
+    // compare to the last event record
+    let EventRecord { event, .. } = &events[events.len() - 1];
+    assert_eq!(event, &system_event);
+}
+
+benchmarks! {
+    revoke_permission {
+        let owner = create_owner::<T>();
+        let holder = create_account::<T>("holder", 0);
+        Pallet::<T>::grant_permission_with_scope(
+            owner.clone(),
+            holder.clone(),
+            MINT,
+            benchmark_scope(),
+        )
+        .expect("Failed to grant permission");
+    }: _(RawOrigin::Signed(owner), holder.clone(), MINT, benchmark_scope())
+    verify {
+        assert_last_event::<T>(Event::<T>::PermissionRevoked(MINT, holder).into());
+    }
+
+    grant_permission_with_expiry {
+        let owner = create_owner::<T>();
+        let holder = create_account::<T>("holder", 0);
+        let expires_at = frame_system::Pallet::<T>::block_number() + 100u32.into();
+        // Fill the block up to the cap, the holder's previous expiration is cleared from it.
+        for i in 1..T::MaxExpiringPermissionsPerBlock::get() {
+            Pallet::<T>::grant_permission_with_expiry(
+                RawOrigin::Signed(owner.clone()).into(),
+                create_account::<T>("holder", i),
+                MINT,
+                benchmark_scope(),
+                expires_at,
+            )?;
+        }
+        Pallet::<T>::grant_permission_with_expiry(
+            RawOrigin::Signed(owner.clone()).into(),
+            holder.clone(),
+            MINT,
+            benchmark_scope(),
+            expires_at,
+        )?;
+    }: _(RawOrigin::Signed(owner), holder.clone(), MINT, benchmark_scope(), expires_at)
+    verify {
+        assert_last_event::<T>(
+            Event::<T>::PermissionGrantedWithExpiry(MINT, holder, expires_at).into()
+        );
+    }
+
+    on_initialize {
+        let n in 0 .. T::MaxExpiringPermissionsPerBlock::get();
+        let owner = create_owner::<T>();
+        let expires_at = frame_system::Pallet::<T>::block_number() + 1u32.into();
+        for i in 0..n {
+            let holder = create_account::<T>("holder", i);
+            Pallet::<T>::grant_permission_with_expiry(
+                RawOrigin::Signed(owner.clone()).into(),
+                holder,
+                MINT,
+                benchmark_scope(),
+                expires_at,
+            )?;
+        }
+    }: {
+        Pallet::<T>::on_initialize(expires_at);
+    }
+    verify {
+        assert!(ExpiringPermissions::<T>::get(expires_at).is_empty());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{ExtBuilder, Runtime};
+    use frame_support::assert_ok;
+
+    #[test]
+    fn test_benchmarks() {
+        ExtBuilder::default().build().execute_with(|| {
+            frame_system::Pallet::<Runtime>::set_block_number(1);
+            assert_ok!(Pallet::<Runtime>::test_benchmark_revoke_permission());
+            assert_ok!(Pallet::<Runtime>::test_benchmark_grant_permission_with_expiry());
+            assert_ok!(Pallet::<Runtime>::test_benchmark_on_initialize());
+        });
+    }
+}
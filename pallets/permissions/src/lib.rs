@@ -47,7 +47,7 @@
 // TODO #167: fix clippy warnings
 #![allow(clippy::all)]
 
-use codec::{Decode, Encode};
+use codec::{Decode, DecodeWithMemTracking, Encode};
 use common::permissions::TRANSFER;
 use common::{hash, AssetRegulator};
 use frame_support::ensure;
@@ -55,6 +55,12 @@ use frame_support::sp_runtime::{DispatchError, RuntimeDebug};
 use serde::{Deserialize, Serialize};
 use sp_core::hash::H512;
 use sp_std::vec::Vec;
+
+mod benchmarking;
+pub mod migrations;
+pub mod weights;
+pub use weights::WeightInfo;
+
 #[cfg(test)]
 mod mock;
 
@@ -70,6 +76,7 @@ pub type OwnerId<T> = <T as frame_system::Config>::AccountId;
 /// The id of the account having a permission
 pub type HolderId<T> = <T as frame_system::Config>::AccountId;
 type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+type BlockNumberOf<T> = frame_system::pallet_prelude::BlockNumberFor<T>;
 
 #[derive(
     PartialEq,
@@ -79,6 +86,7 @@ type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
     RuntimeDebug,
     Encode,
     Decode,
+    DecodeWithMemTracking,
     scale_info::TypeInfo,
     Serialize,
     Deserialize,
@@ -137,15 +145,7 @@ impl<T: Config> Pallet<T> {
             }
         };
         if owns_permission {
-            if Permissions::<T>::iter_prefix_values(&account_id).count() == 0 {
-                frame_system::Pallet::<T>::inc_consumers(&account_id)
-                    .map_err(|_| Error::<T>::IncRefError)?;
-            }
-            Permissions::<T>::mutate(&account_id, &scope, |permissions| {
-                if let Err(index) = permissions.binary_search(&permission_id) {
-                    permissions.insert(index, permission_id);
-                }
-            });
+            Self::add_holder_permission(&account_id, permission_id, scope)?;
             Self::deposit_event(Event::<T>::PermissionGranted(permission_id, account_id));
             Ok(())
         } else if permission_found {
@@ -196,11 +196,8 @@ impl<T: Config> Pallet<T> {
             owners.push(owner);
             Ok(())
         })?;
-        Permissions::<T>::mutate(&account_id, scope, |permissions| {
-            if let Err(index) = permissions.binary_search(&permission_id) {
-                permissions.insert(index, permission_id);
-            }
-        });
+        let _ = Self::insert_holder_permission(&account_id, permission_id, scope);
+        Self::clear_expiration(&account_id, scope, permission_id);
         Self::deposit_event(Event::<T>::PermissionCreated(permission_id, account_id));
         Ok(())
     }
@@ -226,7 +223,104 @@ impl<T: Config> Pallet<T> {
             frame_system::Pallet::<T>::inc_consumers(&holder_id)
                 .map_err(|_| Error::<T>::IncRefError)?;
         }
-        let granted_permission = Permissions::<T>::mutate(&holder_id, scope, |permissions| {
+        let granted_permission = Self::insert_holder_permission(holder_id, permission_id, scope);
+        Self::clear_expiration(holder_id, scope, permission_id);
+        if made_owner || granted_permission {
+            Ok(())
+        } else {
+            Err(Error::PermissionAlreadyExists)
+        }
+    }
+
+    /// Checks that `who` owns `permission_id` either in `scope` or in the unlimited scope.
+    pub fn ensure_permission_owner(
+        who: &OwnerId<T>,
+        permission_id: PermissionId,
+        scope: &Scope,
+    ) -> Result<(), Error<T>> {
+        let mut permission_found = false;
+        for scope in [*scope, Scope::Unlimited] {
+            let owners = Owners::<T>::get(permission_id, scope);
+            if owners.contains(who) {
+                return Ok(());
+            }
+            permission_found |= !owners.is_empty();
+        }
+        if permission_found {
+            Err(Error::PermissionNotOwned)
+        } else {
+            Err(Error::PermissionNotFound)
+        }
+    }
+
+    /// Removes `permission_id` in `scope` from `holder_id`, also dropping its expiration.
+    pub fn revoke_permission_from(
+        holder_id: &HolderId<T>,
+        permission_id: PermissionId,
+        scope: Scope,
+    ) -> Result<(), Error<T>> {
+        Permissions::<T>::try_mutate_exists(holder_id, scope, |maybe_permissions| {
+            let permissions = maybe_permissions
+                .as_mut()
+                .ok_or(Error::<T>::PermissionNotFound)?;
+            let index = permissions
+                .binary_search(&permission_id)
+                .map_err(|_| Error::<T>::PermissionNotFound)?;
+            let _ = permissions.remove(index);
+            if permissions.is_empty() {
+                *maybe_permissions = None;
+            }
+            Ok(())
+        })?;
+        PermissionHolders::<T>::remove((permission_id, scope, holder_id));
+        Self::clear_expiration(holder_id, scope, permission_id);
+        if Permissions::<T>::iter_prefix_values(holder_id).count() == 0 {
+            frame_system::Pallet::<T>::dec_consumers(holder_id);
+        }
+        Ok(())
+    }
+
+    /// Returns the holders of `permission_id` in `scope` with their expiration blocks.
+    pub fn list_permission_holders(
+        permission_id: PermissionId,
+        scope: Scope,
+    ) -> Vec<(HolderId<T>, Option<BlockNumberOf<T>>)> {
+        PermissionHolders::<T>::iter_key_prefix((permission_id, scope))
+            .map(|account_id| {
+                let expires_at =
+                    PermissionExpirations::<T>::get((&account_id, scope, permission_id));
+                (account_id, expires_at)
+            })
+            .collect()
+    }
+
+    /// Returns the owners of `permission_id` in `scope`.
+    pub fn list_permission_owners(permission_id: PermissionId, scope: Scope) -> Vec<OwnerId<T>> {
+        Owners::<T>::get(permission_id, scope)
+    }
+
+    fn add_holder_permission(
+        holder_id: &HolderId<T>,
+        permission_id: PermissionId,
+        scope: Scope,
+    ) -> Result<(), Error<T>> {
+        if Permissions::<T>::iter_prefix_values(holder_id).count() == 0 {
+            frame_system::Pallet::<T>::inc_consumers(holder_id)
+                .map_err(|_| Error::<T>::IncRefError)?;
+        }
+        let _ = Self::insert_holder_permission(holder_id, permission_id, scope);
+        Self::clear_expiration(holder_id, scope, permission_id);
+        Ok(())
+    }
+
+    /// Adds `permission_id` to `holder_id` in `scope`, keeping `PermissionHolders` in sync.
+    /// Returns `false` if the holder already had the permission.
+    fn insert_holder_permission(
+        holder_id: &HolderId<T>,
+        permission_id: PermissionId,
+        scope: Scope,
+    ) -> bool {
+        let inserted = Permissions::<T>::mutate(holder_id, scope, |permissions| {
             if let Err(index) = permissions.binary_search(&permission_id) {
                 permissions.insert(index, permission_id);
                 true
@@ -234,11 +328,44 @@ impl<T: Config> Pallet<T> {
                 false
             }
         });
-        if made_owner || granted_permission {
-            Ok(())
-        } else {
-            Err(Error::PermissionAlreadyExists)
+        PermissionHolders::<T>::insert((permission_id, scope, holder_id), ());
+        inserted
+    }
+
+    /// Drops the expiration of `permission_id` held by `holder_id` in `scope`, if any,
+    /// together with its entry in `ExpiringPermissions`.
+    fn clear_expiration(holder_id: &HolderId<T>, scope: Scope, permission_id: PermissionId) {
+        let Some(expires_at) = PermissionExpirations::<T>::take((holder_id, scope, permission_id))
+        else {
+            return;
+        };
+        ExpiringPermissions::<T>::mutate_exists(expires_at, |maybe_expiring| {
+            if let Some(expiring) = maybe_expiring {
+                expiring.retain(|(holder, expiring_scope, expiring_permission)| {
+                    holder != holder_id
+                        || *expiring_scope != scope
+                        || *expiring_permission != permission_id
+                });
+                if expiring.is_empty() {
+                    *maybe_expiring = None;
+                }
+            }
+        });
+    }
+
+    fn expire_permissions(now: BlockNumberOf<T>) -> u32 {
+        let expiring = ExpiringPermissions::<T>::take(now);
+        let count = expiring.len() as u32;
+        for (holder_id, scope, permission_id) in expiring {
+            // The permission may have been revoked or re-granted after the expiration was set.
+            if PermissionExpirations::<T>::get((&holder_id, scope, permission_id)) != Some(now) {
+                continue;
+            }
+            if Self::revoke_permission_from(&holder_id, permission_id, scope).is_ok() {
+                Self::deposit_event(Event::<T>::PermissionExpired(permission_id, holder_id));
+            }
         }
+        count
     }
 
     fn account_has_permission(
@@ -265,10 +392,15 @@ pub mod pallet {
         /// Permissions pallet's events.
         #[allow(deprecated)]
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        /// Maximum number of time-limited permissions expiring in one block.
+        #[pallet::constant]
+        type MaxExpiringPermissionsPerBlock: Get<u32>;
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
     }
 
     /// The current storage version.
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -276,10 +408,85 @@ pub mod pallet {
     pub struct Pallet<T>(PhantomData<T>);
 
     #[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            let expired = Self::expire_permissions(now);
+            <T as Config>::WeightInfo::on_initialize(expired)
+        }
+    }
 
     #[pallet::call]
-    impl<T: Config> Pallet<T> {}
+    impl<T: Config> Pallet<T> {
+        /// Revoke a permission from a holder.
+        ///
+        /// Can be called by the root (governance) or by an owner of the permission in the scope.
+        ///
+        /// - `origin`: the root or the permission owner,
+        /// - `holder_id`: account to revoke the permission from,
+        /// - `permission_id`: permission to revoke,
+        /// - `scope`: scope the permission was granted in.
+        #[pallet::call_index(0)]
+        #[pallet::weight(<T as Config>::WeightInfo::revoke_permission())]
+        pub fn revoke_permission(
+            origin: OriginFor<T>,
+            holder_id: HolderId<T>,
+            permission_id: PermissionId,
+            scope: Scope,
+        ) -> DispatchResult {
+            if let Some(who) = ensure_signed_or_root(origin)? {
+                Self::ensure_permission_owner(&who, permission_id, &scope)?;
+            }
+            Self::revoke_permission_from(&holder_id, permission_id, scope)?;
+            Self::deposit_event(Event::<T>::PermissionRevoked(permission_id, holder_id));
+            Ok(())
+        }
+
+        /// Grant a permission to a holder until the given block, when it is revoked automatically.
+        /// Granting it again replaces the previous expiration.
+        ///
+        /// Can be called by the root (governance) or by an owner of the permission in the scope.
+        ///
+        /// - `origin`: the root or the permission owner,
+        /// - `holder_id`: account to grant the permission to,
+        /// - `permission_id`: permission to grant, it must have an owner,
+        /// - `scope`: scope to grant the permission in,
+        /// - `expires_at`: block at which the permission is revoked.
+        #[pallet::call_index(1)]
+        #[pallet::weight(<T as Config>::WeightInfo::grant_permission_with_expiry())]
+        pub fn grant_permission_with_expiry(
+            origin: OriginFor<T>,
+            holder_id: HolderId<T>,
+            permission_id: PermissionId,
+            scope: Scope,
+            expires_at: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            match ensure_signed_or_root(origin)? {
+                Some(who) => Self::ensure_permission_owner(&who, permission_id, &scope)?,
+                None => ensure!(
+                    !Owners::<T>::get(permission_id, scope).is_empty()
+                        || !Owners::<T>::get(permission_id, Scope::Unlimited).is_empty(),
+                    Error::<T>::PermissionNotFound
+                ),
+            }
+            ensure!(
+                expires_at > frame_system::Pallet::<T>::block_number(),
+                Error::<T>::InvalidExpiration
+            );
+            Self::add_holder_permission(&holder_id, permission_id, scope)?;
+            ExpiringPermissions::<T>::try_mutate(expires_at, |expiring| {
+                expiring
+                    .try_push((holder_id.clone(), scope, permission_id))
+                    .map_err(|_| Error::<T>::TooManyExpiringPermissions)
+            })?;
+            PermissionExpirations::<T>::insert((&holder_id, scope, permission_id), expires_at);
+            Self::deposit_event(Event::<T>::PermissionGrantedWithExpiry(
+                permission_id,
+                holder_id,
+                expires_at,
+            ));
+            Ok(())
+        }
+    }
 
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -292,6 +499,12 @@ pub mod pallet {
         PermissionCreated(u32, AccountIdOf<T>),
         /// Permission was assigned to the account in the scope. [permission, who]
         PermissionAssigned(u32, AccountIdOf<T>),
+        /// Permission was revoked from a holder. [permission, who]
+        PermissionRevoked(u32, AccountIdOf<T>),
+        /// Permission was granted to a holder until the block. [permission, who, expires at]
+        PermissionGrantedWithExpiry(u32, AccountIdOf<T>, BlockNumberFor<T>),
+        /// Time-limited permission has expired and was revoked. [permission, who]
+        PermissionExpired(u32, AccountIdOf<T>),
     }
 
     #[pallet::error]
@@ -306,6 +519,10 @@ pub mod pallet {
         Forbidden,
         /// Increment account reference error.
         IncRefError,
+        /// Expiration block must be in the future.
+        InvalidExpiration,
+        /// Too many permissions already expire in the block.
+        TooManyExpiringPermissions,
     }
 
     #[pallet::storage]
@@ -330,6 +547,42 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// Holders of a permission in a scope, the reverse index of `Permissions`.
+    #[pallet::storage]
+    pub type PermissionHolders<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_256, PermissionId>,
+            NMapKey<Blake2_256, Scope>,
+            NMapKey<Blake2_128Concat, HolderId<T>>,
+        ),
+        (),
+        OptionQuery,
+    >;
+
+    /// Expiration blocks of time-limited permissions.
+    #[pallet::storage]
+    pub type PermissionExpirations<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, HolderId<T>>,
+            NMapKey<Blake2_128Concat, Scope>,
+            NMapKey<Identity, PermissionId>,
+        ),
+        BlockNumberFor<T>,
+        OptionQuery,
+    >;
+
+    /// Time-limited permissions to be revoked at the block.
+    #[pallet::storage]
+    pub type ExpiringPermissions<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<(HolderId<T>, Scope, PermissionId), T::MaxExpiringPermissionsPerBlock>,
+        ValueQuery,
+    >;
+
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
         pub initial_permission_owners: Vec<(PermissionId, Scope, Vec<OwnerId<T>>)>,
@@ -379,6 +632,9 @@ pub mod pallet {
                         );
                         return;
                     }
+                    for permission_id in &permissions {
+                        PermissionHolders::<T>::insert((permission_id, scope, holder_id), ());
+                    }
                     Permissions::<T>::insert(holder_id, scope, permissions);
                 });
         }
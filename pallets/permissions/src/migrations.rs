@@ -0,0 +1,282 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+pub mod v2 {
+    use crate::{
+        Config, HolderId, OwnerId, Owners, Pallet, PermissionExpirations, PermissionHolders,
+        Permissions,
+    };
+    use codec::{Decode, Encode, MaxEncodedLen};
+    use common::permissions::PermissionId;
+    use core::marker::PhantomData;
+    use frame_support::migrations::{MigrationId, SteppedMigration, SteppedMigrationError};
+    use frame_support::pallet_prelude::*;
+    use frame_support::storage::{unhashed, KeyPrefixIterator, PrefixIterator, StoragePrefixedMap};
+    use frame_support::weights::WeightMeter;
+    use frame_support::{__private::log::info, traits::StorageVersion, StorageHasher};
+    use sp_std::vec::Vec;
+
+    const PALLET_MIGRATIONS_ID: &[u8; 11] = b"permissions";
+
+    /// Max length of the raw storage key kept in the cursor
+    type RawKey = BoundedVec<u8, ConstU32<256>>;
+
+    /// Storage which holders are taken from and the last raw key processed in it.
+    #[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, Eq, RuntimeDebug)]
+    pub enum MigrationCursor {
+        /// Owners of permissions, who are holders of the permissions they grant
+        Owners(RawKey),
+        /// Holders of time-limited permissions
+        Expirations(RawKey),
+        /// Holders which are only known as accounts
+        Accounts(RawKey),
+    }
+
+    /// Calls `f` with the hashed scope and the permission id of every permission of the holder.
+    /// Returns the number of storage reads.
+    ///
+    /// `Permissions` keys are not reversible, so scopes are only available hashed with `Blake2_256`.
+    fn for_each_permission<T: Config>(
+        encoded_holder: &[u8],
+        mut f: impl FnMut(&[u8], PermissionId),
+    ) -> u64 {
+        let mut reads = 1;
+        let mut prefix = Permissions::<T>::final_prefix().to_vec();
+        prefix.extend_from_slice(&Blake2_256::hash(encoded_holder));
+        let scope_hashes: Vec<Vec<u8>> =
+            KeyPrefixIterator::new(prefix.clone(), prefix.clone(), |scope_hash| {
+                Ok(scope_hash.to_vec())
+            })
+            .collect();
+        for scope_hash in scope_hashes {
+            reads += 1;
+            let mut key = prefix.clone();
+            key.extend_from_slice(&scope_hash);
+            let permission_ids: Vec<PermissionId> = unhashed::get(&key).unwrap_or_default();
+            for permission_id in permission_ids {
+                f(&scope_hash, permission_id);
+            }
+        }
+        reads
+    }
+
+    /// Raw `PermissionHolders` key of the holder's permission.
+    fn holder_index_key<T: Config>(
+        encoded_holder: &[u8],
+        scope_hash: &[u8],
+        permission_id: PermissionId,
+    ) -> Vec<u8> {
+        let mut key = PermissionHolders::<T>::final_prefix().to_vec();
+        key.extend_from_slice(&Blake2_256::hash(&permission_id.encode()));
+        key.extend_from_slice(scope_hash);
+        key.extend_from_slice(&Blake2_128Concat::hash(encoded_holder));
+        key
+    }
+
+    /// Indexes all permissions of the holder, returns the weight used.
+    fn index_holder<T: Config>(holder_id: &HolderId<T>) -> Weight {
+        let encoded_holder = holder_id.encode();
+        let mut writes = 0;
+        let reads = for_each_permission::<T>(&encoded_holder, |scope_hash, permission_id| {
+            unhashed::put(
+                &holder_index_key::<T>(&encoded_holder, scope_hash, permission_id),
+                &(),
+            );
+            writes += 1;
+        });
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+
+    /// Builds the `PermissionHolders` reverse index from `Permissions` over multiple blocks.
+    ///
+    /// `Permissions` keys are hashed with `Blake2_256`, so holders can't be decoded from them.
+    /// Holders are taken from the owners of permissions and time-limited permissions first,
+    /// then from the remaining accounts. Index keys are written raw since the scopes can't be
+    /// decoded back from `Permissions`; both maps hash the scope with `Blake2_256`,
+    /// so the hashed scope is copied as is.
+    pub struct LazyMigrate<T>(PhantomData<T>);
+
+    impl<T: Config> LazyMigrate<T> {
+        /// Min weight of a step, which indexes a holder with a single scope and permission
+        fn step_weight() -> Weight {
+            T::DbWeight::get().reads_writes(3, 1)
+        }
+
+        /// Consumes the weight used, a holder with many permissions may use more than the meter has left
+        fn consume(meter: &mut WeightMeter, weight: Weight) {
+            meter.consume(weight.min(meter.remaining()));
+        }
+
+        fn raw_key(key: &[u8]) -> Result<RawKey, SteppedMigrationError> {
+            RawKey::try_from(key.to_vec()).map_err(|_| SteppedMigrationError::Failed)
+        }
+
+        /// Indexes the holders of the next owners entry
+        fn owners_step(
+            last_key: Option<&RawKey>,
+            meter: &mut WeightMeter,
+        ) -> Result<Option<MigrationCursor>, SteppedMigrationError> {
+            let prefix = Owners::<T>::final_prefix().to_vec();
+            let previous_key = last_key.map(|key| key.to_vec()).unwrap_or(prefix.clone());
+            let mut iter =
+                PrefixIterator::<Vec<OwnerId<T>>>::new(prefix, previous_key, |_, mut value| {
+                    Vec::<OwnerId<T>>::decode(&mut value)
+                });
+            Self::consume(meter, T::DbWeight::get().reads(1));
+            let Some(owners) = iter.next() else {
+                return Ok(Some(MigrationCursor::Expirations(Self::raw_key(
+                    &PermissionExpirations::<T>::final_prefix(),
+                )?)));
+            };
+            for owner in owners.iter() {
+                Self::consume(meter, index_holder::<T>(owner));
+            }
+            Ok(Some(MigrationCursor::Owners(Self::raw_key(
+                iter.last_raw_key(),
+            )?)))
+        }
+
+        /// Indexes the holder of the next time-limited permission
+        fn expirations_step(
+            last_key: &RawKey,
+            meter: &mut WeightMeter,
+        ) -> Result<Option<MigrationCursor>, SteppedMigrationError> {
+            let mut iter = PermissionExpirations::<T>::iter_keys_from(last_key.to_vec());
+            Self::consume(meter, T::DbWeight::get().reads(1));
+            let Some((holder_id, _, _)) = iter.next() else {
+                return Ok(Some(MigrationCursor::Accounts(Self::raw_key(
+                    &frame_system::Account::<T>::final_prefix(),
+                )?)));
+            };
+            Self::consume(meter, index_holder::<T>(&holder_id));
+            Ok(Some(MigrationCursor::Expirations(Self::raw_key(
+                iter.last_raw_key(),
+            )?)))
+        }
+
+        /// Indexes the next account, the migration is finished when there are no accounts left
+        fn accounts_step(
+            last_key: &RawKey,
+            meter: &mut WeightMeter,
+        ) -> Result<Option<MigrationCursor>, SteppedMigrationError> {
+            let mut iter = frame_system::Account::<T>::iter_keys_from(last_key.to_vec());
+            Self::consume(meter, T::DbWeight::get().reads(1));
+            let Some(holder_id) = iter.next() else {
+                return Ok(None);
+            };
+            Self::consume(meter, index_holder::<T>(&holder_id));
+            Ok(Some(MigrationCursor::Accounts(Self::raw_key(
+                iter.last_raw_key(),
+            )?)))
+        }
+    }
+
+    impl<T: Config> SteppedMigration for LazyMigrate<T> {
+        type Cursor = MigrationCursor;
+        type Identifier = MigrationId<11>;
+
+        fn id() -> Self::Identifier {
+            MigrationId {
+                pallet_id: *PALLET_MIGRATIONS_ID,
+                version_from: 1,
+                version_to: 2,
+            }
+        }
+
+        fn step(
+            mut cursor: Option<Self::Cursor>,
+            meter: &mut WeightMeter,
+        ) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+            if StorageVersion::get::<Pallet<T>>() != StorageVersion::new(1) {
+                return Ok(None);
+            }
+
+            let required = Self::step_weight();
+            if meter.remaining().any_lt(required) {
+                return Err(SteppedMigrationError::InsufficientWeight { required });
+            }
+
+            while meter.can_consume(required) {
+                let next = match cursor.as_ref() {
+                    None => Self::owners_step(None, meter)?,
+                    Some(MigrationCursor::Owners(last_key)) => {
+                        Self::owners_step(Some(last_key), meter)?
+                    }
+                    Some(MigrationCursor::Expirations(last_key)) => {
+                        Self::expirations_step(last_key, meter)?
+                    }
+                    Some(MigrationCursor::Accounts(last_key)) => {
+                        Self::accounts_step(last_key, meter)?
+                    }
+                };
+                let Some(next) = next else {
+                    StorageVersion::new(2).put::<Pallet<T>>();
+                    info!("permissions v2 applied successfully, permission holders indexed.");
+                    return Ok(None);
+                };
+                cursor = Some(next);
+            }
+
+            Ok(cursor)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+            frame_support::ensure!(
+                StorageVersion::get::<Pallet<T>>() == StorageVersion::new(1),
+                "Wrong storage version before permissions v2 upgrade"
+            );
+            Ok(Vec::new())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+            frame_support::ensure!(
+                StorageVersion::get::<Pallet<T>>() == StorageVersion::new(2),
+                "Wrong storage version after permissions v2 upgrade"
+            );
+            // every permission in `Permissions` must be indexed
+            let prefix = Permissions::<T>::final_prefix().to_vec();
+            let permissions =
+                PrefixIterator::<Vec<PermissionId>>::new(prefix.clone(), prefix, |_, mut value| {
+                    Vec::<PermissionId>::decode(&mut value)
+                })
+                .map(|permission_ids| permission_ids.len() as u64)
+                .sum::<u64>();
+            let prefix = PermissionHolders::<T>::final_prefix().to_vec();
+            let indexed = KeyPrefixIterator::new(prefix.clone(), prefix, |_| Ok(())).count() as u64;
+            frame_support::ensure!(
+                indexed == permissions,
+                "Some permission holders were not indexed during permissions v2 upgrade"
+            );
+            Ok(())
+        }
+    }
+}
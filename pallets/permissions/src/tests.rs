@@ -31,6 +31,7 @@
 use crate::mock::*;
 use crate::*;
 use frame_support::assert_ok;
+use frame_support::traits::Hooks;
 use sp_core::hash::H512;
 
 type Permissions = Pallet<Runtime>;
@@ -311,3 +312,280 @@ fn genesis_should_skip_entries_when_inc_consumers_fails() {
             ));
         });
 }
+
+#[test]
+fn revoke_permission_by_owner_passes() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        assert_eq!(
+            Permissions::revoke_permission(
+                RuntimeOrigin::signed(JOHN),
+                BOB,
+                BURN,
+                Scope::Unlimited
+            ),
+            Err(Error::<Runtime>::PermissionNotOwned.into())
+        );
+        assert_ok!(Permissions::revoke_permission(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            BURN,
+            Scope::Unlimited
+        ));
+        frame_system::Pallet::<Runtime>::assert_last_event(
+            Event::<Runtime>::PermissionRevoked(BURN, BOB).into(),
+        );
+        assert_eq!(
+            Permissions::check_permission(BOB, BURN),
+            Err(Error::<Runtime>::Forbidden)
+        );
+        assert_ok!(Permissions::check_permission(BOB, INIT_DEX));
+        assert_eq!(
+            Permissions::revoke_permission(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                BURN,
+                Scope::Unlimited
+            ),
+            Err(Error::<Runtime>::PermissionNotFound.into())
+        );
+    });
+}
+
+#[test]
+fn revoke_permission_by_root_passes() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let consumers = frame_system::Pallet::<Runtime>::consumers(&JOHN);
+        assert_ok!(Permissions::revoke_permission(
+            RuntimeOrigin::root(),
+            JOHN,
+            MINT,
+            Scope::Unlimited
+        ));
+        assert_eq!(
+            Permissions::check_permission(JOHN, MINT),
+            Err(Error::<Runtime>::Forbidden)
+        );
+        assert_eq!(
+            frame_system::Pallet::<Runtime>::consumers(&JOHN),
+            consumers - 1
+        );
+    });
+}
+
+#[test]
+fn grant_permission_with_expiry_passes() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        assert_eq!(
+            Permissions::grant_permission_with_expiry(
+                RuntimeOrigin::signed(ALICE),
+                JOHN,
+                BURN,
+                Scope::Unlimited,
+                1
+            ),
+            Err(Error::<Runtime>::InvalidExpiration.into())
+        );
+        assert_eq!(
+            Permissions::grant_permission_with_expiry(
+                RuntimeOrigin::signed(JOHN),
+                JOHN,
+                BURN,
+                Scope::Unlimited,
+                5
+            ),
+            Err(Error::<Runtime>::PermissionNotOwned.into())
+        );
+        assert_ok!(Permissions::grant_permission_with_expiry(
+            RuntimeOrigin::signed(ALICE),
+            JOHN,
+            BURN,
+            Scope::Unlimited,
+            5
+        ));
+        frame_system::Pallet::<Runtime>::assert_last_event(
+            Event::<Runtime>::PermissionGrantedWithExpiry(BURN, JOHN, 5).into(),
+        );
+        assert_ok!(Permissions::check_permission(JOHN, BURN));
+        assert_eq!(
+            PermissionExpirations::<Runtime>::get((&JOHN, Scope::Unlimited, BURN)),
+            Some(5)
+        );
+
+        frame_system::Pallet::<Runtime>::set_block_number(4);
+        Permissions::on_initialize(4);
+        assert_ok!(Permissions::check_permission(JOHN, BURN));
+
+        frame_system::Pallet::<Runtime>::set_block_number(5);
+        Permissions::on_initialize(5);
+        frame_system::Pallet::<Runtime>::assert_last_event(
+            Event::<Runtime>::PermissionExpired(BURN, JOHN).into(),
+        );
+        assert_eq!(
+            Permissions::check_permission(JOHN, BURN),
+            Err(Error::<Runtime>::Forbidden)
+        );
+        assert_ok!(Permissions::check_permission(JOHN, MINT));
+        assert_eq!(
+            PermissionExpirations::<Runtime>::get((&JOHN, Scope::Unlimited, BURN)),
+            None
+        );
+        assert!(ExpiringPermissions::<Runtime>::get(5).is_empty());
+    });
+}
+
+#[test]
+fn regrant_permission_cancels_expiry() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        assert_ok!(Permissions::grant_permission_with_expiry(
+            RuntimeOrigin::root(),
+            JOHN,
+            BURN,
+            Scope::Unlimited,
+            5
+        ));
+        assert_ok!(Permissions::grant_permission(ALICE, JOHN, BURN));
+        assert_eq!(
+            PermissionExpirations::<Runtime>::get((&JOHN, Scope::Unlimited, BURN)),
+            None
+        );
+        Permissions::on_initialize(5);
+        assert_ok!(Permissions::check_permission(JOHN, BURN));
+
+        assert_eq!(
+            Permissions::grant_permission_with_expiry(
+                RuntimeOrigin::root(),
+                JOHN,
+                CUSTOM_PERMISSION,
+                Scope::Unlimited,
+                5
+            ),
+            Err(Error::<Runtime>::PermissionNotFound.into())
+        );
+    });
+}
+
+#[test]
+fn list_permission_holders_and_owners_passes() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        assert_ok!(Permissions::grant_permission_with_expiry(
+            RuntimeOrigin::signed(ALICE),
+            JOHN,
+            BURN,
+            Scope::Unlimited,
+            10
+        ));
+        let mut holders = Permissions::list_permission_holders(BURN, Scope::Unlimited);
+        holders.sort();
+        assert_eq!(holders, vec![(BOB, None), (JOHN, Some(10))]);
+        assert_eq!(
+            Permissions::list_permission_holders(MINT, Scope::Unlimited),
+            vec![(JOHN, None)]
+        );
+        assert_eq!(
+            Permissions::list_permission_owners(BURN, Scope::Unlimited),
+            vec![ALICE]
+        );
+        assert!(
+            Permissions::list_permission_holders(BURN, Scope::Limited(H512::repeat_byte(1)))
+                .is_empty()
+        );
+
+        assert_ok!(Permissions::revoke_permission(
+            RuntimeOrigin::signed(ALICE),
+            JOHN,
+            BURN,
+            Scope::Unlimited
+        ));
+        assert_eq!(
+            Permissions::list_permission_holders(BURN, Scope::Unlimited),
+            vec![(BOB, None)]
+        );
+    });
+}
+
+#[test]
+fn regrant_permission_with_expiry_replaces_expiry() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        assert_ok!(Permissions::grant_permission_with_expiry(
+            RuntimeOrigin::signed(ALICE),
+            JOHN,
+            BURN,
+            Scope::Unlimited,
+            5
+        ));
+        assert_ok!(Permissions::grant_permission_with_expiry(
+            RuntimeOrigin::signed(ALICE),
+            JOHN,
+            BURN,
+            Scope::Unlimited,
+            5
+        ));
+        assert_eq!(ExpiringPermissions::<Runtime>::get(5).len(), 1);
+
+        assert_ok!(Permissions::grant_permission_with_expiry(
+            RuntimeOrigin::signed(ALICE),
+            JOHN,
+            BURN,
+            Scope::Unlimited,
+            7
+        ));
+        assert!(ExpiringPermissions::<Runtime>::get(5).is_empty());
+        assert_eq!(
+            ExpiringPermissions::<Runtime>::get(7).into_inner(),
+            vec![(JOHN, Scope::Unlimited, BURN)]
+        );
+
+        Permissions::on_initialize(5);
+        assert_ok!(Permissions::check_permission(JOHN, BURN));
+        Permissions::on_initialize(7);
+        assert_eq!(
+            Permissions::check_permission(JOHN, BURN),
+            Err(Error::<Runtime>::Forbidden)
+        );
+    });
+}
+
+#[test]
+fn grant_permission_with_expiry_fails_when_block_is_full() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        for (holder_id, permission_id) in [(JOHN, BURN), (BOB, BURN), (ALICE, BURN)] {
+            assert_ok!(Permissions::grant_permission_with_expiry(
+                RuntimeOrigin::signed(ALICE),
+                holder_id,
+                permission_id,
+                Scope::Unlimited,
+                5
+            ));
+        }
+        assert_eq!(
+            Permissions::grant_permission_with_expiry(
+                RuntimeOrigin::signed(ALICE),
+                JOHN,
+                SLASH,
+                Scope::Unlimited,
+                5
+            ),
+            Err(Error::<Runtime>::TooManyExpiringPermissions.into())
+        );
+        assert_ok!(Permissions::grant_permission_with_expiry(
+            RuntimeOrigin::signed(ALICE),
+            JOHN,
+            SLASH,
+            Scope::Unlimited,
+            6
+        ));
+    });
+}
@@ -0,0 +1,194 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Autogenerated weights for permissions
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2026-10-18, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `983eb2919397`, CPU: `Intel(R) Xeon(R) CPU E3-1240 v6 @ 3.70GHz`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("local"), DB CACHE: 1024
+
+// Executed Command:
+// /usr/local/bin/framenode
+// benchmark
+// pallet
+// --chain=local
+// --steps=50
+// --repeat=20
+// --pallet=permissions
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --header=./misc/file_header.txt
+// --template=./misc/pallet-weight-template.hbs
+// --output=./pallets/permissions/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for permissions.
+pub trait WeightInfo {
+	fn revoke_permission() -> Weight;
+	fn grant_permission_with_expiry() -> Weight;
+	fn on_initialize(n: u32, ) -> Weight;
+}
+
+/// Weights for permissions using the Substrate node and recommended hardware.
+#[allow(dead_code)]
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: Permissions Owners (r:1 w:0)
+	/// Proof Skipped: Permissions Owners (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Permissions Permissions (r:2 w:1)
+	/// Proof Skipped: Permissions Permissions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Permissions PermissionExpirations (r:0 w:1)
+	/// Proof Skipped: Permissions PermissionExpirations (max_values: None, max_size: None, mode: Measured)
+	fn revoke_permission() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `10102`
+		// Minimum execution time: 41_208_000 picoseconds.
+		Weight::from_parts(41_932_000, 10102)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Permissions Owners (r:1 w:0)
+	/// Proof Skipped: Permissions Owners (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Permissions Permissions (r:2 w:1)
+	/// Proof Skipped: Permissions Permissions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Permissions PermissionExpirations (r:0 w:1)
+	/// Proof Skipped: Permissions PermissionExpirations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Permissions ExpiringPermissions (r:1 w:1)
+	/// Proof Skipped: Permissions ExpiringPermissions (max_values: None, max_size: None, mode: Measured)
+	fn grant_permission_with_expiry() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `343`
+		//  Estimated: `11235`
+		// Minimum execution time: 46_871_000 picoseconds.
+		Weight::from_parts(47_395_000, 11235)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Permissions ExpiringPermissions (r:1 w:1)
+	/// Proof Skipped: Permissions ExpiringPermissions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Permissions PermissionExpirations (r:100 w:100)
+	/// Proof Skipped: Permissions PermissionExpirations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Permissions Permissions (r:200 w:100)
+	/// Proof Skipped: Permissions Permissions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:100 w:100)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 100]`.
+	fn on_initialize(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `132 + n * (171 ±0)`
+		//  Estimated: `3597 + n * (7738 ±0)`
+		// Minimum execution time: 5_412_000 picoseconds.
+		Weight::from_parts(6_018_000, 3597)
+			// Standard Error: 21_384
+			.saturating_add(Weight::from_parts(31_764_518, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 7738).saturating_mul(n.into()))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: Permissions Owners (r:1 w:0)
+	/// Proof Skipped: Permissions Owners (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Permissions Permissions (r:2 w:1)
+	/// Proof Skipped: Permissions Permissions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Permissions PermissionExpirations (r:0 w:1)
+	/// Proof Skipped: Permissions PermissionExpirations (max_values: None, max_size: None, mode: Measured)
+	fn revoke_permission() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `10102`
+		// Minimum execution time: 41_208_000 picoseconds.
+		Weight::from_parts(41_932_000, 10102)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Permissions Owners (r:1 w:0)
+	/// Proof Skipped: Permissions Owners (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Permissions Permissions (r:2 w:1)
+	/// Proof Skipped: Permissions Permissions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Permissions PermissionExpirations (r:0 w:1)
+	/// Proof Skipped: Permissions PermissionExpirations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Permissions ExpiringPermissions (r:1 w:1)
+	/// Proof Skipped: Permissions ExpiringPermissions (max_values: None, max_size: None, mode: Measured)
+	fn grant_permission_with_expiry() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `343`
+		//  Estimated: `11235`
+		// Minimum execution time: 46_871_000 picoseconds.
+		Weight::from_parts(47_395_000, 11235)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Permissions ExpiringPermissions (r:1 w:1)
+	/// Proof Skipped: Permissions ExpiringPermissions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Permissions PermissionExpirations (r:100 w:100)
+	/// Proof Skipped: Permissions PermissionExpirations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Permissions Permissions (r:200 w:100)
+	/// Proof Skipped: Permissions Permissions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:100 w:100)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 100]`.
+	fn on_initialize(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `132 + n * (171 ±0)`
+		//  Estimated: `3597 + n * (7738 ±0)`
+		// Minimum execution time: 5_412_000 picoseconds.
+		Weight::from_parts(6_018_000, 3597)
+			// Standard Error: 21_384
+			.saturating_add(Weight::from_parts(31_764_518, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 7738).saturating_mul(n.into()))
+	}
+}
@@ -0,0 +1,174 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use codec::Codec;
+
+use jsonrpsee::{core::RpcResult as Result, proc_macros::rpc, types::ErrorObjectOwned};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::{Block as BlockT, MaybeDisplay, MaybeFromStr};
+
+use std::sync::Arc;
+
+fn runtime_error_into_rpc_error(error: impl core::fmt::Debug) -> ErrorObjectOwned {
+    ErrorObjectOwned::owned(1, "Runtime error", Some(format!("{error:?}")))
+}
+
+// Runtime API imports.
+pub use presto_runtime_api::PrestoAPI as PrestoRuntimeAPI;
+use presto_runtime_api::{CouponHoldingInfo, CropReceiptInfo, RequestInfo, TreasuryInfo};
+
+#[rpc(server)]
+pub trait PrestoAPI<
+    BlockHash,
+    AccountId,
+    VecCropReceiptInfo,
+    VecRequestInfo,
+    VecCouponHoldingInfo,
+    OptionTreasuryInfo,
+>
+{
+    #[method(name = "presto_listCropReceipts")]
+    fn list_crop_receipts(
+        &self,
+        status: Option<String>,
+        country: Option<String>,
+        rating: Option<String>,
+        at: Option<BlockHash>,
+    ) -> Result<VecCropReceiptInfo>;
+
+    #[method(name = "presto_userRequests")]
+    fn user_requests(&self, account_id: AccountId, at: Option<BlockHash>)
+        -> Result<VecRequestInfo>;
+
+    #[method(name = "presto_couponHoldings")]
+    fn coupon_holdings(
+        &self,
+        account_id: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<VecCouponHoldingInfo>;
+
+    #[method(name = "presto_treasury")]
+    fn treasury(&self, at: Option<BlockHash>) -> Result<OptionTreasuryInfo>;
+}
+
+pub struct PrestoClient<C, B> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> PrestoClient<C, B> {
+    /// Construct default `PrestoClient`.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block, AccountId, AssetId, Balance, CropReceiptId, RequestId, Moment>
+    PrestoAPIServer<
+        <Block as BlockT>::Hash,
+        AccountId,
+        Vec<CropReceiptInfo<AccountId, AssetId, Balance, CropReceiptId, Moment>>,
+        Vec<RequestInfo<AccountId, Balance, RequestId, Moment>>,
+        Vec<CouponHoldingInfo<AssetId, Balance, CropReceiptId>>,
+        Option<TreasuryInfo<Balance>>,
+    > for PrestoClient<C, Block>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: PrestoRuntimeAPI<Block, AccountId, AssetId, Balance, CropReceiptId, RequestId, Moment>,
+    AccountId: Codec,
+    AssetId: Codec,
+    Balance: Codec + MaybeFromStr + MaybeDisplay,
+    CropReceiptId: Codec,
+    RequestId: Codec,
+    Moment: Codec,
+{
+    fn list_crop_receipts(
+        &self,
+        status: Option<String>,
+        country: Option<String>,
+        rating: Option<String>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<CropReceiptInfo<AccountId, AssetId, Balance, CropReceiptId, Moment>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        );
+        api.list_crop_receipts(at, status, country, rating)
+            .map_err(|e| runtime_error_into_rpc_error(e))
+    }
+
+    fn user_requests(
+        &self,
+        account_id: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<RequestInfo<AccountId, Balance, RequestId, Moment>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        );
+        api.user_requests(at, account_id)
+            .map_err(|e| runtime_error_into_rpc_error(e))
+    }
+
+    fn coupon_holdings(
+        &self,
+        account_id: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<CouponHoldingInfo<AssetId, Balance, CropReceiptId>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        );
+        api.coupon_holdings(at, account_id)
+            .map_err(|e| runtime_error_into_rpc_error(e))
+    }
+
+    fn treasury(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Option<TreasuryInfo<Balance>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        );
+        api.treasury(at)
+            .map_err(|e| runtime_error_into_rpc_error(e))
+    }
+}
@@ -0,0 +1,280 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::unnecessary_mut_passed)]
+
+extern crate alloc;
+
+use alloc::string::String;
+use codec::{Codec, Decode, Encode};
+#[cfg(feature = "std")]
+use common::utils::{string_serialization, string_serialization_opt};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::traits::{MaybeDisplay, MaybeFromStr};
+use sp_std::prelude::*;
+
+#[derive(Clone, Eq, PartialEq, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct CropReceiptInfo<AccountId, AssetId, Balance, CropReceiptId, Moment> {
+    pub id: CropReceiptId,
+    pub owner: AccountId,
+    /// `Rating`, `Decision`, `Declined`, `Published`, `Closed`, `Overdue` or `Defaulted`
+    pub status: String,
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization"
+        )
+    )]
+    pub amount: Balance,
+    /// Profit in parts per million
+    pub profit: u32,
+    pub country: String,
+    pub rating: Option<String>,
+    pub rated_by: Option<AccountId>,
+    pub time: Moment,
+    pub close_initial_period: Moment,
+    pub date_of_issue: Moment,
+    pub perfomance_time: Moment,
+    pub coupon_asset_id: Option<AssetId>,
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization"
+        )
+    )]
+    pub repaid: Balance,
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization"
+        )
+    )]
+    pub recovered: Balance,
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization"
+        )
+    )]
+    pub refunded: Balance,
+    /// Not defined until the crop receipt is published
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization_opt"
+        )
+    )]
+    pub outstanding_debt: Option<Balance>,
+}
+
+#[derive(Clone, Eq, PartialEq, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct RequestInfo<AccountId, Balance, RequestId, Moment> {
+    pub id: RequestId,
+    /// `Deposit` or `Withdraw`
+    pub kind: String,
+    pub owner: AccountId,
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization"
+        )
+    )]
+    pub amount: Balance,
+    pub time: Moment,
+    /// `Pending`, `Cancelled`, `Approved` or `Declined`
+    pub status: String,
+    pub processed_by: Option<AccountId>,
+    pub processed_at: Option<Moment>,
+}
+
+#[derive(Clone, Eq, PartialEq, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct CouponHoldingInfo<AssetId, Balance, CropReceiptId> {
+    pub coupon_asset_id: AssetId,
+    pub crop_receipt_id: CropReceiptId,
+    pub crop_receipt_status: String,
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization"
+        )
+    )]
+    pub amount: Balance,
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization"
+        )
+    )]
+    pub refund_price: Balance,
+    /// Refund for the coupons once the crop receipt is fully paid off
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization"
+        )
+    )]
+    pub expected_refund: Balance,
+    /// Refund for the coupons that can be claimed right now
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization"
+        )
+    )]
+    pub claimable_refund: Balance,
+}
+
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Default, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct TreasuryInfo<Balance> {
+    /// PRUSD held by the main treasury account
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization"
+        )
+    )]
+    pub treasury: Balance,
+    /// PRUSD held by the buffer account for withdraw requests and refunds
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization"
+        )
+    )]
+    pub buffer: Balance,
+    /// Total PRUSD supply
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization"
+        )
+    )]
+    pub total_supply: Balance,
+    /// Debt of published crop receipts which is not covered yet
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization"
+        )
+    )]
+    pub outstanding_debt: Balance,
+}
+
+sp_api::decl_runtime_apis! {
+    pub trait PrestoAPI<AccountId, AssetId, Balance, CropReceiptId, RequestId, Moment> where
+        AccountId: Codec,
+        AssetId: Codec,
+        Balance: Codec + MaybeFromStr + MaybeDisplay,
+        CropReceiptId: Codec,
+        RequestId: Codec,
+        Moment: Codec,
+    {
+        /// Crop receipts matching all provided filters, the filters are the labels of the variants
+        fn list_crop_receipts(
+            status: Option<String>,
+            country: Option<String>,
+            rating: Option<String>,
+        ) -> Vec<CropReceiptInfo<AccountId, AssetId, Balance, CropReceiptId, Moment>>;
+
+        fn user_requests(account_id: AccountId) -> Vec<RequestInfo<AccountId, Balance, RequestId, Moment>>;
+
+        fn coupon_holdings(account_id: AccountId) -> Vec<CouponHoldingInfo<AssetId, Balance, CropReceiptId>>;
+
+        fn treasury() -> Option<TreasuryInfo<Balance>>;
+    }
+}
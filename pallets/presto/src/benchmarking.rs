@@ -32,7 +32,7 @@
 #![cfg(feature = "stage")] // presto
 
 use crate::crop_receipt::{crop_receipt_content_template, Country, Rating};
-use crate::{Config, Coupons, Event, Pallet};
+use crate::{Config, CouponOrderBooks, Coupons, CropReceipts, Event, Pallet};
 use codec::Decode;
 use common::{balance, AssetIdOf, AssetInfoProvider, BoundedString, DexIdOf};
 use frame_benchmarking::benchmarks;
@@ -378,7 +378,7 @@ benchmarks! {
 
         let supply = 1000;
     }: {
-        Pallet::<T>::publish_crop_receipt(RawOrigin::Signed(bob::<T>()).into(), crop_receipt_id, supply).unwrap();
+        Pallet::<T>::publish_crop_receipt(RawOrigin::Signed(bob::<T>()).into(), crop_receipt_id, supply, true).unwrap();
     }
     verify {
         let coupon_asset_id = Coupons::<T>::iter().collect::<Vec<_>>().first().unwrap().0;
@@ -406,7 +406,7 @@ benchmarks! {
         Pallet::<T>::rate_crop_receipt(RawOrigin::Signed(alice::<T>()).into(), crop_receipt_id, Rating::AA).unwrap();
 
         let supply = 1000;
-        Pallet::<T>::publish_crop_receipt(RawOrigin::Signed(bob::<T>()).into(), crop_receipt_id, supply).unwrap();
+        Pallet::<T>::publish_crop_receipt(RawOrigin::Signed(bob::<T>()).into(), crop_receipt_id, supply, true).unwrap();
 
         let debt = balance!(10500);
         Pallet::<T>::mint_presto_usd(RawOrigin::Signed(alice::<T>()).into(), debt).unwrap();
@@ -441,7 +441,7 @@ benchmarks! {
         Pallet::<T>::rate_crop_receipt(RawOrigin::Signed(alice::<T>()).into(), crop_receipt_id, Rating::AA).unwrap();
 
         let supply = 1000;
-        Pallet::<T>::publish_crop_receipt(RawOrigin::Signed(bob::<T>()).into(), crop_receipt_id, supply).unwrap();
+        Pallet::<T>::publish_crop_receipt(RawOrigin::Signed(bob::<T>()).into(), crop_receipt_id, supply, true).unwrap();
 
         let debt = balance!(10500);
         Pallet::<T>::mint_presto_usd(RawOrigin::Signed(alice::<T>()).into(), debt).unwrap();
@@ -470,6 +470,136 @@ benchmarks! {
         assert_eq!(Assets::<T>::free_balance(&T::PrestoUsdAssetId::get(), &charlie::<T>()).unwrap(), balance!(2100));
     }
 
+    repay_crop_receipt {
+        Pallet::<T>::add_presto_auditor(RawOrigin::Root.into(), alice::<T>()).unwrap();
+        Pallet::<T>::add_presto_manager(RawOrigin::Root.into(), alice::<T>()).unwrap();
+        Pallet::<T>::apply_creditor_kyc(RawOrigin::Signed(alice::<T>()).into(), bob::<T>()).unwrap();
+
+        let amount = balance!(10000);
+        let profit = Permill::from_percent(5);
+        let close_initial_period = 123u32.into();
+        let date_of_issue = 234u32.into();
+        let place_of_issue = BoundedString::truncate_from("place of issue");
+        let debtor = BoundedString::truncate_from("debtor");
+        let creditor = BoundedString::truncate_from("creditor");
+        let perfomance_time = 345u32.into();
+        let data = crop_receipt_content_template::<T>();
+        Pallet::<T>::create_crop_receipt(RawOrigin::Signed(bob::<T>()).into(), amount, profit, Country::Brazil, close_initial_period, date_of_issue, place_of_issue, debtor, creditor, perfomance_time, data).unwrap();
+
+        let crop_receipt_id = 1u32.into();
+
+        Pallet::<T>::rate_crop_receipt(RawOrigin::Signed(alice::<T>()).into(), crop_receipt_id, Rating::AA).unwrap();
+
+        let supply = 1000;
+        Pallet::<T>::publish_crop_receipt(RawOrigin::Signed(bob::<T>()).into(), crop_receipt_id, supply, true).unwrap();
+
+        let debt = balance!(10500);
+        Pallet::<T>::mint_presto_usd(RawOrigin::Signed(alice::<T>()).into(), debt).unwrap();
+        Pallet::<T>::send_presto_usd(RawOrigin::Signed(alice::<T>()).into(), debt, bob::<T>()).unwrap();
+    }: {
+        Pallet::<T>::repay_crop_receipt(RawOrigin::Signed(bob::<T>()).into(), crop_receipt_id, debt).unwrap();
+    }
+    verify {
+        assert_last_event::<T>(Event::<T>::CropReceiptClosed { id: crop_receipt_id }.into());
+        assert_eq!(Assets::<T>::free_balance(&T::PrestoUsdAssetId::get(), &bob::<T>()).unwrap(), 0);
+    }
+
+    declare_crop_receipt_default {
+        Pallet::<T>::add_presto_auditor(RawOrigin::Root.into(), alice::<T>()).unwrap();
+        Pallet::<T>::add_presto_manager(RawOrigin::Root.into(), alice::<T>()).unwrap();
+        Pallet::<T>::apply_creditor_kyc(RawOrigin::Signed(alice::<T>()).into(), bob::<T>()).unwrap();
+
+        let amount = balance!(10000);
+        let profit = Permill::from_percent(5);
+        let close_initial_period = 123u32.into();
+        let date_of_issue = 234u32.into();
+        let place_of_issue = BoundedString::truncate_from("place of issue");
+        let debtor = BoundedString::truncate_from("debtor");
+        let creditor = BoundedString::truncate_from("creditor");
+        let perfomance_time = 345u32.into();
+        let data = crop_receipt_content_template::<T>();
+        Pallet::<T>::create_crop_receipt(RawOrigin::Signed(bob::<T>()).into(), amount, profit, Country::Brazil, close_initial_period, date_of_issue, place_of_issue, debtor, creditor, perfomance_time, data).unwrap();
+
+        let crop_receipt_id = 1u32.into();
+
+        Pallet::<T>::rate_crop_receipt(RawOrigin::Signed(alice::<T>()).into(), crop_receipt_id, Rating::AA).unwrap();
+
+        let supply = 1000;
+        Pallet::<T>::publish_crop_receipt(RawOrigin::Signed(bob::<T>()).into(), crop_receipt_id, supply, true).unwrap();
+
+        CropReceipts::<T>::mutate(crop_receipt_id, |crop_receipt| crop_receipt.as_mut().unwrap().mark_overdue()).unwrap();
+    }: {
+        Pallet::<T>::declare_crop_receipt_default(RawOrigin::Signed(alice::<T>()).into(), crop_receipt_id).unwrap();
+    }
+    verify {
+        assert_last_event::<T>(Event::<T>::CropReceiptDefaulted { id: crop_receipt_id, by: alice::<T>() }.into());
+    }
+
+    record_crop_receipt_recovery {
+        Pallet::<T>::add_presto_auditor(RawOrigin::Root.into(), alice::<T>()).unwrap();
+        Pallet::<T>::add_presto_manager(RawOrigin::Root.into(), alice::<T>()).unwrap();
+        Pallet::<T>::apply_creditor_kyc(RawOrigin::Signed(alice::<T>()).into(), bob::<T>()).unwrap();
+
+        let amount = balance!(10000);
+        let profit = Permill::from_percent(5);
+        let close_initial_period = 123u32.into();
+        let date_of_issue = 234u32.into();
+        let place_of_issue = BoundedString::truncate_from("place of issue");
+        let debtor = BoundedString::truncate_from("debtor");
+        let creditor = BoundedString::truncate_from("creditor");
+        let perfomance_time = 345u32.into();
+        let data = crop_receipt_content_template::<T>();
+        Pallet::<T>::create_crop_receipt(RawOrigin::Signed(bob::<T>()).into(), amount, profit, Country::Brazil, close_initial_period, date_of_issue, place_of_issue, debtor, creditor, perfomance_time, data).unwrap();
+
+        let crop_receipt_id = 1u32.into();
+
+        Pallet::<T>::rate_crop_receipt(RawOrigin::Signed(alice::<T>()).into(), crop_receipt_id, Rating::AA).unwrap();
+
+        let supply = 1000;
+        Pallet::<T>::publish_crop_receipt(RawOrigin::Signed(bob::<T>()).into(), crop_receipt_id, supply, true).unwrap();
+
+        CropReceipts::<T>::mutate(crop_receipt_id, |crop_receipt| crop_receipt.as_mut().unwrap().mark_overdue()).unwrap();
+        Pallet::<T>::declare_crop_receipt_default(RawOrigin::Signed(alice::<T>()).into(), crop_receipt_id).unwrap();
+
+        let recovery = balance!(5000);
+        Pallet::<T>::mint_presto_usd(RawOrigin::Signed(alice::<T>()).into(), recovery).unwrap();
+    }: {
+        Pallet::<T>::record_crop_receipt_recovery(RawOrigin::Signed(alice::<T>()).into(), crop_receipt_id, recovery).unwrap();
+    }
+    verify {
+        assert_last_event::<T>(Event::<T>::CropReceiptRecoveryRecorded { id: crop_receipt_id, amount: recovery, by: alice::<T>() }.into());
+    }
+
+    list_coupons {
+        Pallet::<T>::add_presto_auditor(RawOrigin::Root.into(), alice::<T>()).unwrap();
+        Pallet::<T>::add_presto_manager(RawOrigin::Root.into(), alice::<T>()).unwrap();
+        Pallet::<T>::apply_creditor_kyc(RawOrigin::Signed(alice::<T>()).into(), bob::<T>()).unwrap();
+
+        let amount = balance!(10000);
+        let profit = Permill::from_percent(5);
+        let close_initial_period = 123u32.into();
+        let date_of_issue = 234u32.into();
+        let place_of_issue = BoundedString::truncate_from("place of issue");
+        let debtor = BoundedString::truncate_from("debtor");
+        let creditor = BoundedString::truncate_from("creditor");
+        let perfomance_time = 345u32.into();
+        let data = crop_receipt_content_template::<T>();
+        Pallet::<T>::create_crop_receipt(RawOrigin::Signed(bob::<T>()).into(), amount, profit, Country::Brazil, close_initial_period, date_of_issue, place_of_issue, debtor, creditor, perfomance_time, data).unwrap();
+
+        let crop_receipt_id = 1u32.into();
+
+        Pallet::<T>::rate_crop_receipt(RawOrigin::Signed(alice::<T>()).into(), crop_receipt_id, Rating::AA).unwrap();
+
+        let supply = 1000;
+        Pallet::<T>::publish_crop_receipt(RawOrigin::Signed(bob::<T>()).into(), crop_receipt_id, supply, false).unwrap();
+    }: {
+        Pallet::<T>::list_coupons(RawOrigin::Signed(bob::<T>()).into(), crop_receipt_id).unwrap();
+    }
+    verify {
+        let coupon_asset_id = Pallet::<T>::crop_receipt_to_coupon(crop_receipt_id).unwrap();
+        assert!(CouponOrderBooks::<T>::contains_key(coupon_asset_id));
+    }
+
     impl_benchmark_test_suite!(
         Pallet,
         crate::mock::ext(),
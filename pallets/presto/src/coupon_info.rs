@@ -32,8 +32,8 @@ use crate::{Config, Error};
 
 use codec::{Decode, Encode, MaxEncodedLen};
 use common::prelude::BalanceUnit;
-use common::Balance;
-use sp_runtime::traits::CheckedMul;
+use common::{AssetIdOf, Balance};
+use sp_runtime::traits::{CheckedDiv, CheckedMul, Zero};
 use sp_runtime::DispatchError;
 
 #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, scale_info::TypeInfo, MaxEncodedLen)]
@@ -60,4 +60,80 @@ impl<T: Config> CouponInfo<T> {
             .ok_or(Error::<T>::CalculationError)?;
         Ok(*cost.balance())
     }
+
+    /// Coupons that are not redeemed by the creditor, including the ones claimed for refunds
+    pub fn outstanding_coupons(&self, repayment: &RepaymentInfo) -> Balance {
+        self.supply.balance().saturating_sub(repayment.redeemed)
+    }
+
+    /// Debt that is still not covered by repayments and recoveries
+    pub fn outstanding_debt(&self, repayment: &RepaymentInfo) -> Result<Balance, DispatchError> {
+        let outstanding_cost = self.coupons_cost(self.outstanding_coupons(repayment))?;
+        Ok(outstanding_cost.saturating_sub(repayment.paid()))
+    }
+
+    /// Pro-rata share of all the funds paid so far for `coupons_amount` coupons.
+    /// A coupon never pays more than its `refund_price`.
+    pub fn refund_amount(
+        &self,
+        repayment: &RepaymentInfo,
+        coupons_amount: Balance,
+    ) -> Result<Balance, DispatchError> {
+        let outstanding_coupons = self.outstanding_coupons(repayment);
+        if outstanding_coupons.is_zero() {
+            return Ok(Balance::zero());
+        }
+
+        let share = BalanceUnit::divisible(repayment.paid())
+            .checked_mul(&BalanceUnit::indivisible(coupons_amount))
+            .ok_or(Error::<T>::CalculationError)?
+            .checked_div(&BalanceUnit::indivisible(outstanding_coupons))
+            .ok_or(Error::<T>::CalculationError)?;
+
+        Ok((*share.balance()).min(self.coupons_cost(coupons_amount)?))
+    }
+}
+
+/// Coupons held by an account
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CouponHolding<T: Config> {
+    pub coupon_asset_id: AssetIdOf<T>,
+    pub info: CouponInfo<T>,
+    pub amount: Balance,
+    /// Refund for the coupons and the ones already claimed once the crop receipt is fully paid off
+    pub expected_refund: Balance,
+    /// Refund for the coupons and the ones already claimed that can be claimed right now
+    pub claimable_refund: Balance,
+}
+
+/// Funds paid for the crop receipt and refunds made to coupon holders
+#[derive(
+    Debug, Default, Clone, PartialEq, Eq, Encode, Decode, scale_info::TypeInfo, MaxEncodedLen,
+)]
+pub struct RepaymentInfo {
+    /// PRUSD paid by the creditor
+    pub repaid: Balance,
+    /// PRUSD recovered by managers after the default
+    pub recovered: Balance,
+    /// PRUSD already refunded to coupon holders
+    pub refunded: Balance,
+    /// Coupons burned by the creditor pay off, which are not backed by the debt anymore
+    pub redeemed: Balance,
+}
+
+impl RepaymentInfo {
+    /// Funds paid for the coupons so far, refunded ones included
+    pub fn paid(&self) -> Balance {
+        self.repaid.saturating_add(self.recovered)
+    }
+}
+
+/// Coupons given up by the holder for refunds and the refunds already paid for them.
+/// The holder can claim the share of each new repayment until the coupons are paid in full.
+#[derive(
+    Debug, Default, Clone, PartialEq, Eq, Encode, Decode, scale_info::TypeInfo, MaxEncodedLen,
+)]
+pub struct RefundClaim {
+    pub coupons: Balance,
+    pub refunded: Balance,
 }
@@ -47,6 +47,36 @@ pub enum Status {
     Declined,
     Published,
     Closed,
+    Overdue,
+    Defaulted,
+}
+
+impl Status {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Rating => "Rating",
+            Self::Decision => "Decision",
+            Self::Declined => "Declined",
+            Self::Published => "Published",
+            Self::Closed => "Closed",
+            Self::Overdue => "Overdue",
+            Self::Defaulted => "Defaulted",
+        }
+    }
+
+    pub fn from_label(label: &str) -> Option<Self> {
+        [
+            Self::Rating,
+            Self::Decision,
+            Self::Declined,
+            Self::Published,
+            Self::Closed,
+            Self::Overdue,
+            Self::Defaulted,
+        ]
+        .into_iter()
+        .find(|status| status.label() == label)
+    }
 }
 
 #[allow(clippy::upper_case_acronyms)]
@@ -76,6 +106,42 @@ pub enum Rating {
     NR,
 }
 
+impl Rating {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::AAA => "AAA",
+            Self::AA => "AA",
+            Self::A => "A",
+            Self::BBB => "BBB",
+            Self::BB => "BB",
+            Self::B => "B",
+            Self::CCC => "CCC",
+            Self::CC => "CC",
+            Self::C => "C",
+            Self::D => "D",
+            Self::NR => "NR",
+        }
+    }
+
+    pub fn from_label(label: &str) -> Option<Self> {
+        [
+            Self::AAA,
+            Self::AA,
+            Self::A,
+            Self::BBB,
+            Self::BB,
+            Self::B,
+            Self::CCC,
+            Self::CC,
+            Self::C,
+            Self::D,
+            Self::NR,
+        ]
+        .into_iter()
+        .find(|rating| rating.label() == label)
+    }
+}
+
 #[derive(
     Debug,
     Clone,
@@ -119,6 +185,30 @@ impl Country {
             Self::Other => b"Other",
         }
     }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Brazil => "Brazil",
+            Self::Indonesia => "Indonesia",
+            Self::Nigeria => "Nigeria",
+            Self::Ukraine => "Ukraine",
+            Self::Usa => "Usa",
+            Self::Other => "Other",
+        }
+    }
+
+    pub fn from_label(label: &str) -> Option<Self> {
+        [
+            Self::Brazil,
+            Self::Indonesia,
+            Self::Nigeria,
+            Self::Ukraine,
+            Self::Usa,
+            Self::Other,
+        ]
+        .into_iter()
+        .find(|country| country.label() == label)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, scale_info::TypeInfo, MaxEncodedLen)]
@@ -251,15 +341,71 @@ impl<T: Config> CropReceipt<T> {
             return Err(Error::<T>::CropReceiptHasBeenClosed.into());
         }
 
+        ensure!(self.is_repayable(), Error::<T>::CropReceiptCannotBeClosed);
+
+        self.status = Status::Closed;
+
+        Ok(())
+    }
+
+    /// Published crop receipts accept repayments until they are closed or defaulted
+    pub fn is_repayable(&self) -> bool {
+        matches!(self.status, Status::Published | Status::Overdue)
+    }
+
+    pub fn ensure_is_repayable(&self) -> DispatchResult {
+        if self.status == Status::Closed {
+            return Err(Error::<T>::CropReceiptHasBeenClosed.into());
+        }
+        if self.status == Status::Defaulted {
+            return Err(Error::<T>::CropReceiptHasDefaulted.into());
+        }
+
+        ensure!(self.is_repayable(), Error::<T>::CropReceiptIsNotPublished);
+
+        Ok(())
+    }
+
+    pub fn is_overdue_at(&self, now: MomentOf<T>) -> bool {
+        self.status == Status::Published && now > self.perfomance_time
+    }
+
+    pub fn mark_overdue(&mut self) -> DispatchResult {
         ensure!(
             self.status == Status::Published,
-            Error::<T>::CropReceiptCannotBeClosed
+            Error::<T>::CropReceiptIsNotPublished
         );
 
-        self.status = Status::Closed;
+        self.status = Status::Overdue;
 
         Ok(())
     }
+
+    pub fn declare_default(&mut self) -> DispatchResult {
+        if self.status == Status::Closed {
+            return Err(Error::<T>::CropReceiptHasBeenClosed.into());
+        }
+        if self.status == Status::Defaulted {
+            return Err(Error::<T>::CropReceiptHasDefaulted.into());
+        }
+
+        ensure!(
+            self.status == Status::Overdue,
+            Error::<T>::CropReceiptIsNotOverdue
+        );
+
+        self.status = Status::Defaulted;
+
+        Ok(())
+    }
+
+    /// Coupon holders may claim refunds once the coupons were issued
+    pub fn is_refundable(&self) -> bool {
+        matches!(
+            self.status,
+            Status::Published | Status::Overdue | Status::Defaulted | Status::Closed
+        )
+    }
 }
 
 #[cfg(all(any(test, feature = "runtime-benchmarks"), feature = "stage"))] // presto
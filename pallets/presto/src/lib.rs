@@ -34,6 +34,7 @@
 mod benchmarking;
 mod coupon_info;
 mod crop_receipt;
+pub mod migrations;
 #[cfg(test)]
 mod mock;
 mod requests;
@@ -42,15 +43,28 @@ mod test;
 mod treasury;
 pub mod weights;
 
-use common::{AccountIdOf, AssetInfoProvider, Balance};
+use common::fixnum::ops::RoundMode;
+use common::permissions::{PermissionId, TRANSFER};
+use common::prelude::BalanceUnit;
+use common::{
+    balance, AccountIdOf, AssetIdOf, AssetInfoProvider, AssetManager, AssetRegulator, Balance,
+    DEXId, OrderBookId, OrderBookManager, TradingPairSourceManager,
+};
 use frame_support::ensure;
 use frame_support::sp_runtime::DispatchError;
 use frame_support::traits::Time;
+use frame_support::weights::Weight;
 use sp_core::Get;
 use sp_runtime::traits::{One, Saturating, Zero};
 use sp_std::vec::Vec;
 
+pub use coupon_info::{CouponHolding, CouponInfo, RefundClaim, RepaymentInfo};
+pub use crop_receipt::{Country, CropReceipt, Rating, Score, Status};
 pub use pallet::*;
+pub use requests::{Request, RequestStatus};
+pub use treasury::TreasuryPosition;
+
+use treasury::Treasury;
 
 pub type MomentOf<T> = <<T as Config>::Time as Time>::Moment;
 
@@ -62,17 +76,22 @@ pub const TECH_ACCOUNT_BUFFER: &[u8] = b"buffer";
 
 const COUPON_SYMBOL: &[u8] = b"C";
 const COUPON_NAME: &[u8] = b"Coupon";
+/// Tick size of coupon order books is always 0.01 PRUSD
+const COUPON_TICK_SIZE: Balance = balance!(0.01);
+/// Crop receipts are checked for overdue by one hour periods of their perfomance time
+const OVERDUE_CHECK_PERIOD: u32 = 3_600_000;
+/// Max number of perfomance time periods checked for overdue in one block
+const MAX_OVERDUE_CHECK_PERIODS: u32 = 24;
+/// Max number of published crop receipts checked for overdue in one block
+const MAX_OVERDUE_CHECKS: u32 = 64;
 
 #[frame_support::pallet]
 #[allow(clippy::too_many_arguments)]
 pub mod pallet {
     use super::*;
-    use common::fixnum::ops::RoundMode;
-    use common::prelude::BalanceUnit;
     use common::{
-        balance, itoa, AssetIdOf, AssetManager, AssetName, AssetSymbol, AssetType, BoundedString,
-        ContentSource, DEXId, ExtendedAssetsManager, ItoaInteger, OrderBookManager, PriceVariant,
-        TradingPairSourceManager,
+        itoa, AssetName, AssetSymbol, AssetType, BoundedString, ContentSource,
+        ExtendedAssetsManager, ItoaInteger, PriceVariant,
     };
     use core::fmt::Debug;
     use frame_support::pallet_prelude::*;
@@ -81,13 +100,11 @@ pub mod pallet {
     use sp_runtime::traits::{AtLeast32BitUnsigned, CheckedDiv, MaybeDisplay, Zero};
     use sp_runtime::BoundedVec;
 
-    use coupon_info::CouponInfo;
-    use crop_receipt::{Country, CropReceipt, CropReceiptContent, Rating, Status};
-    use requests::{DepositRequest, Request, RequestStatus, WithdrawRequest};
-    use treasury::Treasury;
+    use crop_receipt::CropReceiptContent;
+    use requests::{DepositRequest, WithdrawRequest};
     use weights::WeightInfo;
 
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -271,6 +288,61 @@ pub mod pallet {
     pub type CropReceiptToCoupon<T: Config> =
         StorageMap<_, Twox64Concat, T::CropReceiptId, AssetIdOf<T>, OptionQuery>;
 
+    /// Repayments, recoveries and refunds of published crop receipts
+    #[pallet::storage]
+    #[pallet::getter(fn crop_receipt_repayments)]
+    pub type CropReceiptRepayments<T: Config> =
+        StorageMap<_, Twox64Concat, T::CropReceiptId, RepaymentInfo, ValueQuery>;
+
+    /// Coupons claimed for refunds by the holder and the refunds paid for them
+    #[pallet::storage]
+    #[pallet::getter(fn refund_claims)]
+    pub type RefundClaims<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        T::CropReceiptId,
+        Blake2_128Concat,
+        AccountIdOf<T>,
+        RefundClaim,
+        ValueQuery,
+    >;
+
+    /// Published crop receipts with their perfomance time, which are checked for overdue
+    #[pallet::storage]
+    #[pallet::getter(fn active_crop_receipts)]
+    pub type ActiveCropReceipts<T: Config> =
+        StorageMap<_, Twox64Concat, T::CropReceiptId, MomentOf<T>, OptionQuery>;
+
+    /// Published crop receipts with their perfomance time, grouped by the overdue check period
+    #[pallet::storage]
+    #[pallet::getter(fn active_crop_receipts_by_period)]
+    pub type ActiveCropReceiptsByPeriod<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        MomentOf<T>,
+        Twox64Concat,
+        T::CropReceiptId,
+        MomentOf<T>,
+        OptionQuery,
+    >;
+
+    /// Earliest overdue check period which may still have published crop receipts to check
+    #[pallet::storage]
+    #[pallet::getter(fn next_overdue_check_period)]
+    pub type NextOverdueCheckPeriod<T: Config> = StorageValue<_, MomentOf<T>, ValueQuery>;
+
+    /// Last crop receipt checked for overdue in the period, which wasn't checked in full in one block
+    #[pallet::storage]
+    #[pallet::getter(fn overdue_check_cursor)]
+    pub type OverdueCheckCursor<T: Config> =
+        StorageValue<_, (MomentOf<T>, T::CropReceiptId), OptionQuery>;
+
+    /// Order books where coupons are listed for trading
+    #[pallet::storage]
+    #[pallet::getter(fn coupon_order_books)]
+    pub type CouponOrderBooks<T: Config> =
+        StorageMap<_, Twox64Concat, AssetIdOf<T>, OrderBookId<AssetIdOf<T>, T::DEXId>, OptionQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -327,6 +399,33 @@ pub mod pallet {
         CropReceiptClosed {
             id: T::CropReceiptId,
         },
+        CouponsListed {
+            id: T::CropReceiptId,
+            coupon_asset_id: AssetIdOf<T>,
+            order_book_id: OrderBookId<AssetIdOf<T>, T::DEXId>,
+        },
+        CropReceiptRepaid {
+            id: T::CropReceiptId,
+            amount: Balance,
+        },
+        CropReceiptOverdue {
+            id: T::CropReceiptId,
+        },
+        CropReceiptDefaulted {
+            id: T::CropReceiptId,
+            by: AccountIdOf<T>,
+        },
+        CropReceiptRecoveryRecorded {
+            id: T::CropReceiptId,
+            amount: Balance,
+            by: AccountIdOf<T>,
+        },
+        RefundClaimed {
+            coupon_asset_id: AssetIdOf<T>,
+            coupon_amount: Balance,
+            refund_amount: Balance,
+            by: AccountIdOf<T>,
+        },
     }
 
     #[pallet::error]
@@ -395,6 +494,29 @@ pub mod pallet {
         CalculationError,
         /// There is no data about emitted coupon for the crop receipt
         NoCouponData,
+        /// The crop receipt is not published
+        CropReceiptIsNotPublished,
+        /// The crop receipt is not overdue yet
+        CropReceiptIsNotOverdue,
+        /// The crop receipt has been defaulted
+        CropReceiptHasDefaulted,
+        /// The crop receipt is not defaulted
+        CropReceiptIsNotDefaulted,
+        /// The amount exceeds the outstanding debt of the crop receipt
+        AmountExceedsDebt,
+        /// There are no funds to refund for the coupons yet
+        NothingToRefund,
+        /// The coupons are already listed in the order book
+        CouponsAlreadyListed,
+        /// Coupons of the defaulted crop receipt cannot be transferred
+        CouponsAreFrozen,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_now: BlockNumberFor<T>) -> Weight {
+            Self::mark_overdue_crop_receipts()
+        }
     }
 
     #[pallet::call]
@@ -922,12 +1044,16 @@ pub mod pallet {
             })
         }
 
+        /// Publishes the crop receipt and emits its coupons.
+        /// If `list_coupons` is set, the order book is created and the whole supply is offered there,
+        /// otherwise the coupons stay with the creditor until `list_coupons` is called.
         #[pallet::call_index(20)]
         #[pallet::weight(<T as Config>::WeightInfo::publish_crop_receipt())]
         pub fn publish_crop_receipt(
             origin: OriginFor<T>,
             crop_receipt_id: T::CropReceiptId,
             supply: Balance,
+            list_coupons: bool,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
             ensure!(!supply.is_zero(), Error::<T>::AmountIsZero);
@@ -938,6 +1064,7 @@ pub mod pallet {
             let mut country = Country::Other;
             let mut amount = BalanceUnit::default();
             let mut profit = Permill::default();
+            let mut perfomance_time = MomentOf::<T>::default();
             CropReceipts::<T>::try_mutate(crop_receipt_id, |crop_receipt| {
                 let crop_receipt = crop_receipt
                     .as_mut()
@@ -947,6 +1074,7 @@ pub mod pallet {
                 country = crop_receipt.country;
                 amount = BalanceUnit::divisible(crop_receipt.amount);
                 profit = crop_receipt.profit;
+                perfomance_time = crop_receipt.perfomance_time;
 
                 // The initial price (amount / supply) must be >= 1.00
                 ensure!(coupon_supply <= amount, Error::<T>::TooBigCouponSupply);
@@ -989,62 +1117,13 @@ pub mod pallet {
                 supply,
             )?;
 
-            T::TradingPairSourceManager::register_pair(
-                DEXId::PolkaswapPresto.into(),
-                T::PrestoUsdAssetId::get(),
-                coupon_asset_id,
-            )?;
-
-            let order_book_id = T::OrderBookManager::assemble_order_book_id(
-                DEXId::PolkaswapPresto.into(),
-                &T::PrestoUsdAssetId::get(),
-                &coupon_asset_id,
-            )
-            .ok_or(Error::<T>::CouponOfferingFail)?;
-
-            // Tick size always is 0.01 PRUSD
-            let tick_size = balance!(0.01);
-            // Since Coupon is non-divisible asset, the step lot size is always 1 Coupon
-            let step_lot_size = 1;
-
-            // This value must correlate with `order_book::Config` const `MaxLimitOrdersForPrice`.
-            // It shouldn't be equal, but it must always be not higher: `max_orders_count` <= `MaxLimitOrdersForPrice`
-            let max_orders_count = BalanceUnit::divisible(balance!(1000));
-
-            // This value must correlate with `order_book::Config` const `SOFT_MIN_MAX_RATIO`.
-            // It shouldn't be equal, but it must always be not higher: `max_orders_count` <= `SOFT_MIN_MAX_RATIO`
-            let min_max_ratio = BalanceUnit::divisible(balance!(1000));
-
-            // Calculate the max lot size amount that is suitable to offer all Coupon supply at one price in order book
-            let max = coupon_supply
-                .checked_div(&max_orders_count)
-                .ok_or(Error::<T>::CalculationError)?;
-
-            // default values
-            let default_min_lot_size = 1;
-            let default_max_lot_size = 1000;
-
-            let (min_lot_size, max_lot_size) =
-                if max <= BalanceUnit::indivisible(default_max_lot_size) {
-                    (default_min_lot_size, default_max_lot_size)
-                } else {
-                    // if necessary max lot size exceeds the default value 1000 - calculate suitable min value
-                    let min = max
-                        .checked_div(&min_max_ratio)
-                        .ok_or(Error::<T>::CalculationError)?;
-                    (
-                        *min.into_indivisible(RoundMode::Ceil).balance(),
-                        *max.into_indivisible(RoundMode::Ceil).balance(),
-                    )
-                };
-
             let offer_price = *amount
                 .checked_div(&coupon_supply)
                 .ok_or(Error::<T>::CalculationError)?
                 .into_divisible()
                 .ok_or(Error::<T>::CalculationError)?
                 .balance();
-            let offer_price = Self::align_price(offer_price, tick_size)?;
+            let offer_price = Self::align_price(offer_price, COUPON_TICK_SIZE)?;
 
             let refund_price = offer_price
                 .checked_add(profit * offer_price)
@@ -1061,46 +1140,35 @@ pub mod pallet {
             );
 
             CropReceiptToCoupon::<T>::insert(crop_receipt_id, coupon_asset_id);
+            Self::activate_crop_receipt(crop_receipt_id, perfomance_time);
 
-            // create order book
-            T::OrderBookManager::initialize_orderbook(
-                &order_book_id,
-                tick_size,
-                step_lot_size,
-                min_lot_size,
-                max_lot_size,
-            )?;
-
-            let order_book_account_id =
-                T::OrderBookManager::tech_account_id_for_order_book(&order_book_id)?;
-
-            // Presto KYC SBT for order book tech account
-            T::AssetManager::mint_to(
-                &T::PrestoKycAssetId::get(),
-                &presto_tech_account_id,
-                &order_book_account_id,
-                1,
-            )?;
-
-            // place all supply in order book in according with `max_lot_size` limitation
-            let mut remaining_amount = supply;
-            while !remaining_amount.is_zero() {
-                let qty = if remaining_amount > max_lot_size {
-                    max_lot_size
-                } else {
-                    remaining_amount
-                };
-
-                T::OrderBookManager::place_limit_order(
-                    who.clone(),
-                    order_book_id,
-                    offer_price,
-                    qty,
-                    PriceVariant::Sell,
-                    None,
+            if list_coupons {
+                let (order_book_id, max_lot_size) = Self::list_coupons_in_order_book(
+                    crop_receipt_id,
+                    coupon_asset_id,
+                    coupon_supply,
                 )?;
 
-                remaining_amount = remaining_amount.saturating_sub(qty);
+                // place all supply in order book in according with `max_lot_size` limitation
+                let mut remaining_amount = supply;
+                while !remaining_amount.is_zero() {
+                    let qty = if remaining_amount > max_lot_size {
+                        max_lot_size
+                    } else {
+                        remaining_amount
+                    };
+
+                    T::OrderBookManager::place_limit_order(
+                        who.clone(),
+                        order_book_id,
+                        offer_price,
+                        qty,
+                        PriceVariant::Sell,
+                        None,
+                    )?;
+
+                    remaining_amount = remaining_amount.saturating_sub(qty);
+                }
             }
 
             Self::deposit_event(Event::<T>::CropReceiptPublished {
@@ -1112,7 +1180,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(21)]
-        #[pallet::weight(<T as Config>::WeightInfo::publish_crop_receipt())]
+        #[pallet::weight(<T as Config>::WeightInfo::pay_off_crop_receipt())]
         pub fn pay_off_crop_receipt(
             origin: OriginFor<T>,
             crop_receipt_id: T::CropReceiptId,
@@ -1129,21 +1197,12 @@ pub mod pallet {
                 crop_receipt.close()
             })?;
 
-            let coupon_asset_id =
-                CropReceiptToCoupon::<T>::get(crop_receipt_id).ok_or(Error::<T>::NoCouponData)?;
-
-            let coupon_info = Coupons::<T>::get(coupon_asset_id).ok_or(Error::<T>::NoCouponData)?;
-
-            let mut total_debt = coupon_info.total_debt_cost()?;
+            let (coupon_asset_id, coupon_info) = Self::crop_receipt_coupon(crop_receipt_id)?;
+            let mut repayment = CropReceiptRepayments::<T>::get(crop_receipt_id);
 
             // if creditor has any amount of coupons - they are burned, but the total pay off debt is reduced by the coupons cost
             let coupon_amount = T::AssetInfoProvider::free_balance(&coupon_asset_id, &who)?;
             if coupon_amount > Balance::zero() {
-                let coupons_cost = coupon_info.coupons_cost(coupon_amount)?;
-                total_debt = total_debt
-                    .checked_sub(coupons_cost)
-                    .ok_or(Error::<T>::CalculationError)?;
-
                 let presto_tech_account_id = technical::Pallet::<T>::tech_account_id_to_account_id(
                     &T::PrestoTechAccount::get(),
                 )?;
@@ -1154,9 +1213,25 @@ pub mod pallet {
                     &who,
                     coupon_amount,
                 )?;
+
+                repayment.redeemed = repayment
+                    .redeemed
+                    .checked_add(coupon_amount)
+                    .ok_or(Error::<T>::CalculationError)?;
             }
 
-            Treasury::<T>::transfer_to_buffer(total_debt, &who)?;
+            let total_debt = coupon_info.outstanding_debt(&repayment)?;
+            if total_debt > Balance::zero() {
+                Treasury::<T>::transfer_to_buffer(total_debt, &who)?;
+                repayment.repaid = repayment
+                    .repaid
+                    .checked_add(total_debt)
+                    .ok_or(Error::<T>::CalculationError)?;
+            }
+
+            CropReceiptRepayments::<T>::insert(crop_receipt_id, repayment);
+            Self::deactivate_crop_receipt(crop_receipt_id);
+            Self::freeze_coupon_order_book(&coupon_asset_id)?;
 
             Self::deposit_event(Event::<T>::CropReceiptClosed {
                 id: crop_receipt_id,
@@ -1165,6 +1240,8 @@ pub mod pallet {
             Ok(())
         }
 
+        /// Burns `coupon_amount` coupons and pays the share of the funds repaid so far for all the coupons
+        /// claimed by the holder. The holder can claim again with zero coupons once more funds arrive.
         #[pallet::call_index(22)]
         #[pallet::weight(<T as Config>::WeightInfo::claim_refund())]
         pub fn claim_refund(
@@ -1175,28 +1252,219 @@ pub mod pallet {
             let who = ensure_signed(origin)?;
             Self::ensure_has_kyc(&who)?;
 
+            let coupon_info = Coupons::<T>::get(coupon_asset_id).ok_or(Error::<T>::NoCouponData)?;
+            let crop_receipt_id = coupon_info.crop_receipt_id;
+
+            let crop_receipt = CropReceipts::<T>::get(crop_receipt_id)
+                .ok_or(Error::<T>::CropReceiptIsNotExists)?;
+            ensure!(
+                crop_receipt.is_refundable(),
+                Error::<T>::CropReceiptIsNotClosedYet
+            );
+
             let presto_tech_account_id = technical::Pallet::<T>::tech_account_id_to_account_id(
                 &T::PrestoTechAccount::get(),
             )?;
 
-            T::AssetManager::burn_from(
-                &coupon_asset_id,
-                &presto_tech_account_id,
+            if !coupon_amount.is_zero() {
+                T::AssetManager::burn_from(
+                    &coupon_asset_id,
+                    &presto_tech_account_id,
+                    &who,
+                    coupon_amount,
+                )?;
+            }
+
+            let mut repayment = CropReceiptRepayments::<T>::get(crop_receipt_id);
+            let refund_amount = RefundClaims::<T>::try_mutate(
+                crop_receipt_id,
                 &who,
-                coupon_amount,
+                |claim| -> Result<Balance, DispatchError> {
+                    claim.coupons = claim
+                        .coupons
+                        .checked_add(coupon_amount)
+                        .ok_or(Error::<T>::CalculationError)?;
+
+                    // claimed coupons share the funds which have arrived so far, the part of their
+                    // price which is not repaid yet can be claimed later
+                    let refund_amount = coupon_info
+                        .refund_amount(&repayment, claim.coupons)?
+                        .saturating_sub(claim.refunded);
+                    ensure!(!refund_amount.is_zero(), Error::<T>::NothingToRefund);
+
+                    claim.refunded = claim
+                        .refunded
+                        .checked_add(refund_amount)
+                        .ok_or(Error::<T>::CalculationError)?;
+
+                    Ok(refund_amount)
+                },
             )?;
 
-            let coupon_info = Coupons::<T>::get(coupon_asset_id).ok_or(Error::<T>::NoCouponData)?;
+            repayment.refunded = repayment
+                .refunded
+                .checked_add(refund_amount)
+                .ok_or(Error::<T>::CalculationError)?;
+            CropReceiptRepayments::<T>::insert(crop_receipt_id, repayment);
+
+            Treasury::<T>::transfer_from_buffer(refund_amount, &who)?;
+
+            Self::deposit_event(Event::<T>::RefundClaimed {
+                coupon_asset_id,
+                coupon_amount,
+                refund_amount,
+                by: who,
+            });
+
+            Ok(())
+        }
+
+        /// Repays a part of the crop receipt debt. The crop receipt is closed once the debt is fully repaid.
+        #[pallet::call_index(23)]
+        #[pallet::weight(<T as Config>::WeightInfo::repay_crop_receipt())]
+        pub fn repay_crop_receipt(
+            origin: OriginFor<T>,
+            crop_receipt_id: T::CropReceiptId,
+            amount: Balance,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(!amount.is_zero(), Error::<T>::AmountIsZero);
+            Self::ensure_has_creditor_kyc(&who)?;
 
-            let crop_receipt = CropReceipts::<T>::get(coupon_info.crop_receipt_id)
+            let mut crop_receipt = CropReceipts::<T>::get(crop_receipt_id)
+                .ok_or(Error::<T>::CropReceiptIsNotExists)?;
+            crop_receipt.ensure_is_owner(&who)?;
+            crop_receipt.ensure_is_repayable()?;
+
+            let (coupon_asset_id, coupon_info) = Self::crop_receipt_coupon(crop_receipt_id)?;
+            let mut repayment = CropReceiptRepayments::<T>::get(crop_receipt_id);
+
+            let outstanding_debt = coupon_info.outstanding_debt(&repayment)?;
+            ensure!(amount <= outstanding_debt, Error::<T>::AmountExceedsDebt);
+
+            Treasury::<T>::transfer_to_buffer(amount, &who)?;
+            repayment.repaid = repayment
+                .repaid
+                .checked_add(amount)
+                .ok_or(Error::<T>::CalculationError)?;
+            CropReceiptRepayments::<T>::insert(crop_receipt_id, repayment);
+
+            Self::deposit_event(Event::<T>::CropReceiptRepaid {
+                id: crop_receipt_id,
+                amount,
+            });
+
+            if amount == outstanding_debt {
+                crop_receipt.close()?;
+                CropReceipts::<T>::insert(crop_receipt_id, crop_receipt);
+                Self::deactivate_crop_receipt(crop_receipt_id);
+                Self::freeze_coupon_order_book(&coupon_asset_id)?;
+
+                Self::deposit_event(Event::<T>::CropReceiptClosed {
+                    id: crop_receipt_id,
+                });
+            }
+
+            Ok(())
+        }
+
+        /// Declares the overdue crop receipt as defaulted, freezes the trading and transfers of its coupons
+        #[pallet::call_index(24)]
+        #[pallet::weight(<T as Config>::WeightInfo::declare_crop_receipt_default())]
+        pub fn declare_crop_receipt_default(
+            origin: OriginFor<T>,
+            crop_receipt_id: T::CropReceiptId,
+        ) -> DispatchResult {
+            let auditor = ensure_signed(origin)?;
+            Self::ensure_is_auditor(&auditor)?;
+
+            CropReceipts::<T>::try_mutate(crop_receipt_id, |crop_receipt| {
+                let crop_receipt = crop_receipt
+                    .as_mut()
+                    .ok_or(Error::<T>::CropReceiptIsNotExists)?;
+
+                crop_receipt.declare_default()
+            })?;
+
+            let (coupon_asset_id, _) = Self::crop_receipt_coupon(crop_receipt_id)?;
+            Self::freeze_coupon_order_book(&coupon_asset_id)?;
+
+            Self::deposit_event(Event::<T>::CropReceiptDefaulted {
+                id: crop_receipt_id,
+                by: auditor,
+            });
+
+            Ok(())
+        }
+
+        /// Moves the proceeds recovered for the defaulted crop receipt from the treasury to coupon holders
+        #[pallet::call_index(25)]
+        #[pallet::weight(<T as Config>::WeightInfo::record_crop_receipt_recovery())]
+        pub fn record_crop_receipt_recovery(
+            origin: OriginFor<T>,
+            crop_receipt_id: T::CropReceiptId,
+            amount: Balance,
+        ) -> DispatchResult {
+            let manager = ensure_signed(origin)?;
+            Self::ensure_is_manager(&manager)?;
+            ensure!(!amount.is_zero(), Error::<T>::AmountIsZero);
+
+            let crop_receipt = CropReceipts::<T>::get(crop_receipt_id)
                 .ok_or(Error::<T>::CropReceiptIsNotExists)?;
             ensure!(
-                crop_receipt.status == Status::Closed,
-                Error::<T>::CropReceiptIsNotClosedYet
+                crop_receipt.status == Status::Defaulted,
+                Error::<T>::CropReceiptIsNotDefaulted
             );
 
-            let refund_amount = coupon_info.coupons_cost(coupon_amount)?;
-            Treasury::<T>::transfer_from_buffer(refund_amount, &who)?;
+            let (_, coupon_info) = Self::crop_receipt_coupon(crop_receipt_id)?;
+            let mut repayment = CropReceiptRepayments::<T>::get(crop_receipt_id);
+
+            ensure!(
+                amount <= coupon_info.outstanding_debt(&repayment)?,
+                Error::<T>::AmountExceedsDebt
+            );
+
+            Treasury::<T>::transfer_from_main_to_buffer(amount)?;
+            repayment.recovered = repayment
+                .recovered
+                .checked_add(amount)
+                .ok_or(Error::<T>::CalculationError)?;
+            CropReceiptRepayments::<T>::insert(crop_receipt_id, repayment);
+
+            Self::deposit_event(Event::<T>::CropReceiptRecoveryRecorded {
+                id: crop_receipt_id,
+                amount,
+                by: manager,
+            });
+
+            Ok(())
+        }
+
+        /// Creates the order book for coupons of the crop receipt published without listing
+        #[pallet::call_index(26)]
+        #[pallet::weight(<T as Config>::WeightInfo::list_coupons())]
+        pub fn list_coupons(
+            origin: OriginFor<T>,
+            crop_receipt_id: T::CropReceiptId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_has_creditor_kyc(&who)?;
+
+            let crop_receipt = CropReceipts::<T>::get(crop_receipt_id)
+                .ok_or(Error::<T>::CropReceiptIsNotExists)?;
+            crop_receipt.ensure_is_owner(&who)?;
+            ensure!(
+                crop_receipt.status == Status::Published,
+                Error::<T>::CropReceiptIsNotPublished
+            );
+
+            let (coupon_asset_id, coupon_info) = Self::crop_receipt_coupon(crop_receipt_id)?;
+            ensure!(
+                !CouponOrderBooks::<T>::contains_key(coupon_asset_id),
+                Error::<T>::CouponsAlreadyListed
+            );
+
+            Self::list_coupons_in_order_book(crop_receipt_id, coupon_asset_id, coupon_info.supply)?;
 
             Ok(())
         }
@@ -1238,12 +1506,311 @@ impl<T: Config> Pallet<T> {
         id
     }
 
+    /// Crop receipts matching all of the provided filters, ordered by id
+    pub fn filter_crop_receipts(
+        status: Option<Status>,
+        country: Option<Country>,
+        rating: Option<Rating>,
+    ) -> Vec<(T::CropReceiptId, CropReceipt<T>)> {
+        let mut crop_receipts = CropReceipts::<T>::iter()
+            .filter(|(_, crop_receipt)| {
+                status.map_or(true, |status| crop_receipt.status == status)
+                    && country.map_or(true, |country| crop_receipt.country == country)
+                    && rating.map_or(true, |rating| {
+                        crop_receipt.score.as_ref().map(|score| score.rating) == Some(rating)
+                    })
+            })
+            .collect::<Vec<_>>();
+        crop_receipts.sort_by_key(|(id, _)| *id);
+        crop_receipts
+    }
+
+    /// Debt of the published crop receipt which is not covered by repayments and recoveries yet
+    pub fn crop_receipt_outstanding_debt(crop_receipt_id: T::CropReceiptId) -> Option<Balance> {
+        let (_, coupon_info) = Self::crop_receipt_coupon(crop_receipt_id).ok()?;
+        coupon_info
+            .outstanding_debt(&CropReceiptRepayments::<T>::get(crop_receipt_id))
+            .ok()
+    }
+
+    /// Deposit and withdraw requests of the account
+    pub fn user_requests_info(account: &AccountIdOf<T>) -> Vec<(T::RequestId, Request<T>)> {
+        UserRequests::<T>::get(account)
+            .into_iter()
+            .filter_map(|id| Requests::<T>::get(id).map(|request| (id, request)))
+            .collect()
+    }
+
+    /// Coupons in the free balance of the account and the ones it claimed with the refunds they are worth
+    pub fn coupon_holdings(account: &AccountIdOf<T>) -> Vec<CouponHolding<T>> {
+        let mut holdings = Coupons::<T>::iter()
+            .filter_map(|(coupon_asset_id, info)| {
+                let amount = T::AssetInfoProvider::free_balance(&coupon_asset_id, account).ok()?;
+                let claim = RefundClaims::<T>::get(info.crop_receipt_id, account);
+                let coupons = amount.checked_add(claim.coupons)?;
+                if coupons.is_zero() {
+                    return None;
+                }
+
+                let repayment = CropReceiptRepayments::<T>::get(info.crop_receipt_id);
+                let expected_refund = info.coupons_cost(coupons).ok()?;
+                let claimable_refund = info.refund_amount(&repayment, coupons).ok()?;
+                if amount.is_zero() && expected_refund <= claim.refunded {
+                    return None;
+                }
+
+                Some(CouponHolding {
+                    coupon_asset_id,
+                    expected_refund: expected_refund.saturating_sub(claim.refunded),
+                    claimable_refund: claimable_refund.saturating_sub(claim.refunded),
+                    info,
+                    amount,
+                })
+            })
+            .collect::<Vec<_>>();
+        holdings.sort_by_key(|holding| holding.info.crop_receipt_id);
+        holdings
+    }
+
+    /// PRUSD position of the treasury and the total debt of published crop receipts
+    pub fn treasury_position() -> Result<TreasuryPosition, DispatchError> {
+        let mut position = Treasury::<T>::position()?;
+        position.outstanding_debt = CropReceiptToCoupon::<T>::iter_keys()
+            .filter_map(Self::crop_receipt_outstanding_debt)
+            .fold(Balance::zero(), |total, debt| total.saturating_add(debt));
+        Ok(position)
+    }
+
+    fn crop_receipt_coupon(
+        crop_receipt_id: T::CropReceiptId,
+    ) -> Result<(AssetIdOf<T>, CouponInfo<T>), DispatchError> {
+        let coupon_asset_id =
+            CropReceiptToCoupon::<T>::get(crop_receipt_id).ok_or(Error::<T>::NoCouponData)?;
+        let coupon_info = Coupons::<T>::get(coupon_asset_id).ok_or(Error::<T>::NoCouponData)?;
+        Ok((coupon_asset_id, coupon_info))
+    }
+
+    /// Start of the overdue check period which `moment` belongs to
+    pub fn overdue_check_period(moment: MomentOf<T>) -> MomentOf<T> {
+        moment - moment % MomentOf::<T>::from(OVERDUE_CHECK_PERIOD)
+    }
+
+    pub(crate) fn activate_crop_receipt(
+        crop_receipt_id: T::CropReceiptId,
+        perfomance_time: MomentOf<T>,
+    ) {
+        let period = Self::overdue_check_period(perfomance_time);
+        ActiveCropReceipts::<T>::insert(crop_receipt_id, perfomance_time);
+        ActiveCropReceiptsByPeriod::<T>::insert(period, crop_receipt_id, perfomance_time);
+        NextOverdueCheckPeriod::<T>::mutate(|next_period| {
+            if period < *next_period {
+                *next_period = period;
+            }
+        });
+    }
+
+    fn deactivate_crop_receipt(crop_receipt_id: T::CropReceiptId) {
+        if let Some(perfomance_time) = ActiveCropReceipts::<T>::take(crop_receipt_id) {
+            ActiveCropReceiptsByPeriod::<T>::remove(
+                Self::overdue_check_period(perfomance_time),
+                crop_receipt_id,
+            );
+        }
+    }
+
+    /// Marks published crop receipts whose perfomance time has passed as overdue.
+    /// Only the periods up to the current one are checked, so crop receipts due later are not touched.
+    /// At most `MAX_OVERDUE_CHECKS` crop receipts are checked in one block, the rest are checked
+    /// in the next blocks starting from the cursor.
+    pub fn mark_overdue_crop_receipts() -> Weight {
+        let now = T::Time::now();
+        let current_period = Self::overdue_check_period(now);
+        let start_period = NextOverdueCheckPeriod::<T>::get();
+        let cursor = OverdueCheckCursor::<T>::get();
+        let mut reads: u64 = 3;
+        let mut writes: u64 = 0;
+
+        // nothing to check, so the cursor doesn't walk through empty periods later
+        if ActiveCropReceiptsByPeriod::<T>::iter_keys()
+            .next()
+            .is_none()
+        {
+            if start_period != current_period {
+                NextOverdueCheckPeriod::<T>::put(current_period);
+                writes += 1;
+            }
+            if cursor.is_some() {
+                OverdueCheckCursor::<T>::kill();
+                writes += 1;
+            }
+            return T::DbWeight::get().reads_writes(reads, writes);
+        }
+
+        let mut checks_left = MAX_OVERDUE_CHECKS as usize;
+        let mut period = start_period;
+        let mut next_cursor = None;
+        for _ in 0..MAX_OVERDUE_CHECK_PERIODS {
+            reads += 1;
+            // the cursor is only valid for the period it was set in
+            let entries = match cursor {
+                Some((cursor_period, last_id)) if cursor_period == period => {
+                    ActiveCropReceiptsByPeriod::<T>::iter_prefix_from(
+                        period,
+                        ActiveCropReceiptsByPeriod::<T>::hashed_key_for(period, last_id),
+                    )
+                    .take(checks_left)
+                    .collect::<Vec<_>>()
+                }
+                _ => ActiveCropReceiptsByPeriod::<T>::iter_prefix(period)
+                    .take(checks_left)
+                    .collect::<Vec<_>>(),
+            };
+            reads += entries.len() as u64;
+            checks_left -= entries.len();
+
+            if checks_left == 0 {
+                next_cursor = entries.last().map(|(id, _)| (period, *id));
+            }
+
+            // crop receipts of the past periods are all overdue, the current one is checked partially
+            for (id, _) in entries
+                .into_iter()
+                .filter(|(_, perfomance_time)| now > *perfomance_time)
+            {
+                Self::deactivate_crop_receipt(id);
+                reads += 2;
+                writes += 2;
+
+                let result = CropReceipts::<T>::try_mutate(id, |crop_receipt| {
+                    let crop_receipt = crop_receipt
+                        .as_mut()
+                        .ok_or(Error::<T>::CropReceiptIsNotExists)?;
+                    crop_receipt.mark_overdue()
+                });
+
+                if result.is_ok() {
+                    writes += 1;
+                    Self::deposit_event(Event::<T>::CropReceiptOverdue { id });
+                }
+            }
+
+            if next_cursor.is_some() || period >= current_period {
+                break;
+            }
+            period = period.saturating_add(MomentOf::<T>::from(OVERDUE_CHECK_PERIOD));
+        }
+
+        if period != start_period {
+            NextOverdueCheckPeriod::<T>::put(period);
+            writes += 1;
+        }
+        if next_cursor != cursor {
+            OverdueCheckCursor::<T>::set(next_cursor);
+            writes += 1;
+        }
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+
     fn align_price(price: Balance, tick_size: Balance) -> Result<Balance, DispatchError> {
         ensure!(tick_size != 0, Error::<T>::CalculationError);
         let steps = price.saturating_div(tick_size);
         Ok(tick_size.saturating_mul(steps))
     }
 
+    /// Creates the order book for coupons against PRUSD and returns it with the max lot size
+    fn list_coupons_in_order_book(
+        crop_receipt_id: T::CropReceiptId,
+        coupon_asset_id: AssetIdOf<T>,
+        coupon_supply: BalanceUnit,
+    ) -> Result<(OrderBookId<AssetIdOf<T>, T::DEXId>, Balance), DispatchError> {
+        T::TradingPairSourceManager::register_pair(
+            DEXId::PolkaswapPresto.into(),
+            T::PrestoUsdAssetId::get(),
+            coupon_asset_id,
+        )?;
+
+        let order_book_id = T::OrderBookManager::assemble_order_book_id(
+            DEXId::PolkaswapPresto.into(),
+            &T::PrestoUsdAssetId::get(),
+            &coupon_asset_id,
+        )
+        .ok_or(Error::<T>::CouponOfferingFail)?;
+
+        // Since Coupon is non-divisible asset, the step lot size is always 1 Coupon
+        let step_lot_size = 1;
+
+        // This value must correlate with `order_book::Config` const `MaxLimitOrdersForPrice`.
+        // It shouldn't be equal, but it must always be not higher: `max_orders_count` <= `MaxLimitOrdersForPrice`
+        let max_orders_count = BalanceUnit::divisible(balance!(1000));
+
+        // This value must correlate with `order_book::Config` const `SOFT_MIN_MAX_RATIO`.
+        // It shouldn't be equal, but it must always be not higher: `max_orders_count` <= `SOFT_MIN_MAX_RATIO`
+        let min_max_ratio = BalanceUnit::divisible(balance!(1000));
+
+        // Calculate the max lot size amount that is suitable to offer all Coupon supply at one price in order book
+        let max = coupon_supply
+            .checked_div(&max_orders_count)
+            .ok_or(Error::<T>::CalculationError)?;
+
+        // default values
+        let default_min_lot_size = 1;
+        let default_max_lot_size = 1000;
+
+        let (min_lot_size, max_lot_size) = if max <= BalanceUnit::indivisible(default_max_lot_size)
+        {
+            (default_min_lot_size, default_max_lot_size)
+        } else {
+            // if necessary max lot size exceeds the default value 1000 - calculate suitable min value
+            let min = max
+                .checked_div(&min_max_ratio)
+                .ok_or(Error::<T>::CalculationError)?;
+            (
+                *min.into_indivisible(RoundMode::Ceil).balance(),
+                *max.into_indivisible(RoundMode::Ceil).balance(),
+            )
+        };
+
+        T::OrderBookManager::initialize_orderbook(
+            &order_book_id,
+            COUPON_TICK_SIZE,
+            step_lot_size,
+            min_lot_size,
+            max_lot_size,
+        )?;
+
+        let presto_tech_account_id =
+            technical::Pallet::<T>::tech_account_id_to_account_id(&T::PrestoTechAccount::get())?;
+        let order_book_account_id =
+            T::OrderBookManager::tech_account_id_for_order_book(&order_book_id)?;
+
+        // Presto KYC SBT for order book tech account
+        T::AssetManager::mint_to(
+            &T::PrestoKycAssetId::get(),
+            &presto_tech_account_id,
+            &order_book_account_id,
+            1,
+        )?;
+
+        CouponOrderBooks::<T>::insert(coupon_asset_id, order_book_id);
+
+        Self::deposit_event(Event::<T>::CouponsListed {
+            id: crop_receipt_id,
+            coupon_asset_id,
+            order_book_id,
+        });
+
+        Ok((order_book_id, max_lot_size))
+    }
+
+    /// Forbids the further trading of coupons, if they are listed
+    fn freeze_coupon_order_book(coupon_asset_id: &AssetIdOf<T>) -> DispatchResult {
+        if let Some(order_book_id) = CouponOrderBooks::<T>::get(coupon_asset_id) {
+            T::OrderBookManager::freeze_orderbook(&order_book_id)?;
+        }
+        Ok(())
+    }
+
     pub fn ensure_no_kyc(account: &AccountIdOf<T>) -> Result<(), DispatchError> {
         ensure!(
             T::AssetInfoProvider::free_balance(&T::PrestoKycAssetId::get(), account)?.is_zero(),
@@ -1285,3 +1852,46 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 }
+
+/// Allows coupon transfers, including trades in the coupon order books, only between KYC passed accounts
+impl<T: Config> AssetRegulator<AccountIdOf<T>, AssetIdOf<T>> for Pallet<T> {
+    fn assign_permission(
+        _owner: &AccountIdOf<T>,
+        _asset_id: &AssetIdOf<T>,
+        _permission_id: &PermissionId,
+    ) -> Result<(), DispatchError> {
+        Ok(())
+    }
+
+    fn check_permission(
+        issuer: &AccountIdOf<T>,
+        affected_account: &AccountIdOf<T>,
+        asset_id: &AssetIdOf<T>,
+        permission_id: &PermissionId,
+    ) -> Result<(), DispatchError> {
+        if *permission_id != TRANSFER {
+            return Ok(());
+        }
+        let Some(coupon_info) = Coupons::<T>::get(asset_id) else {
+            return Ok(());
+        };
+
+        for account in [issuer, affected_account] {
+            // tech accounts of Presto and order books are trusted
+            if technical::Pallet::<T>::lookup_tech_account_id(account).is_err() {
+                Self::ensure_has_kyc(account)?;
+            }
+        }
+
+        // coupons of the defaulted crop receipt are frozen, only the ones locked by order books can be returned
+        let is_defaulted = CropReceipts::<T>::get(coupon_info.crop_receipt_id)
+            .map(|crop_receipt| crop_receipt.status == Status::Defaulted)
+            .unwrap_or_default();
+        ensure!(
+            !is_defaulted || technical::Pallet::<T>::lookup_tech_account_id(issuer).is_ok(),
+            Error::<T>::CouponsAreFrozen
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,234 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+pub mod v1 {
+    use crate::crop_receipt::Status;
+    use crate::{
+        Config, CouponInfo, Coupons, CropReceiptRepayments, CropReceiptToCoupon, CropReceipts,
+        NextOverdueCheckPeriod, Pallet, RepaymentInfo,
+    };
+    use common::{AssetInfoProvider, Balance};
+    use core::marker::PhantomData;
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion, Time};
+    use frame_support::weights::Weight;
+    use sp_core::Get;
+
+    /// Indexes already published crop receipts to check them for overdue
+    /// and records the repayments of already closed ones.
+    ///
+    /// The old pay off moved the cost of all coupons left in circulation to the buffer
+    /// and refunds paid the full coupons cost, so the closed crop receipts are recorded as repaid
+    /// for exactly the coupons that are still in circulation.
+    pub struct Migrate<T>(PhantomData<T>);
+
+    fn closed_crop_receipt_repayment<T: Config>(
+        crop_receipt_id: T::CropReceiptId,
+    ) -> Option<(CouponInfo<T>, RepaymentInfo)> {
+        let coupon_asset_id = CropReceiptToCoupon::<T>::get(crop_receipt_id)?;
+        let coupon_info = Coupons::<T>::get(coupon_asset_id)?;
+        let outstanding_coupons = T::AssetInfoProvider::total_issuance(&coupon_asset_id).ok()?;
+
+        let repayment = RepaymentInfo {
+            repaid: coupon_info.coupons_cost(outstanding_coupons).ok()?,
+            recovered: Balance::default(),
+            refunded: Balance::default(),
+            redeemed: coupon_info
+                .supply
+                .balance()
+                .saturating_sub(outstanding_coupons),
+        };
+
+        Some((coupon_info, repayment))
+    }
+
+    impl<T> OnRuntimeUpgrade for Migrate<T>
+    where
+        T: Config,
+    {
+        fn on_runtime_upgrade() -> Weight {
+            if Pallet::<T>::on_chain_storage_version() != StorageVersion::new(0) {
+                frame_support::__private::log::info!(
+                    "Presto migration to v1 skipped, storage version is not 0"
+                );
+                return T::DbWeight::get().reads(1);
+            }
+
+            let mut reads: u64 = 2;
+            let mut writes: u64 = 2;
+            let mut indexed: u64 = 0;
+            let mut repaid: u64 = 0;
+            let mut next_overdue_check_period = Pallet::<T>::overdue_check_period(T::Time::now());
+            for (id, crop_receipt) in CropReceipts::<T>::iter() {
+                reads += 1;
+                match crop_receipt.status {
+                    Status::Published => {
+                        Pallet::<T>::activate_crop_receipt(id, crop_receipt.perfomance_time);
+                        next_overdue_check_period = next_overdue_check_period.min(
+                            Pallet::<T>::overdue_check_period(crop_receipt.perfomance_time),
+                        );
+                        reads += 1;
+                        writes += 3;
+                        indexed += 1;
+                    }
+                    Status::Closed => {
+                        reads += 3;
+                        if let Some((_, repayment)) = closed_crop_receipt_repayment::<T>(id) {
+                            CropReceiptRepayments::<T>::insert(id, repayment);
+                            writes += 1;
+                            repaid += 1;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            NextOverdueCheckPeriod::<T>::put(next_overdue_check_period);
+            StorageVersion::new(1).put::<Pallet<T>>();
+
+            frame_support::__private::log::info!(
+                "Presto migrated to v1, indexed {} published and recorded repayments of {} closed crop receipts",
+                indexed,
+                repaid
+            );
+
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            frame_support::ensure!(
+                Pallet::<T>::on_chain_storage_version() == StorageVersion::new(1),
+                "Presto storage version must be 1 after the migration"
+            );
+            frame_support::ensure!(
+                CropReceipts::<T>::iter()
+                    .filter(|(_, crop_receipt)| crop_receipt.status == Status::Published)
+                    .all(|(id, crop_receipt)| {
+                        Pallet::<T>::active_crop_receipts_by_period(
+                            Pallet::<T>::overdue_check_period(crop_receipt.perfomance_time),
+                            id,
+                        )
+                        .is_some()
+                    }),
+                "Published crop receipts must be indexed"
+            );
+            for (id, _) in CropReceipts::<T>::iter()
+                .filter(|(_, crop_receipt)| crop_receipt.status == Status::Closed)
+            {
+                let Some((coupon_info, _)) = closed_crop_receipt_repayment::<T>(id) else {
+                    continue;
+                };
+                let repayment = CropReceiptRepayments::<T>::get(id);
+                let outstanding_coupons = coupon_info.outstanding_coupons(&repayment);
+                frame_support::ensure!(
+                    coupon_info.outstanding_debt(&repayment)? == 0,
+                    "Closed crop receipts must be fully repaid"
+                );
+                frame_support::ensure!(
+                    coupon_info.refund_amount(&repayment, outstanding_coupons)?
+                        == coupon_info.coupons_cost(outstanding_coupons)?,
+                    "Coupons of closed crop receipts must be refundable"
+                );
+            }
+            Ok(())
+        }
+    }
+}
+
+pub mod v2 {
+    use crate::crop_receipt::Status;
+    use crate::{Config, CouponOrderBooks, Coupons, CropReceipts, Pallet};
+    use common::{DEXId, OrderBookManager};
+    use core::marker::PhantomData;
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+    use frame_support::weights::Weight;
+    use sp_core::Get;
+
+    /// Indexes order books of already emitted coupons, which were always listed on publishing,
+    /// and freezes the ones of closed crop receipts
+    pub struct Migrate<T>(PhantomData<T>);
+
+    impl<T> OnRuntimeUpgrade for Migrate<T>
+    where
+        T: Config,
+    {
+        fn on_runtime_upgrade() -> Weight {
+            if Pallet::<T>::on_chain_storage_version() != StorageVersion::new(1) {
+                frame_support::__private::log::info!(
+                    "Presto migration to v2 skipped, storage version is not 1"
+                );
+                return T::DbWeight::get().reads(1);
+            }
+
+            let mut reads: u64 = 1;
+            let mut writes: u64 = 1;
+            for (coupon_asset_id, coupon_info) in Coupons::<T>::iter() {
+                reads += 2;
+                let Some(order_book_id) = T::OrderBookManager::assemble_order_book_id(
+                    DEXId::PolkaswapPresto.into(),
+                    &T::PrestoUsdAssetId::get(),
+                    &coupon_asset_id,
+                ) else {
+                    continue;
+                };
+
+                CouponOrderBooks::<T>::insert(coupon_asset_id, order_book_id);
+                writes += 1;
+
+                let is_closed = CropReceipts::<T>::get(coupon_info.crop_receipt_id)
+                    .map(|crop_receipt| crop_receipt.status == Status::Closed)
+                    .unwrap_or_default();
+                if is_closed && T::OrderBookManager::freeze_orderbook(&order_book_id).is_ok() {
+                    reads += 1;
+                    writes += 1;
+                }
+            }
+
+            StorageVersion::new(2).put::<Pallet<T>>();
+
+            frame_support::__private::log::info!("Presto migrated to v2");
+
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            frame_support::ensure!(
+                Pallet::<T>::on_chain_storage_version() == StorageVersion::new(2),
+                "Presto storage version must be 2 after the migration"
+            );
+            frame_support::ensure!(
+                Coupons::<T>::iter_keys().all(CouponOrderBooks::<T>::contains_key),
+                "Order books of emitted coupons must be indexed"
+            );
+            Ok(())
+        }
+    }
+}
@@ -79,7 +79,7 @@ construct_runtime! {
         Assets: assets::{Pallet, Call, Config<T>, Storage, Event<T>},
         ExtendedAssets: extended_assets::{Pallet, Call, Config<T>, Storage, Event<T>},
         Balances: pallet_balances::{Pallet, Call, Storage, Event<T>},
-        DexManager: dex_manager::{Pallet, Call, Config<T>, Storage},
+        DexManager: dex_manager::{Pallet, Call, Config<T>, Storage, Event<T>},
         OrderBook: order_book::{Pallet, Call, Storage, Event<T>},
         Technical: technical::{Pallet, Call, Config<T>, Storage, Event<T>},
         TradingPair: trading_pair::{Pallet, Call, Storage, Event<T>},
@@ -98,7 +98,7 @@ mock_assets_config!(
     Runtime,
     (
         permissions::Pallet<Runtime>,
-        extended_assets::Pallet<Runtime>,
+        (extended_assets::Pallet<Runtime>, presto::Pallet<Runtime>),
     )
 );
 mock_currencies_config!(Runtime);
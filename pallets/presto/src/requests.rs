@@ -67,13 +67,29 @@ pub enum Request<T: Config> {
 
 impl<T: Config> Request<T> {
     pub fn ensure_is_owner(&self, who: &AccountIdOf<T>) -> DispatchResult {
-        let owner = match self {
+        ensure!(self.owner() == who, Error::<T>::CallerIsNotRequestOwner);
+        Ok(())
+    }
+
+    pub fn owner(&self) -> &AccountIdOf<T> {
+        match self {
             Self::Deposit(request) => &request.owner,
             Self::Withdraw(request) => &request.owner,
-        };
+        }
+    }
 
-        ensure!(owner == who, Error::<T>::CallerIsNotRequestOwner);
-        Ok(())
+    pub fn amount(&self) -> Balance {
+        match self {
+            Self::Deposit(request) => request.amount,
+            Self::Withdraw(request) => request.amount,
+        }
+    }
+
+    pub fn time(&self) -> MomentOf<T> {
+        match self {
+            Self::Deposit(request) => request.time,
+            Self::Withdraw(request) => request.time,
+        }
     }
 
     pub fn status(&self) -> &RequestStatus<T> {
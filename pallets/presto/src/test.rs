@@ -38,17 +38,20 @@ use crate::crop_receipt::{
 };
 use crate::mock::{
     ext, AccountId, AssetId, PrestoBufferTechAccountId, PrestoTechAccountId, Runtime,
-    RuntimeOrigin, TechAccountId,
+    RuntimeOrigin, TechAccountId, Timestamp,
 };
 use crate::requests::{DepositRequest, Request, RequestStatus, WithdrawRequest};
 
+use common::permissions::TRANSFER;
 use common::prelude::BalanceUnit;
 use common::{
-    balance, AssetIdOf, AssetInfoProvider, AssetName, AssetSymbol, Balance, BoundedString, DEXId,
-    OrderBookId, PRUSD, SBT_PRACS, SBT_PRCRDT, SBT_PRINVST,
+    balance, AssetIdOf, AssetInfoProvider, AssetName, AssetRegulator, AssetSymbol, Balance,
+    BoundedString, DEXId, OrderBookId, PRUSD, SBT_PRACS, SBT_PRCRDT, SBT_PRINVST,
 };
 use frame_support::sp_runtime::Permill;
+use frame_support::traits::Hooks;
 use frame_support::{assert_err, assert_ok};
+use order_book::OrderBookStatus;
 use sp_runtime::DispatchError::BadOrigin;
 use sp_std::collections::btree_set::BTreeSet;
 
@@ -1518,27 +1521,27 @@ fn should_publish_crop_receipt() {
         let supply = 10000;
 
         assert_err!(
-            PrestoPallet::publish_crop_receipt(RuntimeOrigin::signed(bob()), 1, 0),
+            PrestoPallet::publish_crop_receipt(RuntimeOrigin::signed(bob()), 1, 0, true),
             E::AmountIsZero
         );
 
         assert_err!(
-            PrestoPallet::publish_crop_receipt(RuntimeOrigin::signed(bob()), 2, supply),
+            PrestoPallet::publish_crop_receipt(RuntimeOrigin::signed(bob()), 2, supply, true),
             E::CropReceiptIsNotExists
         );
 
         assert_err!(
-            PrestoPallet::publish_crop_receipt(RuntimeOrigin::signed(charlie()), 1, supply),
+            PrestoPallet::publish_crop_receipt(RuntimeOrigin::signed(charlie()), 1, supply, true),
             E::CallerIsNotCropReceiptOwner
         );
 
         assert_err!(
-            PrestoPallet::publish_crop_receipt(RuntimeOrigin::signed(bob()), 1, 1000000),
+            PrestoPallet::publish_crop_receipt(RuntimeOrigin::signed(bob()), 1, 1000000, true),
             E::TooBigCouponSupply
         );
 
         assert_err!(
-            PrestoPallet::publish_crop_receipt(RuntimeOrigin::signed(bob()), 1, supply),
+            PrestoPallet::publish_crop_receipt(RuntimeOrigin::signed(bob()), 1, supply, true),
             E::CropReceiptWaitingForRate
         );
 
@@ -1551,11 +1554,12 @@ fn should_publish_crop_receipt() {
         assert_ok!(PrestoPallet::publish_crop_receipt(
             RuntimeOrigin::signed(bob()),
             1,
-            supply
+            supply,
+            true
         ));
 
         assert_err!(
-            PrestoPallet::publish_crop_receipt(RuntimeOrigin::signed(bob()), 1, supply),
+            PrestoPallet::publish_crop_receipt(RuntimeOrigin::signed(bob()), 1, supply, true),
             E::CropReceiptAlreadyHasDecision
         );
 
@@ -1693,7 +1697,8 @@ fn should_pay_off_crop_receipt_full_debt() {
         assert_ok!(PrestoPallet::publish_crop_receipt(
             RuntimeOrigin::signed(bob()),
             1,
-            supply
+            supply,
+            true
         ));
 
         assert_err!(
@@ -1731,7 +1736,7 @@ fn should_pay_off_crop_receipt_full_debt() {
         );
 
         assert_err!(
-            PrestoPallet::publish_crop_receipt(RuntimeOrigin::signed(bob()), 1, supply),
+            PrestoPallet::publish_crop_receipt(RuntimeOrigin::signed(bob()), 1, supply, true),
             E::CropReceiptHasBeenClosed
         );
 
@@ -1803,7 +1808,8 @@ fn should_pay_off_crop_receipt_part_debt() {
         assert_ok!(PrestoPallet::publish_crop_receipt(
             RuntimeOrigin::signed(bob()),
             1,
-            supply
+            supply,
+            true
         ));
 
         assert_err!(
@@ -1918,7 +1924,8 @@ fn should_pay_off_crop_receipt_without_debt() {
         assert_ok!(PrestoPallet::publish_crop_receipt(
             RuntimeOrigin::signed(bob()),
             1,
-            supply
+            supply,
+            true
         ));
 
         assert_err!(
@@ -2021,9 +2028,49 @@ fn should_claim_refund() {
         assert_ok!(PrestoPallet::publish_crop_receipt(
             RuntimeOrigin::signed(bob()),
             1,
-            supply
+            supply,
+            true
         ));
 
+        // investor buys coupons
+
+        let (coupon_asset_id, _) = Coupons::<Runtime>::iter()
+            .collect::<Vec<_>>()
+            .first()
+            .cloned()
+            .unwrap();
+
+        let order_book_id = OrderBookId::<AssetIdOf<Runtime>, DEXId> {
+            dex_id: DEXId::PolkaswapPresto,
+            base: coupon_asset_id,
+            quote: PRUSD,
+        };
+
+        assert_ok!(<Runtime as common::Config>::AssetManager::update_balance(
+            RuntimeOrigin::root(),
+            charlie(),
+            PRUSD,
+            balance!(20000) as i128
+        ));
+
+        assert_eq!(free_balance(&coupon_asset_id, &charlie()), 0);
+
+        assert_ok!(OrderBookPallet::execute_market_order(
+            RuntimeOrigin::signed(charlie()),
+            order_book_id,
+            common::PriceVariant::Buy,
+            1000
+        ));
+        assert_ok!(OrderBookPallet::execute_market_order(
+            RuntimeOrigin::signed(charlie()),
+            order_book_id,
+            common::PriceVariant::Buy,
+            1000
+        ));
+
+        assert_eq!(free_balance(&coupon_asset_id, &charlie()), 2000);
+        assert_eq!(free_balance(&PRUSD, &charlie()), 0);
+
         let debt = balance!(105000);
         assert_ok!(<Runtime as common::Config>::AssetManager::update_balance(
             RuntimeOrigin::root(),
@@ -2037,6 +2084,84 @@ fn should_claim_refund() {
             1
         ));
 
+        // test
+
+        assert_err!(
+            PrestoPallet::claim_refund(RuntimeOrigin::signed(charlie()), coupon_asset_id, 3000),
+            tokens::Error::<Runtime>::BalanceTooLow
+        );
+
+        assert_ok!(PrestoPallet::claim_refund(
+            RuntimeOrigin::signed(charlie()),
+            coupon_asset_id,
+            500
+        ));
+        assert_eq!(free_balance(&coupon_asset_id, &charlie()), 1500);
+        assert_eq!(free_balance(&PRUSD, &charlie()), balance!(5250));
+
+        assert_ok!(PrestoPallet::claim_refund(
+            RuntimeOrigin::signed(charlie()),
+            coupon_asset_id,
+            1500
+        ));
+        assert_eq!(free_balance(&coupon_asset_id, &charlie()), 0);
+        assert_eq!(free_balance(&PRUSD, &charlie()), balance!(21000));
+    });
+}
+
+#[test]
+fn should_repay_crop_receipt_by_installments() {
+    ext().execute_with(|| {
+        // prepare
+
+        assert_ok!(PrestoPallet::add_presto_auditor(
+            RuntimeOrigin::root(),
+            alice()
+        ));
+
+        assert_ok!(PrestoPallet::add_presto_manager(
+            RuntimeOrigin::root(),
+            dave()
+        ));
+
+        assert_ok!(PrestoPallet::apply_creditor_kyc(
+            RuntimeOrigin::signed(dave()),
+            bob()
+        ));
+
+        assert_ok!(PrestoPallet::apply_investor_kyc(
+            RuntimeOrigin::signed(dave()),
+            charlie()
+        ));
+
+        assert_ok!(PrestoPallet::create_crop_receipt(
+            RuntimeOrigin::signed(bob()),
+            balance!(100000),
+            Permill::from_percent(5),
+            Country::Brazil,
+            100,
+            200,
+            BoundedString::truncate_from("place of issue"),
+            BoundedString::truncate_from("debtor"),
+            BoundedString::truncate_from("creditor"),
+            300,
+            crop_receipt_content_template::<Runtime>()
+        ));
+
+        assert_ok!(PrestoPallet::rate_crop_receipt(
+            RuntimeOrigin::signed(alice()),
+            1,
+            Rating::AA
+        ));
+
+        let supply = 10000;
+        assert_ok!(PrestoPallet::publish_crop_receipt(
+            RuntimeOrigin::signed(bob()),
+            1,
+            supply,
+            true
+        ));
+
         // investor buys coupons
 
         let (coupon_asset_id, _) = Coupons::<Runtime>::iter()
@@ -2058,8 +2183,6 @@ fn should_claim_refund() {
             balance!(20000) as i128
         ));
 
-        assert_eq!(free_balance(&coupon_asset_id, &charlie()), 0);
-
         assert_ok!(OrderBookPallet::execute_market_order(
             RuntimeOrigin::signed(charlie()),
             order_book_id,
@@ -2076,27 +2199,648 @@ fn should_claim_refund() {
         assert_eq!(free_balance(&coupon_asset_id, &charlie()), 2000);
         assert_eq!(free_balance(&PRUSD, &charlie()), 0);
 
+        assert_ok!(<Runtime as common::Config>::AssetManager::update_balance(
+            RuntimeOrigin::root(),
+            bob(),
+            PRUSD,
+            balance!(105000) as i128
+        ));
+
         // test
 
         assert_err!(
-            PrestoPallet::claim_refund(RuntimeOrigin::signed(charlie()), coupon_asset_id, 3000),
-            tokens::Error::<Runtime>::BalanceTooLow
+            PrestoPallet::claim_refund(RuntimeOrigin::signed(charlie()), coupon_asset_id, 1000),
+            E::NothingToRefund
+        );
+
+        assert_err!(
+            PrestoPallet::repay_crop_receipt(RuntimeOrigin::signed(charlie()), 1, balance!(1)),
+            E::CreditorKycNotPassed
         );
 
+        assert_err!(
+            PrestoPallet::repay_crop_receipt(RuntimeOrigin::signed(bob()), 1, balance!(105001)),
+            E::AmountExceedsDebt
+        );
+
+        assert_ok!(PrestoPallet::repay_crop_receipt(
+            RuntimeOrigin::signed(bob()),
+            1,
+            balance!(52500)
+        ));
+
+        assert_eq!(
+            PrestoPallet::crop_receipt_repayments(1).repaid,
+            balance!(52500)
+        );
+        assert_eq!(
+            PrestoPallet::crop_receipts(1).unwrap().status,
+            Status::Published
+        );
+
+        // half of the debt is repaid, so each coupon refunds a half of its price
         assert_ok!(PrestoPallet::claim_refund(
             RuntimeOrigin::signed(charlie()),
             coupon_asset_id,
-            500
+            1000
         ));
-        assert_eq!(free_balance(&coupon_asset_id, &charlie()), 1500);
+        assert_eq!(free_balance(&coupon_asset_id, &charlie()), 1000);
         assert_eq!(free_balance(&PRUSD, &charlie()), balance!(5250));
+        assert_eq!(
+            PrestoPallet::refund_claims(1, charlie()),
+            RefundClaim {
+                coupons: 1000,
+                refunded: balance!(5250)
+            }
+        );
+
+        assert_err!(
+            PrestoPallet::claim_refund(RuntimeOrigin::signed(charlie()), coupon_asset_id, 0),
+            E::NothingToRefund
+        );
+
+        // coupons claimed in advance are still backed by the debt
+        assert_eq!(
+            PrestoPallet::crop_receipt_outstanding_debt(1),
+            Some(balance!(52500))
+        );
+        assert_err!(
+            PrestoPallet::repay_crop_receipt(RuntimeOrigin::signed(bob()), 1, balance!(52501)),
+            E::AmountExceedsDebt
+        );
+
+        assert_ok!(PrestoPallet::repay_crop_receipt(
+            RuntimeOrigin::signed(bob()),
+            1,
+            balance!(52500)
+        ));
+
+        // the creditor has also received PRUSD for the sold coupons
+        assert_eq!(free_balance(&PRUSD, &bob()), balance!(20000));
+        assert_eq!(
+            PrestoPallet::crop_receipts(1).unwrap().status,
+            Status::Closed
+        );
+        assert_eq!(PrestoPallet::active_crop_receipts(1), None);
+        assert_eq!(PrestoPallet::active_crop_receipts_by_period(0, 1), None);
+
+        assert_err!(
+            PrestoPallet::repay_crop_receipt(RuntimeOrigin::signed(bob()), 1, balance!(1)),
+            E::CropReceiptHasBeenClosed
+        );
 
+        // the rest of the price of the coupons claimed in advance
         assert_ok!(PrestoPallet::claim_refund(
             RuntimeOrigin::signed(charlie()),
             coupon_asset_id,
-            1500
+            0
+        ));
+        assert_eq!(free_balance(&PRUSD, &charlie()), balance!(10500));
+
+        assert_ok!(PrestoPallet::claim_refund(
+            RuntimeOrigin::signed(charlie()),
+            coupon_asset_id,
+            1000
         ));
         assert_eq!(free_balance(&coupon_asset_id, &charlie()), 0);
         assert_eq!(free_balance(&PRUSD, &charlie()), balance!(21000));
+        assert_eq!(
+            PrestoPallet::refund_claims(1, charlie()),
+            RefundClaim {
+                coupons: 2000,
+                refunded: balance!(21000)
+            }
+        );
+        assert!(PrestoPallet::coupon_holdings(&charlie()).is_empty());
+    });
+}
+
+#[test]
+fn should_default_overdue_crop_receipt() {
+    ext().execute_with(|| {
+        // prepare
+
+        assert_ok!(PrestoPallet::add_presto_auditor(
+            RuntimeOrigin::root(),
+            alice()
+        ));
+
+        assert_ok!(PrestoPallet::add_presto_manager(
+            RuntimeOrigin::root(),
+            dave()
+        ));
+
+        assert_ok!(PrestoPallet::apply_creditor_kyc(
+            RuntimeOrigin::signed(dave()),
+            bob()
+        ));
+
+        assert_ok!(PrestoPallet::apply_investor_kyc(
+            RuntimeOrigin::signed(dave()),
+            charlie()
+        ));
+
+        assert_ok!(PrestoPallet::create_crop_receipt(
+            RuntimeOrigin::signed(bob()),
+            balance!(100000),
+            Permill::from_percent(5),
+            Country::Brazil,
+            100,
+            200,
+            BoundedString::truncate_from("place of issue"),
+            BoundedString::truncate_from("debtor"),
+            BoundedString::truncate_from("creditor"),
+            300,
+            crop_receipt_content_template::<Runtime>()
+        ));
+
+        assert_ok!(PrestoPallet::rate_crop_receipt(
+            RuntimeOrigin::signed(alice()),
+            1,
+            Rating::AA
+        ));
+
+        let supply = 10000;
+        assert_ok!(PrestoPallet::publish_crop_receipt(
+            RuntimeOrigin::signed(bob()),
+            1,
+            supply,
+            true
+        ));
+
+        // investor buys coupons
+
+        let (coupon_asset_id, _) = Coupons::<Runtime>::iter()
+            .collect::<Vec<_>>()
+            .first()
+            .cloned()
+            .unwrap();
+
+        let order_book_id = OrderBookId::<AssetIdOf<Runtime>, DEXId> {
+            dex_id: DEXId::PolkaswapPresto,
+            base: coupon_asset_id,
+            quote: PRUSD,
+        };
+
+        assert_ok!(<Runtime as common::Config>::AssetManager::update_balance(
+            RuntimeOrigin::root(),
+            charlie(),
+            PRUSD,
+            balance!(20000) as i128
+        ));
+
+        assert_ok!(OrderBookPallet::execute_market_order(
+            RuntimeOrigin::signed(charlie()),
+            order_book_id,
+            common::PriceVariant::Buy,
+            1000
+        ));
+        assert_ok!(OrderBookPallet::execute_market_order(
+            RuntimeOrigin::signed(charlie()),
+            order_book_id,
+            common::PriceVariant::Buy,
+            1000
+        ));
+
+        assert_eq!(free_balance(&coupon_asset_id, &charlie()), 2000);
+        assert_eq!(free_balance(&PRUSD, &charlie()), 0);
+
+        // test
+
+        assert_err!(
+            PrestoPallet::declare_crop_receipt_default(RuntimeOrigin::signed(alice()), 1),
+            E::CropReceiptIsNotOverdue
+        );
+
+        Timestamp::set_timestamp(300);
+        PrestoPallet::on_initialize(1);
+        assert_eq!(
+            PrestoPallet::crop_receipts(1).unwrap().status,
+            Status::Published
+        );
+        assert_eq!(
+            PrestoPallet::active_crop_receipts_by_period(0, 1),
+            Some(300)
+        );
+
+        Timestamp::set_timestamp(301);
+        PrestoPallet::on_initialize(2);
+        assert_eq!(
+            PrestoPallet::crop_receipts(1).unwrap().status,
+            Status::Overdue
+        );
+        assert_eq!(PrestoPallet::active_crop_receipts(1), None);
+        assert_eq!(PrestoPallet::active_crop_receipts_by_period(0, 1), None);
+        assert_eq!(PrestoPallet::next_overdue_check_period(), 0);
+
+        assert_err!(
+            PrestoPallet::record_crop_receipt_recovery(
+                RuntimeOrigin::signed(dave()),
+                1,
+                balance!(1)
+            ),
+            E::CropReceiptIsNotDefaulted
+        );
+
+        assert_err!(
+            PrestoPallet::declare_crop_receipt_default(RuntimeOrigin::signed(bob()), 1),
+            E::CallerIsNotAuditor
+        );
+
+        assert_ok!(PrestoPallet::declare_crop_receipt_default(
+            RuntimeOrigin::signed(alice()),
+            1
+        ));
+        assert_eq!(
+            PrestoPallet::crop_receipts(1).unwrap().status,
+            Status::Defaulted
+        );
+
+        assert_err!(
+            PrestoPallet::declare_crop_receipt_default(RuntimeOrigin::signed(alice()), 1),
+            E::CropReceiptHasDefaulted
+        );
+
+        // coupons are frozen
+        assert_err!(
+            <Runtime as common::Config>::AssetManager::transfer_from(
+                &coupon_asset_id,
+                &charlie(),
+                &bob(),
+                100
+            ),
+            E::CouponsAreFrozen
+        );
+        assert_eq!(
+            order_book::OrderBooks::<Runtime>::get(order_book_id)
+                .unwrap()
+                .status,
+            order_book::OrderBookStatus::OnlyCancel
+        );
+        assert_err!(
+            OrderBookPallet::execute_market_order(
+                RuntimeOrigin::signed(charlie()),
+                order_book_id,
+                common::PriceVariant::Buy,
+                1000
+            ),
+            order_book::Error::<Runtime>::TradingIsForbidden
+        );
+
+        assert_err!(
+            PrestoPallet::repay_crop_receipt(RuntimeOrigin::signed(bob()), 1, balance!(1)),
+            E::CropReceiptHasDefaulted
+        );
+
+        assert_ok!(PrestoPallet::mint_presto_usd(
+            RuntimeOrigin::signed(dave()),
+            balance!(50000)
+        ));
+
+        assert_err!(
+            PrestoPallet::record_crop_receipt_recovery(
+                RuntimeOrigin::signed(bob()),
+                1,
+                balance!(50000)
+            ),
+            E::CallerIsNotManager
+        );
+
+        assert_ok!(PrestoPallet::record_crop_receipt_recovery(
+            RuntimeOrigin::signed(dave()),
+            1,
+            balance!(50000)
+        ));
+        assert_eq!(
+            PrestoPallet::crop_receipt_repayments(1).recovered,
+            balance!(50000)
+        );
+        assert_eq!(
+            free_balance(
+                &PRUSD,
+                &tech_account_id_to_account_id(&PrestoBufferTechAccountId::get())
+            ),
+            balance!(50000)
+        );
+
+        // recovered funds are shared by all coupons in circulation
+        assert_ok!(PrestoPallet::claim_refund(
+            RuntimeOrigin::signed(charlie()),
+            coupon_asset_id,
+            2000
+        ));
+        assert_eq!(free_balance(&coupon_asset_id, &charlie()), 0);
+        assert_eq!(free_balance(&PRUSD, &charlie()), balance!(10000));
+    });
+}
+
+#[test]
+fn should_provide_crop_receipts_and_coupon_holdings_info() {
+    ext().execute_with(|| {
+        // prepare
+
+        assert_ok!(PrestoPallet::add_presto_auditor(
+            RuntimeOrigin::root(),
+            alice()
+        ));
+
+        assert_ok!(PrestoPallet::add_presto_manager(
+            RuntimeOrigin::root(),
+            dave()
+        ));
+
+        assert_ok!(PrestoPallet::apply_creditor_kyc(
+            RuntimeOrigin::signed(dave()),
+            bob()
+        ));
+
+        assert_ok!(PrestoPallet::apply_investor_kyc(
+            RuntimeOrigin::signed(dave()),
+            charlie()
+        ));
+
+        for country in [Country::Brazil, Country::Nigeria] {
+            assert_ok!(PrestoPallet::create_crop_receipt(
+                RuntimeOrigin::signed(bob()),
+                balance!(100000),
+                Permill::from_percent(5),
+                country,
+                100,
+                200,
+                BoundedString::truncate_from("place of issue"),
+                BoundedString::truncate_from("debtor"),
+                BoundedString::truncate_from("creditor"),
+                300,
+                crop_receipt_content_template::<Runtime>()
+            ));
+        }
+
+        assert_ok!(PrestoPallet::rate_crop_receipt(
+            RuntimeOrigin::signed(alice()),
+            1,
+            Rating::AA
+        ));
+
+        assert_ok!(PrestoPallet::publish_crop_receipt(
+            RuntimeOrigin::signed(bob()),
+            1,
+            10000,
+            true
+        ));
+
+        let coupon_asset_id = PrestoPallet::crop_receipt_to_coupon(1).unwrap();
+
+        let order_book_id = OrderBookId::<AssetIdOf<Runtime>, DEXId> {
+            dex_id: DEXId::PolkaswapPresto,
+            base: coupon_asset_id,
+            quote: PRUSD,
+        };
+
+        assert_ok!(<Runtime as common::Config>::AssetManager::update_balance(
+            RuntimeOrigin::root(),
+            charlie(),
+            PRUSD,
+            balance!(10000) as i128
+        ));
+
+        assert_ok!(OrderBookPallet::execute_market_order(
+            RuntimeOrigin::signed(charlie()),
+            order_book_id,
+            common::PriceVariant::Buy,
+            1000
+        ));
+
+        assert_ok!(PrestoPallet::create_deposit_request(
+            RuntimeOrigin::signed(charlie()),
+            balance!(100),
+            BoundedString::truncate_from("payment reference"),
+            None
+        ));
+
+        // test
+
+        let ids = |crop_receipts: Vec<(u64, CropReceipt<Runtime>)>| {
+            crop_receipts
+                .into_iter()
+                .map(|(id, _)| id)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            ids(PrestoPallet::filter_crop_receipts(None, None, None)),
+            vec![1, 2]
+        );
+        assert_eq!(
+            ids(PrestoPallet::filter_crop_receipts(
+                Some(Status::Published),
+                None,
+                None
+            )),
+            vec![1]
+        );
+        assert_eq!(
+            ids(PrestoPallet::filter_crop_receipts(
+                None,
+                Some(Country::Nigeria),
+                None
+            )),
+            vec![2]
+        );
+        assert_eq!(
+            ids(PrestoPallet::filter_crop_receipts(
+                None,
+                Some(Country::Brazil),
+                Some(Rating::A)
+            )),
+            Vec::<u64>::new()
+        );
+
+        assert_eq!(
+            Status::from_label(Status::Overdue.label()),
+            Some(Status::Overdue)
+        );
+        assert_eq!(Country::from_label("Nigeria"), Some(Country::Nigeria));
+        assert_eq!(Rating::from_label("BBB"), Some(Rating::BBB));
+        assert_eq!(Rating::from_label("E"), None);
+
+        assert_eq!(
+            PrestoPallet::crop_receipt_outstanding_debt(1),
+            Some(balance!(105000))
+        );
+        assert_eq!(PrestoPallet::crop_receipt_outstanding_debt(2), None);
+
+        let requests = PrestoPallet::user_requests_info(&charlie());
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].0, 1);
+        assert_eq!(requests[0].1.amount(), balance!(100));
+        assert_eq!(*requests[0].1.status(), RequestStatus::Pending);
+
+        let holdings = PrestoPallet::coupon_holdings(&charlie());
+        assert_eq!(holdings.len(), 1);
+        assert_eq!(holdings[0].coupon_asset_id, coupon_asset_id);
+        assert_eq!(holdings[0].amount, 1000);
+        assert_eq!(holdings[0].expected_refund, balance!(10500));
+        assert_eq!(holdings[0].claimable_refund, 0);
+
+        assert_ok!(<Runtime as common::Config>::AssetManager::update_balance(
+            RuntimeOrigin::root(),
+            bob(),
+            PRUSD,
+            balance!(52500) as i128
+        ));
+        assert_ok!(PrestoPallet::repay_crop_receipt(
+            RuntimeOrigin::signed(bob()),
+            1,
+            balance!(52500)
+        ));
+
+        let holdings = PrestoPallet::coupon_holdings(&charlie());
+        assert_eq!(holdings[0].claimable_refund, balance!(5250));
+
+        let position = PrestoPallet::treasury_position().unwrap();
+        assert_eq!(position.treasury, 0);
+        assert_eq!(position.buffer, balance!(52500));
+        assert_eq!(position.outstanding_debt, balance!(52500));
+    });
+}
+
+#[test]
+fn should_list_coupons_in_order_book() {
+    ext().execute_with(|| {
+        // prepare
+
+        assert_ok!(PrestoPallet::add_presto_auditor(
+            RuntimeOrigin::root(),
+            alice()
+        ));
+
+        assert_ok!(PrestoPallet::add_presto_manager(
+            RuntimeOrigin::root(),
+            dave()
+        ));
+
+        assert_ok!(PrestoPallet::apply_creditor_kyc(
+            RuntimeOrigin::signed(dave()),
+            bob()
+        ));
+
+        assert_ok!(PrestoPallet::create_crop_receipt(
+            RuntimeOrigin::signed(bob()),
+            balance!(100000),
+            Permill::from_percent(5),
+            Country::Brazil,
+            100,
+            200,
+            BoundedString::truncate_from("place of issue"),
+            BoundedString::truncate_from("debtor"),
+            BoundedString::truncate_from("creditor"),
+            300,
+            crop_receipt_content_template::<Runtime>()
+        ));
+
+        assert_ok!(PrestoPallet::rate_crop_receipt(
+            RuntimeOrigin::signed(alice()),
+            1,
+            Rating::AA
+        ));
+
+        // test
+
+        assert_err!(
+            PrestoPallet::list_coupons(RuntimeOrigin::signed(bob()), 1),
+            E::CropReceiptIsNotPublished
+        );
+
+        let supply = 10000;
+        assert_ok!(PrestoPallet::publish_crop_receipt(
+            RuntimeOrigin::signed(bob()),
+            1,
+            supply,
+            false
+        ));
+
+        let coupon_asset_id = PrestoPallet::crop_receipt_to_coupon(1).unwrap();
+        let order_book_id = OrderBookId::<AssetIdOf<Runtime>, DEXId> {
+            dex_id: DEXId::PolkaswapPresto,
+            base: coupon_asset_id,
+            quote: PRUSD,
+        };
+
+        assert_eq!(PrestoPallet::coupon_order_books(coupon_asset_id), None);
+        assert!(OrderBookPallet::order_books(order_book_id).is_none());
+        assert_eq!(free_balance(&coupon_asset_id, &bob()), supply);
+
+        // coupons can be transferred only between KYC passed accounts
+        assert_err!(
+            <PrestoPallet as AssetRegulator<AccountId, AssetId>>::check_permission(
+                &bob(),
+                &charlie(),
+                &coupon_asset_id,
+                &TRANSFER
+            ),
+            E::KycNotPassed
+        );
+        assert_ok!(
+            <PrestoPallet as AssetRegulator<AccountId, AssetId>>::check_permission(
+                &bob(),
+                &charlie(),
+                &PRUSD,
+                &TRANSFER
+            )
+        );
+
+        assert_err!(
+            PrestoPallet::list_coupons(RuntimeOrigin::signed(charlie()), 1),
+            E::CreditorKycNotPassed
+        );
+
+        assert_ok!(PrestoPallet::list_coupons(RuntimeOrigin::signed(bob()), 1));
+
+        assert_err!(
+            PrestoPallet::list_coupons(RuntimeOrigin::signed(bob()), 1),
+            E::CouponsAlreadyListed
+        );
+
+        assert_eq!(
+            PrestoPallet::coupon_order_books(coupon_asset_id),
+            Some(order_book_id)
+        );
+        let order_book = OrderBookPallet::order_books(order_book_id).unwrap();
+        assert_eq!(order_book.status, OrderBookStatus::Trade);
+        assert_eq!(order_book.max_lot_size, BalanceUnit::indivisible(1000));
+        assert!(OrderBookPallet::aggregated_asks(order_book_id).is_empty());
+
+        // the order book is frozen when the crop receipt is closed
+        assert_ok!(PrestoPallet::pay_off_crop_receipt(
+            RuntimeOrigin::signed(bob()),
+            1
+        ));
+
+        assert_eq!(
+            OrderBookPallet::order_books(order_book_id).unwrap().status,
+            OrderBookStatus::OnlyCancel
+        );
+    });
+}
+
+#[test]
+fn should_check_overdue_crop_receipts_in_bounded_batches() {
+    ext().execute_with(|| {
+        for id in 1..=100 {
+            PrestoPallet::activate_crop_receipt(id, 300);
+        }
+
+        Timestamp::set_timestamp(301);
+        PrestoPallet::on_initialize(1);
+
+        assert_eq!(ActiveCropReceipts::<Runtime>::iter().count(), 36);
+        assert_eq!(ActiveCropReceiptsByPeriod::<Runtime>::iter().count(), 36);
+        assert!(OverdueCheckCursor::<Runtime>::get().is_some());
+
+        PrestoPallet::on_initialize(2);
+
+        assert_eq!(ActiveCropReceipts::<Runtime>::iter().count(), 0);
+        assert_eq!(ActiveCropReceiptsByPeriod::<Runtime>::iter().count(), 0);
+        assert_eq!(OverdueCheckCursor::<Runtime>::get(), None);
     });
 }
@@ -30,14 +30,45 @@
 
 use crate::Config;
 
-use common::{AccountIdOf, AssetManager, Balance};
+use common::{AccountIdOf, AssetInfoProvider, AssetManager, Balance};
 use core::marker::PhantomData;
-use sp_runtime::traits::Get;
-use sp_runtime::DispatchResult;
+use sp_runtime::traits::{Get, Zero};
+use sp_runtime::{DispatchError, DispatchResult};
+
+/// PRUSD position of the Presto treasury
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TreasuryPosition {
+    /// PRUSD held by the main treasury account
+    pub treasury: Balance,
+    /// PRUSD held by the buffer account for withdraw requests and refunds
+    pub buffer: Balance,
+    /// Total PRUSD supply
+    pub total_supply: Balance,
+    /// Debt of published crop receipts which is not covered yet
+    pub outstanding_debt: Balance,
+}
 
 pub struct Treasury<T: Config>(PhantomData<T>);
 
 impl<T: Config> Treasury<T> {
+    pub fn position() -> Result<TreasuryPosition, DispatchError> {
+        let presto_tech_account_id =
+            technical::Pallet::<T>::tech_account_id_to_account_id(&T::PrestoTechAccount::get())?;
+
+        let presto_buffer_tech_account_id = technical::Pallet::<T>::tech_account_id_to_account_id(
+            &T::PrestoBufferTechAccount::get(),
+        )?;
+
+        let asset_id = T::PrestoUsdAssetId::get();
+
+        Ok(TreasuryPosition {
+            treasury: T::AssetInfoProvider::free_balance(&asset_id, &presto_tech_account_id)?,
+            buffer: T::AssetInfoProvider::free_balance(&asset_id, &presto_buffer_tech_account_id)?,
+            total_supply: T::AssetInfoProvider::total_issuance(&asset_id)?,
+            outstanding_debt: Balance::zero(),
+        })
+    }
+
     pub fn mint_presto_usd(amount: Balance) -> DispatchResult {
         let presto_tech_account_id =
             technical::Pallet::<T>::tech_account_id_to_account_id(&T::PrestoTechAccount::get())?;
@@ -98,6 +129,24 @@ impl<T: Config> Treasury<T> {
         Ok(())
     }
 
+    pub fn transfer_from_main_to_buffer(amount: Balance) -> DispatchResult {
+        let presto_tech_account_id =
+            technical::Pallet::<T>::tech_account_id_to_account_id(&T::PrestoTechAccount::get())?;
+
+        let presto_buffer_tech_account_id = technical::Pallet::<T>::tech_account_id_to_account_id(
+            &T::PrestoBufferTechAccount::get(),
+        )?;
+
+        T::AssetManager::transfer_from(
+            &T::PrestoUsdAssetId::get(),
+            &presto_tech_account_id,
+            &presto_buffer_tech_account_id,
+            amount,
+        )?;
+
+        Ok(())
+    }
+
     pub fn transfer_from_buffer(amount: Balance, to: &AccountIdOf<T>) -> DispatchResult {
         let presto_buffer_tech_account_id = technical::Pallet::<T>::tech_account_id_to_account_id(
             &T::PrestoBufferTechAccount::get(),
@@ -84,6 +84,10 @@ pub trait WeightInfo {
     fn publish_crop_receipt() -> Weight;
     fn pay_off_crop_receipt() -> Weight;
     fn claim_refund() -> Weight;
+    fn repay_crop_receipt() -> Weight;
+    fn declare_crop_receipt_default() -> Weight;
+    fn record_crop_receipt_recovery() -> Weight;
+    fn list_coupons() -> Weight;
 }
 
 /// Weight functions for `presto`.
@@ -603,14 +607,101 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     /// Proof: Presto CropReceipts (max_values: None, max_size: Some(402), added: 2877, mode: MaxEncodedLen)
     /// Storage: System Account (r:1 w:0)
     /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: Presto RefundClaims (r:1 w:1)
+    /// Proof: Presto RefundClaims (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
     fn claim_refund() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `4695`
         //  Estimated: `708014`
         // Minimum execution time: 101_368 nanoseconds.
         Weight::from_parts(109_997_000, 708014)
-            .saturating_add(T::DbWeight::get().reads(22))
-            .saturating_add(T::DbWeight::get().writes(4))
+            .saturating_add(T::DbWeight::get().reads(23))
+            .saturating_add(T::DbWeight::get().writes(5))
+    }
+    /// Storage: Presto CropReceipts (r:1 w:1)
+    /// Proof: Presto CropReceipts (max_values: None, max_size: Some(402), added: 2877, mode: MaxEncodedLen)
+    /// Storage: Presto CropReceiptToCoupon (r:1 w:0)
+    /// Proof: Presto CropReceiptToCoupon (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+    /// Storage: Presto Coupons (r:1 w:0)
+    /// Proof: Presto Coupons (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+    /// Storage: Presto CropReceiptRepayments (r:1 w:1)
+    /// Proof: Presto CropReceiptRepayments (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+    /// Storage: Tokens Accounts (r:2 w:2)
+    /// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+    /// Storage: Presto ActiveCropReceipts (r:1 w:1)
+    /// Proof: Presto ActiveCropReceipts (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+    /// Storage: Presto ActiveCropReceiptsByPeriod (r:0 w:1)
+    /// Proof: Presto ActiveCropReceiptsByPeriod (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+    fn repay_crop_receipt() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `2187`
+        //  Estimated: `8212`
+        // Minimum execution time: 54_112 nanoseconds.
+        Weight::from_parts(56_904_000, 8212)
+            .saturating_add(T::DbWeight::get().reads(13))
+            .saturating_add(T::DbWeight::get().writes(7))
+    }
+    /// Storage: Presto Auditors (r:1 w:0)
+    /// Proof: Presto Auditors (max_values: Some(1), max_size: Some(3202), added: 3697, mode: MaxEncodedLen)
+    /// Storage: Presto CropReceipts (r:1 w:1)
+    /// Proof: Presto CropReceipts (max_values: None, max_size: Some(402), added: 2877, mode: MaxEncodedLen)
+    /// Storage: Presto CropReceiptToCoupon (r:1 w:0)
+    /// Proof: Presto CropReceiptToCoupon (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+    /// Storage: Presto Coupons (r:1 w:0)
+    /// Proof: Presto Coupons (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+    /// Storage: OrderBook OrderBooks (r:1 w:1)
+    /// Proof: OrderBook OrderBooks (max_values: None, max_size: Some(238), added: 2713, mode: MaxEncodedLen)
+    fn declare_crop_receipt_default() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1824`
+        //  Estimated: `4687`
+        // Minimum execution time: 31_406 nanoseconds.
+        Weight::from_parts(32_951_000, 4687)
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+    /// Storage: Presto Managers (r:1 w:0)
+    /// Proof: Presto Managers (max_values: Some(1), max_size: Some(3202), added: 3697, mode: MaxEncodedLen)
+    /// Storage: Presto CropReceipts (r:1 w:0)
+    /// Proof: Presto CropReceipts (max_values: None, max_size: Some(402), added: 2877, mode: MaxEncodedLen)
+    /// Storage: Presto CropReceiptToCoupon (r:1 w:0)
+    /// Proof: Presto CropReceiptToCoupon (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+    /// Storage: Presto Coupons (r:1 w:0)
+    /// Proof: Presto Coupons (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+    /// Storage: Presto CropReceiptRepayments (r:1 w:1)
+    /// Proof: Presto CropReceiptRepayments (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+    /// Storage: Tokens Accounts (r:2 w:2)
+    /// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+    fn record_crop_receipt_recovery() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `2204`
+        //  Estimated: `8212`
+        // Minimum execution time: 47_835 nanoseconds.
+        Weight::from_parts(49_620_000, 8212)
+            .saturating_add(T::DbWeight::get().reads(10))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }    /// Storage: Presto CropReceipts (r:1 w:0)
+    /// Proof: Presto CropReceipts (max_values: None, max_size: Some(402), added: 2877, mode: MaxEncodedLen)
+    /// Storage: Presto CropReceiptToCoupon (r:1 w:0)
+    /// Proof: Presto CropReceiptToCoupon (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+    /// Storage: Presto Coupons (r:1 w:0)
+    /// Proof: Presto Coupons (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+    /// Storage: Presto CouponOrderBooks (r:1 w:1)
+    /// Proof: Presto CouponOrderBooks (max_values: None, max_size: Some(153), added: 2628, mode: MaxEncodedLen)
+    /// Storage: TradingPair EnabledSources (r:1 w:1)
+    /// Proof Skipped: TradingPair EnabledSources (max_values: None, max_size: None, mode: Measured)
+    /// Storage: OrderBook OrderBooks (r:1 w:1)
+    /// Proof: OrderBook OrderBooks (max_values: None, max_size: Some(238), added: 2713, mode: MaxEncodedLen)
+    /// Storage: Tokens Accounts (r:2 w:2)
+    /// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+    fn list_coupons() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `2561`
+        //  Estimated: `8934`
+        // Minimum execution time: 71_204 nanoseconds.
+        Weight::from_parts(73_118_000, 8934)
+            .saturating_add(T::DbWeight::get().reads(14))
+            .saturating_add(T::DbWeight::get().writes(8))
     }
 }
 
@@ -1130,13 +1221,100 @@ impl WeightInfo for () {
     /// Proof: Presto CropReceipts (max_values: None, max_size: Some(402), added: 2877, mode: MaxEncodedLen)
     /// Storage: System Account (r:1 w:0)
     /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: Presto RefundClaims (r:1 w:1)
+    /// Proof: Presto RefundClaims (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
     fn claim_refund() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `4695`
         //  Estimated: `708014`
         // Minimum execution time: 101_368 nanoseconds.
         Weight::from_parts(109_997_000, 708014)
-            .saturating_add(RocksDbWeight::get().reads(22))
-            .saturating_add(RocksDbWeight::get().writes(4))
+            .saturating_add(RocksDbWeight::get().reads(23))
+            .saturating_add(RocksDbWeight::get().writes(5))
+    }
+    /// Storage: Presto CropReceipts (r:1 w:1)
+    /// Proof: Presto CropReceipts (max_values: None, max_size: Some(402), added: 2877, mode: MaxEncodedLen)
+    /// Storage: Presto CropReceiptToCoupon (r:1 w:0)
+    /// Proof: Presto CropReceiptToCoupon (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+    /// Storage: Presto Coupons (r:1 w:0)
+    /// Proof: Presto Coupons (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+    /// Storage: Presto CropReceiptRepayments (r:1 w:1)
+    /// Proof: Presto CropReceiptRepayments (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+    /// Storage: Tokens Accounts (r:2 w:2)
+    /// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+    /// Storage: Presto ActiveCropReceipts (r:1 w:1)
+    /// Proof: Presto ActiveCropReceipts (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+    /// Storage: Presto ActiveCropReceiptsByPeriod (r:0 w:1)
+    /// Proof: Presto ActiveCropReceiptsByPeriod (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+    fn repay_crop_receipt() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `2187`
+        //  Estimated: `8212`
+        // Minimum execution time: 54_112 nanoseconds.
+        Weight::from_parts(56_904_000, 8212)
+            .saturating_add(RocksDbWeight::get().reads(13))
+            .saturating_add(RocksDbWeight::get().writes(7))
+    }
+    /// Storage: Presto Auditors (r:1 w:0)
+    /// Proof: Presto Auditors (max_values: Some(1), max_size: Some(3202), added: 3697, mode: MaxEncodedLen)
+    /// Storage: Presto CropReceipts (r:1 w:1)
+    /// Proof: Presto CropReceipts (max_values: None, max_size: Some(402), added: 2877, mode: MaxEncodedLen)
+    /// Storage: Presto CropReceiptToCoupon (r:1 w:0)
+    /// Proof: Presto CropReceiptToCoupon (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+    /// Storage: Presto Coupons (r:1 w:0)
+    /// Proof: Presto Coupons (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+    /// Storage: OrderBook OrderBooks (r:1 w:1)
+    /// Proof: OrderBook OrderBooks (max_values: None, max_size: Some(238), added: 2713, mode: MaxEncodedLen)
+    fn declare_crop_receipt_default() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1824`
+        //  Estimated: `4687`
+        // Minimum execution time: 31_406 nanoseconds.
+        Weight::from_parts(32_951_000, 4687)
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+    /// Storage: Presto Managers (r:1 w:0)
+    /// Proof: Presto Managers (max_values: Some(1), max_size: Some(3202), added: 3697, mode: MaxEncodedLen)
+    /// Storage: Presto CropReceipts (r:1 w:0)
+    /// Proof: Presto CropReceipts (max_values: None, max_size: Some(402), added: 2877, mode: MaxEncodedLen)
+    /// Storage: Presto CropReceiptToCoupon (r:1 w:0)
+    /// Proof: Presto CropReceiptToCoupon (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+    /// Storage: Presto Coupons (r:1 w:0)
+    /// Proof: Presto Coupons (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+    /// Storage: Presto CropReceiptRepayments (r:1 w:1)
+    /// Proof: Presto CropReceiptRepayments (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+    /// Storage: Tokens Accounts (r:2 w:2)
+    /// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+    fn record_crop_receipt_recovery() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `2204`
+        //  Estimated: `8212`
+        // Minimum execution time: 47_835 nanoseconds.
+        Weight::from_parts(49_620_000, 8212)
+            .saturating_add(RocksDbWeight::get().reads(10))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }    /// Storage: Presto CropReceipts (r:1 w:0)
+    /// Proof: Presto CropReceipts (max_values: None, max_size: Some(402), added: 2877, mode: MaxEncodedLen)
+    /// Storage: Presto CropReceiptToCoupon (r:1 w:0)
+    /// Proof: Presto CropReceiptToCoupon (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+    /// Storage: Presto Coupons (r:1 w:0)
+    /// Proof: Presto Coupons (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+    /// Storage: Presto CouponOrderBooks (r:1 w:1)
+    /// Proof: Presto CouponOrderBooks (max_values: None, max_size: Some(153), added: 2628, mode: MaxEncodedLen)
+    /// Storage: TradingPair EnabledSources (r:1 w:1)
+    /// Proof Skipped: TradingPair EnabledSources (max_values: None, max_size: None, mode: Measured)
+    /// Storage: OrderBook OrderBooks (r:1 w:1)
+    /// Proof: OrderBook OrderBooks (max_values: None, max_size: Some(238), added: 2713, mode: MaxEncodedLen)
+    /// Storage: Tokens Accounts (r:2 w:2)
+    /// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+    fn list_coupons() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `2561`
+        //  Estimated: `8934`
+        // Minimum execution time: 71_204 nanoseconds.
+        Weight::from_parts(73_118_000, 8934)
+            .saturating_add(RocksDbWeight::get().reads(14))
+            .saturating_add(RocksDbWeight::get().writes(8))
     }
 }
@@ -0,0 +1,124 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use codec::Codec;
+use common::PriceVariant;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::ErrorObjectOwned};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::{Block as BlockT, MaybeDisplay, MaybeFromStr};
+use std::sync::Arc;
+
+fn runtime_error_into_rpc_error(error: impl core::fmt::Debug) -> ErrorObjectOwned {
+    ErrorObjectOwned::owned(1, "Runtime error", Some(format!("{error:?}")))
+}
+
+// Runtime API imports.
+use price_tools_runtime_api::PriceInfo;
+pub use price_tools_runtime_api::PriceToolsAPI as PriceToolsRuntimeAPI;
+
+#[rpc(server)]
+pub trait PriceToolsAPI<BlockHash, AssetId, OptionPriceInfo, VecPriceInfos> {
+    #[method(name = "priceTools_getPriceInfo")]
+    fn get_price_info(
+        &self,
+        asset_id: AssetId,
+        price_variant: PriceVariant,
+        at: Option<BlockHash>,
+    ) -> RpcResult<OptionPriceInfo>;
+
+    #[method(name = "priceTools_listPriceInfos")]
+    fn list_price_infos(
+        &self,
+        price_variant: PriceVariant,
+        at: Option<BlockHash>,
+    ) -> RpcResult<VecPriceInfos>;
+}
+
+pub struct PriceToolsClient<C, B> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> PriceToolsClient<C, B> {
+    /// Construct default PriceTools as intermediary impl for rpc.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block, AssetId, Balance, Symbol>
+    PriceToolsAPIServer<
+        <Block as BlockT>::Hash,
+        AssetId,
+        Option<PriceInfo<Balance, Symbol>>,
+        Vec<(AssetId, PriceInfo<Balance, Symbol>)>,
+    > for PriceToolsClient<C, Block>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: PriceToolsRuntimeAPI<Block, AssetId, Balance, Symbol>,
+    AssetId: Codec,
+    Balance: Codec + MaybeFromStr + MaybeDisplay,
+    Symbol: Codec,
+{
+    fn get_price_info(
+        &self,
+        asset_id: AssetId,
+        price_variant: PriceVariant,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<PriceInfo<Balance, Symbol>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        );
+        api.get_price_info(at, asset_id, price_variant)
+            .map_err(runtime_error_into_rpc_error)
+    }
+
+    fn list_price_infos(
+        &self,
+        price_variant: PriceVariant,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<(AssetId, PriceInfo<Balance, Symbol>)>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        );
+        api.list_price_infos(at, price_variant)
+            .map_err(runtime_error_into_rpc_error)
+    }
+}
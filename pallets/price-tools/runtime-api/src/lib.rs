@@ -0,0 +1,158 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::unnecessary_mut_passed)]
+
+use codec::{Codec, Decode, Encode};
+#[cfg(feature = "std")]
+use common::utils::{string_serialization, string_serialization_opt};
+use common::{FixedInner, PriceVariant};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::traits::{MaybeDisplay, MaybeFromStr};
+use sp_std::prelude::*;
+
+/// Prices tracked by price-tools for a single asset and price variant.
+///
+/// Prices are amounts of the asset per one XOR.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Default, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct PriceInfo<Balance, Symbol> {
+    /// Average price, `None` while the spot price history is incomplete.
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization_opt"
+        ),
+        serde(default)
+    )]
+    pub average_price: Option<Balance>,
+    /// Latest observed spot price.
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization"
+        )
+    )]
+    pub last_spot_price: Balance,
+    /// Length of the current spot price quote failure streak.
+    pub price_failures: u32,
+    /// Average price with relaxed change bounds, `None` while the spot price history is incomplete.
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization_opt"
+        ),
+        serde(default)
+    )]
+    pub fast_average_price: Option<Balance>,
+    /// Comparison with the oracle rate, if the asset is mapped to an oracle symbol.
+    #[cfg_attr(
+        feature = "std",
+        serde(bound(
+            serialize = "Balance: std::fmt::Display, Symbol: Serialize",
+            deserialize = "Balance: std::str::FromStr, Symbol: Deserialize<'de>"
+        ))
+    )]
+    pub oracle: Option<OracleComparison<Balance, Symbol>>,
+}
+
+/// Difference between price-tools prices and the oracle rate of the same asset.
+///
+/// Deviations are fixed point numbers with 18 decimals, positive when the price-tools price is
+/// higher than the oracle one.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Default, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct OracleComparison<Balance, Symbol> {
+    /// Oracle symbol the asset is mapped to.
+    pub symbol: Symbol,
+    /// Oracle rate of the symbol in the reference asset.
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization"
+        )
+    )]
+    pub rate: Balance,
+    /// Oracle rate converted to the amount of asset per one XOR.
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization_opt"
+        ),
+        serde(default)
+    )]
+    pub price: Option<Balance>,
+    #[cfg_attr(feature = "std", serde(with = "string_serialization_opt", default))]
+    pub average_price_deviation: Option<FixedInner>,
+    #[cfg_attr(feature = "std", serde(with = "string_serialization_opt", default))]
+    pub spot_price_deviation: Option<FixedInner>,
+    #[cfg_attr(feature = "std", serde(with = "string_serialization_opt", default))]
+    pub fast_price_deviation: Option<FixedInner>,
+}
+
+sp_api::decl_runtime_apis! {
+    pub trait PriceToolsAPI<AssetId, Balance, Symbol> where
+        AssetId: Codec,
+        Balance: Codec + MaybeFromStr + MaybeDisplay,
+        Symbol: Codec,
+    {
+        fn get_price_info(
+            asset_id: AssetId,
+            price_variant: PriceVariant,
+        ) -> Option<PriceInfo<Balance, Symbol>>;
+
+        fn list_price_infos(price_variant: PriceVariant) -> Vec<(AssetId, PriceInfo<Balance, Symbol>)>;
+    }
+}
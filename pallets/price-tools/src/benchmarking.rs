@@ -258,6 +258,25 @@ benchmarks! {
             );
         }
     }
+
+    set_price_parameters {
+        let asset = create_asset::<T>(b"asset".to_vec(), 0);
+        register_asset::<T>(alice::<T>(), asset);
+        let parameters = PriceParameters {
+            block_span: 60,
+            median: Some(MedianParameters {
+                sources: vec![LiquiditySourceType::XYKPool, LiquiditySourceType::OrderBook]
+                    .try_into()
+                    .unwrap(),
+                min_sources: 1,
+                max_deviation: fixed!(0.05),
+            }),
+            ..Default::default()
+        };
+    }: _(RawOrigin::Root, asset, Some(parameters.clone()))
+    verify {
+        assert_eq!(AssetPriceParameters::<T>::get(asset), Some(parameters));
+    }
 }
 
 #[cfg(test)]
@@ -271,5 +290,8 @@ mod tests {
         ExtBuilder::default().build().execute_with(|| {
             assert_ok!(Pallet::<Runtime>::test_benchmark_on_initialize());
         });
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(Pallet::<Runtime>::test_benchmark_set_price_parameters());
+        });
     }
 }
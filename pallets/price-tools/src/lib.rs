@@ -51,37 +51,64 @@ pub mod migrations;
 
 use core::marker::PhantomData;
 
-use codec::{Decode, Encode};
+use codec::{Decode, DecodeWithMemTracking, Encode};
 use common::prelude::{
     AssetIdOf, Balance, Fixed, FixedWrapper, LiquiditySourceType, PriceToolsProvider, QuoteAmount,
     TradingPairSourceManager,
 };
 use common::{
-    balance, fixed_const, fixed_wrapper, BalanceOf, DEXId, LiquidityProxyTrait,
+    balance, fixed, fixed_const, fixed_wrapper, BalanceOf, DEXId, LiquidityProxyTrait,
     LiquiditySourceFilter, OnDenominate, OnPoolReservesChanged, PriceVariant, XOR,
 };
 use frame_support::dispatch::DispatchResult;
+use frame_support::traits::ConstU32;
 use frame_support::weights::Weight;
+use frame_support::BoundedVec;
 use frame_support::{ensure, fail};
 use frame_support::{IterableStorageMap, StorageMap as StorageMapT};
 use sp_runtime::DispatchError;
 use sp_std::collections::{btree_map::BTreeMap, vec_deque::VecDeque};
 use sp_std::convert::TryInto;
+use sp_std::vec::Vec;
 
 pub use pallet::*;
 
-/// Count of blocks to participate in avg value calculation.
+/// Default count of blocks to participate in avg value calculation.
 pub const AVG_BLOCK_SPAN: usize = 30;
 
+/// Max count of blocks to participate in avg value calculation.
+pub const MAX_BLOCK_SPAN: u32 = 600;
+
+/// Max count of liquidity sources quoted separately in median mode.
+pub const MAX_MEDIAN_SOURCES: u32 = 8;
+
+#[derive(
+    Encode, Decode, DecodeWithMemTracking, Eq, PartialEq, Clone, Debug, scale_info::TypeInfo,
+)]
 pub struct AdjustParameters {
     /// Max percentage difference for average value between blocks when price goes down for buy price.
-    max_buy_dec: Fixed,
+    pub max_buy_dec: Fixed,
     /// Max percentage difference for average value between blocks when price goes up for buy price.
-    max_buy_inc: Fixed,
+    pub max_buy_inc: Fixed,
     /// Max percentage difference for average value between blocks when price goes down for sell price.
-    max_sell_dec: Fixed,
+    pub max_sell_dec: Fixed,
     /// Max percentage difference for average value between blocks when price goes up for sell price.
-    max_sell_inc: Fixed,
+    pub max_sell_inc: Fixed,
+}
+
+impl AdjustParameters {
+    fn is_valid(&self) -> bool {
+        let zero: Fixed = fixed!(0);
+        let one: Fixed = fixed!(1);
+        [
+            self.max_buy_dec,
+            self.max_buy_inc,
+            self.max_sell_dec,
+            self.max_sell_inc,
+        ]
+        .iter()
+        .all(|bound| *bound >= zero && *bound < one)
+    }
 }
 
 pub const DEFAULT_PARAMETERS: AdjustParameters = AdjustParameters {
@@ -98,6 +125,72 @@ pub const FAST_PARAMETERS: AdjustParameters = AdjustParameters {
     max_sell_inc: fixed_const!(0.00197), // 0.197%
 };
 
+/// Spot price is taken from several liquidity sources quoted separately. Quotes deviating from
+/// their median by more than `max_deviation` are discarded and the rest are averaged.
+#[derive(
+    Encode, Decode, DecodeWithMemTracking, Eq, PartialEq, Clone, Debug, scale_info::TypeInfo,
+)]
+pub struct MedianParameters {
+    /// Liquidity sources of Polkaswap DEX to quote.
+    pub sources: BoundedVec<LiquiditySourceType, ConstU32<MAX_MEDIAN_SOURCES>>,
+    /// Min count of quotes left after outliers rejection for the spot price to be accepted.
+    pub min_sources: u32,
+    /// Max relative deviation of a quote from the median.
+    pub max_deviation: Fixed,
+}
+
+impl MedianParameters {
+    fn is_valid(&self) -> bool {
+        let zero: Fixed = fixed!(0);
+        let mut sources = self.sources.to_vec();
+        sources.sort();
+        sources.dedup();
+        sources.len() == self.sources.len()
+            && self.min_sources > 0
+            && self.min_sources as usize <= self.sources.len()
+            && self.max_deviation > zero
+    }
+}
+
+/// Averaging parameters of an asset.
+#[derive(
+    Encode, Decode, DecodeWithMemTracking, Eq, PartialEq, Clone, Debug, scale_info::TypeInfo,
+)]
+pub struct PriceParameters {
+    /// Count of blocks to participate in avg value calculation.
+    pub block_span: u32,
+    /// Bounds of average price change between blocks.
+    pub adjust: AdjustParameters,
+    /// Bounds of fast average price change between blocks.
+    pub fast_adjust: AdjustParameters,
+    /// Median-of-sources mode, aggregated XYK pool quote is used if not set.
+    pub median: Option<MedianParameters>,
+}
+
+impl Default for PriceParameters {
+    fn default() -> Self {
+        Self {
+            block_span: AVG_BLOCK_SPAN as u32,
+            adjust: DEFAULT_PARAMETERS,
+            fast_adjust: FAST_PARAMETERS,
+            median: None,
+        }
+    }
+}
+
+impl PriceParameters {
+    fn is_valid(&self) -> bool {
+        self.block_span > 0
+            && self.block_span <= MAX_BLOCK_SPAN
+            && self.adjust.is_valid()
+            && self.fast_adjust.is_valid()
+            && self
+                .median
+                .as_ref()
+                .map_or(true, MedianParameters::is_valid)
+    }
+}
+
 pub use weights::WeightInfo;
 
 #[derive(Encode, Decode, Eq, PartialEq, Clone, PartialOrd, Ord, Debug, scale_info::TypeInfo)]
@@ -116,11 +209,26 @@ pub enum PriceError {
 }
 
 impl PriceInfo {
+    /// Averaged price, available once spot price history covers the whole block span.
+    pub fn average_price(&self, block_span: usize) -> Option<Balance> {
+        (self.spot_prices.len() == block_span).then_some(self.average_price)
+    }
+
+    /// Latest observed spot price, zero if no spot price has been observed yet.
+    pub fn last_spot_price(&self) -> Balance {
+        self.last_spot_price
+    }
+
+    /// Length of the current spot price quote failure streak.
+    pub fn price_failures(&self) -> u32 {
+        self.price_failures
+    }
+
     /// Register spot price quote failure, continuous failure has to block average price quotation.
-    pub fn incoming_spot_price_failure(&mut self) {
-        if (self.price_failures as usize) < AVG_BLOCK_SPAN {
+    pub fn incoming_spot_price_failure(&mut self, block_span: usize) {
+        if (self.price_failures as usize) < block_span {
             self.price_failures += 1;
-            if (self.price_failures as usize) == AVG_BLOCK_SPAN {
+            if (self.price_failures as usize) == block_span {
                 self.spot_prices.clear();
             }
         }
@@ -132,16 +240,21 @@ impl PriceInfo {
         price: Balance,
         price_variant: PriceVariant,
         adjust_params: &AdjustParameters,
+        block_span: usize,
     ) -> Result<(), PriceError> {
         // reset failure streak
         self.price_failures = 0;
         self.needs_update = false;
+        // spot price history is longer than the span, i.e. the span has been shortened
+        if self.spot_prices.len() > block_span {
+            self.spot_prices.clear();
+        }
         // spot price history is consistent, normal behavior
-        if self.spot_prices.len() == AVG_BLOCK_SPAN {
+        if self.spot_prices.len() == block_span {
             let old_value = self.spot_prices.pop_front().expect("Checked above");
 
             let mut new_avg =
-                Self::replace_in_average(self.average_price, old_value, price, AVG_BLOCK_SPAN)?;
+                Self::replace_in_average(self.average_price, old_value, price, block_span)?;
             new_avg = Self::adjust_to_difference(
                 self.average_price,
                 new_avg,
@@ -149,11 +262,11 @@ impl PriceInfo {
                 adjust_params,
             )?;
             let adjusted_incoming_price =
-                Self::adjusted_spot_price(self.average_price, new_avg, old_value, AVG_BLOCK_SPAN)?;
+                Self::adjusted_spot_price(self.average_price, new_avg, old_value, block_span)?;
             self.spot_prices.push_back(adjusted_incoming_price);
             self.average_price = new_avg;
         // spot price history has been recovered/initiated, create initial average value
-        } else if self.spot_prices.len() == AVG_BLOCK_SPAN - 1 {
+        } else if self.spot_prices.len() + 1 == block_span {
             self.spot_prices.push_back(price);
             let sum = self
                 .spot_prices
@@ -307,13 +420,46 @@ pub mod pallet {
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
-        // no extrinsics
+        /// Set averaging parameters of a registered asset, `None` restores the defaults.
+        ///
+        /// Changing the block span resets collected price history of the asset.
+        ///
+        /// - `origin`: the root,
+        /// - `asset_id`: asset registered in price tools,
+        /// - `parameters`: new averaging parameters.
+        #[pallet::call_index(0)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_price_parameters())]
+        pub fn set_price_parameters(
+            origin: OriginFor<T>,
+            asset_id: AssetIdOf<T>,
+            parameters: Option<PriceParameters>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                PriceInfos::<T>::contains_key(asset_id),
+                Error::<T>::AssetIsNotRegistered
+            );
+            if let Some(parameters) = &parameters {
+                ensure!(parameters.is_valid(), Error::<T>::InvalidPriceParameters);
+            }
+
+            let old_block_span = Self::price_parameters(&asset_id).block_span;
+            AssetPriceParameters::<T>::set(asset_id, parameters);
+            if Self::price_parameters(&asset_id).block_span != old_block_span {
+                PriceInfos::<T>::insert(asset_id, AggregatedPriceInfo::default());
+                FastPriceInfos::<T>::insert(asset_id, AggregatedPriceInfo::default());
+            }
+
+            Self::deposit_event(Event::<T>::PriceParametersChanged(asset_id));
+            Ok(())
+        }
     }
 
     #[pallet::event]
-    // #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
-        // no events
+        /// Averaging parameters of the asset have been changed. [Asset Id]
+        PriceParametersChanged(AssetIdOf<T>),
     }
 
     #[pallet::error]
@@ -333,6 +479,10 @@ pub mod pallet {
         AssetAlreadyRegistered,
         /// Spot price for asset has not changed but info for last spot price is unavailable.
         CantDuplicateLastPrice,
+        /// Asset is not registered in price tools.
+        AssetIsNotRegistered,
+        /// Block span, adjust bounds or median parameters are out of allowed ranges.
+        InvalidPriceParameters,
     }
 
     #[pallet::storage]
@@ -342,6 +492,12 @@ pub mod pallet {
     #[pallet::storage]
     #[pallet::getter(fn fast_price_infos)]
     pub type FastPriceInfos<T: Config> = StorageMap<_, Identity, AssetIdOf<T>, AggregatedPriceInfo>;
+
+    /// Averaging parameters set by governance, assets without an entry use the defaults.
+    #[pallet::storage]
+    #[pallet::getter(fn asset_price_parameters)]
+    pub type AssetPriceParameters<T: Config> =
+        StorageMap<_, Identity, AssetIdOf<T>, PriceParameters>;
 }
 
 impl<T: Config> Pallet<T> {
@@ -401,11 +557,10 @@ impl<T: Config> Pallet<T> {
             .map_or_else(
                 || Err(Error::<T>::UnsupportedQuotePath.into()),
                 |price_info| {
-                    ensure!(
-                        price_info.spot_prices.len() == AVG_BLOCK_SPAN,
-                        Error::<T>::InsufficientSpotPriceData
-                    );
-                    Ok(price_info.average_price)
+                    let block_span = Self::price_parameters(asset_id).block_span as usize;
+                    price_info
+                        .average_price(block_span)
+                        .ok_or_else(|| Error::<T>::InsufficientSpotPriceData.into())
                 },
             )
     }
@@ -417,22 +572,64 @@ impl<T: Config> Pallet<T> {
         )
     }
 
-    /// Get current spot price for
-    pub fn spot_price(asset_id: &AssetIdOf<T>) -> Result<Balance, DispatchError> {
+    /// Averaging parameters of the asset set by governance or the default ones.
+    pub fn price_parameters(asset_id: &AssetIdOf<T>) -> PriceParameters {
+        AssetPriceParameters::<T>::get(asset_id).unwrap_or_default()
+    }
+
+    fn quote_xor(
+        asset_id: &AssetIdOf<T>,
+        filter: LiquiditySourceFilter<T::DEXId, LiquiditySourceType>,
+    ) -> Result<Balance, DispatchError> {
         T::LiquidityProxy::quote(
             DEXId::Polkaswap.into(),
             &XOR.into(),
-            &asset_id,
+            asset_id,
             QuoteAmount::with_desired_input(balance!(1)),
-            Self::secondary_market_filter(),
+            filter,
             false,
         )
         .map(|so| so.amount)
     }
 
+    /// Get current spot price for asset, i.e. amount of asset for one XOR.
+    pub fn spot_price(asset_id: &AssetIdOf<T>) -> Result<Balance, DispatchError> {
+        match Self::price_parameters(asset_id).median {
+            Some(median) => Self::median_spot_price(asset_id, &median),
+            None => Self::quote_xor(asset_id, Self::secondary_market_filter()),
+        }
+    }
+
+    /// Quote every source separately, discard quotes too far from the median and average the rest.
+    fn median_spot_price(
+        asset_id: &AssetIdOf<T>,
+        median: &MedianParameters,
+    ) -> Result<Balance, DispatchError> {
+        let quotes = median
+            .sources
+            .iter()
+            .filter_map(|source| {
+                let filter =
+                    LiquiditySourceFilter::with_allowed(DEXId::Polkaswap.into(), [*source].into());
+                Self::quote_xor(asset_id, filter).ok()
+            })
+            .collect::<Vec<_>>();
+        let accepted = reject_outliers(quotes, median.max_deviation);
+        ensure!(
+            accepted.len() >= median.min_sources as usize,
+            Error::<T>::FailedToQuoteAveragePrice
+        );
+        let sum = accepted
+            .iter()
+            .fold(FixedWrapper::from(0), |sum, quote| sum + *quote);
+        (sum / balance!(accepted.len()))
+            .try_into_balance()
+            .map_err(|_| Error::<T>::FailedToQuoteAveragePrice.into())
+    }
+
     fn update_price<S>(
         price_cache: &mut BTreeMap<AssetIdOf<T>, Option<Balance>>,
-        adjust_params: &AdjustParameters,
+        adjust_params_of: fn(&PriceParameters) -> &AdjustParameters,
     ) -> (u32, u32)
     where
         S: IterableStorageMap<
@@ -444,6 +641,9 @@ impl<T: Config> Pallet<T> {
         let mut count_active = 0;
         let mut count_updated = 0;
         for asset_id in S::iter_keys() {
+            let parameters = Self::price_parameters(&asset_id);
+            let adjust_params = adjust_params_of(&parameters);
+            let block_span = parameters.block_span as usize;
             S::mutate(asset_id, |opt_value| {
                 let Some(value) = opt_value.as_mut() else {
                     // Should not happen, because we get asset_id from iter_keys() call
@@ -455,7 +655,10 @@ impl<T: Config> Pallet<T> {
                         if let Some(price) = price_cache.get(&asset_id).cloned() {
                             price
                         } else {
-                            count_updated += 1;
+                            count_updated += parameters
+                                .median
+                                .as_ref()
+                                .map_or(1, |median| median.sources.len() as u32);
                             let price = Self::spot_price(&asset_id)
                                 .map_err(|err| {
                                     frame_support::__private::log::warn!(
@@ -472,13 +675,16 @@ impl<T: Config> Pallet<T> {
                         Some(price_info.last_spot_price)
                     };
                     if let Some(val) = price {
-                        if let Err(err) =
-                            price_info.incoming_spot_price(val, price_variant, adjust_params)
-                        {
+                        if let Err(err) = price_info.incoming_spot_price(
+                            val,
+                            price_variant,
+                            adjust_params,
+                            block_span,
+                        ) {
                             frame_support::__private::log::warn!("Failed to add spot price for {asset_id:?} with {price_variant:?} variant and {val} price: {err:?}");
                         }
                     } else {
-                        price_info.incoming_spot_price_failure();
+                        price_info.incoming_spot_price_failure(block_span);
                     }
                     count_active += 1;
                 }
@@ -491,9 +697,9 @@ impl<T: Config> Pallet<T> {
     pub fn average_prices_calculation_routine() -> (u32, u32) {
         let mut price_cache = BTreeMap::new();
         let (count_active, count_updated) =
-            Self::update_price::<PriceInfos<T>>(&mut price_cache, &DEFAULT_PARAMETERS);
+            Self::update_price::<PriceInfos<T>>(&mut price_cache, |params| &params.adjust);
         let (fast_count_active, fast_count_updated) =
-            Self::update_price::<FastPriceInfos<T>>(&mut price_cache, &FAST_PARAMETERS);
+            Self::update_price::<FastPriceInfos<T>>(&mut price_cache, |params| &params.fast_adjust);
         (
             count_active + fast_count_active,
             count_updated + fast_count_updated,
@@ -530,6 +736,119 @@ impl<T: Config> Pallet<T> {
     }
 }
 
+/// Keep quotes whose relative deviation from the median of all quotes does not exceed
+/// `max_deviation`.
+pub fn reject_outliers(mut quotes: Vec<Balance>, max_deviation: Fixed) -> Vec<Balance> {
+    if quotes.is_empty() {
+        return quotes;
+    }
+    quotes.sort();
+    let mid = quotes.len() / 2;
+    let median = if quotes.len() % 2 == 0 {
+        quotes[mid - 1] / 2 + quotes[mid] / 2 + (quotes[mid - 1] % 2 + quotes[mid] % 2) / 2
+    } else {
+        quotes[mid]
+    };
+    quotes.retain(|quote| {
+        relative_difference(*quote, median)
+            .and_then(|diff| diff.abs().ok())
+            .map_or(false, |diff| diff <= max_deviation)
+    });
+    quotes
+}
+
+/// Difference between price-tools prices of an asset and an external rate for the same asset.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RateComparison {
+    /// External rate converted to the price-tools quotation, i.e. amount of asset per one XOR.
+    pub price: Option<Balance>,
+    /// Relative difference of the average price from the converted rate.
+    pub average_price_deviation: Option<Fixed>,
+    /// Relative difference of the last spot price from the converted rate.
+    pub spot_price_deviation: Option<Fixed>,
+    /// Relative difference of the fast average price from the converted rate.
+    pub fast_price_deviation: Option<Fixed>,
+}
+
+/// Relative difference `(price - reference) / reference`, positive when `price` is higher.
+pub fn relative_difference(price: Balance, reference: Balance) -> Option<Fixed> {
+    if reference == 0 {
+        return None;
+    }
+    let reference = FixedWrapper::from(reference);
+    ((FixedWrapper::from(price) - reference.clone()) / reference)
+        .get()
+        .ok()
+}
+
+impl<T: Config> Pallet<T> {
+    /// Compare prices of `asset_id` with `rate`, the value of one unit of the asset expressed in
+    /// `reference_asset_id` (e.g. an oracle USD rate with DAI as the reference asset).
+    ///
+    /// Every price is compared with the rate converted to XOR through the price of the reference
+    /// asset of the same kind, so that the average price is matched against the average reference
+    /// price, the spot price against the reference spot price and so on.
+    pub fn compare_with_rate(
+        asset_id: &AssetIdOf<T>,
+        reference_asset_id: &AssetIdOf<T>,
+        rate: Balance,
+        price_variant: PriceVariant,
+    ) -> RateComparison {
+        let to_asset_price = |reference_price: Balance| -> Option<Balance> {
+            if rate == 0 || reference_price == 0 {
+                return None;
+            }
+            (FixedWrapper::from(reference_price) / FixedWrapper::from(rate))
+                .try_into_balance()
+                .ok()
+        };
+        let compare = |price: Option<Balance>, reference_price: Option<Balance>| {
+            let reference_price = reference_price.and_then(to_asset_price)?;
+            relative_difference(price.filter(|price| *price > 0)?, reference_price)
+        };
+        let price_info = |asset_id: &AssetIdOf<T>| {
+            PriceInfos::<T>::get(asset_id).map(|info| info.price_of(price_variant))
+        };
+        let fast_price_info = |asset_id: &AssetIdOf<T>| {
+            FastPriceInfos::<T>::get(asset_id).map(|info| info.price_of(price_variant))
+        };
+        let asset_span = Self::price_parameters(asset_id).block_span as usize;
+        let reference_span = Self::price_parameters(reference_asset_id).block_span as usize;
+
+        let asset = price_info(asset_id);
+        let reference = price_info(reference_asset_id);
+        let asset_fast = fast_price_info(asset_id);
+        let reference_fast = fast_price_info(reference_asset_id);
+
+        RateComparison {
+            price: reference
+                .as_ref()
+                .and_then(|info| info.average_price(reference_span))
+                .and_then(to_asset_price),
+            average_price_deviation: compare(
+                asset
+                    .as_ref()
+                    .and_then(|info| info.average_price(asset_span)),
+                reference
+                    .as_ref()
+                    .and_then(|info| info.average_price(reference_span)),
+            ),
+            spot_price_deviation: compare(
+                asset.as_ref().map(PriceInfo::last_spot_price),
+                reference.as_ref().map(PriceInfo::last_spot_price),
+            ),
+            fast_price_deviation: compare(
+                asset_fast
+                    .as_ref()
+                    .and_then(|info| info.average_price(asset_span)),
+                reference_fast
+                    .as_ref()
+                    .and_then(|info| info.average_price(reference_span)),
+            ),
+        }
+    }
+}
+
 impl<T: Config> PriceToolsProvider<AssetIdOf<T>> for Pallet<T> {
     fn is_asset_registered(asset_id: &AssetIdOf<T>) -> bool {
         PriceInfos::<T>::get(asset_id).is_some()
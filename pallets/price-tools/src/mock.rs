@@ -107,7 +107,7 @@ construct_runtime! {
         UncheckedExtrinsic = UncheckedExtrinsic,
     {
         System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
-        DexManager: dex_manager::{Pallet, Call, Storage},
+        DexManager: dex_manager::{Pallet, Call, Storage, Event<T>},
         MockLiquiditySource: mock_liquidity_source::<Instance1>::{Pallet, Call, Config<T>, Storage},
         Tokens: tokens::{Pallet, Call, Config<T>, Storage, Event<T>},
         Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
@@ -226,7 +226,7 @@ impl LiquidityProxyTrait<DEXId, AccountId, AssetId> for MockDEXApi {
         _input_asset_id: &AssetId,
         output_asset_id: &AssetId,
         _amount: QuoteAmount<Balance>,
-        _filter: LiquiditySourceFilter<DEXId, LiquiditySourceType>,
+        filter: LiquiditySourceFilter<DEXId, LiquiditySourceType>,
         _deduce_fee: bool,
     ) -> Result<SwapOutcome<Balance, AssetId>, DispatchError> {
         let assets = vec![ETH, DAI, VAL, PSWAP, XOR, USDT];
@@ -235,9 +235,22 @@ impl LiquidityProxyTrait<DEXId, AccountId, AssetId> for MockDEXApi {
             // it is necessary for unit tests
             Err(DispatchError::CannotLookup)
         } else {
-            // return some price for any custom asset
-            // it is necessary for benchmark tests
-            Ok(SwapOutcome::new(balance!(2), Default::default()))
+            // return some price for any custom asset, depending on the first allowed source
+            // it is necessary for benchmark and median mode tests
+            let source = [
+                LiquiditySourceType::XYKPool,
+                LiquiditySourceType::OrderBook,
+                LiquiditySourceType::MulticollateralBondingCurvePool,
+            ]
+            .into_iter()
+            .find(|source| filter.matches_index(*source));
+            let price = match source {
+                Some(LiquiditySourceType::XYKPool) => balance!(2),
+                Some(LiquiditySourceType::OrderBook) => balance!(2.1),
+                Some(LiquiditySourceType::MulticollateralBondingCurvePool) => balance!(3),
+                _ => return Err(DispatchError::CannotLookup),
+            };
+            Ok(SwapOutcome::new(price, Default::default()))
         }
     }
 }
@@ -31,13 +31,16 @@
 use std::convert::TryInto;
 
 use crate::mock::*;
-use crate::{Error, AVG_BLOCK_SPAN};
-use common::prelude::Balance;
+use crate::{
+    reject_outliers, Error, MedianParameters, PriceParameters, AVG_BLOCK_SPAN, MAX_BLOCK_SPAN,
+};
+use common::prelude::{Balance, Fixed};
 use common::{
-    balance, fixed_wrapper, OnPoolReservesChanged, PriceToolsProvider, PriceVariant, DOT, ETH,
-    PSWAP, VAL, XOR,
+    balance, fixed, fixed_wrapper, LiquiditySourceType, OnPoolReservesChanged, PriceToolsProvider,
+    PriceVariant, DOT, ETH, PSWAP, VAL, XOR,
 };
-use frame_support::assert_noop;
+use frame_support::{assert_noop, assert_ok};
+use hex_literal::hex;
 use sp_runtime::DispatchError;
 
 fn to_avg<'a, I>(it: I, size: usize) -> Balance
@@ -70,22 +73,34 @@ pub fn get_fast_average_price(
 }
 
 pub fn incoming_spot_price(asset_id: &AssetId, price: Balance) {
+    let parameters = PriceTools::price_parameters(asset_id);
+    let block_span = parameters.block_span as usize;
     crate::PriceInfos::<Runtime>::mutate(asset_id, |opt_val| {
         let val = opt_val.as_mut().unwrap();
         val.buy
-            .incoming_spot_price(price, PriceVariant::Buy, &crate::DEFAULT_PARAMETERS)
+            .incoming_spot_price(price, PriceVariant::Buy, &parameters.adjust, block_span)
             .unwrap();
         val.sell
-            .incoming_spot_price(price, PriceVariant::Sell, &crate::DEFAULT_PARAMETERS)
+            .incoming_spot_price(price, PriceVariant::Sell, &parameters.adjust, block_span)
             .unwrap();
     });
     crate::FastPriceInfos::<Runtime>::mutate(asset_id, |opt_val| {
         let val = opt_val.as_mut().unwrap();
         val.buy
-            .incoming_spot_price(price, PriceVariant::Buy, &crate::FAST_PARAMETERS)
+            .incoming_spot_price(
+                price,
+                PriceVariant::Buy,
+                &parameters.fast_adjust,
+                block_span,
+            )
             .unwrap();
         val.sell
-            .incoming_spot_price(price, PriceVariant::Sell, &crate::FAST_PARAMETERS)
+            .incoming_spot_price(
+                price,
+                PriceVariant::Sell,
+                &parameters.fast_adjust,
+                block_span,
+            )
             .unwrap();
     })
 }
@@ -811,3 +826,304 @@ fn asset_already_registered() {
         );
     });
 }
+
+#[test]
+fn price_info_accessors_reflect_history() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        PriceTools::register_asset(&ETH).unwrap();
+        let price_info = || {
+            PriceTools::price_infos(&ETH)
+                .unwrap()
+                .price_of(PriceVariant::Buy)
+        };
+        assert_eq!(price_info().average_price(AVG_BLOCK_SPAN), None);
+        assert_eq!(price_info().last_spot_price(), 0);
+
+        for _ in 1..AVG_BLOCK_SPAN {
+            incoming_spot_price(&ETH, balance!(10));
+        }
+        assert_eq!(price_info().average_price(AVG_BLOCK_SPAN), None);
+        assert_eq!(price_info().last_spot_price(), balance!(10));
+
+        incoming_spot_price(&ETH, balance!(10));
+        assert_eq!(
+            price_info().average_price(AVG_BLOCK_SPAN),
+            Some(balance!(10))
+        );
+
+        crate::PriceInfos::<Runtime>::mutate(&ETH, |val| {
+            val.as_mut()
+                .unwrap()
+                .buy
+                .incoming_spot_price_failure(AVG_BLOCK_SPAN);
+        });
+        assert_eq!(price_info().price_failures(), 1);
+    });
+}
+
+#[test]
+fn compare_with_rate_works() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        PriceTools::register_asset(&ETH).unwrap();
+        PriceTools::register_asset(&DAI).unwrap();
+
+        // Only spot prices are known until the history covers the whole span.
+        incoming_spot_price(&ETH, balance!(10));
+        incoming_spot_price(&DAI, balance!(20));
+        let comparison = PriceTools::compare_with_rate(&ETH, &DAI, balance!(2), PriceVariant::Buy);
+        assert_eq!(
+            comparison,
+            crate::RateComparison {
+                price: None,
+                average_price_deviation: None,
+                spot_price_deviation: Some(fixed!(0)),
+                fast_price_deviation: None,
+            }
+        );
+
+        for _ in 1..AVG_BLOCK_SPAN {
+            incoming_spot_price(&ETH, balance!(10));
+            incoming_spot_price(&DAI, balance!(20));
+        }
+        // 20 DAI per XOR at 2.5 DAI per ETH gives 8 ETH per XOR, price-tools is 25% above.
+        let comparison =
+            PriceTools::compare_with_rate(&ETH, &DAI, balance!(2.5), PriceVariant::Sell);
+        assert_eq!(
+            comparison,
+            crate::RateComparison {
+                price: Some(balance!(8)),
+                average_price_deviation: Some(fixed!(0.25)),
+                spot_price_deviation: Some(fixed!(0.25)),
+                fast_price_deviation: Some(fixed!(0.25)),
+            }
+        );
+
+        // Nothing to compare with for unregistered reference asset or zero rate.
+        assert_eq!(
+            PriceTools::compare_with_rate(&ETH, &VAL, balance!(2), PriceVariant::Buy),
+            Default::default()
+        );
+        assert_eq!(
+            PriceTools::compare_with_rate(&ETH, &DAI, 0, PriceVariant::Buy),
+            Default::default()
+        );
+    });
+}
+
+fn median_parameters(
+    sources: Vec<LiquiditySourceType>,
+    min_sources: u32,
+    max_deviation: Fixed,
+) -> MedianParameters {
+    MedianParameters {
+        sources: sources.try_into().unwrap(),
+        min_sources,
+        max_deviation,
+    }
+}
+
+#[test]
+fn set_price_parameters_validates_input() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let parameters = PriceParameters {
+            block_span: 10,
+            ..Default::default()
+        };
+        assert_noop!(
+            PriceTools::set_price_parameters(
+                RuntimeOrigin::signed(alice()),
+                ETH,
+                Some(parameters.clone())
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            PriceTools::set_price_parameters(RuntimeOrigin::root(), ETH, Some(parameters.clone())),
+            Error::<Runtime>::AssetIsNotRegistered
+        );
+
+        PriceTools::register_asset(&ETH).unwrap();
+        let mut invalid_parameters = vec![
+            PriceParameters {
+                block_span: 0,
+                ..Default::default()
+            },
+            PriceParameters {
+                block_span: MAX_BLOCK_SPAN + 1,
+                ..Default::default()
+            },
+        ];
+        let mut adjust = PriceParameters::default();
+        adjust.adjust.max_buy_inc = fixed!(1);
+        invalid_parameters.push(adjust);
+        let mut fast_adjust = PriceParameters::default();
+        let max_sell_dec: Fixed = fixed!(0.1);
+        fast_adjust.fast_adjust.max_sell_dec = max_sell_dec.cneg().unwrap();
+        invalid_parameters.push(fast_adjust);
+        for median in [
+            median_parameters(vec![], 0, fixed!(0.1)),
+            median_parameters(vec![LiquiditySourceType::XYKPool], 2, fixed!(0.1)),
+            median_parameters(vec![LiquiditySourceType::XYKPool], 1, fixed!(0)),
+            median_parameters(
+                vec![LiquiditySourceType::XYKPool, LiquiditySourceType::XYKPool],
+                1,
+                fixed!(0.1),
+            ),
+        ] {
+            invalid_parameters.push(PriceParameters {
+                median: Some(median),
+                ..Default::default()
+            });
+        }
+        for parameters in invalid_parameters {
+            assert_noop!(
+                PriceTools::set_price_parameters(RuntimeOrigin::root(), ETH, Some(parameters)),
+                Error::<Runtime>::InvalidPriceParameters
+            );
+        }
+
+        assert_ok!(PriceTools::set_price_parameters(
+            RuntimeOrigin::root(),
+            ETH,
+            Some(parameters.clone())
+        ));
+        assert_eq!(PriceTools::asset_price_parameters(ETH), Some(parameters));
+        assert_ok!(PriceTools::set_price_parameters(
+            RuntimeOrigin::root(),
+            ETH,
+            None
+        ));
+        assert_eq!(PriceTools::asset_price_parameters(ETH), None);
+        assert_eq!(
+            PriceTools::price_parameters(&ETH),
+            PriceParameters::default()
+        );
+    });
+}
+
+#[test]
+fn custom_block_span_is_used_for_average() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        PriceTools::register_asset(&ETH).unwrap();
+        for i in 1..=AVG_BLOCK_SPAN {
+            incoming_spot_price(&ETH, balance!(i));
+        }
+        assert!(get_average_price(&XOR.into(), &ETH.into(), PriceVariant::Buy).is_ok());
+
+        // History is reset when the span changes.
+        assert_ok!(PriceTools::set_price_parameters(
+            RuntimeOrigin::root(),
+            ETH,
+            Some(PriceParameters {
+                block_span: 5,
+                ..Default::default()
+            })
+        ));
+        assert_noop!(
+            get_average_price(&XOR.into(), &ETH.into(), PriceVariant::Buy),
+            Error::<Runtime>::InsufficientSpotPriceData
+        );
+        for i in 1..5 {
+            incoming_spot_price(&ETH, balance!(i));
+            assert_noop!(
+                get_average_price(&XOR.into(), &ETH.into(), PriceVariant::Buy),
+                Error::<Runtime>::InsufficientSpotPriceData
+            );
+        }
+        incoming_spot_price(&ETH, balance!(5));
+        assert_eq!(
+            get_average_price(&XOR.into(), &ETH.into(), PriceVariant::Buy).unwrap(),
+            balance!(3)
+        );
+        assert_eq!(
+            get_fast_average_price(&XOR.into(), &ETH.into(), PriceVariant::Sell).unwrap(),
+            balance!(3)
+        );
+
+        // History is kept when only the bounds change.
+        let mut parameters = PriceParameters {
+            block_span: 5,
+            ..Default::default()
+        };
+        parameters.adjust.max_buy_inc = fixed!(0.9);
+        assert_ok!(PriceTools::set_price_parameters(
+            RuntimeOrigin::root(),
+            ETH,
+            Some(parameters)
+        ));
+        incoming_spot_price(&ETH, balance!(13));
+        // (2 + 3 + 4 + 5 + 13) / 5 = 5.4, i.e. 80% increase within 90% bound
+        assert_eq!(
+            get_average_price(&XOR.into(), &ETH.into(), PriceVariant::Buy).unwrap(),
+            balance!(5.4)
+        );
+    });
+}
+
+#[test]
+fn median_mode_rejects_outliers() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let asset_id = AssetId::from_bytes(hex!(
+            "0200077700000000000000000000000000000000000000000000000000000000"
+        ));
+        PriceTools::register_asset(&asset_id).unwrap();
+        assert_eq!(PriceTools::spot_price(&asset_id).unwrap(), balance!(2));
+
+        let sources = vec![
+            LiquiditySourceType::XYKPool,
+            LiquiditySourceType::OrderBook,
+            LiquiditySourceType::MulticollateralBondingCurvePool,
+        ];
+        assert_ok!(PriceTools::set_price_parameters(
+            RuntimeOrigin::root(),
+            asset_id,
+            Some(PriceParameters {
+                median: Some(median_parameters(sources.clone(), 2, fixed!(0.1))),
+                ..Default::default()
+            })
+        ));
+        // 3 is too far from the 2.1 median, 2 and 2.1 are averaged
+        assert_eq!(PriceTools::spot_price(&asset_id).unwrap(), balance!(2.05));
+
+        assert_ok!(PriceTools::set_price_parameters(
+            RuntimeOrigin::root(),
+            asset_id,
+            Some(PriceParameters {
+                median: Some(median_parameters(sources, 3, fixed!(0.1))),
+                ..Default::default()
+            })
+        ));
+        assert_noop!(
+            PriceTools::spot_price(&asset_id),
+            Error::<Runtime>::FailedToQuoteAveragePrice
+        );
+    });
+}
+
+#[test]
+fn reject_outliers_works() {
+    assert_eq!(reject_outliers(vec![], fixed!(0.1)), Vec::<Balance>::new());
+    assert_eq!(
+        reject_outliers(vec![balance!(10)], fixed!(0.1)),
+        vec![balance!(10)]
+    );
+    assert_eq!(
+        reject_outliers(
+            vec![balance!(12), balance!(1), balance!(10), balance!(9)],
+            fixed!(0.3)
+        ),
+        vec![balance!(9), balance!(10), balance!(12)]
+    );
+    assert_eq!(
+        reject_outliers(
+            vec![balance!(100), balance!(10), balance!(10.5), balance!(9.8)],
+            fixed!(0.05)
+        ),
+        vec![balance!(9.8), balance!(10), balance!(10.5)]
+    );
+}
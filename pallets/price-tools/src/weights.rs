@@ -61,6 +61,7 @@ use core::marker::PhantomData;
 /// Weight functions needed for price_tools.
 pub trait WeightInfo {
 	fn on_initialize(a: u32, b: u32, ) -> Weight;
+	fn set_price_parameters() -> Weight;
 }
 
 /// Weights for price_tools using the Substrate node and recommended hardware.
@@ -105,6 +106,21 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(0, 20084).saturating_mul(a.into()))
 			.saturating_add(Weight::from_parts(0, 31245).saturating_mul(b.into()))
 	}
+	/// Storage: PriceTools PriceInfos (r:1 w:1)
+	/// Proof Skipped: PriceTools PriceInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PriceTools AssetPriceParameters (r:2 w:1)
+	/// Proof Skipped: PriceTools AssetPriceParameters (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PriceTools FastPriceInfos (r:0 w:1)
+	/// Proof Skipped: PriceTools FastPriceInfos (max_values: None, max_size: None, mode: Measured)
+	fn set_price_parameters() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1418`
+		//  Estimated: `7358`
+		// Minimum execution time: 27_512_000 picoseconds.
+		Weight::from_parts(28_164_000, 7358)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -148,4 +164,19 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(0, 20084).saturating_mul(a.into()))
 			.saturating_add(Weight::from_parts(0, 31245).saturating_mul(b.into()))
 	}
+	/// Storage: PriceTools PriceInfos (r:1 w:1)
+	/// Proof Skipped: PriceTools PriceInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PriceTools AssetPriceParameters (r:2 w:1)
+	/// Proof Skipped: PriceTools AssetPriceParameters (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PriceTools FastPriceInfos (r:0 w:1)
+	/// Proof Skipped: PriceTools FastPriceInfos (max_values: None, max_size: None, mode: Measured)
+	fn set_price_parameters() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1418`
+		//  Estimated: `7358`
+		// Minimum execution time: 27_512_000 picoseconds.
+		Weight::from_parts(28_164_000, 7358)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
 }
@@ -302,19 +302,7 @@ fn revoke_permission<T: Config>(
     permission_id: PermissionId,
     scope: Scope,
 ) {
-    let mut permissions = permissions::Permissions::<T>::get(holder, scope);
-    if let Ok(id) = permissions.binary_search(&permission_id) {
-        permissions.remove(id);
-
-        if permissions.is_empty() {
-            permissions::Permissions::<T>::remove(holder, scope);
-            if permissions::Permissions::<T>::iter_prefix_values(holder).count() == 0 {
-                frame_system::Pallet::<T>::dec_consumers(holder);
-            }
-        } else {
-            permissions::Permissions::<T>::set(holder, scope, permissions)
-        }
-    }
+    let _ = permissions::Pallet::<T>::revoke_permission_from(holder, permission_id, scope);
 }
 
 fn clear_presto_storages<T: Config>() {
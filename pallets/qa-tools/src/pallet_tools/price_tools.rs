@@ -85,19 +85,23 @@ pub(crate) fn set_price_unchecked<T: Config>(
     variant: PriceVariant,
 ) -> DispatchResult {
     let _ = price_tools::Pallet::<T>::register_asset(asset_id);
+    let parameters = price_tools::Pallet::<T>::price_parameters(asset_id);
+    let block_span = parameters.block_span as usize;
 
     price_tools::PriceInfos::<T>::mutate(asset_id, |opt_val| {
         let val = opt_val.as_mut().unwrap();
         // feed failures in order to ignore the limits
-        for _ in 0..price_tools::AVG_BLOCK_SPAN {
-            val.price_mut_of(variant).incoming_spot_price_failure();
+        for _ in 0..block_span {
+            val.price_mut_of(variant)
+                .incoming_spot_price_failure(block_span);
         }
 
-        for _ in 0..price_tools::AVG_BLOCK_SPAN + 1 {
+        for _ in 0..block_span + 1 {
             let _ = val.price_mut_of(variant).incoming_spot_price(
                 price,
                 variant,
-                &price_tools::DEFAULT_PARAMETERS,
+                &parameters.adjust,
+                block_span,
             );
         }
     });
@@ -105,15 +109,17 @@ pub(crate) fn set_price_unchecked<T: Config>(
     price_tools::FastPriceInfos::<T>::mutate(asset_id, |opt_val| {
         let val = opt_val.as_mut().unwrap();
         // feed failures in order to ignore the limits
-        for _ in 0..price_tools::AVG_BLOCK_SPAN {
-            val.price_mut_of(variant).incoming_spot_price_failure();
+        for _ in 0..block_span {
+            val.price_mut_of(variant)
+                .incoming_spot_price_failure(block_span);
         }
 
-        for _ in 0..price_tools::AVG_BLOCK_SPAN + 1 {
+        for _ in 0..block_span + 1 {
             let _ = val.price_mut_of(variant).incoming_spot_price(
                 price,
                 variant,
-                &price_tools::FAST_PARAMETERS,
+                &parameters.fast_adjust,
+                block_span,
             );
         }
     });
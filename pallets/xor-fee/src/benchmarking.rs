@@ -126,5 +126,20 @@ benchmarks! {
         assert_eq!(Multiplier::<T>::get(), FixedU128::from((9, 2)));
     }
 
+    set_custom_fee {
+        let key = CustomFeeKey {
+            pallet_index: 1,
+            call_index: Some(1),
+        };
+        // removal of the existing fee is the worst case, it reads the table
+        CustomFeeTable::<T>::insert(key, CustomFee::PerClaim {
+            base: balance!(0.007),
+            per_claim: balance!(0.0007),
+        });
+    }: _(RawOrigin::Root, key, None)
+    verify {
+        assert_eq!(<CustomFeeTable<T>>::get(key), None);
+    }
+
     impl_benchmark_test_suite!(Pallet, mock::ExtBuilder::build(), mock::Runtime);
 }
@@ -30,7 +30,7 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use codec::{Decode, Encode};
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 #[cfg(feature = "wip")] // Xorless fee
 use common::prelude::FixedWrapper;
 use common::prelude::SwapAmount;
@@ -57,6 +57,7 @@ use pallet_transaction_payment as ptp;
 use pallet_transaction_payment::{
     FeeDetails, InclusionFee, OnChargeTransaction, RuntimeDispatchInfo, TxCreditHold,
 };
+use serde::{Deserialize, Serialize};
 use smallvec::smallvec;
 use sp_arithmetic::traits::CheckedDiv;
 use sp_arithmetic::FixedPointOperand;
@@ -68,6 +69,7 @@ use sp_runtime::{DispatchError, DispatchResult, FixedPointNumber, FixedU128, Per
 use sp_staking::{EraIndex, Page};
 use sp_std::boxed::Box;
 use sp_std::marker::PhantomData;
+use sp_std::vec::Vec;
 #[cfg(feature = "wip")] // Xorless fee
 use traits::MultiCurrency;
 
@@ -128,6 +130,58 @@ pub struct AssetFee {
     pub fee_without_referral: Balance,
 }
 
+/// Key of the custom fee table, `call_index` set to `None` applies the fee to every call of the
+/// pallet which doesn't have its own entry.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Eq,
+    PartialEq,
+    Clone,
+    Copy,
+    PartialOrd,
+    Ord,
+    Debug,
+    scale_info::TypeInfo,
+    MaxEncodedLen,
+    Serialize,
+    Deserialize,
+)]
+pub struct CustomFeeKey {
+    /// Index of the pallet in the runtime
+    pub pallet_index: u8,
+    /// Index of the call in the pallet
+    pub call_index: Option<u8>,
+}
+
+/// Custom fee formula of the call, values which depend on call arguments are extracted by
+/// `Config::CustomFees`.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Eq,
+    PartialEq,
+    Clone,
+    Copy,
+    Debug,
+    scale_info::TypeInfo,
+    MaxEncodedLen,
+    Serialize,
+    Deserialize,
+)]
+pub enum CustomFee {
+    /// Fixed fee
+    Flat(Balance),
+    /// Fee is charged for every receiver of the call, but at least once
+    PerReceiver(Balance),
+    /// Fee depends on the lifespan of the created entity, the value is an upper bound
+    PerLifespan(Balance),
+    /// Fixed fee plus fee for every claim of the created schedule
+    PerClaim { base: Balance, per_claim: Balance },
+}
+
 impl<T: Config> sp_std::fmt::Debug for LiquidityInfo<T> {
     fn fmt(&self, f: &mut sp_std::fmt::Formatter<'_>) -> sp_std::fmt::Result {
         match self {
@@ -1116,6 +1170,29 @@ impl<T: Config> Pallet<T> {
     }
 }
 
+impl<T: Config> Pallet<T> {
+    /// Returns custom fee of the call from the fee table. Entry for the call takes precedence
+    /// over entry for the whole pallet.
+    pub fn custom_fee<Call: Encode>(call: &Call) -> Option<CustomFee> {
+        let (pallet_index, call_index) =
+            call.using_encoded(|encoded| (encoded.first().copied(), encoded.get(1).copied()));
+        let pallet_index = pallet_index?;
+        call_index
+            .and_then(|call_index| {
+                CustomFeeTable::<T>::get(CustomFeeKey {
+                    pallet_index,
+                    call_index: Some(call_index),
+                })
+            })
+            .or_else(|| {
+                CustomFeeTable::<T>::get(CustomFeeKey {
+                    pallet_index,
+                    call_index: None,
+                })
+            })
+    }
+}
+
 pub use pallet::*;
 
 pub use weights::WeightInfo;
@@ -1184,7 +1261,7 @@ pub mod pallet {
     }
 
     /// The current storage version.
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -1403,6 +1480,30 @@ pub mod pallet {
                 Ok(().into())
             })
         }
+
+        /// Set custom fee of the call or of the whole pallet.
+        /// # Parameters:
+        /// - `origin`: root
+        /// - `key`: pallet index and call index, where None call index - any call of the pallet
+        /// - `fee`: fee formula, where None - remove custom fee, which must exist
+        #[pallet::call_index(8)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_custom_fee())]
+        pub fn set_custom_fee(
+            origin: OriginFor<T>,
+            key: CustomFeeKey,
+            fee: Option<CustomFee>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            if fee.is_none() {
+                ensure!(
+                    CustomFeeTable::<T>::contains_key(key),
+                    Error::<T>::CustomFeeNotFound
+                );
+            }
+            CustomFeeTable::<T>::set(key, fee);
+            Self::deposit_event(Event::CustomFeeUpdated(key, fee));
+            Ok(().into())
+        }
     }
 
     #[pallet::event]
@@ -1439,6 +1540,8 @@ pub mod pallet {
         AssetAddedToWhiteList(AssetIdOf<T>),
         /// Average remint period updated: [Period]
         RemintPeriodUpdated(u32),
+        /// Custom fee updated, None if removed: [Key, Fee]
+        CustomFeeUpdated(CustomFeeKey, Option<CustomFee>),
     }
     #[pallet::error]
     pub enum Error<T> {
@@ -1456,6 +1559,8 @@ pub mod pallet {
         FeeCalculationFailed,
         /// Remint period should not be 0 or to be greater than 600
         WrongRemintPeriod,
+        /// Custom fee to remove is not set
+        CustomFeeNotFound,
     }
 
     #[cfg(feature = "wip")] // Xorless fee
@@ -1517,6 +1622,35 @@ pub mod pallet {
     #[pallet::getter(fn multiplier)]
     pub type Multiplier<T> = StorageValue<_, FixedU128, ValueQuery, DefaultForFeeMultiplier<T>>;
 
+    /// Custom fees of calls, keyed by pallet index and call index
+    #[pallet::storage]
+    #[pallet::getter(fn custom_fee_table)]
+    pub type CustomFeeTable<T: Config> = StorageMap<_, Twox64Concat, CustomFeeKey, CustomFee>;
+
+    #[pallet::genesis_config]
+    pub struct GenesisConfig<T: Config> {
+        pub custom_fees: Vec<(CustomFeeKey, CustomFee)>,
+        pub _phantom: PhantomData<T>,
+    }
+
+    impl<T: Config> Default for GenesisConfig<T> {
+        fn default() -> Self {
+            Self {
+                custom_fees: Default::default(),
+                _phantom: Default::default(),
+            }
+        }
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            for (key, fee) in self.custom_fees.iter() {
+                CustomFeeTable::<T>::insert(key, fee);
+            }
+        }
+    }
+
     #[pallet::type_value]
     pub fn DefaultForRemintPeriod<T: Config>() -> u32 {
         100
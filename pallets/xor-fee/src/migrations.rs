@@ -226,3 +226,56 @@ pub mod v3 {
         }
     }
 }
+
+pub mod v4 {
+    use crate::*;
+    use core::marker::PhantomData;
+    use frame_support::traits::OnRuntimeUpgrade;
+    use frame_support::weights::Weight;
+    use frame_support::{__private::log::info, traits::StorageVersion};
+
+    /// Fills the custom fee table with `DefaultFees`, which were hard-coded in the runtime before.
+    pub struct Migrate<T, DefaultFees>(PhantomData<(T, DefaultFees)>);
+
+    impl<T, DefaultFees> OnRuntimeUpgrade for Migrate<T, DefaultFees>
+    where
+        T: Config,
+        DefaultFees: Get<Vec<(CustomFeeKey, CustomFee)>>,
+    {
+        fn on_runtime_upgrade() -> Weight {
+            if StorageVersion::get::<Pallet<T>>() == StorageVersion::new(3) {
+                let fees = DefaultFees::get();
+                let count = fees.len() as u64;
+                for (key, fee) in fees {
+                    CustomFeeTable::<T>::insert(key, fee);
+                }
+                StorageVersion::new(4).put::<Pallet<T>>();
+                info!("xor-fee v4 applied successfully, {count} custom fees set.");
+                return T::DbWeight::get().reads_writes(1, count.saturating_add(1));
+            }
+            Weight::default()
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::DispatchError> {
+            frame_support::ensure!(
+                StorageVersion::get::<Pallet<T>>() == StorageVersion::new(3),
+                "Wrong storage version before xor-fee v4 upgrade"
+            );
+            Ok(Vec::new())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+            frame_support::ensure!(
+                StorageVersion::get::<Pallet<T>>() == StorageVersion::new(4),
+                "Wrong storage version after xor-fee v4 upgrade"
+            );
+            frame_support::ensure!(
+                CustomFeeTable::<T>::iter().count() == DefaultFees::get().len(),
+                "Custom fee table was not filled"
+            );
+            Ok(())
+        }
+    }
+}
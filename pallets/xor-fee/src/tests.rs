@@ -36,11 +36,12 @@ use crate::extension::ChargeTransactionPayment;
 #[cfg(feature = "wip")] // Xorless fee
 use crate::WeightInfo;
 use crate::{
-    mock::*, Error, LiquidityInfo, UnassignedValStakingReward, ValStakingEraReward, XorToBuyBack,
-    XorToVal,
+    mock::*, CustomFee, CustomFeeKey, CustomFeeTable, Error, LiquidityInfo,
+    UnassignedValStakingReward, ValStakingEraReward, XorToBuyBack, XorToVal,
 };
 #[cfg(feature = "wip")] // Dynamic fee
 use crate::{CalculateMultiplier, Multiplier, UpdatePeriod};
+use codec::Encode;
 use common::mock::{alice, bob};
 #[cfg(feature = "wip")] // Dynamic fee
 use common::prelude::FixedWrapper;
@@ -55,7 +56,7 @@ use frame_support::dispatch::GetDispatchInfo;
 #[cfg(feature = "wip")] // Dynamic fee
 use frame_support::dispatch::{DispatchErrorWithPostInfo, Pays};
 use frame_support::error::BadOrigin;
-use frame_support::traits::{Currency, OnRuntimeUpgrade, StorageVersion};
+use frame_support::traits::{Currency, Get, OnRuntimeUpgrade, StorageVersion};
 use frame_support::weights::{Weight, WeightToFee};
 use frame_support::{assert_noop, assert_ok};
 use sp_arithmetic::traits::Zero;
@@ -115,6 +116,110 @@ fn v3_migration_clears_legacy_buyback_bucket() {
     });
 }
 
+fn call_key(call: &RuntimeCall) -> CustomFeeKey {
+    let encoded = call.encode();
+    CustomFeeKey {
+        pallet_index: encoded[0],
+        call_index: Some(encoded[1]),
+    }
+}
+
+fn pallet_key(call: &RuntimeCall) -> CustomFeeKey {
+    CustomFeeKey {
+        call_index: None,
+        ..call_key(call)
+    }
+}
+
+#[test]
+fn set_custom_fee_only_root() {
+    ExtBuilder::build().execute_with(|| {
+        let key = call_key(&remark_call(b"fee"));
+        assert_noop!(
+            XorFee::set_custom_fee(
+                RuntimeOrigin::signed(alice()),
+                key,
+                Some(CustomFee::Flat(balance!(1)))
+            ),
+            BadOrigin
+        );
+    });
+}
+
+#[test]
+fn set_custom_fee_works() {
+    ExtBuilder::build().execute_with(|| {
+        let call = remark_call(b"fee");
+        assert_eq!(XorFee::custom_fee(&call), None);
+
+        assert_ok!(XorFee::set_custom_fee(
+            RuntimeOrigin::root(),
+            pallet_key(&call),
+            Some(CustomFee::Flat(balance!(1)))
+        ));
+        assert_eq!(
+            XorFee::custom_fee(&call),
+            Some(CustomFee::Flat(balance!(1)))
+        );
+
+        // call entry takes precedence over pallet entry
+        assert_ok!(XorFee::set_custom_fee(
+            RuntimeOrigin::root(),
+            call_key(&call),
+            Some(CustomFee::PerReceiver(balance!(2)))
+        ));
+        assert_eq!(
+            XorFee::custom_fee(&call),
+            Some(CustomFee::PerReceiver(balance!(2)))
+        );
+
+        assert_ok!(XorFee::set_custom_fee(
+            RuntimeOrigin::root(),
+            call_key(&call),
+            None
+        ));
+        assert_eq!(
+            XorFee::custom_fee(&call),
+            Some(CustomFee::Flat(balance!(1)))
+        );
+        assert!(!CustomFeeTable::<Runtime>::contains_key(call_key(&call)));
+
+        assert_noop!(
+            XorFee::set_custom_fee(RuntimeOrigin::root(), call_key(&call), None),
+            Error::<Runtime>::CustomFeeNotFound
+        );
+    });
+}
+
+struct TestDefaultCustomFees;
+
+impl Get<Vec<(CustomFeeKey, CustomFee)>> for TestDefaultCustomFees {
+    fn get() -> Vec<(CustomFeeKey, CustomFee)> {
+        vec![(
+            pallet_key(&remark_call(b"fee")),
+            CustomFee::Flat(balance!(0.007)),
+        )]
+    }
+}
+
+#[test]
+fn v4_migration_fills_custom_fee_table() {
+    ExtBuilder::build().execute_with(|| {
+        StorageVersion::new(3).put::<crate::Pallet<Runtime>>();
+
+        crate::migrations::v4::Migrate::<Runtime, TestDefaultCustomFees>::on_runtime_upgrade();
+
+        assert_eq!(
+            XorFee::custom_fee(&remark_call(b"fee")),
+            Some(CustomFee::Flat(balance!(0.007)))
+        );
+        assert_eq!(
+            StorageVersion::get::<crate::Pallet<Runtime>>(),
+            StorageVersion::new(4)
+        );
+    });
+}
+
 #[test]
 fn random_remint_discards_stale_legacy_buyback_bucket() {
     ExtBuilder::build().execute_with(|| {
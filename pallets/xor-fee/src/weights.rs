@@ -68,6 +68,7 @@ pub trait WeightInfo {
 	fn remove_asset_from_white_list() -> Weight;
 	fn set_random_remint_period() -> Weight;
 	fn scale_multiplier() -> Weight;
+	fn set_custom_fee() -> Weight;
 }
 
 /// Weights for xor_fee using the Substrate node and recommended hardware.
@@ -133,6 +134,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(15_876_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+
+	/// Storage: XorFee CustomFeeTable (r:1 w:1)
+	/// Proof: XorFee CustomFeeTable (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	fn set_custom_fee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `95`
+		//  Estimated: `3509`
+		// Minimum execution time: 21_683_000 picoseconds.
+		Weight::from_parts(22_247_000, 3509)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -197,4 +210,16 @@ impl WeightInfo for () {
 		Weight::from_parts(15_876_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+
+	/// Storage: XorFee CustomFeeTable (r:1 w:1)
+	/// Proof: XorFee CustomFeeTable (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	fn set_custom_fee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `95`
+		//  Estimated: `3509`
+		// Minimum execution time: 21_683_000 picoseconds.
+		Weight::from_parts(22_247_000, 3509)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
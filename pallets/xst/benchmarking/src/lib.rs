@@ -45,7 +45,7 @@ use common::{
 };
 use frame_benchmarking::benchmarks;
 use frame_support::pallet_prelude::DispatchResultWithPostInfo;
-use frame_support::traits::Get;
+use frame_support::traits::{Get, Hooks};
 use frame_system::{EventRecord, RawOrigin};
 use hex_literal::hex;
 use oracle_proxy::Pallet as OracleProxy;
@@ -53,7 +53,7 @@ use price_tools::Pallet as PriceTools;
 use sp_std::prelude::*;
 use sp_std::vec;
 use technical::Pallet as Technical;
-use xst::{Call, Event, Pallet as XSTPool};
+use xst::{Call, Event, Pallet as XSTPool, PauseReason, SyntheticLimits};
 
 #[cfg(test)]
 mod mock;
@@ -102,10 +102,20 @@ mod utils {
         price_tools::PriceInfos::<T>::mutate(asset_id, |opt_val| {
             let val = opt_val.as_mut().unwrap();
             val.price_mut_of(PriceVariant::Buy)
-                .incoming_spot_price(price, PriceVariant::Buy, &price_tools::DEFAULT_PARAMETERS)
+                .incoming_spot_price(
+                    price,
+                    PriceVariant::Buy,
+                    &price_tools::DEFAULT_PARAMETERS,
+                    price_tools::AVG_BLOCK_SPAN,
+                )
                 .unwrap();
             val.price_mut_of(PriceVariant::Sell)
-                .incoming_spot_price(price, PriceVariant::Sell, &price_tools::DEFAULT_PARAMETERS)
+                .incoming_spot_price(
+                    price,
+                    PriceVariant::Sell,
+                    &price_tools::DEFAULT_PARAMETERS,
+                    price_tools::AVG_BLOCK_SPAN,
+                )
                 .unwrap();
         })
     }
@@ -315,5 +325,64 @@ benchmarks! {
         ).unwrap();
     }
 
+    set_synthetic_limits {
+        let asset_id = utils::enable_synthetic_asset::<T>()?;
+        let limits = SyntheticLimits {
+            max_supply: Some(balance!(1000000)),
+            max_mint_per_block: Some(balance!(10000)),
+            max_burn_per_block: Some(balance!(10000)),
+            max_rate_deviation: Some(fixed!(0.1)),
+        };
+    }: _(
+        RawOrigin::Root,
+        asset_id.clone(),
+        limits.clone()
+    )
+    verify {
+        utils::assert_last_event::<T>(Event::SyntheticAssetLimitsChanged(asset_id, limits).into())
+    }
+
+    unpause_synthetic_asset {
+        let asset_id = utils::enable_synthetic_asset::<T>()?;
+        xst::PausedSynthetics::<T>::insert(asset_id, PauseReason::OracleRateStale(0));
+    }: _(
+        RawOrigin::Root,
+        asset_id.clone()
+    )
+    verify {
+        utils::assert_last_event::<T>(Event::SyntheticAssetUnpaused(asset_id).into())
+    }
+
+    check_synthetic_rate {
+        let asset_id = utils::enable_synthetic_asset::<T>()?;
+        XSTPool::<T>::set_synthetic_limits(
+            RawOrigin::Root.into(),
+            asset_id.clone(),
+            SyntheticLimits {
+                max_rate_deviation: Some(fixed!(0.1)),
+                ..Default::default()
+            },
+        )?;
+        XSTPool::<T>::on_initialize(frame_system::Pallet::<T>::block_number());
+        Band::<T>::relay(
+            RawOrigin::Signed(utils::alice::<T>()).into(),
+            vec![(utils::symbol::<<T as band::Config>::Symbol>(), 2000000000)]
+                .try_into()
+                .unwrap(),
+            1,
+            1,
+        )?;
+    }: {
+        XSTPool::<T>::on_initialize(frame_system::Pallet::<T>::block_number());
+    }
+    verify {
+        utils::assert_last_event::<T>(
+            Event::SyntheticAssetPaused(
+                asset_id,
+                PauseReason::OracleRateJump(balance!(1), balance!(2)),
+            ).into()
+        )
+    }
+
     impl_benchmark_test_suite!(Pallet, crate::mock::ExtBuilder::default().build(), crate::mock::Runtime);
 }
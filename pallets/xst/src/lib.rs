@@ -47,7 +47,7 @@ pub mod migrations;
 
 use core::convert::TryInto;
 
-use codec::{Decode, Encode};
+use codec::{Decode, DecodeWithMemTracking, Encode};
 use common::alt::{DiscreteQuotation, SideAmount, SwapChunk};
 use common::fixnum::ops::Zero as _;
 use common::prelude::{
@@ -64,6 +64,7 @@ use frame_support::pallet_prelude::DispatchResult;
 use frame_support::traits::Get;
 use frame_support::weights::Weight;
 use frame_support::{ensure, fail};
+use frame_system::pallet_prelude::BlockNumberFor;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 use sp_runtime::traits::Zero;
@@ -128,6 +129,48 @@ pub struct SyntheticInfo<Symbol> {
     pub fee_ratio: Fixed,
 }
 
+/// Safeguards applied to a synthetic asset, `None` disables a particular limit.
+#[derive(
+    RuntimeDebug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    scale_info::TypeInfo,
+)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct SyntheticLimits {
+    /// Maximum total supply of the synthetic asset
+    pub max_supply: Option<Balance>,
+    /// Maximum amount of the synthetic asset minted in one block
+    pub max_mint_per_block: Option<Balance>,
+    /// Maximum amount of the synthetic asset burned in one block
+    pub max_burn_per_block: Option<Balance>,
+    /// Maximum change of the oracle rate between two updates, the synthetic asset is paused
+    /// if it is exceeded. 1 = 100%
+    pub max_rate_deviation: Option<Fixed>,
+}
+
+/// Reason why a synthetic asset was paused.
+#[derive(RuntimeDebug, Clone, PartialEq, Eq, Encode, Decode, scale_info::TypeInfo)]
+pub enum PauseReason {
+    /// Oracle rate moved more than allowed between updates. [Previous Rate, New Rate]
+    OracleRateJump(Balance, Balance),
+    /// Oracle rate is older than allowed. [Last Update Timestamp]
+    OracleRateStale(u64),
+}
+
+/// Amounts of a synthetic asset minted and burned during the block.
+#[derive(RuntimeDebug, Clone, Default, PartialEq, Eq, Encode, Decode, scale_info::TypeInfo)]
+pub struct BlockVolume<BlockNumber> {
+    pub block: BlockNumber,
+    pub minted: Balance,
+    pub burned: Balance,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -150,6 +193,9 @@ pub mod pallet {
         /// Maximum tradable amount of XST
         #[pallet::constant]
         type GetSyntheticBaseBuySellLimit: Get<Balance>;
+        /// Maximum age of oracle rates in seconds, `None` uses rates without checks
+        #[pallet::constant]
+        type MaxOracleRateAge: Get<Option<u64>>;
         type TradingPairSourceManager: TradingPairSourceManager<Self::DEXId, AssetIdOf<Self>>;
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
@@ -172,6 +218,17 @@ pub mod pallet {
     #[pallet::without_storage_info]
     pub struct Pallet<T>(PhantomData<T>);
 
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// Pause synthetics whose oracle rates jumped or became stale.
+        fn on_initialize(_now: BlockNumberFor<T>) -> Weight {
+            let checked = Self::check_oracle_rates();
+            T::DbWeight::get().reads(1).saturating_add(
+                <T as Config>::WeightInfo::check_synthetic_rate().saturating_mul(checked.into()),
+            )
+        }
+    }
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Change reference asset which is used to determine collateral assets value.
@@ -277,6 +334,10 @@ pub mod pallet {
 
             EnabledSynthetics::<T>::remove(synthetic_asset);
             EnabledSymbols::<T>::remove(&reference_symbol);
+            SyntheticAssetLimits::<T>::remove(synthetic_asset);
+            PausedSynthetics::<T>::remove(synthetic_asset);
+            LastOracleRates::<T>::remove(synthetic_asset);
+            SyntheticBlockVolumes::<T>::remove(synthetic_asset);
 
             Self::deposit_event(Event::SyntheticAssetRemoved(
                 synthetic_asset,
@@ -336,6 +397,65 @@ pub mod pallet {
             Self::deposit_event(Event::SyntheticBaseAssetFloorPriceChanged(floor_price));
             Ok(().into())
         }
+
+        /// Set supply, volume and oracle rate limits of the synthetic asset.
+        ///
+        /// - `origin`: the sudo account on whose behalf the transaction is being executed,
+        /// - `synthetic_asset`: synthetic asset id to set limits for,
+        /// - `limits`: new limits, default value removes all of them.
+        #[pallet::call_index(7)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_synthetic_limits())]
+        pub fn set_synthetic_limits(
+            origin: OriginFor<T>,
+            synthetic_asset: AssetIdOf<T>,
+            limits: SyntheticLimits,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            ensure!(
+                Self::enabled_synthetics(synthetic_asset).is_some(),
+                Error::<T>::SyntheticIsNotEnabled
+            );
+            ensure!(
+                limits
+                    .max_rate_deviation
+                    .map_or(true, |deviation| deviation > fixed!(0)),
+                Error::<T>::InvalidSyntheticLimits
+            );
+
+            if limits == SyntheticLimits::default() {
+                SyntheticAssetLimits::<T>::remove(synthetic_asset);
+            } else {
+                SyntheticAssetLimits::<T>::insert(synthetic_asset, limits.clone());
+            }
+            if limits.max_rate_deviation.is_none() {
+                LastOracleRates::<T>::remove(synthetic_asset);
+            }
+
+            Self::deposit_event(Event::SyntheticAssetLimitsChanged(synthetic_asset, limits));
+            Ok(().into())
+        }
+
+        /// Resume exchanges of the synthetic asset paused by the circuit breaker.
+        ///
+        /// The current oracle rate becomes the new reference for the rate jump check.
+        ///
+        /// - `origin`: the sudo account on whose behalf the transaction is being executed,
+        /// - `synthetic_asset`: synthetic asset id to unpause.
+        #[pallet::call_index(8)]
+        #[pallet::weight(<T as Config>::WeightInfo::unpause_synthetic_asset())]
+        pub fn unpause_synthetic_asset(
+            origin: OriginFor<T>,
+            synthetic_asset: AssetIdOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            ensure!(
+                PausedSynthetics::<T>::take(synthetic_asset).is_some(),
+                Error::<T>::SyntheticIsNotPaused
+            );
+            LastOracleRates::<T>::remove(synthetic_asset);
+            Self::deposit_event(Event::SyntheticAssetUnpaused(synthetic_asset));
+            Ok(().into())
+        }
     }
 
     #[pallet::event]
@@ -353,6 +473,12 @@ pub mod pallet {
         SyntheticBaseAssetFloorPriceChanged(Balance),
         /// Synthetic asset has been removed. [Synthetic Asset Id, Reference Symbol]
         SyntheticAssetRemoved(AssetIdOf<T>, T::Symbol),
+        /// Synthetic asset limits have been changed. [Synthetic Asset Id, New Limits]
+        SyntheticAssetLimitsChanged(AssetIdOf<T>, SyntheticLimits),
+        /// Synthetic asset exchanges have been paused. [Synthetic Asset Id, Reason]
+        SyntheticAssetPaused(AssetIdOf<T>, PauseReason),
+        /// Synthetic asset exchanges have been resumed. [Synthetic Asset Id]
+        SyntheticAssetUnpaused(AssetIdOf<T>),
     }
 
     #[pallet::error]
@@ -382,6 +508,18 @@ pub mod pallet {
         CantEnableIndivisibleAsset,
         /// Input/output amount of synthetic base asset exceeds the limit
         SyntheticBaseBuySellLimitExceeded,
+        /// Invalid synthetic asset limits.
+        InvalidSyntheticLimits,
+        /// Minting the synthetic asset would exceed its maximum supply.
+        SyntheticSupplyLimitExceeded,
+        /// Amount of the synthetic asset minted in this block exceeds the limit.
+        SyntheticMintLimitExceeded,
+        /// Amount of the synthetic asset burned in this block exceeds the limit.
+        SyntheticBurnLimitExceeded,
+        /// Synthetic asset exchanges are paused.
+        SyntheticIsPaused,
+        /// Attempt to unpause synthetic asset that is not paused.
+        SyntheticIsNotPaused,
     }
 
     /// Synthetic assets and their reference symbols.
@@ -421,6 +559,28 @@ pub mod pallet {
     pub type SyntheticBaseAssetFloorPrice<T: Config> =
         StorageValue<_, Balance, ValueQuery, SyntheticBaseAssetDefaultFloorPrice>;
 
+    /// Supply, volume and oracle rate limits of synthetic assets.
+    #[pallet::storage]
+    #[pallet::getter(fn synthetic_limits)]
+    pub type SyntheticAssetLimits<T: Config> =
+        StorageMap<_, Blake2_128Concat, AssetIdOf<T>, SyntheticLimits, ValueQuery>;
+
+    /// Amounts of synthetic assets minted and burned during the last block they were exchanged.
+    #[pallet::storage]
+    pub type SyntheticBlockVolumes<T: Config> =
+        StorageMap<_, Blake2_128Concat, AssetIdOf<T>, BlockVolume<BlockNumberFor<T>>, ValueQuery>;
+
+    /// Synthetic assets paused by the circuit breaker and the reasons.
+    #[pallet::storage]
+    #[pallet::getter(fn paused_synthetics)]
+    pub type PausedSynthetics<T: Config> =
+        StorageMap<_, Blake2_128Concat, AssetIdOf<T>, PauseReason, OptionQuery>;
+
+    /// Last seen oracle rates of synthetic assets with the rate jump check enabled.
+    #[pallet::storage]
+    pub type LastOracleRates<T: Config> =
+        StorageMap<_, Blake2_128Concat, AssetIdOf<T>, Rate, OptionQuery>;
+
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
         /// Asset that is used to compare collateral assets by value, e.g., DAI.
@@ -827,6 +987,17 @@ impl<T: Config> Pallet<T> {
                     )?
                 };
 
+            let (synthetic_asset_id, minted, burned) = if input_asset_id == synthetic_base_asset_id
+            {
+                (output_asset_id, output_amount, 0)
+            } else {
+                (input_asset_id, 0, input_amount)
+            };
+            if let Some(volume) = Self::synthetic_volume_after(synthetic_asset_id, minted, burned)?
+            {
+                SyntheticBlockVolumes::<T>::insert(synthetic_asset_id, volume);
+            }
+
             // in XST
             let fee = OutcomeFee::from_asset(T::GetSyntheticBaseAssetId::get(), fee_amount);
 
@@ -938,13 +1109,21 @@ impl<T: Config> Pallet<T> {
                 let symbol = EnabledSynthetics::<T>::get(id)
                     .ok_or(Error::<T>::SyntheticDoesNotExist)?
                     .reference_symbol;
-                T::Oracle::quote_unchecked(&symbol)
+                Self::oracle_rate(&symbol)?
                     .map(|rate| rate.value)
                     .ok_or(Error::<T>::OracleQuoteError.into())
             }
         }
     }
 
+    /// Get `symbol` rate from oracle, checking its age if [`Config::MaxOracleRateAge`] is set
+    fn oracle_rate(symbol: &T::Symbol) -> Result<Option<Rate>, DispatchError> {
+        match T::MaxOracleRateAge::get() {
+            Some(max_age) => T::Oracle::quote_with_max_age(symbol, max_age),
+            None => Ok(T::Oracle::quote_unchecked(symbol)),
+        }
+    }
+
     /// Check if any symbol rate is present in OracleProxy
     fn ensure_symbol_exists(reference_symbol: &T::Symbol) -> Result<(), DispatchError> {
         if *reference_symbol == common::SymbolName::usd().into() {
@@ -1012,6 +1191,14 @@ impl<T: Config> Pallet<T> {
             )?
         };
 
+        if check_limits {
+            if input_asset_id == synthetic_base_asset_id {
+                Self::synthetic_volume_after(output_asset_id, output_amount, 0)?;
+            } else {
+                Self::synthetic_volume_after(input_asset_id, 0, input_amount)?;
+            }
+        }
+
         // in XST
         let fee = OutcomeFee::from_asset(T::GetSyntheticBaseAssetId::get(), fee_amount);
 
@@ -1025,8 +1212,129 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Checks supply and per-block volume limits of the synthetic asset if `minted` and `burned`
+    /// amounts are exchanged. Returns the block volume to be stored if it is tracked.
+    fn synthetic_volume_after(
+        synthetic_asset_id: &AssetIdOf<T>,
+        minted: Balance,
+        burned: Balance,
+    ) -> Result<Option<BlockVolume<BlockNumberFor<T>>>, DispatchError> {
+        let limits = SyntheticAssetLimits::<T>::get(synthetic_asset_id);
+
+        match limits.max_supply {
+            Some(max_supply) if !minted.is_zero() => {
+                let total_issuance =
+                    <T as Config>::AssetInfoProvider::total_issuance(synthetic_asset_id)?;
+                ensure!(
+                    total_issuance
+                        .checked_add(minted)
+                        .map_or(false, |supply| supply <= max_supply),
+                    Error::<T>::SyntheticSupplyLimitExceeded
+                );
+            }
+            _ => {}
+        }
+
+        if limits.max_mint_per_block.is_none() && limits.max_burn_per_block.is_none() {
+            return Ok(None);
+        }
+
+        let now = frame_system::Pallet::<T>::block_number();
+        let mut volume = SyntheticBlockVolumes::<T>::get(synthetic_asset_id);
+        if volume.block != now {
+            volume = BlockVolume {
+                block: now,
+                ..Default::default()
+            };
+        }
+        volume.minted = volume.minted.saturating_add(minted);
+        volume.burned = volume.burned.saturating_add(burned);
+
+        ensure!(
+            minted.is_zero()
+                || limits
+                    .max_mint_per_block
+                    .map_or(true, |max_mint| volume.minted <= max_mint),
+            Error::<T>::SyntheticMintLimitExceeded
+        );
+        ensure!(
+            burned.is_zero()
+                || limits
+                    .max_burn_per_block
+                    .map_or(true, |max_burn| volume.burned <= max_burn),
+            Error::<T>::SyntheticBurnLimitExceeded
+        );
+        Ok(Some(volume))
+    }
+
+    /// Pauses synthetics with stale oracle rates or rates that moved more than allowed since
+    /// the previous update. Returns the number of checked synthetics.
+    fn check_oracle_rates() -> u32 {
+        let mut checked = 0;
+        for (synthetic_asset_id, info) in EnabledSynthetics::<T>::iter() {
+            if PausedSynthetics::<T>::contains_key(synthetic_asset_id) {
+                continue;
+            }
+            checked += 1;
+            let Some(rate) = T::Oracle::quote_unchecked(&info.reference_symbol) else {
+                continue;
+            };
+
+            if let Some(max_age) = T::MaxOracleRateAge::get() {
+                if T::Oracle::quote_with_max_age(&info.reference_symbol, max_age).is_err() {
+                    Self::pause_synthetic(
+                        synthetic_asset_id,
+                        PauseReason::OracleRateStale(rate.last_updated),
+                    );
+                    continue;
+                }
+            }
+
+            let Some(max_deviation) =
+                SyntheticAssetLimits::<T>::get(synthetic_asset_id).max_rate_deviation
+            else {
+                continue;
+            };
+            match LastOracleRates::<T>::get(synthetic_asset_id) {
+                Some(last_rate) if last_rate.value == rate.value => {}
+                Some(last_rate) => {
+                    let deviation = if last_rate.value.is_zero() {
+                        None
+                    } else {
+                        (FixedWrapper::from(last_rate.value.abs_diff(rate.value))
+                            / FixedWrapper::from(last_rate.value))
+                        .get()
+                        .ok()
+                    };
+                    if deviation.map_or(true, |deviation| deviation > max_deviation) {
+                        Self::pause_synthetic(
+                            synthetic_asset_id,
+                            PauseReason::OracleRateJump(last_rate.value, rate.value),
+                        );
+                    } else {
+                        LastOracleRates::<T>::insert(synthetic_asset_id, rate);
+                    }
+                }
+                None => LastOracleRates::<T>::insert(synthetic_asset_id, rate),
+            }
+        }
+        checked
+    }
+
+    fn pause_synthetic(synthetic_asset_id: AssetIdOf<T>, reason: PauseReason) {
+        PausedSynthetics::<T>::insert(synthetic_asset_id, reason.clone());
+        Self::deposit_event(Event::SyntheticAssetPaused(synthetic_asset_id, reason));
+    }
+
+    fn is_paused(asset_id: &AssetIdOf<T>) -> bool {
+        PausedSynthetics::<T>::contains_key(asset_id)
+    }
+
     fn disable_synthetic_asset_unchecked(synthetic_asset: AssetIdOf<T>) -> DispatchResult {
         EnabledSynthetics::<T>::remove(synthetic_asset);
+        PausedSynthetics::<T>::remove(synthetic_asset);
+        LastOracleRates::<T>::remove(synthetic_asset);
+        SyntheticBlockVolumes::<T>::remove(synthetic_asset);
         T::TradingPairSourceManager::disable_source_for_trading_pair(
             &DEXId::Polkaswap.into(),
             &T::GetSyntheticBaseAssetId::get(),
@@ -1050,9 +1358,9 @@ impl<T: Config> LiquiditySource<T::DEXId, T::AccountId, AssetIdOf<T>, Balance, D
             return false;
         }
         if input_asset_id == &T::GetSyntheticBaseAssetId::get() {
-            Self::is_synthetic(&output_asset_id)
+            Self::is_synthetic(&output_asset_id) && !Self::is_paused(&output_asset_id)
         } else if output_asset_id == &T::GetSyntheticBaseAssetId::get() {
-            Self::is_synthetic(&input_asset_id)
+            Self::is_synthetic(&input_asset_id) && !Self::is_paused(&input_asset_id)
         } else {
             false
         }
@@ -1185,6 +1493,10 @@ impl<T: Config> LiquiditySource<T::DEXId, T::AccountId, AssetIdOf<T>, Balance, D
         output_asset_id: &AssetIdOf<T>,
         desired_amount: SwapAmount<Balance>,
     ) -> Result<(SwapOutcome<Balance, AssetIdOf<T>>, Weight), DispatchError> {
+        ensure!(
+            !Self::is_paused(input_asset_id) && !Self::is_paused(output_asset_id),
+            Error::<T>::SyntheticIsPaused
+        );
         if !Self::can_exchange(dex_id, input_asset_id, output_asset_id) {
             fail!(Error::<T>::CantExchange);
         }
@@ -119,7 +119,7 @@ construct_runtime! {
         UncheckedExtrinsic = UncheckedExtrinsic,
     {
         System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
-        DexManager: dex_manager::{Pallet, Call, Storage},
+        DexManager: dex_manager::{Pallet, Call, Storage, Event<T>},
         TradingPair: trading_pair::{Pallet, Call, Storage, Event<T>},
         MockLiquiditySource: mock_liquidity_source::<Instance1>::{Pallet, Call, Config<T>, Storage},
         Tokens: tokens::{Pallet, Call, Config<T>, Storage, Event<T>},
@@ -191,6 +191,7 @@ where
                     price_buy,
                     PriceVariant::Buy,
                     &price_tools::DEFAULT_PARAMETERS,
+                    price_tools::AVG_BLOCK_SPAN,
                 )
                 .expect("Failed to relay spot price");
             val.price_mut_of(PriceVariant::Sell)
@@ -198,6 +199,7 @@ where
                     price_sell,
                     PriceVariant::Sell,
                     &price_tools::DEFAULT_PARAMETERS,
+                    price_tools::AVG_BLOCK_SPAN,
                 )
                 .expect("Failed to relay spot price");
         })
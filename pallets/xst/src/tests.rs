@@ -33,7 +33,7 @@ mod tests {
     use crate::{
         mock::*,
         test_utils::{relay_new_symbol, relay_symbol},
-        Error, Pallet,
+        Error, Event, Pallet, PauseReason, SyntheticLimits,
     };
     use band::FeeCalculationParameters;
     use common::alt::{DiscreteQuotation, SideAmount, SwapChunk, SwapLimits};
@@ -1998,4 +1998,275 @@ mod tests {
             );
         });
     }
+
+    fn register_xsteuro() -> AssetId {
+        let euro = relay_new_symbol("EURO", 2_000_000_000);
+        XSTPool::register_synthetic_asset(
+            RuntimeOrigin::root(),
+            AssetSymbol("XSTEUR".into()),
+            AssetName("XST Euro".into()),
+            euro.clone(),
+            fixed!(0),
+        ).expect("Failed to register synthetic asset");
+        XSTPool::enabled_symbols(&euro).expect("Expected synthetic asset")
+    }
+
+    #[test]
+    fn set_synthetic_limits_should_work() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            frame_system::Pallet::<Runtime>::set_block_number(1);
+            let xsteuro = register_xsteuro();
+            let limits = SyntheticLimits {
+                max_supply: Some(balance!(1000)),
+                max_mint_per_block: Some(balance!(100)),
+                max_burn_per_block: Some(balance!(100)),
+                max_rate_deviation: Some(fixed!(0.1)),
+            };
+
+            assert_noop!(
+                XSTPool::set_synthetic_limits(RuntimeOrigin::signed(alice()), xsteuro, limits.clone()),
+                sp_runtime::DispatchError::BadOrigin
+            );
+            assert_noop!(
+                XSTPool::set_synthetic_limits(RuntimeOrigin::root(), DAI, limits.clone()),
+                Error::<Runtime>::SyntheticIsNotEnabled
+            );
+            assert_noop!(
+                XSTPool::set_synthetic_limits(
+                    RuntimeOrigin::root(),
+                    xsteuro,
+                    SyntheticLimits {
+                        max_rate_deviation: Some(fixed!(0)),
+                        ..Default::default()
+                    }
+                ),
+                Error::<Runtime>::InvalidSyntheticLimits
+            );
+
+            assert_ok!(XSTPool::set_synthetic_limits(RuntimeOrigin::root(), xsteuro, limits.clone()));
+            assert_eq!(XSTPool::synthetic_limits(xsteuro), limits);
+            frame_system::Pallet::<Runtime>::assert_last_event(
+                Event::<Runtime>::SyntheticAssetLimitsChanged(xsteuro, limits).into(),
+            );
+
+            assert_ok!(XSTPool::set_synthetic_limits(RuntimeOrigin::root(), xsteuro, Default::default()));
+            assert!(!crate::SyntheticAssetLimits::<Runtime>::contains_key(xsteuro));
+        });
+    }
+
+    #[test]
+    fn synthetic_mint_and_burn_limits_should_work() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            frame_system::Pallet::<Runtime>::set_block_number(1);
+            let xsteuro = register_xsteuro();
+            // 1 XST sell price = 150 DAI, 1 XSTEURO = 2 DAI, so 1 XST = 75 XSTEURO
+            assert_ok!(XSTPool::set_synthetic_limits(
+                RuntimeOrigin::root(),
+                xsteuro,
+                SyntheticLimits {
+                    max_mint_per_block: Some(balance!(100)),
+                    max_burn_per_block: Some(balance!(10)),
+                    ..Default::default()
+                }
+            ));
+
+            assert_ok!(XSTPool::exchange(
+                &alice(),
+                &alice(),
+                &DEXId::Polkaswap,
+                &XST,
+                &xsteuro,
+                SwapAmount::with_desired_input(balance!(1), 0),
+            ));
+            assert_noop!(
+                XSTPool::quote(
+                    &DEXId::Polkaswap,
+                    &XST,
+                    &xsteuro,
+                    QuoteAmount::with_desired_input(balance!(1)),
+                    true,
+                ),
+                Error::<Runtime>::SyntheticMintLimitExceeded
+            );
+            assert_noop!(
+                XSTPool::exchange(
+                    &alice(),
+                    &alice(),
+                    &DEXId::Polkaswap,
+                    &XST,
+                    &xsteuro,
+                    SwapAmount::with_desired_input(balance!(1), 0),
+                ),
+                Error::<Runtime>::SyntheticMintLimitExceeded
+            );
+
+            assert_ok!(XSTPool::exchange(
+                &alice(),
+                &alice(),
+                &DEXId::Polkaswap,
+                &xsteuro,
+                &XST,
+                SwapAmount::with_desired_input(balance!(10), 0),
+            ));
+            assert_noop!(
+                XSTPool::exchange(
+                    &alice(),
+                    &alice(),
+                    &DEXId::Polkaswap,
+                    &xsteuro,
+                    &XST,
+                    SwapAmount::with_desired_input(balance!(1), 0),
+                ),
+                Error::<Runtime>::SyntheticBurnLimitExceeded
+            );
+
+            // limits are reset in the next block
+            frame_system::Pallet::<Runtime>::set_block_number(2);
+            assert_ok!(XSTPool::exchange(
+                &alice(),
+                &alice(),
+                &DEXId::Polkaswap,
+                &XST,
+                &xsteuro,
+                SwapAmount::with_desired_input(balance!(1), 0),
+            ));
+            assert_ok!(XSTPool::exchange(
+                &alice(),
+                &alice(),
+                &DEXId::Polkaswap,
+                &xsteuro,
+                &XST,
+                SwapAmount::with_desired_input(balance!(1), 0),
+            ));
+        });
+    }
+
+    #[test]
+    fn synthetic_supply_limit_should_work() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            let xsteuro = register_xsteuro();
+            assert_ok!(XSTPool::exchange(
+                &alice(),
+                &alice(),
+                &DEXId::Polkaswap,
+                &XST,
+                &xsteuro,
+                SwapAmount::with_desired_input(balance!(1), 0),
+            ));
+            let supply = Assets::total_issuance(&xsteuro).unwrap();
+            assert!(supply > balance!(0));
+
+            assert_ok!(XSTPool::set_synthetic_limits(
+                RuntimeOrigin::root(),
+                xsteuro,
+                SyntheticLimits {
+                    max_supply: Some(supply + balance!(1)),
+                    ..Default::default()
+                }
+            ));
+            assert_noop!(
+                XSTPool::quote(
+                    &DEXId::Polkaswap,
+                    &XST,
+                    &xsteuro,
+                    QuoteAmount::with_desired_input(balance!(1)),
+                    true,
+                ),
+                Error::<Runtime>::SyntheticSupplyLimitExceeded
+            );
+            assert_noop!(
+                XSTPool::exchange(
+                    &alice(),
+                    &alice(),
+                    &DEXId::Polkaswap,
+                    &XST,
+                    &xsteuro,
+                    SwapAmount::with_desired_input(balance!(1), 0),
+                ),
+                Error::<Runtime>::SyntheticSupplyLimitExceeded
+            );
+            assert_ok!(XSTPool::exchange(
+                &alice(),
+                &alice(),
+                &DEXId::Polkaswap,
+                &XST,
+                &xsteuro,
+                SwapAmount::with_desired_output(balance!(1), balance!(1)),
+            ));
+            // burning is not affected by the supply limit
+            assert_ok!(XSTPool::exchange(
+                &alice(),
+                &alice(),
+                &DEXId::Polkaswap,
+                &xsteuro,
+                &XST,
+                SwapAmount::with_desired_input(balance!(1), 0),
+            ));
+        });
+    }
+
+    #[test]
+    fn oracle_rate_jump_should_pause_synthetic() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            frame_system::Pallet::<Runtime>::set_block_number(1);
+            let xsteuro = register_xsteuro();
+            let euro = XSTPool::enabled_synthetics(&xsteuro).unwrap().reference_symbol;
+            assert_ok!(XSTPool::set_synthetic_limits(
+                RuntimeOrigin::root(),
+                xsteuro,
+                SyntheticLimits {
+                    max_rate_deviation: Some(fixed!(0.1)),
+                    ..Default::default()
+                }
+            ));
+            assert_noop!(
+                XSTPool::unpause_synthetic_asset(RuntimeOrigin::root(), xsteuro),
+                Error::<Runtime>::SyntheticIsNotPaused
+            );
+
+            XSTPool::on_initialize(1);
+            // 5% change is within the limit
+            relay_symbol(euro.clone(), 2_100_000_000);
+            XSTPool::on_initialize(2);
+            assert!(XSTPool::paused_synthetics(xsteuro).is_none());
+
+            relay_symbol(euro.clone(), 3_000_000_000);
+            XSTPool::on_initialize(3);
+            let reason = PauseReason::OracleRateJump(balance!(2.1), balance!(3));
+            assert_eq!(XSTPool::paused_synthetics(xsteuro), Some(reason.clone()));
+            frame_system::Pallet::<Runtime>::assert_last_event(
+                Event::<Runtime>::SyntheticAssetPaused(xsteuro, reason).into(),
+            );
+            assert!(!XSTPool::can_exchange(&DEXId::Polkaswap, &XST, &xsteuro));
+            assert_noop!(
+                XSTPool::exchange(
+                    &alice(),
+                    &alice(),
+                    &DEXId::Polkaswap,
+                    &XST,
+                    &xsteuro,
+                    SwapAmount::with_desired_input(balance!(1), 0),
+                ),
+                Error::<Runtime>::SyntheticIsPaused
+            );
+
+            assert_noop!(
+                XSTPool::unpause_synthetic_asset(RuntimeOrigin::signed(alice()), xsteuro),
+                sp_runtime::DispatchError::BadOrigin
+            );
+            assert_ok!(XSTPool::unpause_synthetic_asset(RuntimeOrigin::root(), xsteuro));
+            frame_system::Pallet::<Runtime>::assert_last_event(
+                Event::<Runtime>::SyntheticAssetUnpaused(xsteuro).into(),
+            );
+
+            // the current rate becomes the new reference
+            XSTPool::on_initialize(4);
+            assert!(XSTPool::paused_synthetics(xsteuro).is_none());
+            assert!(XSTPool::can_exchange(&DEXId::Polkaswap, &XST, &xsteuro));
+        });
+    }
 }
@@ -70,6 +70,9 @@ pub trait WeightInfo {
 	fn quote() -> Weight;
 	fn step_quote() -> Weight;
 	fn exchange() -> Weight;
+	fn set_synthetic_limits() -> Weight;
+	fn unpause_synthetic_asset() -> Weight;
+	fn check_synthetic_rate() -> Weight;
 }
 
 /// Weights for xst using the Substrate node and recommended hardware.
@@ -276,6 +279,57 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(20_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
+	/// Storage: XSTPool EnabledSynthetics (r:1 w:0)
+	/// Proof Skipped: XSTPool EnabledSynthetics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool SyntheticAssetLimits (r:0 w:1)
+	/// Proof Skipped: XSTPool SyntheticAssetLimits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool LastOracleRates (r:0 w:1)
+	/// Proof Skipped: XSTPool LastOracleRates (max_values: None, max_size: None, mode: Measured)
+	fn set_synthetic_limits() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `285`
+		//  Estimated: `3750`
+		// Minimum execution time: 27_904_000 picoseconds.
+		Weight::from_parts(28_421_000, 3750)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: XSTPool PausedSynthetics (r:1 w:1)
+	/// Proof Skipped: XSTPool PausedSynthetics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool LastOracleRates (r:0 w:1)
+	/// Proof Skipped: XSTPool LastOracleRates (max_values: None, max_size: None, mode: Measured)
+	fn unpause_synthetic_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `231`
+		//  Estimated: `3696`
+		// Minimum execution time: 24_512_000 picoseconds.
+		Weight::from_parts(25_086_000, 3696)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: XSTPool EnabledSynthetics (r:1 w:0)
+	/// Proof Skipped: XSTPool EnabledSynthetics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool PausedSynthetics (r:1 w:1)
+	/// Proof Skipped: XSTPool PausedSynthetics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: OracleProxy SymbolProviders (r:2 w:0)
+	/// Proof Skipped: OracleProxy SymbolProviders (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Band SymbolRates (r:2 w:0)
+	/// Proof Skipped: Band SymbolRates (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: XSTPool SyntheticAssetLimits (r:1 w:0)
+	/// Proof Skipped: XSTPool SyntheticAssetLimits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool LastOracleRates (r:1 w:0)
+	/// Proof Skipped: XSTPool LastOracleRates (max_values: None, max_size: None, mode: Measured)
+	fn check_synthetic_rate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1214`
+		//  Estimated: `17329`
+		// Minimum execution time: 52_173_000 picoseconds.
+		Weight::from_parts(53_018_000, 17329)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -481,4 +535,55 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(20_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
+	/// Storage: XSTPool EnabledSynthetics (r:1 w:0)
+	/// Proof Skipped: XSTPool EnabledSynthetics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool SyntheticAssetLimits (r:0 w:1)
+	/// Proof Skipped: XSTPool SyntheticAssetLimits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool LastOracleRates (r:0 w:1)
+	/// Proof Skipped: XSTPool LastOracleRates (max_values: None, max_size: None, mode: Measured)
+	fn set_synthetic_limits() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `285`
+		//  Estimated: `3750`
+		// Minimum execution time: 27_904_000 picoseconds.
+		Weight::from_parts(28_421_000, 3750)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: XSTPool PausedSynthetics (r:1 w:1)
+	/// Proof Skipped: XSTPool PausedSynthetics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool LastOracleRates (r:0 w:1)
+	/// Proof Skipped: XSTPool LastOracleRates (max_values: None, max_size: None, mode: Measured)
+	fn unpause_synthetic_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `231`
+		//  Estimated: `3696`
+		// Minimum execution time: 24_512_000 picoseconds.
+		Weight::from_parts(25_086_000, 3696)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: XSTPool EnabledSynthetics (r:1 w:0)
+	/// Proof Skipped: XSTPool EnabledSynthetics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool PausedSynthetics (r:1 w:1)
+	/// Proof Skipped: XSTPool PausedSynthetics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: OracleProxy SymbolProviders (r:2 w:0)
+	/// Proof Skipped: OracleProxy SymbolProviders (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Band SymbolRates (r:2 w:0)
+	/// Proof Skipped: Band SymbolRates (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: XSTPool SyntheticAssetLimits (r:1 w:0)
+	/// Proof Skipped: XSTPool SyntheticAssetLimits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool LastOracleRates (r:1 w:0)
+	/// Proof Skipped: XSTPool LastOracleRates (max_values: None, max_size: None, mode: Measured)
+	fn check_synthetic_rate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1214`
+		//  Estimated: `17329`
+		// Minimum execution time: 52_173_000 picoseconds.
+		Weight::from_parts(53_018_000, 17329)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -47,6 +47,7 @@ pub mod constants;
 mod impls;
 pub mod migrations;
 mod xor_fee_impls;
+pub use xor_fee_impls::DefaultCustomFees;
 
 #[cfg(test)]
 pub mod mock;
@@ -110,8 +111,8 @@ fn staking_storage_version_bridge_reaches_v16() {
 }
 #[cfg(test)]
 #[test]
-fn demeter_storage_version_bridge_reaches_v3() {
-    tests::demeter_storage_version_bridge_reaches_v3();
+fn demeter_storage_version_bridge_reaches_v4() {
+    tests::demeter_storage_version_bridge_reaches_v4();
 }
 #[cfg(test)]
 #[test]
@@ -1161,10 +1162,16 @@ impl assets::Config for Runtime {
     type WeightInfo = assets::weights::SubstrateWeight<Runtime>;
     type AssetRegulator = (
         permissions::Pallet<Runtime>,
-        extended_assets::Pallet<Runtime>,
+        (extended_assets::Pallet<Runtime>, PrestoAssetRegulator),
     );
 }
 
+#[cfg(any(feature = "stage", feature = "private-net"))] // presto
+pub type PrestoAssetRegulator = presto::Pallet<Runtime>;
+
+#[cfg(not(any(feature = "stage", feature = "private-net")))]
+pub type PrestoAssetRegulator = ();
+
 parameter_types! {
     pub const PolkamarktPalletId: PalletId = PalletId(*b"pk/mktpl");
     pub PolkamarktCanonicalStableAssetId: AssetId = AssetId::from_bytes(hex!(
@@ -1217,7 +1224,11 @@ impl trading_pair::Config for Runtime {
     type AssetInfoProvider = assets::Pallet<Runtime>;
 }
 
-impl dex_manager::Config for Runtime {}
+impl dex_manager::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type AssetInfoProvider = assets::Pallet<Runtime>;
+    type WeightInfo = dex_manager::weights::SubstrateWeight<Runtime>;
+}
 
 parameter_types! {
     pub const CheckInTransferAmount: Balance = balance!(0.1);
@@ -1231,6 +1242,20 @@ impl soratopia::Config for Runtime {
     type WeightInfo = soratopia::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+    pub const AirdropMaxProofLength: u32 = 32;
+    pub const AirdropClaimedRemovalLimit: u32 = 200;
+}
+
+impl airdrop::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    type TreasuryAccount = GetTreasuryAccountId;
+    type MaxProofLength = AirdropMaxProofLength;
+    type ClaimedRemovalLimit = AirdropClaimedRemovalLimit;
+    type WeightInfo = airdrop::weights::SubstrateWeight<Runtime>;
+}
+
 pub type TechAccountId = common::TechAccountId<AccountId, TechAssetId, DEXId>;
 pub type TechAssetId = common::TechAssetId<PredefinedAssetId>;
 pub type AssetId = AssetId32<PredefinedAssetId>;
@@ -1429,10 +1454,24 @@ impl dex_api::Config for Runtime {
     type XSTPool = xst::Pallet<Runtime>;
     type DexInfoProvider = dex_manager::Pallet<Runtime>;
     type OrderBook = order_book::Pallet<Runtime>;
+    type ConcentratedLiquidityPool = concentrated_liquidity_pool::Pallet<Runtime>;
 
     type WeightInfo = dex_api::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+    pub const MaxInitializedTicks: u32 = 1000;
+}
+
+impl concentrated_liquidity_pool::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type EnsureDEXManager = dex_manager::Pallet<Runtime>;
+    type TradingPairSourceManager = trading_pair::Pallet<Runtime>;
+    type DexInfoProvider = dex_manager::Pallet<Runtime>;
+    type MaxInitializedTicks = MaxInitializedTicks;
+    type WeightInfo = concentrated_liquidity_pool::weights::SubstrateWeight<Runtime>;
+}
+
 impl pallet_multisig::Config for Runtime {
     type RuntimeCall = RuntimeCall;
     type RuntimeEvent = RuntimeEvent;
@@ -1708,8 +1747,14 @@ impl pallet_sudo::Config for Runtime {
     type WeightInfo = ();
 }
 
+parameter_types! {
+    pub const MaxExpiringPermissionsPerBlock: u32 = 100;
+}
+
 impl permissions::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
+    type MaxExpiringPermissionsPerBlock = MaxExpiringPermissionsPerBlock;
+    type WeightInfo = permissions::weights::SubstrateWeight<Runtime>;
 }
 
 impl pallet_utility::Config for Runtime {
@@ -2108,6 +2153,8 @@ impl multicollateral_bonding_curve_pool::Config for Runtime {
 parameter_types! {
     pub const GetXstPoolConversionAssetId: AssetId = GetXstAssetId::get();
     pub const GetSyntheticBaseBuySellLimit: Balance = Balance::MAX;
+    // Synthetics are paused once their rate is older than the Band stale period, in seconds
+    pub const XstMaxOracleRateAge: Option<u64> = Some(GetBandRateStalePeriod::get() / 1000);
 }
 
 impl xst::Config for Runtime {
@@ -2121,6 +2168,7 @@ impl xst::Config for Runtime {
     type Symbol = <Runtime as band::Config>::Symbol;
     type TradingPairSourceManager = TradingPair;
     type GetSyntheticBaseBuySellLimit = GetSyntheticBaseBuySellLimit;
+    type MaxOracleRateAge = XstMaxOracleRateAge;
     type AssetInfoProvider = assets::Pallet<Runtime>;
 }
 
@@ -2327,6 +2375,7 @@ impl ceres_token_locker::Config for Runtime {
 }
 
 impl ceres_governance_platform::Config for Runtime {
+    const CONVICTION_LOCK_PERIOD: Moment = 86_400_000;
     type StringLimit = StringLimit;
     type OptionsLimit = OptionsLimit;
     type TitleLimit = TitleLimit;
@@ -2352,6 +2401,10 @@ impl oracle_proxy::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = oracle_proxy::weights::SubstrateWeight<Runtime>;
     type BandChainOracle = band::Pallet<Runtime>;
+    type AssetId = AssetId;
+    type PriceTools = PriceTools;
+    type GetReferenceAssetId = GetReferenceAssetId;
+    type Time = Timestamp;
 }
 
 parameter_types! {
@@ -2383,6 +2436,7 @@ parameter_types! {
 impl hermes_governance_platform::Config for Runtime {
     const MIN_DURATION_OF_POLL: Moment = 14_400_000;
     const MAX_DURATION_OF_POLL: Moment = 604_800_000;
+    const CONVICTION_LOCK_PERIOD: Moment = 86_400_000;
     type StringLimit = StringLimit;
     type OptionsLimit = OptionsLimit;
     type RuntimeEvent = RuntimeEvent;
@@ -2431,6 +2485,8 @@ parameter_types! {
         Perbill::from_percent(10) * TransactionPriority::max_value();
     // 10 blocks, if tx spoils, worker will resend it
     pub KensetsuOffchainWorkerTxLongevity: TransactionLongevity = 10;
+    // Pegged stablecoins are not priced with rates older than the Band stale period, in seconds
+    pub const KensetsuMaxOracleRateAge: Option<u64> = Some(GetBandRateStalePeriod::get() / 1000);
 }
 
 impl kensetsu::Config for Runtime {
@@ -2450,6 +2506,7 @@ impl kensetsu::Config for Runtime {
     type KarmaIncentiveRemintPercent = GetKarmaIncentiveRemintPercent;
     type MaxCdpsPerOwner = ConstU32<10000>;
     type MinimalStabilityFeeAccrue = MinimalStabilityFeeAccrue;
+    type MaxOracleRateAge = KensetsuMaxOracleRateAge;
     type UnsignedPriority = KensetsuOffchainWorkerTxPriority;
     type UnsignedLongevity = KensetsuOffchainWorkerTxLongevity;
     type WeightInfo = kensetsu::weights::SubstrateWeight<Runtime>;
@@ -3148,7 +3205,7 @@ construct_runtime! {
         Permissions: permissions::{Pallet, Call, Storage, Config<T>, Event<T>} = 6,
         Referrals: referrals::{Pallet, Call, Storage} = 7,
         Rewards: rewards::{Pallet, Call, Config<T>, Storage, Event<T>} = 8,
-        XorFee: xor_fee::{Pallet, Call, Storage, Event<T>} = 9,
+        XorFee: xor_fee::{Pallet, Call, Config<T>, Storage, Event<T>} = 9,
         BridgeMultisig: bridge_multisig::{Pallet, Call, Storage, Config<T>, Event<T>} = 10,
         Utility: pallet_utility::{Pallet, Call, Event} = 11,
 
@@ -3167,7 +3224,7 @@ construct_runtime! {
         Currencies: currencies::{Pallet} = 19,
         TradingPair: trading_pair::{Pallet, Call, Storage, Config<T>, Event<T>} = 20,
         Assets: assets::{Pallet, Call, Storage, Config<T>, Event<T>} = 21,
-        DEXManager: dex_manager::{Pallet, Storage, Config<T>} = 22,
+        DEXManager: dex_manager::{Pallet, Call, Storage, Config<T>, Event<T>} = 22,
         MulticollateralBondingCurvePool: multicollateral_bonding_curve_pool::{Pallet, Call, Storage, Config<T>, Event<T>} = 23,
         Technical: technical::{Pallet, Call, Config<T>, Event<T>, Storage} = 24,
         PoolXYK: pool_xyk::{Pallet, Call, Storage, Event<T>} = 25,
@@ -3187,7 +3244,7 @@ construct_runtime! {
         Identity: pallet_identity::{Pallet, Call, Storage, Event<T>} = 41,
         Farming: farming::{Pallet, Call, Storage, Event<T>} = 42,
         XSTPool: xst::{Pallet, Call, Storage, Config<T>, Event<T>} = 43,
-        PriceTools: price_tools::{Pallet, Storage, Event<T>} = 44,
+        PriceTools: price_tools::{Pallet, Call, Storage, Event<T>} = 44,
         CeresStaking: ceres_staking::{Pallet, Call, Storage, Event<T>} = 45,
         CeresLiquidityLocker: ceres_liquidity_locker::{Pallet, Call, Storage, Event<T>} = 46,
         CeresTokenLocker: ceres_token_locker::{Pallet, Call, Storage, Event<T>} = 47,
@@ -3207,6 +3264,7 @@ construct_runtime! {
         Presto: presto::{Pallet, Call, Storage, Event<T>} = 59,
         Denomination: denomination::{Pallet, Call, Storage, Event<T>} = 60,
         Polkamarkt: pallet_polkamarkt::{Pallet, Call, Storage, Event<T>, Config<T>} = 61,
+        ConcentratedLiquidityPool: concentrated_liquidity_pool::{Pallet, Call, Storage, Event<T>} = 62,
 
         // Leaf provider should be placed before any pallet which is uses it
         LeafProvider: leaf_provider::{Pallet, Storage, Event<T>} = 99,
@@ -3261,6 +3319,8 @@ construct_runtime! {
         ExtendedAssets: extended_assets::{Pallet, Call, Storage, Event<T>, Config<T>} = 115,
 
         Soratopia: soratopia::{Pallet, Call, Storage, Event<T>} = 116,
+
+        Airdrop: airdrop::{Pallet, Call, Storage, Event<T>} = 120,
     }
 }
 
@@ -3425,6 +3485,7 @@ pub mod genesis_config_presets {
             LiquiditySourceType::MulticollateralBondingCurvePool,
             LiquiditySourceType::XSTPool,
             LiquiditySourceType::OrderBook,
+            LiquiditySourceType::ConcentratedLiquidityPool,
         ];
 
         #[cfg(feature = "private-net")]
@@ -3625,6 +3686,29 @@ pub type Executive = frame_executive::Executive<
 #[cfg(feature = "wip")] // Trustless bridges
 pub type MmrHashing = <Runtime as pallet_mmr::Config>::Hashing;
 
+fn apollo_pool_info(
+    asset_id: AssetId,
+    pool_info: &apollo_platform::PoolInfo,
+) -> apollo_platform_runtime_api::PoolInfo<AssetId, Balance> {
+    apollo_platform_runtime_api::PoolInfo {
+        asset_id,
+        total_supplied: pool_info
+            .total_liquidity
+            .saturating_add(pool_info.total_borrowed),
+        remaining_liquidity: pool_info.total_liquidity,
+        total_borrowed: pool_info.total_borrowed,
+        total_collateral: pool_info.total_collateral,
+        utilization_rate: ApolloPlatform::utilization_rate(pool_info),
+        supply_apy: ApolloPlatform::lending_apy(asset_id, pool_info),
+        borrow_apy: ApolloPlatform::borrowing_apy(pool_info),
+        borrowing_rewards_apy: ApolloPlatform::borrowing_rewards_apy(asset_id, pool_info),
+        loan_to_value: pool_info.loan_to_value,
+        liquidation_threshold: pool_info.liquidation_threshold,
+        reserve_factor: pool_info.reserve_factor,
+        is_removed: pool_info.is_removed,
+    }
+}
+
 fn polkamarkt_outcome_from_string(outcome: String) -> Option<PolkamarktBinaryOutcome> {
     match outcome.as_bytes() {
         b"YES" | b"Yes" | b"yes" => Some(PolkamarktBinaryOutcome::Yes),
@@ -3649,6 +3733,127 @@ fn polkamarkt_status_label(status: &PolkamarktMarketStatus) -> String {
     }
 }
 
+#[cfg(any(feature = "stage", feature = "private-net"))] // presto
+fn presto_crop_receipt_info(
+    id: u64,
+    crop_receipt: presto::CropReceipt<Runtime>,
+) -> presto_runtime_api::CropReceiptInfo<AccountId, AssetId, Balance, u64, Moment> {
+    let repayment = Presto::crop_receipt_repayments(id);
+    presto_runtime_api::CropReceiptInfo {
+        id,
+        owner: crop_receipt.owner,
+        status: String::from(crop_receipt.status.label()),
+        amount: crop_receipt.amount,
+        profit: crop_receipt.profit.deconstruct(),
+        country: String::from(crop_receipt.country.label()),
+        rating: crop_receipt
+            .score
+            .as_ref()
+            .map(|score| String::from(score.rating.label())),
+        rated_by: crop_receipt.score.map(|score| score.by_auditor),
+        time: crop_receipt.time,
+        close_initial_period: crop_receipt.close_initial_period,
+        date_of_issue: crop_receipt.date_of_issue,
+        perfomance_time: crop_receipt.perfomance_time,
+        coupon_asset_id: Presto::crop_receipt_to_coupon(id),
+        repaid: repayment.repaid,
+        recovered: repayment.recovered,
+        refunded: repayment.refunded,
+        outstanding_debt: Presto::crop_receipt_outstanding_debt(id),
+    }
+}
+
+#[cfg(any(feature = "stage", feature = "private-net"))] // presto
+fn presto_request_info(
+    id: u64,
+    request: presto::Request<Runtime>,
+) -> presto_runtime_api::RequestInfo<AccountId, Balance, u64, Moment> {
+    let kind = match request {
+        presto::Request::Deposit(_) => "Deposit",
+        presto::Request::Withdraw(_) => "Withdraw",
+    };
+    let (status, processed_by, processed_at) = match request.status() {
+        presto::RequestStatus::Pending => ("Pending", None, None),
+        presto::RequestStatus::Cancelled => ("Cancelled", None, None),
+        presto::RequestStatus::Approved { by, time } => {
+            ("Approved", Some(by.clone()), Some(*time))
+        }
+        presto::RequestStatus::Declined { by, time } => {
+            ("Declined", Some(by.clone()), Some(*time))
+        }
+    };
+    presto_runtime_api::RequestInfo {
+        id,
+        kind: String::from(kind),
+        owner: request.owner().clone(),
+        amount: request.amount(),
+        time: request.time(),
+        status: String::from(status),
+        processed_by,
+        processed_at,
+    }
+}
+
+fn price_tools_price_info(
+    asset_id: AssetId,
+    price_variant: common::PriceVariant,
+) -> Option<price_tools_runtime_api::PriceInfo<Balance, Symbol>> {
+    let block_span = PriceTools::price_parameters(&asset_id).block_span as usize;
+    let price_info = PriceTools::price_infos(asset_id)?.price_of(price_variant);
+    let fast_price_info =
+        PriceTools::fast_price_infos(asset_id).map(|info| info.price_of(price_variant));
+    // Synthetic assets are the only ones mapped to oracle symbols, their rates are given in
+    // the XST reference asset.
+    let oracle = xst::EnabledSynthetics::<Runtime>::get(asset_id).and_then(|info| {
+        let symbol = info.reference_symbol;
+        let rate = if symbol == Symbol::usd() {
+            balance!(1)
+        } else {
+            <OracleProxy as common::DataFeed<Symbol, common::Rate, ResolveTime>>::quote_unchecked(
+                &symbol,
+            )?
+            .value
+        };
+        let comparison = PriceTools::compare_with_rate(
+            &asset_id,
+            &xst::ReferenceAssetId::<Runtime>::get(),
+            rate,
+            price_variant,
+        );
+        Some(price_tools_runtime_api::OracleComparison {
+            symbol,
+            rate,
+            price: comparison.price,
+            average_price_deviation: comparison.average_price_deviation.map(Fixed::into_bits),
+            spot_price_deviation: comparison.spot_price_deviation.map(Fixed::into_bits),
+            fast_price_deviation: comparison.fast_price_deviation.map(Fixed::into_bits),
+        })
+    });
+    Some(price_tools_runtime_api::PriceInfo {
+        average_price: price_info.average_price(block_span),
+        last_spot_price: price_info.last_spot_price(),
+        price_failures: price_info.price_failures(),
+        fast_average_price: fast_price_info.and_then(|info| info.average_price(block_span)),
+        oracle,
+    })
+}
+
+fn mcbc_collateral_reserve_info(
+    collateral_asset_id: AssetId,
+) -> Option<multicollateral_bonding_curve_pool_runtime_api::CollateralReserveInfo<AssetId, Balance>>
+{
+    let (reserve, reserve_value) =
+        MulticollateralBondingCurvePool::collateral_reserves(&collateral_asset_id).ok()?;
+    let prices = MulticollateralBondingCurvePool::current_prices(&collateral_asset_id).ok();
+    Some(multicollateral_bonding_curve_pool_runtime_api::CollateralReserveInfo {
+        asset_id: collateral_asset_id,
+        reserve,
+        reserve_value,
+        buy_price: prices.map(|(buy_price, _)| buy_price),
+        sell_price: prices.map(|(_, sell_price)| sell_price),
+    })
+}
+
 impl_runtime_apis! {
     impl sp_api::Core<Block> for Runtime {
         fn version() -> RuntimeVersion {
@@ -3764,6 +3969,21 @@ impl_runtime_apis! {
         }
     }
 
+    impl permissions_runtime_api::PermissionsAPI<Block, AccountId, permissions::Scope, BlockNumber>
+        for Runtime
+    {
+        fn list_permission_holders(
+            permission_id: u32,
+            scope: permissions::Scope,
+        ) -> Vec<(AccountId, Option<BlockNumber>)> {
+            Permissions::list_permission_holders(permission_id, scope)
+        }
+
+        fn list_permission_owners(permission_id: u32, scope: permissions::Scope) -> Vec<AccountId> {
+            Permissions::list_permission_owners(permission_id, scope)
+        }
+    }
+
     impl dex_runtime_api::DEXAPI<
         Block,
         AssetId,
@@ -3907,6 +4127,154 @@ impl_runtime_apis! {
         }
     }
 
+    impl apollo_platform_runtime_api::ApolloAPI<Block, AccountId, AssetId, Balance> for Runtime {
+        fn pools() -> Vec<apollo_platform_runtime_api::PoolInfo<AssetId, Balance>> {
+            apollo_platform::PoolData::<Runtime>::iter()
+                .map(|(asset_id, pool_info)| apollo_pool_info(asset_id, &pool_info))
+                .collect()
+        }
+
+        fn pool(
+            asset_id: AssetId,
+        ) -> Option<apollo_platform_runtime_api::PoolInfo<AssetId, Balance>> {
+            ApolloPlatform::pool_info(asset_id)
+                .map(|pool_info| apollo_pool_info(asset_id, &pool_info))
+        }
+
+        fn user_lending_positions(
+            account_id: AccountId,
+        ) -> Vec<apollo_platform_runtime_api::LendingPositionInfo<AssetId, Balance>> {
+            apollo_platform::PoolData::<Runtime>::iter_keys()
+                .filter_map(|asset_id| {
+                    let position = ApolloPlatform::accrued_lending_position(asset_id, &account_id)?;
+                    Some(apollo_platform_runtime_api::LendingPositionInfo {
+                        asset_id,
+                        lending_amount: position.lending_amount,
+                        lending_interest: position.lending_interest,
+                    })
+                })
+                .collect()
+        }
+
+        fn user_borrowing_positions(
+            account_id: AccountId,
+        ) -> Vec<apollo_platform_runtime_api::BorrowingPositionInfo<AssetId, Balance>> {
+            let mut result = Vec::new();
+            for borrowing_asset_id in apollo_platform::PoolData::<Runtime>::iter_keys() {
+                let Some(positions) =
+                    ApolloPlatform::accrued_borrowing_positions(borrowing_asset_id, &account_id)
+                else {
+                    continue;
+                };
+                let health_factor = ApolloPlatform::health_factor(&positions, borrowing_asset_id);
+                for (collateral_asset_id, position) in positions {
+                    result.push(apollo_platform_runtime_api::BorrowingPositionInfo {
+                        borrowing_asset_id,
+                        collateral_asset_id,
+                        collateral_amount: position.collateral_amount,
+                        borrowing_amount: position.borrowing_amount,
+                        borrowing_interest: position.borrowing_interest,
+                        borrowing_rewards: position.borrowing_rewards,
+                        health_factor,
+                        liquidation_threshold: ApolloPlatform::pool_info(collateral_asset_id)
+                            .map(|pool_info| pool_info.liquidation_threshold)
+                            .unwrap_or_default(),
+                    });
+                }
+            }
+            result
+        }
+    }
+
+    impl ceres_governance_platform_runtime_api::CeresGovernanceAPI<Block, Balance> for Runtime {
+        fn poll_tallies(
+            poll_id: H256,
+        ) -> Vec<ceres_governance_platform_runtime_api::OptionTally<Balance>> {
+            CeresGovernancePlatform::poll_tallies(poll_id)
+                .into_iter()
+                .map(|(option, votes)| ceres_governance_platform_runtime_api::OptionTally {
+                    option: option.as_bytes().to_vec(),
+                    votes,
+                })
+                .collect()
+        }
+    }
+
+    impl hermes_governance_platform_runtime_api::HermesGovernanceAPI<Block, Balance> for Runtime {
+        fn poll_tallies(
+            poll_id: H256,
+        ) -> Vec<hermes_governance_platform_runtime_api::OptionTally<Balance>> {
+            HermesGovernancePlatform::poll_tallies(poll_id)
+                .into_iter()
+                .map(|(option, votes)| hermes_governance_platform_runtime_api::OptionTally {
+                    option: option.as_bytes().to_vec(),
+                    votes,
+                })
+                .collect()
+        }
+    }
+
+    #[cfg(any(feature = "stage", feature = "private-net"))] // presto
+    impl presto_runtime_api::PrestoAPI<Block, AccountId, AssetId, Balance, u64, u64, Moment> for Runtime {
+        fn list_crop_receipts(
+            status: Option<String>,
+            country: Option<String>,
+            rating: Option<String>,
+        ) -> Vec<presto_runtime_api::CropReceiptInfo<AccountId, AssetId, Balance, u64, Moment>> {
+            // unknown labels match nothing
+            let (Ok(status), Ok(country), Ok(rating)) = (
+                status.map(|label| presto::Status::from_label(&label).ok_or(())).transpose(),
+                country.map(|label| presto::Country::from_label(&label).ok_or(())).transpose(),
+                rating.map(|label| presto::Rating::from_label(&label).ok_or(())).transpose(),
+            ) else {
+                return Vec::new();
+            };
+
+            Presto::filter_crop_receipts(status, country, rating)
+                .into_iter()
+                .map(|(id, crop_receipt)| presto_crop_receipt_info(id, crop_receipt))
+                .collect()
+        }
+
+        fn user_requests(
+            account_id: AccountId,
+        ) -> Vec<presto_runtime_api::RequestInfo<AccountId, Balance, u64, Moment>> {
+            Presto::user_requests_info(&account_id)
+                .into_iter()
+                .map(|(id, request)| presto_request_info(id, request))
+                .collect()
+        }
+
+        fn coupon_holdings(
+            account_id: AccountId,
+        ) -> Vec<presto_runtime_api::CouponHoldingInfo<AssetId, Balance, u64>> {
+            Presto::coupon_holdings(&account_id)
+                .into_iter()
+                .map(|holding| presto_runtime_api::CouponHoldingInfo {
+                    coupon_asset_id: holding.coupon_asset_id,
+                    crop_receipt_id: holding.info.crop_receipt_id,
+                    crop_receipt_status: Presto::crop_receipts(holding.info.crop_receipt_id)
+                        .map(|crop_receipt| String::from(crop_receipt.status.label()))
+                        .unwrap_or_default(),
+                    amount: holding.amount,
+                    refund_price: *holding.info.refund_price.balance(),
+                    expected_refund: holding.expected_refund,
+                    claimable_refund: holding.claimable_refund,
+                })
+                .collect()
+        }
+
+        fn treasury() -> Option<presto_runtime_api::TreasuryInfo<Balance>> {
+            let position = Presto::treasury_position().ok()?;
+            Some(presto_runtime_api::TreasuryInfo {
+                treasury: position.treasury,
+                buffer: position.buffer,
+                total_supply: position.total_supply,
+                outstanding_debt: position.outstanding_debt,
+            })
+        }
+    }
+
     impl trading_pair_runtime_api::TradingPairAPI<Block, DEXId, common::TradingPair<AssetId>, AssetId, LiquiditySourceType> for Runtime {
         fn list_enabled_pairs(dex_id: DEXId) -> Vec<common::TradingPair<AssetId>> {
             // TODO: error passing PR fixes this crunch return
@@ -4173,6 +4541,62 @@ impl_runtime_apis! {
         }
     }
 
+    impl price_tools_runtime_api::PriceToolsAPI<Block, AssetId, Balance, Symbol> for Runtime {
+        fn get_price_info(
+            asset_id: AssetId,
+            price_variant: common::PriceVariant,
+        ) -> Option<price_tools_runtime_api::PriceInfo<Balance, Symbol>> {
+            price_tools_price_info(asset_id, price_variant)
+        }
+
+        fn list_price_infos(
+            price_variant: common::PriceVariant,
+        ) -> Vec<(AssetId, price_tools_runtime_api::PriceInfo<Balance, Symbol>)> {
+            price_tools::PriceInfos::<Runtime>::iter_keys()
+                .filter_map(|asset_id| {
+                    price_tools_price_info(asset_id, price_variant).map(|info| (asset_id, info))
+                })
+                .collect()
+        }
+    }
+
+    impl multicollateral_bonding_curve_pool_runtime_api::MulticollateralBondingCurvePoolAPI<
+        Block,
+        AssetId,
+        Balance,
+    > for Runtime {
+        fn get_collateral_reserve_info(
+            collateral_asset_id: AssetId,
+        ) -> Option<multicollateral_bonding_curve_pool_runtime_api::CollateralReserveInfo<AssetId, Balance>> {
+            if !multicollateral_bonding_curve_pool::EnabledTargets::<Runtime>::get()
+                .contains(&collateral_asset_id)
+            {
+                return None;
+            }
+            mcbc_collateral_reserve_info(collateral_asset_id)
+        }
+
+        fn list_collateral_reserve_infos(
+        ) -> Vec<multicollateral_bonding_curve_pool_runtime_api::CollateralReserveInfo<AssetId, Balance>> {
+            multicollateral_bonding_curve_pool::EnabledTargets::<Runtime>::get()
+                .into_iter()
+                .filter_map(mcbc_collateral_reserve_info)
+                .collect()
+        }
+
+        fn get_collateralization_info(
+        ) -> Option<multicollateral_bonding_curve_pool_runtime_api::CollateralizationInfo<Balance>> {
+            let (reserves_value, total_supply, supply_value, ratio) =
+                MulticollateralBondingCurvePool::collateralization().ok()?;
+            Some(multicollateral_bonding_curve_pool_runtime_api::CollateralizationInfo {
+                reserves_value,
+                total_supply,
+                supply_value,
+                collateral_ratio: ratio.map(Fixed::into_bits),
+            })
+        }
+    }
+
     impl pswap_distribution_runtime_api::PswapDistributionAPI<
         Block,
         AccountId,
@@ -4194,6 +4618,16 @@ impl_runtime_apis! {
         }
     }
 
+    impl airdrop_runtime_api::AirdropAPI<Block, AccountId, sp_core::H160, Balance> for Runtime {
+        fn is_account_eligible(campaign_id: u32, account_id: AccountId, amount: Balance, proof: Vec<H256>) -> bool {
+            Airdrop::is_eligible(campaign_id, &airdrop::Beneficiary::Account(account_id), amount, &proof)
+        }
+
+        fn is_eth_address_eligible(campaign_id: u32, eth_address: sp_core::H160, amount: Balance, proof: Vec<H256>) -> bool {
+            Airdrop::is_eligible(campaign_id, &airdrop::Beneficiary::Ethereum(eth_address), amount, &proof)
+        }
+    }
+
     impl sp_consensus_babe::BabeApi<Block> for Runtime {
             fn configuration() -> sp_consensus_babe::BabeConfiguration {
                     // The choice of `c` parameter (where `1 - c` represents the
@@ -4495,6 +4929,8 @@ impl_runtime_apis! {
             list_benchmark!(list, extra, farming, Farming);
             list_benchmark!(list, extra, iroha_migration, IrohaMigration);
             list_benchmark!(list, extra, dex_api, DEXAPI);
+            list_benchmark!(list, extra, dex_manager, DEXManager);
+            list_benchmark!(list, extra, permissions, Permissions);
             list_benchmark!(list, extra, kensetsu, KensetsuBench::<Runtime>);
             list_benchmark!(list, extra, liquidity_proxy, LiquidityProxyBench::<Runtime>);
             list_benchmark!(list, extra, multicollateral_bonding_curve_pool, MulticollateralBondingCurvePool);
@@ -4543,6 +4979,8 @@ impl_runtime_apis! {
             list_benchmark!(list, extra, extended_assets, ExtendedAssets);
 
             list_benchmark!(list, extra, soratopia, Soratopia);
+            list_benchmark!(list, extra, airdrop, Airdrop);
+            list_benchmark!(list, extra, concentrated_liquidity_pool, ConcentratedLiquidityPool);
 
             let storage_info = AllPalletsWithSystem::storage_info();
 
@@ -4587,6 +5025,8 @@ impl_runtime_apis! {
             add_benchmark!(params, batches, farming, Farming);
             add_benchmark!(params, batches, iroha_migration, IrohaMigration);
             add_benchmark!(params, batches, dex_api, DEXAPI);
+            add_benchmark!(params, batches, dex_manager, DEXManager);
+            add_benchmark!(params, batches, permissions, Permissions);
             add_benchmark!(params, batches, kensetsu, KensetsuBench::<Runtime>);
             add_benchmark!(params, batches, liquidity_proxy, LiquidityProxyBench::<Runtime>);
             add_benchmark!(params, batches, multicollateral_bonding_curve_pool, MulticollateralBondingCurvePool);
@@ -4635,6 +5075,8 @@ impl_runtime_apis! {
             add_benchmark!(params, batches, extended_assets, ExtendedAssets);
 
             add_benchmark!(params, batches, soratopia, Soratopia);
+            add_benchmark!(params, batches, airdrop, Airdrop);
+            add_benchmark!(params, batches, concentrated_liquidity_pool, ConcentratedLiquidityPool);
 
             if batches.is_empty() { return Err("Benchmark not found for this pallet.".into()) }
             Ok(batches)
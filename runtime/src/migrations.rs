@@ -62,18 +62,24 @@ pub type Migrations = (
     BridgePeerIsolationAudit,
     DecommissionLegacyEthereumXor,
     QueueEthereumXorThischainAddAsset,
-    DemeterFarmingPlatformStorageVersionV3,
+    DemeterFarmingPlatformStorageVersionV4,
     RepairXorTbcdRewardDenomination,
     pallet_polkamarkt::migrations::v2::Migrate<crate::Runtime>,
     pallet_polkamarkt::migrations::v3::Migrate<crate::Runtime>,
     pallet_polkamarkt::migrations::v4::Migrate<crate::Runtime>,
     PrivateNetMigrations,
     WipMigrations,
+    PrestoMigrations,
     xor_fee::migrations::v3::Migrate<crate::Runtime>,
+    xor_fee::migrations::v4::Migrate<crate::Runtime, crate::DefaultCustomFees>,
+    oracle_proxy::migrations::v2::Migrate<crate::Runtime>,
+    apollo_platform::migrations::MigrateToV2<crate::Runtime>,
 );
 
-pub type MultiBlockMigrations =
-    (pallet_identity::migration::v2::LazyMigrationV1ToV2<crate::Runtime>,);
+pub type MultiBlockMigrations = (
+    pallet_identity::migration::v2::LazyMigrationV1ToV2<crate::Runtime>,
+    permissions::migrations::v2::LazyMigrate<crate::Runtime>,
+);
 
 #[cfg(feature = "try-runtime")]
 fn decode_storage_version(
@@ -625,9 +631,9 @@ impl OnRuntimeUpgrade for RepairXorTbcdRewardDenomination {
     }
 }
 
-pub struct DemeterFarmingPlatformStorageVersionV3;
+pub struct DemeterFarmingPlatformStorageVersionV4;
 
-impl OnRuntimeUpgrade for DemeterFarmingPlatformStorageVersionV3 {
+impl OnRuntimeUpgrade for DemeterFarmingPlatformStorageVersionV4 {
     fn on_runtime_upgrade() -> Weight {
         <demeter_farming_platform::Pallet<crate::Runtime> as Hooks<crate::BlockNumber>>::on_runtime_upgrade()
     }
@@ -646,8 +652,9 @@ impl OnRuntimeUpgrade for DemeterFarmingPlatformStorageVersionV3 {
         let expected = match previous {
             demeter_farming_platform::StorageVersion::V1
             | demeter_farming_platform::StorageVersion::V2
-            | demeter_farming_platform::StorageVersion::V3 => {
-                demeter_farming_platform::StorageVersion::V3
+            | demeter_farming_platform::StorageVersion::V3
+            | demeter_farming_platform::StorageVersion::V4 => {
+                demeter_farming_platform::StorageVersion::V4
             }
         };
         let current = demeter_farming_platform::PalletStorageVersion::<crate::Runtime>::get();
@@ -729,6 +736,15 @@ pub type WipMigrations = (
 #[cfg(not(feature = "wip"))]
 pub type WipMigrations = ();
 
+#[cfg(any(feature = "stage", feature = "private-net"))] // presto
+pub type PrestoMigrations = (
+    presto::migrations::v1::Migrate<crate::Runtime>,
+    presto::migrations::v2::Migrate<crate::Runtime>,
+);
+
+#[cfg(not(any(feature = "stage", feature = "private-net")))]
+pub type PrestoMigrations = ();
+
 #[cfg(feature = "private-net")]
 pub type PrivateNetMigrations = (ClearSudoKey,);
 
@@ -33,6 +33,7 @@ mod referrals;
 #[cfg(feature = "try-runtime")]
 mod remote;
 mod xor_fee;
+mod xst;
 
 use crate::{genesis_config_presets, Currencies, Referrals, RuntimeOrigin};
 use assets::GetTotalBalance;
@@ -491,31 +492,31 @@ pub(crate) fn staking_storage_version_bridge_reaches_v16() {
     });
 }
 
-pub(crate) fn demeter_storage_version_bridge_reaches_v3() {
+pub(crate) fn demeter_storage_version_bridge_reaches_v4() {
     sp_io::TestExternalities::new_empty().execute_with(|| {
         demeter_farming_platform::PalletStorageVersion::<crate::Runtime>::put(
-            demeter_farming_platform::StorageVersion::V2,
+            demeter_farming_platform::StorageVersion::V3,
         );
 
-        crate::migrations::DemeterFarmingPlatformStorageVersionV3::on_runtime_upgrade();
+        crate::migrations::DemeterFarmingPlatformStorageVersionV4::on_runtime_upgrade();
 
         assert!(
             demeter_farming_platform::PalletStorageVersion::<crate::Runtime>::get()
-                == demeter_farming_platform::StorageVersion::V3
+                == demeter_farming_platform::StorageVersion::V4
         );
     });
 
     sp_io::TestExternalities::new_empty().execute_with(|| {
         demeter_farming_platform::PalletStorageVersion::<crate::Runtime>::put(
-            demeter_farming_platform::StorageVersion::V3,
+            demeter_farming_platform::StorageVersion::V4,
         );
 
         let weight =
-            crate::migrations::DemeterFarmingPlatformStorageVersionV3::on_runtime_upgrade();
+            crate::migrations::DemeterFarmingPlatformStorageVersionV4::on_runtime_upgrade();
 
         assert!(
             demeter_farming_platform::PalletStorageVersion::<crate::Runtime>::get()
-                == demeter_farming_platform::StorageVersion::V3
+                == demeter_farming_platform::StorageVersion::V4
         );
         assert_eq!(weight, frame_support::weights::Weight::zero());
     });
@@ -675,22 +676,22 @@ pub(crate) fn band_migrate_to_v2_if_needed_try_runtime_hooks() {
 pub(crate) fn demeter_storage_version_bridge_try_runtime_hooks() {
     sp_io::TestExternalities::new_empty().execute_with(|| {
         demeter_farming_platform::PalletStorageVersion::<crate::Runtime>::put(
-            demeter_farming_platform::StorageVersion::V2,
+            demeter_farming_platform::StorageVersion::V3,
         );
         let state =
-            crate::migrations::DemeterFarmingPlatformStorageVersionV3::pre_upgrade().unwrap();
-        crate::migrations::DemeterFarmingPlatformStorageVersionV3::on_runtime_upgrade();
-        crate::migrations::DemeterFarmingPlatformStorageVersionV3::post_upgrade(state).unwrap();
+            crate::migrations::DemeterFarmingPlatformStorageVersionV4::pre_upgrade().unwrap();
+        crate::migrations::DemeterFarmingPlatformStorageVersionV4::on_runtime_upgrade();
+        crate::migrations::DemeterFarmingPlatformStorageVersionV4::post_upgrade(state).unwrap();
     });
 
     sp_io::TestExternalities::new_empty().execute_with(|| {
         demeter_farming_platform::PalletStorageVersion::<crate::Runtime>::put(
-            demeter_farming_platform::StorageVersion::V3,
+            demeter_farming_platform::StorageVersion::V4,
         );
         let state =
-            crate::migrations::DemeterFarmingPlatformStorageVersionV3::pre_upgrade().unwrap();
-        crate::migrations::DemeterFarmingPlatformStorageVersionV3::on_runtime_upgrade();
-        crate::migrations::DemeterFarmingPlatformStorageVersionV3::post_upgrade(state).unwrap();
+            crate::migrations::DemeterFarmingPlatformStorageVersionV4::pre_upgrade().unwrap();
+        crate::migrations::DemeterFarmingPlatformStorageVersionV4::on_runtime_upgrade();
+        crate::migrations::DemeterFarmingPlatformStorageVersionV4::post_upgrade(state).unwrap();
     });
 }
 
@@ -0,0 +1,85 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use core::str::FromStr;
+
+use common::{fixed, AssetName, AssetSymbol, Oracle, SymbolName};
+use frame_support::assert_ok;
+use frame_support::traits::Hooks;
+use framenode_chain_spec::ext;
+use xst::PauseReason;
+
+use crate::{AccountId, Band, OracleProxy, RuntimeOrigin, Timestamp, XSTPool, XstMaxOracleRateAge};
+
+const RESOLVE_TIME: u64 = 1_000;
+
+#[test]
+fn stale_oracle_rate_pauses_synthetic() {
+    ext().execute_with(|| {
+        let euro = SymbolName::from_str("EURO").unwrap();
+        let relayer = AccountId::from([42; 32]);
+        Timestamp::set_timestamp(RESOLVE_TIME * 1000);
+        if !OracleProxy::enabled_oracles().contains(&Oracle::BandChainFeed) {
+            assert_ok!(OracleProxy::enable_oracle(
+                RuntimeOrigin::root(),
+                Oracle::BandChainFeed
+            ));
+        }
+        assert_ok!(Band::add_relayers(
+            RuntimeOrigin::root(),
+            vec![relayer.clone()]
+        ));
+        assert_ok!(Band::relay(
+            RuntimeOrigin::signed(relayer),
+            vec![(euro.clone(), 2_000_000_000)].try_into().unwrap(),
+            RESOLVE_TIME,
+            0,
+        ));
+        assert_ok!(XSTPool::register_synthetic_asset(
+            RuntimeOrigin::root(),
+            AssetSymbol(b"XSTEUR".to_vec()),
+            AssetName(b"XST Euro".to_vec()),
+            euro.clone(),
+            fixed!(0),
+        ));
+        let xsteuro = XSTPool::enabled_symbols(&euro).expect("Expected synthetic asset");
+
+        XSTPool::on_initialize(1);
+        assert!(XSTPool::paused_synthetics(xsteuro).is_none());
+
+        let max_age = XstMaxOracleRateAge::get().expect("Max oracle rate age must be set");
+        Timestamp::set_timestamp((RESOLVE_TIME + max_age + 1) * 1000);
+        XSTPool::on_initialize(2);
+        assert_eq!(
+            XSTPool::paused_synthetics(xsteuro),
+            Some(PauseReason::OracleRateStale(RESOLVE_TIME))
+        );
+    });
+}
@@ -39,7 +39,7 @@ use common::PriceToolsProvider;
 #[cfg(feature = "wip")] // Xorless fee
 use common::PriceVariant;
 use frame_support::dispatch::DispatchResult;
-use frame_support::traits::Currency;
+use frame_support::traits::{Currency, GetCallIndex, GetCallName, PalletInfoAccess};
 use pallet_utility::Call as UtilityCall;
 use sp_runtime::traits::Zero;
 #[cfg(feature = "wip")] // Dynamic fee
@@ -47,6 +47,7 @@ use sp_runtime::FixedU128;
 use sp_runtime::Perbill;
 use sp_staking::{EraIndex, Page, StakingAccount};
 use vested_rewards::vesting_currencies::VestingSchedule;
+use xor_fee::{CustomFee, CustomFeeKey};
 
 #[derive(Debug, PartialEq)]
 pub struct CallDepth {
@@ -365,65 +366,117 @@ fn pay_val_to_reward_destination(
     Ok(())
 }
 
-impl CustomFees {
-    fn match_call(call: &RuntimeCall) -> Option<Balance> {
-        match call {
-            RuntimeCall::LiquidityProxy(liquidity_proxy::Call::swap_transfer_batch {
-                swap_batches,
-                ..
-            }) => Some(
-                swap_batches
-                    .iter()
-                    .map(|x| x.receivers.len() as Balance)
-                    .fold(Balance::zero(), |acc, x| acc.saturating_add(x))
-                    .saturating_mul(SMALL_FEE)
-                    .max(SMALL_FEE),
+/// Custom fees which were hard-coded in the runtime before the fee table was introduced.
+/// Used to fill `xor_fee::CustomFeeTable` at genesis and on the runtime upgrade.
+pub struct DefaultCustomFees;
+
+impl DefaultCustomFees {
+    fn pallet_fee<P: PalletInfoAccess>(fee: CustomFee) -> Option<(CustomFeeKey, CustomFee)> {
+        let key = CustomFeeKey {
+            pallet_index: P::index().try_into().ok()?,
+            call_index: None,
+        };
+        Some((key, fee))
+    }
+
+    fn call_fee<P: PalletInfoAccess, C: GetCallName + GetCallIndex>(
+        call_name: &str,
+        fee: CustomFee,
+    ) -> Option<(CustomFeeKey, CustomFee)> {
+        let position = C::get_call_names()
+            .iter()
+            .position(|name| *name == call_name)?;
+        let key = CustomFeeKey {
+            pallet_index: P::index().try_into().ok()?,
+            call_index: Some(*C::get_call_indices().get(position)?),
+        };
+        Some((key, fee))
+    }
+}
+
+impl Get<Vec<(CustomFeeKey, CustomFee)>> for DefaultCustomFees {
+    fn get() -> Vec<(CustomFeeKey, CustomFee)> {
+        type Collective<I> = pallet_collective::Call<Runtime, I>;
+        use pallet_collective::{Instance1, Instance2};
+        use CustomFee::*;
+
+        [
+            Self::call_fee::<LiquidityProxy, liquidity_proxy::Call<Runtime>>(
+                "swap_transfer_batch",
+                PerReceiver(SMALL_FEE),
+            ),
+            Self::call_fee::<Assets, assets::Call<Runtime>>("register", Flat(BIG_FEE)),
+            Self::call_fee::<EthBridge, eth_bridge::Call<Runtime>>(
+                "transfer_to_sidechain",
+                Flat(BIG_FEE),
+            ),
+            Self::call_fee::<BridgeProxy, bridge_proxy::Call<Runtime>>("burn", Flat(BIG_FEE)),
+            Self::call_fee::<PoolXYK, pool_xyk::Call<Runtime>>("withdraw_liquidity", Flat(BIG_FEE)),
+            Self::call_fee::<Rewards, rewards::Call<Runtime>>("claim", Flat(BIG_FEE)),
+            Self::call_fee::<VestedRewards, vested_rewards::Call<Runtime>>(
+                "claim_crowdloan_rewards",
+                Flat(BIG_FEE),
+            ),
+            Self::call_fee::<VestedRewards, vested_rewards::Call<Runtime>>(
+                "claim_rewards",
+                Flat(BIG_FEE),
+            ),
+            Self::call_fee::<OrderBook, order_book::Call<Runtime>>(
+                "update_orderbook",
+                Flat(BIG_FEE),
+            ),
+            Self::call_fee::<OrderBook, order_book::Call<Runtime>>(
+                "place_limit_order",
+                PerLifespan(SMALL_FEE),
+            ),
+            Self::pallet_fee::<Assets>(Flat(SMALL_FEE)),
+            Self::pallet_fee::<EthBridge>(Flat(SMALL_FEE)),
+            Self::pallet_fee::<LiquidityProxy>(Flat(SMALL_FEE)),
+            Self::pallet_fee::<MulticollateralBondingCurvePool>(Flat(SMALL_FEE)),
+            Self::call_fee::<Polkamarkt, pallet_polkamarkt::Call<Runtime>>(
+                "create_condition",
+                Flat(SMALL_FEE),
+            ),
+            Self::call_fee::<Polkamarkt, pallet_polkamarkt::Call<Runtime>>(
+                "create_market",
+                Flat(SMALL_FEE),
+            ),
+            Self::pallet_fee::<PoolXYK>(Flat(SMALL_FEE)),
+            Self::pallet_fee::<Rewards>(Flat(SMALL_FEE)),
+            Self::pallet_fee::<TradingPair>(Flat(SMALL_FEE)),
+            Self::pallet_fee::<Referrals>(Flat(SMALL_FEE)),
+            Self::pallet_fee::<OrderBook>(Flat(SMALL_FEE)),
+            Self::call_fee::<TechnicalCommittee, Collective<Instance2>>("close", Flat(SMALL_FEE)),
+            Self::call_fee::<TechnicalCommittee, Collective<Instance2>>("propose", Flat(SMALL_FEE)),
+            Self::call_fee::<Council, Collective<Instance1>>("close", Flat(SMALL_FEE)),
+            Self::call_fee::<Council, Collective<Instance1>>("propose", Flat(SMALL_FEE)),
+            Self::call_fee::<VestedRewards, vested_rewards::Call<Runtime>>(
+                "vested_transfer",
+                PerClaim {
+                    base: SMALL_FEE,
+                    per_claim: SMALL_FEE,
+                },
+            ),
+            Self::call_fee::<VestedRewards, vested_rewards::Call<Runtime>>(
+                "claim_unlocked",
+                Flat(SMALL_FEE),
             ),
-            RuntimeCall::Assets(assets::Call::register { .. })
-            | RuntimeCall::EthBridge(eth_bridge::Call::transfer_to_sidechain { .. })
-            | RuntimeCall::BridgeProxy(bridge_proxy::Call::burn { .. })
-            | RuntimeCall::PoolXYK(pool_xyk::Call::withdraw_liquidity { .. })
-            | RuntimeCall::Rewards(rewards::Call::claim { .. })
-            | RuntimeCall::VestedRewards(vested_rewards::Call::claim_crowdloan_rewards {
-                ..
-            })
-            | RuntimeCall::VestedRewards(vested_rewards::Call::claim_rewards { .. })
-            | RuntimeCall::OrderBook(order_book::Call::update_orderbook { .. }) => Some(BIG_FEE),
-            RuntimeCall::Assets(..)
-            | RuntimeCall::EthBridge(..)
-            | RuntimeCall::LiquidityProxy(..)
-            | RuntimeCall::MulticollateralBondingCurvePool(..)
-            | RuntimeCall::Polkamarkt(pallet_polkamarkt::Call::create_condition { .. })
-            | RuntimeCall::Polkamarkt(pallet_polkamarkt::Call::create_market { .. })
-            | RuntimeCall::PoolXYK(..)
-            | RuntimeCall::Rewards(..)
-            | RuntimeCall::TradingPair(..)
-            | RuntimeCall::Referrals(..)
-            | RuntimeCall::OrderBook(..)
-            | RuntimeCall::TechnicalCommittee(
-                pallet_collective::Call::close { .. } | pallet_collective::Call::propose { .. },
-            )
-            | RuntimeCall::Council(
-                pallet_collective::Call::close { .. } | pallet_collective::Call::propose { .. },
-            )
-            | RuntimeCall::VestedRewards(vested_rewards::Call::vested_transfer { .. })
-            | RuntimeCall::VestedRewards(vested_rewards::Call::claim_unlocked { .. }) => {
-                Some(SMALL_FEE)
-            }
             // NOTE: reducing fees to 1/10 for payout_stakers (from SMALL_FEE)
             // https://github.com/sora-xor/sora2-network/issues/1335#issuecomment-3004262480
-            RuntimeCall::Staking(pallet_staking::Call::payout_stakers { .. })
-            | RuntimeCall::Staking(pallet_staking::Call::payout_stakers_by_page { .. })
-            | RuntimeCall::Band(..)
-            | RuntimeCall::Soratopia(soratopia::Call::check_in {}) => Some(MINIMAL_FEE),
-            _ => None,
-        }
-    }
-    fn base_fee(call: &RuntimeCall) -> Option<Balance> {
-        match call {
-            RuntimeCall::XorFee(xor_fee::Call::xorless_call { call, .. }) => Self::match_call(call),
-            call => Self::match_call(call),
-        }
+            Self::call_fee::<Staking, pallet_staking::Call<Runtime>>(
+                "payout_stakers",
+                Flat(MINIMAL_FEE),
+            ),
+            Self::call_fee::<Staking, pallet_staking::Call<Runtime>>(
+                "payout_stakers_by_page",
+                Flat(MINIMAL_FEE),
+            ),
+            Self::pallet_fee::<Band>(Flat(MINIMAL_FEE)),
+            Self::call_fee::<Soratopia, soratopia::Call<Runtime>>("check_in", Flat(MINIMAL_FEE)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
     }
 }
 
@@ -446,34 +499,49 @@ impl xor_fee::ApplyCustomFees<RuntimeCall, AccountId> for CustomFees {
     type FeeDetails = CustomFeeDetails;
 
     fn compute_fee(call: &RuntimeCall) -> Option<(Balance, CustomFeeDetails)> {
-        let mut fee = Self::base_fee(call)?;
-
-        let mut compute_details = |call: &RuntimeCall| -> CustomFeeDetails {
-            match call {
-                RuntimeCall::OrderBook(order_book::Call::place_limit_order {
-                    lifespan, ..
-                }) => CustomFeeDetails::LimitOrderLifetime(*lifespan),
-                RuntimeCall::VestedRewards(vested_rewards::Call::vested_transfer {
-                    schedule,
-                    ..
-                }) => {
-                    // claim fee = SMALL_FEE
-                    let whole_claims_fee =
-                        SMALL_FEE.saturating_mul(schedule.claims_count() as Balance);
-                    let fee_without_claims = fee;
-                    fee = fee.saturating_add(whole_claims_fee);
-                    CustomFeeDetails::VestedTransferClaims((fee, fee_without_claims))
-                }
-                _ => CustomFeeDetails::Regular(fee),
-            }
+        let call = match call {
+            RuntimeCall::XorFee(xor_fee::Call::xorless_call { call, .. }) => call.as_ref(),
+            call => call,
         };
 
-        let details = match call {
-            RuntimeCall::XorFee(xor_fee::Call::xorless_call { call, .. }) => compute_details(call),
-            call => compute_details(call),
-        };
-
-        Some((fee, details))
+        match XorFee::custom_fee(call)? {
+            CustomFee::Flat(fee) => Some((fee, CustomFeeDetails::Regular(fee))),
+            CustomFee::PerReceiver(fee_per_receiver) => {
+                let receivers = match call {
+                    RuntimeCall::LiquidityProxy(liquidity_proxy::Call::swap_transfer_batch {
+                        swap_batches,
+                        ..
+                    }) => swap_batches
+                        .iter()
+                        .map(|x| x.receivers.len() as Balance)
+                        .fold(Balance::zero(), |acc, x| acc.saturating_add(x)),
+                    _ => 1,
+                };
+                let fee = fee_per_receiver.saturating_mul(receivers.max(1));
+                Some((fee, CustomFeeDetails::Regular(fee)))
+            }
+            CustomFee::PerLifespan(fee) => {
+                let lifespan = match call {
+                    RuntimeCall::OrderBook(order_book::Call::place_limit_order {
+                        lifespan,
+                        ..
+                    }) => *lifespan,
+                    _ => None,
+                };
+                Some((fee, CustomFeeDetails::LimitOrderLifetime(lifespan)))
+            }
+            CustomFee::PerClaim { base, per_claim } => {
+                let claims = match call {
+                    RuntimeCall::VestedRewards(vested_rewards::Call::vested_transfer {
+                        schedule,
+                        ..
+                    }) => schedule.claims_count() as Balance,
+                    _ => 0,
+                };
+                let fee = base.saturating_add(per_claim.saturating_mul(claims));
+                Some((fee, CustomFeeDetails::VestedTransferClaims((fee, base))))
+            }
+        }
     }
 
     fn should_be_postponed(
@@ -836,18 +904,21 @@ mod tests {
     use vested_rewards::vesting_currencies::{LinearVestingSchedule, VestingScheduleVariant};
 
     use crate::{
-        xor_fee_impls::{CallDepth, CustomFeeDetails, CustomFees},
+        xor_fee_impls::{CallDepth, CustomFeeDetails, CustomFees, DefaultCustomFees},
         *,
     };
+    use codec::Encode;
     use common::OrderBookId;
     use common::{balance, PriceVariant, VAL, XOR};
+    use frame_support::assert_ok;
+    use frame_support::traits::Get;
     use pallet_staking::{
         EraRewardPoints, Exposure, IndividualExposure, RewardDestination, StakingLedger,
         ValidatorPrefs,
     };
     use sp_runtime::{AccountId32, DispatchError, Perbill};
     use sp_staking::{ExposurePage, PagedExposureMetadata};
-    use xor_fee::{extension::ChargeTransactionPayment, ApplyCustomFees};
+    use xor_fee::{extension::ChargeTransactionPayment, ApplyCustomFees, CustomFee, CustomFeeKey};
 
     #[test]
     fn check_calls_from_bridge_peers_pays_yes() {
@@ -2122,116 +2193,155 @@ mod tests {
 
     #[test]
     fn compute_fee_works_fine() {
-        // compute fee works fine for vested transfer
+        framenode_chain_spec::ext().execute_with(|| {
+            // compute fee works fine for vested transfer
+
+            let schedule = VestingScheduleVariant::LinearVestingSchedule(LinearVestingSchedule {
+                asset_id: DOT,
+                start: 0u32,
+                period: 10u32,
+                period_count: 2u32,
+                per_period: 10,
+                remainder_amount: 0,
+            });
 
-        let schedule = VestingScheduleVariant::LinearVestingSchedule(LinearVestingSchedule {
-            asset_id: DOT,
-            start: 0u32,
-            period: 10u32,
-            period_count: 2u32,
-            per_period: 10,
-            remainder_amount: 0,
-        });
+            let fee = 3 * SMALL_FEE;
+            let fee_without_claims = SMALL_FEE;
 
-        let fee = 3 * SMALL_FEE;
-        let fee_without_claims = SMALL_FEE;
+            let vesting_call = RuntimeCall::VestedRewards(vested_rewards::Call::vested_transfer {
+                dest: From::from([1; 32]),
+                schedule,
+            });
+            let xorless_call_vesting = RuntimeCall::XorFee(xor_fee::Call::xorless_call {
+                call: Box::new(vesting_call.clone()),
+                asset_id: None,
+            });
+            assert_eq!(
+                CustomFees::compute_fee(&xorless_call_vesting),
+                Some((
+                    fee,
+                    CustomFeeDetails::VestedTransferClaims((fee, fee_without_claims))
+                ))
+            );
+            assert_eq!(
+                CustomFees::compute_fee(&vesting_call),
+                Some((
+                    fee,
+                    CustomFeeDetails::VestedTransferClaims((fee, fee_without_claims))
+                ))
+            );
 
-        let vesting_call = RuntimeCall::VestedRewards(vested_rewards::Call::vested_transfer {
-            dest: From::from([1; 32]),
-            schedule,
-        });
-        let xorless_call_vesting = RuntimeCall::XorFee(xor_fee::Call::xorless_call {
-            call: Box::new(vesting_call.clone()),
-            asset_id: None,
-        });
-        assert_eq!(
-            CustomFees::compute_fee(&xorless_call_vesting),
-            Some((
-                fee,
-                CustomFeeDetails::VestedTransferClaims((fee, fee_without_claims))
-            ))
-        );
-        assert_eq!(
-            CustomFees::compute_fee(&vesting_call),
-            Some((
-                fee,
-                CustomFeeDetails::VestedTransferClaims((fee, fee_without_claims))
-            ))
-        );
+            // compute fee works fine for order book
 
-        // compute fee works fine for order book
+            let order_book_id = OrderBookId {
+                dex_id: common::DEXId::Polkaswap.into(),
+                base: VAL.into(),
+                quote: XOR.into(),
+            };
+            let order_call = RuntimeCall::OrderBook(order_book::Call::place_limit_order {
+                order_book_id,
+                price: balance!(11),
+                amount: balance!(100),
+                side: PriceVariant::Sell,
+                lifespan: None,
+            });
+            let xorless_call = RuntimeCall::XorFee(xor_fee::Call::xorless_call {
+                call: Box::new(order_call.clone()),
+                asset_id: None,
+            });
+            assert_eq!(
+                CustomFees::compute_fee(&xorless_call),
+                Some((SMALL_FEE, CustomFeeDetails::LimitOrderLifetime(None)))
+            );
+            assert_eq!(
+                CustomFees::compute_fee(&order_call),
+                Some((SMALL_FEE, CustomFeeDetails::LimitOrderLifetime(None)))
+            );
 
-        let order_book_id = OrderBookId {
-            dex_id: common::DEXId::Polkaswap.into(),
-            base: VAL.into(),
-            quote: XOR.into(),
-        };
-        let order_call = RuntimeCall::OrderBook(order_book::Call::place_limit_order {
-            order_book_id,
-            price: balance!(11),
-            amount: balance!(100),
-            side: PriceVariant::Sell,
-            lifespan: None,
-        });
-        let xorless_call = RuntimeCall::XorFee(xor_fee::Call::xorless_call {
-            call: Box::new(order_call.clone()),
-            asset_id: None,
-        });
-        assert_eq!(
-            CustomFees::compute_fee(&xorless_call),
-            Some((SMALL_FEE, CustomFeeDetails::LimitOrderLifetime(None)))
-        );
-        assert_eq!(
-            CustomFees::compute_fee(&order_call),
-            Some((SMALL_FEE, CustomFeeDetails::LimitOrderLifetime(None)))
-        );
+            // compute fee works fine for Some predefined fee
+
+            let transfer_call = RuntimeCall::Assets(assets::Call::transfer {
+                asset_id: GetBaseAssetId::get(),
+                to: From::from([1; 32]),
+                amount: balance!(100),
+            });
+            let xorless_call = RuntimeCall::XorFee(xor_fee::Call::xorless_call {
+                call: Box::new(transfer_call.clone()),
+                asset_id: None,
+            });
+            assert_eq!(
+                CustomFees::compute_fee(&transfer_call),
+                Some((SMALL_FEE, CustomFeeDetails::Regular(SMALL_FEE)))
+            );
+            assert_eq!(
+                CustomFees::compute_fee(&xorless_call),
+                Some((SMALL_FEE, CustomFeeDetails::Regular(SMALL_FEE)))
+            );
 
-        // compute fee works fine for Some predefined fee
+            let polkamarkt_call = RuntimeCall::Polkamarkt(pallet_polkamarkt::Call::create_market {
+                condition_id: 1,
+                close_block: 42,
+                seed_liquidity: balance!(100),
+            });
+            let xorless_call = RuntimeCall::XorFee(xor_fee::Call::xorless_call {
+                call: Box::new(polkamarkt_call.clone()),
+                asset_id: None,
+            });
+            assert_eq!(
+                CustomFees::compute_fee(&polkamarkt_call),
+                Some((SMALL_FEE, CustomFeeDetails::Regular(SMALL_FEE)))
+            );
+            assert_eq!(
+                CustomFees::compute_fee(&xorless_call),
+                Some((SMALL_FEE, CustomFeeDetails::Regular(SMALL_FEE)))
+            );
 
-        let transfer_call = RuntimeCall::Assets(assets::Call::transfer {
-            asset_id: GetBaseAssetId::get(),
-            to: From::from([1; 32]),
-            amount: balance!(100),
-        });
-        let xorless_call = RuntimeCall::XorFee(xor_fee::Call::xorless_call {
-            call: Box::new(transfer_call.clone()),
-            asset_id: None,
-        });
-        assert_eq!(
-            CustomFees::compute_fee(&transfer_call),
-            Some((SMALL_FEE, CustomFeeDetails::Regular(SMALL_FEE)))
-        );
-        assert_eq!(
-            CustomFees::compute_fee(&xorless_call),
-            Some((SMALL_FEE, CustomFeeDetails::Regular(SMALL_FEE)))
-        );
+            // compute fee works fine for others
 
-        let polkamarkt_call = RuntimeCall::Polkamarkt(pallet_polkamarkt::Call::create_market {
-            condition_id: 1,
-            close_block: 42,
-            seed_liquidity: balance!(100),
-        });
-        let xorless_call = RuntimeCall::XorFee(xor_fee::Call::xorless_call {
-            call: Box::new(polkamarkt_call.clone()),
-            asset_id: None,
+            let set_call = RuntimeCall::Timestamp(pallet_timestamp::Call::set { now: 1_u64 });
+            let xorless_call = RuntimeCall::XorFee(xor_fee::Call::xorless_call {
+                call: Box::new(set_call.clone()),
+                asset_id: None,
+            });
+            assert_eq!(CustomFees::compute_fee(&set_call), None);
+            assert_eq!(CustomFees::compute_fee(&xorless_call), None);
         });
-        assert_eq!(
-            CustomFees::compute_fee(&polkamarkt_call),
-            Some((SMALL_FEE, CustomFeeDetails::Regular(SMALL_FEE)))
-        );
-        assert_eq!(
-            CustomFees::compute_fee(&xorless_call),
-            Some((SMALL_FEE, CustomFeeDetails::Regular(SMALL_FEE)))
-        );
+    }
 
-        // compute fee works fine for others
+    #[test]
+    fn default_custom_fees_resolve_all_calls() {
+        let fees = DefaultCustomFees::get();
+        assert_eq!(fees.len(), 31);
+        let mut keys = fees.iter().map(|(key, _)| *key).collect::<Vec<_>>();
+        keys.sort();
+        keys.dedup();
+        assert_eq!(keys.len(), fees.len());
+    }
 
-        let set_call = RuntimeCall::Timestamp(pallet_timestamp::Call::set { now: 1_u64 });
-        let xorless_call = RuntimeCall::XorFee(xor_fee::Call::xorless_call {
-            call: Box::new(set_call.clone()),
-            asset_id: None,
+    #[test]
+    fn custom_fee_table_is_editable() {
+        framenode_chain_spec::ext().execute_with(|| {
+            let polkamarkt_call = RuntimeCall::Polkamarkt(pallet_polkamarkt::Call::create_market {
+                condition_id: 1,
+                close_block: 42,
+                seed_liquidity: balance!(100),
+            });
+            let key = CustomFeeKey {
+                pallet_index: polkamarkt_call.encode()[0],
+                call_index: Some(polkamarkt_call.encode()[1]),
+            };
+            assert_ok!(XorFee::set_custom_fee(
+                RuntimeOrigin::root(),
+                key,
+                Some(CustomFee::Flat(BIG_FEE))
+            ));
+            assert_eq!(
+                CustomFees::compute_fee(&polkamarkt_call),
+                Some((BIG_FEE, CustomFeeDetails::Regular(BIG_FEE)))
+            );
+
+            assert_ok!(XorFee::set_custom_fee(RuntimeOrigin::root(), key, None));
+            assert_eq!(CustomFees::compute_fee(&polkamarkt_call), None);
         });
-        assert_eq!(CustomFees::compute_fee(&set_call), None);
-        assert_eq!(CustomFees::compute_fee(&xorless_call), None);
     }
 }
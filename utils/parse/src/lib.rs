@@ -0,0 +1,532 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! SCALE decoder for SORA extrinsics, events and storage.
+//!
+//! Payloads are decoded with `framenode_runtime` metadata and converted to JSON.
+
+use codec::{DecodeAll, Encode};
+use frame_metadata::v14::{StorageEntryType, StorageHasher};
+use frame_metadata::v15::{PalletMetadata, RuntimeMetadataV15};
+use frame_metadata::{RuntimeMetadata, RuntimeMetadataPrefixed};
+use framenode_runtime::{Hash, Runtime, RuntimeCall, RuntimeEvent, UncheckedExtrinsic};
+use scale_info::form::PortableForm;
+use scale_info::{PortableRegistry, TypeDef, TypeDefPrimitive};
+use scale_value::{Composite, Primitive, Value, ValueDef};
+use serde_json::{json, Map, Value as Json};
+use sp_runtime::generic::Preamble;
+
+/// Parse hex string with optional `0x` prefix.
+pub fn parse_hex(payload: &str) -> Result<Vec<u8>, String> {
+    let payload = payload.trim();
+    hex::decode(payload.strip_prefix("0x").unwrap_or(payload))
+        .map_err(|e| format!("Invalid hex payload: {e}"))
+}
+
+/// Format bytes as `0x` prefixed hex string.
+pub fn to_hex(bytes: &[u8]) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
+
+/// Storage prefix of the `pallet::item` storage entry.
+pub fn storage_prefix(pallet: &str, item: &str) -> Vec<u8> {
+    [
+        sp_core::twox_128(pallet.as_bytes()),
+        sp_core::twox_128(item.as_bytes()),
+    ]
+    .concat()
+}
+
+/// Kind of the pallet enum a payload is decoded as.
+#[derive(Clone, Copy)]
+enum VariantKind {
+    Call,
+    Event,
+}
+
+/// Decoder built from `framenode_runtime` metadata.
+pub struct Decoder {
+    metadata: RuntimeMetadataV15,
+}
+
+impl Decoder {
+    /// Create decoder from the metadata of the linked runtime.
+    pub fn new() -> Result<Self, String> {
+        let metadata = Runtime::metadata_at_version(15)
+            .ok_or("Runtime doesn't provide metadata V15".to_string())?;
+        match RuntimeMetadataPrefixed::decode_all(&mut &metadata[..])
+            .map_err(|e| format!("Failed to decode runtime metadata: {e}"))?
+            .1
+        {
+            RuntimeMetadata::V15(metadata) => Ok(Self { metadata }),
+            _ => Err("Unexpected runtime metadata version".into()),
+        }
+    }
+
+    fn types(&self) -> &PortableRegistry {
+        &self.metadata.types
+    }
+
+    fn type_def(&self, ty: u32) -> Option<&TypeDef<PortableForm>> {
+        self.types().resolve(ty).map(|ty| &ty.type_def)
+    }
+
+    fn decode_value(&self, ty: u32, data: &mut &[u8]) -> Result<Value<u32>, String> {
+        scale_value::scale::decode_as_type(data, ty, self.types())
+            .map_err(|e| format!("Failed to decode type #{ty}: {e}"))
+    }
+
+    fn decode_exact(&self, ty: u32, mut data: &[u8]) -> Result<Json, String> {
+        let value = self.decode_value(ty, &mut data)?;
+        if !data.is_empty() {
+            return Err(format!("{} bytes left after decoding", data.len()));
+        }
+        Ok(self.to_json(&value))
+    }
+
+    /// Byte sequences and arrays are printed as hex.
+    fn as_bytes(&self, ty: u32, values: &[Value<u32>]) -> Option<Vec<u8>> {
+        let element = match self.type_def(ty)? {
+            TypeDef::Sequence(sequence) => sequence.type_param.id,
+            TypeDef::Array(array) => array.type_param.id,
+            _ => return None,
+        };
+        if !matches!(
+            self.type_def(element)?,
+            TypeDef::Primitive(TypeDefPrimitive::U8)
+        ) {
+            return None;
+        }
+        values
+            .iter()
+            .map(|value| match value.value {
+                ValueDef::Primitive(Primitive::U128(byte)) => u8::try_from(byte).ok(),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn to_json(&self, value: &Value<u32>) -> Json {
+        match &value.value {
+            ValueDef::Composite(Composite::Unnamed(values)) => {
+                if let Some(bytes) = self.as_bytes(value.context, values) {
+                    return Json::String(to_hex(&bytes));
+                }
+                // Unwrap new type structs like `AccountId32` or `H256`
+                if values.len() == 1
+                    && matches!(self.type_def(value.context), Some(TypeDef::Composite(_)))
+                {
+                    return self.to_json(&values[0]);
+                }
+                Json::Array(values.iter().map(|value| self.to_json(value)).collect())
+            }
+            ValueDef::Composite(composite) => self.fields_to_json(composite),
+            ValueDef::Variant(variant) => match &variant.values {
+                Composite::Named(fields) if fields.is_empty() => Json::String(variant.name.clone()),
+                Composite::Unnamed(values) if values.is_empty() => {
+                    Json::String(variant.name.clone())
+                }
+                Composite::Unnamed(values) if values.len() == 1 => {
+                    json!({ variant.name.clone(): self.to_json(&values[0]) })
+                }
+                values => json!({ variant.name.clone(): self.fields_to_json(values) }),
+            },
+            ValueDef::BitSequence(bits) => {
+                Json::String(bits.iter().map(|bit| if bit { '1' } else { '0' }).collect())
+            }
+            ValueDef::Primitive(primitive) => match primitive {
+                Primitive::Bool(value) => Json::Bool(*value),
+                Primitive::Char(value) => Json::String(value.to_string()),
+                Primitive::String(value) => Json::String(value.clone()),
+                // Balances don't fit JSON numbers, so big values are printed as strings
+                Primitive::U128(value) => u64::try_from(*value)
+                    .map(Json::from)
+                    .unwrap_or_else(|_| Json::String(value.to_string())),
+                Primitive::I128(value) => i64::try_from(*value)
+                    .map(Json::from)
+                    .unwrap_or_else(|_| Json::String(value.to_string())),
+                Primitive::U256(value) | Primitive::I256(value) => Json::String(to_hex(value)),
+            },
+        }
+    }
+
+    fn fields_to_json(&self, composite: &Composite<u32>) -> Json {
+        match composite {
+            Composite::Named(fields) => Json::Object(
+                fields
+                    .iter()
+                    .map(|(name, value)| (name.clone(), self.to_json(value)))
+                    .collect(),
+            ),
+            Composite::Unnamed(values) => {
+                Json::Array(values.iter().map(|value| self.to_json(value)).collect())
+            }
+        }
+    }
+
+    fn pallet_variant_ty(pallet: &PalletMetadata<PortableForm>, kind: VariantKind) -> Option<u32> {
+        match kind {
+            VariantKind::Call => pallet.calls.as_ref().map(|calls| calls.ty.id),
+            VariantKind::Event => pallet.event.as_ref().map(|event| event.ty.id),
+        }
+    }
+
+    /// Find pallet and variant by the first two bytes of a call or event.
+    fn identify_variant(
+        &self,
+        bytes: &[u8],
+        kind: VariantKind,
+    ) -> Result<(&PalletMetadata<PortableForm>, u32, String), String> {
+        let (pallet_index, variant_index) = match bytes {
+            [pallet_index, variant_index, ..] => (*pallet_index, *variant_index),
+            _ => return Err("Payload is too short".into()),
+        };
+        let (pallet, ty) = self
+            .metadata
+            .pallets
+            .iter()
+            .find(|pallet| pallet.index == pallet_index)
+            .and_then(|pallet| Some((pallet, Self::pallet_variant_ty(pallet, kind)?)))
+            .ok_or_else(|| format!("No pallet with index {pallet_index}"))?;
+        let variant = match self.type_def(ty) {
+            Some(TypeDef::Variant(variants)) => variants
+                .variants
+                .iter()
+                .find(|variant| variant.index == variant_index)
+                .map(|variant| variant.name.clone()),
+            _ => None,
+        }
+        .ok_or_else(|| {
+            format!(
+                "No variant with index {variant_index} in pallet {}",
+                pallet.name
+            )
+        })?;
+        Ok((pallet, ty, variant))
+    }
+
+    fn decode_variant(&self, bytes: &[u8], kind: VariantKind) -> Result<Json, String> {
+        let (pallet, ty, variant) = self.identify_variant(bytes, kind)?;
+        let mut data = &bytes[1..];
+        let value = self.decode_value(ty, &mut data)?;
+        if !data.is_empty() {
+            return Err(format!("{} bytes left after decoding", data.len()));
+        }
+        let fields = match &value.value {
+            ValueDef::Variant(variant) => self.fields_to_json(&variant.values),
+            _ => Json::Null,
+        };
+        let (variant_key, index_key, fields_key) = match kind {
+            VariantKind::Call => ("call", "callIndex", "args"),
+            VariantKind::Event => ("event", "eventIndex", "fields"),
+        };
+        Ok(json!({
+            "pallet": pallet.name,
+            "palletIndex": pallet.index,
+            variant_key: variant,
+            index_key: bytes[1],
+            fields_key: fields,
+        }))
+    }
+
+    /// Decode a `RuntimeCall`.
+    pub fn call(&self, bytes: &[u8]) -> Result<Json, String> {
+        RuntimeCall::decode_all(&mut &bytes[..]).map_err(|e| format!("Invalid call: {e}"))?;
+        self.decode_variant(bytes, VariantKind::Call)
+    }
+
+    /// Decode a `RuntimeEvent`.
+    pub fn event(&self, bytes: &[u8]) -> Result<Json, String> {
+        RuntimeEvent::decode_all(&mut &bytes[..]).map_err(|e| format!("Invalid event: {e}"))?;
+        self.decode_variant(bytes, VariantKind::Event)
+    }
+
+    /// Decode a `frame_system::EventRecord`.
+    pub fn event_record(&self, bytes: &[u8]) -> Result<Json, String> {
+        let record = frame_system::EventRecord::<RuntimeEvent, Hash>::decode_all(&mut &bytes[..])
+            .map_err(|e| format!("Invalid event record: {e}"))?;
+        Ok(json!({
+            "phase": format!("{:?}", record.phase),
+            "event": self.event(&record.event.encode())?,
+            "topics": record.topics.iter().map(|topic| to_hex(topic.as_bytes())).collect::<Vec<_>>(),
+        }))
+    }
+
+    /// Decode an `UncheckedExtrinsic`.
+    pub fn extrinsic(&self, bytes: &[u8]) -> Result<Json, String> {
+        let xt = UncheckedExtrinsic::decode_all(&mut &bytes[..])
+            .map_err(|e| format!("Invalid extrinsic: {e}"))?;
+        let types = &self.metadata.extrinsic;
+        let mut json = Map::new();
+        json.insert("hash".into(), to_hex(&sp_core::blake2_256(bytes)).into());
+        match &xt.preamble {
+            Preamble::Bare(version) => {
+                json.insert("type".into(), "bare".into());
+                json.insert("version".into(), (*version).into());
+            }
+            Preamble::Signed(address, signature, extra) => {
+                json.insert("type".into(), "signed".into());
+                json.insert(
+                    "address".into(),
+                    self.decode_exact(types.address_ty.id, &address.encode())?,
+                );
+                json.insert(
+                    "signature".into(),
+                    self.decode_exact(types.signature_ty.id, &signature.encode())?,
+                );
+                json.insert(
+                    "extra".into(),
+                    self.decode_exact(types.extra_ty.id, &extra.encode())?,
+                );
+            }
+            Preamble::General(version, extra) => {
+                json.insert("type".into(), "general".into());
+                json.insert("extensionVersion".into(), (*version).into());
+                json.insert(
+                    "extra".into(),
+                    self.decode_exact(types.extra_ty.id, &extra.encode())?,
+                );
+            }
+        }
+        json.insert("call".into(), self.call(&xt.function.encode())?);
+        Ok(Json::Object(json))
+    }
+
+    fn decode_storage_key(
+        &self,
+        hasher: &StorageHasher,
+        ty: u32,
+        data: &mut &[u8],
+    ) -> Result<Json, String> {
+        let (hash_len, concat) = match hasher {
+            StorageHasher::Blake2_128 | StorageHasher::Twox128 => (16, false),
+            StorageHasher::Blake2_256 | StorageHasher::Twox256 => (32, false),
+            StorageHasher::Blake2_128Concat => (16, true),
+            StorageHasher::Twox64Concat => (8, true),
+            StorageHasher::Identity => (0, true),
+        };
+        if data.len() < hash_len {
+            return Err("Storage key is too short".into());
+        }
+        let (hash, rest) = data.split_at(hash_len);
+        *data = rest;
+        if concat {
+            Ok(self.to_json(&self.decode_value(ty, data)?))
+        } else {
+            // Key can't be recovered from non-concat hashers
+            Ok(json!({ "hash": to_hex(hash) }))
+        }
+    }
+
+    /// Decode a storage key and optionally its value, storage item is found by the key prefix
+    /// if names are not given.
+    pub fn storage(
+        &self,
+        pallet: Option<&str>,
+        item: Option<&str>,
+        key: &[u8],
+        value: Option<&[u8]>,
+    ) -> Result<Json, String> {
+        let (prefix, entry) = self
+            .metadata
+            .pallets
+            .iter()
+            .filter(|metadata| pallet.map_or(true, |pallet| metadata.name == pallet))
+            .filter_map(|metadata| metadata.storage.as_ref())
+            .flat_map(|storage| {
+                storage
+                    .entries
+                    .iter()
+                    .map(move |entry| (storage.prefix.as_str(), entry))
+            })
+            .find(|(prefix, entry)| {
+                item.map_or(true, |item| entry.name == item)
+                    && key.starts_with(&storage_prefix(prefix, &entry.name))
+            })
+            .ok_or("Storage item for the key is not found".to_string())?;
+        let mut data = &key[32..];
+        let (keys, value_ty) = match &entry.ty {
+            StorageEntryType::Plain(ty) => (vec![], ty.id),
+            StorageEntryType::Map {
+                hashers,
+                key: key_ty,
+                value: value_ty,
+            } => {
+                let key_tys = if hashers.len() == 1 {
+                    vec![key_ty.id]
+                } else {
+                    match self.type_def(key_ty.id) {
+                        Some(TypeDef::Tuple(tuple)) if tuple.fields.len() == hashers.len() => {
+                            tuple.fields.iter().map(|field| field.id).collect()
+                        }
+                        _ => return Err("Unexpected storage key type".into()),
+                    }
+                };
+                let mut keys = vec![];
+                for (hasher, ty) in hashers.iter().zip(key_tys) {
+                    // Partial keys are used to iterate maps
+                    if data.is_empty() {
+                        break;
+                    }
+                    keys.push(self.decode_storage_key(hasher, ty, &mut data)?);
+                }
+                (keys, value_ty.id)
+            }
+        };
+        if !data.is_empty() {
+            return Err(format!("{} bytes left after decoding key", data.len()));
+        }
+        let mut json = Map::new();
+        json.insert("pallet".into(), prefix.into());
+        json.insert("item".into(), entry.name.clone().into());
+        json.insert("keys".into(), keys.into());
+        if let Some(value) = value {
+            json.insert("value".into(), self.decode_exact(value_ty, value)?);
+        }
+        Ok(Json::Object(json))
+    }
+
+    /// Report every interpretation of the payload that decodes without leftovers.
+    pub fn identify(&self, bytes: &[u8]) -> Result<Json, String> {
+        let mut matches = vec![];
+        if let Ok(call) = self.call(bytes) {
+            matches.push(json!({ "kind": "call", "call": call }));
+        }
+        if let Ok(event) = self.event(bytes) {
+            matches.push(json!({ "kind": "event", "event": event }));
+        }
+        if let Ok(extrinsic) = self.extrinsic(bytes) {
+            matches.push(json!({ "kind": "extrinsic", "extrinsic": extrinsic }));
+        }
+        if matches.is_empty() {
+            // Fall back to indices only to point at the pallet the payload belongs to
+            for kind in [VariantKind::Call, VariantKind::Event] {
+                if let Ok((pallet, _, variant)) = self.identify_variant(bytes, kind) {
+                    let kind = match kind {
+                        VariantKind::Call => "call",
+                        VariantKind::Event => "event",
+                    };
+                    matches.push(json!({
+                        "kind": kind,
+                        "pallet": pallet.name,
+                        "palletIndex": pallet.index,
+                        "variant": variant,
+                        "variantIndex": bytes[1],
+                        "decoded": false,
+                    }));
+                }
+            }
+        }
+        if matches.is_empty() {
+            return Err("Payload doesn't match any call or event".into());
+        }
+        Ok(Json::Array(matches))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use framenode_runtime::AccountId;
+
+    fn remark_call() -> RuntimeCall {
+        frame_system::Call::<Runtime>::remark {
+            remark: vec![1, 2, 3],
+        }
+        .into()
+    }
+
+    #[test]
+    fn hex_is_parsed_with_and_without_prefix() {
+        assert_eq!(parse_hex("0x0102"), Ok(vec![1, 2]));
+        assert_eq!(parse_hex(" 0102\n"), Ok(vec![1, 2]));
+        assert!(parse_hex("0xzz").is_err());
+        assert_eq!(to_hex(&[1, 2]), "0x0102");
+    }
+
+    #[test]
+    fn call_is_decoded_by_metadata() {
+        let decoder = Decoder::new().unwrap();
+        let call = remark_call().encode();
+        assert_eq!(
+            decoder.call(&call).unwrap(),
+            json!({
+                "pallet": "System",
+                "palletIndex": call[0],
+                "call": "remark",
+                "callIndex": call[1],
+                "args": { "remark": "0x010203" },
+            })
+        );
+        assert!(decoder.call(&[call.as_slice(), &[0]].concat()).is_err());
+    }
+
+    #[test]
+    fn storage_key_and_value_are_decoded() {
+        let decoder = Decoder::new().unwrap();
+        let account = AccountId::new([7; 32]);
+        let key = [
+            storage_prefix("System", "Account"),
+            sp_core::blake2_128(&account.encode()).to_vec(),
+            account.encode(),
+        ]
+        .concat();
+        let value = framenode_runtime::AccountData::default().encode();
+        let decoded = decoder.storage(None, None, &key, Some(&value)).unwrap();
+        assert_eq!(decoded["pallet"], "System");
+        assert_eq!(decoded["item"], "Account");
+        assert_eq!(decoded["keys"], json!([to_hex(&[7; 32])]));
+        assert_eq!(decoded["value"]["nonce"], 0);
+
+        let number = decoder
+            .storage(
+                Some("System"),
+                Some("Number"),
+                &storage_prefix("System", "Number"),
+                Some(&5u32.encode()),
+            )
+            .unwrap();
+        assert_eq!(number["value"], 5);
+        assert!(decoder.storage(None, None, &[0; 32], None).is_err());
+    }
+
+    #[test]
+    fn truncated_call_is_identified_by_indices() {
+        let decoder = Decoder::new().unwrap();
+        let call = remark_call().encode();
+        let matches = decoder.identify(&call[..2]).unwrap();
+        assert_eq!(matches[0]["kind"], "call");
+        assert_eq!(matches[0]["pallet"], "System");
+        assert_eq!(matches[0]["variant"], "remark");
+        assert_eq!(matches[0]["decoded"], false);
+
+        assert!(decoder.identify(&[255, 255]).is_err());
+    }
+}
@@ -1,12 +1,147 @@
-use codec::Decode;
-use framenode_runtime::UncheckedExtrinsic;
-
-fn main() {
-    let mut args = std::env::args();
-    let _ = args.next();
-    let bytes = args.next().unwrap();
-    let mut bytes = hex::decode(bytes).unwrap();
-    bytes.insert(0, 0);
-    let ext = UncheckedExtrinsic::decode(&mut &bytes[..]).unwrap();
-    println!("{ext:?}");
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! SCALE decoder CLI for SORA extrinsics, events and storage.
+//!
+//! Payloads are decoded with `framenode_runtime` metadata and printed as JSON. When the payload
+//! argument is omitted, payloads are read from stdin line by line and every line produces a
+//! separate JSON document.
+
+use clap::{Parser, Subcommand};
+use parse::{parse_hex, Decoder};
+use serde_json::{json, Value as Json};
+use std::io::BufRead;
+
+#[derive(Debug, Parser)]
+#[clap(about = "Decode SCALE encoded SORA payloads into JSON")]
+struct Cli {
+    /// Pretty print JSON output
+    #[clap(long, global = true)]
+    pretty: bool,
+
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Decode an `UncheckedExtrinsic`
+    Extrinsic {
+        /// Hex encoded extrinsic, stdin lines are used if omitted
+        payload: Option<String>,
+    },
+    /// Decode a `RuntimeCall`
+    Call {
+        /// Hex encoded call, stdin lines are used if omitted
+        payload: Option<String>,
+    },
+    /// Decode a `RuntimeEvent`
+    Event {
+        /// Payload is a `frame_system::EventRecord` instead of a bare event
+        #[clap(long)]
+        record: bool,
+        /// Hex encoded event, stdin lines are used if omitted
+        payload: Option<String>,
+    },
+    /// Decode a storage key and optionally its value.
+    /// Storage item is found by the key prefix if pallet and item names are not given
+    Storage {
+        /// Pallet name
+        #[clap(long)]
+        pallet: Option<String>,
+        /// Storage item name
+        #[clap(long)]
+        item: Option<String>,
+        /// Hex encoded storage key, stdin lines `<key> [value]` are used if omitted
+        key: Option<String>,
+        /// Hex encoded storage value
+        value: Option<String>,
+    },
+    /// Find the pallet and the call or event variant a payload belongs to
+    Identify {
+        /// Hex encoded call or event, stdin lines are used if omitted
+        payload: Option<String>,
+    },
+}
+
+fn run(decoder: &Decoder, command: &Command, input: &str) -> Result<Json, String> {
+    match command {
+        Command::Extrinsic { .. } => decoder.extrinsic(&parse_hex(input)?),
+        Command::Call { .. } => decoder.call(&parse_hex(input)?),
+        Command::Event { record: false, .. } => decoder.event(&parse_hex(input)?),
+        Command::Event { record: true, .. } => decoder.event_record(&parse_hex(input)?),
+        Command::Identify { .. } => decoder.identify(&parse_hex(input)?),
+        Command::Storage { pallet, item, .. } => {
+            let mut parts = input.split_whitespace();
+            let key = parse_hex(parts.next().unwrap_or_default())?;
+            let value = parts.next().map(parse_hex).transpose()?;
+            decoder.storage(pallet.as_deref(), item.as_deref(), &key, value.as_deref())
+        }
+    }
+}
+
+fn print_json(json: &Json, pretty: bool) {
+    let output = if pretty {
+        serde_json::to_string_pretty(json)
+    } else {
+        serde_json::to_string(json)
+    };
+    println!("{}", output.expect("JSON values are always serializable"));
+}
+
+fn main() -> Result<(), String> {
+    let cli = Cli::parse();
+    let decoder = Decoder::new()?;
+    let input = match &cli.command {
+        Command::Extrinsic { payload }
+        | Command::Call { payload }
+        | Command::Event { payload, .. }
+        | Command::Identify { payload } => payload.clone(),
+        Command::Storage { key, value, .. } => key
+            .as_ref()
+            .map(|key| format!("{key} {}", value.as_deref().unwrap_or_default())),
+    };
+    match input {
+        Some(input) => print_json(&run(&decoder, &cli.command, &input)?, cli.pretty),
+        None => {
+            // Batch mode, errors are reported per line and don't stop processing
+            for line in std::io::stdin().lock().lines() {
+                let line = line.map_err(|e| format!("Failed to read stdin: {e}"))?;
+                if line.trim().is_empty() {
+                    continue;
+                }
+                let json = run(&decoder, &cli.command, &line)
+                    .unwrap_or_else(|error| json!({ "input": line, "error": error }));
+                print_json(&json, cli.pretty);
+            }
+        }
+    }
+    Ok(())
 }
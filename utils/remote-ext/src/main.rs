@@ -2,17 +2,25 @@
 #![allow(clippy::all)]
 
 use clap::Parser;
-use codec::Decode;
+use codec::{Decode, Encode};
 use frame_remote_externalities::{
-    Builder, Mode, OfflineConfig, OnlineConfig, RemoteExternalities, SnapshotConfig,
+    Builder, Mode, OfflineConfig, OnlineConfig, RemoteExternalities, Snapshot, SnapshotConfig,
 };
+use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use framenode_runtime::{Runtime, XorFee};
 use jsonrpsee::http_client::{HttpClient, HttpClientBuilder};
+use pallet_transaction_payment::FeeDetails;
+use parse::{parse_hex, to_hex, Decoder};
+use serde_json::{json, Value as Json};
 use sp_core::H256;
-use sp_runtime::generic::Preamble;
-use sp_runtime::{traits::Block as BlockT, DeserializeOwned};
+use sp_runtime::generic::ExtrinsicFormat;
+use sp_runtime::traits::{Applyable, Checkable};
+use sp_runtime::{traits::Block as BlockT, DeserializeOwned, DispatchResult};
+use sp_state_machine::{Backend, Ext, OverlayedChanges, TestExternalities};
 
-use anyhow::Result as AnyResult;
-use sp_runtime::traits::Dispatchable;
+use anyhow::{anyhow, Result as AnyResult};
+use common::Balance;
+use sp_runtime::traits::BlakeTwo256;
 use std::path::PathBuf;
 
 async fn create_ext<B>(
@@ -58,30 +66,336 @@ struct Cli {
     /// Sora snapshot path.
     #[clap(long)]
     snapshot_path: Option<PathBuf>,
-    /// Encoded extrinsic
+    /// Encoded extrinsic, can be repeated to execute extrinsics sequentially.
     #[clap(long)]
-    xt: String,
+    xt: Vec<String>,
+    /// File with encoded extrinsics, one per line. Executed after `--xt` extrinsics.
+    #[clap(long)]
+    xt_file: Option<PathBuf>,
+    /// Don't apply changes, every extrinsic is executed against the initial state.
+    #[clap(long)]
+    dry_run: bool,
+    /// Write resulting state to a new snapshot.
+    #[clap(long, conflicts_with = "dry_run")]
+    out_snapshot: Option<PathBuf>,
+}
+
+impl Cli {
+    fn extrinsics(&self) -> AnyResult<Vec<Vec<u8>>> {
+        let mut encoded = self.xt.clone();
+        if let Some(path) = &self.xt_file {
+            let content = std::fs::read_to_string(path)?;
+            encoded.extend(
+                content
+                    .lines()
+                    .map(str::trim)
+                    .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                    .map(str::to_string),
+            );
+        }
+        if encoded.is_empty() {
+            return Err(anyhow!("No extrinsics provided, use --xt or --xt-file"));
+        }
+        encoded
+            .iter()
+            .map(|xt| parse_hex(xt).map_err(|e| anyhow!(e)))
+            .collect()
+    }
+}
+
+/// Result of the extrinsic execution, collected inside externalities.
+struct Execution {
+    result: DispatchResult,
+    events: Vec<Json>,
+    declared_weight: Json,
+    actual_weight: Json,
+    fee: Option<Json>,
+}
+
+fn fee_json(fee: &FeeDetails<Balance>) -> Json {
+    let inclusion_fee = fee.inclusion_fee.as_ref().map(|fee| {
+        json!({
+            "base_fee": fee.base_fee.to_string(),
+            "len_fee": fee.len_fee.to_string(),
+            "adjusted_weight_fee": fee.adjusted_weight_fee.to_string(),
+        })
+    });
+    json!({
+        "inclusion_fee": inclusion_fee,
+        "tip": fee.tip.to_string(),
+        "final_fee": fee.final_fee().to_string(),
+    })
+}
+
+fn execute(decoder: &Decoder, xt_encoded: &[u8]) -> AnyResult<Execution> {
+    let xt = framenode_runtime::UncheckedExtrinsic::decode(&mut &xt_encoded[..])?;
+    let len = xt_encoded.len();
+    frame_system::Pallet::<Runtime>::reset_events();
+
+    // Extrinsic is checked and applied with its signed extensions like in a block, so nonce and
+    // fee changes are a part of the diff
+    let checked: framenode_runtime::CheckedExtrinsic = xt
+        .check(&frame_system::ChainContext::<Runtime>::default())
+        .map_err(|e| anyhow!("Invalid extrinsic: {e:?}"))?;
+    let info = checked.get_dispatch_info();
+    let declared_fee = match &checked.format {
+        ExtrinsicFormat::Signed(..) => Some(XorFee::compute_fee_details(
+            len as u32,
+            &checked.function,
+            &info,
+            0,
+        )),
+        _ => None,
+    };
+    let res = checked
+        .apply::<Runtime>(&info, len)
+        .map_err(|e| anyhow!("Extrinsic is not applicable: {e:?}"))?;
+    let (post_info, result): (PostDispatchInfo, DispatchResult) = match res {
+        Ok(post_info) => (post_info, Ok(())),
+        Err(err) => (err.post_info, Err(err.error)),
+    };
+    let fee = declared_fee.map(|(declared, custom_fee_details)| {
+        let actual = XorFee::compute_actual_fee_details(
+            len as u32,
+            &info,
+            &post_info,
+            &result,
+            0,
+            custom_fee_details,
+        );
+        json!({
+            "declared": fee_json(&declared),
+            "actual": fee_json(&actual),
+        })
+    });
+    let events = frame_system::Pallet::<Runtime>::read_events_no_consensus()
+        .map(|record| {
+            let event = record.event.encode();
+            decoder
+                .event(&event)
+                .unwrap_or_else(|_| Json::String(to_hex(&event)))
+        })
+        .collect();
+    let actual_weight = post_info.calc_actual_weight(&info);
+    Ok(Execution {
+        result,
+        events,
+        declared_weight: json!({
+            "ref_time": info.total_weight().ref_time(),
+            "proof_size": info.total_weight().proof_size(),
+        }),
+        actual_weight: json!({
+            "ref_time": actual_weight.ref_time(),
+            "proof_size": actual_weight.proof_size(),
+        }),
+        fee,
+    })
+}
+
+/// Decode storage value if possible, fallback to hex otherwise.
+fn storage_json(decoder: &Decoder, key: &[u8], value: Option<&[u8]>) -> Json {
+    match value {
+        Some(value) => decoder
+            .storage(None, None, key, Some(value))
+            .ok()
+            .and_then(|decoded| decoded.get("value").cloned())
+            .unwrap_or_else(|| Json::String(to_hex(value))),
+        None => Json::Null,
+    }
+}
+
+/// Execute extrinsic in a separate overlay to collect storage changes it made.
+fn execute_with_diff(
+    ext: &mut RemoteExternalities<framenode_runtime::Block>,
+    decoder: &Decoder,
+    xt_encoded: &[u8],
+    dry_run: bool,
+) -> AnyResult<Json> {
+    ext.commit_all().map_err(|e| anyhow!(e))?;
+    let inner = &mut ext.inner_ext;
+    let mut overlay = OverlayedChanges::<BlakeTwo256>::default();
+    let execution = {
+        let mut externalities = Ext::new(&mut overlay, &inner.backend, Some(&mut inner.extensions));
+        sp_externalities::set_and_run_with_externalities(&mut externalities, || {
+            execute(decoder, xt_encoded)
+        })?
+    };
+    let changes = overlay
+        .drain_storage_changes(&inner.backend, inner.state_version)
+        .map_err(|e| anyhow!("Failed to collect storage changes: {e}"))?;
+
+    let mut diff = Vec::new();
+    for (key, new) in changes.main_storage_changes.iter() {
+        let old = inner
+            .backend
+            .storage(key)
+            .map_err(|e| anyhow!("Failed to read storage: {e}"))?;
+        if old.as_ref() == new.as_ref() {
+            continue;
+        }
+        let item = decoder
+            .storage(None, None, key, None)
+            .ok()
+            .and_then(|decoded| {
+                let pallet = decoded.get("pallet")?.as_str()?.to_string();
+                let item = decoded.get("item")?.as_str()?.to_string();
+                Some((format!("{pallet}::{item}"), decoded.get("keys").cloned()))
+            });
+        let (item, keys) = match item {
+            Some((item, keys)) => (Json::String(item), keys.unwrap_or(Json::Null)),
+            None => (Json::Null, Json::Null),
+        };
+        diff.push(json!({
+            "key": to_hex(key),
+            "item": item,
+            "keys": keys,
+            "old": storage_json(decoder, key, old.as_deref()),
+            "new": storage_json(decoder, key, new.as_deref()),
+        }));
+    }
+    if !dry_run {
+        inner
+            .backend
+            .apply_transaction(changes.transaction_storage_root, changes.transaction);
+    }
+
+    Ok(json!({
+        "result": match execution.result {
+            Ok(()) => json!("Ok"),
+            Err(err) => json!({ "Err": format!("{err:?}") }),
+        },
+        "weight": {
+            "declared": execution.declared_weight,
+            "actual": execution.actual_weight,
+        },
+        "fee": execution.fee,
+        "events": execution.events,
+        "storage": diff,
+    }))
+}
+
+fn save_snapshot(
+    mut inner_ext: TestExternalities<BlakeTwo256>,
+    header: framenode_runtime::Header,
+    path: &PathBuf,
+) -> AnyResult<()> {
+    inner_ext.commit_all().map_err(|e| anyhow!(e))?;
+    let state_version = inner_ext.state_version;
+    let (raw_storage, storage_root) = inner_ext.into_raw_snapshot();
+    let snapshot =
+        Snapshot::<framenode_runtime::Block>::new(state_version, raw_storage, storage_root, header);
+    std::fs::write(path, snapshot.encode())?;
+    Ok(())
 }
 
 #[tokio::main]
 async fn main() -> AnyResult<()> {
     env_logger::init();
     let cli = Cli::parse();
+    let extrinsics = cli.extrinsics()?;
+    let decoder = Decoder::new().map_err(|e| anyhow!(e))?;
     let client = HttpClientBuilder::default()
         .max_request_size(u32::MAX)
-        .build(cli.uri)?;
-    let mut ext = create_ext::<framenode_runtime::Block>(client, cli.at, cli.snapshot_path).await?;
-    let _res: AnyResult<()> = ext.execute_with(|| {
-        let xt_encoded = hex::decode(&cli.xt).unwrap();
-        let framenode_runtime::UncheckedExtrinsic {
-            preamble, function, ..
-        } = framenode_runtime::UncheckedExtrinsic::decode(&mut &xt_encoded[..]).unwrap();
-        if let Preamble::Signed(account, _signature, _extra) = preamble {
-            function
-                .dispatch(framenode_runtime::RuntimeOrigin::signed(account))
-                .unwrap();
-        }
-        Ok(())
-    });
+        .build(&cli.uri)?;
+    let mut ext =
+        create_ext::<framenode_runtime::Block>(client, cli.at, cli.snapshot_path.clone()).await?;
+    for (index, xt) in extrinsics.iter().enumerate() {
+        let mut report = execute_with_diff(&mut ext, &decoder, xt, cli.dry_run)?;
+        report["index"] = json!(index);
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    }
+    if let Some(path) = &cli.out_snapshot {
+        let RemoteExternalities {
+            inner_ext, header, ..
+        } = ext;
+        save_snapshot(inner_ext, header, path)?;
+        log::info!("Resulting state saved to {}", path.display());
+    }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use frame_support::storage::unhashed;
+    use sp_runtime::traits::Header as _;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("remote-ext-{}-{name}", std::process::id()))
+    }
+
+    #[test]
+    fn extrinsics_are_read_from_args_and_file() {
+        let path = temp_path("xts");
+        std::fs::write(&path, "# comment\n0x0203\n\n  0405  \n").unwrap();
+        let cli = Cli::parse_from([
+            "remote-ext",
+            "--uri",
+            "http://localhost:9933",
+            "--xt",
+            "0x01",
+            "--xt-file",
+            path.to_str().unwrap(),
+        ]);
+        assert_eq!(
+            cli.extrinsics().unwrap(),
+            vec![vec![1], vec![2, 3], vec![4, 5]]
+        );
+        std::fs::remove_file(path).unwrap();
+
+        let cli = Cli::parse_from(["remote-ext", "--uri", "http://localhost:9933"]);
+        assert!(cli.extrinsics().is_err());
+    }
+
+    #[test]
+    fn storage_values_are_decoded_or_printed_as_hex() {
+        let decoder = Decoder::new().unwrap();
+        let key = parse::storage_prefix("System", "Number");
+        assert_eq!(
+            storage_json(&decoder, &key, Some(&5u32.encode()[..])),
+            json!(5)
+        );
+        assert_eq!(storage_json(&decoder, &key, None), Json::Null);
+        assert_eq!(
+            storage_json(&decoder, &[1, 2, 3], Some(&[4u8, 5][..])),
+            json!("0x0405")
+        );
+    }
+
+    #[test]
+    fn bare_calls_are_not_dispatched_without_validation() {
+        let decoder = Decoder::new().unwrap();
+        let xt = framenode_runtime::UncheckedExtrinsic::new_bare(
+            frame_system::Call::<Runtime>::remark { remark: vec![1] }.into(),
+        );
+        TestExternalities::<BlakeTwo256>::default().execute_with(|| {
+            assert!(execute(&decoder, &xt.encode()).is_err());
+        });
+    }
+
+    #[tokio::test]
+    async fn saved_snapshot_is_loaded_by_builder() {
+        let path = temp_path("snapshot");
+        let mut inner_ext = TestExternalities::<BlakeTwo256>::default();
+        inner_ext.execute_with(|| unhashed::put_raw(b"key", b"value"));
+        let header = framenode_runtime::Header::new(
+            42,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        );
+        save_snapshot(inner_ext, header.clone(), &path).unwrap();
+
+        let mut ext = Builder::<framenode_runtime::Block>::new()
+            .mode(Mode::Offline(OfflineConfig {
+                state_snapshot: SnapshotConfig::new(&path),
+            }))
+            .build()
+            .await
+            .unwrap();
+        assert_eq!(ext.header, header);
+        ext.execute_with(|| assert_eq!(unhashed::get_raw(b"key"), Some(b"value".to_vec())));
+        std::fs::remove_file(path).unwrap();
+    }
+}